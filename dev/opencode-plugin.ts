@@ -4,12 +4,27 @@
 //   🤖 — agent is working
 //   💬 — agent is waiting for input
 //
+// Each event also records the session under `wt config state activity`, which
+// feeds the Agent column of `wt list`.
+//
 // Installed globally via: wt config plugins opencode install
 // Or manually: copy to ~/.config/opencode/plugins/worktrunk.ts
 
 import type { Plugin } from "@opencode-ai/plugin";
 
 export default (async ({ $, directory }) => {
+  // Every session event names its session: `sessionID` on status, idle and
+  // permission events, `info.id` on `session.deleted`.
+  const session = (properties: any): string =>
+    properties?.sessionID ?? properties?.info?.id ?? "";
+  const activity = (args: string[], id: string) =>
+    (id
+      ? $`wt config state activity ${args} --tool opencode --session ${id} || true`
+      : $`wt config state activity ${args} --tool opencode || true`
+    )
+      .cwd(directory)
+      .quiet();
+
   return {
     event: async ({ event }) => {
       // `$` is the host's process-global Bun shell, shared by every plugin in
@@ -22,12 +37,18 @@ export default (async ({ $, directory }) => {
       switch (event.type) {
         case "session.status":
           await $`wt config state marker set ${'🤖'} || true`.cwd(directory).quiet();
+          await activity(["set", "working"], session(event.properties));
           break;
         case "session.idle":
           await $`wt config state marker set ${'💬'} || true`.cwd(directory).quiet();
+          await activity(["set", "idle"], session(event.properties));
+          break;
+        case "permission.updated":
+          await activity(["set", "waiting"], session(event.properties));
           break;
         case "session.deleted":
           await $`wt config state marker clear || true`.cwd(directory).quiet();
+          await activity(["end"], session(event.properties));
           break;
       }
     },
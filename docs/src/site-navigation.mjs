@@ -10,6 +10,8 @@ export const sidebar = [
       { label: 'wt config', link: '/config/' },
       { label: 'wt step', link: '/step/' },
       { label: 'wt hook', link: '/hook/' },
+      { label: 'wt serve', link: '/serve/' },
+      { label: 'wt mcp', link: '/mcp/' },
     ],
   },
   {
@@ -0,0 +1,417 @@
+//! Agent session activity, recorded under `.git/wt/activity/`.
+//!
+//! Agent CLI plugins report each session's state through
+//! `wt config state activity set`: working on a turn, waiting on the user
+//! mid-turn (a permission prompt or a question), or idle between turns.
+//!
+//! - `sessions/<tool>-<session>.json` — one [`Session`] per live session,
+//!   rewritten on every report and removed when the session ends
+//! - `history.jsonl` — one [`ActivityEvent`] per state change and per
+//!   session end
+//!
+//! `wt list` reads the records into its Agent column and JSON `agents` field;
+//! `wt config state activity list --older-than` reads them to answer "which
+//! agents have been waiting on me".
+//!
+//! Record writes use the cache primitives' plain write and degrade silently,
+//! since the callers are hooks whose failure must never break the agent
+//! session: a torn record reads as no record, and the next report rewrites it.
+//! The history rotates to `history.jsonl.old` past 1MB, like the command log's
+//! default.
+
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{clear_json_files, clear_one, read_json, write_json};
+use crate::config::short_hash;
+use crate::git::Repository;
+use crate::path::sanitize_for_filename;
+
+/// Maximum history size before rotation (1MB).
+const MAX_HISTORY_SIZE: u64 = 1_048_576;
+
+/// File name of the live history within the activity directory.
+pub const HISTORY_FILE_NAME: &str = "history.jsonl";
+
+/// Minimum age of `last_activity_at` before a [`Activity::heartbeat`]
+/// rewrites it. The Claude Code statusline renders on every prompt redraw;
+/// a record that fresh has nothing to gain from another write.
+const HEARTBEAT_INTERVAL_SECS: u64 = 30;
+
+/// What an agent session is doing.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum AgentState {
+    /// Running a turn.
+    Working,
+    /// Blocked on the user mid-turn: a permission prompt or a question.
+    Waiting,
+    /// Finished its turn; ready for the next prompt.
+    Idle,
+}
+
+impl AgentState {
+    /// The symbol `wt list` shows for this state. Working and waiting match
+    /// the 🤖/💬 branch markers the plugins set.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            AgentState::Working => "🤖",
+            AgentState::Waiting => "💬",
+            AgentState::Idle => "💤",
+        }
+    }
+}
+
+/// One live agent session.
+///
+/// Timestamps are Unix seconds ([`crate::utils::epoch_now`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Session {
+    /// The agent CLI (`claude`, `codex`, `opencode`, `gemini`, …).
+    pub tool: String,
+    /// The tool's own session ID; absent when the reporter has none, which
+    /// makes the session one-per-tool-per-worktree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// The worktree the session runs in.
+    pub worktree: PathBuf,
+    /// The branch checked out there at the last report; absent when detached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    pub state: AgentState,
+    pub started_at: u64,
+    /// When the session entered `state`.
+    pub state_since: u64,
+    /// The last report or heartbeat.
+    pub last_activity_at: u64,
+}
+
+/// One line of `history.jsonl`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEvent {
+    pub ts: u64,
+    pub tool: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    pub worktree: PathBuf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// The state entered; `None` when the session ended.
+    pub state: Option<AgentState>,
+    /// The state left; absent when the session started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<AgentState>,
+    /// Seconds spent in `from`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secs: Option<u64>,
+}
+
+/// A state report from a plugin.
+#[derive(Debug, Clone, Copy)]
+pub struct Report<'a> {
+    pub tool: &'a str,
+    pub session_id: Option<&'a str>,
+    pub worktree: &'a Path,
+    pub branch: Option<&'a str>,
+    pub state: AgentState,
+}
+
+/// The activity directory of one repository.
+#[derive(Debug, Clone)]
+pub struct Activity {
+    dir: PathBuf,
+}
+
+impl Activity {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// `<git-common-dir>/wt/activity/` — shared by every worktree.
+    pub fn for_repo(repo: &Repository) -> Self {
+        Self::new(repo.wt_dir().join("activity"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn sessions_dir(&self) -> PathBuf {
+        self.dir.join("sessions")
+    }
+
+    /// Record file for a session. Without a session ID the worktree stands
+    /// in, hashed so the name stays short and filesystem-safe.
+    fn session_path(&self, tool: &str, session_id: Option<&str>, worktree: &Path) -> PathBuf {
+        let id = match session_id {
+            Some(id) => id.to_string(),
+            None => short_hash(&worktree.to_string_lossy()),
+        };
+        self.sessions_dir().join(format!(
+            "{}.json",
+            sanitize_for_filename(&format!("{tool}-{id}"))
+        ))
+    }
+
+    /// Apply a report: create the session, or update it. A state change
+    /// resets `state_since` and appends a history event; a repeated state
+    /// only refreshes `last_activity_at`.
+    pub fn record(&self, report: Report<'_>, now: u64) -> Session {
+        let path = self.session_path(report.tool, report.session_id, report.worktree);
+        let previous: Option<Session> = read_json(&path);
+        let session = Session {
+            tool: report.tool.to_string(),
+            session_id: report.session_id.map(str::to_string),
+            worktree: report.worktree.to_path_buf(),
+            branch: report.branch.map(str::to_string),
+            state: report.state,
+            started_at: previous.as_ref().map_or(now, |p| p.started_at),
+            state_since: match &previous {
+                Some(p) if p.state == report.state => p.state_since,
+                _ => now,
+            },
+            last_activity_at: now,
+        };
+        write_json(&path, &session);
+        if previous.as_ref().is_none_or(|p| p.state != report.state) {
+            self.append(&ActivityEvent {
+                ts: now,
+                tool: session.tool.clone(),
+                session_id: session.session_id.clone(),
+                worktree: session.worktree.clone(),
+                branch: session.branch.clone(),
+                state: Some(report.state),
+                from: previous.as_ref().map(|p| p.state),
+                secs: previous.map(|p| now.saturating_sub(p.state_since)),
+            });
+        }
+        session
+    }
+
+    /// End a session: remove its record and append an end event. Returns the
+    /// session that ended, or `None` when there was no record.
+    pub fn end(
+        &self,
+        tool: &str,
+        session_id: Option<&str>,
+        worktree: &Path,
+        now: u64,
+    ) -> anyhow::Result<Option<Session>> {
+        let path = self.session_path(tool, session_id, worktree);
+        let Some(session) = read_json::<Session>(&path) else {
+            return Ok(None);
+        };
+        clear_one(&path)?;
+        self.append(&ActivityEvent {
+            ts: now,
+            tool: session.tool.clone(),
+            session_id: session.session_id.clone(),
+            worktree: session.worktree.clone(),
+            branch: session.branch.clone(),
+            state: None,
+            from: Some(session.state),
+            secs: Some(now.saturating_sub(session.state_since)),
+        });
+        Ok(Some(session))
+    }
+
+    /// Refresh `last_activity_at` of an existing session without touching its
+    /// state. Never creates a record: the statusline knows a session is
+    /// alive, not what it is doing.
+    pub fn heartbeat(&self, tool: &str, session_id: &str, now: u64) {
+        // With a session ID the worktree doesn't enter the file name.
+        let path = self.session_path(tool, Some(session_id), Path::new(""));
+        let Some(mut session) = read_json::<Session>(&path) else {
+            return;
+        };
+        if now.saturating_sub(session.last_activity_at) < HEARTBEAT_INTERVAL_SECS {
+            return;
+        }
+        session.last_activity_at = now;
+        write_json(&path, &session);
+    }
+
+    /// Every live session, oldest first.
+    pub fn sessions(&self) -> Vec<Session> {
+        let Ok(entries) = fs::read_dir(self.sessions_dir()) else {
+            return Vec::new();
+        };
+        let mut sessions: Vec<Session> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| read_json(&path))
+            .collect();
+        sessions.sort_by(|a, b| {
+            (a.started_at, &a.tool, &a.session_id).cmp(&(b.started_at, &b.tool, &b.session_id))
+        });
+        sessions
+    }
+
+    /// Every history event, oldest first, across the rotated and live files.
+    /// Lines that don't parse are skipped.
+    pub fn history(&self) -> Vec<ActivityEvent> {
+        let live = self.dir.join(HISTORY_FILE_NAME);
+        [live.with_extension("jsonl.old"), live]
+            .iter()
+            .filter_map(|path| fs::File::open(path).ok())
+            .flat_map(|file| {
+                BufReader::new(file)
+                    .lines()
+                    .map_while(Result::ok)
+                    .filter_map(|line| serde_json::from_str(&line).ok())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Remove every session record and the history. Returns the number of
+    /// files removed.
+    pub fn clear(&self) -> anyhow::Result<usize> {
+        let live = self.dir.join(HISTORY_FILE_NAME);
+        let mut cleared = clear_json_files(&self.sessions_dir())?;
+        for path in [live.with_extension("jsonl.old"), live] {
+            if clear_one(&path)? {
+                cleared += 1;
+            }
+        }
+        Ok(cleared)
+    }
+
+    /// Append one event, rotating first if the live file is past the limit.
+    /// Degrades silently, like the record writes.
+    fn append(&self, event: &ActivityEvent) {
+        let path = self.dir.join(HISTORY_FILE_NAME);
+        if fs::metadata(&path).is_ok_and(|m| m.len() > MAX_HISTORY_SIZE) {
+            let _ = fs::rename(&path, path.with_extension("jsonl.old"));
+        }
+        let Ok(line) = serde_json::to_string(event) else {
+            return;
+        };
+        let result = fs::create_dir_all(&self.dir).and_then(|()| {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .and_then(|mut file| writeln!(file, "{line}"))
+        });
+        if let Err(e) = result {
+            log::debug!("Failed to append to {}: {e}", path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report<'a>(worktree: &'a Path, state: AgentState) -> Report<'a> {
+        Report {
+            tool: "claude",
+            session_id: Some("abc"),
+            worktree,
+            branch: Some("feature"),
+            state,
+        }
+    }
+
+    #[test]
+    fn test_state_changes_are_recorded_once() {
+        let temp = tempfile::tempdir().unwrap();
+        let activity = Activity::new(temp.path().to_path_buf());
+        let worktree = Path::new("/repo.feature");
+
+        activity.record(report(worktree, AgentState::Working), 100);
+        let repeated = activity.record(report(worktree, AgentState::Working), 150);
+        assert_eq!(repeated.state_since, 100);
+        assert_eq!(repeated.last_activity_at, 150);
+
+        let waiting = activity.record(report(worktree, AgentState::Waiting), 400);
+        assert_eq!(waiting.started_at, 100);
+        assert_eq!(waiting.state_since, 400);
+        assert_eq!(activity.sessions(), vec![waiting]);
+
+        let ended = activity.end("claude", Some("abc"), worktree, 460).unwrap();
+        assert!(ended.is_some());
+        assert!(activity.sessions().is_empty());
+        assert!(
+            activity
+                .end("claude", Some("abc"), worktree, 470)
+                .unwrap()
+                .is_none()
+        );
+
+        let history: Vec<_> = activity
+            .history()
+            .into_iter()
+            .map(|e| (e.ts, e.state, e.from, e.secs))
+            .collect();
+        assert_eq!(
+            history,
+            [
+                (100, Some(AgentState::Working), None, None),
+                (
+                    400,
+                    Some(AgentState::Waiting),
+                    Some(AgentState::Working),
+                    Some(300)
+                ),
+                (460, None, Some(AgentState::Waiting), Some(60)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_anonymous_sessions_are_per_worktree() {
+        let temp = tempfile::tempdir().unwrap();
+        let activity = Activity::new(temp.path().to_path_buf());
+        for worktree in ["/repo.a", "/repo.b", "/repo.a"] {
+            activity.record(
+                Report {
+                    session_id: None,
+                    ..report(Path::new(worktree), AgentState::Working)
+                },
+                100,
+            );
+        }
+        assert_eq!(activity.sessions().len(), 2);
+    }
+
+    #[test]
+    fn test_heartbeat_only_refreshes_existing_sessions() {
+        let temp = tempfile::tempdir().unwrap();
+        let activity = Activity::new(temp.path().to_path_buf());
+        let worktree = Path::new("/repo.feature");
+
+        activity.heartbeat("claude", "abc", 100);
+        assert!(activity.sessions().is_empty());
+
+        activity.record(report(worktree, AgentState::Idle), 100);
+        activity.heartbeat("claude", "abc", 110);
+        assert_eq!(activity.sessions()[0].last_activity_at, 100);
+        activity.heartbeat("claude", "abc", 200);
+        let session = &activity.sessions()[0];
+        assert_eq!((session.last_activity_at, session.state_since), (200, 100));
+
+        assert_eq!(activity.clear().unwrap(), 2);
+        assert!(activity.history().is_empty());
+    }
+}
@@ -0,0 +1,239 @@
+//! Hook execution strategies for [`Worktrunk`](super::Worktrunk) operations.
+//!
+//! Every operation calls its [`HookRunner`] at the points where `wt` runs the
+//! matching hook type — `pre-start`/`post-start` around creation,
+//! `pre-remove`/`post-remove` around removal, `pre-merge`/`post-merge` around
+//! the fast-forward. A `pre-*` failure aborts the operation before it changes
+//! anything; a `post-*` failure is reported in the result's `warnings`, since
+//! the operation has already happened.
+//!
+//! [`ConfiguredHooks`] (the default) runs what `wt` would run; [`SkipHooks`]
+//! runs nothing (`wt … --no-hooks`). Callers that want to log, filter or
+//! forward hooks elsewhere implement [`HookRunner`] themselves.
+
+use std::path::Path;
+use std::process::Stdio;
+
+use crate::HookType;
+use crate::config::{Approvals, CommandConfig, TemplateContext, VarScope, build_hook_context};
+use crate::container::{self, Container};
+use crate::git::{Repository, WorktrunkError};
+use crate::sandbox::{SandboxPolicy, sandboxed};
+use crate::shell_exec::{Cmd, ShellEscapeMode};
+
+/// Runs the hooks of one type for one operation.
+pub trait HookRunner: Send + Sync {
+    /// Run every hook of `invocation.hook_type`, returning the first failure.
+    fn run(&self, invocation: &HookInvocation<'_>) -> anyhow::Result<()>;
+}
+
+/// One hook point reached by an operation.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct HookInvocation<'a> {
+    /// Which hook type is due.
+    pub hook_type: HookType,
+    /// The repository the operation runs in.
+    pub repo: &'a Repository,
+    /// Directory the hook commands run in (`{{ cwd }}`) — the worktree the
+    /// operation acted on, or the primary worktree once that one is gone.
+    pub worktree_path: &'a Path,
+    /// The branch the operation acted on (`{{ branch }}`).
+    pub branch: Option<&'a str>,
+    /// The branch a new worktree was created from (`{{ base }}`).
+    pub base: Option<&'a str>,
+    /// The branch being merged into (`{{ target }}`).
+    pub target: Option<&'a str>,
+}
+
+impl<'a> HookInvocation<'a> {
+    pub(super) fn new(
+        hook_type: HookType,
+        repo: &'a Repository,
+        worktree_path: &'a Path,
+        branch: Option<&'a str>,
+    ) -> Self {
+        Self {
+            hook_type,
+            repo,
+            worktree_path,
+            branch,
+            base: None,
+            target: None,
+        }
+    }
+}
+
+/// Runs no hooks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SkipHooks;
+
+impl HookRunner for SkipHooks {
+    fn run(&self, _invocation: &HookInvocation<'_>) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the hooks `wt` would: user hooks from the user config, then project
+/// hooks from the repository's `.config/wt.toml`.
+///
+/// Commands run one at a time in the hook's working directory, with the hook
+/// template variables expanded and their JSON form on stdin; their stdout goes
+/// to stderr, as in `wt`. Project commands are confined as in `wt`: inside the
+/// worktree's `[container]` when it's running, else under `[hooks] sandbox`.
+/// Concurrent steps (`[[hook]]` tables) run sequentially here. Unlike `wt`,
+/// nothing is backgrounded: `post-*` hooks finish before the operation
+/// returns.
+///
+/// Project commands must already be approved (`wt config approvals add`) —
+/// there is no prompt to fall back on, so an unapproved command fails the
+/// hook. [`Self::trust_project`] lifts that requirement for callers that
+/// vet the project config themselves.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConfiguredHooks {
+    trust_project: bool,
+}
+
+impl ConfiguredHooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run project commands, and exec into the project's `[container]`,
+    /// without requiring a recorded approval.
+    pub fn trust_project(mut self, trust: bool) -> Self {
+        self.trust_project = trust;
+        self
+    }
+}
+
+impl HookRunner for ConfiguredHooks {
+    fn run(&self, invocation: &HookInvocation<'_>) -> anyhow::Result<()> {
+        let repo = invocation.repo;
+        let hook_type = invocation.hook_type;
+        let project_id = repo.project_identifier().ok();
+
+        let user = repo
+            .user_config()
+            .hooks(project_id.as_deref())
+            .get(hook_type)
+            .cloned();
+        let project_config = repo.load_project_config()?;
+        let project = project_config
+            .as_ref()
+            .and_then(|c| c.hooks.get(hook_type))
+            .cloned();
+        if user.is_none() && project.is_none() {
+            return Ok(());
+        }
+
+        if let Some(project) = &project
+            && !self.trust_project
+        {
+            let approvals = Approvals::load()?;
+            let scope = hook_type.to_string();
+            let unapproved = project.commands().find(|cmd| {
+                !project_id
+                    .as_deref()
+                    .is_some_and(|id| approvals.is_command_approved(id, &cmd.template, &scope))
+            });
+            if let Some(cmd) = unapproved {
+                anyhow::bail!(
+                    "{hook_type} project command is not approved: {}; approve it with `wt config approvals add`",
+                    cmd.template
+                );
+            }
+        }
+
+        let hook_type_name = hook_type.to_string();
+        let mut extra_vars = vec![("hook_type", hook_type_name.as_str())];
+        extra_vars.extend(invocation.base.map(|base| ("base", base)));
+        extra_vars.extend(invocation.target.map(|target| ("target", target)));
+        let context = build_hook_context(
+            repo,
+            invocation.worktree_path,
+            invocation.branch,
+            &extra_vars,
+            VarScope::All,
+        )?;
+
+        if let Some(user) = user {
+            run_commands(invocation, &context, "user", &user, None, None)?;
+        }
+        if let Some(project) = project {
+            let container = if self.trust_project {
+                container::configured(repo)
+            } else {
+                container::approved(repo)
+            }
+            .and_then(|config| container::running(&config, invocation.worktree_path));
+            let sandbox = SandboxPolicy::for_project_hooks(
+                repo,
+                repo.user_config(),
+                invocation.worktree_path,
+            );
+            run_commands(
+                invocation,
+                &context,
+                "project",
+                &project,
+                sandbox.as_ref(),
+                container.as_ref(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Run `config`'s commands in order, each inside `container` or `sandbox`
+/// when given — see [`sandboxed`].
+fn run_commands(
+    invocation: &HookInvocation<'_>,
+    context: &TemplateContext,
+    source: &str,
+    config: &CommandConfig,
+    sandbox: Option<&SandboxPolicy>,
+    container: Option<&Container>,
+) -> anyhow::Result<()> {
+    let hook_type = invocation.hook_type;
+    for cmd in config.commands() {
+        let mut context = context.clone();
+        let label = match &cmd.name {
+            Some(name) => {
+                context.insert("hook_name", name.clone());
+                format!("{hook_type} {source}:{name}")
+            }
+            None => format!("{hook_type} {source}"),
+        };
+        let command = context.expand(
+            &cmd.template,
+            ShellEscapeMode::Posix,
+            invocation.repo,
+            &label,
+        )?;
+        sandboxed(sandbox, container, command, invocation.worktree_path)
+            .and_then(|command| {
+                Cmd::shell(command)
+                    .current_dir(invocation.worktree_path)
+                    .scrub_git_discovery_env()
+                    .stdout(Stdio::from(std::io::stderr()))
+                    .stdin_bytes(context.to_json())
+                    .external(label)
+                    .external_branch(invocation.branch)
+                    .stream()
+            })
+            .map_err(|e| {
+                let exit_code = match e.downcast_ref::<WorktrunkError>() {
+                    Some(WorktrunkError::ChildProcessExited { code, .. }) => Some(*code),
+                    _ => None,
+                };
+                WorktrunkError::HookCommandFailed {
+                    hook_type,
+                    command_name: cmd.name.clone(),
+                    error: e.to_string(),
+                    exit_code,
+                }
+            })?;
+    }
+    Ok(())
+}
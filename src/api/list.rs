@@ -0,0 +1,443 @@
+//! Listing worktrees and branches.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use dunce::canonicalize;
+use serde::Serialize;
+
+use crate::git::{
+    IntegrationReason, LineDiff, NULL_OID, PorcelainStatus, RefSnapshot, Repository, WorktreeInfo,
+    duplicated_branches, select_comparison_base,
+};
+use crate::path::paths_match;
+use crate::utils::format_timestamp_iso8601_opt;
+
+use super::Worktrunk;
+
+/// Options for [`Worktrunk::list`].
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ListOptions {
+    /// Include local branches without a worktree (`wt list --branches`).
+    pub branches: bool,
+}
+
+impl ListOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn branches(mut self, branches: bool) -> Self {
+        self.branches = branches;
+        self
+    }
+}
+
+/// One `wt list` row: a worktree, or a branch without one.
+///
+/// Serializes to the item shape `wt list --format=json` prints under
+/// `[list] json-schema = 2` (published at
+/// `https://worktrunk.dev/schema/list-v2.json`), with the same field names,
+/// nesting and absence rules. It carries the local facts only — the fields
+/// that need a forge, an LLM or the table renderer are left out: `pr`,
+/// `checks`, `summary`, `dev_server`, `display`, and `default_branch.diff` /
+/// `default_branch.merge_conflicts`. Every fact it does carry is determined,
+/// so nothing is `null` for being pending.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct Item {
+    /// Branch name; `None` for a detached-HEAD worktree.
+    pub branch: Option<String>,
+    /// HEAD commit; `None` for an unborn branch.
+    pub head: Option<ItemHead>,
+    /// Worktree facts; absent on branch-only rows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worktree: Option<ItemWorktree>,
+    /// Relation to the default branch; absent on the default branch itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<ItemDefaultBranch>,
+    /// Tracking-branch relation; absent when no upstream is configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream: Option<ItemUpstream>,
+    /// Custom variables stored via `wt config state vars`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub vars: BTreeMap<String, String>,
+}
+
+/// HEAD commit facts.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct ItemHead {
+    pub sha: String,
+    /// Abbreviated per `core.abbrev`.
+    pub short_sha: String,
+    /// Commit subject (first line); `None` when it couldn't be read.
+    pub subject: Option<String>,
+    /// Committer time, RFC 3339 UTC; `None` when it couldn't be read.
+    pub committed_at: Option<String>,
+}
+
+/// Worktree facts.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct ItemWorktree {
+    pub path: PathBuf,
+    /// The main worktree.
+    pub main: bool,
+    /// The worktree the handle was opened in.
+    pub current: bool,
+    /// The previous worktree (`wt switch -`).
+    pub previous: bool,
+    pub detached: bool,
+    /// Present when the worktree is locked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<ItemReason>,
+    /// Present when git considers the worktree prunable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prunable: Option<ItemReason>,
+    /// The worktree isn't at the path the `worktree-path` template gives its
+    /// branch.
+    pub branch_mismatch: bool,
+    /// Another worktree has the same branch checked out.
+    pub duplicate_branch: bool,
+    /// In-progress operation (`merge`, `rebase`, `cherry_pick`, `revert`,
+    /// `bisect`); absent when none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<&'static str>,
+    /// Working-tree state; `None` for a prunable worktree.
+    pub changes: Option<WorktreeChanges>,
+}
+
+/// Working-tree change facts.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct WorktreeChanges {
+    pub staged: bool,
+    pub modified: bool,
+    pub untracked: bool,
+    pub renamed: bool,
+    pub deleted: bool,
+    /// Tracked files carry merge conflicts.
+    pub conflicted: Option<bool>,
+    /// Lines added/deleted vs HEAD, tracked files only.
+    pub diff: Option<LineDiff>,
+}
+
+/// Relation to the default branch, measured against its upstream when the
+/// local copy lags (as `wt list` does).
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct ItemDefaultBranch {
+    /// Commits ahead; `None` for orphans and when there is no default branch.
+    pub ahead: Option<usize>,
+    /// Commits behind; `None` for orphans and when there is no default branch.
+    pub behind: Option<usize>,
+    /// No merge-base with the default branch.
+    pub orphan: Option<bool>,
+    /// How committed content is integrated; absent when it isn't.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration: Option<ItemIntegration>,
+}
+
+/// Why committed content counts as integrated.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct ItemIntegration {
+    /// Which check matched.
+    #[serde(serialize_with = "serialize_integration_reason")]
+    pub reason: IntegrationReason,
+}
+
+/// Reason payload for `locked` / `prunable`.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct ItemReason {
+    /// The reason git records; `None` when none was given.
+    pub reason: Option<String>,
+}
+
+/// Tracking-branch relation.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct ItemUpstream {
+    pub remote: String,
+    /// Branch name on the remote.
+    pub branch: Option<String>,
+    pub ahead: usize,
+    pub behind: usize,
+}
+
+/// Schema 2 spells reasons in snake_case, where the enum's own serde form is
+/// kebab-case.
+fn serialize_integration_reason<S: serde::Serializer>(
+    reason: &IntegrationReason,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&<&str>::from(*reason).replace('-', "_"))
+}
+
+/// Repo-wide facts every row reads.
+struct Shared {
+    snapshot: RefSnapshot,
+    default_branch: Option<String>,
+    comparison_base: Option<String>,
+    commits: HashMap<String, (String, i64, String)>,
+    vars: HashMap<String, BTreeMap<String, String>>,
+}
+
+impl Worktrunk {
+    /// The `wt list` rows, in table order: the current worktree, the main
+    /// worktree, other worktrees by commit time, then (with
+    /// [`ListOptions::branches`]) branches without a worktree by commit time.
+    pub fn list(&self, options: &ListOptions) -> anyhow::Result<Vec<Item>> {
+        let repo = self.repository()?;
+        let worktrees = repo.list_worktrees()?;
+        let snapshot = repo.capture_refs()?;
+        let default_branch = repo.default_branch();
+        let targets = repo.integration_targets(&snapshot);
+        let comparison_base =
+            select_comparison_base(targets.as_ref(), default_branch.as_deref()).map(String::from);
+
+        let branch_rows: Vec<(String, String)> = if options.branches {
+            snapshot
+                .local_branches()
+                .iter()
+                .filter(|b| {
+                    !worktrees
+                        .iter()
+                        .any(|wt| wt.branch.as_deref() == Some(b.name.as_str()))
+                })
+                .map(|b| (b.name.clone(), b.commit_sha.clone()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let shas: Vec<&str> = worktrees
+            .iter()
+            .map(|wt| wt.head.as_str())
+            .chain(branch_rows.iter().map(|(_, sha)| sha.as_str()))
+            .filter(|sha| !sha.is_empty() && *sha != NULL_OID)
+            .collect();
+        let shared = Shared {
+            commits: repo.commit_details_many(&shas)?,
+            vars: repo.all_vars_from_snapshot().unwrap_or_default(),
+            snapshot,
+            default_branch,
+            comparison_base,
+        };
+        let timestamp = |sha: &str| shared.commits.get(sha).map_or(0, |(_, ts, _)| *ts);
+
+        let current = repo.current_worktree().root().ok();
+        // The primary worktree; in a bare repository without a default-branch
+        // worktree, the first live one.
+        let main = match repo.primary_worktree()? {
+            Some(path) => Some(path),
+            None => worktrees
+                .iter()
+                .find(|wt| !wt.is_prunable())
+                .map(|wt| wt.path.clone()),
+        };
+        let previous = repo.switch_previous();
+        let duplicated = duplicated_branches(worktrees);
+
+        let mut sorted: Vec<&WorktreeInfo> = worktrees.iter().collect();
+        sorted.sort_by_key(|wt| {
+            let priority = if is_same_dir(&wt.path, current.as_deref()) {
+                0
+            } else if main.as_ref() == Some(&wt.path) {
+                1
+            } else {
+                2
+            };
+            (priority, std::cmp::Reverse(timestamp(&wt.head)))
+        });
+        let mut branch_rows = branch_rows;
+        branch_rows.sort_by_key(|(_, sha)| std::cmp::Reverse(timestamp(sha)));
+
+        let mut items = Vec::with_capacity(sorted.len() + branch_rows.len());
+        for wt in sorted {
+            let is_main = is_same_dir(&wt.path, main.as_deref());
+            let worktree = ItemWorktree {
+                path: wt.path.clone(),
+                main: is_main,
+                current: is_same_dir(&wt.path, current.as_deref()),
+                previous: wt.branch.is_some() && wt.branch == previous,
+                detached: wt.detached,
+                locked: reason(&wt.locked),
+                prunable: reason(&wt.prunable),
+                branch_mismatch: !wt.branch.as_deref().is_some_and(|branch| {
+                    repo.expected_worktree_path(branch, repo.user_config())
+                        .is_ok_and(|expected| paths_match(&wt.path, &expected))
+                }),
+                duplicate_branch: wt
+                    .branch
+                    .as_deref()
+                    .is_some_and(|branch| duplicated.contains(branch)),
+                operation: None,
+                changes: None,
+            };
+            let worktree = if wt.is_prunable() {
+                worktree
+            } else {
+                with_working_tree_state(&repo, worktree)?
+            };
+            items.push(shared.item(&repo, wt.branch.clone(), &wt.head, Some(worktree))?);
+        }
+        for (branch, sha) in branch_rows {
+            items.push(shared.item(&repo, Some(branch), &sha, None)?);
+        }
+        Ok(items)
+    }
+}
+
+/// Empty lock/prune reasons (git records the state without a message)
+/// become `reason: None`.
+fn reason(reason: &Option<String>) -> Option<ItemReason> {
+    reason.as_ref().map(|reason| ItemReason {
+        reason: Some(reason.clone()).filter(|r| !r.is_empty()),
+    })
+}
+
+fn is_same_dir(path: &Path, other: Option<&Path>) -> bool {
+    other.is_some_and(|other| canonicalize(path).is_ok_and(|p| p == other) || path == other)
+}
+
+fn with_working_tree_state(
+    repo: &Repository,
+    mut worktree: ItemWorktree,
+) -> anyhow::Result<ItemWorktree> {
+    let wt = repo.worktree_at(&worktree.path);
+    let status = PorcelainStatus::parse(&wt.status_porcelain_cached()?);
+    let diff = if status.is_dirty() {
+        wt.working_tree_diff_stats()?
+    } else {
+        LineDiff::default()
+    };
+    worktree.operation = wt.operation_in_progress()?.map(<&'static str>::from);
+    worktree.changes = Some(WorktreeChanges {
+        staged: status.staged,
+        modified: status.modified,
+        untracked: status.untracked,
+        renamed: status.renamed,
+        deleted: status.deleted,
+        conflicted: Some(status.conflicted),
+        diff: Some(diff),
+    });
+    Ok(worktree)
+}
+
+impl Shared {
+    fn item(
+        &self,
+        repo: &Repository,
+        branch: Option<String>,
+        sha: &str,
+        worktree: Option<ItemWorktree>,
+    ) -> anyhow::Result<Item> {
+        let head = match (sha.is_empty() || sha == NULL_OID, self.commits.get(sha)) {
+            (true, _) => None,
+            (false, Some((short_sha, timestamp, subject))) => Some(ItemHead {
+                sha: sha.to_string(),
+                short_sha: short_sha.clone(),
+                subject: Some(subject.clone()),
+                committed_at: format_timestamp_iso8601_opt(*timestamp),
+            }),
+            (false, None) => Some(ItemHead {
+                sha: sha.to_string(),
+                short_sha: repo.short_sha(sha)?,
+                subject: None,
+                committed_at: None,
+            }),
+        };
+
+        let is_default_branch_row = worktree.as_ref().is_some_and(|wt| wt.main)
+            || (branch.is_some() && branch == self.default_branch);
+        let default_branch = if is_default_branch_row {
+            None
+        } else {
+            Some(self.default_branch_relation(repo, branch.as_deref(), head.as_ref())?)
+        };
+
+        let upstream = match branch.as_deref() {
+            Some(branch) if head.is_some() => upstream(repo, branch, sha)?,
+            _ => None,
+        };
+
+        let vars = branch
+            .as_deref()
+            .and_then(|branch| self.vars.get(branch))
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(Item {
+            branch,
+            head,
+            worktree,
+            default_branch,
+            upstream,
+            vars,
+        })
+    }
+
+    fn default_branch_relation(
+        &self,
+        repo: &Repository,
+        branch: Option<&str>,
+        head: Option<&ItemHead>,
+    ) -> anyhow::Result<ItemDefaultBranch> {
+        let (Some(base), Some(default_branch), Some(head)) =
+            (&self.comparison_base, &self.default_branch, head)
+        else {
+            return Ok(ItemDefaultBranch {
+                ahead: None,
+                behind: None,
+                orphan: None,
+                integration: None,
+            });
+        };
+        let base_sha = repo
+            .run_command(&["rev-parse", "--verify", "--end-of-options", base])?
+            .trim()
+            .to_string();
+        let sha = head.sha.as_str();
+        if repo.merge_base_by_sha(&base_sha, sha)?.is_none() {
+            return Ok(ItemDefaultBranch {
+                ahead: None,
+                behind: None,
+                orphan: Some(true),
+                integration: None,
+            });
+        }
+        let (ahead, behind) = repo.ahead_behind_by_sha(&base_sha, sha)?;
+        let (_, integration) =
+            repo.integration_reason(&self.snapshot, branch.unwrap_or(sha), default_branch)?;
+        Ok(ItemDefaultBranch {
+            ahead: Some(ahead),
+            behind: Some(behind),
+            orphan: Some(false),
+            integration: integration.map(|reason| ItemIntegration { reason }),
+        })
+    }
+}
+
+fn upstream(repo: &Repository, branch: &str, sha: &str) -> anyhow::Result<Option<ItemUpstream>> {
+    let Some(upstream) = repo.branch(branch).upstream()? else {
+        return Ok(None);
+    };
+    let Some((remote, remote_branch)) = upstream.split_once('/') else {
+        return Ok(None);
+    };
+    let upstream_sha = repo
+        .run_command(&["rev-parse", "--verify", "--end-of-options", &upstream])?
+        .trim()
+        .to_string();
+    let (ahead, behind) = repo.ahead_behind_by_sha(&upstream_sha, sha)?;
+    Ok(Some(ItemUpstream {
+        remote: remote.to_string(),
+        branch: Some(remote_branch.to_string()),
+        ahead,
+        behind,
+    }))
+}
@@ -0,0 +1,208 @@
+//! Merging a branch into its target.
+
+use crate::HookType;
+use crate::git::{ErrorExt, GitError, Repository};
+
+use super::{HookInvocation, RemoveOptions, RemovedWorktree, Worktrunk, anchor_path};
+
+/// Options for [`Worktrunk::merge`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MergeOptions {
+    /// Branch to merge; it must have a worktree.
+    pub branch: String,
+    /// Branch to merge into; the default branch when unset.
+    pub target: Option<String>,
+    /// Squash the branch's commits into one with this message first. `wt
+    /// merge` generates the message; here the caller supplies it.
+    pub squash_message: Option<String>,
+    /// Rebase onto the target when the branch isn't already on it
+    /// (default). When off, an un-rebased branch fails with
+    /// [`GitError::NotRebased`].
+    pub rebase: bool,
+    /// Remove the branch's worktree and branch after merging (default).
+    pub remove: bool,
+}
+
+impl MergeOptions {
+    pub fn new(branch: impl Into<String>) -> Self {
+        Self {
+            branch: branch.into(),
+            target: None,
+            squash_message: None,
+            rebase: true,
+            remove: true,
+        }
+    }
+
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn squash(mut self, message: impl Into<String>) -> Self {
+        self.squash_message = Some(message.into());
+        self
+    }
+
+    pub fn rebase(mut self, rebase: bool) -> Self {
+        self.rebase = rebase;
+        self
+    }
+
+    pub fn remove(mut self, remove: bool) -> Self {
+        self.remove = remove;
+        self
+    }
+}
+
+/// The outcome of [`Worktrunk::merge`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Merged {
+    pub branch: String,
+    pub target: String,
+    /// The target branch's new tip.
+    pub commit: String,
+    /// The branch's commits were squashed.
+    pub squashed: bool,
+    /// The branch was rebased onto the target.
+    pub rebased: bool,
+    /// The removal, when [`MergeOptions::remove`] was set and it succeeded.
+    pub removed: Option<RemovedWorktree>,
+    /// `post-merge` hook failures, and why removal didn't happen.
+    pub warnings: Vec<String>,
+}
+
+impl Worktrunk {
+    /// Fast-forward the target branch to a branch — `wt merge` run from the
+    /// branch's worktree, without commit-message generation.
+    ///
+    /// The branch's worktree must be clean and not mid-operation. In order:
+    /// squash (when asked), rebase onto the target, `pre-merge` hooks, the
+    /// fast-forward (syncing the target's worktree, as `wt merge` does),
+    /// `post-merge` hooks, then removal.
+    pub fn merge(&self, options: &MergeOptions) -> anyhow::Result<Merged> {
+        let branch = options.branch.as_str();
+        let worktree_path = self
+            .repository()?
+            .usable_worktree_for_branch(branch)?
+            .ok_or_else(|| GitError::WorktreeNotFound {
+                branch: branch.to_string(),
+            })?;
+        // Rooted in the branch's worktree, so HEAD is the branch.
+        let repo = Repository::at(&worktree_path)?;
+        repo.ensure_no_operation_in_progress("merge")?;
+        repo.worktree_at(&worktree_path)
+            .ensure_clean("merge", Some(branch), false)?;
+        let target = repo.require_target_branch(options.target.as_deref())?;
+
+        let squashed = match &options.squash_message {
+            Some(message) => squash(&repo, &target, message)?,
+            None => false,
+        };
+
+        let rebased = if repo.is_rebased_onto(&target)? {
+            false
+        } else if options.rebase {
+            rebase(&repo, &target)?;
+            true
+        } else {
+            return Err(GitError::NotRebased {
+                target_branch: target,
+            }
+            .into());
+        };
+
+        let mut invocation =
+            HookInvocation::new(HookType::PreMerge, &repo, &worktree_path, Some(branch));
+        invocation.target = Some(&target);
+        self.run_hooks(invocation)?;
+
+        let head_sha = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
+        let target_sha = repo
+            .run_command(&["rev-parse", &format!("refs/heads/{target}")])?
+            .trim()
+            .to_string();
+        if !repo.is_ancestor_by_sha(&target_sha, &head_sha)? {
+            return Err(GitError::NotFastForward {
+                target_branch: target,
+                commits_formatted: String::new(),
+                in_merge_context: true,
+            }
+            .into());
+        }
+        let target_worktree = repo.usable_worktree_for_branch(&target)?;
+        repo.ensure_no_target_conflicts(target_worktree.as_deref(), &target)?;
+        repo.advance_branch(
+            &target,
+            target_worktree.as_deref(),
+            &target_sha,
+            &head_sha,
+            "wt merge: fast-forward",
+        )?;
+
+        // `post-merge` runs where the merged commits landed.
+        let mut warnings = Vec::new();
+        let post_merge_path = match &target_worktree {
+            Some(path) => path.clone(),
+            None => anchor_path(&repo)?,
+        };
+        let mut invocation =
+            HookInvocation::new(HookType::PostMerge, &repo, &post_merge_path, Some(branch));
+        invocation.target = Some(&target);
+        self.run_post_hooks(invocation, &mut warnings);
+
+        let removed = if options.remove && branch != target {
+            match self.remove_worktree(&RemoveOptions::new(branch).target(&target)) {
+                Ok(removed) => Some(removed),
+                Err(e) => {
+                    warnings.push(format!("Failed to remove {branch}: {e:#}"));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        Ok(Merged {
+            branch: branch.to_string(),
+            target,
+            commit: head_sha,
+            squashed,
+            rebased,
+            removed,
+            warnings,
+        })
+    }
+}
+
+/// Squash everything since the merge base into one commit. Returns whether a
+/// squash happened — one commit, or none, is left as is.
+fn squash(repo: &Repository, target: &str, message: &str) -> anyhow::Result<bool> {
+    let Some(merge_base) = repo.merge_base("HEAD", target)? else {
+        anyhow::bail!("Cannot squash: no common ancestor with {target}");
+    };
+    if repo.count_commits(&merge_base, "HEAD")? < 2 {
+        return Ok(false);
+    }
+    repo.run_command(&["reset", "--soft", &merge_base])?;
+    repo.run_command(&["commit", "-m", message])?;
+    Ok(true)
+}
+
+/// Rebase HEAD onto `target`, aborting a conflicted rebase so the worktree is
+/// left as it was.
+fn rebase(repo: &Repository, target: &str) -> anyhow::Result<()> {
+    let Err(e) = repo.run_command(&["rebase", "--end-of-options", target]) else {
+        return Ok(());
+    };
+    if repo.operation_in_progress()?.is_some() {
+        let _ = repo.run_command(&["rebase", "--abort"]);
+    }
+    Err(GitError::RebaseConflict {
+        target_branch: target.to_string(),
+        git_output: e.display_message(),
+    }
+    .into())
+}
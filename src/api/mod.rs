@@ -0,0 +1,120 @@
+//! Stable operations for embedding worktrunk.
+//!
+//! The rest of the crate is `wt`'s internals and changes freely; this module
+//! is the supported surface for tools that drive worktrees programmatically —
+//! dashboards, bots, editor integrations. It builds with
+//! `default-features = false`.
+//!
+//! ```no_run
+//! use worktrunk::api::{CreateOptions, ListOptions, MergeOptions, SkipHooks, Worktrunk};
+//!
+//! let wt = Worktrunk::open("/path/to/repo")?;
+//! let created = wt.create_worktree(&CreateOptions::new("feature").create_branch(true))?;
+//! println!("created {}", created.path.display());
+//!
+//! for item in wt.list(&ListOptions::new())? {
+//!     println!("{}", serde_json::to_string(&item)?);
+//! }
+//!
+//! let wt = wt.with_hooks(SkipHooks);
+//! wt.merge(&MergeOptions::new("feature"))?;
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! # Stability
+//!
+//! Option and result types are `#[non_exhaustive]`: options are built with
+//! `new` plus setters and results are read by field, so fields can be added
+//! without a breaking release. [`Item`] serializes to the `wt list --format=json`
+//! schema-2 item shape; the fields it omits are listed on the type.
+//!
+//! # Errors
+//!
+//! Operations return [`anyhow::Error`]. Failures `wt` reports with a typed
+//! error keep it: downcast to [`GitError`](crate::git::GitError) (e.g.
+//! `UncommittedChanges`, `NotFastForward`, `RebaseConflict`) or
+//! [`WorktrunkError`](crate::git::WorktrunkError) (`HookCommandFailed`).
+//!
+//! # Hooks
+//!
+//! Operations run hooks through a [`HookRunner`] —
+//! [`ConfiguredHooks`] by default. See [`hooks`] for the contract.
+
+pub mod hooks;
+mod list;
+mod merge;
+mod worktree;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::git::Repository;
+
+pub use hooks::{ConfiguredHooks, HookInvocation, HookRunner, SkipHooks};
+pub use list::{
+    Item, ItemDefaultBranch, ItemHead, ItemIntegration, ItemReason, ItemUpstream, ItemWorktree,
+    ListOptions, WorktreeChanges,
+};
+pub use merge::{MergeOptions, Merged};
+pub use worktree::{CreateOptions, CreatedWorktree, RemoveOptions, RemovedWorktree};
+
+/// A handle on one repository.
+///
+/// Each operation reads the repository afresh, so one handle can serve a
+/// long-running process while `wt` and git change the repository underneath.
+#[derive(Clone)]
+pub struct Worktrunk {
+    path: PathBuf,
+    hooks: Arc<dyn HookRunner>,
+}
+
+impl Worktrunk {
+    /// Open the repository containing `path` (any of its worktrees, or a bare
+    /// repository).
+    pub fn open(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        Repository::at(path.as_path())?;
+        Ok(Self {
+            path,
+            hooks: Arc::new(ConfiguredHooks::default()),
+        })
+    }
+
+    /// Replace the hook runner used by every operation.
+    pub fn with_hooks(mut self, hooks: impl HookRunner + 'static) -> Self {
+        self.hooks = Arc::new(hooks);
+        self
+    }
+
+    /// A [`Repository`] for reads this module doesn't cover.
+    ///
+    /// `Repository` caches what it reads, so take a new one after changing
+    /// the repository rather than holding one across operations.
+    pub fn repository(&self) -> anyhow::Result<Repository> {
+        Repository::at(self.path.as_path())
+    }
+
+    fn run_hooks(&self, invocation: HookInvocation<'_>) -> anyhow::Result<()> {
+        self.hooks.run(&invocation)
+    }
+
+    /// Run a `post-*` hook, recording a failure in `warnings` instead of
+    /// failing an operation that has already happened.
+    fn run_post_hooks(&self, invocation: HookInvocation<'_>, warnings: &mut Vec<String>) {
+        if let Err(e) = self.hooks.run(&invocation) {
+            warnings.push(format!("{} hook failed: {e:#}", invocation.hook_type));
+        }
+    }
+}
+
+/// The primary worktree, falling back to the repository's own path (bare
+/// repositories without a default-branch worktree).
+fn anchor_path(repo: &Repository) -> anyhow::Result<PathBuf> {
+    Ok(match repo.primary_worktree()? {
+        Some(path) => path,
+        None => repo.repo_path()?.to_path_buf(),
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,225 @@
+use std::sync::{Arc, Mutex};
+
+use crate::HookType;
+use crate::git::GitError;
+use crate::testing::TestRepo;
+
+use super::*;
+
+type Seen = Arc<Mutex<Vec<(HookType, Option<String>)>>>;
+
+/// Records every hook point reached, failing the ones in `fail`.
+#[derive(Default)]
+struct Recorder {
+    seen: Seen,
+    fail: Vec<HookType>,
+}
+
+impl HookRunner for Recorder {
+    fn run(&self, invocation: &HookInvocation<'_>) -> anyhow::Result<()> {
+        self.seen
+            .lock()
+            .unwrap()
+            .push((invocation.hook_type, invocation.branch.map(str::to_string)));
+        if self.fail.contains(&invocation.hook_type) {
+            anyhow::bail!("refused");
+        }
+        Ok(())
+    }
+}
+
+fn hook_types(seen: &Seen) -> Vec<HookType> {
+    seen.lock().unwrap().iter().map(|(t, _)| *t).collect()
+}
+
+#[test]
+fn create_merge_remove_round_trip() {
+    let test = TestRepo::with_initial_commit();
+    let recorder = Recorder::default();
+    let seen = recorder.seen.clone();
+    let wt = Worktrunk::open(test.root_path())
+        .unwrap()
+        .with_hooks(recorder);
+
+    let created = wt
+        .create_worktree(&CreateOptions::new("feature").create_branch(true))
+        .unwrap();
+    assert!(created.path.ends_with("repo.feature"), "{:?}", created.path);
+    assert!(created.warnings.is_empty());
+
+    std::fs::write(created.path.join("a.txt"), "a").unwrap();
+    test.run_git_in(&created.path, &["add", "a.txt"]);
+    test.run_git_in(&created.path, &["commit", "-m", "add a"]);
+    std::fs::write(created.path.join("b.txt"), "b").unwrap();
+    test.run_git_in(&created.path, &["add", "b.txt"]);
+    test.run_git_in(&created.path, &["commit", "-m", "add b"]);
+
+    let items = wt.list(&ListOptions::new()).unwrap();
+    let feature = items
+        .iter()
+        .find(|item| item.branch.as_deref() == Some("feature"))
+        .unwrap();
+    assert_eq!(feature.default_branch.as_ref().unwrap().ahead, Some(2));
+
+    let merged = wt
+        .merge(&MergeOptions::new("feature").squash("feature work"))
+        .unwrap();
+    assert_eq!(merged.target, "main");
+    assert!(merged.squashed);
+    assert!(!merged.rebased);
+    assert_eq!(test.git_output(&["rev-parse", "main"]), merged.commit);
+    assert_eq!(
+        test.git_output(&["log", "-1", "--format=%s"]),
+        "feature work"
+    );
+    // The primary worktree has main checked out and follows the fast-forward.
+    assert!(test.root_path().join("b.txt").exists());
+
+    let removed = merged.removed.unwrap();
+    assert!(removed.branch_deleted, "{:?}", removed.warnings);
+    assert!(!created.path.exists());
+    assert_eq!(
+        hook_types(&seen),
+        [
+            HookType::PreCreate,
+            HookType::PostCreate,
+            HookType::PreMerge,
+            HookType::PostMerge,
+            HookType::PreRemove,
+            HookType::PostRemove,
+        ]
+    );
+    assert!(
+        seen.lock()
+            .unwrap()
+            .iter()
+            .all(|(_, branch)| branch.as_deref() == Some("feature"))
+    );
+}
+
+#[test]
+fn failing_pre_merge_hook_leaves_target_alone() {
+    let test = TestRepo::with_initial_commit();
+    let wt = Worktrunk::open(test.root_path())
+        .unwrap()
+        .with_hooks(SkipHooks);
+    let created = wt
+        .create_worktree(&CreateOptions::new("feature").create_branch(true))
+        .unwrap();
+    std::fs::write(created.path.join("a.txt"), "a").unwrap();
+    test.run_git_in(&created.path, &["add", "a.txt"]);
+    test.run_git_in(&created.path, &["commit", "-m", "add a"]);
+    let main_before = test.git_output(&["rev-parse", "main"]);
+
+    let wt = wt.with_hooks(Recorder {
+        fail: vec![HookType::PreMerge],
+        ..Recorder::default()
+    });
+    let err = wt.merge(&MergeOptions::new("feature")).unwrap_err();
+    assert_eq!(err.to_string(), "refused");
+    assert_eq!(test.git_output(&["rev-parse", "main"]), main_before);
+    assert!(created.path.exists());
+}
+
+#[test]
+fn remove_refuses_uncommitted_changes_without_force() {
+    let test = TestRepo::with_initial_commit();
+    let wt = Worktrunk::open(test.root_path())
+        .unwrap()
+        .with_hooks(SkipHooks);
+    let created = wt
+        .create_worktree(&CreateOptions::new("feature").create_branch(true))
+        .unwrap();
+    std::fs::write(created.path.join("dirty.txt"), "dirty").unwrap();
+
+    let err = wt
+        .remove_worktree(&RemoveOptions::new("feature"))
+        .unwrap_err();
+    assert!(
+        matches!(
+            err.downcast_ref::<GitError>(),
+            Some(GitError::UncommittedChanges { .. })
+        ),
+        "{err:#}"
+    );
+    assert!(created.path.exists());
+
+    let removed = wt
+        .remove_worktree(&RemoveOptions::new("feature").force(true))
+        .unwrap();
+    assert!(!removed.path.exists());
+    // `feature` has no commits beyond main, so it counts as integrated.
+    assert!(removed.branch_deleted);
+}
+
+#[test]
+fn remove_releases_ports() {
+    let test = TestRepo::with_initial_commit();
+    let wt = Worktrunk::open(test.root_path())
+        .unwrap()
+        .with_hooks(SkipHooks);
+    wt.create_worktree(&CreateOptions::new("feature").create_branch(true))
+        .unwrap();
+    let repo = crate::git::Repository::at(test.root_path()).unwrap();
+    let ports = crate::ports::Ports::for_repo(&repo);
+    ports.allocate("feature", "web").unwrap();
+
+    wt.remove_worktree(&RemoveOptions::new("feature")).unwrap();
+    assert!(ports.for_branch("feature").unwrap().is_empty());
+}
+
+#[test]
+fn create_refuses_existing_branch() {
+    let test = TestRepo::with_initial_commit();
+    test.run_git(&["branch", "feature"]);
+    let wt = Worktrunk::open(test.root_path())
+        .unwrap()
+        .with_hooks(SkipHooks);
+
+    let err = wt
+        .create_worktree(&CreateOptions::new("feature").create_branch(true))
+        .unwrap_err();
+    assert!(
+        matches!(
+            err.downcast_ref::<GitError>(),
+            Some(GitError::BranchAlreadyExists { .. })
+        ),
+        "{err:#}"
+    );
+
+    let created = wt.create_worktree(&CreateOptions::new("feature")).unwrap();
+    assert!(created.path.exists());
+}
+
+#[test]
+fn list_items_serialize_to_schema_2_shape() {
+    let test = TestRepo::with_initial_commit();
+    let wt = Worktrunk::open(test.root_path())
+        .unwrap()
+        .with_hooks(SkipHooks);
+    let created = wt
+        .create_worktree(&CreateOptions::new("feature").create_branch(true))
+        .unwrap();
+    std::fs::write(created.path.join("new.txt"), "new").unwrap();
+    test.run_git(&["branch", "orphaned-branch"]);
+
+    let items = wt.list(&ListOptions::new().branches(true)).unwrap();
+    let branches: Vec<_> = items.iter().map(|i| i.branch.as_deref()).collect();
+    assert_eq!(
+        branches,
+        [Some("main"), Some("feature"), Some("orphaned-branch")]
+    );
+
+    let json = serde_json::to_value(&items).unwrap();
+    assert_eq!(json[0]["worktree"]["main"], true);
+    assert_eq!(json[0]["worktree"]["current"], true);
+    assert!(json[0].get("default_branch").is_none());
+    assert_eq!(json[1]["worktree"]["changes"]["untracked"], true);
+    assert_eq!(json[1]["worktree"]["changes"]["staged"], false);
+    assert_eq!(json[1]["default_branch"]["ahead"], 0);
+    assert!(json[2].get("worktree").is_none());
+    assert_eq!(
+        json[2]["head"]["sha"], json[0]["head"]["sha"],
+        "branch-only row points at main's commit"
+    );
+}
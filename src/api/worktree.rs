@@ -0,0 +1,312 @@
+//! Creating and removing worktrees.
+
+use std::path::PathBuf;
+
+use crate::HookType;
+use crate::git::{
+    BranchDeletionMode, BranchDeletionOutcome, GitError, Repository, remove_worktree_with_cleanup,
+};
+use crate::lifecycle::{self, NewWorktree, Notice, Setup};
+use crate::path::paths_match;
+use crate::progress::Progress;
+use crate::remove_dir::remove_dir_with_progress;
+
+use super::{HookInvocation, Worktrunk, anchor_path};
+
+/// Options for [`Worktrunk::create_worktree`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CreateOptions {
+    /// Branch to check out in the new worktree.
+    pub branch: String,
+    /// Create `branch` rather than check out an existing one (`wt switch --create`).
+    pub create_branch: bool,
+    /// Commit-ish a created branch starts from (`--base`); `HEAD` when unset.
+    pub base: Option<String>,
+    /// Where to put the worktree; the `worktree-path` template when unset.
+    pub path: Option<PathBuf>,
+}
+
+impl CreateOptions {
+    pub fn new(branch: impl Into<String>) -> Self {
+        Self {
+            branch: branch.into(),
+            create_branch: false,
+            base: None,
+            path: None,
+        }
+    }
+
+    pub fn create_branch(mut self, create: bool) -> Self {
+        self.create_branch = create;
+        self
+    }
+
+    pub fn base(mut self, base: impl Into<String>) -> Self {
+        self.base = Some(base.into());
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+/// A worktree [`Worktrunk::create_worktree`] added.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CreatedWorktree {
+    pub path: PathBuf,
+    pub branch: String,
+    /// Setup steps that fell short (LFS hydration, submodules, the
+    /// container) and `post-start` hook failures.
+    pub warnings: Vec<String>,
+}
+
+/// Options for [`Worktrunk::remove_worktree`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RemoveOptions {
+    /// Branch whose worktree to remove.
+    pub branch: String,
+    /// What happens to the branch once its worktree is gone. The default
+    /// deletes it only when it is integrated into [`Self::target`].
+    pub deletion_mode: BranchDeletionMode,
+    /// Integration target for the safe-delete check; the default branch when
+    /// unset.
+    pub target: Option<String>,
+    /// Remove the worktree even with uncommitted changes (`wt remove --force`).
+    pub force: bool,
+}
+
+impl RemoveOptions {
+    pub fn new(branch: impl Into<String>) -> Self {
+        Self {
+            branch: branch.into(),
+            deletion_mode: BranchDeletionMode::default(),
+            target: None,
+            force: false,
+        }
+    }
+
+    pub fn deletion_mode(mut self, mode: BranchDeletionMode) -> Self {
+        self.deletion_mode = mode;
+        self
+    }
+
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+}
+
+/// A worktree [`Worktrunk::remove_worktree`] removed.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RemovedWorktree {
+    pub path: PathBuf,
+    /// The branch was deleted along with its worktree.
+    pub branch_deleted: bool,
+    /// A container that couldn't be removed, why a branch that should have
+    /// been deleted was kept, and `post-remove` hook failures.
+    pub warnings: Vec<String>,
+}
+
+impl Worktrunk {
+    /// Add a worktree for a branch — `wt switch [--create] <branch>` without
+    /// the switch.
+    ///
+    /// Sets the worktree up as `wt switch` does — LFS files hydrated from
+    /// the shared cache, submodules per `[switch] submodules`, and the
+    /// project's container once its `[container]` is approved — then runs
+    /// `pre-start` hooks in it, then `post-start` hooks. A failing
+    /// `pre-start` hook fails the call but leaves the worktree in place, as
+    /// `wt switch` does.
+    pub fn create_worktree(&self, options: &CreateOptions) -> anyhow::Result<CreatedWorktree> {
+        let repo = &self.repository()?;
+        let branch = options.branch.as_str();
+        let branch_handle = repo.branch(branch);
+        if options.create_branch && branch_handle.exists_locally()? {
+            return Err(GitError::BranchAlreadyExists {
+                branch: branch.to_string(),
+            }
+            .into());
+        }
+
+        let path = match &options.path {
+            Some(path) => path.clone(),
+            None => repo.expected_worktree_path(branch, repo.user_config())?,
+        };
+        if path.exists() {
+            return Err(GitError::WorktreePathExists {
+                branch: branch.to_string(),
+                path,
+                create: options.create_branch,
+            }
+            .into());
+        }
+
+        // The same setup `wt switch` gives a new worktree; a `[container]`
+        // only starts once the user has saved an approval of it.
+        let setup = Setup::new(repo, repo.user_config(), crate::container::approved(repo));
+        lifecycle::add_worktree(
+            repo,
+            &NewWorktree {
+                branch,
+                create_branch: options.create_branch,
+                base: options.base.as_deref(),
+                path: &path,
+            },
+            &setup,
+            None,
+        )?;
+        let mut warnings = Vec::new();
+        setup.run(repo, &path, Some(branch), &mut |notice| {
+            push_warning(&mut warnings, notice)
+        });
+
+        let mut invocation = HookInvocation::new(HookType::PreCreate, repo, &path, Some(branch));
+        invocation.base = options.base.as_deref();
+        self.run_hooks(invocation)?;
+        invocation.hook_type = HookType::PostCreate;
+        self.run_post_hooks(invocation, &mut warnings);
+
+        Ok(CreatedWorktree {
+            path,
+            branch: branch.to_string(),
+            warnings,
+        })
+    }
+
+    /// Remove a branch's worktree and, per [`RemoveOptions::deletion_mode`],
+    /// the branch — `wt remove <branch>`, in the foreground.
+    ///
+    /// Runs `pre-remove` hooks in the worktree first, then removes its
+    /// container and releases its `{{ ports.* }}`, as `wt remove` does;
+    /// `post-remove` hooks run in the primary worktree afterwards. Refuses the main worktree, locked
+    /// worktrees, and (without [`RemoveOptions::force`]) uncommitted changes.
+    ///
+    /// Removing the worktree this handle was opened in leaves the handle
+    /// pointing at a directory that no longer exists; open the repository
+    /// from the primary worktree to remove others.
+    pub fn remove_worktree(&self, options: &RemoveOptions) -> anyhow::Result<RemovedWorktree> {
+        let branch = options.branch.as_str();
+        let repo = self.repository()?;
+        let worktree = repo
+            .list_worktrees()?
+            .iter()
+            .find(|wt| wt.branch.as_deref() == Some(branch))
+            .cloned()
+            .ok_or_else(|| GitError::WorktreeNotFound {
+                branch: branch.to_string(),
+            })?;
+        if worktree.locked.is_some() {
+            return Err(GitError::WorktreeLocked {
+                branch: branch.to_string(),
+                path: worktree.path,
+                reason: worktree.locked,
+            }
+            .into());
+        }
+        if worktree.is_prunable() || !worktree.path.exists() {
+            return Err(GitError::WorktreeMissing {
+                branch: branch.to_string(),
+            }
+            .into());
+        }
+
+        let anchor = anchor_path(&repo)?;
+        if !repo.is_bare()? && paths_match(&worktree.path, &anchor) {
+            return Err(GitError::CannotRemoveMainWorktree.into());
+        }
+        // Git commands for the removal run from the primary worktree, which
+        // outlives the one being removed.
+        let repo = Repository::at(&anchor)?;
+
+        self.run_hooks(HookInvocation::new(
+            HookType::PreRemove,
+            &repo,
+            &worktree.path,
+            Some(branch),
+        ))?;
+
+        let mut warnings = Vec::new();
+        lifecycle::tear_down(
+            &repo,
+            &worktree.path,
+            Some(branch),
+            crate::container::approved(&repo).as_ref(),
+            &mut |notice| push_warning(&mut warnings, notice),
+        );
+
+        let snapshot = repo.capture_refs()?;
+        let output = remove_worktree_with_cleanup(
+            &repo,
+            &snapshot,
+            &worktree.path,
+            crate::git::RemoveOptions {
+                branch: Some(branch.to_string()),
+                deletion_mode: options.deletion_mode,
+                target_branch: options.target.clone().or_else(|| repo.default_branch()),
+                force_worktree: options.force,
+            },
+        )?;
+        if let Some(staged) = &output.staged_path {
+            remove_dir_with_progress(staged, &Progress::disabled());
+        }
+
+        let branch_deleted = match output.branch_result {
+            None => false,
+            Some(Ok(result)) => match result.outcome {
+                BranchDeletionOutcome::ForceDeleted | BranchDeletionOutcome::Integrated(_) => true,
+                BranchDeletionOutcome::NotDeleted => {
+                    warnings.push(format!(
+                        "Branch {branch} was kept: not integrated into {}",
+                        result.integration_target
+                    ));
+                    false
+                }
+                BranchDeletionOutcome::RetainedCheckedOut { path } => {
+                    warnings.push(format!(
+                        "Branch {branch} was kept: checked out at {}",
+                        path.display()
+                    ));
+                    false
+                }
+                BranchDeletionOutcome::RetainedRaced => {
+                    warnings.push(format!("Branch {branch} was kept: it moved during removal"));
+                    false
+                }
+            },
+            Some(Err(e)) => {
+                warnings.push(format!("Failed to delete branch {branch}: {e:#}"));
+                false
+            }
+        };
+
+        self.run_post_hooks(
+            HookInvocation::new(HookType::PostRemove, &repo, &anchor, Some(branch)),
+            &mut warnings,
+        );
+
+        Ok(RemovedWorktree {
+            path: worktree.path,
+            branch_deleted,
+            warnings,
+        })
+    }
+}
+
+/// Keep a lifecycle step's warning for the result, as plain text; its other
+/// notices are `wt`'s progress chatter.
+fn push_warning(warnings: &mut Vec<String>, notice: Notice) {
+    if let Notice::Warning(message) = notice {
+        warnings.push(anstream::adapter::strip_str(&message).to_string());
+    }
+}
@@ -2,6 +2,7 @@ use clap::{Args, Subcommand};
 
 use super::SwitchFormat;
 use crate::commands::Shell;
+use worktrunk::HookType;
 
 /// Shared global `--format` flag for the `wt config state` subcommands whose
 /// action subcommands inherit it (`cache`, `logs`, `hints`, `ci-status`,
@@ -51,6 +52,16 @@ eval "$(wt config shell init zsh)"
 Nushell [experimental] — save to vendor autoload directory:
 ```console
 $ wt config shell init nu | save -f ($nu.vendor-autoload-dirs | last | path join wt.nu)
+```
+
+Xonsh (~/.xonshrc):
+```console
+$ execx($(wt config shell init xonsh))
+```
+
+Elvish (~/.config/elvish/rc.elv):
+```console
+$ eval (wt config shell init elvish | slurp)
 ```"#
     )]
     Init {
@@ -235,7 +246,7 @@ This leaves any already-installed Worktrunk plugin unchanged."#
 pub enum ApprovalsCommand {
     /// List project commands and their approval status
     #[command(
-        after_long_help = r#"Shows every command the project config declares — hooks, aliases, and commit-message guidance — grouped into APPROVED and UNAPPROVED sections. Approvals recorded for commands no longer in the project config (edited or removed since approval) are listed separately.
+        after_long_help = r#"Shows every command the project config declares — hooks, aliases, and commit-message guidance — grouped into APPROVED and UNAPPROVED sections. Stale approvals are listed separately: those for commands no longer in the project config (edited or removed since approval), those that have expired, and those approved from a different version of the project config — edited since, or approved on another branch — with who approved them and on which machine.
 
 Reading is all it does: no prompt, no write. `--format=json` emits the same four distinctions as a structured payload — see [Reading approval state](/config/#reading-approval-state).
 
@@ -265,16 +276,27 @@ including previously approved ones.
 
 `--yes` writes the approvals without prompting, which is how a container or CI job pre-approves a project it has just cloned. It trusts every command the project config declares, including one whose template changed since an earlier approval. A caller that wants to look before granting them can list those first — see [Reading approval state](/config/#reading-approval-state).
 
+`--expires-in` makes the approvals lapse after the given duration, after which the commands prompt again.
+
 ## Examples
 
 ```console
 $ wt config approvals add --yes
+```
+
+Approve for a week:
+```console
+$ wt config approvals add --expires-in 7d
 ```"#
     )]
     Add {
         /// Show all commands
         #[arg(long)]
         all: bool,
+
+        /// Expire the approvals after this long (e.g., `12h`, `7d`)
+        #[arg(long, value_name = "DURATION")]
+        expires_in: Option<String>,
     },
 
     /// Clear approved commands from approvals.toml
@@ -282,8 +304,10 @@ $ wt config approvals add --yes
         after_long_help = r#"Removes saved approvals, requiring re-approval on next command run.
 
 By default, clears approvals for the current project. Use `--global` to clear
-all approvals across all projects, or `--stale` to clear only approvals for
-commands no longer in the project config (edited or removed since approval)."#
+all approvals across all projects, or `--stale` to clear only stale approvals:
+those for commands no longer in the project config (edited or removed since
+approval), expired ones, and ones approved from a different version of the
+project config."#
     )]
     Clear {
         /// Clear global approvals
@@ -469,7 +493,8 @@ $ wt config alias dry-run deploy -- --env=staging
 }
 
 // Ordering: user journey — shell (install integration), create (bootstrap
-// config files), show (inspect), update (migrate deprecations), approvals
+// config files), show (inspect), update (migrate deprecations), validate
+// (check for errors), schema (editor integration), approvals
 // (security policy for project commands), aliases (inspect/preview user &
 // project aliases), plugins (optional add-ons), state (advanced diagnostics).
 #[derive(Subcommand)]
@@ -490,7 +515,8 @@ pub enum ConfigCommand {
             "## Project config\n\n",
             "With `--project`, creates `.config/wt.toml` in the current repository:\n\n```\n",
             include_str!("../../dev/wt.example.toml"),
-            "```"
+            "```\n\n",
+            "Both files start with a `#:schema` directive, so editors with TOML schema support complete and validate keys. See `wt config schema`."
         )
     )]
     Create {
@@ -563,6 +589,84 @@ $ wt config update --print
         print: bool,
     },
 
+    /// Check config files for errors
+    #[command(
+        after_long_help = r#"Checks the user config, the system config if present, and the current repository's project config (`.config/wt.toml`) without running anything. Every finding is reported at once, located by line and column:
+
+- **Syntax** — the file isn't valid TOML
+- **Schema** — a value has the wrong type or isn't one of the allowed values
+- **Unknown keys** — typos, with the closest valid key suggested, and keys that belong in the other config file
+- **Templates** — each hook and alias template is parsed, and the variables it uses are checked against the ones that hook type (or alias) receives; `{{ target }}` in a `pre-start` hook is fine, in a `post-commit` hook it is never set
+- **Deprecations** — settings `wt config update` would migrate (warnings only)
+
+Exits 1 when there is any error, so it can gate CI:
+
+```console
+$ wt config validate
+```
+
+## JSON output
+
+`--format=json` prints one object:
+
+```json
+{
+  "valid": false,
+  "files": [
+    {
+      "kind": "project",
+      "path": "/path/to/repo/.config/wt.toml",
+      "diagnostics": [
+        {"severity": "error", "code": "unknown-variable", "message": "post-start:install references undefined variable `brnch`",
+         "key": "post-start.install", "line": 2, "column": 31, "suggestion": "branch"}
+      ]
+    }
+  ]
+}
+```
+
+`code` is one of `syntax`, `schema`, `unknown-key`, `misplaced-key`, `deprecated`, `template-syntax`, `unknown-variable`, `unavailable-variable`, or `template-render`. `key`, `line`, `column`, and `suggestion` are omitted when they don't apply."#
+    )]
+    Validate {
+        /// Output format
+        #[arg(long, default_value = "text", help_heading = "Output")]
+        format: SwitchFormat,
+    },
+
+    /// Print the JSON Schema for config files
+    #[command(
+        after_long_help = r#"Prints the JSON Schema for user config (the default) or, with `--project`, for project config (`.config/wt.toml`). Both cover every key — hooks in all their forms, aliases, `[list.custom-columns]` — and reject keys that belong in the other file, such as a user-only setting written into `.config/wt.toml`.
+
+The schemas are also published at `https://worktrunk.dev/schema/user-config.json` and `https://worktrunk.dev/schema/project-config.json`.
+
+## Editor integration
+
+Editors built on [taplo](https://taplo.tamasfe.dev/) — VS Code's Even Better TOML, Zed, Helix — complete and validate a TOML file that starts with a `#:schema` directive. `wt config create` writes one:
+
+```toml
+#:schema https://worktrunk.dev/schema/project-config.json
+```
+
+For an existing file, add that line at the top. To work offline, save the schema and point the directive at the file:
+
+```console
+$ wt config schema --project > .config/wt.schema.json
+```
+
+```toml
+#:schema ./wt.schema.json
+```"#
+    )]
+    Schema {
+        /// Print the user config schema (default)
+        #[arg(long, conflicts_with = "project")]
+        user: bool,
+
+        /// Print the project config schema
+        #[arg(long)]
+        project: bool,
+    },
+
     /// Manage command approvals
     #[command(
         after_long_help = r#"Project hooks and project aliases prompt for approval on first run to prevent untrusted projects from running arbitrary commands. Approvals from both flows are stored together.
@@ -608,6 +712,8 @@ $ wt config approvals list --format=json | jq -r .state
 
 Approved commands are saved to `~/.config/worktrunk/approvals.toml`. Re-approval is required when the command template changes or the project moves.
 
+Each approval is scoped to where the command runs — the hook type, or the alias — so approving `npm install` as a `post-start` hook doesn't approve it as an alias. It also records the hash of the project config it was approved from, who approved it on which machine, and, with `wt config approvals add --expires-in`, when it lapses. The hash doesn't revoke anything: an unchanged command stays approved when the rest of the config changes. `wt config approvals list` flags these approvals as stale, so after switching into a branch whose `.config/wt.toml` someone else edited — `wt switch pr:123` — the listing shows which approvals came from a different config, and `wt config approvals clear --stale` revokes them for a fresh review.
+
 `--yes` bypasses the prompt, and what it leaves behind depends on the command it is passed to. On a command that runs project commands it grants consent for that run alone and records nothing, so the next run asks again. On `wt config approvals add` the record is the whole point, so the approvals are written — which is how an unattended environment pre-approves a project it has just cloned.
 
 ## Reading approval state
@@ -618,16 +724,19 @@ Approved commands are saved to `~/.config/worktrunk/approvals.toml`. Re-approval
 {
   "state": "approval_required",
   "commands": [
-    {"phase": "post-start", "name": "dev", "template": "npm run dev", "approved": false},
-    {"phase": "pre-merge", "template": "cargo test", "approved": true}
+    {"phase": "post-start", "scope": "post-start", "name": "dev", "template": "npm run dev", "approved": false},
+    {"phase": "pre-merge", "scope": "pre-merge", "template": "cargo test", "approved": true,
+     "approved_at": 1767225600, "approver": "alice@example.com", "host": "alice-laptop"}
   ],
-  "stale": ["some removed command"]
+  "stale": ["some removed command"],
+  "expired": [],
+  "config_changed": ["cargo test"]
 }
 ```
 
-`state` is `no_commands` (the project declares none), `approval_required` (at least one is unapproved), or `approved`. `name` is absent for an unnamed command and for the commit-template fragment.
+`state` is `no_commands` (the project declares none), `approval_required` (at least one is unapproved), or `approved`. `name` is absent for an unnamed command and for the commit-template fragment. `scope` is what an approval covers: the hook type, `alias:<name>`, or `commit-template-append`. An approved command carries its record's `approved_at`, `expires_at` (Unix seconds), `approver`, and `host` when the approval recorded them.
 
-`stale` is separate rather than a fourth `state`, because it co-occurs with all three: these are approvals recorded earlier whose command has since been edited or removed from the project config. They are what `--yes` would silently re-approve, so an orchestrator preserving the approval model reads them before choosing that flag."#
+`stale` is separate rather than a fourth `state`, because it co-occurs with all three: these are approvals recorded earlier whose command has since been edited or removed from the project config. They are what `--yes` would silently re-approve, so an orchestrator preserving the approval model reads them before choosing that flag. `expired` lists templates whose approval has lapsed, and `config_changed` those still approved but approved from a different version of the project config."#
     )]
     Approvals {
         #[command(subcommand)]
@@ -690,9 +799,10 @@ $ wt config plugins opencode install
 
 ## Keys
 
-- **cache**: [Regenerable caches — CI status, summaries, git commands, hints, and the `wt switch -` target](/config/#wt-config-state-cache)
+- **cache**: [Regenerable caches — CI status, summaries, reviews, git commands, hints, and the `wt switch -` target](/config/#wt-config-state-cache)
 - **default-branch**: [The repository's default branch (`main`, `master`, etc.)](/config/#wt-config-state-default-branch)
 - **marker**: [Custom status marker for a branch (shown in `wt list`)](/config/#wt-config-state-marker)
+- **activity**: [Agent sessions per worktree, with their state history](/config/#wt-config-state-activity)
 - **vars**: [experimental] [Custom variables per branch](/config/#wt-config-state-vars)
 - **logs**: [Operation and debug logs](/config/#wt-config-state-logs)
 
@@ -737,6 +847,7 @@ $ wt config state clear
 <!-- subdoc: logs -->
 <!-- subdoc: ci-status -->
 <!-- subdoc: marker -->
+<!-- subdoc: activity -->
 <!-- subdoc: vars -->"#
     )]
     State {
@@ -749,8 +860,8 @@ $ wt config state clear
 // exploring, wiping, or refreshing state. Then authoritative state managed by
 // the user (default-branch override, marker, vars) and the operation logs.
 //
-// `cache` is the home for every regenerable cache (ci-status, summaries, git
-// commands, hints, previous branch). The per-category subcommands those caches
+// `cache` is the home for every regenerable cache (ci-status, summaries,
+// reviews, git commands, hints, previous branch). The per-category subcommands those caches
 // used to have (ci-status, hints, previous-branch) are `hide`-deprecated: they
 // still work and still appear in the match below, but print a deprecation
 // notice and are absent from `--help`.
@@ -762,13 +873,16 @@ pub enum StateCommand {
 - **Default branch**: Cached result of querying remote for default branch
 - **Previous branch**: Previous branch for `wt switch -`
 - **Branch markers**: User-defined branch notes
+- **Agent sessions**: Live agent sessions per worktree, from `wt config state activity`
 - **Vars**: Custom variables per branch
 - **CI status**: Cached GitHub/GitLab CI status per branch (30-60s TTL), plus the largest PR/MR number seen (sizes the `wt list` CI column)
 - **Summaries**: Cached LLM-generated branch summaries (shown in `wt list --full` and `wt switch` preview)
+- **Reviews**: Cached `wt step review` results, with their blocking-finding counts
 - **Git commands cache**: Cached merge-tree, ancestry, diff-stat, and `wt switch` preview results
 - **Hints**: One-time hints that have been shown
 - **Log files**: Operation and debug logs
 - **Trash**: Staged worktree directories awaiting background deletion
+- **Store**: Size of the `wt step copy-ignored --store` file store, and how much of it no worktree uses
 
 Every category that `wt config state clear` sweeps is shown here.
 
@@ -785,17 +899,19 @@ CI cache entries show status, age, and the commit SHA they were fetched for."#)]
 - Default branch cache
 - Previous branch
 - All branch markers
+- All agent sessions and their history
 - All variables
-- All caches (CI status, summaries, git commands)
+- All caches (CI status, summaries, reviews, git commands)
 - All hints
 - All log files
 - Stale trash from worktree removal (`.git/wt/trash/`)
+- The shared file store (`.git/wt/store/`) — worktrees keep their linked files
 
 Prompts for confirmation before clearing, since this removes hand-authored
 markers and vars. Pass `--yes` to skip the prompt.
 
-To drop only the regenerable caches (CI status, summaries, git commands,
-hints, previous branch), run `wt config state cache clear` — it needs no
+To drop only the regenerable caches (CI status, summaries, reviews, git
+commands, hints, previous branch), run `wt config state cache clear` — it needs no
 confirmation and leaves markers, vars, and the default-branch override
 untouched."#)]
     Clear,
@@ -808,6 +924,7 @@ untouched."#)]
 
 - **CI status** — GitHub/GitLab CI per branch (30–60s TTL), shown in [`wt list`](/list/#ci-status), plus the largest PR/MR number seen (sizes the CI column)
 - **Summaries** — LLM-generated branch summaries (`wt list --full`, `wt switch` preview)
+- **Reviews** — `wt step review` results, keyed by the reviewed diff
 - **Git commands** — cached merge-tree, ancestry, diff-stat, and `wt switch` preview results
 - **Hints** — one-time hints already shown in this repo
 - **Previous branch** — the `wt switch -` target, re-recorded on the next switch
@@ -836,6 +953,34 @@ $ wt config state cache clear
         format: GlobalFormatFlag,
     },
 
+    /// Shared file store for copy-ignored
+    #[command(
+        after_long_help = r#"The content-addressed store behind [`wt step copy-ignored --store`](/step/#shared-store). Each stored file is hardlinked into the worktrees that use it; a file no worktree links any more — its worktree was removed, or a build replaced it — is unreferenced, and `gc` removes it.
+
+Without a subcommand, runs `get`.
+
+## Examples
+
+Show the store's size:
+```console
+$ wt config state store
+```
+
+Remove unreferenced files:
+```console
+$ wt config state store gc
+```
+
+Reference counts come from hardlink counts, which only Unix reports; elsewhere `gc` keeps everything, and `wt config state clear` removes the whole store."#
+    )]
+    Store {
+        #[command(subcommand)]
+        action: Option<StoreAction>,
+
+        #[command(flatten)]
+        format: GlobalFormatFlag,
+    },
+
     /// Default branch detection and override
     #[command(
         name = "default-branch",
@@ -906,18 +1051,19 @@ Three kinds of logs live in `.git/wt/logs/`:
 
 ### Command log (`commands.jsonl`)
 
-All hook executions and LLM commands are recorded automatically — one JSON object per line. Rotates to `commands.jsonl.old` at 1MB (~2MB total). Fields:
+All hook executions and LLM commands are recorded automatically — one JSON object per line. Rotates to `commands.jsonl.old` at 1MB (~2MB total), or to gzipped generations when [`[logs] keep`](/config/#logs) is set. Fields:
 
 | Field | Description |
 |-------|-------------|
 | `ts` | ISO 8601 timestamp |
 | `wt` | The `wt` command that triggered this (e.g., `wt hook pre-merge --yes`) |
 | `label` | What ran (e.g., `pre-merge user:lint`, `commit.generation`) |
+| `branch` | Branch the command ran for (absent when not tied to a branch) |
 | `cmd` | Shell command executed |
 | `exit` | Exit code (`null` for background commands) |
 | `dur_ms` | Duration in milliseconds (`null` for background commands) |
 
-The command log appends entries and is not branch-specific — it records all activity across all worktrees.
+The command log appends entries and records activity across all worktrees. Query it with `wt config state logs history` — filter by branch, hook type, label, failures, or time range, or summarize durations with `--stats`.
 
 ### Hook output logs
 
@@ -1049,7 +1195,7 @@ wt list
 ## Use cases
 
 - **Work status** — `🚧` WIP, `✅` ready for review, `🔥` urgent
-- **Agent tracking** — The [Claude Code](/claude-code/) plugin sets markers automatically
+- **Agent tracking** — The [Claude Code](/claude-code/) plugin sets markers automatically (and records structured [activity](/config/#wt-config-state-activity))
 - **Notes** — Any short text: `"blocked"`, `"needs tests"`
 
 ## Storage
@@ -1070,6 +1216,57 @@ Without a subcommand, runs `get` for the current branch. For `--branch`, use `ge
         format: GlobalFormatFlag,
     },
 
+    /// Agent sessions per worktree
+    #[command(
+        after_long_help = r#"Structured records of the agent sessions running in each worktree: which agent CLI, its session ID, what it is doing, and since when. The [agent plugins](/claude-code/) write them from their session hooks; `wt list` shows them in the Agent column and the JSON `agents` field.
+
+## States
+
+| State | Symbol | Meaning |
+|-------|--------|---------|
+| `working` | 🤖 | Running a turn |
+| `waiting` | 💬 | Blocked on the user mid-turn — a permission prompt or a question |
+| `idle` | 💤 | Turn finished; ready for the next prompt |
+
+The Agent column shows the tool, the state's symbol, and how long the session has been in that state (`claude 💬 14m`). A worktree with several sessions shows the one most recently active, plus a count of the others.
+
+## Reporting
+
+A plugin reports every state change from inside the worktree, and ends the session when the agent exits:
+
+```console
+$ wt config state activity set working --tool claude --stdin
+$ wt config state activity end --tool claude --stdin
+```
+
+`--stdin` reads the session ID from the hook's JSON payload (`session_id`), which Claude Code, Codex, and Gemini CLI all send; `--session ID` passes it directly. Without either, the tool has one session per worktree. Reporting never fails the hook on a write error: a missed report is corrected by the next one.
+
+The Claude Code [statusline](/claude-code/#statusline-claude-code-only) refreshes the session's last-activity time as it renders, so a session the agent was killed in shows how long it has been silent.
+
+## Queries
+
+Agents that have been waiting on you for more than ten minutes:
+```console
+$ wt config state activity list --state waiting,idle --older-than 10m
+```
+
+`--older-than` measures time in the current state. The history of state changes, one row per change with the time spent in the previous state:
+```console
+$ wt config state activity history --branch feature
+```
+
+## Storage
+
+Each live session is a JSON file in `.git/wt/activity/sessions/`, removed when the session ends; `.git/wt/activity/history.jsonl` appends one JSON object per state change and session end, rotating to `history.jsonl.old` at 1MB. A session whose agent was killed before its end hook ran stays until `wt config state activity end` or `clear` removes it."#
+    )]
+    Activity {
+        #[command(subcommand)]
+        action: Option<ActivityAction>,
+
+        #[command(flatten)]
+        format: GlobalFormatFlag,
+    },
+
     /// \[experimental\] Custom variables per branch
     #[command(
         name = "vars",
@@ -1200,6 +1397,16 @@ pub enum CacheAction {
     Clear,
 }
 
+// Ordering: CRUD — get, then gc (a partial clear).
+#[derive(Subcommand)]
+pub enum StoreAction {
+    /// Show store size
+    Get,
+
+    /// Remove files no worktree references
+    Gc,
+}
+
 // Ordering: CRUD — get, clear.
 #[derive(Subcommand)]
 pub enum CiStatusAction {
@@ -1328,6 +1535,106 @@ $ wt config state marker clear --all
     },
 }
 
+/// Which session an `activity set`/`end` report is about.
+#[derive(Args)]
+pub struct ActivitySessionArgs {
+    /// Agent CLI reporting (`claude`, `codex`, `opencode`, `gemini`, …)
+    #[arg(long, value_name = "NAME")]
+    pub tool: String,
+
+    /// The agent's session ID
+    #[arg(long, value_name = "ID", conflicts_with = "stdin")]
+    pub session: Option<String>,
+
+    /// Read the session ID from a hook's JSON payload on stdin (`session_id`)
+    #[arg(long)]
+    pub stdin: bool,
+}
+
+// Ordering: reads before writes — list, history, set, end, clear.
+#[derive(Subcommand)]
+pub enum ActivityAction {
+    /// List live agent sessions
+    #[command(after_long_help = r#"## Examples
+
+List every session:
+```console
+$ wt config state activity
+```
+
+Sessions waiting on you for more than ten minutes:
+```console
+$ wt config state activity list --state waiting,idle --older-than 10m
+```"#)]
+    List {
+        /// Only sessions in these states
+        #[arg(long, value_delimiter = ',', value_name = "STATE")]
+        state: Vec<worktrunk::activity::AgentState>,
+
+        /// Only sessions in their current state for at least this long (`10m`, `2h`)
+        #[arg(long, value_name = "DURATION")]
+        older_than: Option<String>,
+
+        /// Only sessions on this branch
+        #[arg(long, add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
+        branch: Option<String>,
+    },
+
+    /// Show state changes
+    #[command(
+        after_long_help = r#"One row per state change or session end, oldest first, with the time the session spent in the state it left.
+
+## Examples
+
+Recent activity on a branch:
+```console
+$ wt config state activity history --branch feature -n 20
+```"#
+    )]
+    History {
+        /// Only events on this branch
+        #[arg(long, add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
+        branch: Option<String>,
+
+        /// Show only the last N events
+        #[arg(short = 'n', long, value_name = "N")]
+        limit: Option<usize>,
+    },
+
+    /// Report a session's state
+    #[command(
+        after_long_help = r#"Records the state of the session running in the current worktree, creating the session on its first report.
+
+## Examples
+
+From a Claude Code hook:
+```console
+$ wt config state activity set working --tool claude --stdin
+```
+
+With an explicit session ID:
+```console
+$ wt config state activity set idle --tool opencode --session ses_123
+```"#
+    )]
+    Set {
+        /// The session's new state
+        state: worktrunk::activity::AgentState,
+
+        #[command(flatten)]
+        session: ActivitySessionArgs,
+    },
+
+    /// End a session
+    End {
+        #[command(flatten)]
+        session: ActivitySessionArgs,
+    },
+
+    /// Clear all sessions and history
+    Clear,
+}
+
 // Ordering: CRUD — get, clear.
 #[derive(Subcommand)]
 pub enum LogsAction {
@@ -1394,6 +1701,117 @@ $ wt config state logs profile --format=json | jq '.by_type[0]'
         file: Option<std::path::PathBuf>,
     },
 
+    /// Export a trace as OpenTelemetry spans
+    #[command(
+        after_long_help = r#"Convert the records a `-vv` run captured to `trace.jsonl` into OpenTelemetry spans (OTLP/JSON), for a tracing backend such as Jaeger, Tempo or Honeycomb.
+
+The run becomes one trace: a root `wt <command>` span, a client span per subprocess (every git command, forge CLI call, hook step and LLM call) and an internal span per in-process region. Resource attributes carry the repo (`worktrunk.repo`), branch (`worktrunk.branch`), subcommand (`worktrunk.command`) and full command line (`process.command_line`); each subprocess span carries its command line, its `worktrunk.category` (`git`, `network`, `hook`, `llm`, `command` or `process`) and the worktree it ran against (`worktrunk.context`). Failed commands get an error status.
+
+Reads `.git/wt/logs/trace.jsonl` by default, or a trace given as an argument (`-` for stdin). Writes the JSON to stdout, or to `--output`. `--endpoint` instead POSTs it to an OTLP/HTTP collector (appending `/v1/traces` unless the URL already ends with it), using `curl`.
+
+Exporting the same capture twice produces the same trace and span IDs.
+
+## Examples
+
+Push the last `-vv` run to a local collector:
+```console
+$ wt -vv list
+$ wt config state logs export --endpoint http://localhost:4318
+```
+
+Write OTLP/JSON to a file:
+```console
+$ wt config state logs export --output list-trace.json
+```
+
+Export a trace from elsewhere (e.g. a CI artifact):
+```console
+$ wt config state logs export ci-run.jsonl --endpoint http://localhost:4318
+```"#
+    )]
+    Export {
+        /// Trace to read (defaults to `.git/wt/logs/trace.jsonl`; `-` for stdin)
+        file: Option<std::path::PathBuf>,
+
+        /// Write OTLP/JSON to this file instead of stdout
+        #[arg(long, value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+
+        /// POST to an OTLP/HTTP collector (e.g. `http://localhost:4318`)
+        #[arg(long, value_name = "URL")]
+        endpoint: Option<String>,
+    },
+
+    /// Query the command history
+    #[command(
+        after_long_help = r#"Read back `commands.jsonl` — the always-on record of every hook command and LLM call, with its exit code and duration — including rotated generations.
+
+Filters combine: `--branch` (the branch a hook ran for), `--hook` (hook type), `--label` (substring of the label, such as `user:lint` or `commit.generation`), `--failed` (non-zero exit) and `--since`/`--until`. Times are a duration ago (`2h`, `7d`), a date (`2026-03-01`, local midnight), or an RFC 3339 timestamp.
+
+`--stats` aggregates instead of listing: runs, failures and p50/p95/max duration per command label. `--follow` prints new records as they're written, until interrupted. `--format=json` emits the records (or stats) as JSON; with `--follow`, one object per line.
+
+The log rotates at 1MB. By default one previous file (`commands.jsonl.old`) is kept; set `keep` under `[logs]` in user config to keep that many gzipped generations, which history reads too.
+
+## Examples
+
+Recent failures:
+```console
+$ wt config state logs history --failed --since 1d
+```
+
+Which pre-merge hooks are slow:
+```console
+$ wt config state logs history --hook pre-merge --stats
+```
+
+Everything that ran for one branch:
+```console
+$ wt config state logs history --branch feature
+```
+
+Watch hooks as they run:
+```console
+$ wt config state logs history --follow
+```"#
+    )]
+    History {
+        /// Only commands that ran for this branch
+        #[arg(long, add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
+        branch: Option<String>,
+
+        /// Only this hook type
+        #[arg(long, value_name = "TYPE")]
+        hook: Option<HookType>,
+
+        /// Only labels containing this text
+        #[arg(long, value_name = "TEXT")]
+        label: Option<String>,
+
+        /// Only commands that exited non-zero
+        #[arg(long)]
+        failed: bool,
+
+        /// Only records at or after this time (`2h`, `2026-03-01`, …)
+        #[arg(long, value_name = "WHEN")]
+        since: Option<String>,
+
+        /// Only records before this time
+        #[arg(long, value_name = "WHEN")]
+        until: Option<String>,
+
+        /// Show only the last N records
+        #[arg(short = 'n', long, value_name = "N")]
+        limit: Option<usize>,
+
+        /// Aggregate durations per command label
+        #[arg(long, conflicts_with_all = ["follow", "limit"])]
+        stats: bool,
+
+        /// Keep printing records as they're written
+        #[arg(short, long)]
+        follow: bool,
+    },
+
     /// Clear all log files
     Clear,
 }
@@ -1461,6 +1879,15 @@ impl StateWrite for CacheAction {
     }
 }
 
+impl StateWrite for StoreAction {
+    fn write_verb(&self) -> Option<&'static str> {
+        match self {
+            Self::Get => None,
+            Self::Gc => Some("gc"),
+        }
+    }
+}
+
 impl StateWrite for CiStatusAction {
     fn write_verb(&self) -> Option<&'static str> {
         match self {
@@ -1480,10 +1907,21 @@ impl StateWrite for MarkerAction {
     }
 }
 
+impl StateWrite for ActivityAction {
+    fn write_verb(&self) -> Option<&'static str> {
+        match self {
+            Self::List { .. } | Self::History { .. } => None,
+            Self::Set { .. } => Some("set"),
+            Self::End { .. } => Some("end"),
+            Self::Clear => Some("clear"),
+        }
+    }
+}
+
 impl StateWrite for LogsAction {
     fn write_verb(&self) -> Option<&'static str> {
         match self {
-            Self::Get | Self::Profile { .. } => None,
+            Self::Get | Self::Profile { .. } | Self::Export { .. } | Self::History { .. } => None,
             Self::Clear => Some("clear"),
         }
     }
@@ -4,11 +4,11 @@ mod list;
 mod step;
 
 pub(crate) use config::{
-    ApprovalsCommand, CacheAction, CiStatusAction, ConfigAliasCommand, ConfigCommand,
-    ConfigPluginsClaudeCommand, ConfigPluginsCodexCommand, ConfigPluginsCommand,
-    ConfigPluginsOpencodeCommand, ConfigShellCommand, DefaultBranchAction, GlobalFormatFlag,
-    HintsAction, LogsAction, MarkerAction, PreviousBranchAction, StateCommand, StateWrite,
-    VarsAction,
+    ActivityAction, ActivitySessionArgs, ApprovalsCommand, CacheAction, CiStatusAction,
+    ConfigAliasCommand, ConfigCommand, ConfigPluginsClaudeCommand, ConfigPluginsCodexCommand,
+    ConfigPluginsCommand, ConfigPluginsOpencodeCommand, ConfigShellCommand, DefaultBranchAction,
+    GlobalFormatFlag, HintsAction, LogsAction, MarkerAction, PreviousBranchAction, StateCommand,
+    StateWrite, StoreAction, VarsAction,
 };
 pub(crate) use hook::{HOOK_TYPE_NAMES, HookCommand, HookOptions, parse_hook_type};
 pub(crate) use list::ListSubcommand;
@@ -211,13 +211,27 @@ pub(crate) enum SwitchFormat {
     Json,
 }
 
-/// Output format for `wt list` and `wt config state get` (table or JSON).
+/// Output format for `wt config state get` (table or JSON).
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 pub(crate) enum OutputFormat {
     Table,
     Json,
 }
 
+/// Output format for `wt list`: the terminal table, JSON, or a document
+/// rendering of the table's columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ListFormat {
+    Table,
+    Json,
+    #[value(alias = "json-stream")]
+    Ndjson,
+    Markdown,
+    Csv,
+    Tsv,
+    Html,
+}
+
 /// Output format for `wt list statusline`, including the Claude Code mode.
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 pub(crate) enum StatuslineFormat {
@@ -417,6 +431,14 @@ pub(crate) struct SwitchArgs {
     #[arg(long, requires = "branch")]
     pub(crate) clobber: bool,
 
+    /// Switch on a `[remotes]` host over SSH
+    ///
+    /// Runs the host's `wt switch` in its clone of the repository, so the
+    /// worktree, its hooks and any `--execute` command live on the host.
+    /// The local shell doesn't change directory.
+    #[arg(long, value_name = "name", requires = "branch")]
+    pub(crate) on: Option<String>,
+
     /// Skip directory change after switching
     ///
     /// Hooks still run normally. Useful when hooks handle navigation
@@ -445,8 +467,16 @@ pub(crate) struct ListArgs {
     pub(crate) subcommand: Option<ListSubcommand>,
 
     /// Output format
+    ///
+    /// Besides the table and JSON: `markdown` (a GitHub-flavored table),
+    /// `csv`, `tsv` and `html` (a self-contained page) render the same
+    /// columns as the table, without width limits or styling.
+    ///
+    /// `ndjson` (alias `json-stream`) streams schema-2 JSON: a header, one
+    /// record per row as soon as it's known, then patches as slow fields
+    /// resolve.
     #[arg(long, value_enum, default_value = "table")]
-    pub(crate) format: OutputFormat,
+    pub(crate) format: ListFormat,
 
     /// Include branches without worktrees
     #[arg(long)]
@@ -473,6 +503,18 @@ pub(crate) struct ListArgs {
     pub(crate) no_progressive: bool,
 }
 
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+pub(crate) struct ServeArgs {
+    /// Serve on stdin/stdout
+    #[arg(long)]
+    pub(crate) stdio: bool,
+
+    /// Serve on a Unix socket at this path
+    #[arg(long, value_name = "PATH")]
+    pub(crate) socket: Option<std::path::PathBuf>,
+}
+
 #[derive(Args)]
 pub(crate) struct RemoveArgs {
     /// Branch name or worktree path [default: current]
@@ -519,6 +561,14 @@ pub(crate) struct RemoveArgs {
     #[arg(short, long)]
     pub(crate) force: bool,
 
+    /// Remove on a `[remotes]` host over SSH
+    ///
+    /// Runs the host's `wt remove` in its clone of the repository, in the
+    /// foreground. Without a branch, removes the current branch's worktree
+    /// there.
+    #[arg(long, value_name = "name")]
+    pub(crate) on: Option<String>,
+
     /// Output format
     ///
     /// JSON prints structured result to stdout after removal completes.
@@ -594,6 +644,12 @@ pub(crate) struct MergeArgs {
     #[arg(long)]
     pub(crate) stage: Option<crate::commands::commit::StageMode>,
 
+    /// Merge on a `[remotes]` host over SSH
+    ///
+    /// Runs the host's `wt merge` in its worktree for the current branch.
+    #[arg(long, value_name = "name")]
+    pub(crate) on: Option<String>,
+
     /// Output format
     ///
     /// JSON prints structured result to stdout after merge completes.
@@ -685,11 +741,13 @@ The CI column shows each row's PR/MR CI and review status, the same as [`wt list
 | `Enter` | Switch to selected worktree |
 | `Alt-c` | Create new worktree named as entered text |
 | `Alt-x` | Remove selected worktree/branch (never forces) |
+| `Alt-m` | Merge selected worktree (`wt merge`) |
+| `Ctrl-Space` | Mark/unmark selected row for a bulk action |
 | `Alt-y` | Copy selected branch name to the clipboard |
 | `Alt-o` | Open the selected row's PR/MR URL in the browser |
 | `Alt-r` | Refresh the list (pick up worktrees created elsewhere) |
 | `Esc` | Cancel |
-| `Alt-1`–`Alt-8` | Jump to a preview tab |
+| `Alt-1`–`Alt-9` | Jump to a preview tab |
 | `Tab`/`Shift-Tab` | Cycle available preview tabs forward/backward |
 | `Alt-p` | Toggle preview panel |
 | `Ctrl-u`/`Ctrl-d` | Scroll preview up/down |
@@ -698,6 +756,22 @@ The CI column shows each row's PR/MR CI and review status, the same as [`wt list
 
 `Alt-x` is a no-op on the current worktree (the `@` row) — removing the worktree in use would have to switch elsewhere first, so switch away and remove it from there.
 
+While any rows are marked, `Alt-x`, `Alt-m`, and custom actions apply to every marked row instead of the selected one. Marked removals run in parallel; merges and custom actions run one row at a time. The header counts through the rows and ends on a summary, and rows that couldn't be removed stay in the list with their reason printed on exit.
+
+The `Alt` keys above can be rebound, and extra keys bound to custom actions, under `[switch.picker.keys]` in user config. A custom action is a command template run against the selected row (or each marked row) — in its worktree, or the repository root for a branch without one — with the same variables as hooks. The row then reflects what the command did: a removed worktree turns into its branch row, and a deleted branch's row leaves the list.
+
+```toml
+[switch.picker.keys]
+remove = "ctrl-x"        # also create, merge, mark, refresh, copy-branch, open-url
+preview-tabs = "ctrl"    # ctrl-1 … ctrl-9
+
+[switch.picker.keys.actions]
+alt-e = "code {{ worktree_path }}"
+alt-t = "tmux new-window -c {{ worktree_path }}"
+```
+
+Actions run in the background without a terminal; the header shows when one starts and finishes, and a failure's output prints when the picker exits.
+
 Each row filters by its branch, path, and — when it has a PR/MR — the PR/MR's number, title, and author, the same fields whether the PR is checked out (a worktree row) or listed via `--prs`. Plain digits go to the filter, so a number can be typed directly and the preview tabs move to `Alt`.
 
 Typing a gutter sigil filters by row kind: `+` narrows to linked worktrees and `@` to the current worktree. The other sigils don't filter cleanly — `^` and `|` are skim's prefix-anchor and OR query operators (so `^` matches every row and `|` none), and `/` matches most rows because every worktree path contains it.
@@ -710,10 +784,11 @@ Typing a gutter sigil filters by row kind: `+` narrows to linked worktrees and `
 4. **log** — Recent commits; commits already on the default branch have dimmed hashes
 5. **remote⇅** — Ahead/behind diff vs upstream tracking branch
 6. **summary** — LLM-generated branch summary; requires `[list] summary = true` and [`commit.generation`](/config/#commit)
-7. **pr** — The selected row's PR/MR, for any row whose branch has one
-8. **comments** — The PR/MR's comment thread, fetched from the forge for any row whose branch has one
+7. **review** — The cached [`wt step review`](/step/#wt-step-review) findings for the branch's current changes; the picker never runs a review itself
+8. **pr** — The selected row's PR/MR, for any row whose branch has one
+9. **comments** — The PR/MR's comment thread, fetched from the forge for any row whose branch has one
 
-The comparison base is the merge-base with the default branch, or with its upstream when the local default branch lags. The picker opens on **diff** for local rows and **pr** for a PR/MR listed by `--prs` but not available locally. `Tab` and `Shift-Tab` skip tabs without content; `Alt-1` through `Alt-8` open any tab directly. After you choose a tab, that choice stays active while you navigate.
+The comparison base is the merge-base with the default branch, or with its upstream when the local default branch lags. The picker opens on **diff** for local rows and **pr** for a PR/MR listed by `--prs` but not available locally. `Tab` and `Shift-Tab` skip tabs without content; `Alt-1` through `Alt-9` open any tab directly. After you choose a tab, that choice stays active while you navigate.
 
 On narrow previews the tab bar compacts to digits — only the active tab keeps its label — so every `Alt-N` accelerator stays visible.
 
@@ -744,6 +819,34 @@ The `--prs` flag adds the repository's open PRs (GitHub) or MRs (GitLab) to the
 
 Requires `gh` (GitHub), `glab` (GitLab), or an equivalent CLI installed and authenticated; see [forge platform](/config/#forge-platform) for Gitea, Azure DevOps, and other supported platforms.
 
+## Remote hosts
+
+`--on <name>` runs the switch on a [`[remotes]`](/config/#remotes) host: the host's `wt` creates or switches to the worktree in its clone, runs its hooks there, and runs `--execute` there with the terminal attached. The local shell stays where it is.
+
+```console
+$ wt switch --on devbox --create feat              # Worktree on devbox
+$ wt switch --on devbox feat -x claude             # Agent on devbox, driven from here
+$ wt list                                          # devbox:… rows alongside local ones
+$ wt remove --on devbox feat
+```
+
+## Containers
+
+With a project [`[container]`](/config/#containers), the switch starts the destination worktree's container — creating it on first use — before `pre-start` hooks, which then run inside it, as does `--execute`.
+
+## Git LFS
+
+In a repository whose `.gitattributes` sets `filter=lfs`, `--create` checks LFS files out as pointers (`GIT_LFS_SKIP_SMUDGE=1`) rather than downloading each object again, then fills them in from the `.git/lfs/objects` cache every worktree shares — reflinked where the filesystem supports it, copied otherwise — before `pre-start` hooks run. Objects the cache doesn't have stay pointers, with a hint to run `git lfs pull`. Hydration needs the LFS filter configured (`git lfs install`); without it the files stay pointers.
+
+## Submodules
+
+A new worktree's submodules start uninitialized. With [`[switch] submodules`](/config/#switch) set, `--create` initializes them before `pre-start` hooks, so no hook needs to:
+
+- `"init"` — `git submodule update --init --recursive`, cloning each submodule from its URL.
+- `"reference"` — clone each from the main worktree's copy under `.git/modules/`, with `--reference` so objects are shared rather than copied; no network. `origin` is then set back to the submodule's real URL. Submodules the main worktree never initialized are skipped, with a hint to run `git submodule update --init`.
+
+`wt list` marks a worktree whose submodules drifted with `⊙`; `wt step copy-ignored` and `wt step diff` look inside submodules too.
+
 ## When wt switch fails
 
 - **Branch doesn't exist** — Use `--create`, or check `wt list --branches`
@@ -824,6 +927,12 @@ Output as JSON for scripting:
 $ wt list --format=json
 ```
 
+Paste a status report into a PR description:
+
+```console
+$ wt list --format=markdown
+```
+
 ## Columns
 
 | Column | Shows |
@@ -839,6 +948,9 @@ $ wt list --format=json
 | Path | Worktree directory |
 | URL | Dev server URL from project config; dimmed if port is not listening |
 | *(custom)* | User-defined [custom columns](#custom-columns) from `[list.custom-columns]` user config [experimental] |
+| Agent | Latest [agent session](/claude-code/#agent-sessions) as tool, state, time in state (`claude 💬 14m`) |
+| Container | State of the worktree's [container](/config/#containers), `running` or `stopped` |
+| LFS | [LFS](/switch/#git-lfs) files holding their content, of those tracked (`3/5`: two are still pointers); LFS repositories only |
 | Commit | Short hash, abbreviated per `core.abbrev` |
 | Age | Time since last commit |
 | Message | Last commit message (truncated) |
@@ -914,7 +1026,7 @@ Independent flags from `git status`; several can show at once (e.g. `+!?`). Each
 
 ### Worktree
 
-An in-progress git operation, a worktree-location attribute, or a branch with no worktree. One symbol shows, highest priority first (`✘ > ↻ > ⊟ > ⊞ > ⚑ > /`):
+An in-progress git operation, a worktree-location attribute, or a branch with no worktree. One symbol shows, highest priority first (`✘ > ↻ > ⊟ > ⊞ > ⚑ > / > ⊙`):
 
 | Symbol | JSON | Meaning |
 |--------|------|---------|
@@ -925,6 +1037,7 @@ An in-progress git operation, a worktree-location attribute, or a branch with no
 | `⚑` | `worktree.state` `"duplicate_branch"` | Branch checked out in more than one worktree, so `wt` resolves it to whichever git lists first; every worktree on the branch is flagged |
 | `⚑` | `worktree.state` `"branch_worktree_mismatch"` | Worktree isn't at the path its branch implies — including a detached one, which has no branch to imply a path and so is never at home |
 | `/` | `kind` `"branch"` | Branch without a worktree (no `worktree` object) |
+| `⊙` | `submodules` `{out_of_date, dirty}` | A submodule's checkout differs from the commit the superproject records, or has changes of its own |
 
 ### Default branch
 
@@ -963,6 +1076,19 @@ These appear across all columns while the table is loading:
 |--------|---------|
 | `·` | Data is loading, or collection timed out / branch too stale |
 
+## Document output
+
+`--format=markdown`, `csv`, `tsv`, and `html` render the table's columns — the same `[list] columns` selection and [custom columns](#custom-columns), the same `--full` gate — as a document on stdout, once collection finishes:
+
+| Format | Output |
+|--------|--------|
+| `markdown` | GitHub-flavored Markdown table; diff columns right-aligned |
+| `csv` | Comma-separated values with a header row, quoted per RFC 4180 |
+| `tsv` | Tab-separated values with a header row |
+| `html` | A self-contained HTML page holding one table |
+
+Cells carry the table's text without styling, padding, or truncation: messages and summaries are whole, and the CI cell names its status (`#412 passed`). A cell follows the table's absence rule — empty when nothing applies, `·` when the value was requested but couldn't be determined (a timed-out task), matching `null` in JSON schema 2. The summary line is omitted.
+
 ---
 
 ## JSON output
@@ -1026,7 +1152,7 @@ Item fields:
 | `branch` | Branch name; null for a detached-HEAD worktree. Remote rows carry the bare name with the remote in `remote` |
 | `remote` | Remote name, present only on remote-only branch rows |
 | `head` | `{sha, short_sha, subject, committed_at}`; null for unborn branches. `committed_at` is RFC 3339 UTC |
-| `worktree` | `{path, main, current, previous, detached, locked, prunable, branch_mismatch, duplicate_branch, operation, changes}`; absent on branch-only rows. `locked`/`prunable` are `{reason}` objects and can co-occur; `operation` is `"rebase"` or `"merge"`; `changes` holds the five working-tree flags plus `conflicted` and `diff {added, deleted}` |
+| `worktree` | `{path, host, main, current, previous, detached, locked, prunable, branch_mismatch, duplicate_branch, operation, changes, agents, container, lfs}`; absent on branch-only rows. `host` names the [`[remotes]`](/config/#remotes) host of a remote worktree (absent for local ones). `locked`/`prunable` are `{reason}` objects and can co-occur; `operation` is `"rebase"` or `"merge"`; `changes` holds the five working-tree flags plus `conflicted` and `diff {added, deleted}`, and `submodules {out_of_date, dirty}` when a submodule drifted; `agents` lists [agent sessions](/config/#wt-config-state-activity) as `{tool, session_id, state, started_at, state_since, last_activity_at}`, most recently active first, with RFC 3339 UTC times (absent when none); `container` is the [container](/config/#containers) state, `"running"` or `"stopped"` (absent without one); `lfs` is `{files, pointers}`, the [LFS](/switch/#git-lfs) files tracked and those still pointers (absent outside an LFS repository) |
 | `default_branch` | Relation to the default branch: `{ahead, behind, diff, orphan, integration, merge_conflicts}`; absent on the default branch itself. `integration.reason` is one of `same_commit`, `ancestor`, `no_added_changes`, `trees_match`, `merge_adds_nothing`, `patch_id_match`; a dirty tree skips the checks, leaving `integration` null |
 | `upstream` | Tracking branch: `{remote, branch, ahead, behind}`; absent when none is configured |
 | `pr` | Open PR/MR: `{number, url, review, mergeable, repo}`; collected with `--full`. `review` uses the schema 1 `ci.review_state` vocabulary; `mergeable` is false when the forge reports conflicts, null otherwise |
@@ -1061,6 +1187,24 @@ A JSON Schema for the envelope is published at
 It describes what `wt` writes, so a field the absence rule can omit is
 optional there rather than required-and-null.
 
+### Streaming (NDJSON)
+
+`--format=ndjson` (alias `json-stream`) emits schema-2 data as it is collected, one JSON object per line, so a dashboard can show local state at once and fill in CI, summaries and upstream as they arrive. Each line's `type` says what it carries:
+
+| `type` | Fields | Meaning |
+|--------|--------|---------|
+| `header` | `schema`, `repo`, `collected` | The envelope without `items`; always first |
+| `item` | `index`, `item` | One row, as soon as its branch and commit are known; fields still being computed are `null` |
+| `patch` | `index`, `set`, `unset` | Row `index` changed: replace each top-level field in `set`, drop each field named in `unset` |
+| `end` | — | Collection finished; the patched items now equal `--format=json`'s |
+
+`index` follows table order. The stream is always schema 2, whatever `[list] json-schema` says.
+
+```console
+# Print each branch's CI status as it resolves
+$ wt list --format=ndjson --full | jq -c 'select(.type == "patch" and .set.checks) | {index, checks: .set.checks}'
+```
+
 ### Schema 1
 
 The original bare-array format, and the default while unset:
@@ -1097,6 +1241,7 @@ $ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 |-------|------|-------------|
 | `branch` | string/null | Branch name (null for detached HEAD) |
 | `path` | string | Worktree path (absent for branches without worktrees) |
+| `host` | string | [`[remotes]`](/config/#remotes) host the worktree lives on (absent for local worktrees) |
 | `kind` | string | `"worktree"` or `"branch"` |
 | `commit` | object | Commit info (see below) |
 | `working_tree` | object | Working tree state (see below) |
@@ -1119,6 +1264,10 @@ $ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `symbols` | string | Raw status symbols without colors (e.g., `"!?↓"`) |
 | `vars` | object | Per-branch variables from [`wt config state vars`](/config/#wt-config-state-vars) (absent when empty) |
 | `columns` | object | Rendered [custom column](#custom-columns) values keyed by header; empty cells omitted (absent when none configured) |
+| `agents` | array | [Agent sessions](/config/#wt-config-state-activity) in the worktree, most recently active first: `{tool, session_id, worktree, branch, state, started_at, state_since, last_activity_at}` with Unix-second times (absent when none) |
+| `container` | string | [Container](/config/#containers) state, `"running"` or `"stopped"` (absent without one) |
+| `lfs` | object | `{files, pointers}`: [LFS](/switch/#git-lfs) files tracked, and those still pointers (absent outside an LFS repository) |
+| `submodules` | object | `{out_of_date, dirty}`: a submodule's checkout differs from the recorded commit, or has changes (absent unless one drifted) |
 
 ### Commit object
 
@@ -1332,6 +1481,8 @@ To avoid killing work the user did not mean to kill, two guards keep `--reap` co
 
 Reaping runs before the worktree directory is touched, so it is independent of foreground/background removal and the `--force` flag. Unix only; on Windows `--reap` is rejected.
 
+A worktree's [container](/config/#containers) is removed with it (`rm -f`, which also stops it), after `pre-remove` hooks — no `--reap` needed.
+
 ## JSON output
 
 `--format=json` prints one object per removal to stdout: `{kind, branch, path, branch_outcome, branch_checked_out_at}` for a worktree, with `pruned` in place of `path` for a branch-only removal.
@@ -1519,6 +1670,8 @@ $ wt step push
 - [`promote`](#wt-step-promote) — [experimental] Swap a branch into the main worktree
 - [`prune`](#wt-step-prune) — Remove worktrees and branches merged into the default branch
 - [`relocate`](#wt-step-relocate) — [experimental] Move worktrees to expected paths
+- [`resolve`](#wt-step-resolve) — [experimental] Resolve rebase conflicts with the configured LLM
+- [`review`](#wt-step-review) — [experimental] Review a branch's changes with the configured LLM
 - [`tether`](#wt-step-tether) — [experimental] Run a command; kill its whole process tree when its worktree is removed
 - [`<alias>`](/extending/#aliases) — Run a configured command alias
 
@@ -1538,6 +1691,8 @@ $ wt step push
 <!-- subdoc: promote -->
 <!-- subdoc: prune -->
 <!-- subdoc: relocate -->
+<!-- subdoc: resolve -->
+<!-- subdoc: review -->
 <!-- subdoc: tether -->"#
     )]
     Step {
@@ -1650,6 +1805,7 @@ Most hooks don't need `[[hook]]` blocks. Reach for them when there's a dependenc
 | Location | `.config/wt.toml` | `~/.config/worktrunk/config.toml` |
 | Scope | Single repository | All repositories (or [per-project](/config/#user-project-specific-settings)) |
 | Approval | Required | Not required |
+| Sandbox | Optional, via [`[hooks] sandbox`](/config/#sandboxing-project-hooks) | Never |
 | Execution order | After user hooks | First |
 
 Skip all hooks with `--no-hooks`. To run a specific hook when user and project both define the same name, use `user:name` or `project:name` syntax.
@@ -1685,6 +1841,7 @@ Hooks can use template variables that expand at runtime:
 |           | `{{ hook_name }}`             | Hook command name (if named) |
 |           | `{{ args }}`                  | Tokens forwarded from the CLI — see [Running Hooks Manually](#running-hooks-manually) |
 | user      | `{{ vars.<key> }}`            | Per-branch variables from [`wt config state vars`](/config/#wt-config-state-vars) |
+|           | `{{ ports.<service> }}`       | Per-branch port for a named service, from the [port registry](#port-registry) |
 
 The `repo` variables (`repo`, `repo_path`, `owner`, `remote_repo`, `primary_worktree_path`, `default_branch`, `remote`, `remote_url`) are constant across the whole repository — `default_branch` is the same in every worktree. The `active` variables (`branch`, `worktree_path`, `worktree_name`, `commit`, `short_commit`, `upstream`) vary per worktree.
 
@@ -1778,6 +1935,21 @@ Hash any string, including concatenations:
 dev = "npm run dev --port {{ (repo ~ '-' ~ branch) | hash_port }}"
 ```
 
+### Port registry
+
+`hash_port` can give two branches the same port, and doesn't check that the port is free. `{{ ports.<service> }}` instead assigns the branch a port for each named service the first time a template asks, records it in `.git/wt/ports.json`, and releases it when the worktree is removed:
+
+```toml
+[post-start]
+web = "npm run dev -- --port {{ ports.web }}"
+db = "docker run -d -p {{ ports.db }}:5432 postgres"
+
+[list]
+url = "http://localhost:{{ ports.web }}"
+```
+
+A new assignment starts from `hash_port` of `<branch>-<service>` and moves up past ports another branch holds or that something is listening on. Once assigned, the port stays the same in every hook, the `wt list` URL column, and [`wt step eval`](/step/#wt-step-eval).
+
 Variables are shell-escaped automatically — quotes around `{{ ... }}` are unnecessary and can cause issues with special characters.
 
 ## Worktrunk functions
@@ -2088,8 +2260,9 @@ columns = ["branch", "status", "ci", "path"]   # Columns to show, in order — b
 timeout-ms = 0     # Wall-clock budget for the entire collect phase; 0 disables
 ```
 
-`columns` selects and orders the columns the `wt list` table and the `wt switch`
-picker render; `--format json` ignores it and always emits every field. Omit it
+`columns` selects and orders the columns the `wt list` table (and its
+[document formats](/list/#document-output)) and the `wt switch` picker render;
+`--format json` ignores it and always emits every field. Omit it
 for the default set. It is meant to drive a per-invocation
 [alias](/extending/#aliases) (`wt --config-set 'list.columns=[…]' list`),
 giving a named view without disturbing the default `wt list`. A static setting
@@ -2108,6 +2281,7 @@ Valid built-in names:
 - `ci` — CI status of the head commit
 - `path` — The worktree's path
 - `url` — Dev-server URL from the `[list] url` template
+- `agent` — The worktree's most recently active agent session
 - `commit` — The head commit's short hash
 - `age` — Time since the last commit
 - `message` — The head commit's subject
@@ -2202,9 +2376,17 @@ delete-branch = true   # Delete branch after removal (--no-delete-branch to keep
 ```toml
 [switch]
 cd = true          # Change directory after switching (--no-cd to skip)
+submodules = "reference"   # Initialize submodules in new worktrees: "init" or "reference" (unset: leave them)
 
 [switch.picker]
 pager = "delta --paging=never"   # Example: override git's core.pager for diff preview
+
+[switch.picker.keys]
+remove = "alt-x"         # Also create (alt-c), merge (alt-m), mark (ctrl-space), refresh (alt-r), copy-branch (alt-y), open-url (alt-o)
+preview-tabs = "alt"     # Modifier for the numbered preview-tab keys
+
+[switch.picker.keys.actions]   # Key → command template, run against the selected row
+alt-e = "code {{ worktree_path }}"
 ```
 
 ### Step
@@ -2216,6 +2398,59 @@ exclude = []   # Additional excludes (e.g., [".cache/", ".turbo/"])
 
 Built-in excludes (VCS metadata and tool-state directories) always apply; [the `wt step copy-ignored` docs](/step/#wt-step-copy-ignored) list them. User config and project config exclusions are combined.
 
+### Logs
+
+```toml
+[logs]
+keep = 5   # Gzipped generations of commands.jsonl to keep on rotation
+```
+
+`commands.jsonl` rotates at 1MB. By default the previous file is kept once, as `commands.jsonl.old`; with `keep` set, rotated files are gzipped as `commands.jsonl.1.gz` (newest) through `commands.jsonl.<keep>.gz`, and `keep = 0` discards them. `wt config state logs history` reads every generation. Global only — not read from `[projects]` entries.
+
+### Notify
+
+Notifications for work that changes out of sight. An event notifies only when its table is present; `sinks` defaults to `["osc9"]`.
+
+```toml
+[notify]
+webhook = "http://localhost:8080/hooks/wt"   # For the `webhook` sink; local http:// only
+
+[notify.pipeline-failed]   # A background hook pipeline (post-start, post-merge, …) failed
+sinks = ["osc9", "notify-send"]
+
+[notify.ci]                # A branch's CI status changed between two fetches
+sinks = ["notify-send"]
+on = ["passed", "failed"]  # Statuses to notify on (default: any change)
+
+[notify.marker]            # A branch marker was set
+sinks = ["osc777", "webhook"]
+on = ["💬"]                 # Markers to notify on (default: any)
+```
+
+| Sink | Delivery |
+|------|----------|
+| `osc9` | OSC 9 escape to the terminal (iTerm2, WezTerm, Ghostty, kitty) |
+| `osc777` | OSC 777 escape to the terminal (foot, rxvt-unicode, VTE-based) |
+| `notify-send` | Desktop notification via `notify-send` |
+| `command` | Runs the shell command set as `command` under `[notify]`, with `{"event", "title", "body", "branch", "worktree", "value"}` on stdin |
+| `webhook` | POSTs the same JSON to `webhook` |
+
+CI changes are seen when something fetches CI — `wt list --full`, the statusline, the picker — not by polling. Delivery is best-effort: a failed sink is logged at `-vv` and never fails the command. Global only — not read from `[projects]` entries.
+
+### Remotes
+
+Hosts holding a clone of the repository, for worktrees on another machine — a build box, a bigger GPU. `wt switch --on <name>`, `wt remove --on <name>` and `wt merge --on <name>` run the host's own `wt` in its clone over SSH, so hooks, approvals and `worktree-path` resolve there; `wt list` adds the hosts' worktrees to the table, with paths shown as `<name>:<path>`.
+
+```toml
+[remotes.devbox]
+host = "me@devbox.internal"        # SSH destination or ~/.ssh/config alias
+path = "~/src/{{ repo }}"          # The clone on the host; {{ repo }} is the local repo's directory name
+ssh = "ssh -o ConnectTimeout=5"    # Connection command (default: ssh)
+wt = "~/.cargo/bin/wt"             # wt on the host (default: wt)
+```
+
+The host needs `wt` installed and the clone in place. `wt list` asks every host on each run and skips one that fails to answer, with a warning; `--format=json` marks their rows with `host`. Global only — not read from `[projects]` entries.
+
 ### Aliases
 
 Command templates that run as `wt <name>`. See the [Extending Worktrunk guide](/extending/#aliases) for usage and flags.
@@ -2263,7 +2498,7 @@ worktree-path = ".worktrees/{{ branch | sanitize }}"
 
 Every matching entry applies, least- to most-specific, following the rule above: a more specific entry — `git.company.example/platform/*` over `git.company.example/*` — wins where both set the same setting, while hooks and aliases from every matching entry all run, least-specific first. A literal key is the most specific of all; specificity is the count of non-`*` characters in the key. End a host-wide key with `/*` — a bare `git.company.example*` also covers hosts whose names merely start with that string.
 
-`approved-commands` matches the same way, so a pattern entry approves its commands for every repository it covers. Only a key written by hand is ever a pattern: `wt config approvals add` and the interactive prompt record under the exact identifier, and `wt config approvals clear` removes only that exact entry, leaving a pattern other repositories share intact.
+Keys in `approvals.toml` match the same way, so a pattern entry approves its commands for every repository it covers. Only a key written by hand is ever a pattern: `wt config approvals add` and the interactive prompt record under the exact identifier, and `wt config approvals clear` removes only that exact entry, leaving a pattern other repositories share intact.
 
 #### Forge platform and hostname
 
@@ -2425,6 +2660,21 @@ How the fragment renders, and the project-config counterpart: [the LLM commits g
 ## Hooks
 
 See [`wt hook`](/hook/) for hook types, execution order, template variables, and examples. User hooks apply to all projects; [project hooks](/config/#project-configuration) apply only to that repository.
+
+### Sandboxing project hooks
+
+Approved project hooks run with your full privileges. For repositories whose hooks arrive from people you don't fully trust — a fork's pull request checked out with `wt switch pr:N` — `[hooks]` can run them sandboxed instead:
+
+```toml
+[hooks]
+sandbox = "bubblewrap"     # "off" (default) runs project hooks unconfined
+allow-network = false      # Let sandboxed hooks reach the network
+writable = ["~/.cache"]    # Writable paths besides the worktree
+```
+
+A sandboxed hook sees the filesystem read-only, except its worktree, the worktree's git directory, a private `/tmp`, and the `writable` paths. It has no network unless `allow-network` is set, and runs in its own session so it can't type into the terminal. Writes to shared repository state — new commits, `wt config state vars set` — need the repository's `.git` directory in `writable`. Even then, git's config, `.git/hooks/`, and the worktree's link to its repository stay read-only, so a hook can't plant code for your next `git` command to run.
+
+Sandboxing needs Linux and [bubblewrap](https://github.com/containers/bubblewrap) (`bwrap`) on `PATH`. When the sandbox can't be built, the hook fails rather than running unconfined. Your own hooks never run sandboxed, and the approval prompt shows the policy that will apply. Set it per repository or host under `[projects."…".hooks]` — for example, `sandbox = "off"` for your own organization's repositories.
 <!-- USER_CONFIG_END -->
 <!-- PROJECT_CONFIG_START -->
 # Project Configuration
@@ -2500,6 +2750,24 @@ url = "echo http://localhost:{{ branch | hash_port }}"
 ```
 
 Aliases defined here are shared with teammates. For personal aliases, use the [user config](/config/#aliases) `[aliases]` section instead.
+
+## Containers
+
+A container per worktree, in place of `post-start` / `pre-remove` hooks that start and stop one. Set `image` to run a published image, or `build` to build one from a directory's Dockerfile (rebuilt when the Dockerfile changes):
+
+```toml
+[container]
+image = "node:22"                        # Or: build = ".devcontainer"
+# dockerfile = ".devcontainer/Dockerfile"  # With `build`; defaults to <build>/Dockerfile
+ports = ["{{ branch | hash_port }}:3000"]  # Published ports (templates)
+args = ["--env", "NODE_ENV=development"]   # Extra `run` arguments
+# command = ["sleep", "infinity"]          # What the container runs (the default)
+# runtime = "podman"                       # docker or podman; defaults to docker, else podman
+```
+
+`wt switch` starts the worktree's container, creating it the first time, before `pre-start` hooks run. The worktree and the repository's git directory are mounted at their host paths. While the container runs, project hooks and `wt switch --execute` run inside it, from the worktree; user hooks and aliases stay on the host. `wt list` shows each container's state, and `wt remove` removes the container with the worktree.
+
+Like hooks, the `[container]` section needs approval before it first runs, and again when it changes. Declining, or a container that fails to start, leaves the switch to go ahead on the host. Until it's approved, nothing runs the container runtime: `wt list` leaves the Container column out, and `wt remove` leaves any container alone unless run with `--yes`.
 <!-- PROJECT_CONFIG_END -->
 
 # Shell Integration
@@ -2600,6 +2868,77 @@ Hooks, aliases and `step.copy-ignored.exclude` accumulate rather than replace, s
         action: ConfigCommand,
     },
 
+    /// Serve worktrunk over JSON-RPC \[experimental\]
+    ///
+    /// A long-lived server for editor and agent integrations: one process answers list, switch, remove and merge requests instead of a `wt` run per query.
+    #[command(
+        after_long_help = r#"Speaks JSON-RPC 2.0, one message per line, on stdin/stdout (`--stdio`) or on connections to a Unix socket (`--socket`). stderr carries hook output and diagnostics.
+
+```console
+$ wt serve --stdio
+{"jsonrpc":"2.0","id":1,"method":"list","params":{"branches":true}}
+{"jsonrpc":"2.0","id":1,"result":{"schema":2,"repo":{...},"items":[...]}}
+```
+
+## Methods
+
+| Method | Params | Result |
+|--------|--------|--------|
+| `list` | `branches`, `remotes`, `full` | The [`wt list --format=json`](/list/#json-output) schema-2 envelope |
+| `switch` | `branch`, `create`, `base`, `hooks` | `{branch, path, created, warnings}` — the branch's worktree, created when missing |
+| `remove` | `branch`, `force`, `keep_branch`, `force_delete`, `hooks` | `{branch, path, branch_deleted, warnings}` |
+| `merge` | `branch`, `target`, `squash_message`, `rebase`, `remove`, `hooks` | `{branch, target, commit, squashed, rebased, removed, warnings}` |
+| `hooks/show` | `hook_type` | The `wt hook show --format=json` records |
+| `vars/get` | `branch`, `key` | The value, or `null` |
+| `vars/set` | `branch`, `key`, `value` | `null` |
+| `vars/list` | `branch` | An object of the branch's vars |
+
+`switch` doesn't change anyone's directory: the client opens `path`. `merge` fast-forwards like `wt merge` but generates no commit message; pass `squash_message` to squash. Mutations run hooks unless `hooks` is `false`; with no prompt to ask, unapproved project hooks fail the request — approve them first with `wt config approvals add`.
+
+A failed request's error carries the message `wt` would print and, for typed failures, `data.kind` (`"UncommittedChanges"`, `"NotFastForward"`, `"HookCommandFailed"`, …).
+
+## Notifications
+
+When refs or worktrees change — through the server or anything else — the server sends:
+
+```json
+{"jsonrpc":"2.0","method":"changed","params":{"refs":true,"worktrees":false}}
+```
+
+Clients refresh on it rather than polling `list`. Between requests the server keeps its commit-keyed caches (merge bases, merge-tree results, diff stats) in memory, so a `list` after a change re-pays only what changed."#
+    )]
+    Serve(ServeArgs),
+
+    /// Serve worktrunk to AI agents over MCP \[experimental\]
+    ///
+    /// A Model Context Protocol server on stdin/stdout: agents list worktrees, start subtasks in new worktrees, read each other's diffs, set markers and merge, without a shell.
+    #[command(
+        after_long_help = r#"Register it with an MCP client, which starts it in the agent's working directory:
+
+```console
+$ claude mcp add worktrunk -- wt mcp
+$ codex mcp add worktrunk -- wt mcp
+```
+
+## Tools
+
+| Tool | Arguments | Does |
+|------|-----------|------|
+| `list_worktrees` | `branches`, `full` | The [`wt list --format=json`](/list/#json-output) schema-2 envelope: status, ahead/behind, markers, and CI with `full` |
+| `create_worktree` | `branch`, `base` | Creates `branch` in a new worktree at the `worktree-path` location, from `base` or the agent's `HEAD`; returns its path |
+| `worktree_diff` | `branch`, `target`, `stat` | The branch's changes since it left `target`, untracked files included, like `wt step diff` |
+| `set_marker` | `branch`, `marker` | Sets the branch's marker, or clears it without `marker` |
+| `set_var` | `branch`, `key`, `value` | Sets a branch variable (`{{ vars.<key> }}`), or clears it without `value` |
+| `merge` | `branch`, `target`, `squash_message`, `remove` | Merges like `wt merge`, squashing with `squash_message` when given |
+
+`set_marker` and `set_var` default to the agent's own branch. Results carry JSON in `structuredContent` and as text; a tool that fails returns its error as `wt` would print it, with `isError` set.
+
+## Hook approval
+
+`create_worktree` and `merge` run the project's hooks. With stdin taken by the protocol there is no one to prompt, so a tool whose project hooks aren't all approved fails before changing anything and lists the commands. The agent can't approve them; the user reviews them and runs `wt config approvals add`."#
+    )]
+    Mcp,
+
     /// Run a custom `wt-<name>` command found on PATH.
     ///
     /// Captured by clap when the first positional argument doesn't match any
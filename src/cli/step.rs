@@ -179,7 +179,7 @@ When the target's local ref lags its upstream, the rows are measured against tha
 
 ## Conflicts
 
-A conflicting commit leaves the rebase open rather than undoing it. The worktree keeps git's conflict markers, and the ways out are `git rebase --continue` once the conflict is resolved, `git rebase --skip`, or `git rebase --abort`. Until the rebase is settled, `wt step rebase`, `wt step squash`, `wt step push`, and `wt merge` refuse to run — as they do while any other git operation is open, a conflicted `git merge` included.
+A conflicting commit leaves the rebase open rather than undoing it. The worktree keeps git's conflict markers, and the ways out are `git rebase --continue` once the conflict is resolved (`wt step resolve` can propose resolutions with the configured LLM), `git rebase --skip`, or `git rebase --abort`. Until the rebase is settled, `wt step rebase`, `wt step squash`, `wt step push`, and `wt merge` refuse to run — as they do while any other git operation is open, a conflicted `git merge` included.
 "#
     )]
     Rebase {
@@ -271,6 +271,10 @@ The diff is pipeable to tools like `delta`:
 $ wt step diff | delta
 ```
 
+## Submodules
+
+Changes inside submodules show as their content diff (`--submodule=diff`) rather than a pair of commit hashes. A `diff.submodule` setting in git config, or `--submodule=…` after `--`, takes precedence.
+
 ## How it works
 
 Equivalent to:
@@ -363,6 +367,54 @@ Uses per-file reflink (like `cp -Rc`) — copy time scales with file count.
 
 Use the `post-start` hook so the copy runs in the background. Use `pre-start` instead if subsequent hooks or `--execute` command need the copied files immediately.
 
+## Incremental sync
+
+By default, files that already exist in the destination are skipped, and `--force` re-copies everything. `--sync` updates a long-lived worktree the way `rsync` does: files whose size or modification time differ from the source are replaced, and unchanged files are left alone. Synced files take the source's modification time, so the next sync recognizes them with a single stat.
+
+```console
+$ wt step copy-ignored --sync
+$ wt step copy-ignored --sync --delete      # also remove files deleted at the source
+$ wt step copy-ignored --sync --checksum    # compare contents, ignoring mtimes
+```
+
+`--delete` only prunes inside the directories being copied (e.g. stale objects in `target/`); it never touches destination paths outside them. Files from a plain (non-sync) copy don't carry the source's mtime, so the first `--sync` after one rewrites them once. `--checksum` avoids that at the cost of reading both copies of every same-sized file.
+
+To keep `target/` and `node_modules/` fresh in worktrees that outlive many switches:
+
+```toml
+[post-switch]
+refresh = "wt step copy-ignored --sync --delete"
+```
+
+`--dry-run --sync` reports what would change without writing. With `--format=json`, each entry carries its own `created` / `updated` / `unchanged` / `deleted` counts.
+
+## Shared store
+
+On filesystems without reflink (ext4, NFS), each copy is a full copy, so ten worktrees hold ten copies of `target/`. `--store` deduplicates them through a content-addressed store in `.git/wt/store/`: each file's contents are stored once, keyed by hash, and every worktree gets a hardlink to the stored copy.
+
+```console
+$ wt step copy-ignored --store
+```
+
+To make it the default for every copy (including hooks):
+
+```toml
+[step.copy-ignored]
+store = true
+```
+
+Linked files are read-only, since a hardlink is shared by every worktree that has it. Build tools that replace their outputs (write a new file, rename it over the old one) give that worktree its own copy the first time they write; a tool that edits a file in place fails with a permission error instead of changing it everywhere. Where a hardlink isn't possible — store and worktree on different filesystems — the file is copied instead. `--sync` doesn't use the store; with `store = true` configured, it says so and syncs with plain copies.
+
+`wt config state store` reports the store's size and how much of it no worktree uses any more; `wt config state store gc` removes those entries. On filesystems with reflink the store saves little — plain copies already share blocks.
+
+## Git LFS
+
+LFS files are tracked, not ignored, so they aren't copied — and `.git/lfs` is already shared by every worktree. A destination whose LFS files are pointers (checked out with `GIT_LFS_SKIP_SMUDGE`, outside `wt switch`) has them [hydrated](/switch/#git-lfs) from that cache instead.
+
+## Submodules
+
+`git ls-files` stops at submodule boundaries, so copy-ignored descends into each submodule itself: ignored files inside a submodule that's initialized in both worktrees are copied to the same path. `.worktreeinclude` and excludes inside a submodule apply relative to it. [`[switch] submodules`](/switch/#submodules) initializes them in new worktrees.
+
 ## Background-hook priority (experimental)
 
 When invoked from a background hook pipeline (`post-*` hooks), `wt step copy-ignored` self-lowers its CPU and I/O priority — `taskpolicy -b` on macOS, `nice -n 19` plus `ionice -c 3` on Linux — so it yields to interactive work. Foreground callers (`pre-*` hooks, direct interactive use) run at normal priority so the user isn't waiting on a throttled copy.
@@ -414,9 +466,29 @@ The `.worktreeinclude` pattern is shared with [Claude Code on desktop](https://c
         dry_run: bool,
 
         /// Overwrite existing files in destination
-        #[arg(long)]
+        #[arg(long, conflicts_with = "sync")]
         force: bool,
 
+        /// Update existing files that changed instead of skipping them
+        ///
+        /// Compares size and modification time; unchanged files aren't copied.
+        #[arg(long)]
+        sync: bool,
+
+        /// With --sync, compare file contents instead of size and mtime
+        #[arg(long, requires = "sync")]
+        checksum: bool,
+
+        /// With --sync, delete destination files removed at the source
+        #[arg(long, requires = "sync")]
+        delete: bool,
+
+        /// Deduplicate through the shared store, hardlinking files into place
+        ///
+        /// Defaults to `[step.copy-ignored] store`.
+        #[arg(long, conflicts_with = "sync")]
+        store: bool,
+
         /// Require .worktreeinclude to copy anything
         #[arg(long)]
         require_include: bool,
@@ -456,6 +528,13 @@ $ wt step eval '{{ branch | hash_port }},{{ ("supabase-api-" ~ branch) | hash_po
 16066,16739
 ```
 
+Get (or assign) a port from the [port registry](/hook/#port-registry):
+
+```console
+$ wt step eval '{{ ports.web }}'
+14213
+```
+
 Use conditionals and filters:
 
 ```console
@@ -743,6 +822,106 @@ refuses), unless `--commit` is passed.
         format: crate::cli::SwitchFormat,
     },
 
+    /// \[experimental\] Resolve rebase conflicts with the configured LLM
+    ///
+    /// Proposes a resolution for each conflicted file, shows it as a diff, and stages it once approved.
+    #[command(after_long_help = r#"## Examples
+
+Resolve the conflicts left by `wt step rebase`, approving each file:
+
+```console
+$ wt step rebase
+$ wt step resolve
+```
+
+Accept every proposal without prompting:
+
+```console
+$ wt step resolve --yes
+```
+
+## How it works
+
+For each conflicted file, the base, target, and branch versions go to the `[commit.generation] command` along with the commit being replayed and the target commits that touched the file. The proposal is written into the worktree and shown as git's combined diff — the change against both sides at once. Approving stages the file; declining puts git's conflict markers back.
+
+Once every file is staged, the rebase continues. If the next commit conflicts too, resolution starts over on its files.
+
+## Unresolved files
+
+Some files are left for manual resolution, and the rebase stays open:
+
+- **Deleted on one side** — there's no text to merge
+- **Binary or over 100KB** — the model can't echo the file back reliably
+- **Proposal still has conflict markers**, or the LLM command failed
+- **Declined** proposals
+
+The command then exits non-zero and lists them. Resolve and `git add` them, then `git rebase --continue`.
+
+Without a terminal, `--yes` is required — each proposal otherwise needs an answer.
+"#)]
+    Resolve {
+        /// Output format
+        ///
+        /// JSON prints the resolved and unresolved files to stdout.
+        #[arg(long, default_value = "text", help_heading = "Automation")]
+        format: crate::cli::SwitchFormat,
+    },
+
+    /// \[experimental\] Review a branch's changes with the configured LLM
+    ///
+    /// Sends the branch diff to the `[commit.generation] command` and prints its findings, each tagged blocking or suggestion.
+    #[command(after_long_help = r#"## Examples
+
+Review the current branch, uncommitted changes included:
+
+```console
+$ wt step review
+```
+
+Review another worktree's branch:
+
+```console
+$ wt step review --branch feature
+```
+
+## Gating merges
+
+With `--check`, blocking findings exit 1. As a `pre-merge` hook, that stops `wt merge` before anything lands on the target:
+
+```toml
+# .config/wt.toml
+[pre-merge]
+review = "wt step review --check"
+```
+
+## Caching
+
+Reviews are cached in `.git/wt/cache/review/`, keyed by a hash of the rendered prompt and the LLM command — rerunning on an unchanged branch costs nothing, and any change to the diff, `review-template`, or command triggers a fresh review. `--refresh` ignores the cache. The `wt switch` picker shows the cached review in its `review` tab; it never runs the LLM itself.
+
+## Template
+
+The prompt comes from `[commit.generation] review-template`, with `{{ git_diff }}`, `{{ git_diff_stat }}`, `{{ branch }}`, and `{{ repo }}` available. A custom template has to keep asking for one `- [blocking] …` or `- [suggestion] …` bullet per finding — those tags are what `--check` counts.
+"#)]
+    Review {
+        /// Branch to review (defaults to current worktree)
+        #[arg(short, long, add = crate::completion::worktree_only_completer(), value_parser = crate::cli::non_empty_branch)]
+        branch: Option<String>,
+
+        /// Exit 1 when the review has blocking findings
+        #[arg(long)]
+        check: bool,
+
+        /// Ignore a cached review of the same diff
+        #[arg(long)]
+        refresh: bool,
+
+        /// Output format
+        ///
+        /// JSON prints the review and its blocking findings to stdout.
+        #[arg(long, default_value = "text", help_heading = "Automation")]
+        format: crate::cli::SwitchFormat,
+    },
+
     /// \[experimental\] Run a command; kill its whole process tree when its worktree is removed
     ///
     /// Teardown is automatic and needs no `pre-remove` hook; the group gets `SIGTERM` then `SIGKILL`.
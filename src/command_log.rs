@@ -1,46 +1,63 @@
 //! Always-on logging for configured external commands.
 //!
 //! Logs hook execution and LLM commands to `.git/wt/logs/commands.jsonl` as JSONL.
-//! Provides an audit trail for debugging without requiring `-vv`.
+//! Provides an audit trail for debugging without requiring `-vv`;
+//! `wt config state logs history` reads it back ([`read_history`]).
 //!
 //! # Growth control
 //!
-//! Before each write, the file size is checked. If >1MB, the current file is
-//! renamed to `commands.jsonl.old` and a fresh file is started. This bounds
-//! storage to ~2MB worst case.
+//! Before each write, the file size is checked. If >1MB, the file rotates:
+//!
+//! - By default it is renamed to `commands.jsonl.old` and a fresh file is
+//!   started, bounding storage to ~2MB worst case.
+//! - With `[logs] keep = N` in user config, it is gzipped to
+//!   `commands.jsonl.1.gz` instead, after shifting each older generation up
+//!   one (`.1.gz` → `.2.gz`, …) and dropping the one past `N`. A 1MB log
+//!   compresses to well under 100KB, so a long history stays cheap.
 
 use std::fs::{self, File, OpenOptions};
-use std::io::Write;
+use std::io::{BufRead, BufReader, Read, Write};
 use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
 
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
 /// Maximum log file size before rotation (1MB).
 const MAX_LOG_SIZE: u64 = 1_048_576;
 
 /// Maximum command string length in log entries.
 const MAX_CMD_LENGTH: usize = 2000;
 
+/// File name of the live log within the log directory.
+pub const LOG_FILE_NAME: &str = "commands.jsonl";
+
 static COMMAND_LOG: OnceLock<Mutex<CommandLog>> = OnceLock::new();
 
 struct CommandLog {
     log_path: PathBuf,
     file: Option<File>,
     wt_command: String,
+    /// Gzipped generations to keep on rotation; `None` keeps one `.old`.
+    keep: Option<u32>,
 }
 
 impl CommandLog {
-    fn new(log_dir: &Path, wt_command: &str) -> Self {
+    fn new(log_dir: &Path, wt_command: &str, keep: Option<u32>) -> Self {
         Self {
-            log_path: log_dir.join("commands.jsonl"),
+            log_path: log_dir.join(LOG_FILE_NAME),
             file: None,
             wt_command: wt_command.to_string(),
+            keep,
         }
     }
 
     fn write(
         &mut self,
         label: &str,
+        branch: Option<&str>,
         command: &str,
         exit_code: Option<i32>,
         duration: Option<Duration>,
@@ -49,8 +66,17 @@ impl CommandLog {
         if let Ok(metadata) = fs::metadata(&self.log_path)
             && metadata.len() > MAX_LOG_SIZE
         {
-            let old_path = self.log_path.with_extension("jsonl.old");
-            let _ = fs::rename(&self.log_path, &old_path);
+            match self.keep {
+                None => {
+                    let old_path = self.log_path.with_extension("jsonl.old");
+                    let _ = fs::rename(&self.log_path, &old_path);
+                }
+                Some(keep) => {
+                    if let Err(e) = rotate_compressed(&self.log_path, keep) {
+                        log::debug!("Failed to rotate {}: {e}", self.log_path.display());
+                    }
+                }
+            }
             self.file = None; // Force re-open after rotation
         }
 
@@ -72,6 +98,7 @@ impl CommandLog {
             "ts": ts,
             "wt": self.wt_command,
             "label": label,
+            "branch": branch,
             "cmd": cmd_display,
             "exit": exit_code,
             "dur_ms": duration.map(|d| d.as_millis() as u64),
@@ -88,12 +115,43 @@ impl CommandLog {
     }
 }
 
+/// Path of gzipped generation `n` (1 = most recent) next to `log_path`.
+fn generation_path(log_path: &Path, n: u32) -> PathBuf {
+    log_path.with_extension(format!("jsonl.{n}.gz"))
+}
+
+/// Rotate `log_path` into gzipped generation 1, shifting older generations up
+/// and dropping those past `keep`. `keep = 0` discards the log outright.
+fn rotate_compressed(log_path: &Path, keep: u32) -> std::io::Result<()> {
+    if keep == 0 {
+        return fs::remove_file(log_path);
+    }
+    let _ = fs::remove_file(generation_path(log_path, keep));
+    for n in (1..keep).rev() {
+        let from = generation_path(log_path, n);
+        if from.exists() {
+            fs::rename(&from, generation_path(log_path, n + 1))?;
+        }
+    }
+
+    // Compress into a temp file and rename, so a reader never sees a
+    // half-written generation.
+    let target = generation_path(log_path, 1);
+    let tmp = log_path.with_extension("jsonl.1.gz.tmp");
+    let mut encoder = GzEncoder::new(File::create(&tmp)?, Compression::default());
+    std::io::copy(&mut File::open(log_path)?, &mut encoder)?;
+    encoder.finish()?;
+    fs::rename(&tmp, &target)?;
+    fs::remove_file(log_path)
+}
+
 /// Initialize the command log.
 ///
 /// Call once at startup after determining the repository's log directory.
-/// The log file and directory are created lazily on first write.
-pub fn init(log_dir: &Path, wt_command: &str) {
-    let logger = CommandLog::new(log_dir, wt_command);
+/// The log file and directory are created lazily on first write. `keep` is
+/// the `[logs] keep` setting: gzipped generations to retain on rotation.
+pub fn init(log_dir: &Path, wt_command: &str, keep: Option<u32>) {
+    let logger = CommandLog::new(log_dir, wt_command, keep);
 
     // OnceLock::set fails if already initialized — that's fine, ignore the error
     let _ = COMMAND_LOG.set(Mutex::new(logger));
@@ -102,10 +160,17 @@ pub fn init(log_dir: &Path, wt_command: &str) {
 /// Log an external command execution.
 ///
 /// - `label`: identifies what triggered this command (e.g., "pre-merge user:lint", "commit.generation")
+/// - `branch`: the branch the command ran for, when known
 /// - `command`: the shell command that was executed (truncated to 2000 chars)
 /// - `exit_code`: `None` for background commands where outcome is unknown
 /// - `duration`: `None` for background commands
-pub fn log_command(label: &str, command: &str, exit_code: Option<i32>, duration: Option<Duration>) {
+pub fn log_command(
+    label: &str,
+    branch: Option<&str>,
+    command: &str,
+    exit_code: Option<i32>,
+    duration: Option<Duration>,
+) {
     let mutex = match COMMAND_LOG.get() {
         Some(m) => m,
         None => return,
@@ -115,7 +180,115 @@ pub fn log_command(label: &str, command: &str, exit_code: Option<i32>, duration:
         return;
     };
 
-    logger.write(label, command, exit_code, duration);
+    logger.write(label, branch, command, exit_code, duration);
+}
+
+/// One `commands.jsonl` record, as read back by [`read_history`].
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct HistoryEntry {
+    /// When the command finished (or was spawned, for background commands),
+    /// RFC 3339 UTC
+    pub ts: String,
+    /// The `wt` command line that ran it
+    pub wt: String,
+    /// What triggered it (`pre-merge user:lint`, `commit.generation`)
+    pub label: String,
+    /// The branch it ran for; `None` for records from before the field existed
+    #[serde(default)]
+    pub branch: Option<String>,
+    /// The shell command, truncated to 2000 characters
+    pub cmd: String,
+    /// Exit code; `None` for background commands
+    #[serde(default)]
+    pub exit: Option<i32>,
+    /// Duration in milliseconds; `None` for background commands
+    #[serde(default)]
+    pub dur_ms: Option<u64>,
+}
+
+impl HistoryEntry {
+    /// The hook type a hook record ran for — the first word of its label
+    /// (`pre-merge` in `pre-merge user:lint`). `None` for other records.
+    pub fn hook_type(&self) -> Option<crate::git::HookType> {
+        self.label.split_once(' ')?.0.parse().ok()
+    }
+
+    /// `ts` as a timestamp; `None` when it doesn't parse.
+    pub fn time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::parse_from_rfc3339(&self.ts)
+            .ok()
+            .map(|t| t.with_timezone(&chrono::Utc))
+    }
+
+    /// Whether the command is known to have failed (non-zero exit).
+    pub fn failed(&self) -> bool {
+        self.exit.is_some_and(|code| code != 0)
+    }
+}
+
+/// Parse `commands.jsonl` lines, skipping any that aren't records (a line
+/// torn by a crash, or a future format).
+pub fn parse_history(input: impl BufRead) -> Vec<HistoryEntry> {
+    input
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect()
+}
+
+/// Every record in `log_dir`, oldest first: the gzipped generations, the
+/// uncompressed `.old`, then the live file. Missing or unreadable files are
+/// skipped.
+pub fn read_history(log_dir: &Path) -> Vec<HistoryEntry> {
+    let log_path = log_dir.join(LOG_FILE_NAME);
+    let mut generations: Vec<(u32, PathBuf)> = fs::read_dir(log_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let n = name
+                .to_str()?
+                .strip_prefix("commands.jsonl.")?
+                .strip_suffix(".gz")?
+                .parse()
+                .ok()?;
+            Some((n, entry.path()))
+        })
+        .collect();
+    generations.sort_by_key(|(n, _)| std::cmp::Reverse(*n));
+
+    let mut entries = Vec::new();
+    for (_, path) in generations {
+        if let Ok(file) = File::open(&path) {
+            entries.extend(parse_history(BufReader::new(GzDecoder::new(file))));
+        }
+    }
+    for path in [log_path.with_extension("jsonl.old"), log_path] {
+        if let Ok(file) = File::open(&path) {
+            entries.extend(parse_history(BufReader::new(file)));
+        }
+    }
+    // A `.old` left from before `keep` was configured predates the gzipped
+    // generations; order by time (stable, so same-second records keep file order).
+    entries.sort_by(|a, b| a.ts.cmp(&b.ts));
+    entries
+}
+
+/// Read whatever has been appended to `file` since the last call, as whole
+/// records. Holds back a trailing partial line until its newline arrives.
+/// For `--follow`.
+pub fn read_appended(file: &mut File, pending: &mut String) -> Vec<HistoryEntry> {
+    let mut chunk = String::new();
+    if file.read_to_string(&mut chunk).is_err() {
+        return Vec::new();
+    }
+    pending.push_str(&chunk);
+    let Some(end) = pending.rfind('\n') else {
+        return Vec::new();
+    };
+    let complete: String = pending.drain(..=end).collect();
+    parse_history(complete.as_bytes())
 }
 
 /// Truncate a command string to `MAX_CMD_LENGTH` characters, appending `…` if truncated.
@@ -163,6 +336,7 @@ mod tests {
         // Should silently do nothing when not initialized
         log_command(
             "test",
+            None,
             "echo hello",
             Some(0),
             Some(Duration::from_millis(100)),
@@ -219,10 +393,16 @@ mod tests {
     #[test]
     fn test_write_creates_file_lazily() {
         let dir = tempfile::tempdir().unwrap();
-        let mut logger = CommandLog::new(dir.path(), "wt test");
+        let mut logger = CommandLog::new(dir.path(), "wt test", None);
 
         assert!(!dir.path().join("commands.jsonl").exists());
-        logger.write("test", "echo hi", Some(0), Some(Duration::from_millis(10)));
+        logger.write(
+            "test",
+            Some("main"),
+            "echo hi",
+            Some(0),
+            Some(Duration::from_millis(10)),
+        );
         assert!(dir.path().join("commands.jsonl").exists());
 
         let content = fs::read_to_string(dir.path().join("commands.jsonl")).unwrap();
@@ -231,15 +411,16 @@ mod tests {
         assert_eq!(parsed["cmd"], "echo hi");
         assert_eq!(parsed["exit"], 0);
         assert_eq!(parsed["wt"], "wt test");
+        assert_eq!(parsed["branch"], "main");
     }
 
     #[test]
     fn test_write_appends_multiple_entries() {
         let dir = tempfile::tempdir().unwrap();
-        let mut logger = CommandLog::new(dir.path(), "wt test");
+        let mut logger = CommandLog::new(dir.path(), "wt test", None);
 
-        logger.write("a", "cmd-a", Some(0), Some(Duration::from_millis(1)));
-        logger.write("b", "cmd-b", Some(1), Some(Duration::from_millis(2)));
+        logger.write("a", None, "cmd-a", Some(0), Some(Duration::from_millis(1)));
+        logger.write("b", None, "cmd-b", Some(1), Some(Duration::from_millis(2)));
 
         let content = fs::read_to_string(dir.path().join("commands.jsonl")).unwrap();
         let lines: Vec<&str> = content.trim().lines().collect();
@@ -260,12 +441,13 @@ mod tests {
         let filler = "x".repeat(MAX_LOG_SIZE as usize + 1);
         fs::write(&log_path, &filler).unwrap();
 
-        let mut logger = CommandLog::new(dir.path(), "wt test");
+        let mut logger = CommandLog::new(dir.path(), "wt test", None);
         // Open the existing oversized file so the logger has a handle
         logger.file = OpenOptions::new().append(true).open(&log_path).ok();
 
         logger.write(
             "rotated",
+            None,
             "echo rotated",
             Some(0),
             Some(Duration::from_millis(5)),
@@ -281,4 +463,97 @@ mod tests {
         let parsed: serde_json::Value = serde_json::from_str(content.trim()).unwrap();
         assert_eq!(parsed["label"], "rotated");
     }
+
+    fn record(ts: &str, label: &str) -> String {
+        format!(
+            r#"{{"ts":"{ts}","wt":"wt merge","label":"{label}","cmd":"true","exit":0,"dur_ms":1}}"#
+        )
+    }
+
+    #[test]
+    fn test_rotation_keeps_compressed_generations() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join(LOG_FILE_NAME);
+        let oversized = |ts: &str| {
+            let line = record(ts, "pre-merge user:lint") + "\n";
+            line.repeat(MAX_LOG_SIZE as usize / line.len() + 1)
+        };
+
+        // Three rotations with keep = 2: the oldest generation is dropped.
+        for ts in [
+            "2026-01-01T00:00:00Z",
+            "2026-01-02T00:00:00Z",
+            "2026-01-03T00:00:00Z",
+        ] {
+            fs::write(&log_path, oversized(ts)).unwrap();
+            let mut logger = CommandLog::new(dir.path(), "wt test", Some(2));
+            logger.write("live", None, "echo", Some(0), None);
+        }
+
+        assert!(generation_path(&log_path, 1).exists());
+        assert!(generation_path(&log_path, 2).exists());
+        assert!(!generation_path(&log_path, 3).exists());
+        assert!(!log_path.with_extension("jsonl.old").exists());
+        assert!(fs::metadata(generation_path(&log_path, 1)).unwrap().len() < MAX_LOG_SIZE / 10);
+
+        let history = read_history(dir.path());
+        assert_eq!(history.first().unwrap().ts, "2026-01-02T00:00:00Z");
+        assert_eq!(history.last().unwrap().label, "live");
+        assert!(history.iter().all(|e| e.ts != "2026-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_read_history_orders_old_before_live() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join(LOG_FILE_NAME);
+        fs::write(
+            log_path.with_extension("jsonl.old"),
+            record("2026-01-01T00:00:00Z", "post-start user:server") + "\n",
+        )
+        .unwrap();
+        fs::write(
+            &log_path,
+            format!(
+                "{}\nnot json\n{}\n",
+                record("2026-01-02T00:00:00Z", "commit.generation"),
+                record("2026-01-02T00:00:00Z", "pre-merge user:test"),
+            ),
+        )
+        .unwrap();
+
+        let history = read_history(dir.path());
+        let labels: Vec<_> = history.iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            [
+                "post-start user:server",
+                "commit.generation",
+                "pre-merge user:test"
+            ]
+        );
+        assert_eq!(
+            history[0].hook_type(),
+            Some(crate::git::HookType::PostCreate)
+        );
+        assert_eq!(history[1].hook_type(), None);
+        assert!(history[0].branch.is_none());
+    }
+
+    #[test]
+    fn test_read_appended_holds_partial_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOG_FILE_NAME);
+        let line = record("2026-01-01T00:00:00Z", "pre-merge user:lint");
+        fs::write(&path, format!("{line}\n{}", &line[..10])).unwrap();
+
+        let mut file = File::open(&path).unwrap();
+        let mut pending = String::new();
+        assert_eq!(read_appended(&mut file, &mut pending).len(), 1);
+        assert_eq!(pending, &line[..10]);
+
+        let mut writer = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(writer, "{}", &line[10..]).unwrap();
+        assert_eq!(read_appended(&mut file, &mut pending).len(), 1);
+        assert!(pending.is_empty());
+    }
 }
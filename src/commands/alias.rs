@@ -82,6 +82,8 @@ const BUILTIN_STEP_COMMANDS: &[&str] = &[
     "push",
     "rebase",
     "relocate",
+    "resolve",
+    "review",
     "squash",
     "tether",
 ];
@@ -92,7 +94,7 @@ const BUILTIN_STEP_COMMANDS: &[&str] = &[
 /// if `[aliases] list = …` is configured. Kept in sync with `Cli` via
 /// `test_top_level_builtins_match_clap`.
 pub(crate) const TOP_LEVEL_BUILTINS: &[&str] = &[
-    "config", "hook", "list", "merge", "remove", "select", "step", "switch",
+    "config", "hook", "list", "mcp", "merge", "remove", "select", "serve", "step", "switch",
 ];
 
 /// Whether `--help` or `-h` appears in `args` before any `--` literal-forward
@@ -651,7 +653,12 @@ fn run_alias(
                 })
             })?;
             for step in steps {
-                sourced_steps.push(SourcedStep { step, source });
+                sourced_steps.push(SourcedStep {
+                    step,
+                    source,
+                    sandbox: None,
+                    container: None,
+                });
             }
         }
         sourced_steps_to_foreground(sourced_steps, &PipelineKind::Alias { name: alias_name })
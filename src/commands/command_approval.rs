@@ -22,11 +22,11 @@ use std::path::Path;
 
 use anyhow::Context;
 use color_print::cformat;
-use worktrunk::config::{Approvals, require_approvals_path};
-use worktrunk::git::{GitError, HookType};
+use worktrunk::config::{ApprovalRecord, Approvals, UserConfig, require_approvals_path};
+use worktrunk::git::{GitError, HookType, Repository};
 use worktrunk::styling::{
-    INFO_SYMBOL, WARNING_SYMBOL, eprint, eprintln, hint_message, prompt_message, stderr,
-    warning_message,
+    INFO_SYMBOL, WARNING_SYMBOL, eprint, eprintln, hint_message, info_message, prompt_message,
+    stderr, warning_message,
 };
 
 use super::hook_filter::{HookSource, ParsedFilter};
@@ -57,7 +57,7 @@ pub fn approve_command_batch(
         .iter()
         .filter(|cmd| {
             commands_already_filtered
-                || !approvals.is_command_approved(project_id, &cmd.command.template)
+                || !approvals.is_command_approved(project_id, &cmd.command.template, &cmd.scope())
         })
         .collect();
 
@@ -80,12 +80,9 @@ pub fn approve_command_batch(
     // actually ran, so it warns rather than errors.
     if !yes {
         let mut fresh_approvals = Approvals::load().context("Failed to load approvals")?;
-        let commands: Vec<String> = needs_approval
-            .iter()
-            .map(|cmd| cmd.command.template.clone())
-            .collect();
+        let records = approval_records(&needs_approval, None);
         let save_result = require_approvals_path().and_then(|path| {
-            fresh_approvals.approve_commands(project_id.to_string(), commands, &path)
+            fresh_approvals.approve_commands(project_id.to_string(), records, &path)
         });
         if let Err(e) = save_result {
             eprintln!(
@@ -102,6 +99,58 @@ pub fn approve_command_batch(
     Ok(true)
 }
 
+/// Approval records for `commands`, stamped with where and by whom they're
+/// approved: the hash of the current project config, git `user.email` (or
+/// the login name), and this machine's hostname. `expires_at` is Unix
+/// seconds.
+///
+/// Provenance is best-effort — a field that can't be determined is left
+/// out rather than failing the approval.
+pub fn approval_records(
+    commands: &[&ApprovableCommand],
+    expires_at: Option<u64>,
+) -> Vec<ApprovalRecord> {
+    let repo = Repository::current().ok();
+    let config_hash = repo
+        .as_ref()
+        .and_then(|repo| repo.project_config_hash().ok().flatten());
+    let approver = repo
+        .as_ref()
+        .and_then(|repo| repo.config_value("user.email").ok().flatten())
+        .or_else(|| std::env::var("USER").ok())
+        .or_else(|| std::env::var("USERNAME").ok())
+        .filter(|name| !name.is_empty());
+    let host = hostname();
+    let now = worktrunk::utils::epoch_now();
+    commands
+        .iter()
+        .map(|cmd| ApprovalRecord {
+            command: cmd.command.template.clone(),
+            scope: Some(cmd.scope()),
+            config_hash: config_hash.clone(),
+            approved_at: Some(now),
+            expires_at,
+            approver: approver.clone(),
+            host: host.clone(),
+        })
+        .collect()
+}
+
+/// This machine's name, for approval records.
+fn hostname() -> Option<String> {
+    // Allow tests to pin the name
+    if let Ok(name) = std::env::var("WORKTRUNK_TEST_HOSTNAME") {
+        return Some(name);
+    }
+    #[cfg(unix)]
+    let name = nix::unistd::gethostname()
+        .ok()
+        .and_then(|name| name.into_string().ok());
+    #[cfg(not(unix))]
+    let name = std::env::var("COMPUTERNAME").ok();
+    name.filter(|name| !name.is_empty())
+}
+
 /// The project's directory name, which is what a user recognizes; the full
 /// identifier is a path or a remote URL.
 fn display_project_name(project_id: &str) -> &str {
@@ -152,6 +201,9 @@ pub fn prompt_for_batch_approval(
         ),
         commands,
     );
+    if let Some(notice) = sandbox_notice(commands, project_id) {
+        eprintln!("{}", info_message(notice));
+    }
 
     // Check if stdin is a TTY before attempting to prompt
     // This happens AFTER showing the commands so they appear in CI/CD logs
@@ -176,6 +228,21 @@ pub fn prompt_for_batch_approval(
     Ok(response.trim().eq_ignore_ascii_case("y"))
 }
 
+/// The sandbox the batch's hook commands will run in (`[hooks] sandbox`), so
+/// the user approves knowing how far the commands reach. `None` when the batch
+/// has no hooks or project hooks run unsandboxed.
+fn sandbox_notice(commands: &[&ApprovableCommand], project_id: &str) -> Option<String> {
+    if !commands
+        .iter()
+        .any(|cmd| matches!(cmd.phase, Phase::Hook(_)))
+    {
+        return None;
+    }
+    let config = UserConfig::load().ok()?;
+    let description = worktrunk::sandbox::describe(&config.hook_policy(Some(project_id)))?;
+    Some(format!("Project hooks will run {description}"))
+}
+
 /// Approve a project-config alias before execution.
 ///
 /// Returns `Ok(true)` if approved (or already approved), `Ok(false)` if declined.
@@ -190,7 +257,7 @@ pub fn approve_alias_commands(
     let cmds: Vec<_> = commands
         .commands()
         .map(|cmd| ApprovableCommand {
-            phase: Phase::Alias,
+            phase: Phase::Alias(alias_name.to_string()),
             command: worktrunk::config::Command::new(
                 Some(cmd.name.clone().unwrap_or_else(|| alias_name.to_string())),
                 cmd.template.clone(),
@@ -283,6 +350,30 @@ pub fn approve_hooks_filtered(
     approve_command_batch(&commands, &project_id, &approvals, ctx.yes, false)
 }
 
+/// The project commands for `hook_types` that [`approve_hooks`] would prompt
+/// for — the gate for callers that can never prompt, such as `wt mcp`, whose
+/// stdin is the protocol. Empty means the hooks may run.
+pub fn unapproved_hook_commands(
+    repo: &Repository,
+    hook_types: &[HookType],
+) -> anyhow::Result<Vec<ApprovableCommand>> {
+    let Some(project_config) = repo.load_project_config()? else {
+        return Ok(Vec::new());
+    };
+    let commands = collect_commands_for_hooks(&project_config, hook_types);
+    if commands.is_empty() {
+        return Ok(commands);
+    }
+    let project_id = repo.project_identifier()?;
+    let approvals = Approvals::load().context("Failed to load approvals")?;
+    Ok(commands
+        .into_iter()
+        .filter(|cmd| {
+            !approvals.is_command_approved(&project_id, &cmd.command.template, &cmd.scope())
+        })
+        .collect())
+}
+
 /// Approve `hook_types` and centralize the "decline → continue without hooks" message.
 ///
 /// Returns `true` when approval succeeded (hooks should run) and `false` when the
@@ -347,11 +438,11 @@ pub fn approve_commit_template_append(
     let project_id = ctx.repo.project_identifier()?;
     let approvals = Approvals::load().context("Failed to load approvals")?;
     let owned = fragment.to_string();
-    if approvals.is_command_approved(&project_id, &owned) {
+    let batch = vec![ApprovableCommand::commit_template_append(owned.clone())];
+    if approvals.is_command_approved(&project_id, &owned, &batch[0].scope()) {
         return Ok(Some(owned));
     }
 
-    let batch = vec![ApprovableCommand::commit_template_append(owned.clone())];
     let approved = approve_command_batch(&batch, &project_id, &approvals, ctx.yes, true)?;
     if !approved {
         worktrunk::styling::eprintln!(
@@ -364,3 +455,20 @@ pub fn approve_commit_template_append(
     }
     Ok(Some(owned))
 }
+
+/// Approve the project's `[container]` before `wt` creates a container from
+/// it: its image, ports and `run` arguments decide what the container can
+/// reach, so they pass the same gate as project hooks.
+///
+/// Returns `Ok(true)` when approved (or already approved). Declining is
+/// non-fatal for the caller, which continues without the container.
+pub fn approve_container(
+    repo: &Repository,
+    config: &worktrunk::config::ProjectContainerConfig,
+    yes: bool,
+) -> anyhow::Result<bool> {
+    let project_id = repo.project_identifier()?;
+    let approvals = Approvals::load().context("Failed to load approvals")?;
+    let batch = vec![ApprovableCommand::container(config)];
+    approve_command_batch(&batch, &project_id, &approvals, yes, false)
+}
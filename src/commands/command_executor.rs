@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
@@ -8,8 +7,10 @@ use worktrunk::config::{
     Command, CommandConfig, HookStep, TemplateContext, UserConfig, VarScope, VarsMode,
     format_hook_variables, validate_template_syntax,
 };
+use worktrunk::container::Container;
 use worktrunk::git::{ErrorExt, Repository, WorktrunkError};
-use worktrunk::path::{format_path_for_display, to_posix_path};
+use worktrunk::path::format_path_for_display;
+use worktrunk::sandbox::{SandboxPolicy, sandboxed};
 use worktrunk::shell_exec::ShellEscapeMode;
 use worktrunk::styling::{
     eprintln, error_message, format_bash_with_gutter, format_with_gutter, info_message,
@@ -47,6 +48,15 @@ impl PreparedCommand {
     pub fn context_json(&self) -> String {
         self.context.to_json()
     }
+
+    /// The branch this command runs for, recorded with its `commands.jsonl`
+    /// entry. `None` on a detached HEAD, where the context's `branch` reads
+    /// `HEAD` (see [`CommandContext::branch_or_head`]).
+    pub fn log_branch(&self) -> Option<&str> {
+        self.context
+            .get("branch")
+            .filter(|branch| *branch != "HEAD")
+    }
 }
 
 /// A step in a prepared pipeline, mirroring `HookStep`.
@@ -150,6 +160,14 @@ pub struct ForegroundStep {
     /// than per-pipeline so a merged user+project alias relaxes the user's
     /// own steps without leaking the project's body into the parent shell.
     pub directives: DirectivePassthrough,
+    /// Run each command inside this sandbox (project hook steps under
+    /// `[hooks] sandbox`). Applied after the announcement, so the gutter shows
+    /// the command as configured rather than the `bwrap` invocation.
+    pub sandbox: Option<SandboxPolicy>,
+    /// Run each command inside this container instead (project hook steps
+    /// of a `[container]` project whose container is running). Applied at
+    /// the same point as `sandbox`.
+    pub container: Option<Container>,
 }
 
 /// Controls how foreground execution responds to command failures.
@@ -220,174 +238,46 @@ impl<'a> CommandContext<'a> {
         self.repo.project_identifier().ok()
     }
 
+    /// The sandbox `source`'s hook commands run in here: project hooks under
+    /// `[hooks] sandbox`, confined to this worktree. User hooks are never
+    /// sandboxed.
+    pub fn hook_sandbox(&self, source: HookSource) -> Option<SandboxPolicy> {
+        if source != HookSource::Project {
+            return None;
+        }
+        SandboxPolicy::for_project_hooks(self.repo, self.config, self.worktree_path)
+    }
+
+    /// The container `source`'s hook commands run in here: project hooks of a
+    /// `[container]` project, when this worktree's container is running.
+    /// Checked per pipeline, so with the container stopped they run on the
+    /// host. User hooks always run on the host.
+    pub fn hook_container(&self, source: HookSource) -> Option<Container> {
+        if source != HookSource::Project {
+            return None;
+        }
+        super::container::running(self.repo, self.worktree_path)
+    }
+
     /// Get the commit generation config, merging project-specific settings.
     pub fn commit_generation(&self) -> worktrunk::config::CommitGenerationConfig {
         self.config.commit_generation(self.project_id().as_deref())
     }
 }
 
-/// Resolve the template variables for one command invocation.
-///
-/// The sole producer of [`TemplateContext`], which owns what happens to the
-/// result: expansion, the JSON a hook child reads on stdin, the `-v` table.
-///
-/// `scope` decides how much to resolve. [`VarScope::Referenced`] skips the git
-/// lookups behind vars the templates don't name (`var_commit` rev-parse,
-/// `var_default_branch` cold detection, `branch().upstream()`), and follows
-/// from who reads the finished context, not from what kind of command is
-/// running:
-///
-/// - **`Referenced`** when only the templates read it. A var they don't name
-///   would be computed and discarded. Aliases pass `referenced_vars_for_config`
-///   (extended via `alias_context_filter`); `wt switch --execute` passes
-///   `referenced_vars_for_templates` over its command and trailing args.
-/// - **`All`** when something reads keys the `{{ }}` templates never mention.
-///   Either the child receives the whole context as JSON on stdin and may pull
-///   keys out of it (e.g. via `jq`) — hook pipelines, `wt step for-each` — or
-///   the command's output *is* the variable listing, which filtering would
-///   narrow to what the body happens to reference: `wt step eval -v`, and the
-///   hook pipeline's own `format_hook_variables` table.
-///
-/// The template-preview paths (`render_hook_commands`, `wt config alias`) fit
-/// neither case and still pass `All`: they expand and print one line per
-/// configured command, so filtering would be correct but saves nothing an
-/// interactive display would notice.
+/// [`worktrunk::config::build_hook_context`] for `ctx`'s worktree and branch.
 pub fn build_hook_context(
     ctx: &CommandContext<'_>,
     extra_vars: &[(&str, &str)],
     scope: VarScope<'_>,
 ) -> Result<TemplateContext> {
-    let repo_root = ctx.repo.repo_path()?;
-    let repo_name = repo_root
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("unknown");
-
-    // Convert paths to POSIX format for Git Bash compatibility on Windows.
-    // This avoids shell escaping of `:` and `\` characters in Windows paths.
-    let worktree = to_posix_path(&ctx.worktree_path.to_string_lossy());
-    let worktree_name = ctx
-        .worktree_path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("unknown");
-
-    let repo_path = to_posix_path(&repo_root.to_string_lossy());
-
-    // Cheap vars (already in scope, no I/O) are populated unconditionally —
-    // skipping them saves no work and would just turn the verbose table's
-    // `(unused)` label into noise. Only the expensive blocks below
-    // (subprocesses, git config / remote lookups) consult `scope`.
-    let mut map = HashMap::new();
-    map.insert("repo".into(), repo_name.into());
-    map.insert("branch".into(), ctx.branch_or_head().into());
-    map.insert("worktree_name".into(), worktree_name.into());
-    map.insert("repo_path".into(), repo_path.clone());
-    map.insert("worktree_path".into(), worktree.clone());
-    // Deprecated aliases (kept for backward compatibility)
-    map.insert("main_worktree".into(), repo_name.into());
-    map.insert("repo_root".into(), repo_path);
-    map.insert("worktree".into(), worktree);
-
-    if let Some(parsed_remote) = ctx.repo.primary_remote_parsed_url() {
-        map.insert("owner".into(), parsed_remote.owner().to_string());
-        map.insert("remote_repo".into(), parsed_remote.repo().to_string());
-    }
-
-    // Default branch
-    if scope.wants("default_branch") {
-        let _span = Span::new("var_default_branch");
-        if let Some(default_branch) = ctx.repo.default_branch() {
-            map.insert("default_branch".into(), default_branch);
-        }
-    }
-
-    // Primary worktree path (where established files live)
-    if scope.wants("primary_worktree_path") || scope.wants("main_worktree_path") {
-        let _span = Span::new("var_primary_worktree");
-        if let Ok(Some(path)) = ctx.repo.primary_worktree() {
-            let path_str = to_posix_path(&path.to_string_lossy());
-            if scope.wants("primary_worktree_path") {
-                map.insert("primary_worktree_path".into(), path_str.clone());
-            }
-            // Deprecated alias
-            if scope.wants("main_worktree_path") {
-                map.insert("main_worktree_path".into(), path_str);
-            }
-        }
-    }
-
-    // Resolve commit from the Active branch, not HEAD at discovery path.
-    // This ensures {{ commit }} follows the Active branch even when the
-    // CommandContext points to a different worktree than where we're running.
-    // Detached HEAD (`ctx.branch == None`) must read HEAD from
-    // `ctx.worktree_path`, not the running worktree: `wt step for-each`
-    // iterates over sibling worktrees, and a sibling on detached HEAD has a
-    // different HEAD than the worktree `wt` runs in. Branched contexts go
-    // through `rev-parse <branch>`, which is repo-wide.
-    if scope.wants("commit") || scope.wants("short_commit") {
-        let _span = Span::new("var_commit");
-        let commit = match ctx.branch {
-            Some(branch) => ctx
-                .repo
-                .run_command(&["rev-parse", "--verify", "--end-of-options", branch])
-                .ok()
-                .map(|s| s.trim().to_owned()),
-            None => ctx
-                .repo
-                .worktree_at(ctx.worktree_path)
-                .head_sha()
-                .ok()
-                .flatten(),
-        };
-        if let Some(commit) = commit {
-            if scope.wants("short_commit")
-                && let Ok(short) = ctx.repo.short_sha(&commit)
-            {
-                map.insert("short_commit".into(), short);
-            }
-            if scope.wants("commit") {
-                map.insert("commit".into(), commit);
-            }
-        }
-    }
-
-    if scope.wants("remote") || scope.wants("remote_url") || scope.wants("upstream") {
-        let _span = Span::new("var_remote");
-        if let Ok(remote) = ctx.repo.primary_remote() {
-            if scope.wants("remote") {
-                map.insert("remote".into(), remote.to_string());
-            }
-            // Add remote URL for conditional hook execution (e.g., GitLab vs GitHub)
-            if scope.wants("remote_url")
-                && let Some(url) = ctx.repo.remote_url(&remote)
-            {
-                map.insert("remote_url".into(), url);
-            }
-            if scope.wants("upstream")
-                && let Some(branch) = ctx.branch
-                && let Ok(Some(upstream)) = ctx.repo.branch(branch).upstream()
-            {
-                map.insert("upstream".into(), upstream);
-            }
-        }
-    }
-
-    // Execution directory — always where the hook command runs, even when
-    // worktree_path points to an Active identity that doesn't exist on disk.
-    map.insert(
-        "cwd".into(),
-        to_posix_path(&ctx.worktree_path.to_string_lossy()),
-    );
-
-    // Caller-set bindings (e.g., merge target, switch base, alias args).
-    // Aliases pre-filter via `AliasOptions::parse`, hooks pass everything;
-    // either way the value is already computed, so insert unconditionally.
-    for (k, v) in extra_vars {
-        map.insert((*k).into(), (*v).into());
-    }
-
-    Ok(TemplateContext::from_vars(map))
+    worktrunk::config::build_hook_context(
+        ctx.repo,
+        ctx.worktree_path,
+        ctx.branch,
+        extra_vars,
+        scope,
+    )
 }
 
 /// Drain a sequence of command results, returning the first error.
@@ -522,6 +412,25 @@ fn run_concurrent_group(
     for (cmd, command_str) in cmds.iter().zip(&expanded) {
         announce_command(cmd, &fg_step.announce, command_str);
     }
+    // A sandbox that can't be built fails the whole group before any command
+    // starts: running some of it unconfined is exactly what the policy forbids.
+    let expanded: Vec<String> = match expanded
+        .into_iter()
+        .map(|command_str| {
+            sandboxed(
+                fg_step.sandbox.as_ref(),
+                fg_step.container.as_ref(),
+                command_str,
+                wt_path,
+            )
+        })
+        .collect::<Result<_>>()
+    {
+        Ok(expanded) => expanded,
+        Err(err) => {
+            return handle_command_error(err, &cmds[0], &fg_step.error_wrapper, failure_strategy);
+        }
+    };
 
     // Both alias tables and hook tables produce named commands (TOML keys
     // become `name`), so `cmd.name` is always `Some` here.
@@ -548,6 +457,7 @@ fn run_concurrent_group(
             working_dir: wt_path,
             context_json: &context_jsons[i],
             log_label: log_labels[i].as_deref(),
+            log_branch: cmds[i].log_branch(),
             directives,
             scrub_git_discovery,
         })
@@ -594,15 +504,24 @@ fn run_one_command(
     // terminal. Piping JSON into an interactive alias body steals the tty.
     let stdin_json = fg_step.pipe_stdin.then(|| cmd.context_json());
     let log_label = fg_step.announce.log_label(cmd);
-    let result = execute_shell_command(
+    let result = sandboxed(
+        fg_step.sandbox.as_ref(),
+        fg_step.container.as_ref(),
+        command_str,
         wt_path,
-        &command_str,
-        stdin_json.as_deref(),
-        log_label.as_deref(),
-        directives.clone(),
-        fg_step.redirect_stdout_to_stderr,
-        fg_step.announce.is_hook(),
-    );
+    )
+    .and_then(|command_str| {
+        execute_shell_command(
+            wt_path,
+            &command_str,
+            stdin_json.as_deref(),
+            log_label.as_deref(),
+            cmd.log_branch(),
+            directives.clone(),
+            fg_step.redirect_stdout_to_stderr,
+            fg_step.announce.is_hook(),
+        )
+    });
 
     match result {
         Ok(()) => Ok(()),
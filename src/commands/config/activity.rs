@@ -0,0 +1,213 @@
+//! `wt config state activity` — agent session records.
+//!
+//! Plugins report through `set` and `end`; `list` and `history` read
+//! [`worktrunk::activity`] back for people and scripts. `wt list` reads the
+//! same records for its Agent column.
+
+use std::io::{IsTerminal, Read};
+
+use color_print::cformat;
+use worktrunk::activity::{Activity, ActivityEvent, AgentState, Report, Session};
+use worktrunk::git::Repository;
+use worktrunk::styling::{eprintln, info_message, println, success_message};
+use worktrunk::utils::epoch_now;
+
+use crate::cli::{ActivitySessionArgs, SwitchFormat};
+use crate::display::{format_duration_short, format_relative_time_short};
+use crate::output::print_json;
+
+/// Filters for `wt config state activity list`, straight from the CLI.
+pub struct ActivityListOptions {
+    pub states: Vec<AgentState>,
+    pub older_than: Option<String>,
+    pub branch: Option<String>,
+}
+
+/// The session a `set`/`end` report names: `--session`, the `session_id` of
+/// the hook payload on stdin (`--stdin`), or none.
+fn session_id(args: &ActivitySessionArgs) -> anyhow::Result<Option<String>> {
+    if !args.stdin {
+        return Ok(args.session.clone());
+    }
+    anyhow::ensure!(
+        !std::io::stdin().is_terminal(),
+        cformat!("<bold>--stdin</> expects a hook's JSON payload on stdin")
+    );
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input)?;
+    let payload: serde_json::Value = serde_json::from_str(&input)
+        .map_err(|e| anyhow::anyhow!("Hook payload on stdin is not JSON: {e}"))?;
+    // A payload without one still reports, as the tool's single session in
+    // this worktree.
+    Ok(payload
+        .get("session_id")
+        .and_then(|id| id.as_str())
+        .filter(|id| !id.is_empty())
+        .map(str::to_string))
+}
+
+/// Handle `wt config state activity set`.
+///
+/// Silent on success: plugins call this from every agent hook, and hook
+/// output can surface in the agent's transcript.
+pub fn handle_activity_set(state: AgentState, args: ActivitySessionArgs) -> anyhow::Result<()> {
+    let session_id = session_id(&args)?;
+    let repo = Repository::current()?;
+    let worktree = repo.current_worktree();
+    let path = worktree.root()?;
+    let branch = worktree.branch()?;
+    Activity::for_repo(&repo).record(
+        Report {
+            tool: &args.tool,
+            session_id: session_id.as_deref(),
+            worktree: &path,
+            branch: branch.as_deref(),
+            state,
+        },
+        epoch_now(),
+    );
+    Ok(())
+}
+
+/// Handle `wt config state activity end`. Silent, like `set`.
+pub fn handle_activity_end(args: ActivitySessionArgs) -> anyhow::Result<()> {
+    let session_id = session_id(&args)?;
+    let repo = Repository::current()?;
+    let path = repo.current_worktree().root()?;
+    Activity::for_repo(&repo).end(&args.tool, session_id.as_deref(), &path, epoch_now())?;
+    Ok(())
+}
+
+/// Handle `wt config state activity list`.
+pub fn handle_activity_list(
+    options: ActivityListOptions,
+    format: SwitchFormat,
+) -> anyhow::Result<()> {
+    let older_than = options
+        .older_than
+        .as_deref()
+        .map(|value| {
+            humantime::parse_duration(value).map_err(|_| {
+                anyhow::anyhow!(cformat!(
+                    "Invalid <bold>--older-than</> value <bold>{value}</>; expected a duration (<bold>10m</>, <bold>2h</>)"
+                ))
+            })
+        })
+        .transpose()?;
+
+    let repo = Repository::current()?;
+    let now = epoch_now();
+    let sessions: Vec<Session> = Activity::for_repo(&repo)
+        .sessions()
+        .into_iter()
+        .filter(|s| options.states.is_empty() || options.states.contains(&s.state))
+        .filter(|s| older_than.is_none_or(|min| now.saturating_sub(s.state_since) >= min.as_secs()))
+        .filter(|s| {
+            options
+                .branch
+                .as_deref()
+                .is_none_or(|b| s.branch.as_deref() == Some(b))
+        })
+        .collect();
+
+    if format == SwitchFormat::Json {
+        print_json(&sessions)?;
+    } else if sessions.is_empty() {
+        eprintln!("{}", info_message("No matching agent sessions"));
+    } else {
+        println!("{}", render_sessions(&sessions, now).trim_end());
+    }
+    Ok(())
+}
+
+/// Handle `wt config state activity history`.
+pub fn handle_activity_history(
+    branch: Option<String>,
+    limit: Option<usize>,
+    format: SwitchFormat,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let mut events: Vec<ActivityEvent> = Activity::for_repo(&repo)
+        .history()
+        .into_iter()
+        .filter(|e| {
+            branch
+                .as_deref()
+                .is_none_or(|b| e.branch.as_deref() == Some(b))
+        })
+        .collect();
+    if let Some(limit) = limit {
+        events.drain(..events.len().saturating_sub(limit));
+    }
+
+    if format == SwitchFormat::Json {
+        print_json(&events)?;
+    } else if events.is_empty() {
+        eprintln!("{}", info_message("No matching agent activity"));
+    } else {
+        println!("{}", render_history(&events).trim_end());
+    }
+    Ok(())
+}
+
+/// Handle `wt config state activity clear`.
+pub fn handle_activity_clear() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    if Activity::for_repo(&repo).clear()? == 0 {
+        eprintln!("{}", info_message("No agent activity to clear"));
+    } else {
+        eprintln!("{}", success_message("Cleared agent activity"));
+    }
+    Ok(())
+}
+
+/// `<symbol> <state>` for a table cell.
+fn state_cell(state: AgentState) -> String {
+    format!("{} {state}", state.symbol())
+}
+
+/// One row per session: tool, session, branch, state, time in state, last
+/// activity.
+pub(super) fn render_sessions(sessions: &[Session], now: u64) -> String {
+    let rows: Vec<Vec<String>> = sessions
+        .iter()
+        .map(|s| {
+            vec![
+                s.tool.clone(),
+                s.session_id.clone().unwrap_or_default(),
+                s.branch.clone().unwrap_or_default(),
+                state_cell(s.state),
+                format_duration_short(now.saturating_sub(s.state_since)),
+                format_relative_time_short(s.last_activity_at as i64),
+            ]
+        })
+        .collect();
+    crate::md_help::render_data_table(
+        &["Tool", "Session", "Branch", "State", "For", "Last active"],
+        &rows,
+    )
+}
+
+/// One row per event: age, tool, branch, the state entered (or `ended`), and
+/// the state left with the time spent in it.
+fn render_history(events: &[ActivityEvent]) -> String {
+    let rows: Vec<Vec<String>> = events
+        .iter()
+        .map(|e| {
+            let previous = match (e.from, e.secs) {
+                (Some(from), Some(secs)) => {
+                    format!("after {} {from}", format_duration_short(secs))
+                }
+                _ => "started".to_string(),
+            };
+            vec![
+                format_relative_time_short(e.ts as i64),
+                e.tool.clone(),
+                e.branch.clone().unwrap_or_default(),
+                e.state.map_or_else(|| "ended".to_string(), state_cell),
+                previous,
+            ]
+        })
+        .collect();
+    crate::md_help::render_data_table(&["Age", "Tool", "Branch", "State", "Previous"], &rows)
+}
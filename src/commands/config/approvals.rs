@@ -10,7 +10,7 @@ use anyhow::Context;
 use color_print::cformat;
 use strum::IntoEnumIterator;
 use worktrunk::HookType;
-use worktrunk::config::{Approvals, ProjectConfig, require_approvals_path};
+use worktrunk::config::{ApprovalRecord, Approvals, ProjectConfig, require_approvals_path};
 use worktrunk::git::{GitError, Repository};
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
@@ -19,7 +19,9 @@ use worktrunk::styling::{
 };
 
 use crate::cli::SwitchFormat;
-use crate::commands::command_approval::{announce_batch_approval, prompt_for_batch_approval};
+use crate::commands::command_approval::{
+    announce_batch_approval, approval_records, prompt_for_batch_approval,
+};
 use crate::commands::project_config::{
     ApprovableCommand, collect_commands_for_aliases, collect_commands_for_hooks,
 };
@@ -37,6 +39,9 @@ fn collect_approvable_commands(project_config: &ProjectConfig) -> Vec<Approvable
             fragment.to_string(),
         ));
     }
+    if project_config.container.is_configured() {
+        commands.push(ApprovableCommand::container(&project_config.container));
+    }
     commands
 }
 
@@ -55,14 +60,28 @@ fn require_project_config(repo: &Repository) -> anyhow::Result<ProjectConfig> {
 /// One project command and whether its template is currently approved.
 #[derive(serde::Serialize)]
 struct JsonApprovalCommand<'a> {
-    /// `post-start`, `pre-merge`, `alias`, `commit-template-append`, …
+    /// `post-start`, `pre-merge`, `alias`, `commit-template-append`,
+    /// `container`, …
     phase: String,
+    /// What an approval of this command covers: the hook type,
+    /// `alias:<name>`, or `commit-template-append`.
+    scope: String,
     /// The command's name within its phase; absent for an unnamed command
     /// and for the commit-template fragment.
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<&'a str>,
     template: &'a str,
     approved: bool,
+    /// Provenance of the approving record; absent when unapproved or
+    /// approved by a bare `approved-commands` entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    approved_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    approver: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    host: Option<&'a str>,
 }
 
 /// Structured form of `wt config approvals list`.
@@ -80,6 +99,11 @@ struct JsonApprovals<'a> {
     /// Templates approved earlier but since edited or removed from the
     /// project config.
     stale: Vec<&'a str>,
+    /// Templates whose approval has expired.
+    expired: Vec<&'a str>,
+    /// Templates still approved, but approved from a different version of
+    /// the project config.
+    config_changed: Vec<&'a str>,
 }
 
 /// Handle `wt config approvals list` - show approval status for all project commands
@@ -100,15 +124,31 @@ pub fn list_approvals(format: SwitchFormat) -> anyhow::Result<()> {
         .map(|cmd| cmd.command.template.as_str())
         .collect();
     let stale = approvals.stale_approvals(&project_id, &templates);
+    let expired = approvals.expired_approvals(&project_id);
+    let config_hash = repo.project_config_hash()?;
+    let config_changed =
+        approvals.config_changed_approvals(&project_id, &templates, config_hash.as_deref());
+    let is_approved = |cmd: &ApprovableCommand| {
+        approvals.is_command_approved(&project_id, &cmd.command.template, &cmd.scope())
+    };
 
     if format == SwitchFormat::Json {
         let json_commands: Vec<_> = commands
             .iter()
-            .map(|cmd| JsonApprovalCommand {
-                phase: cmd.phase.to_string(),
-                name: cmd.command.name.as_deref(),
-                template: &cmd.command.template,
-                approved: approvals.is_command_approved(&project_id, &cmd.command.template),
+            .map(|cmd| {
+                let scope = cmd.scope();
+                let record = approvals.approval_record(&project_id, &cmd.command.template, &scope);
+                JsonApprovalCommand {
+                    phase: cmd.phase.to_string(),
+                    scope,
+                    name: cmd.command.name.as_deref(),
+                    template: &cmd.command.template,
+                    approved: is_approved(cmd),
+                    approved_at: record.and_then(|r| r.approved_at),
+                    expires_at: record.and_then(|r| r.expires_at),
+                    approver: record.and_then(|r| r.approver.as_deref()),
+                    host: record.and_then(|r| r.host.as_deref()),
+                }
             })
             .collect();
         // An empty command set is not the same answer as an approved one, so
@@ -124,17 +164,18 @@ pub fn list_approvals(format: SwitchFormat) -> anyhow::Result<()> {
             state,
             commands: json_commands,
             stale,
+            expired: expired.iter().map(|r| r.command.as_str()).collect(),
+            config_changed: config_changed.iter().map(|r| r.command.as_str()).collect(),
         });
     }
 
-    if commands.is_empty() && stale.is_empty() {
+    if commands.is_empty() && stale.is_empty() && expired.is_empty() {
         eprintln!("{}", info_message("No commands configured in project"));
         return Ok(());
     }
 
-    let (approved, unapproved): (Vec<_>, Vec<_>) = commands
-        .iter()
-        .partition(|cmd| approvals.is_command_approved(&project_id, &cmd.command.template));
+    let (approved, unapproved): (Vec<_>, Vec<_>) =
+        commands.iter().partition(|cmd| is_approved(cmd));
 
     let mut out = String::new();
     let render_section =
@@ -166,12 +207,31 @@ pub fn list_approvals(format: SwitchFormat) -> anyhow::Result<()> {
             "{}",
             warning_message("Approved commands no longer in project config:")
         )?;
-        // Stale approvals are bare strings — the phase isn't recorded in
-        // approvals.toml — so bash formatting is the best default even though
+        // Stale approvals are listed by template alone — a bare entry
+        // records no scope — so bash formatting is the best default even though
         // a stale commit-template fragment is prose.
         for command in &stale {
             writeln!(out, "{}", format_bash_with_gutter(command))?;
         }
+    }
+    let mut render_records = |title: &str, records: &[&ApprovalRecord]| {
+        if records.is_empty() {
+            return anyhow::Ok(());
+        }
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        writeln!(out, "{}", warning_message(title))?;
+        for record in records {
+            writeln!(out, "{}", hint_message(record_provenance(record)))?;
+            writeln!(out, "{}", format_bash_with_gutter(&record.command))?;
+        }
+        Ok(())
+    };
+    render_records("Approved from a different project config:", &config_changed)?;
+    render_records("Expired approvals:", &expired)?;
+
+    if !stale.is_empty() || !expired.is_empty() || !config_changed.is_empty() {
         writeln!(
             out,
             "{}",
@@ -188,6 +248,19 @@ pub fn list_approvals(format: SwitchFormat) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// `post-start, approved by alice@example.com on alice-laptop` — the scope
+/// and whichever provenance the record carries.
+fn record_provenance(record: &ApprovalRecord) -> String {
+    let mut line = record.scope.as_deref().unwrap_or("any scope").to_string();
+    if let Some(approver) = &record.approver {
+        line.push_str(&format!(", approved by {approver}"));
+    }
+    if let Some(host) = &record.host {
+        line.push_str(&format!(" on {host}"));
+    }
+    line
+}
+
 /// Handle `wt config approvals add` command - approve all hook and alias commands in the project
 ///
 /// `yes` skips the review prompt, which is what makes the command usable
@@ -196,7 +269,15 @@ pub fn list_approvals(format: SwitchFormat) -> anyhow::Result<()> {
 /// them. Templates edited since an earlier approval are re-approved without
 /// comment — read `wt config approvals list --format=json`'s `stale` first to
 /// see them.
-pub fn add_approvals(show_all: bool, yes: bool) -> anyhow::Result<()> {
+///
+/// `expires_in` (a duration like `7d`) makes the recorded approvals lapse.
+pub fn add_approvals(show_all: bool, expires_in: Option<&str>, yes: bool) -> anyhow::Result<()> {
+    let expires_at = expires_in
+        .map(|d| {
+            let duration = humantime::parse_duration(d).context("Invalid --expires-in duration")?;
+            anyhow::Ok(worktrunk::utils::epoch_now() + duration.as_secs())
+        })
+        .transpose()?;
     let repo = Repository::current()?;
     let project_id = repo.project_identifier()?;
     let mut approvals = Approvals::load().context("Failed to load approvals")?;
@@ -213,7 +294,9 @@ pub fn add_approvals(show_all: bool, yes: bool) -> anyhow::Result<()> {
     let commands_to_approve = if !show_all {
         let unapproved: Vec<_> = commands
             .into_iter()
-            .filter(|cmd| !approvals.is_command_approved(&project_id, &cmd.command.template))
+            .filter(|cmd| {
+                !approvals.is_command_approved(&project_id, &cmd.command.template, &cmd.scope())
+            })
             .collect();
 
         if unapproved.is_empty() {
@@ -244,12 +327,12 @@ pub fn add_approvals(show_all: bool, yes: bool) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    let templates: Vec<String> = commands_to_approve
-        .iter()
-        .map(|cmd| cmd.command.template.clone())
-        .collect();
     approvals
-        .approve_commands(project_id, templates, &require_approvals_path()?)
+        .approve_commands(
+            project_id,
+            approval_records(&batch, expires_at),
+            &require_approvals_path()?,
+        )
         .context("Failed to save command approval")?;
 
     eprintln!("{}", success_message("Commands approved & saved to config"));
@@ -261,9 +344,10 @@ pub fn clear_approvals(global: bool, stale: bool) -> anyhow::Result<()> {
     let mut approvals = Approvals::load().context("Failed to load approvals")?;
 
     if stale {
-        // Clear only approvals whose commands left the project config. A
-        // missing config is an error (matching `add`), not "everything is
-        // stale": approvals are keyed repo-wide while the config is resolved
+        // Clear only stale approvals: commands that left the project config,
+        // expired records, and records from a different config. A missing
+        // config is an error (matching `add`), not "everything is stale":
+        // approvals are keyed repo-wide while the config is resolved
         // per-worktree, so a branch that merely lacks the file must not wipe
         // the whole repo's approvals. Clearing everything is `clear`'s job.
         let repo = Repository::current()?;
@@ -275,8 +359,14 @@ pub fn clear_approvals(global: bool, stale: bool) -> anyhow::Result<()> {
             .map(|cmd| cmd.command.template.as_str())
             .collect();
 
+        let config_hash = repo.project_config_hash()?;
         let removed = approvals
-            .revoke_stale(&project_id, &templates, &require_approvals_path()?)
+            .revoke_stale(
+                &project_id,
+                &templates,
+                config_hash.as_deref(),
+                &require_approvals_path()?,
+            )
             .context("Failed to clear stale approvals")?;
 
         if removed.is_empty() {
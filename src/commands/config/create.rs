@@ -5,7 +5,7 @@
 use anyhow::Context;
 use color_print::cformat;
 use std::path::PathBuf;
-use worktrunk::config::{ConfigFileKind, require_config_path};
+use worktrunk::config::{ConfigFileKind, require_config_path, schema_directive};
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{eprintln, hint_message, info_message, success_message};
@@ -124,8 +124,13 @@ fn create_config_file(
         std::fs::create_dir_all(parent).context("Failed to create config directory")?;
     }
 
-    // Write the example config with all values commented out
-    let commented_config = comment_out_config(content);
+    // Write the example config with all values commented out, under a
+    // `#:schema` directive so taplo-based editors complete and validate keys
+    let commented_config = format!(
+        "{}\n\n{}",
+        schema_directive(kind),
+        comment_out_config(content)
+    );
     worktrunk::utils::write_atomically(&path, &commented_config)
         .context("Failed to write config file")?;
 
@@ -0,0 +1,434 @@
+//! `wt config state logs history` — query and summarize `commands.jsonl`.
+//!
+//! Reads every generation of the command log through
+//! [`worktrunk::command_log::read_history`], filters, then lists the records,
+//! aggregates them per label (`--stats`), or tails the live file (`--follow`).
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{Seek, SeekFrom};
+use std::time::Duration;
+
+use anyhow::Context;
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::command_log::{HistoryEntry, LOG_FILE_NAME, read_appended, read_history};
+use worktrunk::git::Repository;
+use worktrunk::styling::{
+    ERROR_SYMBOL, INFO_SYMBOL, SUCCESS_SYMBOL, eprintln, info_message, println, visual_width,
+};
+use worktrunk::utils::epoch_now;
+
+use crate::cli::SwitchFormat;
+use crate::display::format_relative_time_short;
+use crate::output::print_json;
+
+/// How often `--follow` polls the live log for new records.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Options for `wt config state logs history`, straight from the CLI.
+pub struct HistoryOptions {
+    pub branch: Option<String>,
+    pub hook: Option<HookType>,
+    pub label: Option<String>,
+    pub failed: bool,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub limit: Option<usize>,
+    pub stats: bool,
+    pub follow: bool,
+}
+
+/// The record filters, with `--since`/`--until` resolved to timestamps.
+struct Filter {
+    branch: Option<String>,
+    hook: Option<HookType>,
+    label: Option<String>,
+    failed: bool,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+}
+
+impl Filter {
+    fn matches(&self, entry: &HistoryEntry) -> bool {
+        if self
+            .branch
+            .as_deref()
+            .is_some_and(|b| entry.branch.as_deref() != Some(b))
+        {
+            return false;
+        }
+        if self.hook.is_some() && entry.hook_type() != self.hook {
+            return false;
+        }
+        if self
+            .label
+            .as_deref()
+            .is_some_and(|text| !entry.label.contains(text))
+        {
+            return false;
+        }
+        if self.failed && !entry.failed() {
+            return false;
+        }
+        if self.since.is_some() || self.until.is_some() {
+            let Some(time) = entry.time() else {
+                return false;
+            };
+            if self.since.is_some_and(|since| time < since)
+                || self.until.is_some_and(|until| time >= until)
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Resolve a `--since`/`--until` value: a duration ago (`2h`), a date (local
+/// midnight), or an RFC 3339 timestamp.
+fn parse_time_bound(value: &str, flag: &str, now: DateTime<Utc>) -> anyhow::Result<DateTime<Utc>> {
+    if let Ok(ago) = humantime::parse_duration(value) {
+        return Ok(now - chrono::Duration::from_std(ago)?);
+    }
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        && let Some(midnight) = Local
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+            .earliest()
+    {
+        return Ok(midnight.with_timezone(&Utc));
+    }
+    anyhow::bail!(cformat!(
+        "Invalid <bold>--{flag}</> value <bold>{value}</>; expected a duration (<bold>2h</>, <bold>7d</>), a date (<bold>2026-03-01</>), or an RFC 3339 timestamp"
+    ))
+}
+
+/// `wt config state logs history` entry point.
+pub fn handle_logs_history(options: HistoryOptions, format: SwitchFormat) -> anyhow::Result<()> {
+    let now = DateTime::from_timestamp(epoch_now() as i64, 0).unwrap_or_else(Utc::now);
+    let filter = Filter {
+        since: options
+            .since
+            .as_deref()
+            .map(|v| parse_time_bound(v, "since", now))
+            .transpose()?,
+        until: options
+            .until
+            .as_deref()
+            .map(|v| parse_time_bound(v, "until", now))
+            .transpose()?,
+        branch: options.branch,
+        hook: options.hook,
+        label: options.label,
+        failed: options.failed,
+    };
+
+    let repo = Repository::current()?;
+    let log_dir = repo.wt_logs_dir();
+    let mut entries: Vec<HistoryEntry> = read_history(&log_dir)
+        .into_iter()
+        .filter(|e| filter.matches(e))
+        .collect();
+
+    if options.stats {
+        let stats = aggregate(&entries);
+        if format == SwitchFormat::Json {
+            print_json(&stats)?;
+        } else if stats.is_empty() {
+            eprintln!("{}", info_message("No matching commands"));
+        } else {
+            println!("{}", render_stats(&stats).trim_end());
+        }
+        return Ok(());
+    }
+
+    if let Some(limit) = options.limit {
+        entries.drain(..entries.len().saturating_sub(limit));
+    }
+
+    if options.follow {
+        for entry in &entries {
+            print_record(entry, format)?;
+        }
+        return follow(&log_dir.join(LOG_FILE_NAME), &filter, format);
+    }
+
+    if format == SwitchFormat::Json {
+        print_json(&entries)?;
+    } else if entries.is_empty() {
+        eprintln!("{}", info_message("No matching commands"));
+    } else {
+        println!("{}", render_entries(&entries).trim_end());
+    }
+    Ok(())
+}
+
+/// Print one record as it arrives under `--follow`: a text row, or one
+/// compact JSON object per line.
+fn print_record(entry: &HistoryEntry, format: SwitchFormat) -> anyhow::Result<()> {
+    if format == SwitchFormat::Json {
+        println!("{}", serde_json::to_string(entry)?);
+    } else {
+        println!("{}", render_entries(std::slice::from_ref(entry)).trim_end());
+    }
+    Ok(())
+}
+
+/// Poll the live log for appended records until interrupted. Starts from the
+/// current end of the file; a rotation (the file shrinking or being replaced)
+/// restarts from the top of the new file.
+fn follow(path: &std::path::Path, filter: &Filter, format: SwitchFormat) -> anyhow::Result<()> {
+    let mut file: Option<File> = None;
+    let mut pending = String::new();
+    let mut position = std::fs::metadata(path).map_or(0, |m| m.len());
+    loop {
+        let len = std::fs::metadata(path).map(|m| m.len()).ok();
+        if len.is_some_and(|len| len < position) || (file.is_none() && len.is_some()) {
+            let mut opened =
+                File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+            if len.is_some_and(|len| len < position) {
+                position = 0;
+                pending.clear();
+            }
+            opened.seek(SeekFrom::Start(position))?;
+            file = Some(opened);
+        }
+        if let Some(file) = file.as_mut() {
+            for entry in read_appended(file, &mut pending) {
+                if filter.matches(&entry) {
+                    print_record(&entry, format)?;
+                }
+            }
+            position = file.stream_position()?;
+        }
+        std::thread::sleep(FOLLOW_POLL_INTERVAL);
+    }
+}
+
+/// One row per record: status, age, label, branch, duration, command.
+fn render_entries(entries: &[HistoryEntry]) -> String {
+    let rows: Vec<[String; 6]> = entries
+        .iter()
+        .map(|e| {
+            let status = match e.exit {
+                Some(0) => SUCCESS_SYMBOL.to_string(),
+                Some(code) => cformat!("{ERROR_SYMBOL} <red>{code}</>"),
+                None => INFO_SYMBOL.to_string(),
+            };
+            let age = e
+                .time()
+                .map(|t| format_relative_time_short(t.timestamp()))
+                .unwrap_or_default();
+            // Multi-line commands show their first line.
+            let cmd = match e.cmd.split_once('\n') {
+                Some((first, _)) => format!("{first} …"),
+                None => e.cmd.clone(),
+            };
+            [
+                status,
+                cformat!("<dim>{age}</>"),
+                cformat!("<bold>{}</>", e.label),
+                e.branch.clone().unwrap_or_default(),
+                e.dur_ms.map(format_ms).unwrap_or_default(),
+                cformat!("<dim>{cmd}</>"),
+            ]
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..5)
+        .map(|col| {
+            rows.iter()
+                .map(|row| visual_width(&row[col]))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut out = String::new();
+    for row in &rows {
+        let mut line = String::new();
+        for (col, cell) in row.iter().enumerate() {
+            let pad = " ".repeat(widths.get(col).map_or(0, |w| w - visual_width(cell)));
+            // Right-align the duration so the units line up.
+            if col == 4 {
+                line.push_str(&pad);
+                line.push_str(cell);
+            } else {
+                line.push_str(cell);
+                line.push_str(&pad);
+            }
+            line.push_str("  ");
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// Aggregate durations for one command label.
+#[derive(Debug, serde::Serialize)]
+struct LabelStats {
+    label: String,
+    runs: usize,
+    failed: usize,
+    p50_ms: Option<u64>,
+    p95_ms: Option<u64>,
+    max_ms: Option<u64>,
+    total_ms: u64,
+}
+
+/// Per-label aggregates, slowest total first. Durations come from records
+/// that have one; background commands count as runs only.
+fn aggregate(entries: &[HistoryEntry]) -> Vec<LabelStats> {
+    let mut by_label: BTreeMap<&str, Vec<&HistoryEntry>> = BTreeMap::new();
+    for entry in entries {
+        by_label.entry(&entry.label).or_default().push(entry);
+    }
+    let mut stats: Vec<LabelStats> = by_label
+        .into_iter()
+        .map(|(label, runs)| {
+            let mut durations: Vec<u64> = runs.iter().filter_map(|e| e.dur_ms).collect();
+            durations.sort_unstable();
+            LabelStats {
+                label: label.to_string(),
+                runs: runs.len(),
+                failed: runs.iter().filter(|e| e.failed()).count(),
+                p50_ms: percentile(&durations, 50),
+                p95_ms: percentile(&durations, 95),
+                max_ms: durations.last().copied(),
+                total_ms: durations.iter().sum(),
+            }
+        })
+        .collect();
+    stats.sort_by(|a, b| b.total_ms.cmp(&a.total_ms).then(a.label.cmp(&b.label)));
+    stats
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[u64], pct: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+fn render_stats(stats: &[LabelStats]) -> String {
+    let dur = |ms: Option<u64>| ms.map(format_ms).unwrap_or_default();
+    let rows: Vec<Vec<String>> = stats
+        .iter()
+        .map(|s| {
+            vec![
+                s.label.clone(),
+                s.runs.to_string(),
+                s.failed.to_string(),
+                dur(s.p50_ms),
+                dur(s.p95_ms),
+                dur(s.max_ms),
+            ]
+        })
+        .collect();
+    crate::md_help::render_data_table(&["Command", "Runs", "Failed", "p50", "p95", "Max"], &rows)
+}
+
+/// `850ms`, `12.3s`, `4m 05s`.
+fn format_ms(ms: u64) -> String {
+    match ms {
+        0..1_000 => format!("{ms}ms"),
+        1_000..60_000 => format!("{:.1}s", ms as f64 / 1_000.0),
+        _ => format!("{}m {:02}s", ms / 60_000, ms % 60_000 / 1_000),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        label: &str,
+        branch: Option<&str>,
+        exit: Option<i32>,
+        dur_ms: Option<u64>,
+    ) -> HistoryEntry {
+        HistoryEntry {
+            ts: "2026-03-01T12:00:00Z".to_string(),
+            wt: "wt merge".to_string(),
+            label: label.to_string(),
+            branch: branch.map(str::to_string),
+            cmd: "true".to_string(),
+            exit,
+            dur_ms,
+        }
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let values: Vec<u64> = (1..=20).collect();
+        assert_eq!(percentile(&values, 50), Some(10));
+        assert_eq!(percentile(&values, 95), Some(19));
+        assert_eq!(percentile(&[7], 95), Some(7));
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn test_aggregate_per_label() {
+        let entries = [
+            entry("pre-merge user:lint", Some("a"), Some(0), Some(100)),
+            entry("pre-merge user:lint", Some("b"), Some(1), Some(300)),
+            entry("pre-merge user:test", Some("a"), Some(0), Some(5_000)),
+            entry("post-start user:server", Some("a"), None, None),
+        ];
+        let stats = aggregate(&entries);
+        let labels: Vec<_> = stats.iter().map(|s| s.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            [
+                "pre-merge user:test",
+                "pre-merge user:lint",
+                "post-start user:server"
+            ]
+        );
+        assert_eq!(stats[1].runs, 2);
+        assert_eq!(stats[1].failed, 1);
+        assert_eq!(stats[1].p50_ms, Some(100));
+        assert_eq!(stats[1].p95_ms, Some(300));
+        assert_eq!(stats[2].p50_ms, None);
+    }
+
+    #[test]
+    fn test_filter() {
+        let now = DateTime::parse_from_rfc3339("2026-03-01T13:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let filter = Filter {
+            branch: Some("a".to_string()),
+            hook: Some(HookType::PreMerge),
+            label: Some("lint".to_string()),
+            failed: false,
+            since: Some(parse_time_bound("2h", "since", now).unwrap()),
+            until: Some(parse_time_bound("2026-03-01T12:30:00Z", "until", now).unwrap()),
+        };
+        assert!(filter.matches(&entry("pre-merge user:lint", Some("a"), Some(0), Some(1))));
+        assert!(!filter.matches(&entry("pre-merge user:lint", Some("b"), Some(0), Some(1))));
+        assert!(!filter.matches(&entry("post-merge user:lint", Some("a"), Some(0), Some(1))));
+        assert!(!filter.matches(&entry("pre-merge user:test", Some("a"), Some(0), Some(1))));
+
+        let late = Filter {
+            since: Some(parse_time_bound("30m", "since", now).unwrap()),
+            ..filter
+        };
+        assert!(!late.matches(&entry("pre-merge user:lint", Some("a"), Some(0), Some(1))));
+
+        assert!(parse_time_bound("yesterday", "since", now).is_err());
+    }
+
+    #[test]
+    fn test_format_ms() {
+        assert_eq!(format_ms(850), "850ms");
+        assert_eq!(format_ms(12_340), "12.3s");
+        assert_eq!(format_ms(245_000), "4m 05s");
+    }
+}
@@ -2,34 +2,47 @@
 //!
 //! Commands for managing user config, project config, state, and hints.
 
+mod activity;
 mod alias;
 mod approvals;
 mod codex;
 mod create;
 mod hints;
+mod history;
 pub mod opencode;
 mod plugins;
+mod schema;
 mod show;
 mod state;
 mod update;
+mod validate;
 
 // Re-export public functions
+pub use activity::{
+    ActivityListOptions, handle_activity_clear, handle_activity_end, handle_activity_history,
+    handle_activity_list, handle_activity_set,
+};
 pub use alias::{handle_alias_dry_run, handle_alias_show};
 pub use approvals::{add_approvals, clear_approvals, list_approvals};
 pub use codex::{handle_codex_install, handle_codex_uninstall};
 pub use create::handle_config_create;
 pub use hints::{handle_hints_clear, handle_hints_get};
+pub use history::{HistoryOptions, handle_logs_history};
 pub use opencode::{handle_opencode_install, handle_opencode_uninstall};
 pub use plugins::{
     handle_claude_install, handle_claude_install_statusline, handle_claude_uninstall,
 };
+pub use schema::handle_config_schema;
 pub use show::handle_config_show;
+pub(crate) use state::validate_vars_key;
 pub use state::{
-    handle_cache_clear, handle_cache_get, handle_logs_list, handle_logs_profile,
-    handle_state_clear, handle_state_clear_all, handle_state_get, handle_state_set,
-    handle_state_show, handle_vars_clear, handle_vars_get, handle_vars_list, handle_vars_set,
+    handle_cache_clear, handle_cache_get, handle_logs_export, handle_logs_list,
+    handle_logs_profile, handle_state_clear, handle_state_clear_all, handle_state_get,
+    handle_state_set, handle_state_show, handle_store_gc, handle_store_get, handle_vars_clear,
+    handle_vars_get, handle_vars_list, handle_vars_set,
 };
 pub use update::handle_config_update;
+pub use validate::handle_config_validate;
 
 /// Run a plugin-CLI command (`claude` / `codex`), surfacing a non-zero exit
 /// as a typed [`worktrunk::git::CommandError`].
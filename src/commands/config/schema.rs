@@ -0,0 +1,17 @@
+//! Config schema command.
+//!
+//! Prints the published JSON Schema for user or project config.
+
+use worktrunk::config::{ConfigFileKind, config_schema_document};
+
+use crate::output::print_json;
+
+/// Handle the `wt config schema` command.
+pub fn handle_config_schema(project: bool) -> anyhow::Result<()> {
+    let kind = if project {
+        ConfigFileKind::Project
+    } else {
+        ConfigFileKind::User
+    };
+    print_json(&config_schema_document(kind))
+}
@@ -984,6 +984,8 @@ fn render_verify_wrapper_hint(
     }
     let verify_cmd = match shell {
         Shell::PowerShell => format!("Get-Command {cmd}"),
+        Shell::Xonsh => format!("which {cmd}"),
+        Shell::Elvish => format!("put ${cmd}~"),
         _ => format!("type {cmd}"),
     };
     let hint = hint_message(cformat!(
@@ -18,6 +18,7 @@
 //! - Default branch override (git config `worktrunk.default_branch.*`)
 //! - Branch markers (git config `worktrunk.state.<branch>.marker`)
 //! - Vars (git config `worktrunk.state.<branch>.vars.*`)
+//! - Agent activity (`.git/wt/activity/`)
 //! - Logs (`.git/wt/logs/`)
 //! - Trash (`.git/wt/trash/`)
 //!
@@ -29,12 +30,19 @@
 //! - CI status cache (`.git/wt/cache/ci-status/`, plus the PR-number width
 //!   ratchet in `.git/wt/cache/pr-number/`)
 //! - Summary cache (`.git/wt/cache/summary/`)
+//! - Review cache (`.git/wt/cache/review/`)
 //! - Git commands cache (`.git/wt/cache/{merge-tree-conflicts,is-ancestor,picker-preview,…}/`)
 //!   — one user-facing category covering every SHA-keyed disk cache, even
 //!   when implementation lives in different modules (`sha_cache` for parsed
 //!   results, `commands::picker::preview_cache` for rendered previews)
 //! - Hints (git config `worktrunk.hints.*`)
 //!
+//! The shared file store (`.git/wt/store/`) fits neither bucket: it is
+//! rebuilt by the next `wt step copy-ignored --store`, but dropping it costs
+//! the worktrees already linking it their deduplication, so `cache clear`
+//! leaves it alone. `state clear` removes it (no prompt needed — worktrees keep
+//! their linked files) and `wt config state store gc` trims it.
+//!
 //! Each category has a `clear_*_reported` helper that clears it and prints its
 //! message; `handle_state_clear_all` composes all of them and
 //! `handle_cache_clear` composes the regenerable subset, so the two entry
@@ -71,13 +79,19 @@
 
 use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use crate::commands::picker::preview_cache;
 use anyhow::Context;
 use color_print::cformat;
 use path_slash::PathExt as _;
+use worktrunk::activity::Activity;
 use worktrunk::git::{BranchRef, Repository, resolve_input_path, sha_cache};
+use worktrunk::notify::{Notification, notify};
 use worktrunk::path::format_path_for_display;
+use worktrunk::progress::format_bytes;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::store::Store;
 use worktrunk::styling::{
     eprintln, format_heading, format_with_gutter, hint_message, info_message, println,
     success_message, warning_message,
@@ -91,6 +105,7 @@ use worktrunk::utils::epoch_now;
 use super::super::list::ci_status::{CachedCiStatus, CiBranchName, MaxPrNumber};
 use crate::display::format_relative_time_short;
 use crate::help_pager::show_help_in_pager;
+use crate::review::CachedReview;
 use crate::summary::CachedSummary;
 
 // ==================== Log Management ====================
@@ -128,12 +143,18 @@ fn truncate_display(s: &str, max_chars: usize) -> String {
 }
 
 /// Check if a top-level file belongs to the command audit log
-/// (`commands.jsonl`, rotated to `commands.jsonl.old`).
+/// (`commands.jsonl`, rotated to `commands.jsonl.old` or, with `[logs] keep`,
+/// to gzipped `commands.jsonl.N.gz` generations).
 ///
 /// Matched by exact name, not a `.jsonl` suffix: `trace.jsonl` is a diagnostic
 /// file (see [`DIAGNOSTIC_FILES`]), not part of the audit log.
 fn is_command_log_file(name: &str) -> bool {
-    name == "commands.jsonl" || name == "commands.jsonl.old"
+    name == "commands.jsonl"
+        || name == "commands.jsonl.old"
+        || name
+            .strip_prefix("commands.jsonl.")
+            .and_then(|rest| rest.strip_suffix(".gz"))
+            .is_some_and(|n| n.parse::<u32>().is_ok())
 }
 
 /// A hook-output log file discovered by walking the per-branch subtree.
@@ -646,22 +667,27 @@ pub fn handle_logs_list(format: SwitchFormat) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// `wt config state logs profile [FILE]` — summarize where a `-vv` run spent its
-/// time, from the records in `trace.jsonl` (or a given file / stdin).
-pub fn handle_logs_profile(file: Option<PathBuf>, format: SwitchFormat) -> anyhow::Result<()> {
-    let (input, source) = match file {
+/// Read a `-vv` trace: the given file, stdin for `-`, or the repo's
+/// `.git/wt/logs/trace.jsonl`. Returns the contents, a display name for
+/// messages, and the file's modification time (`None` for stdin).
+fn read_trace(file: Option<PathBuf>) -> anyhow::Result<(String, String, Option<SystemTime>)> {
+    let path = match file {
         Some(ref p) if p.as_os_str() == "-" => {
             let mut buf = String::new();
             std::io::Read::read_to_string(&mut std::io::stdin(), &mut buf)
                 .context("read trace from stdin")?;
-            (buf, "stdin".to_string())
+            return Ok((buf, "stdin".to_string(), None));
         }
         Some(p) => {
             // A relative trace path resolves against `-C` — see `resolve_input_path`.
             let p = resolve_input_path(p);
             let content = std::fs::read_to_string(&p)
                 .with_context(|| format!("Failed to read trace {}", format_path_for_display(&p)))?;
-            (content, format_path_for_display(&p).to_string())
+            return Ok((
+                content,
+                format_path_for_display(&p).to_string(),
+                std::fs::metadata(&p).and_then(|m| m.modified()).ok(),
+            ));
         }
         None => {
             let repo = Repository::current().map_err(|_| {
@@ -669,23 +695,38 @@ pub fn handle_logs_profile(file: Option<PathBuf>, format: SwitchFormat) -> anyho
                     "Not inside a git repository, so there's no default <bold>.git/wt/logs/trace.jsonl</> to read; pass a trace path or <bold>-</> for stdin"
                 ))
             })?;
-            let path = repo.wt_logs_dir().join("trace.jsonl");
-            let content = std::fs::read_to_string(&path).map_err(|_| {
-                anyhow::anyhow!(cformat!(
-                    "No trace at <bold>{}</>; run a command with <bold>-vv</> to capture one",
-                    format_path_for_display(&path)
-                ))
-            })?;
-            (content, format_path_for_display(&path).to_string())
+            repo.wt_logs_dir().join("trace.jsonl")
         }
     };
+    let content = std::fs::read_to_string(&path).map_err(|_| {
+        anyhow::anyhow!(cformat!(
+            "No trace at <bold>{}</>; run a command with <bold>-vv</> to capture one",
+            format_path_for_display(&path)
+        ))
+    })?;
+    Ok((
+        content,
+        format_path_for_display(&path).to_string(),
+        std::fs::metadata(&path).and_then(|m| m.modified()).ok(),
+    ))
+}
 
-    let entries = worktrunk::trace::parse_lines(&input);
+/// Parse a trace's records, failing when there are none.
+fn parse_trace(input: &str, source: &str) -> anyhow::Result<Vec<worktrunk::trace::TraceEntry>> {
+    let entries = worktrunk::trace::parse_lines(input);
     if entries.is_empty() {
         anyhow::bail!(cformat!(
             "No trace records in {source}; run a command with <bold>-vv</> to capture a trace"
         ));
     }
+    Ok(entries)
+}
+
+/// `wt config state logs profile [FILE]` — summarize where a `-vv` run spent its
+/// time, from the records in `trace.jsonl` (or a given file / stdin).
+pub fn handle_logs_profile(file: Option<PathBuf>, format: SwitchFormat) -> anyhow::Result<()> {
+    let (input, source, _) = read_trace(file)?;
+    let entries = parse_trace(&input, &source)?;
 
     let profile = worktrunk::trace::Profile::from_entries(&entries);
 
@@ -697,6 +738,93 @@ pub fn handle_logs_profile(file: Option<PathBuf>, format: SwitchFormat) -> anyho
     Ok(())
 }
 
+/// `wt config state logs export [FILE]` — convert a `-vv` trace to OTLP/JSON
+/// spans, and write it out or push it to a collector.
+pub fn handle_logs_export(
+    file: Option<PathBuf>,
+    output: Option<PathBuf>,
+    endpoint: Option<String>,
+) -> anyhow::Result<()> {
+    let (input, source, modified) = read_trace(file)?;
+    let entries = parse_trace(&input, &source)?;
+
+    // Captures from before the `session` record existed carry no wall-clock
+    // anchor: pin the last record's end to when the file was last written.
+    let session = worktrunk::trace::parse_session(&input).unwrap_or_else(|| {
+        let last_ts = entries
+            .iter()
+            .filter_map(|e| e.start_time_us)
+            .max()
+            .unwrap_or(0);
+        let written = modified.unwrap_or_else(SystemTime::now);
+        worktrunk::trace::TraceSession {
+            command: "wt".to_string(),
+            subcommand: None,
+            repo: None,
+            branch: None,
+            pid: None,
+            ts_us: last_ts,
+            unix_time_us: written
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |d| d.as_micros() as u64),
+        }
+    });
+    let otlp = worktrunk::trace::to_otlp_json(&entries, &session);
+    let json = serde_json::to_string_pretty(&otlp)?;
+
+    if let Some(path) = &output {
+        let path = resolve_input_path(path.clone());
+        std::fs::write(&path, format!("{json}\n"))
+            .with_context(|| format!("Failed to write {}", format_path_for_display(&path)))?;
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Wrote trace to <bold>{}</>",
+                format_path_for_display(&path)
+            ))
+        );
+    }
+
+    if let Some(endpoint) = endpoint {
+        let url = if endpoint.trim_end_matches('/').ends_with("/v1/traces") {
+            endpoint
+        } else {
+            format!("{}/v1/traces", endpoint.trim_end_matches('/'))
+        };
+        let result = Cmd::new("curl")
+            .args([
+                "--silent",
+                "--show-error",
+                "--fail",
+                "--connect-timeout",
+                "10",
+                "--max-time",
+                "60",
+                "--header",
+                "Content-Type: application/json",
+                "--data-binary",
+                "@-",
+                &url,
+            ])
+            .stdin_bytes(json)
+            .run()
+            .context("Failed to run curl")?;
+        if !result.status.success() {
+            anyhow::bail!(cformat!(
+                "Failed to export trace to <bold>{url}</>: {}",
+                String::from_utf8_lossy(&result.stderr).trim()
+            ));
+        }
+        eprintln!(
+            "{}",
+            success_message(cformat!("Exported trace from {source} to <bold>{url}</>"))
+        );
+    } else if output.is_none() {
+        println!("{json}");
+    }
+    Ok(())
+}
+
 // ==================== State Get/Set/Clear Commands ====================
 
 /// Handle the state get command
@@ -875,6 +1003,10 @@ pub fn handle_state_set(key: &str, value: String, branch: Option<String>) -> any
 
             let config_key = format!("worktrunk.state.{branch_name}.marker");
             repo.set_config(&config_key, &json.to_string())?;
+            notify(
+                &repo.user_config().notify,
+                &Notification::marker(&branch_name, &value),
+            );
 
             eprintln!(
                 "{}",
@@ -1020,11 +1152,14 @@ pub fn handle_state_clear_all(yes: bool) -> anyhow::Result<()> {
     cleared_any |= clear_markers_reported(&repo)?;
     cleared_any |= clear_ci_status_reported(&repo)?;
     cleared_any |= clear_summary_reported(&repo)?;
+    cleared_any |= clear_review_reported(&repo)?;
     cleared_any |= clear_git_commands_reported(&repo)?;
     cleared_any |= clear_vars_reported(&repo)?;
+    cleared_any |= clear_activity_reported(&repo)?;
     cleared_any |= clear_logs_reported(&repo)?;
     cleared_any |= clear_hints_reported(&repo)?;
     cleared_any |= clear_trash_reported(&repo)?;
+    cleared_any |= clear_store_reported(&repo)?;
 
     if !cleared_any {
         eprintln!("{}", info_message("No stored state to clear"));
@@ -1046,6 +1181,7 @@ pub fn handle_cache_clear() -> anyhow::Result<()> {
     cleared_any |= clear_previous_branch_reported(&repo)?;
     cleared_any |= clear_ci_status_reported(&repo)?;
     cleared_any |= clear_summary_reported(&repo)?;
+    cleared_any |= clear_review_reported(&repo)?;
     cleared_any |= clear_git_commands_reported(&repo)?;
     cleared_any |= clear_hints_reported(&repo)?;
 
@@ -1118,6 +1254,14 @@ fn clear_summary_reported(repo: &Repository) -> anyhow::Result<bool> {
     ))
 }
 
+fn clear_review_reported(repo: &Repository) -> anyhow::Result<bool> {
+    Ok(report_cleared_count(
+        CachedReview::clear_all(repo)?,
+        "review cache entry",
+        "review cache entries",
+    ))
+}
+
 /// Clear all SHA-keyed git command caches: parsed results (merge-tree,
 /// ancestry, diff-stats) plus rendered picker previews (log, branch-diff,
 /// upstream-diff). Surfaced as one user-facing category — see the parity
@@ -1138,6 +1282,14 @@ fn clear_vars_reported(repo: &Repository) -> anyhow::Result<bool> {
     ))
 }
 
+fn clear_activity_reported(repo: &Repository) -> anyhow::Result<bool> {
+    Ok(report_cleared_count(
+        Activity::for_repo(repo).clear()?,
+        "agent activity file",
+        "agent activity files",
+    ))
+}
+
 fn clear_logs_reported(repo: &Repository) -> anyhow::Result<bool> {
     Ok(report_cleared_count(
         clear_logs(repo)?,
@@ -1162,6 +1314,14 @@ fn clear_trash_reported(repo: &Repository) -> anyhow::Result<bool> {
     ))
 }
 
+fn clear_store_reported(repo: &Repository) -> anyhow::Result<bool> {
+    Ok(report_cleared_count(
+        Store::for_repo(repo).clear()?,
+        "store file",
+        "store files",
+    ))
+}
+
 // ==================== State Show Commands ====================
 
 /// Handle the state get command (shows all state)
@@ -1200,9 +1360,12 @@ fn handle_state_show_json(repo: &Repository) -> anyhow::Result<()> {
         })
         .collect();
 
+    let agent_sessions = Activity::for_repo(repo).sessions();
+
     // Get CI status and summary caches (pre-sorted newest-first)
     let ci_status = ci_status_json(repo);
     let summaries = summaries_json(repo);
+    let reviews = reviews_json(repo);
 
     let (command_log, hook_output, diagnostic) = partition_log_files_json(repo)?;
 
@@ -1247,9 +1410,11 @@ fn handle_state_show_json(repo: &Repository) -> anyhow::Result<()> {
         "remote_head_branch": remote_head_branch,
         "previous_branch": previous_branch,
         "markers": markers,
+        "agent_sessions": agent_sessions,
         "ci_status": ci_status,
         "max_pr_number": MaxPrNumber::read(repo),
         "summaries": summaries,
+        "reviews": reviews,
         "git_commands_cache": sha_cache::count_all(repo) + preview_cache::count_all(repo),
         "vars": vars_data,
         "command_log": command_log,
@@ -1257,6 +1422,7 @@ fn handle_state_show_json(repo: &Repository) -> anyhow::Result<()> {
         "diagnostic": diagnostic,
         "hints": hints,
         "trash": trash,
+        "store": store_json(repo)?,
     });
 
     print_json(&output)?;
@@ -1318,6 +1484,17 @@ fn handle_state_show_table(repo: &Repository) -> anyhow::Result<()> {
     }
     writeln!(out)?;
 
+    // Show agent sessions
+    writeln!(out, "{}", format_heading("AGENT SESSIONS", None))?;
+    let sessions = Activity::for_repo(repo).sessions();
+    if sessions.is_empty() {
+        writeln!(out, "{}", format_with_gutter("(none)", None))?;
+    } else {
+        let rendered = super::activity::render_sessions(&sessions, epoch_now());
+        writeln!(out, "{}", rendered.trim_end())?;
+    }
+    writeln!(out)?;
+
     // Show vars data
     writeln!(out, "{}", format_heading("VARS", None))?;
     let all_vars: std::collections::BTreeMap<_, _> = repo.all_vars_entries().into_iter().collect();
@@ -1349,6 +1526,10 @@ fn handle_state_show_table(repo: &Repository) -> anyhow::Result<()> {
     render_summary_section(&mut out, repo)?;
     writeln!(out)?;
 
+    // Show review cache (LLM reviews keyed by branch + diff hash)
+    render_review_section(&mut out, repo)?;
+    writeln!(out)?;
+
     // Show git commands cache summary
     render_git_commands_section(&mut out, repo)?;
     writeln!(out)?;
@@ -1389,6 +1570,10 @@ fn handle_state_show_table(repo: &Repository) -> anyhow::Result<()> {
         let rendered = crate::md_help::render_data_table(&["Entry", "Age"], &rows);
         writeln!(out, "{}", rendered.trim_end())?;
     }
+    writeln!(out)?;
+
+    // Show the copy-ignored file store
+    render_store_section(&mut out, repo)?;
 
     show_help_in_pager(&out, true);
 
@@ -1419,6 +1604,8 @@ fn handle_cache_get_table(repo: &Repository) -> anyhow::Result<()> {
     writeln!(out)?;
     render_summary_section(&mut out, repo)?;
     writeln!(out)?;
+    render_review_section(&mut out, repo)?;
+    writeln!(out)?;
     render_git_commands_section(&mut out, repo)?;
     writeln!(out)?;
     render_hints_section(&mut out, repo)?;
@@ -1434,6 +1621,7 @@ fn handle_cache_get_json(repo: &Repository) -> anyhow::Result<()> {
         "ci_status": ci_status_json(repo),
         "max_pr_number": MaxPrNumber::read(repo),
         "summaries": summaries_json(repo),
+        "reviews": reviews_json(repo),
         "git_commands_cache": sha_cache::count_all(repo) + preview_cache::count_all(repo),
         "hints": repo.list_shown_hints(),
     });
@@ -1518,6 +1706,27 @@ fn render_summary_section(out: &mut String, repo: &Repository) -> anyhow::Result
     Ok(())
 }
 
+/// Render the review cache: one row per branch with its blocking-finding count.
+fn render_review_section(out: &mut String, repo: &Repository) -> anyhow::Result<()> {
+    writeln!(out, "{}", format_heading("REVIEW CACHE", None))?;
+    let review_entries = CachedReview::list_all(repo);
+    if review_entries.is_empty() {
+        writeln!(out, "{}", format_with_gutter("(none)", None))?;
+    } else {
+        let rows: Vec<Vec<String>> = review_entries
+            .iter()
+            .map(|cached| {
+                let blocking = crate::review::blocking_findings(&cached.review).len();
+                let age = format_relative_time_short(cached.generated_at as i64);
+                vec![cached.branch.clone(), blocking.to_string(), age]
+            })
+            .collect();
+        let rendered = crate::md_help::render_data_table(&["Branch", "Blocking", "Age"], &rows);
+        writeln!(out, "{}", rendered.trim_end())?;
+    }
+    Ok(())
+}
+
 /// Render the git commands cache summary. Spans both `sha_cache` (parsed
 /// SHA-keyed results) and the picker preview cache (rendered SHA-keyed
 /// previews) — one user-facing category covering every SHA-keyed disk cache,
@@ -1551,6 +1760,86 @@ fn render_hints_section(out: &mut String, repo: &Repository) -> anyhow::Result<(
     Ok(())
 }
 
+/// Render the shared file store: object count and size, plus what `gc`
+/// would drop.
+fn render_store_section(out: &mut String, repo: &Repository) -> anyhow::Result<()> {
+    let store = Store::for_repo(repo);
+    let store_display = format_path_for_display(store.root());
+    writeln!(
+        out,
+        "{}",
+        format_heading("STORE", Some(&format!("@ {store_display}")))
+    )?;
+    let usage = store.usage()?;
+    if usage.objects == 0 {
+        writeln!(out, "{}", format_with_gutter("(none)", None))?;
+        return Ok(());
+    }
+    let label = if usage.objects == 1 { "file" } else { "files" };
+    let mut line = format!("{} {label} · {}", usage.objects, format_bytes(usage.bytes));
+    if usage.unreferenced > 0 {
+        write!(
+            line,
+            " · {} unreferenced ({})",
+            usage.unreferenced,
+            format_bytes(usage.unreferenced_bytes)
+        )?;
+    }
+    writeln!(out, "{}", format_with_gutter(&line, None))?;
+    Ok(())
+}
+
+/// Shared file store usage as JSON. Shared by `state get` and `store get`.
+fn store_json(repo: &Repository) -> anyhow::Result<serde_json::Value> {
+    let store = Store::for_repo(repo);
+    let usage = store.usage()?;
+    Ok(serde_json::json!({
+        "path": store.root(),
+        "objects": usage.objects,
+        "bytes": usage.bytes,
+        "unreferenced": usage.unreferenced,
+        "unreferenced_bytes": usage.unreferenced_bytes,
+    }))
+}
+
+/// Handle `wt config state store get`.
+pub fn handle_store_get(format: SwitchFormat) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    match format {
+        SwitchFormat::Json => print_json(&store_json(&repo)?),
+        SwitchFormat::Text => {
+            let mut out = String::new();
+            render_store_section(&mut out, &repo)?;
+            show_help_in_pager(&out, true);
+            Ok(())
+        }
+    }
+}
+
+/// Handle `wt config state store gc`: drop objects no worktree links.
+pub fn handle_store_gc() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let removed = Store::for_repo(&repo).gc()?;
+    if removed.objects == 0 {
+        eprintln!("{}", info_message("No unreferenced store files"));
+        return Ok(());
+    }
+    let noun = if removed.objects == 1 {
+        "store file"
+    } else {
+        "store files"
+    };
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Removed <bold>{}</> unreferenced {noun} · {}",
+            removed.objects,
+            format_bytes(removed.bytes)
+        ))
+    );
+    Ok(())
+}
+
 /// CI status cache entries as JSON (pre-sorted newest-first). Shared by
 /// `state get` and `cache get`.
 fn ci_status_json(repo: &Repository) -> Vec<serde_json::Value> {
@@ -1586,10 +1875,26 @@ fn summaries_json(repo: &Repository) -> Vec<serde_json::Value> {
         .collect()
 }
 
+/// Review cache entries as JSON (freshest per branch, pre-sorted
+/// newest-first). Shared by `state get` and `cache get`.
+fn reviews_json(repo: &Repository) -> Vec<serde_json::Value> {
+    CachedReview::list_all(repo)
+        .into_iter()
+        .map(|cached| {
+            serde_json::json!({
+                "branch": cached.branch,
+                "review": cached.review,
+                "blocking": crate::review::blocking_findings(&cached.review),
+                "generated_at": cached.generated_at,
+            })
+        })
+        .collect()
+}
+
 // ==================== Vars Operations ====================
 
 /// Validate a vars key name: letters, digits, and hyphens only.
-fn validate_vars_key(key: &str) -> anyhow::Result<()> {
+pub(crate) fn validate_vars_key(key: &str) -> anyhow::Result<()> {
     if key.is_empty() {
         anyhow::bail!("Key cannot be empty");
     }
@@ -0,0 +1,288 @@
+//! Config validate command.
+//!
+//! Runs [`worktrunk::config::validate_config`] over every config file that
+//! exists — system, user, and the current repo's project config — and reports
+//! the findings as text with source snippets or as JSON. Any error exits 1 so
+//! the command can gate CI.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{
+    ConfigDiagnostic, ConfigFileKind, DiagnosticCode, Severity, config_path, system_config_path,
+    validate_config,
+};
+use worktrunk::git::{Repository, WorktrunkError};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, error_message, format_with_gutter, hint_message, info_message, success_message,
+    warning_message,
+};
+
+use crate::cli::SwitchFormat;
+use crate::output::print_json;
+
+/// One config file and what validating it found.
+struct CheckedFile {
+    kind: ConfigFileKind,
+    path: PathBuf,
+    contents: String,
+    diagnostics: Vec<ConfigDiagnostic>,
+}
+
+/// Handle the `wt config validate` command.
+pub fn handle_config_validate(format: SwitchFormat) -> anyhow::Result<()> {
+    // Findings are the output; the load-time warnings would repeat them.
+    worktrunk::config::suppress_warnings();
+
+    let files = config_files()?
+        .into_iter()
+        .map(|(kind, path)| {
+            let contents = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", kind.label().to_lowercase()))?;
+            let diagnostics = validate_config(&contents, kind);
+            Ok(CheckedFile {
+                kind,
+                path,
+                contents,
+                diagnostics,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let errors = count(&files, Severity::Error);
+    match format {
+        SwitchFormat::Json => print_json(&json_report(&files, errors))?,
+        SwitchFormat::Text => render_text(&files, errors),
+    }
+
+    if errors > 0 {
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+    }
+    Ok(())
+}
+
+/// The config files to check, in load order. Missing files are skipped.
+fn config_files() -> anyhow::Result<Vec<(ConfigFileKind, PathBuf)>> {
+    let mut files = Vec::new();
+    if let Some(path) = system_config_path() {
+        files.push((ConfigFileKind::System, path));
+    }
+    if let Some(path) = config_path() {
+        files.push((ConfigFileKind::User, path));
+    }
+    if let Ok(repo) = Repository::current()
+        && let Some(path) = repo.project_config_path()?
+    {
+        files.push((ConfigFileKind::Project, path));
+    }
+    files.retain(|(_, path)| path.exists());
+    Ok(files)
+}
+
+fn count(files: &[CheckedFile], severity: Severity) -> usize {
+    files
+        .iter()
+        .flat_map(|f| &f.diagnostics)
+        .filter(|d| d.severity == severity)
+        .count()
+}
+
+fn json_report(files: &[CheckedFile], errors: usize) -> serde_json::Value {
+    let files: Vec<_> = files
+        .iter()
+        .map(|f| {
+            serde_json::json!({
+                "kind": kind_name(f.kind),
+                "path": f.path,
+                "diagnostics": f.diagnostics,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "valid": errors == 0,
+        "files": files,
+    })
+}
+
+fn kind_name(kind: ConfigFileKind) -> &'static str {
+    match kind {
+        ConfigFileKind::User => "user",
+        ConfigFileKind::System => "system",
+        ConfigFileKind::Project => "project",
+    }
+}
+
+fn render_text(files: &[CheckedFile], errors: usize) {
+    if files.is_empty() {
+        eprintln!("{}", info_message("No config files found"));
+        return;
+    }
+
+    for file in files {
+        let display_path = format_path_for_display(&file.path);
+        for diagnostic in &file.diagnostics {
+            eprintln!("{}", render_diagnostic(file, &display_path, diagnostic));
+        }
+    }
+
+    let deprecated = files
+        .iter()
+        .flat_map(|f| &f.diagnostics)
+        .any(|d| d.code == DiagnosticCode::Deprecated);
+    if deprecated {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To migrate deprecated settings, run <underline>wt config update</>"
+            ))
+        );
+    }
+
+    let warnings = count(files, Severity::Warning);
+    let checked = checked_labels(files);
+    if errors > 0 {
+        eprintln!(
+            "{}",
+            error_message(format!("Found {} in {checked}", tally(errors, warnings)))
+        );
+    } else if warnings > 0 {
+        eprintln!(
+            "{}",
+            success_message(format!(
+                "{} valid ({})",
+                capitalize(&checked),
+                tally(0, warnings)
+            ))
+        );
+    } else {
+        eprintln!(
+            "{}",
+            success_message(format!("{} valid", capitalize(&checked)))
+        );
+    }
+}
+
+/// `✗ path:line:col message`, the source line with a caret under the column,
+/// and a "did you mean" hint when there is a suggestion.
+fn render_diagnostic(file: &CheckedFile, display_path: &str, d: &ConfigDiagnostic) -> String {
+    let location = match (d.line, d.column) {
+        (Some(line), Some(column)) => format!("{display_path}:{line}:{column}"),
+        _ => display_path.to_string(),
+    };
+    let message = cformat!("<bold>{location}</> {}", emphasize(&d.message));
+    let mut parts = vec![match d.severity {
+        Severity::Error => error_message(message).to_string(),
+        Severity::Warning => warning_message(message).to_string(),
+    }];
+    if let (Some(line), Some(column)) = (d.line, d.column)
+        && let Some(snippet) = source_snippet(&file.contents, line, column)
+    {
+        parts.push(format_with_gutter(&snippet, None));
+    }
+    if let Some(suggestion) = &d.suggestion {
+        parts.push(hint_message(cformat!("Did you mean <bold>{suggestion}</>?")).to_string());
+    }
+    parts.join("\n")
+}
+
+/// The source line numbered, with a caret under `column` (1-based, chars).
+fn source_snippet(contents: &str, line: usize, column: usize) -> Option<String> {
+    let source = contents.lines().nth(line.checked_sub(1)?)?;
+    let number = line.to_string();
+    let pad = " ".repeat(number.len());
+    let indent: String = source
+        .chars()
+        .take(column.saturating_sub(1))
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    Some(format!("{number} │ {source}\n{pad} │ {indent}^"))
+}
+
+/// Bold the backtick-quoted names in a plain-text diagnostic message.
+fn emphasize(message: &str) -> String {
+    message
+        .split('`')
+        .enumerate()
+        .map(|(i, part)| {
+            if i % 2 == 1 {
+                cformat!("<bold>{part}</>")
+            } else {
+                part.to_string()
+            }
+        })
+        .collect()
+}
+
+/// "user config and project config" — the files that were checked.
+fn checked_labels(files: &[CheckedFile]) -> String {
+    let labels: Vec<String> = files
+        .iter()
+        .map(|f| f.kind.label().to_lowercase())
+        .collect();
+    match labels.as_slice() {
+        [] => String::new(),
+        [only] => only.clone(),
+        [init @ .., last] => format!("{} and {last}", init.join(", ")),
+    }
+}
+
+fn tally(errors: usize, warnings: usize) -> String {
+    let plural = |n: usize, word: &str| {
+        if n == 1 {
+            format!("{n} {word}")
+        } else {
+            format!("{n} {word}s")
+        }
+    };
+    match (errors, warnings) {
+        (0, w) => plural(w, "warning"),
+        (e, 0) => plural(e, "error"),
+        (e, w) => format!("{}, {}", plural(e, "error"), plural(w, "warning")),
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_snippet() {
+        let snippet = source_snippet("[merge]\nsquas = true\n", 2, 1).unwrap();
+        insta::assert_snapshot!(snippet, @"
+        2 │ squas = true
+          │ ^
+        ");
+        assert!(source_snippet("a = 1\n", 5, 1).is_none());
+    }
+
+    #[test]
+    fn test_tally() {
+        assert_eq!(tally(1, 0), "1 error");
+        assert_eq!(tally(2, 1), "2 errors, 1 warning");
+        assert_eq!(tally(0, 3), "3 warnings");
+    }
+
+    #[test]
+    fn test_checked_labels() {
+        let file = |kind| CheckedFile {
+            kind,
+            path: PathBuf::from("x"),
+            contents: String::new(),
+            diagnostics: Vec::new(),
+        };
+        assert_eq!(
+            checked_labels(&[file(ConfigFileKind::User), file(ConfigFileKind::Project)]),
+            "user config and project config"
+        );
+    }
+}
@@ -1303,7 +1303,7 @@ fn scan_for_uninstall(shell_filter: Option<Shell>) -> Result<UninstallScanResult
                 }
             }
 
-            Shell::Bash | Shell::Zsh | Shell::PowerShell => {
+            Shell::Bash | Shell::Zsh | Shell::PowerShell | Shell::Xonsh | Shell::Elvish => {
                 let paths = shell::line_based_config_paths(shell, &home);
                 let mut found = false;
 
@@ -0,0 +1,89 @@
+//! `[container]`: a container per worktree (mechanism in
+//! [`worktrunk::container`], started and removed by
+//! [`worktrunk::lifecycle`]).
+//!
+//! - `wt switch` starts the destination worktree's container — creating it,
+//!   and building its image, the first time — before `pre-start` hooks run,
+//!   after the user approves the `[container]` section.
+//! - Project hooks run in the container while it's running
+//!   ([`super::command_executor::CommandContext::hook_container`]), as do
+//!   `--execute` commands.
+//! - Removing the worktree removes its container, after `pre-remove` hooks
+//!   and before the directory moves.
+//! - `wt list` shows each worktree's container state.
+//!
+//! Project config is untrusted, so nothing runs the container runtime until
+//! the user approves the `[container]` section: `wt switch` asks, and every
+//! other path goes through [`approved`].
+
+use std::io::IsTerminal;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use worktrunk::config::ProjectContainerConfig;
+use worktrunk::container::{self, Container};
+use worktrunk::git::Repository;
+use worktrunk::shell_exec::{ShellEscapeMode, shell_escape_for};
+use worktrunk::styling::{eprintln, info_message};
+
+use super::command_approval::approve_container;
+
+/// Set when [`approve`] approves the section, so the rest of this process
+/// (hooks, `--execute`) treats it as approved even under `--yes`, which
+/// saves nothing.
+static APPROVED_THIS_RUN: AtomicBool = AtomicBool::new(false);
+
+/// The project's `[container]` once the user approves it, for `wt switch`.
+/// `None` without one, or when declined — the switch then goes ahead without
+/// starting a container.
+pub(crate) fn approve(
+    repo: &Repository,
+    yes: bool,
+) -> anyhow::Result<Option<ProjectContainerConfig>> {
+    let Some(config) = container::configured(repo) else {
+        return Ok(None);
+    };
+    if approve_container(repo, &config, yes)? {
+        APPROVED_THIS_RUN.store(true, Ordering::Relaxed);
+        Ok(Some(config))
+    } else {
+        eprintln!(
+            "{}",
+            info_message("Container declined, continuing without it")
+        );
+        Ok(None)
+    }
+}
+
+/// The project's `[container]` when the user has approved it — in this
+/// process, or in a saved approval. `None` otherwise, and callers then leave
+/// the runtime alone.
+pub(crate) fn approved(repo: &Repository) -> Option<ProjectContainerConfig> {
+    if APPROVED_THIS_RUN.load(Ordering::Relaxed) {
+        return container::configured(repo);
+    }
+    container::approved(repo)
+}
+
+/// The worktree's container, when the project's approved `[container]`
+/// gives it one and it's running.
+pub(crate) fn running(repo: &Repository, worktree: &Path) -> Option<Container> {
+    container::running(&approved(repo)?, worktree)
+}
+
+/// `command` — a `--execute` payload already escaped for `escape_mode` —
+/// rewritten to run in `container` from `cwd`, with a terminal when stdin has
+/// one.
+pub(crate) fn exec_command(
+    container: &Container,
+    command: &str,
+    cwd: &Path,
+    escape_mode: ShellEscapeMode,
+) -> String {
+    container
+        .exec_words(command, cwd, std::io::stdin().is_terminal())
+        .iter()
+        .map(|word| shell_escape_for(escape_mode, word))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -67,6 +67,9 @@
 
 use color_print::cformat;
 
+use worktrunk::container::Container;
+use worktrunk::sandbox::SandboxPolicy;
+
 use super::command_executor::PreparedStep;
 use super::hook_filter::HookSource;
 
@@ -81,6 +84,12 @@ use super::hook_filter::HookSource;
 pub struct SourcedStep {
     pub step: PreparedStep,
     pub source: HookSource,
+    /// The sandbox the step's commands run in — set only for project hook
+    /// steps under `[hooks] sandbox`.
+    pub sandbox: Option<SandboxPolicy>,
+    /// The container the step's commands run in — set only for project hook
+    /// steps of a `[container]` project whose container is running.
+    pub container: Option<Container>,
 }
 
 /// Extract the per-step command name lists from a `CommandConfig`.
@@ -215,6 +224,8 @@ mod tests {
         SourcedStep {
             step,
             source: HookSource::User,
+            sandbox: None,
+            container: None,
         }
     }
 
@@ -415,40 +415,64 @@ pub fn handle_hook_show(
 }
 
 /// Emit configured hooks as a JSON array of structured records.
+fn emit_hook_show_json(
+    user_config: &UserConfig,
+    project_config: Option<&ProjectConfig>,
+    approvals: &Approvals,
+    project_id: Option<&str>,
+    filter: Option<HookType>,
+    ctx: Option<&CommandContext>,
+) -> anyhow::Result<()> {
+    let entries = hook_show_records(
+        user_config,
+        project_config,
+        approvals,
+        project_id,
+        filter,
+        ctx,
+    )?;
+    print_json(&entries)?;
+    Ok(())
+}
+
+/// Configured hooks as structured records — `wt hook show --format=json`,
+/// also served by `wt serve`.
 ///
 /// Each record carries the hook type, source (user or project), optional name,
 /// raw template, project approval status, and — when `--expanded` was passed —
 /// the rendered command preview. `handle_hook_show` builds `ctx` only under
 /// `--expanded`, and each row carries whether it was expanded.
-fn emit_hook_show_json(
+pub(crate) fn hook_show_records(
     user_config: &UserConfig,
     project_config: Option<&ProjectConfig>,
     approvals: &Approvals,
     project_id: Option<&str>,
     filter: Option<HookType>,
     ctx: Option<&CommandContext>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Vec<serde_json::Value>> {
     let mut entries: Vec<serde_json::Value> = Vec::new();
 
-    let mut emit =
-        |hook_type: HookType, source: HookSource, cfg: &CommandConfig| -> anyhow::Result<()> {
-            for row in hook_command_rows(cfg, ctx, hook_type, source)? {
-                let mut obj = serde_json::json!({
-                    "type": hook_type.to_string(),
-                    "source": source.to_string(),
-                    "name": row.name,
-                    "template": row.template,
-                    "needs_approval": needs_approval(source, approvals, project_id, &row.template),
-                });
-
-                if let Some(expanded) = row.expanded {
-                    obj["expanded"] = serde_json::Value::String(expanded);
-                }
-
-                entries.push(obj);
+    let mut emit = |hook_type: HookType,
+                    source: HookSource,
+                    cfg: &CommandConfig|
+     -> anyhow::Result<()> {
+        for row in hook_command_rows(cfg, ctx, hook_type, source)? {
+            let mut obj = serde_json::json!({
+                "type": hook_type.to_string(),
+                "source": source.to_string(),
+                "name": row.name,
+                "template": row.template,
+                "needs_approval": needs_approval(source, hook_type, approvals, project_id, &row.template),
+            });
+
+            if let Some(expanded) = row.expanded {
+                obj["expanded"] = serde_json::Value::String(expanded);
             }
-            Ok(())
-        };
+
+            entries.push(obj);
+        }
+        Ok(())
+    };
 
     // User hooks (merge global + per-project so the listing matches what runs)
     let user_hooks = user_config.hooks(project_id);
@@ -477,8 +501,7 @@ fn emit_hook_show_json(
         }
     }
 
-    print_json(&entries)?;
-    Ok(())
+    Ok(entries)
 }
 
 /// Render user hooks section
@@ -617,7 +640,8 @@ fn render_hook_commands(
         wrote_any = true;
         let label = command_label(hook_type, row.name.as_deref());
 
-        let needs_approval = needs_approval(source, approvals, project_id, &row.template);
+        let needs_approval =
+            needs_approval(source, hook_type, approvals, project_id, &row.template);
 
         // Use ❯ for needs approval, ○ for approved/user hooks
         let (emoji, suffix) = if needs_approval {
@@ -641,15 +665,15 @@ fn render_hook_commands(
 /// and nothing is flagged.
 fn needs_approval(
     source: HookSource,
+    hook_type: HookType,
     approvals: &Approvals,
     project_id: Option<&str>,
     template: &str,
 ) -> bool {
     match source {
         HookSource::User => false,
-        HookSource::Project => {
-            project_id.is_some_and(|id| !approvals.is_command_approved(id, template))
-        }
+        HookSource::Project => project_id
+            .is_some_and(|id| !approvals.is_command_approved(id, template, &hook_type.to_string())),
     }
 }
 
@@ -250,8 +250,9 @@ impl HookPlan {
             entry.selection.retain(|(source, cfg)| {
                 *source != HookSource::Project
                     || project_id.is_some_and(|pid| {
+                        let scope = entry.hook_type.to_string();
                         cfg.commands()
-                            .all(|c| approvals.is_command_approved(pid, &c.template))
+                            .all(|c| approvals.is_command_approved(pid, &c.template, &scope))
                     })
             });
         }
@@ -279,8 +280,9 @@ impl HookPlan {
                 if *source != HookSource::Project {
                     continue;
                 }
+                let scope = entry.hook_type.to_string();
                 for cmd in cfg.commands() {
-                    if !approvals.is_command_approved(pid, &cmd.template)
+                    if !approvals.is_command_approved(pid, &cmd.template, &scope)
                         && seen.insert(cmd.template.clone())
                     {
                         out.push(cmd.template.clone());
@@ -359,10 +361,14 @@ fn render_planned(
     let mut out = Vec::new();
     for (source, cfg) in entries {
         let steps = prepare_steps(cfg, ctx, extra_vars, hook_type, *source)?.validated()?;
+        let sandbox = ctx.hook_sandbox(*source);
+        let container = ctx.hook_container(*source);
         for step in steps {
             out.push(SourcedStep {
                 step,
                 source: *source,
+                sandbox: sandbox.clone(),
+                container: container.clone(),
             });
         }
     }
@@ -429,6 +435,7 @@ pub fn register_planned(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use worktrunk::config::ApprovalRecord;
 
     fn project_cfg(toml: &str) -> ProjectConfig {
         toml::from_str(toml).unwrap()
@@ -505,7 +512,11 @@ mod tests {
         approvals
             .approve_commands(
                 "proj".to_string(),
-                vec!["echo project-hook".to_string()],
+                vec![ApprovalRecord {
+                    command: "echo project-hook".to_string(),
+                    scope: Some("pre-remove".to_string()),
+                    ..Default::default()
+                }],
                 &approvals_path,
             )
             .unwrap();
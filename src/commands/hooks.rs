@@ -127,11 +127,15 @@ pub(crate) fn prepare_and_check(
         }
 
         let steps = prepare_steps(config, ctx, extra_vars, hook_type, source)?.validated()?;
+        let sandbox = ctx.hook_sandbox(source);
+        let container = ctx.hook_container(source);
         for step in steps {
             if let Some(filtered) = filter_step_by_name(step, source, &parsed_filters) {
                 result.push(SourcedStep {
                     step: filtered,
                     source,
+                    sandbox: sandbox.clone(),
+                    container: container.clone(),
                 });
             }
         }
@@ -500,6 +504,8 @@ fn spawn_hook_pipeline_quiet(repo: &Repository, pipeline: &PendingPipeline) -> a
                 name: cmd.name.clone(),
                 template_name: cmd.template_name.clone(),
                 template: cmd.template.clone(),
+                sandbox: s.sandbox.clone(),
+                container: s.container.clone(),
             },
             PreparedStep::Concurrent(cmds) => PipelineStepSpec::Concurrent {
                 commands: cmds
@@ -510,6 +516,8 @@ fn spawn_hook_pipeline_quiet(repo: &Repository, pipeline: &PendingPipeline) -> a
                         template: c.template.clone(),
                     })
                     .collect(),
+                sandbox: s.sandbox.clone(),
+                container: s.container.clone(),
             },
         })
         .collect();
@@ -549,7 +557,13 @@ fn spawn_hook_pipeline_quiet(repo: &Repository, pipeline: &PendingPipeline) -> a
         );
     } else {
         let cmd_display = format!("{} hook run-pipeline", wt_bin.display());
-        worktrunk::command_log::log_command(&log_label, &cmd_display, None, None);
+        worktrunk::command_log::log_command(
+            &log_label,
+            pipeline.branch.as_deref(),
+            &cmd_display,
+            None,
+            None,
+        );
     }
 
     Ok(())
@@ -598,6 +612,8 @@ pub(crate) fn sourced_steps_to_foreground(
                 redirect_stdout_to_stderr,
                 error_wrapper,
                 directives,
+                sandbox: sourced.sandbox,
+                container: sourced.container,
             }
         })
         .collect()
@@ -44,8 +44,9 @@ pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
 /// Bash and PowerShell need no transform: Homebrew sources bash files and PowerShell
 /// registrations are sourced into the profile.
 ///
-/// Nushell uses template-based integration (the shell wrapper and completer in one
-/// file), which is already dynamic, so its output is the full `init` template.
+/// Nushell, Xonsh and Elvish use template-based integration (the shell wrapper and
+/// completer in one file), which is already dynamic, so their output is the full
+/// `init` template.
 ///
 /// Unlike `wt config shell init`, this does not:
 /// - Modify any files
@@ -84,13 +85,13 @@ pub fn handle_completions(shell: shell::Shell) -> anyhow::Result<()> {
             let registration = super::configure_shell::fish_completion_content(&cmd_name);
             write!(stdout, "{}", registration).context("failed to write to stdout")?;
         }
-        shell::Shell::Nushell => {
-            // Nushell uses template-based integration (shell wrapper + completions in one)
-            // Unlike other shells, it doesn't use clap_complete
+        shell::Shell::Nushell | shell::Shell::Xonsh | shell::Shell::Elvish => {
+            // Template-based integration (shell wrapper + completions in one).
+            // clap_complete has no registration for these shells
             let init = shell::ShellInit::with_prefix(shell, cmd_name.clone());
             let code = init
                 .generate()
-                .context("failed to generate nushell integration")?;
+                .with_context(|| format!("failed to generate {shell} integration"))?;
             write!(stdout, "{}", code).context("failed to write to stdout")?;
         }
         shell::Shell::PowerShell => {
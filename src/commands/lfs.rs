@@ -0,0 +1,54 @@
+//! Git LFS: pointer checkouts hydrated from the shared cache (mechanism in
+//! [`worktrunk::git::lfs`], hydration in [`worktrunk::lifecycle`]).
+//!
+//! - `wt switch --create` checks LFS files out as pointers, then hydrates
+//!   them before `pre-start` hooks run.
+//! - `wt step copy-ignored` hydrates the destination's pointers, for
+//!   worktrees created outside worktrunk.
+//! - `wt list` shows each worktree's hydrated/tracked LFS file counts.
+
+use worktrunk::git::Repository;
+use worktrunk::git::lfs;
+
+use super::list::model::ListItem;
+
+/// Give each local worktree row its LFS state. No-op unless the repository
+/// uses LFS — a cached answer per `HEAD` commit, read off the current row
+/// when there is one; rows are measured in parallel, and one that can't be
+/// leaves its row without a state.
+pub(crate) fn attach_lfs_states(items: &mut [ListItem], repo: &Repository) {
+    let current_head = items
+        .iter()
+        .find(|item| item.worktree_data().is_some_and(|data| data.is_current))
+        .map(|item| item.head.as_str());
+    let uses_lfs = match current_head {
+        Some(head) => lfs::uses_lfs_at(repo, head),
+        None => lfs::uses_lfs(repo),
+    };
+    if !uses_lfs {
+        return;
+    }
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let data = item.worktree_data()?;
+                if data.host.is_some() {
+                    return None;
+                }
+                let path = data.path.clone();
+                Some((
+                    i,
+                    scope.spawn(move || lfs::state(&repo.worktree_at(path)).ok()),
+                ))
+            })
+            .collect();
+        for (i, handle) in handles {
+            let state = handle.join().ok().flatten();
+            if let Some(data) = items[i].worktree_data_mut() {
+                data.lfs = state;
+            }
+        }
+    });
+}
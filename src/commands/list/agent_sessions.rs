@@ -0,0 +1,95 @@
+//! The `wt list` Agent column and JSON `agents` field, from the session
+//! records `wt config state activity` keeps under `.git/wt/activity/`.
+//!
+//! Attached before the table skeleton renders, like custom columns: the
+//! records are a handful of small files, so reading them costs no subprocess,
+//! and the cell text is rendered once so its width is measured from content
+//! and every repaint shows the same duration. A table where no worktree has a
+//! session drops the column entirely (`ColumnKind::Agent` with width 0).
+
+use worktrunk::activity::{Activity, Session};
+use worktrunk::git::Repository;
+use worktrunk::path::paths_match;
+
+use super::model::ListItem;
+use crate::display::format_duration_short;
+
+/// Give each worktree row its sessions, most recently active first, and the
+/// rendered Agent cell.
+pub fn attach_agent_sessions(items: &mut [ListItem], repo: &Repository, now: u64) {
+    let sessions = Activity::for_repo(repo).sessions();
+    if sessions.is_empty() {
+        return;
+    }
+    for item in items {
+        let Some(path) = item.worktree_path() else {
+            continue;
+        };
+        let mut matched: Vec<Session> = sessions
+            .iter()
+            .filter(|session| paths_match(&session.worktree, path))
+            .cloned()
+            .collect();
+        matched.sort_by_key(|session| std::cmp::Reverse(session.last_activity_at));
+        item.agent_cell = agent_cell(&matched, now);
+        item.agent_sessions = matched;
+    }
+}
+
+/// The most recently active session's tool, state symbol, and time in that
+/// state, plus a count of the others: `claude 💬 14m +1`. Empty without
+/// sessions.
+fn agent_cell(sessions: &[Session], now: u64) -> String {
+    let Some(latest) = sessions.first() else {
+        return String::new();
+    };
+    let mut cell = format!(
+        "{} {} {}",
+        latest.tool,
+        latest.state.symbol(),
+        format_duration_short(now.saturating_sub(latest.state_since))
+    );
+    if sessions.len() > 1 {
+        cell.push_str(&format!(" +{}", sessions.len() - 1));
+    }
+    cell
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use worktrunk::activity::AgentState;
+
+    fn session(tool: &str, state: AgentState, state_since: u64) -> Session {
+        Session {
+            tool: tool.to_string(),
+            session_id: None,
+            worktree: PathBuf::from("/repo.feature"),
+            branch: Some("feature".to_string()),
+            state,
+            started_at: 0,
+            state_since,
+            last_activity_at: state_since,
+        }
+    }
+
+    #[test]
+    fn test_agent_cell() {
+        assert_eq!(agent_cell(&[], 1000), "");
+        assert_eq!(
+            agent_cell(&[session("claude", AgentState::Waiting, 100)], 1000),
+            "claude 💬 15m"
+        );
+        assert_eq!(
+            agent_cell(
+                &[
+                    session("codex", AgentState::Working, 990),
+                    session("claude", AgentState::Idle, 100),
+                ],
+                1000
+            ),
+            "codex 🤖 10s +1"
+        );
+    }
+}
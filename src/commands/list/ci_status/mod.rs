@@ -19,6 +19,7 @@ use anstyle::{AnsiColor, Color, Style};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use worktrunk::git::{BranchRef, Repository, parse_owner_repo};
+use worktrunk::notify::{Notification, notify};
 use worktrunk::shell_exec::Cmd;
 use worktrunk::utils::epoch_now;
 
@@ -600,6 +601,10 @@ impl PrStatus {
                 cached.status
             }
             cached => {
+                let previous = cached
+                    .as_ref()
+                    .and_then(|c| c.status.as_ref())
+                    .map(|s| s.ci_status);
                 if let Some(cached) = cached {
                     tracing::debug!(
                         branch = %branch.full_name,
@@ -625,6 +630,20 @@ impl PrStatus {
                 };
                 cached.write(repo, &branch.full_name);
 
+                // Only a change between two fetches notifies: a first fetch
+                // has nothing to compare against, and a failed fetch (rate
+                // limit, network) says nothing about CI.
+                if let (Some(from), Some(to)) = (previous, status.as_ref().map(|s| s.ci_status))
+                    && from != to
+                    && from != CiStatus::Error
+                    && to != CiStatus::Error
+                {
+                    notify(
+                        &repo.user_config().notify,
+                        &Notification::ci(&branch.full_name, from.into(), to.into()),
+                    );
+                }
+
                 status
             }
         };
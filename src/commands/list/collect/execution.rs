@@ -27,10 +27,10 @@ use worktrunk::git::{BranchRef, Repository, WorktreeInfo};
 use super::super::model::{ItemKind, ListItem, UpstreamStatus, WorkingTreeStatus};
 use super::CollectOptions;
 use super::tasks::{
-    AheadBehindTask, BranchDiffTask, CiStatusTask, CommittedTreesMatchTask, GitOperationTask,
-    HasFileChangesTask, IsAncestorTask, MergeTreeConflictsTask, SummaryGenerateTask, Task,
-    TaskContext, UpstreamTask, UrlStatusTask, UserMarkerTask, WorkingTreeConflictsTask,
-    WorkingTreeDiffTask, WouldMergeAddTask,
+    AheadBehindTask, BranchDiffTask, CiStatusTask, CommittedTreesMatchTask, ContainerStateTask,
+    GitOperationTask, HasFileChangesTask, IsAncestorTask, MergeTreeConflictsTask,
+    SummaryGenerateTask, Task, TaskContext, UpstreamTask, UrlStatusTask, UserMarkerTask,
+    WorkingTreeConflictsTask, WorkingTreeDiffTask, WouldMergeAddTask,
 };
 use super::types::{TaskError, TaskKind, TaskResult};
 
@@ -101,6 +101,7 @@ fn dispatch_task(kind: TaskKind, ctx: TaskContext) -> Result<TaskResult, TaskErr
         TaskKind::CiStatus => CiStatusTask::compute(ctx),
         TaskKind::UrlStatus => UrlStatusTask::compute(ctx),
         TaskKind::SummaryGenerate => SummaryGenerateTask::compute(ctx),
+        TaskKind::ContainerState => ContainerStateTask::compute(ctx),
     }
 }
 
@@ -246,6 +247,11 @@ pub(super) fn seed_skipped_task_defaults(item: &mut ListItem, kind: TaskKind) {
                 data.git_operation = Some(None);
             }
         }
+        TaskKind::ContainerState => {
+            if let ItemKind::Worktree(data) = &mut item.kind {
+                data.container = Some(None);
+            }
+        }
     }
 }
 
@@ -284,10 +290,14 @@ pub(super) fn seed_prunable_item(item: &mut ListItem) {
         working_tree: Some(WorkingTreeStatus::default()),
         operation_state: Some(OperationState::None),
         worktree_state: Some(WorktreeState::Prunable),
+        submodules: Default::default(),
         main_state: Some(MainState::None),
         upstream_divergence: Some(Divergence::None),
         user_marker: Some(None),
     };
+    if let ItemKind::Worktree(data) = &mut item.kind {
+        data.container = Some(None);
+    }
 }
 
 // ============================================================================
@@ -347,12 +357,14 @@ pub fn work_items_for_worktree(
             wt.branch.as_ref().and_then(|branch| {
                 let mut vars = std::collections::HashMap::new();
                 vars.insert("branch", branch.as_str());
-                worktrunk::config::expand_template(
+                // `Recorded`: listing a worktree mustn't assign it ports.
+                worktrunk::config::expand_template_with(
                     template,
                     &vars,
                     worktrunk::shell_exec::ShellEscapeMode::Literal,
                     repo,
                     "url-template",
+                    worktrunk::config::VarsMode::Recorded,
                 )
                 .ok()
             })
@@ -384,6 +396,7 @@ pub fn work_items_for_worktree(
         integration_targets: options.integration_targets.clone(),
         snapshot: options.snapshot.clone(),
         include_untracked_in_working_diff: options.include_untracked_in_working_diff,
+        container_runtime: options.container_runtime.clone(),
     };
 
     let has_commits = wt.has_commits();
@@ -408,6 +421,7 @@ pub fn work_items_for_worktree(
         TaskKind::CiStatus,
         TaskKind::WouldMergeAdd,
         TaskKind::SummaryGenerate,
+        TaskKind::ContainerState,
     ] {
         let will_skip = !run.contains(&kind) || (!has_commits && COMMIT_TASKS.contains(&kind));
         if will_skip {
@@ -498,6 +512,7 @@ pub fn work_items_for_branch(
         // Branches have no working tree; the flag is only consumed by
         // WorkingTreeDiffTask, which doesn't run for branch items.
         include_untracked_in_working_diff: false,
+        container_runtime: None,
     };
 
     let mut items = Vec::with_capacity(11);
@@ -540,6 +555,7 @@ pub fn work_items_for_branch(
         TaskKind::WorkingTreeDiff,
         TaskKind::WorkingTreeConflicts,
         TaskKind::GitOperation,
+        TaskKind::ContainerState,
     ] {
         seed_skipped_task_defaults(item, kind);
     }
@@ -649,6 +665,7 @@ mod tests {
             summary_enabled: true,
             has_llm_command: true,
             has_url_template: false,
+            has_container: false,
         };
         let options = CollectOptions {
             url_template: Some("http://localhost/{{ branch }}".to_string()),
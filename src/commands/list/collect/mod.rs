@@ -475,6 +475,11 @@ pub struct CollectOptions {
     /// `HEAD±`. Set by `wt list --full` and `wt statusline`; consumed
     /// in `tasks.rs` where the cost/cutover rationale lives.
     pub include_untracked_in_working_diff: bool,
+
+    /// Container runtime for the Container column. `None` unless the project
+    /// configures `[container]` and the user approved it — project config is
+    /// untrusted until then, so nothing runs the runtime it names.
+    pub container_runtime: Option<String>,
 }
 
 impl CollectOptions {
@@ -504,6 +509,7 @@ impl CollectOptions {
             integration_targets: None,
             snapshot: None,
             include_untracked_in_working_diff: false,
+            container_runtime: None,
         }
     }
 }
@@ -613,6 +619,8 @@ pub enum ShowConfig {
         cli_branches: bool,
         cli_remotes: bool,
         cli_full: bool,
+        /// Append worktrees from the `[remotes]` hosts in user config.
+        hosts: bool,
     },
 }
 
@@ -788,6 +796,11 @@ fn format_drain_timeout_diag(
 ///   skeleton into the final table.
 /// - [`RenderTarget::Table { progressive: false }`]: collects silently, then
 ///   prints the final table once.
+/// - [`RenderTarget::Document`]: collects silently, then prints the table's
+///   columns once as a Markdown, CSV/TSV or HTML document.
+/// - [`RenderTarget::JsonStream`]: prints a header and one schema-2 record
+///   per row once the skeleton is known, then a patch per row change as
+///   results land.
 /// - [`RenderTarget::Json`]: collects silently and returns data without
 ///   writing to stdout. Used by `--format=json` and the picker (which has its
 ///   own progressive UI driven via `ShowConfig::Resolved::progressive_handler`).
@@ -807,8 +820,20 @@ pub fn collect(
     let repo = redirected.as_ref().unwrap_or(repo);
     let show_progress = matches!(render_target, RenderTarget::Table { progressive: true });
     let render_table = matches!(render_target, RenderTarget::Table { .. });
+    let render_document = match render_target {
+        RenderTarget::Document(format) => Some(format),
+        _ => None,
+    };
     worktrunk::trace::instant("List collect started");
 
+    // Remote hosts answer over SSH, far slower than any local git command, so
+    // their fetches start first and are joined just before layout.
+    let pending_hosts = matches!(
+        show_config,
+        ShowConfig::DeferredToParallel { hosts: true, .. }
+    )
+    .then(|| super::remote_hosts::PendingHosts::spawn(repo));
+
     // Determine what to fetch speculatively in the parallel phase.
     //
     // For Resolved: respect the caller's flags (fetch only what's requested).
@@ -933,6 +958,7 @@ pub fn collect(
             cli_branches,
             cli_remotes,
             cli_full,
+            ..
         } => {
             let config = repo.config();
             let show_branches = cli_branches || config.list.branches();
@@ -1182,6 +1208,8 @@ pub fn collect(
                 status_symbols: StatusSymbols::default(),
                 statusline: None,
                 custom_values: Vec::new(),
+                agent_sessions: Vec::new(),
+                agent_cell: String::new(),
                 seeded: super::model::SeededFacts::default(),
                 kind: ItemKind::Worktree(Box::new(worktree_data)),
             }
@@ -1253,6 +1281,27 @@ pub fn collect(
         );
     }
 
+    // Agent sessions are small local files too; attach them (and render the
+    // Agent cell) now so the column's width is known at layout time.
+    super::agent_sessions::attach_agent_sessions(
+        &mut all_items,
+        repo,
+        worktrunk::utils::epoch_now(),
+    );
+    // LFS states likewise, in LFS repositories only.
+    crate::commands::lfs::attach_lfs_states(&mut all_items, repo);
+
+    // Host rows go last, after every index the task spawns below address.
+    // The progressive table and `--format=ndjson` don't wait for them: a slow
+    // host would hold the skeleton for its whole timeout, so their rows are
+    // appended once the local drain finishes. Every other target renders
+    // once, and joins here so layout measures the host rows too.
+    let defer_hosts = show_progress || matches!(render_target, RenderTarget::JsonStream);
+    let mut pending_hosts = pending_hosts;
+    if !defer_hosts && let Some(pending) = pending_hosts.take() {
+        all_items.extend(pending.join(emit_warning));
+    }
+
     // `[list] columns` selects/reorders the columns to render. Names address
     // built-ins or custom columns (by header → resolved index), so the custom
     // names are passed in resolution order. Like custom columns, a bad name
@@ -1270,23 +1319,31 @@ pub fn collect(
             Err(e) => return Err(e),
         };
 
+    // The Container column's runtime — only for an approved `[container]`,
+    // since the section (and the runtime it names) comes from project config.
+    // One approvals-file read; the per-row `inspect`s run as tasks.
+    let container_runtime = crate::commands::container::approved(repo)
+        .map(|config| worktrunk::container::runtime(&config));
+
     // Decide, in one place, which background tasks to run: the union of the
     // tasks every column the rendered table needs. This is the canonical "what
     // do we need" stage — the spawn loop fires exactly this set, and the layout
     // filter renders exactly the columns it feeds. Three shapes:
     //
-    // - `wt list` table → the `[list] columns` selection (source `Listed`), or
+    // - `wt list` table or document (`--format=markdown|csv|tsv|html`) → the
+    //   `[list] columns` selection (source `Listed`), or
     //   `all_columns` (source `Default`) when nothing narrows it. The `Listed`
     //   source lets an explicit selection override the preset gates (`--full`,
     //   `[list] summary`): listing `ci` runs its task without `--full`. The
-    //   data-source gates (`[commit.generation]`, a url template) still drop a
-    //   column whose data can't be produced, however it was requested.
+    //   data-source gates (`[commit.generation]`, a url template, an approved
+    //   `[container]`) still drop a column whose data can't be produced,
+    //   however it was requested.
     // - picker → `all_columns` for its preview tabs, unioned with the selection's
     //   forced-on columns so its table matches `wt list`'s. The union matters for
     //   a listed `summary` that `Default` alone wouldn't plan (LLM command set,
     //   `[list] summary` off): without it the picker would hide a column `wt list`
     //   shows. CI is already covered — the picker is always `show_full`.
-    // - `--format json` → `all_columns` alone (source `Default`), so the
+    // - `--format json` / `ndjson` → `all_columns` alone (source `Default`), so the
     //   selection reaches it in neither direction: the every-field contract
     //   (`src/cli/mod.rs`) rules out narrowing, and a display setting must not
     //   decide whether a machine-readable call reaches a forge — `--full` is
@@ -1300,6 +1357,7 @@ pub fn collect(
         summary_enabled: config.list.summary(),
         has_llm_command: llm_command.is_some(),
         has_url_template: url_template.is_some(),
+        has_container: container_runtime.is_some(),
     };
     let listed_plan = || {
         super::columns::required_tasks_for_render(
@@ -1323,7 +1381,7 @@ pub fn collect(
         let mut tasks = full_plan();
         tasks.extend(listed_plan());
         tasks
-    } else if render_table && !selected_columns.is_empty() {
+    } else if (render_table || render_document.is_some()) && !selected_columns.is_empty() {
         listed_plan()
     } else {
         // `wt list` with no selection, and every `--format json` run.
@@ -1357,6 +1415,12 @@ pub fn collect(
     let width = list_width.or_else(terminal_width).unwrap_or(usize::MAX);
     let destination = if progressive_handler.is_some() {
         super::layout::Destination::picker(width)
+    } else if render_document.is_some() {
+        // Documents have no width to fit and no terminal to carry OSC 8.
+        super::layout::Destination {
+            width: usize::MAX,
+            link_style: super::layout::LinkStyle::Expanded,
+        }
     } else {
         super::layout::Destination::terminal(width)
     };
@@ -1396,6 +1460,7 @@ pub fn collect(
         integration_targets: None,
         snapshot: None,
         include_untracked_in_working_diff,
+        container_runtime,
     };
 
     // Track expected results per item - populated as spawns are queued
@@ -1803,6 +1868,15 @@ pub fn collect(
     // reach the screen ahead of every task-driven column. `render_skeleton_row`
     // fills them from `item.commit` while each task column keeps its blank
     // placeholder.
+    //
+    // `--format=ndjson` streams its header and one record per row at the same
+    // point, for the same reason: identity and commit are known, and the
+    // drain that fills in everything else hasn't started.
+    let mut json_stream = matches!(render_target, RenderTarget::JsonStream).then(|| {
+        let mut stream = super::json_stream::JsonStream::start(repo, collected, &custom_columns);
+        stream.items(&all_items);
+        stream
+    });
     if progressive_table.is_some() || progressive_handler.is_some() {
         let commit_rows: Vec<String> = all_items
             .iter()
@@ -1876,6 +1950,10 @@ pub fn collect(
                 results::DrainEvent::Result { item_idx, item } => {
                     has_data[item_idx] = true;
 
+                    if let Some(stream) = json_stream.as_mut() {
+                        stream.update(item_idx, item);
+                    }
+
                     // JSON and buffered-table modes render once at the end,
                     // not per-result.
                     if progressive_state.is_none() && progressive_handler.is_none() {
@@ -1995,6 +2073,20 @@ pub fn collect(
     // command.
     handle_drain_timeout(drain_outcome, collect_deadline, &emit_warning);
 
+    // Host rows deferred past the skeleton. Their warnings wait for the table
+    // to finalize: a stderr line in the live table region would throw off its
+    // cursor math.
+    let mut host_warnings = Vec::new();
+    if let Some(pending) = pending_hosts {
+        let warnings = std::cell::RefCell::new(Vec::new());
+        let local_rows = all_items.len();
+        all_items.extend(pending.join(|line| warnings.borrow_mut().push(line)));
+        host_warnings = warnings.into_inner();
+        if let Some(stream) = json_stream.as_mut() {
+            stream.items(&all_items[local_rows..]);
+        }
+    }
+
     // The drain calls `refresh_status_symbols` after every *successful*
     // result, but items with zero successful results (all tasks errored
     // or timed out) never hit that path. Sweep every item so that
@@ -2028,6 +2120,14 @@ pub fn collect(
         return Ok(None);
     }
 
+    for warning in host_warnings {
+        emit_warning(warning);
+    }
+
+    if let Some(format) = render_document {
+        println!("{}", super::document::render(format, &layout, &all_items));
+    }
+
     // Status symbols are now computed during data collection (both modes), no fallback needed
 
     // Display collection errors/warnings (after table rendering)
@@ -2074,6 +2174,10 @@ pub fn collect(
         item.finalize_display();
     }
 
+    if let Some(stream) = json_stream {
+        stream.finish(&all_items);
+    }
+
     // all_items now contains both worktrees and branches (if requested)
     let items = all_items;
 
@@ -2081,6 +2185,8 @@ pub fn collect(
     // - `RenderTarget::Table { progressive: true }`: rows morphed in place,
     //   footer became summary
     // - `RenderTarget::Table { progressive: false }`: rendered final table
+    // - `RenderTarget::Document`: rendered the document over the table's columns
+    // - `RenderTarget::JsonStream`: streamed records, ending with `end` above
     // - `RenderTarget::Json`: no stdout rendering; data returned for the
     //   caller to serialize (`wt list --format=json`) or feed into its own
     //   UI (picker via `progressive_handler`)
@@ -2196,6 +2302,8 @@ pub fn build_worktree_item(
         status_symbols: StatusSymbols::default(),
         statusline: None,
         custom_values: Vec::new(),
+        agent_sessions: Vec::new(),
+        agent_cell: String::new(),
         seeded: super::model::SeededFacts::default(),
         kind: ItemKind::Worktree(Box::new(WorktreeData::from_worktree(
             wt,
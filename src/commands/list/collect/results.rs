@@ -301,12 +301,14 @@ pub(super) fn drain_results_with_timings(
                 working_tree_diff,
                 working_tree_status,
                 has_conflicts,
+                submodule_drift,
                 ..
             } => {
                 if let ItemKind::Worktree(data) = &mut item.kind {
                     data.working_tree_diff = Some(working_tree_diff);
                     data.working_tree_status = Some(working_tree_status);
                     data.has_conflicts = Some(has_conflicts);
+                    data.submodule_drift = Some(submodule_drift);
                 } else {
                     debug_assert!(false, "WorkingTreeDiff result for non-worktree item");
                 }
@@ -359,6 +361,13 @@ pub(super) fn drain_results_with_timings(
             TaskResult::SummaryGenerate { summary, .. } => {
                 item.summary = Some(summary);
             }
+            TaskResult::ContainerState { state, .. } => {
+                if let ItemKind::Worktree(data) = &mut item.kind {
+                    data.container = Some(state);
+                } else {
+                    debug_assert!(false, "ContainerState result for non-worktree item");
+                }
+            }
         }
 
         // Refresh status symbols. Each gate resolves independently once
@@ -408,6 +417,7 @@ mod tests {
             data.working_tree_diff = Some(LineDiff::default());
             data.working_tree_status = Some(WorkingTreeStatus::default());
             data.has_conflicts = Some(false);
+            data.submodule_drift = Some(Default::default());
         }
         item.counts = Some(AheadBehind {
             ahead: 3,
@@ -9,8 +9,10 @@ use std::time::Duration;
 use std::sync::Arc;
 
 use anyhow::Context;
+use worktrunk::git::submodule::SubmoduleDrift;
 use worktrunk::git::{
-    ErrorExt, IntegrationTargets, LineDiff, RefSnapshot, Repository, select_comparison_base,
+    ErrorExt, IntegrationTargets, LineDiff, PorcelainStatus, RefSnapshot, Repository,
+    select_comparison_base,
 };
 
 use super::super::ci_status::{CiBranchName, PrStatus};
@@ -89,6 +91,9 @@ pub struct TaskContext {
     /// Whether `WorkingTreeDiffTask` should include untracked files in
     /// its `HEAD±` line counts. See `CollectOptions` for rationale.
     pub include_untracked_in_working_diff: bool,
+    /// Container runtime for `ContainerStateTask`; set only once the
+    /// project's `[container]` section is approved.
+    pub container_runtime: Option<String>,
 }
 
 impl TaskContext {
@@ -596,11 +601,20 @@ impl Task for WorkingTreeDiffTask {
                 .map_err(|e| ctx.error(Self::KIND, &e))?
         };
 
+        // A drifted submodule always shows in the porcelain (as a modified
+        // path), so a clean worktree has none and skips the second status.
+        let submodule_drift = if is_dirty {
+            worktrunk::git::submodule::drift(&wt).map_err(|e| ctx.error(Self::KIND, &e))?
+        } else {
+            SubmoduleDrift::default()
+        };
+
         Ok(TaskResult::WorkingTreeDiff {
             item_idx: ctx.item_idx,
             working_tree_diff,
             working_tree_status,
             has_conflicts,
+            submodule_drift,
         })
     }
 }
@@ -980,6 +994,35 @@ impl Task for SummaryGenerateTask {
     }
 }
 
+/// Task 15: `[container]` container state, one runtime `inspect` per worktree.
+///
+/// Planned only once the project's `[container]` is approved — until then
+/// the runtime isn't run at all. A runtime that can't answer (not installed,
+/// daemon down) leaves the cell empty rather than warning on every row.
+pub struct ContainerStateTask;
+
+impl Task for ContainerStateTask {
+    const KIND: TaskKind = TaskKind::ContainerState;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let state = ctx
+            .container_runtime
+            .as_deref()
+            .zip(ctx.branch_ref.worktree_path.as_deref())
+            .and_then(|(runtime, path)| {
+                let name = worktrunk::container::container_name(path);
+                worktrunk::container::states(runtime, std::slice::from_ref(&name))
+                    .ok()?
+                    .remove(&name)
+            });
+
+        Ok(TaskResult::ContainerState {
+            item_idx: ctx.item_idx,
+            state,
+        })
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -1007,67 +1050,15 @@ pub(crate) fn parse_port_from_url(url: &str) -> Option<u16> {
 /// Parse git status output to extract working tree status and conflict state.
 /// Returns (WorkingTreeStatus, is_dirty, has_conflicts).
 pub(super) fn parse_working_tree_status(status_output: &str) -> (WorkingTreeStatus, bool, bool) {
-    let mut has_untracked = false;
-    let mut has_modified = false;
-    let mut has_staged = false;
-    let mut has_renamed = false;
-    let mut has_deleted = false;
-    let mut has_conflicts = false;
-
-    for line in status_output.lines() {
-        if line.len() < 2 {
-            continue;
-        }
-
-        let bytes = line.as_bytes();
-        let index_status = bytes[0] as char;
-        let worktree_status = bytes[1] as char;
-
-        if index_status == '?' && worktree_status == '?' {
-            has_untracked = true;
-        }
-
-        // Worktree changes: M = modified, A = intent-to-add (git add -N), T = type change (file↔symlink)
-        if matches!(worktree_status, 'M' | 'A' | 'T') {
-            has_modified = true;
-        }
-
-        // Index changes: A = added, M = modified, C = copied, T = type change (file↔symlink)
-        if matches!(index_status, 'A' | 'M' | 'C' | 'T') {
-            has_staged = true;
-        }
-
-        if index_status == 'R' {
-            has_renamed = true;
-        }
-
-        if index_status == 'D' || worktree_status == 'D' {
-            has_deleted = true;
-        }
-
-        // Detect unmerged/conflicting paths (porcelain v1 two-letter codes)
-        // Only U codes and AA/DD indicate actual merge conflicts.
-        // AD/DA are normal staging states (staged then deleted, or deleted then restored).
-        let is_unmerged_pair = matches!(
-            (index_status, worktree_status),
-            ('U', _) | (_, 'U') | ('A', 'A') | ('D', 'D')
-        );
-        if is_unmerged_pair {
-            has_conflicts = true;
-        }
-    }
-
+    let status = PorcelainStatus::parse(status_output);
     let working_tree_status = WorkingTreeStatus::new(
-        has_staged,
-        has_modified,
-        has_untracked,
-        has_renamed,
-        has_deleted,
+        status.staged,
+        status.modified,
+        status.untracked,
+        status.renamed,
+        status.deleted,
     );
-
-    let is_dirty = working_tree_status.is_dirty();
-
-    (working_tree_status, is_dirty, has_conflicts)
+    (working_tree_status, status.is_dirty(), status.conflicted)
 }
 
 /// Check if `git status --porcelain` output contains unmerged entries.
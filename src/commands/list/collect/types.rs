@@ -5,6 +5,8 @@
 //! - `TaskError` and `ErrorCause` - error handling for failed tasks
 //! - `DrainOutcome` and `MissingResult` - timeout diagnostic info
 
+use worktrunk::container::ContainerState;
+use worktrunk::git::submodule::SubmoduleDrift;
 use worktrunk::git::{InProgressOperation, LineDiff};
 
 use super::super::ci_status::PrStatus;
@@ -65,6 +67,8 @@ pub(crate) enum TaskResult {
         /// Working tree change flags
         working_tree_status: WorkingTreeStatus,
         has_conflicts: bool,
+        /// Submodule drift; only measured when the worktree is dirty
+        submodule_drift: SubmoduleDrift,
     },
     /// Potential merge conflicts with default branch (merge-tree simulation on committed HEAD)
     MergeTreeConflicts {
@@ -116,6 +120,11 @@ pub(crate) enum TaskResult {
         item_idx: usize,
         summary: Option<String>,
     },
+    /// State of the worktree's `[container]` container (None = no container)
+    ContainerState {
+        item_idx: usize,
+        state: Option<ContainerState>,
+    },
 }
 
 impl TaskResult {
@@ -136,7 +145,8 @@ impl TaskResult {
             | TaskResult::Upstream { item_idx, .. }
             | TaskResult::CiStatus { item_idx, .. }
             | TaskResult::UrlStatus { item_idx, .. }
-            | TaskResult::SummaryGenerate { item_idx, .. } => *item_idx,
+            | TaskResult::SummaryGenerate { item_idx, .. }
+            | TaskResult::ContainerState { item_idx, .. } => *item_idx,
         }
     }
 }
@@ -173,6 +183,7 @@ impl TaskKind {
             TaskKind::CiStatus => "CI status",
             TaskKind::UrlStatus => "URL check",
             TaskKind::SummaryGenerate => "summary generation",
+            TaskKind::ContainerState => "container state",
         }
     }
 }
@@ -14,6 +14,15 @@ pub enum ColumnKind {
     CiStatus,
     Path,
     Url, // Dev server URL from project config template
+    /// Most recently active agent session in the worktree, from
+    /// `.git/wt/activity/`. Rendered before layout like custom columns.
+    Agent,
+    /// State of the worktree's `[container]` container, once the section is
+    /// approved.
+    Container,
+    /// Hydrated/tracked LFS files of the worktree. Rendered before layout
+    /// like Agent.
+    Lfs,
     Commit,
     Time,
     Message,
@@ -36,6 +45,9 @@ impl ColumnKind {
             ColumnKind::Path => "Path",
             ColumnKind::Upstream => "Remote⇅",
             ColumnKind::Url => "URL",
+            ColumnKind::Agent => "Agent",
+            ColumnKind::Container => "Container",
+            ColumnKind::Lfs => "LFS",
             ColumnKind::Time => "Age",
             ColumnKind::CiStatus => "CI",
             ColumnKind::Commit => "Commit",
@@ -80,6 +92,9 @@ impl ColumnKind {
             ColumnKind::CiStatus => "ci",
             ColumnKind::Path => "path",
             ColumnKind::Url => "url",
+            ColumnKind::Agent => "agent",
+            ColumnKind::Container => "container",
+            ColumnKind::Lfs => "lfs",
             ColumnKind::Commit => "commit",
             ColumnKind::Time => "age",
             ColumnKind::Message => "message",
@@ -116,8 +131,8 @@ impl ColumnKind {
     ///
     /// `Status` aggregates almost every status-feeding task (the five
     /// `refresh_status_symbols` gates); identity columns (Branch, Path, Commit,
-    /// Age, Message), the always-on Gutter, and custom columns are derived
-    /// without any task.
+    /// Age, Message), the always-on Gutter, Agent, and custom columns are
+    /// derived without any task.
     ///
     /// Drift guard: `test_required_tasks_cover_every_task` asserts the union
     /// across all built-ins is exactly the full `TaskKind` set, so a new task
@@ -152,12 +167,15 @@ impl ColumnKind {
             ColumnKind::CiStatus => &[TaskKind::CiStatus],
             ColumnKind::Url => &[TaskKind::UrlStatus],
             ColumnKind::Summary => &[TaskKind::SummaryGenerate],
+            ColumnKind::Container => &[TaskKind::ContainerState],
             ColumnKind::Gutter
             | ColumnKind::Branch
             | ColumnKind::Path
             | ColumnKind::Commit
             | ColumnKind::Time
             | ColumnKind::Message
+            | ColumnKind::Agent
+            | ColumnKind::Lfs
             | ColumnKind::Custom(_) => &[],
         }
     }
@@ -200,8 +218,9 @@ pub fn all_tasks() -> std::collections::HashSet<TaskKind> {
 /// Two kinds, distinguished by [`column_renders`]. *Preset* gates (`show_full`,
 /// `summary_enabled`) bundle columns into the default table; a column named
 /// outright in `[list] columns` overrides them. *Data-source* gates
-/// (`has_llm_command`, `has_url_template`) are hard: without the command or
-/// template there's nothing to render, so they hold even for a listed column.
+/// (`has_llm_command`, `has_url_template`, `has_container`) are hard: without
+/// the command, template or container there's nothing to render, so they hold
+/// even for a listed column.
 #[derive(Clone, Copy, Debug)]
 pub struct ColumnGates {
     /// `--full` (or `[list] full`): CI status and LLM summaries join the default
@@ -215,6 +234,9 @@ pub struct ColumnGates {
     pub has_llm_command: bool,
     /// A `[list] url` template is configured. A data source: no template, no url.
     pub has_url_template: bool,
+    /// An approved `[container]` section. A data source: until it's approved,
+    /// nothing may run the runtime it names.
+    pub has_container: bool,
 }
 
 /// How a column entered the rendered set, which decides whether the preset gates
@@ -244,6 +266,7 @@ fn column_renders(kind: ColumnKind, source: ColumnSource, gates: &ColumnGates) -
             gates.has_llm_command && (listed || (gates.show_full && gates.summary_enabled))
         }
         ColumnKind::Url => gates.has_url_template,
+        ColumnKind::Container => gates.has_container,
         _ => true,
     }
 }
@@ -376,6 +399,9 @@ pub const COLUMN_SPECS: &[ColumnSpec] = &[
     ColumnSpec::new(ColumnKind::CiStatus, 5),
     ColumnSpec::new(ColumnKind::Path, 7),
     ColumnSpec::new(ColumnKind::Url, 9),
+    ColumnSpec::new(ColumnKind::Agent, 14),
+    ColumnSpec::new(ColumnKind::Container, 15),
+    ColumnSpec::new(ColumnKind::Lfs, 16),
     ColumnSpec::new(ColumnKind::Commit, 11),
     ColumnSpec::new(ColumnKind::Time, 12),
     ColumnSpec::new(ColumnKind::Message, 13),
@@ -384,7 +410,7 @@ pub const COLUMN_SPECS: &[ColumnSpec] = &[
 /// Sort key for display order: (slot in `COLUMN_SPECS`, sub-order).
 ///
 /// Custom columns share the Url slot with a non-zero sub-order, so they
-/// render after Url and before Commit, in their resolution order.
+/// render after Url and before Agent, in their resolution order.
 pub fn column_display_index(kind: ColumnKind) -> (usize, usize) {
     if let ColumnKind::Custom(i) = kind {
         let url_slot = COLUMN_SPECS
@@ -420,6 +446,9 @@ mod tests {
             ColumnKind::CiStatus,
             ColumnKind::Path,
             ColumnKind::Url,
+            ColumnKind::Agent,
+            ColumnKind::Container,
+            ColumnKind::Lfs,
             ColumnKind::Commit,
             ColumnKind::Time,
             ColumnKind::Message,
@@ -499,6 +528,9 @@ mod tests {
             ColumnKind::Path,
             ColumnKind::Upstream,
             ColumnKind::Url,
+            ColumnKind::Agent,
+            ColumnKind::Container,
+            ColumnKind::Lfs,
             ColumnKind::CiStatus,
             ColumnKind::Commit,
             ColumnKind::Time,
@@ -617,6 +649,7 @@ mod tests {
             summary_enabled: true,
             has_llm_command: true,
             has_url_template: true,
+            has_container: true,
         };
         let all: HashSet<TaskKind> = TaskKind::iter().collect();
 
@@ -702,6 +735,16 @@ mod tests {
             "url needs a template even when listed"
         );
 
+        // So is an approved `[container]`: no runtime runs without one.
+        let no_container = ColumnGates {
+            has_container: false,
+            ..open
+        };
+        assert!(
+            required_tasks_for_render([ColumnKind::Container], Listed, &no_container).is_empty(),
+            "container needs an approved [container] even when listed"
+        );
+
         // Every precondition holding, a listed summary runs.
         assert_eq!(
             required_tasks_for_render([ColumnKind::Summary], Listed, &open),
@@ -0,0 +1,226 @@
+//! Document renderings of the `wt list` table: `--format=markdown`, `csv`,
+//! `tsv` and `html`.
+//!
+//! Columns come from the same [`LayoutConfig`] the terminal table renders —
+//! `[list] columns`, custom columns and the `--full` gate all apply — laid out
+//! without a width limit. Each cell is [`ColumnLayout::render_plain_cell`], so
+//! an empty cell and the `·` placeholder mean what they mean in the table (and
+//! what absent and `null` mean in schema-2 JSON).
+//!
+//! [`ColumnLayout::render_plain_cell`]: super::layout::ColumnLayout::render_plain_cell
+
+use super::layout::{ColumnFormat, LayoutConfig};
+use super::model::ListItem;
+
+/// A document format for `wt list` output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    /// GitHub-flavored Markdown table.
+    Markdown,
+    /// Comma-separated values, quoted per RFC 4180.
+    Csv,
+    /// Tab-separated values.
+    Tsv,
+    /// A self-contained HTML page holding one table.
+    Html,
+}
+
+/// Render `items` as a document over the layout's columns, without a
+/// trailing newline.
+pub(crate) fn render(format: DocumentFormat, layout: &LayoutConfig, items: &[ListItem]) -> String {
+    let headers: Vec<&str> = layout.columns.iter().map(|c| c.header.as_ref()).collect();
+    let numeric: Vec<bool> = layout
+        .columns
+        .iter()
+        .map(|c| matches!(c.format, ColumnFormat::Diff(_)))
+        .collect();
+    let rows: Vec<Vec<String>> = items
+        .iter()
+        .map(|item| {
+            layout
+                .columns
+                .iter()
+                .map(|column| column.render_plain_cell(item, layout))
+                .collect()
+        })
+        .collect();
+    render_cells(format, &headers, &numeric, &rows)
+}
+
+/// Render pre-computed cells. `numeric` marks right-aligned columns.
+fn render_cells(
+    format: DocumentFormat,
+    headers: &[&str],
+    numeric: &[bool],
+    rows: &[Vec<String>],
+) -> String {
+    match format {
+        DocumentFormat::Markdown => render_markdown(headers, numeric, rows),
+        DocumentFormat::Csv => render_delimited(headers, rows, ",", csv_field),
+        DocumentFormat::Tsv => render_delimited(headers, rows, "\t", tsv_field),
+        DocumentFormat::Html => render_html(headers, numeric, rows),
+    }
+}
+
+fn render_markdown(headers: &[&str], numeric: &[bool], rows: &[Vec<String>]) -> String {
+    let line = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+    let mut lines = vec![line(headers.iter().map(|h| markdown_cell(h)).collect())];
+    lines.push(line(
+        numeric
+            .iter()
+            .map(|&right| if right { "---:" } else { "---" }.to_string())
+            .collect(),
+    ));
+    lines.extend(
+        rows.iter()
+            .map(|row| line(row.iter().map(|cell| markdown_cell(cell)).collect())),
+    );
+    lines.join("\n")
+}
+
+/// Escape a GFM table cell: a pipe would end the cell and a newline the row.
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+fn render_delimited(
+    headers: &[&str],
+    rows: &[Vec<String>],
+    delimiter: &str,
+    field: fn(&str) -> String,
+) -> String {
+    std::iter::once(headers.iter().map(|h| field(h)).collect::<Vec<_>>())
+        .chain(
+            rows.iter()
+                .map(|row| row.iter().map(|cell| field(cell)).collect()),
+        )
+        .map(|fields| fields.join(delimiter))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Quote a CSV field when it holds a delimiter, quote or line break.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// TSV has no quoting, so tabs and line breaks inside a field become spaces.
+fn tsv_field(text: &str) -> String {
+    text.replace(['\t', '\r', '\n'], " ")
+}
+
+const HTML_STYLE: &str = "\
+body { font-family: system-ui, sans-serif; margin: 2rem; }
+table { border-collapse: collapse; font-variant-numeric: tabular-nums; }
+th, td { padding: 0.25rem 0.75rem; border-bottom: 1px solid #ddd; text-align: left; white-space: nowrap; }
+th { border-bottom-width: 2px; }
+.num { text-align: right; }";
+
+fn render_html(headers: &[&str], numeric: &[bool], rows: &[Vec<String>]) -> String {
+    let cells = |tag: &str, cells: &mut dyn Iterator<Item = &str>| {
+        let cells: String = cells
+            .zip(numeric)
+            .map(|(text, &right)| {
+                let class = if right { " class=\"num\"" } else { "" };
+                format!("<{tag}{class}>{}</{tag}>", html_escape(text))
+            })
+            .collect();
+        format!("<tr>{cells}</tr>")
+    };
+
+    let mut lines = vec![
+        "<!DOCTYPE html>".to_string(),
+        "<html lang=\"en\">".to_string(),
+        "<head>".to_string(),
+        "<meta charset=\"utf-8\">".to_string(),
+        "<title>wt list</title>".to_string(),
+        format!("<style>\n{HTML_STYLE}\n</style>"),
+        "</head>".to_string(),
+        "<body>".to_string(),
+        "<table>".to_string(),
+        "<thead>".to_string(),
+        cells("th", &mut headers.iter().copied()),
+        "</thead>".to_string(),
+        "<tbody>".to_string(),
+    ];
+    lines.extend(
+        rows.iter()
+            .map(|row| cells("td", &mut row.iter().map(String::as_str))),
+    );
+    lines.extend(
+        ["</tbody>", "</table>", "</body>", "</html>"]
+            .into_iter()
+            .map(String::from),
+    );
+    lines.join("\n")
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> (Vec<&'static str>, Vec<bool>, Vec<Vec<String>>) {
+        let headers = vec!["", "Branch", "HEAD±", "Message"];
+        let numeric = vec![false, false, true, false];
+        let rows = vec![
+            vec!["@", "feature", "+3 -1", "Fix a|b, \"quoted\""],
+            vec!["/", "wip", "", "·"],
+        ]
+        .into_iter()
+        .map(|row| row.into_iter().map(String::from).collect())
+        .collect();
+        (headers, numeric, rows)
+    }
+
+    #[test]
+    fn test_markdown_escapes_pipes_and_right_aligns_diffs() {
+        let (headers, numeric, rows) = fixture();
+        insta::assert_snapshot!(render_cells(DocumentFormat::Markdown, &headers, &numeric, &rows), @r#"
+        |  | Branch | HEAD± | Message |
+        | --- | --- | ---: | --- |
+        | @ | feature | +3 -1 | Fix a\|b, "quoted" |
+        | / | wip |  | · |
+        "#);
+    }
+
+    #[test]
+    fn test_csv_quotes_only_when_needed() {
+        let (headers, numeric, rows) = fixture();
+        insta::assert_snapshot!(render_cells(DocumentFormat::Csv, &headers, &numeric, &rows), @r#"
+        ,Branch,HEAD±,Message
+        @,feature,+3 -1,"Fix a|b, ""quoted"""
+        /,wip,,·
+        "#);
+    }
+
+    #[test]
+    fn test_tsv_replaces_separators() {
+        assert_eq!(tsv_field("a\tb\nc"), "a b c");
+        let (headers, numeric, rows) = fixture();
+        let tsv = render_cells(DocumentFormat::Tsv, &headers, &numeric, &rows);
+        assert_eq!(tsv.lines().nth(2), Some("/\twip\t\t·"));
+    }
+
+    #[test]
+    fn test_html_escapes_and_marks_numeric_cells() {
+        let (headers, numeric, mut rows) = fixture();
+        rows[0][3] = "<b>&</b>".to_string();
+        let html = render_cells(DocumentFormat::Html, &headers, &numeric, &rows);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(
+            "<tr><td>@</td><td>feature</td><td class=\"num\">+3 -1</td><td>&lt;b&gt;&amp;&lt;/b&gt;</td></tr>"
+        ));
+        assert!(html.ends_with("</html>"));
+    }
+}
@@ -36,6 +36,10 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<PathBuf>,
 
+    /// `[remotes]` host the worktree lives on (absent for local worktrees)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+
     /// Item kind: "worktree" or "branch"
     pub kind: &'static str,
 
@@ -123,6 +127,23 @@ pub struct JsonItem {
     /// Empty cells are omitted.
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     pub columns: BTreeMap<String, String>,
+
+    /// Agent sessions in this worktree, recorded via
+    /// `wt config state activity`; most recently active first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub agents: Vec<worktrunk::activity::Session>,
+
+    /// State of the worktree's `[container]` container; absent without one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<worktrunk::container::ContainerState>,
+
+    /// LFS files of the worktree; absent outside an LFS repository
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lfs: Option<worktrunk::git::lfs::LfsState>,
+
+    /// Submodule drift; absent unless a submodule is out of date or dirty
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submodules: Option<worktrunk::git::submodule::SubmoduleDrift>,
 }
 
 /// Commit information
@@ -400,6 +421,7 @@ impl JsonItem {
         JsonItem {
             branch: item.branch.clone(),
             path,
+            host: worktree_data.and_then(|d| d.host.clone()),
             kind: kind_str,
             commit,
             working_tree,
@@ -422,6 +444,15 @@ impl JsonItem {
             symbols,
             vars,
             columns,
+            agents: item.agent_sessions.clone(),
+            container: item
+                .worktree_data()
+                .and_then(|data| data.container.flatten()),
+            lfs: item.worktree_data().and_then(|data| data.lfs),
+            submodules: item
+                .worktree_data()
+                .and_then(|data| data.submodule_drift)
+                .filter(|drift| drift.any()),
         }
     }
 }
@@ -545,6 +576,8 @@ pub(crate) fn format_raw_symbols(symbols: &super::model::StatusSymbols) -> Strin
             let s = wt_state.to_string();
             if !s.is_empty() {
                 result.push_str(&s);
+            } else if symbols.submodules.any() {
+                result.push_str(super::model::SUBMODULE_DRIFT);
             }
         }
     }
@@ -824,10 +857,14 @@ mod tests {
             working_tree_diff: None,
             working_tree_status: None,
             has_conflicts: None,
+            submodule_drift: None,
             has_working_tree_conflicts: None,
             git_operation: Some(None),
             branch_worktree_mismatch: false,
             duplicate_branch: false,
+            host: None,
+            container: None,
+            lfs: None,
         }
     }
 
@@ -839,6 +876,7 @@ mod tests {
             operation_state: Some(OperationState::None),
             upstream_divergence: Some(Divergence::None),
             user_marker: Some(None),
+            submodules: Default::default(),
         }
     }
 
@@ -0,0 +1,215 @@
+//! Streaming schema-2 JSON for `wt list --format=ndjson`.
+//!
+//! The progressive table's skeleton-then-fill, for machines: one JSON object
+//! per line, tagged by `type`:
+//!
+//! - `header` — the [`JsonEnvelope`](super::json_v2::JsonEnvelope) minus
+//!   `items`: `schema`, `repo`, `collected`.
+//! - `item` — one per row (`index`, `item`), in table order, as soon as the
+//!   row's identity and commit are known. Fields still being computed are
+//!   `null`, per the schema-2 absence rule. `[remotes]` host rows come last,
+//!   once their hosts answer — possibly after patches to the local rows.
+//! - `patch` — a row's top-level fields that changed since its last record:
+//!   `set` replaces each named field wholesale, `unset` lists fields that
+//!   became absent. Emitted as task results land, so local state arrives
+//!   before CI, summaries and upstream.
+//! - `end` — collection finished; applying every patch yields exactly the
+//!   items `--format=json` prints under `[list] json-schema = 2`.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use worktrunk::git::Repository;
+use worktrunk::styling::println;
+
+use super::custom_columns::ResolvedCustomColumn;
+use super::json_v2::{JsonItemV2, JsonRepo};
+use super::model::{Collected, ListItem};
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Record<'a> {
+    Header {
+        schema: u32,
+        repo: JsonRepo,
+        collected: Collected,
+    },
+    Item {
+        index: usize,
+        item: &'a JsonItemV2,
+    },
+    Patch {
+        index: usize,
+        #[serde(skip_serializing_if = "Map::is_empty")]
+        set: Map<String, Value>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        unset: Vec<String>,
+    },
+    End,
+}
+
+/// Emits the record stream for one `collect` run, remembering each row's
+/// last-emitted fields so patches carry only what changed.
+pub(crate) struct JsonStream<'a> {
+    default_branch: Option<String>,
+    ci_provider_override: Option<String>,
+    collected: Collected,
+    custom_columns: &'a [ResolvedCustomColumn],
+    /// The bulk vars snapshot, read once. Kept whole (schema-2 building
+    /// moves vars out of its map) because every patch rebuilds its row.
+    vars: HashMap<String, BTreeMap<String, String>>,
+    emitted: Vec<Map<String, Value>>,
+}
+
+impl<'a> JsonStream<'a> {
+    /// Print the header record. Repo facts resolve the same way as
+    /// [`super::json_v2::to_json_envelope`], so the stream and the buffered
+    /// envelope agree.
+    pub(crate) fn start(
+        repo: &Repository,
+        collected: Collected,
+        custom_columns: &'a [ResolvedCustomColumn],
+    ) -> Self {
+        let default_branch = repo.default_branch();
+        emit(&Record::Header {
+            schema: 2,
+            repo: JsonRepo {
+                default_branch: default_branch.clone(),
+                forge: repo.repo_info(),
+            },
+            collected,
+        });
+        Self {
+            default_branch,
+            ci_provider_override: repo.configured_forge_platform(),
+            collected,
+            custom_columns,
+            vars: repo.all_vars_from_snapshot().unwrap_or_default(),
+            emitted: Vec::new(),
+        }
+    }
+
+    /// Print one `item` record per row, indexed after the rows already
+    /// printed.
+    pub(crate) fn items(&mut self, items: &[ListItem]) {
+        for item in items {
+            let index = self.emitted.len();
+            let json = self.build(item);
+            emit(&Record::Item { index, item: &json });
+            self.emitted.push(fields_of(&json));
+        }
+    }
+
+    /// Print a `patch` record for row `index` if any of its fields changed.
+    pub(crate) fn update(&mut self, index: usize, item: &ListItem) {
+        let fields = fields_of(&self.build(item));
+        let Some(previous) = self.emitted.get_mut(index) else {
+            return;
+        };
+        let (set, unset) = diff_fields(previous, &fields);
+        if set.is_empty() && unset.is_empty() {
+            return;
+        }
+        emit(&Record::Patch { index, set, unset });
+        *previous = fields;
+    }
+
+    /// Patch every row to its final state, then print the `end` record.
+    pub(crate) fn finish(mut self, items: &[ListItem]) {
+        for (index, item) in items.iter().enumerate() {
+            self.update(index, item);
+        }
+        emit(&Record::End);
+    }
+
+    fn build(&self, item: &ListItem) -> JsonItemV2 {
+        let mut vars: HashMap<String, BTreeMap<String, String>> = item
+            .branch
+            .as_deref()
+            .and_then(|branch| self.vars.get_key_value(branch))
+            .map(|(branch, vars)| (branch.clone(), vars.clone()))
+            .into_iter()
+            .collect();
+        JsonItemV2::from_list_item(
+            item,
+            &mut vars,
+            self.default_branch.as_deref(),
+            self.collected,
+            self.ci_provider_override.as_deref(),
+            self.custom_columns,
+        )
+    }
+}
+
+fn fields_of(item: &JsonItemV2) -> Map<String, Value> {
+    match serde_json::to_value(item) {
+        Ok(Value::Object(fields)) => fields,
+        _ => Map::new(),
+    }
+}
+
+fn emit(record: &Record) {
+    match serde_json::to_string(record) {
+        Ok(line) => println!("{line}"),
+        Err(e) => tracing::debug!(error = %e, "Failed to serialize list stream record"),
+    }
+}
+
+/// Top-level fields of `new` that differ from `old`, and fields of `old`
+/// missing from `new`.
+fn diff_fields(
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+) -> (Map<String, Value>, Vec<String>) {
+    let set = new
+        .iter()
+        .filter(|(key, value)| old.get(*key) != Some(value))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    let unset = old
+        .keys()
+        .filter(|key| !new.contains_key(*key))
+        .cloned()
+        .collect();
+    (set, unset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn object(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_diff_fields_sets_changed_and_unsets_removed() {
+        let old = object(json!({"branch": "a", "upstream": null, "pr": null}));
+        let new = object(json!({"branch": "a", "upstream": {"ahead": 1}}));
+        let (set, unset) = diff_fields(&old, &new);
+        assert_eq!(Value::Object(set), json!({"upstream": {"ahead": 1}}));
+        assert_eq!(unset, ["pr"]);
+    }
+
+    #[test]
+    fn test_patch_record_omits_empty_parts() {
+        let record = Record::Patch {
+            index: 2,
+            set: object(json!({"summary": "Refactor"})),
+            unset: Vec::new(),
+        };
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"type":"patch","index":2,"set":{"summary":"Refactor"}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Record::End).unwrap(),
+            r#"{"type":"end"}"#
+        );
+    }
+}
@@ -31,6 +31,10 @@ use std::path::PathBuf;
 
 use schemars::JsonSchema;
 use serde::Serialize;
+use worktrunk::activity::{AgentState, Session};
+use worktrunk::container::ContainerState;
+use worktrunk::git::lfs::LfsState;
+use worktrunk::git::submodule::SubmoduleDrift;
 use worktrunk::git::{
     GitRepoInfo, InProgressOperation, IntegrationReason, IntegrationSignals, Repository,
     check_integration,
@@ -223,6 +227,10 @@ pub struct JsonWorktreeV2 {
     /// Filesystem path.
     pub path: PathBuf,
 
+    /// `[remotes]` host the worktree lives on; absent for local worktrees.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+
     /// This is the main worktree.
     pub main: bool,
 
@@ -256,6 +264,56 @@ pub struct JsonWorktreeV2 {
 
     /// Working-tree state; null while unresolved.
     pub changes: Option<JsonChanges>,
+
+    /// Agent sessions recorded via `wt config state activity`, most recently
+    /// active first; absent when none.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub agents: Vec<JsonAgent>,
+
+    /// State of the worktree's `[container]` container; absent without one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<ContainerState>,
+
+    /// LFS files of the worktree; absent outside an LFS repository.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lfs: Option<LfsState>,
+}
+
+/// One agent session in a worktree.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JsonAgent {
+    /// The agent CLI (`claude`, `codex`, …).
+    pub tool: String,
+
+    /// The tool's session ID; absent when the reporter had none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+
+    /// `working`, `waiting` (blocked on the user), or `idle`.
+    pub state: AgentState,
+
+    /// Session start, RFC 3339 UTC.
+    pub started_at: Option<String>,
+
+    /// When the session entered `state`, RFC 3339 UTC.
+    pub state_since: Option<String>,
+
+    /// Last report or heartbeat, RFC 3339 UTC.
+    pub last_activity_at: Option<String>,
+}
+
+impl From<&Session> for JsonAgent {
+    fn from(session: &Session) -> Self {
+        let time = |secs: u64| worktrunk::utils::format_timestamp_iso8601_opt(secs as i64);
+        Self {
+            tool: session.tool.clone(),
+            session_id: session.session_id.clone(),
+            state: session.state,
+            started_at: time(session.started_at),
+            state_since: time(session.state_since),
+            last_activity_at: time(session.last_activity_at),
+        }
+    }
 }
 
 /// [`InProgressOperation`] wire values. The domain enum's `strum` names are
@@ -308,6 +366,9 @@ pub struct JsonChanges {
     pub conflicted: Option<bool>,
     /// Lines added/deleted vs HEAD; null while unresolved.
     pub diff: Option<JsonDiff>,
+    /// Submodules out of date or dirty; absent when none drifted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submodules: Option<SubmoduleDrift>,
 }
 
 /// Relation to the default branch — independent facts, not the table's
@@ -613,7 +674,7 @@ impl JsonItemV2 {
                     .and_then(|c| worktrunk::utils::format_timestamp_iso8601_opt(c.timestamp)),
             });
 
-        let worktree = worktree_data.map(json_worktree);
+        let worktree = worktree_data.map(|data| json_worktree(data, &item.agent_sessions));
 
         // The default branch itself gets no relation object. Matching on the
         // worktree's main flag alone would miss a branch-only row for the
@@ -703,7 +764,7 @@ impl JsonItemV2 {
     }
 }
 
-fn json_worktree(data: &WorktreeData) -> JsonWorktreeV2 {
+fn json_worktree(data: &WorktreeData, sessions: &[Session]) -> JsonWorktreeV2 {
     // Empty lock/prune reasons (git records the state without a message)
     // become `reason: null`.
     let reason = |r: &Option<String>| {
@@ -726,10 +787,12 @@ fn json_worktree(data: &WorktreeData) -> JsonWorktreeV2 {
         deleted: wt.deleted,
         conflicted: data.has_conflicts,
         diff: data.working_tree_diff.map(JsonDiff::from),
+        submodules: data.submodule_drift.filter(|drift| drift.any()),
     });
 
     JsonWorktreeV2 {
         path: data.path.clone(),
+        host: data.host.clone(),
         main: data.is_main,
         current: data.is_current,
         previous: data.is_previous,
@@ -740,6 +803,9 @@ fn json_worktree(data: &WorktreeData) -> JsonWorktreeV2 {
         duplicate_branch: data.duplicate_branch,
         operation,
         changes,
+        agents: sessions.iter().map(JsonAgent::from).collect(),
+        container: data.container.flatten(),
+        lfs: data.lfs,
     }
 }
 
@@ -1491,25 +1557,43 @@ mod tests {
         // appear nowhere else in the battery, and each field left at its
         // default takes one more type out of the validator's reach: git
         // records a lock with or without a message, so both `JsonReason` arms
-        // need a row.
-        items.push(convert(
-            &worktree_item(
-                "worktree",
-                WorktreeData {
-                    git_operation: Some(Some(InProgressOperation::Rebase)),
-                    locked: Some("manual lock".to_string()),
-                    prunable: Some(String::new()),
-                    working_tree_status: Some(Default::default()),
-                    has_conflicts: Some(true),
-                    working_tree_diff: Some(worktrunk::git::LineDiff {
-                        added: 3,
-                        deleted: 1,
-                    }),
-                    ..Default::default()
-                },
-            ),
-            collected,
-        ));
+        // need a row, `agents` needs a session, and `container` and `lfs` a
+        // state.
+        let mut full = worktree_item(
+            "worktree",
+            WorktreeData {
+                git_operation: Some(Some(InProgressOperation::Rebase)),
+                locked: Some("manual lock".to_string()),
+                prunable: Some(String::new()),
+                working_tree_status: Some(Default::default()),
+                has_conflicts: Some(true),
+                working_tree_diff: Some(worktrunk::git::LineDiff {
+                    added: 3,
+                    deleted: 1,
+                }),
+                submodule_drift: Some(SubmoduleDrift {
+                    out_of_date: true,
+                    dirty: false,
+                }),
+                container: Some(Some(ContainerState::Running)),
+                lfs: Some(LfsState {
+                    files: 3,
+                    pointers: 1,
+                }),
+                ..Default::default()
+            },
+        );
+        full.agent_sessions = vec![worktrunk::activity::Session {
+            tool: "claude".to_string(),
+            session_id: Some("abc123".to_string()),
+            worktree: PathBuf::from("/repo.worktree"),
+            branch: Some("worktree".to_string()),
+            state: worktrunk::activity::AgentState::Waiting,
+            started_at: 1_700_000_000,
+            state_since: 1_700_000_060,
+            last_activity_at: 1_700_000_060,
+        }];
+        items.push(convert(&full, collected));
 
         // An integrated row with an upstream and a dev server. Without the
         // signals `check_integration` returns `None` and `integration` is
@@ -1612,7 +1696,12 @@ mod tests {
             ("CiSource", "/items/*/checks/source"),
             ("Collected", "/collected"),
             ("GitRepoInfo", "/repo/forge"),
+            ("AgentState", "/items/*/worktree/agents/*/state"),
+            ("ContainerState", "/items/*/worktree/container"),
+            ("LfsState", "/items/*/worktree/lfs"),
+            ("SubmoduleDrift", "/items/*/worktree/changes/submodules"),
             ("GitRepoProvider", "/repo/forge/provider"),
+            ("JsonAgent", "/items/*/worktree/agents/*"),
             ("JsonChanges", "/items/*/worktree/changes"),
             ("JsonCheckStatus", "/items/*/checks/status"),
             ("JsonChecks", "/items/*/checks"),
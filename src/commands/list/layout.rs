@@ -54,13 +54,17 @@
 //! final_priority = base_priority + empty_penalty
 //! ```
 //!
-//! **Base priorities** (0-13) are determined by **user need hierarchy** - what questions users need
+//! **Base priorities** (0-14) are determined by **user need hierarchy** - what questions users need
 //! answered when scanning worktrees:
 //! - 0: Gutter (always present)
 //! - 1: Branch (identity - "what is this?")
 //! - 2-4: Critical (status, working diff, ahead/behind)
 //! - 5-12: Context (CI, branch diff, path, upstream, URL, summary, commit, time)
 //! - 13: Message (nice-to-have, space-hungry)
+//! - 14: Agent (only present when a worktree has an agent session; the Status
+//!   column's user marker still carries 🤖/💬 when it drops)
+//! - 15: Container (only present once the project's `[container]` is approved)
+//! - 16: LFS (only present in LFS repositories)
 //!
 //! **Empty penalty**: +10 if column has no data (only header)
 //! - Empty working_diff: 3 + 10 = priority 13
@@ -68,7 +72,7 @@
 //! - etc.
 //!
 //! This creates two effective priority tiers:
-//! - **Tier 1 (priorities 0-13)**: Columns with actual data
+//! - **Tier 1 (priorities 0-14)**: Columns with actual data
 //! - **Tier 2 (priorities 13-23)**: Empty columns (visual consistency)
 //!
 //! The empty penalty is large (+10) but not infinite, so empty columns maintain their relative
@@ -198,8 +202,6 @@ use anstyle::Style;
 use unicode_width::UnicodeWidthStr;
 use worktrunk::styling::{ADDITION, DELETION, Stream, supports_hyperlinks};
 
-use crate::display::shorten_path;
-
 use super::collect::{TaskKind, parse_port_from_url};
 use super::columns::{COLUMN_SPECS, ColumnKind, ColumnSpec, column_display_index};
 use super::custom_columns::ResolvedCustomColumn;
@@ -274,6 +276,13 @@ pub struct ColumnWidths {
     pub time: usize,
     pub url: usize,
     pub ci_status: usize,
+    /// Measured from the Agent cells rendered before layout, like `custom`;
+    /// 0 when no worktree has an agent session, which excludes the column.
+    pub agent: usize,
+    /// Fixed like `ci_status`; 0 when the Container column's task isn't planned.
+    pub container: usize,
+    /// Measured like `agent`; 0 outside an LFS repository.
+    pub lfs: usize,
     pub ahead_behind: DiffWidths,
     pub working_diff: DiffWidths,
     pub branch_diff: DiffWidths,
@@ -443,11 +452,12 @@ impl ColumnKind {
             ColumnKind::CiStatus => flags.ci_status,
             ColumnKind::Commit => true,
             ColumnKind::Summary => true, // Placeholder shown until data arrives
+            ColumnKind::Container => true, // Placeholder shown until data arrives
             ColumnKind::Message => true,
-            // Custom values are final before layout (nothing arrives later),
+            // Custom values and Agent cells are final before layout (nothing arrives later),
             // so all-empty columns are excluded from candidates instead of
             // taking the EMPTY_PENALTY path built for still-loading data.
-            ColumnKind::Custom(_) => true,
+            ColumnKind::Agent | ColumnKind::Lfs | ColumnKind::Custom(_) => true,
         }
     }
 
@@ -482,6 +492,9 @@ impl ColumnKind {
             ColumnKind::Path => text(max_path_width),
             ColumnKind::Time => text(widths.time),
             ColumnKind::Url => text(widths.url),
+            ColumnKind::Agent => text(widths.agent),
+            ColumnKind::Container => text(widths.container),
+            ColumnKind::Lfs => text(widths.lfs),
             ColumnKind::CiStatus => text(widths.ci_status),
             ColumnKind::Commit => text(commit_width),
             ColumnKind::Summary => None, // Flexible: handled specially in allocation loop
@@ -743,12 +756,15 @@ fn estimate_url_width(url_template: Option<&str>, link: LinkStyle) -> usize {
 /// Uses generous fixed allocations for expensive-to-compute columns (status, diffs, time, CI)
 /// that handle overflow with compact notation (K suffix). This provides consistent layout
 /// without requiring a data scan.
+#[allow(clippy::too_many_arguments)]
 fn build_estimated_widths(
     max_branch: usize,
     tasks: &HashSet<TaskKind>,
     path_is_informative: bool,
     url_width: usize,
     max_pr_number: Option<u64>,
+    agent_width: usize,
+    lfs_width: usize,
     custom_widths: Vec<usize>,
 ) -> LayoutMetadata {
     // Fixed widths for slow columns (require expensive git operations)
@@ -776,6 +792,11 @@ fn build_estimated_widths(
         ColumnKind::CiStatus.header(),
         super::ci_status::pr_ref_width(max_pr_number.unwrap_or(9999)),
     );
+    let container_fixed = if tasks.contains(&TaskKind::ContainerState) {
+        fit_header(ColumnKind::Container.header(), "stopped".width())
+    } else {
+        0
+    };
 
     // Assume columns will have data (better to show and hide than to not show).
     // This is a limitation of progressive mode - we can't know which columns have data
@@ -809,6 +830,9 @@ fn build_estimated_widths(
         time: age_estimate,
         url: url_estimate,
         ci_status: ci_estimate,
+        agent: agent_width,
+        container: container_fixed,
+        lfs: lfs_width,
         // Commit counts (Arrows): compact notation, 2 digits covers up to 99
         ahead_behind: DiffWidths {
             total: ahead_behind_fixed,
@@ -919,6 +943,9 @@ fn allocate_columns_with_priority(
     let mut candidates: Vec<ColumnCandidate> = COLUMN_SPECS
         .iter()
         .filter(|spec| spec.kind.renders_given_run(tasks))
+        // Like an empty custom column: no session anywhere, no column.
+        .filter(|spec| spec.kind != ColumnKind::Agent || metadata.widths.agent > 0)
+        .filter(|spec| spec.kind != ColumnKind::Lfs || metadata.widths.lfs > 0)
         .filter(|spec| match selected {
             Some(order) => spec.kind == ColumnKind::Gutter || order.contains(&spec.kind),
             None => true,
@@ -1208,8 +1235,8 @@ pub fn calculate_layout_with_width(
 
     let path_data_width = items
         .iter()
-        .filter_map(|item| item.worktree_path())
-        .map(|path| shorten_path(path.as_path(), main_worktree_path).width())
+        .filter_map(|item| item.worktree_data())
+        .map(|data| data.display_path(main_worktree_path).width())
         .max()
         .unwrap_or(0);
     let max_path_width = fit_header(ColumnKind::Path.header(), path_data_width);
@@ -1245,6 +1272,20 @@ pub fn calculate_layout_with_width(
         })
         .collect();
 
+    // Agent cells are rendered before layout too, so measured the same way.
+    let agent_width = items
+        .iter()
+        .map(|item| item.agent_cell.width())
+        .max()
+        .filter(|&width| width > 0)
+        .map_or(0, |width| fit_header(ColumnKind::Agent.header(), width));
+    let lfs_width = items
+        .iter()
+        .filter_map(|item| item.worktree_data()?.lfs)
+        .map(|state| super::render::lfs_text(&state).width())
+        .max()
+        .map_or(0, |width| fit_header(ColumnKind::Lfs.header(), width));
+
     // Build pre-allocated width estimates (same as buffered mode)
     let metadata = build_estimated_widths(
         max_branch,
@@ -1252,6 +1293,8 @@ pub fn calculate_layout_with_width(
         path_is_informative,
         url_width,
         max_pr_number,
+        agent_width,
+        lfs_width,
         custom_widths,
     );
 
@@ -1434,6 +1477,9 @@ mod tests {
             time: 4,
             url: 0,
             ci_status: 2,
+            agent: 0,
+            container: 0,
+            lfs: 0,
             ahead_behind: DiffWidths {
                 total: 7,
                 positive_digits: 2,
@@ -1502,6 +1548,9 @@ mod tests {
             time: 0,
             url: 0,
             ci_status: 0,
+            agent: 0,
+            container: 0,
+            lfs: 0,
             ahead_behind: DiffWidths {
                 total: 0,
                 positive_digits: 0,
@@ -1534,7 +1583,8 @@ mod tests {
         // Full run plan means all tasks are computed (equivalent to --full)
         // path_is_informative=true to test the path flag is passed through
         // url_width=0 since we're not testing URL column here
-        let metadata = build_estimated_widths(20, &full_run_tasks(), true, 0, None, Vec::new());
+        let metadata =
+            build_estimated_widths(20, &full_run_tasks(), true, 0, None, 0, 0, Vec::new());
         let widths = metadata.widths;
 
         // Line diffs (Signs variant: +/-) allocate 3 digits for 100-999 range
@@ -1599,17 +1649,29 @@ mod tests {
 
         // CI: no cached PR number → fallback estimate fits "#9999"
         assert_eq!(widths.ci_status, 5, "CI fallback should fit '#9999'");
+
+        // Container: "stopped" fits under the "Container" header
+        assert_eq!(widths.container, 9);
     }
 
     #[test]
     fn test_ci_column_width_from_max_pr_number() {
         // Cached largest number sizes the column: "#12345" → 6
-        let metadata =
-            build_estimated_widths(20, &full_run_tasks(), false, 0, Some(12345), Vec::new());
+        let metadata = build_estimated_widths(
+            20,
+            &full_run_tasks(),
+            false,
+            0,
+            Some(12345),
+            0,
+            0,
+            Vec::new(),
+        );
         assert_eq!(metadata.widths.ci_status, 6);
 
         // Never below header width ("CI" → 2)
-        let metadata = build_estimated_widths(20, &full_run_tasks(), false, 0, Some(1), Vec::new());
+        let metadata =
+            build_estimated_widths(20, &full_run_tasks(), false, 0, Some(1), 0, 0, Vec::new());
         assert_eq!(metadata.widths.ci_status, 2);
     }
 
@@ -1657,6 +1719,8 @@ mod tests {
             status_symbols: StatusSymbols::default(),
             statusline: None,
             custom_values: Vec::new(),
+            agent_sessions: Vec::new(),
+            agent_cell: String::new(),
             seeded: Default::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test/path"),
@@ -1673,6 +1737,10 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 duplicate_branch: false,
+                host: None,
+                container: None,
+                lfs: None,
+                submodule_drift: None,
             })),
         };
 
@@ -1777,6 +1845,8 @@ mod tests {
             status_symbols: StatusSymbols::default(),
             statusline: None,
             custom_values: Vec::new(),
+            agent_sessions: Vec::new(),
+            agent_cell: String::new(),
             seeded: Default::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test"),
@@ -1793,6 +1863,10 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 duplicate_branch: false,
+                host: None,
+                container: None,
+                lfs: None,
+                submodule_drift: None,
             })),
         };
 
@@ -1912,6 +1986,8 @@ mod tests {
             status_symbols: StatusSymbols::default(),
             statusline: None,
             custom_values: Vec::new(),
+            agent_sessions: Vec::new(),
+            agent_cell: String::new(),
             seeded: Default::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test/wt"),
@@ -1928,6 +2004,10 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 duplicate_branch: false,
+                host: None,
+                container: None,
+                lfs: None,
+                submodule_drift: None,
             })),
         }
     }
@@ -2437,6 +2517,8 @@ mod tests {
             status_symbols: StatusSymbols::default(),
             statusline: None,
             custom_values: Vec::new(),
+            agent_sessions: Vec::new(),
+            agent_cell: String::new(),
             seeded: Default::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from(path),
@@ -2453,6 +2535,10 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 duplicate_branch: false,
+                host: None,
+                container: None,
+                lfs: None,
+                submodule_drift: None,
             })),
         }
     }
@@ -2600,6 +2686,8 @@ mod tests {
                 status_symbols: StatusSymbols::default(),
                 statusline: None,
                 custom_values: Vec::new(),
+                agent_sessions: Vec::new(),
+                agent_cell: String::new(),
                 seeded: Default::default(),
                 kind: ItemKind::Worktree(Box::new(WorktreeData {
                     path: PathBuf::from(path),
@@ -2616,6 +2704,10 @@ mod tests {
                     is_previous: false,
                     branch_worktree_mismatch: false,
                     duplicate_branch: false,
+                    host: None,
+                    container: None,
+                    lfs: None,
+                    submodule_drift: None,
                 })),
             }
         };
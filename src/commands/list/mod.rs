@@ -118,16 +118,20 @@
 //! - Run `git gc` periodically to consolidate objects into pack files
 //! - Minimize uncommitted changes across worktrees (each dirty worktree adds diff overhead)
 
+pub(crate) mod agent_sessions;
 pub mod ci_status;
 pub(crate) mod collect;
 pub(crate) mod columns;
 pub(crate) mod custom_columns;
+pub(crate) mod document;
 pub mod json_output;
+pub(crate) mod json_stream;
 pub mod json_v2;
 pub(crate) mod layout;
 pub mod model;
 pub mod progressive;
 mod progressive_table;
+pub(crate) mod remote_hosts;
 pub(crate) mod render;
 
 // Layout is calculated in collect/mod.rs
@@ -145,7 +149,7 @@ pub use model::StatuslineSegment;
 
 pub fn handle_list(
     repo: Repository,
-    format: crate::OutputFormat,
+    format: crate::ListFormat,
     cli_branches: bool,
     cli_remotes: bool,
     cli_full: bool,
@@ -164,6 +168,7 @@ pub fn handle_list(
             cli_branches,
             cli_remotes,
             cli_full,
+            hosts: true,
         },
         render_target,
     )?;
@@ -192,6 +197,36 @@ pub fn handle_list(
     Ok(())
 }
 
+/// The schema-2 envelope `wt list --format=json` prints, for callers that
+/// answer with it rather than print it (`wt serve`). Always schema 2,
+/// whatever `[list] json-schema` says — the setting only keeps existing
+/// stdout consumers on schema 1.
+pub(crate) fn json_envelope(
+    repo: &Repository,
+    branches: bool,
+    remotes: bool,
+    full: bool,
+) -> anyhow::Result<json_v2::JsonEnvelope> {
+    let list_data = collect::collect(
+        repo,
+        collect::ShowConfig::DeferredToParallel {
+            cli_branches: branches,
+            cli_remotes: remotes,
+            cli_full: full,
+            hosts: false,
+        },
+        RenderTarget::Json,
+    )?;
+    Ok(match list_data {
+        Some(ListData {
+            items,
+            custom_columns,
+            collected,
+        }) => json_v2::to_json_envelope(&items, &custom_columns, repo, collected),
+        None => json_v2::envelope_with_items(repo, None, model::Collected::default(), vec![]),
+    })
+}
+
 /// Resolve `[list] json-schema` (per-project resolved config) to 1 or 2.
 ///
 /// Unset defaults to schema 1 and nags once per process; an out-of-range
@@ -3,7 +3,7 @@
 //! This module contains the main data structures used to represent
 //! worktrees and branches in `wt list` output.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use color_print::cformat;
 use worktrunk::git::{
@@ -16,6 +16,7 @@ use super::status_symbols::{StatusSymbols, WorkingTreeStatus};
 use crate::commands::list::ci_status::PrStatus;
 use crate::commands::list::columns::ColumnKind;
 use crate::commands::list::layout::{LinkStyle, format_url_cell};
+use crate::display::shorten_path;
 
 /// Compute the `WorktreeState` from `WorktreeData` metadata alone.
 ///
@@ -55,6 +56,9 @@ pub struct WorktreeData {
     /// Whether the working tree has merge conflicts in tracked files. `None` =
     /// not yet loaded; `Some` = loaded. Fed by the `WorkingTreeDiff` task.
     pub has_conflicts: Option<bool>,
+    /// Whether submodules drifted from what the superproject records. `None`
+    /// = not yet loaded. Fed by the `WorkingTreeDiff` task.
+    pub submodule_drift: Option<worktrunk::git::submodule::SubmoduleDrift>,
     /// Result of `WorkingTreeConflicts` task (`--full` mode only). Outer `None`
     /// = task hasn't run yet. Outer `Some(None)` = task ran but working tree
     /// was clean, so fall back to the committed-HEAD merge-tree check.
@@ -78,6 +82,17 @@ pub struct WorktreeData {
     /// worktree git lists first (see `worktree_for_branch`), so every
     /// worktree on the branch carries the flag, resolved one included.
     pub duplicate_branch: bool,
+    /// The `[remotes]` host this worktree lives on; `None` for local
+    /// worktrees. Host rows arrive fully populated from the host's own
+    /// `wt list` and run no tasks here.
+    pub host: Option<String>,
+    /// State of this worktree's `[container]` container (None = not loaded,
+    /// Some(None) = no container). Filled by `ContainerStateTask` once the
+    /// `[container]` section is approved.
+    pub container: Option<Option<worktrunk::container::ContainerState>>,
+    /// LFS files of this worktree, hydrated vs pointers; `None` outside an
+    /// LFS repository. Measured before layout, like agent sessions.
+    pub lfs: Option<worktrunk::git::lfs::LfsState>,
 }
 
 impl WorktreeData {
@@ -86,6 +101,15 @@ impl WorktreeData {
         self.prunable.is_some()
     }
 
+    /// The Path cell: shortened relative to the main worktree, or
+    /// `host:path` for a worktree on a `[remotes]` host.
+    pub fn display_path(&self, main_worktree_path: &Path) -> String {
+        match &self.host {
+            Some(host) => format!("{host}:{}", self.path.display()),
+            None => shorten_path(&self.path, main_worktree_path),
+        }
+    }
+
     /// This worktree's location on the gutter's presence axis (see
     /// [`WorktreePresence`]). `is_current` wins when a worktree is both
     /// current and primary — you're sitting in the main worktree.
@@ -317,6 +341,13 @@ pub struct ListItem {
     /// columns are configured.
     pub custom_values: Vec<String>,
 
+    /// Agent sessions recorded for this worktree under `.git/wt/activity/`,
+    /// most recently active first, and the rendered Agent cell
+    /// (`ColumnKind::Agent`). Final at construction like `custom_values`;
+    /// empty for branch rows and worktrees without sessions.
+    pub agent_sessions: Vec<worktrunk::activity::Session>,
+    pub agent_cell: String,
+
     /// Which fact families hold *seeded* conservative defaults rather than
     /// computed results (see `seed_skipped_task_defaults`). The table wants
     /// the conservative values; schema-2 JSON reports the seeded families as
@@ -406,6 +437,8 @@ impl ListItem {
             status_symbols: StatusSymbols::default(),
             statusline: None,
             custom_values: Vec::new(),
+            agent_sessions: Vec::new(),
+            agent_cell: String::new(),
             seeded: SeededFacts::default(),
             kind: ItemKind::Branch(scope),
         }
@@ -641,6 +674,10 @@ impl ListItem {
             && let Some(wt) = self.try_gate_working_tree()
         {
             self.status_symbols.working_tree = Some(wt);
+            self.status_symbols.submodules = self
+                .worktree_data()
+                .and_then(|data| data.submodule_drift)
+                .unwrap_or_default();
         }
 
         // Gate 2 (operation family — position 3).
@@ -27,6 +27,7 @@ pub use item::{BranchScope, Collected, ItemKind, ListData, ListItem, SeededFacts
 pub use state::{Divergence, MainState, OperationState, WorktreeState};
 #[allow(unused_imports)]
 pub use stats::{ActiveUpstream, AheadBehind, BranchDiffTotals, CommitDetails, UpstreamStatus};
+pub(crate) use status_symbols::SUBMODULE_DRIFT;
 #[allow(unused_imports)]
 pub use status_symbols::{PositionMask, StatusSymbols, WorkingTreeStatus};
 #[allow(unused_imports)]
@@ -22,7 +22,7 @@
 //! | 0 | `STAGED`            | `+`                  | Are there staged changes?             |
 //! | 1 | `MODIFIED`          | `!`                  | Are there unstaged modifications?     |
 //! | 2 | `UNTRACKED`         | `?`                  | Are there untracked files?            |
-//! | 3 | `WORKTREE_STATE`    | `✘ ↻ ⊟ ⊞ ⚑ /`      | Operation / worktree attribute        |
+//! | 3 | `WORKTREE_STATE`    | `✘ ↻ ⊟ ⊞ ⚑ / ⊙`    | Operation / worktree attribute        |
 //! | 4 | `MAIN_STATE`        | `^ _ ⊂ ✗ – ↕ ↑ ↓`    | Relationship to the default branch    |
 //! | 5 | `UPSTREAM_DIVERGENCE` | \| ⇅ ⇡ ⇣           | Relationship to the tracked remote    |
 //! | 6 | `USER_MARKER`       | emoji / text         | User-defined annotation               |
@@ -55,15 +55,17 @@
 //!
 //! # Gate 2: Worktree state (position 3)
 //!
-//! **Renders:** at most one of `✘ ↻ ⊟ ⊞ ⚑ /`, priority
-//! `✘ > ↻ > ⊟ > ⊞ > ⚑ > /`. The operation family (`✘↻`) comes from live
+//! **Renders:** at most one of `✘ ↻ ⊟ ⊞ ⚑ / ⊙`, priority
+//! `✘ > ↻ > ⊟ > ⊞ > ⚑ > / > ⊙`. The operation family (`✘↻`) comes from live
 //! task data; the attribute family (`⊟⊞⚑/`) is metadata, always known.
 //! `⚑` covers both irregular-mapping states — a duplicated branch outranks
-//! an off-template path, and the JSON `worktree.state` names which.
+//! an off-template path, and the JSON `worktree.state` names which. `⊙`
+//! (submodule drift) arrives with `has_conflicts`, from the same task, so it
+//! never holds the gate back.
 //!
-//! **Inputs:** `data.has_conflicts`, `data.git_operation`, plus metadata
-//! (`locked`, `prunable`, `duplicate_branch`, `branch_worktree_mismatch`,
-//! `ItemKind::Branch`).
+//! **Inputs:** `data.has_conflicts`, `data.git_operation`,
+//! `data.submodule_drift`, plus metadata (`locked`, `prunable`,
+//! `duplicate_branch`, `branch_worktree_mismatch`, `ItemKind::Branch`).
 //!
 //! **Rule — short-circuit on priority:** a higher-priority signal, once known
 //! to be positive, resolves the gate immediately without waiting for
@@ -243,8 +245,13 @@
 //! - **Tuning picker budgets or task skip lists.** This spec only defines
 //!   what happens when data does or does not arrive in time.
 
+use worktrunk::git::submodule::SubmoduleDrift;
+
 use super::state::{Divergence, MainState, OperationState, WorktreeState};
 
+/// Position 3 when nothing else claims it and a submodule drifted.
+pub(crate) const SUBMODULE_DRIFT: &str = "⊙";
+
 /// Per-position character widths for the Status column, used to pad each
 /// position so symbols line up vertically across rows.
 ///
@@ -270,7 +277,7 @@ impl PositionMask {
     pub(crate) const STAGED: usize = 0; // + (staged changes)
     pub(crate) const MODIFIED: usize = 1; // ! (modified files)
     pub(crate) const UNTRACKED: usize = 2; // ? (untracked files)
-    pub(crate) const WORKTREE_STATE: usize = 3; // Worktree: ✘↻/⚑⊟⊞
+    pub(crate) const WORKTREE_STATE: usize = 3; // Worktree: ✘↻/⚑⊟⊞⊙
     pub(crate) const MAIN_STATE: usize = 4; // Main relationship: ^_⊂✗↕↑↓
     pub(crate) const UPSTREAM_DIVERGENCE: usize = 5; // Remote: |⇅⇡⇣
     pub(crate) const USER_MARKER: usize = 6;
@@ -377,7 +384,7 @@ impl WorkingTreeStatus {
 /// ## Mutual Exclusivity
 ///
 /// **Worktree state (operations take priority over location):**
-/// Priority: ✘ > ↻ > ⊟ > ⊞ > ⚑ > /
+/// Priority: ✘ > ↻ > ⊟ > ⊞ > ⚑ > / > ⊙
 /// - ✘: Actual conflicts (must resolve)
 /// - ↻: A git operation is in progress (rebase, merge, cherry-pick, revert, bisect)
 /// - ⊟: Prunable (directory missing)
@@ -386,6 +393,7 @@ impl WorkingTreeStatus {
 ///   more than one worktree, or the path is off-template (informational, dim
 ///   yellow)
 /// - /: Branch without worktree
+/// - ⊙: A submodule is out of date or has changes
 ///
 /// **Main state (single position with priority):**
 /// Priority: ^ > _ > ⊂ > ✗ > – > ↕ > ↑ > ↓
@@ -427,6 +435,10 @@ pub struct StatusSymbols {
     /// once.
     pub(crate) worktree_state: Option<WorktreeState>,
 
+    /// Gate 2 input — submodule drift (position 3, lowest priority). Set
+    /// alongside `working_tree`, from the same task result.
+    pub(crate) submodules: SubmoduleDrift,
+
     /// Gate 4 output (position 5). `None` = loading; `Some(Divergence::None)`
     /// = resolved to nothing (in sync or no upstream).
     pub(crate) upstream_divergence: Option<Divergence>,
@@ -579,6 +591,9 @@ impl StatusSymbols {
                 SlotState::Visible(op.styled().unwrap_or_default())
             }
             Some(_) => match self.worktree_state {
+                None | Some(WorktreeState::None) if self.submodules.any() => {
+                    SlotState::Visible(cformat!("<yellow>{SUBMODULE_DRIFT}</>"))
+                }
                 None | Some(WorktreeState::None) => SlotState::Empty,
                 Some(WorktreeState::Branch) => {
                     SlotState::Visible(cformat!("<dim>{}</>", WorktreeState::Branch))
@@ -763,6 +778,7 @@ mod tests {
             worktree_state: Some(WorktreeState::None),
             upstream_divergence: Some(Divergence::None),
             working_tree: Some(WorkingTreeStatus::default()),
+            submodules: SubmoduleDrift::default(),
             user_marker: Some(None),
         };
         assert!(is_empty(&symbols));
@@ -790,6 +806,34 @@ mod tests {
         assert_snapshot!(symbols.format_compact(), @"[36m+[39m[36m![39m[2m↑[22m");
     }
 
+    #[test]
+    fn test_status_symbols_submodule_drift() {
+        let drifted = StatusSymbols {
+            operation_state: Some(OperationState::None),
+            worktree_state: Some(WorktreeState::None),
+            submodules: SubmoduleDrift {
+                out_of_date: true,
+                dirty: false,
+            },
+            ..Default::default()
+        };
+        assert_snapshot!(drifted.format_compact(), @"[33m⊙[39m");
+
+        // Lowest priority in its position: any worktree attribute wins.
+        let locked = StatusSymbols {
+            worktree_state: Some(WorktreeState::Locked),
+            ..drifted.clone()
+        };
+        assert!(!locked.format_compact().contains(SUBMODULE_DRIFT));
+
+        // Waits on the operation family like the rest of the position.
+        let loading = StatusSymbols {
+            operation_state: None,
+            ..drifted
+        };
+        assert!(!loading.format_compact().contains(SUBMODULE_DRIFT));
+    }
+
     #[test]
     fn test_status_symbols_render_with_mask() {
         let symbols = StatusSymbols {
@@ -821,6 +865,7 @@ mod tests {
             worktree_state: Some(WorktreeState::None),
             main_state: Some(MainState::Ahead),
             upstream_divergence: Some(Divergence::Ahead),
+            submodules: SubmoduleDrift::default(),
             // Two columns wide, matching the USER_MARKER allocation.
             user_marker: Some(Some("🔥".to_string())),
         }
@@ -1,6 +1,7 @@
 use std::io::IsTerminal;
 
-use crate::OutputFormat;
+use super::document::DocumentFormat;
+use crate::ListFormat;
 
 /// Resolved rendering decision for `wt list`. Collapses output format,
 /// `--progressive`/`--no-progressive` flags, and stdout TTY detection into
@@ -14,17 +15,29 @@ pub enum RenderTarget {
     /// Caller serializes data themselves (JSON output, picker UI). `collect`
     /// returns data without writing to stdout.
     Json,
+    /// Stream schema-2 JSON records to stdout as rows and fields resolve
+    /// (`--format=ndjson`). Plans tasks like [`RenderTarget::Json`].
+    JsonStream,
     /// Render a table to stdout. `progressive` controls whether intermediate
     /// rows are streamed (`true`) or only the final table is written (`false`).
     Table { progressive: bool },
+    /// Render the table's columns as a document (Markdown, CSV/TSV, HTML) to
+    /// stdout once collection finishes. Plans tasks and columns like the
+    /// buffered table, but lays out without a width limit.
+    Document(DocumentFormat),
 }
 
 impl RenderTarget {
     /// Resolve the target from CLI inputs and the current stdout TTY state.
-    pub fn detect(format: OutputFormat, progressive_flag: Option<bool>) -> Self {
+    pub fn detect(format: ListFormat, progressive_flag: Option<bool>) -> Self {
         match format {
-            OutputFormat::Json => RenderTarget::Json,
-            OutputFormat::Table => {
+            ListFormat::Json => RenderTarget::Json,
+            ListFormat::Ndjson => RenderTarget::JsonStream,
+            ListFormat::Markdown => RenderTarget::Document(DocumentFormat::Markdown),
+            ListFormat::Csv => RenderTarget::Document(DocumentFormat::Csv),
+            ListFormat::Tsv => RenderTarget::Document(DocumentFormat::Tsv),
+            ListFormat::Html => RenderTarget::Document(DocumentFormat::Html),
+            ListFormat::Table => {
                 let is_tty = std::io::stdout().is_terminal();
                 let progressive = match progressive_flag {
                     Some(p) => p && is_tty,
@@ -43,25 +56,37 @@ mod tests {
     #[test]
     fn json_format_always_resolves_to_json() {
         assert_eq!(
-            RenderTarget::detect(OutputFormat::Json, None),
+            RenderTarget::detect(ListFormat::Json, None),
             RenderTarget::Json
         );
         assert_eq!(
-            RenderTarget::detect(OutputFormat::Json, Some(true)),
+            RenderTarget::detect(ListFormat::Json, Some(true)),
             RenderTarget::Json
         );
         assert_eq!(
-            RenderTarget::detect(OutputFormat::Json, Some(false)),
+            RenderTarget::detect(ListFormat::Json, Some(false)),
             RenderTarget::Json
         );
     }
 
+    #[test]
+    fn document_formats_ignore_progressive() {
+        assert_eq!(
+            RenderTarget::detect(ListFormat::Markdown, Some(true)),
+            RenderTarget::Document(DocumentFormat::Markdown)
+        );
+        assert_eq!(
+            RenderTarget::detect(ListFormat::Csv, None),
+            RenderTarget::Document(DocumentFormat::Csv)
+        );
+    }
+
     #[test]
     fn explicit_no_progressive_disables_progressive() {
         // In test runs stdout isn't a TTY, but we assert the explicit-false
         // branch regardless of TTY state.
         assert_eq!(
-            RenderTarget::detect(OutputFormat::Table, Some(false)),
+            RenderTarget::detect(ListFormat::Table, Some(false)),
             RenderTarget::Table { progressive: false }
         );
     }
@@ -334,12 +334,20 @@ impl ProgressiveTable {
         stdout.flush()
     }
 
+    /// Whether [`finalize`](Self::finalize) with `final_row_count` rows erases
+    /// the skeleton and reprints instead of updating it in place.
+    fn reprints(&self, final_row_count: usize) -> bool {
+        self.row_count < self.total_row_count || final_row_count > self.row_count
+    }
+
     /// Finalize the table with final row content and footer.
     ///
     /// For the normal case, updates rows in-place and redraws the footer.
     /// When overflowing (skeleton showed a subset of rows), erases the skeleton
     /// and prints the complete table — the output scrolls naturally, avoiding
-    /// the `MoveUp`-into-scrollback problem.
+    /// the `MoveUp`-into-scrollback problem. Rows beyond the skeleton's (remote
+    /// host rows appended after the drain) take the same path, having no
+    /// skeleton line to update.
     ///
     /// The footer (summary) is written to stdout here even though the buffered
     /// path (`print_buffered_table`) narrates it on stderr: progressive mode
@@ -352,7 +360,7 @@ impl ProgressiveTable {
         final_rows: Vec<String>,
         final_footer: String,
     ) -> std::io::Result<()> {
-        if self.row_count < self.total_row_count {
+        if self.reprints(final_rows.len()) {
             // Overflow: erase skeleton, print complete table (scrolls naturally)
             debug_assert!(
                 self.rendered,
@@ -627,6 +635,23 @@ mod tests {
         assert!(table.row_count < table.total_row_count);
     }
 
+    #[test]
+    fn appended_rows_reprint() {
+        let skeletons = vec!["a".into(), "b".into()];
+        let table = ProgressiveTable::new_with_height(
+            "header".into(),
+            skeletons,
+            "loading".into(),
+            80,
+            Some(20),
+            false,
+        );
+
+        assert!(!table.reprints(2));
+        // Rows appended after the skeleton (remote hosts) have no line to update
+        assert!(table.reprints(3));
+    }
+
     #[test]
     fn overflow_hidden_rows_are_noop() {
         let skeletons: Vec<String> = (0..10).map(|i| format!("row{i}")).collect();
@@ -0,0 +1,341 @@
+//! `wt list` rows for worktrees on `[remotes]` hosts.
+//!
+//! Each host answers with its own `wt list --format=json` (schema 1), fetched
+//! on a thread per host while the local collect runs. The host already
+//! resolved every fact, so its rows join the table fully populated and run no
+//! tasks here. The progressive table renders the local skeleton without them
+//! and appends them once the local rows finish. A host that fails to answer
+//! is reported as a warning and left out.
+
+use std::path::PathBuf;
+use std::thread::JoinHandle;
+
+use color_print::cformat;
+use serde::Deserialize;
+use worktrunk::git::submodule::SubmoduleDrift;
+use worktrunk::git::{InProgressOperation, IntegrationReason, LineDiff, Repository};
+use worktrunk::styling::warning_message;
+
+use super::model::{
+    AheadBehind, BranchDiffTotals, CommitDetails, Divergence, ItemKind, ListItem, MainState,
+    OperationState, SeededFacts, StatusSymbols, UpstreamStatus, WorkingTreeStatus, WorktreeData,
+    WorktreeState,
+};
+use crate::commands::remote_host::RemoteHost;
+
+/// One row of a host's `wt list --format=json`: the subset of the schema-1
+/// `JsonItem` the table renders.
+#[derive(Debug, Deserialize)]
+pub(crate) struct RemoteItem {
+    pub(crate) branch: Option<String>,
+    pub(crate) path: Option<PathBuf>,
+    kind: String,
+    commit: RemoteCommit,
+    working_tree: Option<RemoteWorkingTree>,
+    main_state: Option<String>,
+    integration_reason: Option<String>,
+    operation_state: Option<String>,
+    main: Option<RemoteMain>,
+    remote: Option<RemoteUpstream>,
+    worktree: Option<RemoteWorktree>,
+    /// Absent when nothing drifted, and from hosts predating the field.
+    #[serde(default)]
+    submodules: Option<SubmoduleDrift>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteCommit {
+    sha: String,
+    short_sha: String,
+    message: String,
+    timestamp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteWorkingTree {
+    staged: bool,
+    modified: bool,
+    untracked: bool,
+    renamed: bool,
+    deleted: bool,
+    diff: Option<RemoteDiff>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+struct RemoteDiff {
+    added: usize,
+    deleted: usize,
+}
+
+impl From<RemoteDiff> for LineDiff {
+    fn from(diff: RemoteDiff) -> Self {
+        LineDiff::from((diff.added, diff.deleted))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteMain {
+    ahead: usize,
+    behind: usize,
+    diff: Option<RemoteDiff>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteUpstream {
+    name: String,
+    branch: String,
+    ahead: usize,
+    behind: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteWorktree {
+    state: Option<String>,
+    reason: Option<String>,
+    detached: bool,
+}
+
+/// In-flight `wt list` fetches, one per configured host.
+pub(crate) struct PendingHosts(Vec<(String, JoinHandle<anyhow::Result<Vec<RemoteItem>>>)>);
+
+impl PendingHosts {
+    /// Start fetching every configured host's worktrees. A host whose config
+    /// doesn't resolve reports at [`Self::join`] like one that fails to answer.
+    pub(crate) fn spawn(repo: &Repository) -> Self {
+        Self(
+            RemoteHost::all(repo)
+                .into_iter()
+                .map(|(name, host)| (name, std::thread::spawn(move || host?.list())))
+                .collect(),
+        )
+    }
+
+    /// Wait for every host and convert its worktrees into rows, in host name
+    /// order. Failures go to `emit_warning`.
+    pub(crate) fn join(self, emit_warning: impl Fn(String)) -> Vec<ListItem> {
+        let mut items = Vec::new();
+        for (name, handle) in self.0 {
+            let fetched = handle
+                .join()
+                .unwrap_or_else(|_| Err(anyhow::anyhow!("fetch panicked")));
+            match fetched {
+                Ok(remote_items) => items.extend(
+                    remote_items
+                        .into_iter()
+                        .filter(|item| item.kind == "worktree")
+                        .map(|item| remote_item(&name, item)),
+                ),
+                Err(err) => emit_warning(
+                    warning_message(cformat!("Skipped remote host <bold>{name}</>: {err:#}"))
+                        .to_string(),
+                ),
+            }
+        }
+        items
+    }
+}
+
+/// A host's worktree as a table row, with every gate resolved from the host's
+/// answer.
+fn remote_item(host: &str, item: RemoteItem) -> ListItem {
+    let worktree = item.worktree.unwrap_or(RemoteWorktree {
+        state: None,
+        reason: None,
+        detached: false,
+    });
+    let worktree_state = match worktree.state.as_deref() {
+        Some("branch_worktree_mismatch") => WorktreeState::BranchWorktreeMismatch,
+        Some("duplicate_branch") => WorktreeState::DuplicateBranch,
+        Some("prunable") => WorktreeState::Prunable,
+        Some("locked") => WorktreeState::Locked,
+        _ => WorktreeState::None,
+    };
+    let operation_state = match item.operation_state.as_deref() {
+        Some("conflicts") => OperationState::Conflicts,
+        Some(operation) => operation
+            .parse::<InProgressOperation>()
+            .map_or(OperationState::None, OperationState::InProgress),
+        None => OperationState::None,
+    };
+    let working_tree_status = item.working_tree.as_ref().map(|wt| {
+        WorkingTreeStatus::new(wt.staged, wt.modified, wt.untracked, wt.renamed, wt.deleted)
+    });
+    let upstream = item
+        .remote
+        .map(|remote| UpstreamStatus {
+            upstream_short: Some(format!("{}/{}", remote.name, remote.branch)),
+            remote: Some(remote.name),
+            ahead: remote.ahead,
+            behind: remote.behind,
+        })
+        .unwrap_or_default();
+    let upstream_divergence = upstream.active().map_or(Divergence::None, |active| {
+        Divergence::from_counts_with_remote(active.ahead, active.behind)
+    });
+
+    let data = WorktreeData {
+        path: item.path.unwrap_or_default(),
+        detached: worktree.detached,
+        locked: (worktree_state == WorktreeState::Locked)
+            .then(|| worktree.reason.clone().unwrap_or_default()),
+        prunable: (worktree_state == WorktreeState::Prunable)
+            .then(|| worktree.reason.clone().unwrap_or_default()),
+        working_tree_diff: item
+            .working_tree
+            .as_ref()
+            .and_then(|wt| wt.diff)
+            .map(LineDiff::from),
+        working_tree_status,
+        has_conflicts: Some(operation_state == OperationState::Conflicts),
+        has_working_tree_conflicts: Some(None),
+        git_operation: Some(match operation_state {
+            OperationState::InProgress(operation) => Some(operation),
+            _ => None,
+        }),
+        branch_worktree_mismatch: worktree_state == WorktreeState::BranchWorktreeMismatch,
+        duplicate_branch: worktree_state == WorktreeState::DuplicateBranch,
+        host: Some(host.to_string()),
+        submodule_drift: Some(item.submodules.unwrap_or_default()),
+        container: Some(None),
+        ..Default::default()
+    };
+
+    ListItem {
+        head: item.commit.sha,
+        short_sha: item.commit.short_sha,
+        branch: item.branch,
+        commit: Some(CommitDetails {
+            timestamp: item.commit.timestamp,
+            commit_message: item.commit.message,
+        }),
+        counts: Some(
+            item.main
+                .as_ref()
+                .map_or_else(AheadBehind::default, |main| AheadBehind {
+                    ahead: main.ahead,
+                    behind: main.behind,
+                }),
+        ),
+        branch_diff: Some(BranchDiffTotals {
+            diff: item
+                .main
+                .as_ref()
+                .and_then(|main| main.diff)
+                .map(LineDiff::from)
+                .unwrap_or_default(),
+        }),
+        committed_trees_match: None,
+        has_file_changes: None,
+        would_merge_add: None,
+        is_patch_id_match: None,
+        is_ancestor: None,
+        is_orphan: Some(item.main_state.as_deref() == Some("orphan")),
+        upstream: Some(upstream),
+        pr_status: Some(None),
+        url: None,
+        url_active: None,
+        summary: None,
+        has_merge_tree_conflicts: Some(item.main_state.as_deref() == Some("would_conflict")),
+        user_marker: Some(None),
+        status_symbols: StatusSymbols {
+            main_state: Some(main_state(
+                item.main_state.as_deref(),
+                item.integration_reason.as_deref(),
+            )),
+            operation_state: Some(operation_state),
+            worktree_state: Some(worktree_state),
+            upstream_divergence: Some(upstream_divergence),
+            working_tree: Some(working_tree_status.unwrap_or_default()),
+            submodules: item.submodules.unwrap_or_default(),
+            user_marker: Some(None),
+        },
+        statusline: None,
+        custom_values: Vec::new(),
+        agent_sessions: Vec::new(),
+        agent_cell: String::new(),
+        // The integration signals stay on the host; schema-2 JSON reports
+        // them as undetermined rather than inventing them.
+        seeded: SeededFacts {
+            integration: true,
+            ..Default::default()
+        },
+        kind: ItemKind::Worktree(Box::new(data)),
+    }
+}
+
+/// The schema-1 `main_state` (and `integration_reason`) back as a [`MainState`].
+fn main_state(state: Option<&str>, reason: Option<&str>) -> MainState {
+    match state {
+        Some("is_main") => MainState::IsMain,
+        Some("would_conflict") => MainState::WouldConflict,
+        Some("empty") => MainState::Empty,
+        Some("same_commit") => MainState::SameCommit,
+        Some("integrated") => reason
+            .and_then(|reason| reason.parse::<IntegrationReason>().ok())
+            .map_or(MainState::None, MainState::Integrated),
+        Some("orphan") => MainState::Orphan,
+        Some("diverged") => MainState::Diverged,
+        Some("ahead") => MainState::Ahead,
+        Some("behind") => MainState::Behind,
+        _ => MainState::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_item_round_trips_schema_1() {
+        let json = r#"{
+            "branch": "feat",
+            "path": "/srv/repo.feat",
+            "kind": "worktree",
+            "commit": {"sha": "abc1234def", "short_sha": "abc1234", "message": "Add feat", "timestamp": 1700000000},
+            "working_tree": {"staged": true, "modified": false, "untracked": true, "renamed": false, "deleted": false, "diff": {"added": 3, "deleted": 1}},
+            "main_state": "integrated",
+            "integration_reason": "trees-match",
+            "operation_state": "rebase",
+            "main": {"ahead": 2, "behind": 1},
+            "remote": {"name": "origin", "branch": "feat", "ahead": 1, "behind": 0},
+            "worktree": {"state": "locked", "reason": "in use", "detached": false},
+            "is_main": false,
+            "is_current": false,
+            "is_previous": false
+        }"#;
+        let item = remote_item("devbox", serde_json::from_str(json).unwrap());
+
+        let data = item.worktree_data().unwrap();
+        assert_eq!(data.host.as_deref(), Some("devbox"));
+        assert_eq!(
+            data.display_path(std::path::Path::new("/repo")),
+            "devbox:/srv/repo.feat"
+        );
+        assert_eq!(data.locked.as_deref(), Some("in use"));
+        assert_eq!(data.git_operation, Some(Some(InProgressOperation::Rebase)));
+        let symbols = &item.status_symbols;
+        assert_eq!(
+            symbols.main_state,
+            Some(MainState::Integrated(IntegrationReason::TreesMatch))
+        );
+        assert_eq!(symbols.worktree_state, Some(WorktreeState::Locked));
+        assert_eq!(symbols.upstream_divergence, Some(Divergence::Ahead));
+        assert!(
+            symbols
+                .working_tree
+                .is_some_and(|wt| wt.staged && wt.untracked)
+        );
+        assert_eq!(item.counts.map(|c| (c.ahead, c.behind)), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_main_state_unknown_values_resolve_to_none() {
+        assert_eq!(main_state(Some("ahead"), None), MainState::Ahead);
+        assert_eq!(
+            main_state(Some("integrated"), Some("nonsense")),
+            MainState::None
+        );
+        assert_eq!(main_state(Some("from-the-future"), None), MainState::None);
+        assert_eq!(main_state(None, None), MainState::None);
+    }
+}
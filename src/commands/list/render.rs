@@ -1,4 +1,4 @@
-use crate::display::{format_relative_time_short, shorten_path};
+use crate::display::format_relative_time_short;
 use anstyle::{Effects, Style};
 use unicode_width::UnicodeWidthStr;
 use worktrunk::styling::{DETACHED, StyledLine, truncate_visible};
@@ -43,6 +43,18 @@ fn bump_emphasis(style: Style) -> Style {
     }
 }
 
+/// LFS state as `hydrated/tracked`.
+pub(super) fn lfs_text(state: &worktrunk::git::lfs::LfsState) -> String {
+    format!("{}/{}", state.hydrated(), state.files)
+}
+
+/// The LFS cell: hydrated/tracked LFS files, empty outside an LFS repository.
+fn lfs_cell(item: &ListItem) -> String {
+    item.worktree_data()
+        .and_then(|data| data.lfs)
+        .map_or_else(String::new, |state| lfs_text(&state))
+}
+
 impl DiffColumnConfig {
     /// Check if a value exceeds the allocated digit width
     fn exceeds_width(value: usize, digits: usize) -> bool {
@@ -285,11 +297,17 @@ impl LayoutConfig {
     ///
     /// Used for both worktrees and branch-only items; branch-only rows render an empty path
     /// and a blank gutter placeholder. See [`Self::render_list_item_line`] for `placeholder` semantics.
+    ///
+    /// Rows from a `[remotes]` host arrive complete and run no tasks, so they
+    /// render in full from the first frame.
     pub fn render_skeleton_row(&self, item: &ListItem, placeholder: &str) -> StyledLine {
+        if item.worktree_data().is_some_and(|data| data.host.is_some()) {
+            return self.render_list_item_line(item, placeholder);
+        }
         let branch = item.display_name();
         let shortened_path = item
-            .worktree_path()
-            .map(|p| shorten_path(p, &self.main_worktree_path))
+            .worktree_data()
+            .map(|data| data.display_path(&self.main_worktree_path))
             .unwrap_or_default();
 
         let dim = Style::new().dimmed();
@@ -350,6 +368,13 @@ impl LayoutConfig {
                         .unwrap_or("");
                     return col.render_text_cell(text, None);
                 }
+                // Agent cells are rendered at collect time, like custom values.
+                ColumnKind::Agent => {
+                    return col.render_text_cell(&item.agent_cell, None);
+                }
+                ColumnKind::Lfs => {
+                    return col.render_text_cell(&lfs_cell(item), None);
+                }
                 ColumnKind::CiStatus if item.pr_status.is_some() => {
                     // Set before the skeleton only by the picker's cache prime —
                     // render it now for an instant first paint; the live CiStatus
@@ -376,6 +401,11 @@ impl LayoutConfig {
                 ColumnKind::Time | ColumnKind::Message => {
                     return col.render_cell(item, self, spinner);
                 }
+                // Branch rows have no container, and prunable or host rows
+                // are settled before the skeleton; the rest show the spinner.
+                ColumnKind::Container => {
+                    return col.render_cell(item, self, spinner);
+                }
                 _ => {
                     // Show spinner for data columns (placeholder_cell handles alignment)
                     return col.placeholder_cell(spinner);
@@ -534,7 +564,7 @@ impl ColumnLayout {
                 let Some(data) = worktree_data else {
                     return StyledLine::new();
                 };
-                let path_str = shorten_path(&data.path, main_worktree_path);
+                let path_str = data.display_path(main_worktree_path);
                 self.render_text_cell(&path_str, text_style)
             }
             ColumnKind::Upstream => {
@@ -628,6 +658,65 @@ impl ColumnLayout {
                     .unwrap_or("");
                 self.render_text_cell(text, text_style)
             }
+            ColumnKind::Agent => self.render_text_cell(&item.agent_cell, text_style),
+            ColumnKind::Container => {
+                let Some(data) = worktree_data else {
+                    return StyledLine::new();
+                };
+                match data.container {
+                    None => self.placeholder_cell(placeholder),
+                    Some(state) => self.render_text_cell(
+                        state.map_or("", worktrunk::container::ContainerState::as_str),
+                        text_style,
+                    ),
+                }
+            }
+            ColumnKind::Lfs => self.render_text_cell(&lfs_cell(item), text_style),
+        }
+    }
+
+    /// Render this column's cell for `item` as plain text, for the document
+    /// formats (`wt list --format=markdown|csv|tsv|html`).
+    ///
+    /// The same cell [`Self::render_cell`] draws with [`PLACEHOLDER`] — so an
+    /// undetermined value reads `·` and an inapplicable one is empty, as in
+    /// the table — without styling, padding or truncation: diffs read `+3 -1`,
+    /// message and summary are whole, and CI names its status.
+    pub(crate) fn render_plain_cell(&self, item: &ListItem, layout: &LayoutConfig) -> String {
+        use ansi_str::AnsiStr;
+
+        match self.kind {
+            ColumnKind::Message => {
+                if let Some(commit) = &item.commit {
+                    return commit.commit_message.clone();
+                }
+            }
+            ColumnKind::Summary => {
+                if let Some(summary) = &item.summary {
+                    return summary.clone().unwrap_or_default();
+                }
+            }
+            ColumnKind::CiStatus => {
+                if let Some(Some(pr_status)) = &item.pr_status {
+                    let status: &'static str = pr_status.ci_status.into();
+                    return format!(
+                        "{} {status}",
+                        pr_status.format_cell(usize::MAX, LinkStyle::Expanded)
+                    )
+                    .ansi_strip()
+                    .into_owned();
+                }
+            }
+            _ => {}
+        }
+        let rendered = self.render_cell(item, layout, PLACEHOLDER).render();
+        let plain = rendered.ansi_strip();
+        if matches!(self.format, ColumnFormat::Diff(_)) || self.kind == ColumnKind::Status {
+            // Collapse the alignment padding inside positional cells
+            // (`+3   -1`, `+!  ↕`).
+            plain.split_whitespace().collect::<Vec<_>>().join(" ")
+        } else {
+            plain.trim().to_string()
         }
     }
 }
@@ -0,0 +1,534 @@
+//! `wt mcp` — worktrunk as a Model Context Protocol server for AI agents.
+//!
+//! The Claude, Codex and OpenCode plugins report an agent's activity to
+//! worktrunk through markers; this is the other direction, letting an agent
+//! query and drive worktrunk through MCP tools instead of a shell. The
+//! transport is MCP's stdio framing — newline-delimited JSON-RPC, shared with
+//! `wt serve` — and the server lives for the agent's session, keeping one
+//! warm [`Repository`] as `wt serve` does.
+//!
+//! | Tool | Does |
+//! |---|---|
+//! | `list_worktrees` | `wt list --format=json` (schema 2) |
+//! | `create_worktree` | A new branch in a worktree beside the others, from the agent's `HEAD` |
+//! | `worktree_diff` | `wt step diff` for another worktree |
+//! | `set_marker`, `set_var` | `wt config state marker` / `vars` |
+//! | `merge` | `wt merge` via [`worktrunk::api`] |
+//!
+//! Project hooks get the same gate as everywhere else
+//! ([`unapproved_hook_commands`]), but nobody can answer a prompt: a tool
+//! whose hooks aren't all approved fails before changing anything, naming the
+//! commands for the user to approve with `wt config approvals add`. An agent
+//! has no way to approve them itself.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use anyhow::Context;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use worktrunk::api::{ConfiguredHooks, CreateOptions, MergeOptions, Worktrunk};
+use worktrunk::git::{HookType, Repository};
+use worktrunk::notify::{Notification, notify};
+use worktrunk::utils::epoch_now;
+
+use super::command_approval::unapproved_hook_commands;
+use super::serve::rpc::{self, Outbox, Request, RpcError};
+
+/// Protocol revisions this server speaks, newest first. A client asking for
+/// one of them gets it; any other gets the newest.
+const PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// Handle `wt mcp`: answer MCP requests on stdin until it closes.
+pub(crate) fn handle_mcp() -> anyhow::Result<()> {
+    let repo = Repository::current().context("Failed to start MCP server")?;
+    let server = McpServer::new(repo)?;
+    let outbox = Outbox::new(std::io::stdout());
+    rpc::serve_lines(std::io::stdin().lock(), &outbox, |request| {
+        server.dispatch(request)
+    })
+}
+
+struct McpServer {
+    /// The warm repository, refreshed per request as in `wt serve`.
+    repo: Mutex<Repository>,
+    /// The main worktree (or bare repository), where merges run from so
+    /// removing a merged worktree never pulls the floor from under the
+    /// operation.
+    root: PathBuf,
+    /// The worktree the agent runs in: the base of the worktrees it creates.
+    here: PathBuf,
+}
+
+impl McpServer {
+    fn new(repo: Repository) -> anyhow::Result<Self> {
+        let root = repo.repo_path()?.to_path_buf();
+        let here = repo.current_worktree().root()?;
+        Ok(Self {
+            repo: Mutex::new(repo),
+            root,
+            here,
+        })
+    }
+
+    fn repo(&self) -> Repository {
+        let mut warm = self.repo.lock().unwrap_or_else(|e| e.into_inner());
+        *warm = warm.refreshed();
+        warm.clone()
+    }
+
+    fn dispatch(&self, request: &Request) -> Result<Value, RpcError> {
+        match request.method.as_str() {
+            "initialize" => Ok(initialize(request.params()?)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tools() })),
+            "tools/call" => self.call(request.params()?),
+            // `notifications/initialized`, `notifications/cancelled`: nothing
+            // to do, and notifications get no response.
+            method if method.starts_with("notifications/") => Ok(Value::Null),
+            method => Err(RpcError::method_not_found(method)),
+        }
+    }
+
+    /// Run a tool. Bad arguments are a protocol error; a tool that ran and
+    /// failed is a result with `isError`, which MCP hands to the model.
+    fn call(&self, params: CallParams) -> Result<Value, RpcError> {
+        let arguments = params.arguments;
+        let outcome = match params.name.as_str() {
+            "list_worktrees" => self.list_worktrees(parse(arguments)?),
+            "create_worktree" => self.create_worktree(parse(arguments)?),
+            "worktree_diff" => self.worktree_diff(parse(arguments)?),
+            "set_marker" => self.set_marker(parse(arguments)?),
+            "set_var" => self.set_var(parse(arguments)?),
+            "merge" => self.merge(parse(arguments)?),
+            name => return Err(RpcError::invalid_params(format!("Unknown tool: {name}"))),
+        };
+        Ok(outcome
+            .unwrap_or_else(|e| ToolResult::failed(&e))
+            .into_value())
+    }
+
+    fn list_worktrees(&self, args: ListArgs) -> anyhow::Result<ToolResult> {
+        let envelope = super::list::json_envelope(&self.repo(), args.branches, false, args.full)?;
+        Ok(ToolResult::json(serde_json::to_value(envelope)?))
+    }
+
+    fn create_worktree(&self, args: CreateArgs) -> anyhow::Result<ToolResult> {
+        let repo = self.repo();
+        if let Some(denied) = require_approved(&repo, &[HookType::PreCreate, HookType::PostCreate])?
+        {
+            return Ok(denied);
+        }
+        let mut options = CreateOptions::new(&args.branch).create_branch(true);
+        if let Some(base) = args.base {
+            options = options.base(base);
+        }
+        let created = Worktrunk::open(&self.here)?
+            .with_hooks(ConfiguredHooks::new())
+            .create_worktree(&options)?;
+        Ok(ToolResult::json(json!({
+            "branch": created.branch,
+            "path": created.path,
+            "warnings": created.warnings,
+        })))
+    }
+
+    /// The branch's changes since it left the target — committed, staged,
+    /// unstaged and untracked — as `wt step diff --branch` shows them.
+    fn worktree_diff(&self, args: DiffArgs) -> anyhow::Result<ToolResult> {
+        let path = self.repo().require_worktree(&args.branch)?;
+        let repo = Repository::at(&path)?;
+        let target = repo.require_target_ref(args.target.as_deref())?;
+        let merge_base = repo
+            .merge_base("HEAD", &target)?
+            .context("No common ancestor with target branch")?;
+        let format = if args.stat { "--stat" } else { "--patch" };
+        let diff = repo
+            .current_worktree()
+            .prepare_diff_with_untracked([merge_base])?
+            .capture([format, "--no-color"])?;
+        Ok(ToolResult::text(if diff.is_empty() {
+            format!("No changes on {} since {target}", args.branch)
+        } else {
+            diff
+        }))
+    }
+
+    /// `wt config state marker set`, or `clear` without a marker.
+    fn set_marker(&self, args: MarkerArgs) -> anyhow::Result<ToolResult> {
+        let repo = self.repo();
+        let branch = state_branch(&repo, args.branch.as_deref(), "set marker")?;
+        let key = format!("worktrunk.state.{branch}.marker");
+        match &args.marker {
+            Some(marker) => {
+                let value = json!({ "marker": marker, "set_at": epoch_now() });
+                repo.set_config(&key, &value.to_string())?;
+                notify(
+                    &repo.user_config().notify,
+                    &Notification::marker(&branch, marker),
+                );
+            }
+            None => {
+                repo.unset_config(&key)?;
+            }
+        }
+        Ok(ToolResult::json(
+            json!({ "branch": branch, "marker": args.marker }),
+        ))
+    }
+
+    /// `wt config state vars set`, or `clear` without a value.
+    fn set_var(&self, args: VarArgs) -> anyhow::Result<ToolResult> {
+        super::config::validate_vars_key(&args.key)?;
+        let repo = self.repo();
+        let branch = state_branch(&repo, args.branch.as_deref(), "set variable")?;
+        let key = format!("worktrunk.state.{branch}.vars.{}", args.key);
+        match &args.value {
+            Some(value) => repo.set_config(&key, value)?,
+            None => {
+                repo.unset_config(&key)?;
+            }
+        }
+        Ok(ToolResult::json(json!({
+            "branch": branch,
+            "key": args.key,
+            "value": args.value,
+        })))
+    }
+
+    fn merge(&self, args: MergeArgs) -> anyhow::Result<ToolResult> {
+        let repo = self.repo();
+        let mut hook_types = vec![HookType::PreMerge, HookType::PostMerge];
+        if args.remove {
+            hook_types.extend([HookType::PreRemove, HookType::PostRemove]);
+        }
+        if let Some(denied) = require_approved(&repo, &hook_types)? {
+            return Ok(denied);
+        }
+
+        let mut options = MergeOptions::new(&args.branch).remove(args.remove);
+        if let Some(target) = args.target {
+            options = options.target(target);
+        }
+        if let Some(message) = args.squash_message {
+            options = options.squash(message);
+        }
+        let merged = Worktrunk::open(&self.root)?
+            .with_hooks(ConfiguredHooks::new())
+            .merge(&options)?;
+        Ok(ToolResult::json(json!({
+            "branch": merged.branch,
+            "target": merged.target,
+            "commit": merged.commit,
+            "squashed": merged.squashed,
+            "rebased": merged.rebased,
+            "removed": merged.removed.is_some(),
+            "warnings": merged.warnings,
+        })))
+    }
+}
+
+/// The `initialize` handshake. Capabilities are tools only: no resources,
+/// prompts, or list-changed notifications.
+fn initialize(params: InitializeParams) -> Value {
+    let version = PROTOCOL_VERSIONS
+        .iter()
+        .find(|v| params.protocol_version.as_deref() == Some(**v))
+        .unwrap_or(&PROTOCOL_VERSIONS[0]);
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": {} },
+        "serverInfo": { "name": "worktrunk", "version": crate::cli::version_str() },
+        "instructions": "Worktrunk manages this repository's git worktrees. Each worktree holds one branch; \
+            use list_worktrees to see them all, create_worktree to start a subtask on its own branch, \
+            and merge to land a finished branch. Project hooks must be approved by the user \
+            (`wt config approvals add`) before tools that run them succeed.",
+    })
+}
+
+/// Hold a hook-running tool at the gate: `Some` failure result naming the
+/// project commands still awaiting approval, `None` when all are approved.
+fn require_approved(
+    repo: &Repository,
+    hook_types: &[HookType],
+) -> anyhow::Result<Option<ToolResult>> {
+    let unapproved = unapproved_hook_commands(repo, hook_types)?;
+    if unapproved.is_empty() {
+        return Ok(None);
+    }
+    let listing: Vec<String> = unapproved
+        .iter()
+        .map(|cmd| format!("{}: {}", cmd.scope(), cmd.command.template))
+        .collect();
+    let commands: Vec<Value> = unapproved
+        .iter()
+        .map(|cmd| {
+            json!({
+                "hook": cmd.scope(),
+                "name": cmd.command.name,
+                "command": cmd.command.template,
+            })
+        })
+        .collect();
+    let mut result = ToolResult::error(format!(
+        "This project's hooks need approval before they can run without a prompt. \
+         Ask the user to review them and run `wt config approvals add`:\n{}",
+        listing.join("\n")
+    ));
+    result.structured = Some(json!({ "unapproved": commands }));
+    Ok(Some(result))
+}
+
+/// The branch a state tool writes to: the named one, or the agent's own.
+fn state_branch(repo: &Repository, branch: Option<&str>, action: &str) -> anyhow::Result<String> {
+    match branch {
+        Some(branch) => repo.require_selected_branch(branch, action),
+        None => repo.require_current_branch(&format!("{action} for current branch")),
+    }
+}
+
+fn parse<T: DeserializeOwned>(arguments: Value) -> Result<T, RpcError> {
+    serde_json::from_value(arguments)
+        .map_err(|e| RpcError::invalid_params(format!("Invalid arguments: {e}")))
+}
+
+/// A `tools/call` result: text for the model, and the same data as JSON for
+/// clients that read `structuredContent`.
+struct ToolResult {
+    text: String,
+    structured: Option<Value>,
+    is_error: bool,
+}
+
+impl ToolResult {
+    fn text(text: String) -> Self {
+        Self {
+            text,
+            structured: None,
+            is_error: false,
+        }
+    }
+
+    fn json(value: Value) -> Self {
+        Self {
+            text: serde_json::to_string_pretty(&value).unwrap_or_default(),
+            structured: Some(value),
+            is_error: false,
+        }
+    }
+
+    fn error(message: String) -> Self {
+        Self {
+            text: message,
+            structured: None,
+            is_error: true,
+        }
+    }
+
+    /// A tool that failed partway, with the error as `wt` would print it.
+    fn failed(error: &anyhow::Error) -> Self {
+        Self::error(anstream::adapter::strip_str(&format!("{error:#}")).to_string())
+    }
+
+    fn into_value(self) -> Value {
+        let mut result = json!({
+            "content": [{ "type": "text", "text": self.text }],
+            "isError": self.is_error,
+        });
+        if let Some(structured) = self.structured {
+            result["structuredContent"] = structured;
+        }
+        result
+    }
+}
+
+/// Every tool with its input schema, as `tools/list` returns them.
+fn tools() -> Value {
+    let branch = json!({ "type": "string", "description": "Branch name, or a worktree path" });
+    json!([
+        {
+            "name": "list_worktrees",
+            "title": "List worktrees",
+            "description": "List the repository's worktrees as `wt list --format=json` reports them: \
+                each branch's path, working-tree changes, commits ahead of and behind the default \
+                branch and its upstream, and markers. `full` adds CI status and line counts; \
+                `branches` adds branches that have no worktree.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branches": { "type": "boolean", "description": "Include branches without a worktree" },
+                    "full": { "type": "boolean", "description": "Include CI status and diff line counts (slower)" },
+                },
+                "additionalProperties": false,
+            },
+            "outputSchema": super::list::json_v2::schema_document(),
+            "annotations": { "readOnlyHint": true },
+        },
+        {
+            "name": "create_worktree",
+            "title": "Create a worktree",
+            "description": "Create a new branch in its own worktree beside the others, for working on a \
+                subtask in parallel. The branch starts from `base`, or from this worktree's HEAD. \
+                Returns the worktree's path.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": { "type": "string", "description": "Name of the new branch" },
+                    "base": { "type": "string", "description": "Commit-ish to start from (default: this worktree's HEAD)" },
+                },
+                "required": ["branch"],
+                "additionalProperties": false,
+            },
+            "annotations": { "destructiveHint": false },
+        },
+        {
+            "name": "worktree_diff",
+            "title": "Diff a worktree",
+            "description": "Show everything a worktree's branch changed since it diverged from the target \
+                branch — commits plus staged, unstaged and untracked files — as a unified diff.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": branch,
+                    "target": { "type": "string", "description": "Branch to compare against (default: the default branch)" },
+                    "stat": { "type": "boolean", "description": "Only a per-file summary" },
+                },
+                "required": ["branch"],
+                "additionalProperties": false,
+            },
+            "annotations": { "readOnlyHint": true },
+        },
+        {
+            "name": "set_marker",
+            "title": "Set a branch marker",
+            "description": "Set the short marker `wt list` shows beside a branch (an emoji or a word), \
+                or clear it when `marker` is omitted.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": { "type": "string", "description": "Branch to mark (default: this worktree's)" },
+                    "marker": { "type": "string" },
+                },
+                "additionalProperties": false,
+            },
+            "annotations": { "idempotentHint": true },
+        },
+        {
+            "name": "set_var",
+            "title": "Set a branch variable",
+            "description": "Set a per-branch variable that hook templates read as `{{ vars.<key> }}`, \
+                or clear it when `value` is omitted.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": { "type": "string", "description": "Branch to set it on (default: this worktree's)" },
+                    "key": { "type": "string", "description": "Letters, digits, `-` and `_`" },
+                    "value": { "type": "string" },
+                },
+                "required": ["key"],
+                "additionalProperties": false,
+            },
+            "annotations": { "idempotentHint": true },
+        },
+        {
+            "name": "merge",
+            "title": "Merge a branch",
+            "description": "Merge a worktree's branch into the target like `wt merge`: rebase onto the \
+                target, run pre-merge hooks, fast-forward the target, and remove the worktree and \
+                branch. Pass `squash_message` to squash the branch into one commit first.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": branch,
+                    "target": { "type": "string", "description": "Branch to merge into (default: the default branch)" },
+                    "squash_message": { "type": "string", "description": "Squash into one commit with this message" },
+                    "remove": { "type": "boolean", "description": "Remove the worktree and branch afterwards (default: true)" },
+                },
+                "required": ["branch"],
+                "additionalProperties": false,
+            },
+            "annotations": { "destructiveHint": true },
+        },
+    ])
+}
+
+fn yes() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InitializeParams {
+    #[serde(default)]
+    protocol_version: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CallParams {
+    name: String,
+    #[serde(default = "empty_object")]
+    arguments: Value,
+}
+
+fn empty_object() -> Value {
+    json!({})
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ListArgs {
+    #[serde(default)]
+    branches: bool,
+    #[serde(default)]
+    full: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CreateArgs {
+    branch: String,
+    #[serde(default)]
+    base: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DiffArgs {
+    branch: String,
+    #[serde(default)]
+    target: Option<String>,
+    #[serde(default)]
+    stat: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MarkerArgs {
+    #[serde(default)]
+    branch: Option<String>,
+    #[serde(default)]
+    marker: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VarArgs {
+    #[serde(default)]
+    branch: Option<String>,
+    key: String,
+    #[serde(default)]
+    value: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MergeArgs {
+    branch: String,
+    #[serde(default)]
+    target: Option<String>,
+    #[serde(default)]
+    squash_message: Option<String>,
+    #[serde(default = "yes")]
+    remove: bool,
+}
@@ -5,6 +5,7 @@ pub(crate) mod command_executor;
 pub(crate) mod commit;
 pub(crate) mod config;
 pub(crate) mod configure_shell;
+pub(crate) mod container;
 pub(crate) mod context;
 pub(crate) mod custom;
 mod eval;
@@ -15,16 +16,20 @@ mod hook_filter;
 pub(crate) mod hook_plan;
 pub(crate) mod hooks;
 pub(crate) mod init;
+pub(crate) mod lfs;
 pub(crate) mod list;
+pub(crate) mod mcp;
 pub(crate) mod merge;
 pub(crate) mod picker;
 pub(crate) mod pipeline_spec;
 pub(crate) mod process;
 pub(crate) mod project_config;
 mod relocate;
+pub(crate) mod remote_host;
 pub(crate) mod remove;
 pub(crate) mod repository_ext;
 mod run_pipeline;
+pub(crate) mod serve;
 pub(crate) mod statusline;
 pub(crate) mod step;
 pub(crate) mod template_vars;
@@ -35,14 +40,16 @@ pub(crate) use alias::{
     try_alias,
 };
 pub(crate) use config::{
-    add_approvals, clear_approvals, handle_alias_dry_run, handle_alias_show, handle_cache_clear,
-    handle_cache_get, handle_claude_install, handle_claude_install_statusline,
-    handle_claude_uninstall, handle_codex_install, handle_codex_uninstall, handle_config_create,
-    handle_config_show, handle_config_update, handle_hints_clear, handle_hints_get,
-    handle_logs_list, handle_logs_profile, handle_opencode_install, handle_opencode_uninstall,
-    handle_state_clear, handle_state_clear_all, handle_state_get, handle_state_set,
-    handle_state_show, handle_vars_clear, handle_vars_get, handle_vars_list, handle_vars_set,
-    list_approvals,
+    add_approvals, clear_approvals, handle_activity_clear, handle_activity_end,
+    handle_activity_history, handle_activity_list, handle_activity_set, handle_alias_dry_run,
+    handle_alias_show, handle_cache_clear, handle_cache_get, handle_claude_install,
+    handle_claude_install_statusline, handle_claude_uninstall, handle_codex_install,
+    handle_codex_uninstall, handle_config_create, handle_config_schema, handle_config_show,
+    handle_config_update, handle_config_validate, handle_hints_clear, handle_hints_get,
+    handle_logs_export, handle_logs_history, handle_logs_list, handle_logs_profile,
+    handle_opencode_install, handle_opencode_uninstall, handle_state_clear, handle_state_clear_all,
+    handle_state_get, handle_state_set, handle_state_show, handle_store_gc, handle_store_get,
+    handle_vars_clear, handle_vars_get, handle_vars_list, handle_vars_set, list_approvals,
 };
 pub(crate) use configure_shell::{
     handle_configure_shell, handle_show_theme, handle_unconfigure_shell,
@@ -50,7 +57,7 @@ pub(crate) use configure_shell::{
 pub(crate) use custom::handle_custom_command;
 pub(crate) use eval::step_eval;
 pub(crate) use for_each::step_for_each;
-pub(crate) use hook_commands::{HookCliArgs, handle_hook_show, run_hook};
+pub(crate) use hook_commands::{HookCliArgs, handle_hook_show, hook_show_records, run_hook};
 pub(crate) use init::{handle_completions, handle_init};
 pub(crate) use list::handle_list;
 pub(crate) use merge::{MergeFlagOverrides, MergeOptions, handle_merge};
@@ -61,7 +68,7 @@ pub(crate) use run_pipeline::run_pipeline;
 pub(crate) use step::{
     PreApprovedGuidance, PromoteResult, RebaseResult, SquashResult, handle_promote, handle_rebase,
     handle_squash, step_commit, step_copy_ignored, step_diff, step_dry_run_squash, step_prune,
-    step_relocate, step_show_squash_prompt, step_tether,
+    step_relocate, step_resolve, step_review, step_show_squash_prompt, step_tether,
 };
 pub(crate) use worktree::{
     handle_switch_command, is_worktree_at_expected_path, worktree_display_name,
@@ -69,6 +76,7 @@ pub(crate) use worktree::{
 
 // Re-export Shell from the canonical location
 pub(crate) use worktrunk::shell::Shell;
+pub(crate) use worktrunk::styling::did_you_mean;
 
 use color_print::cformat;
 use worktrunk::styling::{eprintln, format_with_gutter};
@@ -93,29 +101,6 @@ pub(crate) fn format_command_label(command_type: &str, name: Option<&str>) -> St
     }
 }
 
-/// Return candidates similar to `query`, sorted by descending Jaro–Winkler
-/// similarity, filtered by `score > 0.7`, and deduplicated while preserving
-/// order. The 0.7 threshold matches clap's internal `did_you_mean` so
-/// wt-synthesized "unrecognized subcommand" tips read identically to clap's
-/// native output — keep them aligned if clap ever changes it.
-pub(crate) fn did_you_mean(
-    query: &str,
-    candidates: impl IntoIterator<Item = String>,
-) -> Vec<String> {
-    let mut scored: Vec<(f64, String)> = candidates
-        .into_iter()
-        .map(|candidate| (strsim::jaro_winkler(query, &candidate), candidate))
-        .filter(|(score, _)| *score > 0.7)
-        .collect();
-    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
-    let mut seen = std::collections::HashSet::new();
-    scored
-        .into_iter()
-        .filter(|(_, n)| seen.insert(n.clone()))
-        .map(|(_, n)| n)
-        .collect()
-}
-
 /// Return visible subcommand names of `parent` plus `alias_names`, filtered to
 /// those similar to `name`. Hidden subcommands (e.g., deprecated aliases) and
 /// clap's implicit `help` are excluded. Shared by the top-level (`wt <typo>`)
@@ -0,0 +1,351 @@
+//! Custom picker actions (`[switch.picker.keys.actions]`).
+//!
+//! A custom action binds a key to a command template. Pressing it expands the
+//! template against the selected row — the same variables a hook sees, with
+//! `branch` and `worktree_path` taken from the row — and runs it on a
+//! background thread in the row's worktree (the repository root for a row
+//! without one). skim owns the terminal, so the command gets no TTY: its start
+//! and outcome flash in the header, and a failure's output is stashed for
+//! stderr on exit.
+//!
+//! Once the command finishes, the row is reconciled with what it did, landing
+//! in the same end states `alt-x` produces ([`reconcile_row`]): a worktree the
+//! command removed morphs to its `/ branch` row — or drops, when the branch
+//! went too — and a deleted branch's row drops. Anything else (a new
+//! worktree, a fresh commit) shows on the next `alt-r` refresh.
+//!
+//! With rows marked ([`bulk`](super::bulk)), the action runs in each marked
+//! row in turn through [`run_on_rows`], which the bulk `alt-m` merge shares.
+
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use anyhow::Context;
+use color_print::cformat;
+use skim::prelude::*;
+use skim::tui::event::ActionCallback;
+use worktrunk::config::VarScope;
+use worktrunk::git::{ErrorExt, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::{Cmd, ShellConfig};
+use worktrunk::styling::{
+    error_message, format_with_gutter, progress_message, success_message, warning_message,
+};
+
+use super::keys::CustomAction;
+use super::{
+    AltXRemover, RemoveTarget, parse_removal_target, resolve_shortcut_branch, resync_pool_action,
+    run_preview_when_settled, spawn_removal,
+};
+use crate::commands::command_executor::{
+    CommandContext, build_hook_context, expand_shell_template,
+};
+
+/// Install each custom action as a native binding. The callback resolves its
+/// targets — the marked rows, else the selected one ([`super::bulk::targets`])
+/// — and hands them to [`run_on_rows`]; nothing changes on skim's event loop
+/// until the background command reports back.
+///
+/// Each callback owns a clone of `rows` — the [`AltXRemover`]'s shared row
+/// state, all `Arc`s — since a custom action reconciles rows the same way an
+/// `alt-x` removal does.
+pub(super) fn install_custom_action_keybindings(
+    keymap: &mut skim::binds::KeyMap,
+    rows: &AltXRemover,
+    actions: Vec<CustomAction>,
+) {
+    use skim::binds::parse_key;
+
+    for action in actions {
+        let Ok(key) = parse_key(&action.key) else {
+            continue;
+        };
+        let rows = rows.clone();
+        keymap.insert(
+            key,
+            vec![Action::Custom(ActionCallback::new_sync(move |app| {
+                let targets = super::bulk::targets(&rows, app);
+                if targets.is_empty() {
+                    return Ok(Vec::new());
+                }
+                let key = action.key.clone();
+                let command = action.command.clone();
+                run_on_rows(
+                    &rows,
+                    format!("picker-action-{key}"),
+                    OpWording {
+                        running: cformat!("Running <bold>{key}</> for"),
+                        done: cformat!("Ran <bold>{key}</> for"),
+                        failed: cformat!("<bold>{key}</> failed for"),
+                    },
+                    targets,
+                    move |repo, target| execute(repo, &command, target),
+                );
+                // Marks were consumed; repaint so their highlight clears.
+                Ok(vec![Event::Render])
+            }))],
+        );
+    }
+}
+
+/// A row an action runs against, decoded from its `output()` token.
+pub(super) struct ActionTarget {
+    pub selected_output: String,
+    pub branch: Option<String>,
+    pub worktree_path: Option<PathBuf>,
+    /// How the header names the row: its branch, else the worktree's path.
+    pub label: String,
+}
+
+impl ActionTarget {
+    /// Decode `selected_output`; `None` for an empty token.
+    pub(super) fn decode(rows: &AltXRemover, selected_output: String) -> Option<Self> {
+        let target = parse_removal_target(&selected_output)?;
+        let (branch, worktree_path) = match target {
+            RemoveTarget::WorktreePath(path) => (
+                resolve_shortcut_branch(&rows.shortcut_table, &selected_output),
+                Some(path),
+            ),
+            // A `--prs` row's token is `pr:N`; its head branch is in the table.
+            RemoveTarget::BranchOnly(branch) => (
+                resolve_shortcut_branch(&rows.shortcut_table, &selected_output).or(Some(branch)),
+                None,
+            ),
+        };
+        let label = match (&branch, &worktree_path) {
+            (Some(branch), _) => branch.clone(),
+            (None, Some(path)) => format_path_for_display(path),
+            (None, None) => selected_output.clone(),
+        };
+        Some(Self {
+            selected_output,
+            branch,
+            worktree_path,
+            label,
+        })
+    }
+}
+
+/// How the header words an operation, each phrase preceding the row label —
+/// `Running alt-t for` / `Ran alt-t for` / `alt-t failed for`, or `Merging` /
+/// `Merged` / `Merge failed for`. Pre-rendered with [`cformat!`].
+pub(super) struct OpWording {
+    pub running: String,
+    pub done: String,
+    pub failed: String,
+}
+
+/// Run `op` against each of `targets` in turn and reconcile each row with what
+/// it did.
+///
+/// Runs on skim's event loop, so only the header flash happens here; the
+/// commands and the row reconciliation run on one background thread, one row
+/// after another — an action is arbitrary (often a merge into the same target
+/// branch), so running several at once could race. The header counts through
+/// the rows and ends on a summary when there's more than one. That thread goes
+/// through [`spawn_removal`], exempt from background-command cancellation: the
+/// action is work the user asked for (often a merge or removal), so picking a
+/// row right after must not kill it halfway.
+pub(super) fn run_on_rows<F>(
+    rows: &AltXRemover,
+    name: String,
+    wording: OpWording,
+    targets: Vec<ActionTarget>,
+    op: F,
+) where
+    F: Fn(&Repository, &ActionTarget) -> anyhow::Result<std::process::Output> + Send + 'static,
+{
+    let total = targets.len();
+    let Some(first) = targets.first() else {
+        return;
+    };
+    rows.flash_header(
+        progress_message(progress_line(&wording, &first.label, 1, total)).to_string(),
+    );
+
+    let rows = rows.clone();
+    spawn_removal(name, move || {
+        let mut failures = 0;
+        for (i, target) in targets.iter().enumerate() {
+            if i > 0 {
+                rows.flash_header(
+                    progress_message(progress_line(&wording, &target.label, i + 1, total))
+                        .to_string(),
+                );
+            }
+            // A fresh `Repository` per row: an earlier row's command may have
+            // removed worktrees or branches the startup inventory still lists.
+            let repo = match Repository::at(rows.repo.discovery_path()) {
+                Ok(repo) => repo,
+                Err(e) => {
+                    tracing::warn!(error = %e, "picker: could not open the repository: {e:#}");
+                    return;
+                }
+            };
+            // Whether the row's branch is a local branch, before the command runs —
+            // a remote or `--prs` row's branch isn't, and must not read as deleted.
+            let local_branch = target
+                .branch
+                .as_deref()
+                .filter(|b| repo.branch(b).exists_locally().unwrap_or(false));
+
+            let outcome = op(&repo, target);
+            if !report(&rows, &wording, &target.label, outcome) {
+                failures += 1;
+            }
+            reconcile_row(
+                &rows,
+                &repo,
+                &target.selected_output,
+                target.worktree_path.as_deref(),
+                local_branch,
+            );
+        }
+        if total > 1 {
+            let summary = if failures == 0 {
+                success_message(cformat!("{} <bold>{total}</> rows", wording.done)).to_string()
+            } else {
+                warning_message(cformat!(
+                    "{} <bold>{failures}</> of {total} rows",
+                    wording.failed
+                ))
+                .to_string()
+            };
+            rows.flash_header(summary);
+        }
+    });
+}
+
+/// `Running alt-t for feature...`, with a `(2/5)` count when several rows run.
+fn progress_line(wording: &OpWording, label: &str, position: usize, total: usize) -> String {
+    let count = if total > 1 {
+        format!(" ({position}/{total})")
+    } else {
+        String::new()
+    };
+    cformat!("{} <bold>{label}</>{count}...", wording.running)
+}
+
+/// Expand `command` for the row and run it through the platform shell,
+/// capturing its output. A template error is an `Err`; a command that ran is
+/// `Ok` whatever its exit status.
+fn execute(
+    repo: &Repository,
+    command: &str,
+    target: &ActionTarget,
+) -> anyhow::Result<std::process::Output> {
+    let repo_root = repo.repo_path()?.to_path_buf();
+    let dir: PathBuf = target
+        .worktree_path
+        .as_deref()
+        .filter(|path| path.is_dir())
+        .map_or(repo_root, Path::to_path_buf);
+    let config = repo.user_config();
+    let ctx = CommandContext::new(repo, config, target.branch.as_deref(), &dir, false);
+    let context = build_hook_context(&ctx, &[], VarScope::All)?;
+    let expanded = expand_shell_template(command, &context, repo, "picker action")?;
+
+    let shell = ShellConfig::get()?;
+    let args = shell
+        .args
+        .iter()
+        .map(String::as_str)
+        .chain([expanded.as_str()]);
+    Cmd::new(shell.executable.to_string_lossy())
+        .args(args)
+        .current_dir(dir)
+        .external("picker.action")
+        .stdin(Stdio::null())
+        .run()
+        .with_context(|| format!("Failed to run {expanded}"))
+}
+
+/// Flash the operation's outcome in the header, returning whether it
+/// succeeded. A failure is also stashed, with the command's output, for stderr
+/// on exit — the header has room for a headline only.
+fn report(
+    rows: &AltXRemover,
+    wording: &OpWording,
+    label: &str,
+    outcome: anyhow::Result<std::process::Output>,
+) -> bool {
+    let failed = &wording.failed;
+    let (headline, detail) = match outcome {
+        Ok(output) if output.status.success() => {
+            rows.flash_header(
+                success_message(cformat!("{} <bold>{label}</>", wording.done)).to_string(),
+            );
+            return true;
+        }
+        Ok(output) => {
+            let status = output
+                .status
+                .code()
+                .map_or_else(|| "killed by a signal".to_string(), |c| format!("exit {c}"));
+            let mut text = String::from_utf8_lossy(&output.stderr).into_owned();
+            text.push_str(&String::from_utf8_lossy(&output.stdout));
+            let headline =
+                warning_message(cformat!("{failed} <bold>{label}</> ({status})")).to_string();
+            (headline, text.trim().to_string())
+        }
+        Err(e) => {
+            let headline = error_message(cformat!("{failed} <bold>{label}</>")).to_string();
+            let detail = e.render_diagnostic().unwrap_or_else(|| format!("{e:#}"));
+            (headline, detail)
+        }
+    };
+    rows.flash_header(headline.clone());
+    let mut stashed = rows.stashed_warnings.lock().unwrap();
+    stashed.push(headline);
+    if !detail.is_empty() {
+        stashed.push(format_with_gutter(&detail, None));
+    }
+    false
+}
+
+/// Bring the row in line with what the command did, off the event loop.
+///
+/// Observes the targets directly, as
+/// [`removal_target_still_present`](super::removal_target_still_present) does
+/// for `alt-x`: a worktree row
+/// whose directory is gone morphs to `/ branch` if its branch survives
+/// ([`AltXRemover::morph_in_place`]) and drops otherwise; a branch row whose
+/// local branch is gone drops. The drop queues the same
+/// [`resync_pool_action`] + settled preview the `alt-x` drop returns; a morph
+/// just repaints.
+fn reconcile_row(
+    rows: &AltXRemover,
+    repo: &Repository,
+    selected_output: &str,
+    worktree_path: Option<&Path>,
+    local_branch: Option<&str>,
+) {
+    let branch_survives =
+        local_branch.is_some_and(|branch| repo.branch(branch).exists_locally().unwrap_or(false));
+    let drop = match worktree_path {
+        Some(path) if path.exists() => return,
+        Some(_) => match local_branch.filter(|_| branch_survives) {
+            Some(branch) if rows.morph_in_place(selected_output, branch).is_some() => {
+                if let Some(tx) = rows.render_tx.get() {
+                    let _ = tx.try_send(Event::Render);
+                    let _ = tx.try_send(Event::RunPreview);
+                }
+                return;
+            }
+            _ => true,
+        },
+        None => local_branch.is_some() && !branch_survives,
+    };
+    if drop && rows.drop_row(selected_output).is_some() {
+        let Some(tx) = rows.render_tx.get() else {
+            return;
+        };
+        let _ = tx.try_send(Event::Action(resync_pool_action(Arc::clone(&rows.items))));
+        let _ = tx.try_send(Event::Action(run_preview_when_settled(
+            Arc::new(AtomicUsize::new(0)),
+            Arc::new(AtomicUsize::new(0)),
+        )));
+    }
+}
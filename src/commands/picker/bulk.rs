@@ -0,0 +1,289 @@
+//! Multi-select: marking rows and acting on all of them at once.
+//!
+//! The mark key (`ctrl-space`) toggles the selected row's mark and moves on.
+//! While any row is marked, `alt-x`, `alt-m`, and the custom actions act on
+//! every marked row instead of the selected one, consuming the marks:
+//!
+//! - **remove** stages each row exactly as a single `alt-x` does — drop,
+//!   morph, or keep, with the same restore when a removal fails — then runs
+//!   the git work concurrently on a worker pool ([`remove_marked`]) under the
+//!   locks `wt step prune` removes with ([`RemovalLocks`](super::RemovalLocks));
+//! - **merge** runs `wt merge` in each marked worktree, one at a time
+//!   ([`install_merge_keybinding`]) — each merge lands on the same target
+//!   branch, so they can't overlap;
+//! - a **custom action** runs its command in each marked row, one at a time.
+//!
+//! The header counts through the rows and ends on a summary; per-row failures
+//! flash as they happen and drain to stderr on exit.
+//!
+//! Marks are kept here, by `output()` token, rather than in skim's own
+//! multi-select: skim's selection isn't readable from a keybinding callback,
+//! and it's keyed by pool index, which the pool rebuild after every `alt-x`
+//! ([`resync_pool`](super::resync_pool)) reassigns. Like the preview tab
+//! selection ([`PreviewStateData`](super::PreviewStateData)), they're
+//! per-process picker state; a marked row reads them when it renders.
+
+use std::process::Stdio;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::Context;
+use color_print::cformat;
+use crossbeam_channel as chan;
+use skim::prelude::*;
+use skim::tui::event::ActionCallback;
+use worktrunk::git::Repository;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{info_message, progress_message, success_message, warning_message};
+
+use super::actions::{ActionTarget, OpWording, run_on_rows};
+use super::{AltXRemover, RemovalEffect, spawn_removal};
+
+/// Marked rows' `output()` tokens, in the order they were marked.
+static MARKED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+pub(super) struct MarkedRows;
+
+impl MarkedRows {
+    /// Mark `token`, or unmark it if it's marked. Returns how many rows are
+    /// marked afterward.
+    pub(super) fn toggle(token: &str) -> usize {
+        let mut marked = MARKED.lock().unwrap();
+        if let Some(pos) = marked.iter().position(|t| t == token) {
+            marked.remove(pos);
+        } else {
+            marked.push(token.to_string());
+        }
+        marked.len()
+    }
+
+    pub(super) fn contains(token: &str) -> bool {
+        MARKED.lock().unwrap().iter().any(|t| t == token)
+    }
+
+    /// Take every mark, leaving none.
+    pub(super) fn take() -> Vec<String> {
+        std::mem::take(&mut *MARKED.lock().unwrap())
+    }
+
+    pub(super) fn reset() {
+        MARKED.lock().unwrap().clear();
+    }
+}
+
+/// Bind `key` to mark or unmark the selected row, then move down a row — so
+/// holding it marks a run of rows. The header shows the running count.
+pub(super) fn install_mark_keybinding(
+    keymap: &mut skim::binds::KeyMap,
+    rows: &AltXRemover,
+    key: &str,
+) {
+    use skim::binds::parse_key;
+    let Ok(key) = parse_key(key) else {
+        return;
+    };
+    let rows = rows.clone();
+    let cb = Action::Custom(ActionCallback::new_sync(move |app| {
+        let Some(selected) = app.item_list.selected() else {
+            return Ok(Vec::new());
+        };
+        let count = MarkedRows::toggle(&selected.item.output());
+        let noun = if count == 1 { "row" } else { "rows" };
+        rows.flash_header(info_message(cformat!("<bold>{count}</> {noun} marked")).to_string());
+        Ok(Vec::new())
+    }));
+    keymap.insert(key, vec![cb, Action::Down(1)]);
+}
+
+/// The rows an action applies to: the marked rows, consuming the marks, or
+/// else the selected row.
+pub(super) fn targets(rows: &AltXRemover, app: &skim::tui::App) -> Vec<ActionTarget> {
+    let marked = MarkedRows::take();
+    let tokens = if marked.is_empty() {
+        app.item_list
+            .selected()
+            .map(|selected| vec![selected.item.output().into_owned()])
+            .unwrap_or_default()
+    } else {
+        marked
+    };
+    tokens
+        .into_iter()
+        .filter_map(|token| ActionTarget::decode(rows, token))
+        .collect()
+}
+
+/// Remove every row in `tokens`: stage each exactly as a single `alt-x` does
+/// ([`AltXRemover::apply`] — the row drops, morphs, or stays on the event
+/// loop), then run the staged git work on a worker pool.
+///
+/// Staging opens the remover's [`batch`](AltXRemover::batch), so each row's
+/// background work queues instead of spawning; the pool is sized like prune's
+/// removal workers (rayon's thread count), and every removal takes prune's
+/// locks ([`AltXRemover::do_removal`]), so worktree teardowns serialize on the
+/// registry while the rest overlap. A removal that fails restores its row the
+/// way a single `alt-x` does.
+///
+/// Returns the strongest effect across the rows — a drop needs the pool
+/// rebuilt, a morph only a repaint — for the keybinding callback.
+pub(super) fn remove_marked(remover: &AltXRemover, tokens: Vec<String>) -> RemovalEffect {
+    *remover.batch.lock().unwrap() = Some(Vec::new());
+    let mut effect = RemovalEffect::Kept;
+    for token in tokens {
+        match remover.apply(token) {
+            RemovalEffect::Dropped => effect = RemovalEffect::Dropped,
+            RemovalEffect::Morphed if matches!(effect, RemovalEffect::Kept) => {
+                effect = RemovalEffect::Morphed;
+            }
+            _ => {}
+        }
+    }
+    let jobs = remover.batch.lock().unwrap().take().unwrap_or_default();
+    let total = jobs.len();
+    if total == 0 {
+        return effect;
+    }
+
+    remover
+        .flash_header(progress_message(cformat!("Removing <bold>{total}</> rows...")).to_string());
+    let rows = remover.clone();
+    spawn_removal("picker-remove-marked".to_string(), move || {
+        let (job_tx, job_rx) = chan::unbounded();
+        for job in jobs {
+            let _ = job_tx.send(job);
+        }
+        drop(job_tx);
+
+        let done = AtomicUsize::new(0);
+        let kept = AtomicUsize::new(0);
+        let workers = rayon::current_num_threads().min(total);
+        std::thread::scope(|s| {
+            for _ in 0..workers {
+                let job_rx = job_rx.clone();
+                let (done, kept, rows) = (&done, &kept, &rows);
+                s.spawn(move || {
+                    // The exemption is per-thread: each worker needs its own.
+                    worktrunk::shell_exec::uninterruptible(|| {
+                        for job in job_rx {
+                            if !job() {
+                                kept.fetch_add(1, Ordering::Relaxed);
+                            }
+                            let finished = done.fetch_add(1, Ordering::Relaxed) + 1;
+                            if finished < total {
+                                rows.flash_header(
+                                    progress_message(cformat!(
+                                        "Removing <bold>{total}</> rows ({finished}/{total})..."
+                                    ))
+                                    .to_string(),
+                                );
+                            }
+                        }
+                    });
+                });
+            }
+        });
+
+        // Each kept row already flashed and stashed its own reason.
+        let kept = kept.into_inner();
+        let summary = if kept == 0 {
+            success_message(cformat!("Removed <bold>{total}</> rows")).to_string()
+        } else {
+            warning_message(cformat!(
+                "Removed <bold>{}</> of {total} rows; kept {kept}",
+                total - kept
+            ))
+            .to_string()
+        };
+        rows.flash_header(summary);
+    });
+    effect
+}
+
+/// Bind `key` to merge the marked worktrees — or the selected one — by running
+/// `wt merge` in each, one after another ([`run_on_rows`]). Each row is then
+/// reconciled like a custom action's: a worktree the merge removed leaves the
+/// list with its branch, or morphs to `/ branch` if the branch was kept.
+pub(super) fn install_merge_keybinding(
+    keymap: &mut skim::binds::KeyMap,
+    rows: &AltXRemover,
+    key: &str,
+) {
+    use skim::binds::parse_key;
+    let Ok(key) = parse_key(key) else {
+        return;
+    };
+    let rows = rows.clone();
+    let cb = Action::Custom(ActionCallback::new_sync(move |app| {
+        let targets = targets(&rows, app);
+        if targets.is_empty() {
+            return Ok(Vec::new());
+        }
+        run_on_rows(
+            &rows,
+            "picker-merge".to_string(),
+            OpWording {
+                running: "Merging".to_string(),
+                done: "Merged".to_string(),
+                failed: "Merge failed for".to_string(),
+            },
+            targets,
+            execute_merge,
+        );
+        // Marks were consumed; repaint so their highlight clears.
+        Ok(vec![Event::Render])
+    }));
+    keymap.insert(key, vec![cb]);
+}
+
+/// Run `wt merge` in the row's worktree, capturing its output.
+///
+/// The merge runs without a terminal, so it can't prompt: a merge whose
+/// project hooks aren't yet approved fails with `wt merge`'s own explanation,
+/// which the failure report stashes. The current worktree is declined, as
+/// `alt-x` declines it — merging it removes the directory the shell is in.
+fn execute_merge(repo: &Repository, target: &ActionTarget) -> anyhow::Result<std::process::Output> {
+    let Some(path) = target.worktree_path.as_deref().filter(|p| p.is_dir()) else {
+        anyhow::bail!("{} has no worktree to merge", target.label);
+    };
+    if repo.current_worktree().root()? == path {
+        anyhow::bail!("Can't merge the current worktree from the picker");
+    }
+    let wt = std::env::current_exe().context("Failed to locate the wt executable")?;
+    Cmd::new(wt.to_string_lossy())
+        .arg("merge")
+        .current_dir(path)
+        .external("picker.merge")
+        .stdin(Stdio::null())
+        .run()
+        .with_context(|| format!("Failed to run wt merge in {}", target.label))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marked_rows_toggle_and_take() {
+        // Tokens unique to this test: the marks are process-wide.
+        let a = "worktree-path:/marked-rows-test/a";
+        let b = "marked-rows-test-b";
+        assert!(!MarkedRows::contains(a));
+
+        MarkedRows::toggle(a);
+        MarkedRows::toggle(b);
+        assert!(MarkedRows::contains(a) && MarkedRows::contains(b));
+
+        // Toggling again unmarks.
+        MarkedRows::toggle(a);
+        assert!(!MarkedRows::contains(a));
+        MarkedRows::toggle(a);
+
+        // Taken in marking order, leaving nothing marked.
+        let taken: Vec<_> = MarkedRows::take()
+            .into_iter()
+            .filter(|t| t.contains("marked-rows-test"))
+            .collect();
+        assert_eq!(taken, vec![b.to_string(), a.to_string()]);
+        assert!(!MarkedRows::contains(a) && !MarkedRows::contains(b));
+    }
+}
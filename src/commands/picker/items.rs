@@ -21,6 +21,7 @@ use worktrunk::styling::{HINT_SYMBOL, INFO_SYMBOL, WARNING_SYMBOL, visual_width}
 
 use super::super::list::ci_status::{PrRef, PrStatus, ReviewState};
 use super::super::list::model::ListItem;
+use super::bulk::MarkedRows;
 use super::log_formatter::{
     FIELD_DELIM, batch_fetch_stats, format_log_output, process_log_with_dimming, strip_hash_markers,
 };
@@ -108,6 +109,31 @@ fn anchor_faint_under_selection(
     line
 }
 
+/// Show a marked row's mark ([`MarkedRows`]): its gutter glyph — the first
+/// visible character — renders reversed and bold. Nothing shifts, so the
+/// columns stay aligned with the unmarked rows, and the glyph still says what
+/// kind of row it is.
+fn highlight_marked(mut line: Line<'static>) -> Line<'static> {
+    let Some(idx) = line.spans.iter().position(|span| {
+        span.content
+            .chars()
+            .next()
+            .is_some_and(|c| !c.is_whitespace())
+    }) else {
+        return line;
+    };
+    let span = line.spans.remove(idx);
+    let mut chars = span.content.chars();
+    let glyph = chars.next().map(String::from).unwrap_or_default();
+    let rest: String = chars.collect();
+    let marked = span.style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+    line.spans.insert(idx, Span::styled(glyph, marked));
+    if !rest.is_empty() {
+        line.spans.insert(idx + 1, Span::styled(rest, span.style));
+    }
+    line
+}
+
 /// Cache key for pre-computed previews: `(row-key, mode)`, where the row-key is
 /// the row's [`PickerRow::preview_key`] — a branch for a worktree row, the
 /// `pr:N` / `mr:N` token for a listed `--prs` row.
@@ -520,7 +546,12 @@ impl SkimItem for PickerRow {
         // Clone-under-lock so the parser's input outlives the guard;
         // `ansi_to_line` returns an owned `Line<'static>`.
         let snapshot = self.rendered.lock().unwrap().clone();
-        anchor_faint_under_selection(ansi_to_line(&snapshot), &context)
+        let line = anchor_faint_under_selection(ansi_to_line(&snapshot), &context);
+        if MarkedRows::contains(&self.output()) {
+            highlight_marked(line)
+        } else {
+            line
+        }
     }
 
     fn output(&self) -> Cow<'_, str> {
@@ -753,6 +784,7 @@ struct LocalTabs {
     branch_diff: bool,
     upstream: bool,
     summary: bool,
+    review: bool,
 }
 
 impl LocalTabs {
@@ -767,7 +799,9 @@ impl LocalTabs {
     /// (`crate::summary::compute_combined_diff` = commits ahead of the comparison
     /// base + tracked working-tree changes), so it uses `branch_diff` and the
     /// tracked-only `summary_working_tree` signal — dimming in concert with its
-    /// actual input rather than the untracked-inclusive working pane.
+    /// actual input rather than the untracked-inclusive working pane. `review`
+    /// reads the review cache for that same combined diff, so it follows the same
+    /// signals without the summaries flag (a cached review outlives the config).
     fn worktree(content: LocalContent, has_upstream: bool, summaries_enabled: bool) -> Self {
         let working_tree = content.working_tree.unwrap_or(true);
         let summary_working_tree = content.summary_working_tree.unwrap_or(true);
@@ -778,6 +812,7 @@ impl LocalTabs {
             branch_diff,
             upstream: has_upstream && content.upstream_diverged.unwrap_or(true),
             summary: summaries_enabled && (summary_working_tree || branch_diff),
+            review: summary_working_tree || branch_diff,
         }
     }
 }
@@ -801,7 +836,7 @@ impl LocalTabs {
 ///   non-empty combined diff — its pane's content source — so it uses the
 ///   `branch_diff` plus tracked-working signals and dims alongside the "no
 ///   changes to summarize" pane, not only when summaries are disabled. A `--prs` row has
-///   no local checkout, so all five local tabs are empty.
+///   no local checkout, so all six local tabs are empty.
 /// - The PR-backed tabs: `pr` and `comments` are available together, gated by
 ///   `has_pr`. On a worktree row that's the live status slot (primed from the CI
 ///   cache, then refreshed by the `CiStatus` task — see
@@ -822,6 +857,7 @@ pub(super) struct TabAvailability {
     branch_diff: bool,
     upstream: bool,
     summary: bool,
+    review: bool,
     pr: bool,
     comments: bool,
 }
@@ -840,6 +876,7 @@ impl TabAvailability {
             branch_diff: local.branch_diff,
             upstream: local.upstream,
             summary: local.summary,
+            review: local.review,
             pr: has_pr,
             comments: has_pr,
         }
@@ -880,6 +917,7 @@ impl TabAvailability {
             PreviewMode::Log => self.log,
             PreviewMode::UpstreamDiff => self.upstream,
             PreviewMode::Summary => self.summary,
+            PreviewMode::Review => self.review,
             PreviewMode::Pr => self.pr,
             PreviewMode::Comments => self.comments,
         }
@@ -889,7 +927,7 @@ impl TabAvailability {
     /// available, so the bounded walk always finds a tab.
     pub(super) fn cycle_from(self, current: PreviewMode, forward: bool) -> PreviewMode {
         let mut candidate = current;
-        for _ in 0..8 {
+        for _ in 0..9 {
             candidate = if forward {
                 candidate.next()
             } else {
@@ -930,7 +968,7 @@ struct Tab {
 /// **Width adaptation.** skim renders previews with wrapping off (its default),
 /// so a tab bar wider than `width` would truncate on the right — and the `pr` /
 /// `comments` tabs, exactly the ones with content on a `--prs` row, sit at that
-/// end. When the eight full-form tabs don't fit, the bar falls back to a compact
+/// end. When the nine full-form tabs don't fit, the bar falls back to a compact
 /// form (`1 2: log 3 …`): every accelerator digit stays visible, but only the
 /// active tab keeps its label. The two style signals survive — empty digits dim,
 /// the active digit+label bolds — so navigation works at any width. `width` is
@@ -981,12 +1019,18 @@ pub(super) fn render_preview_tabs(
         },
         Tab {
             number: 7,
+            label: "review",
+            is_active: mode == PreviewMode::Review,
+            has_content: avail.review,
+        },
+        Tab {
+            number: 8,
             label: "pr",
             is_active: mode == PreviewMode::Pr,
             has_content: avail.pr,
         },
         Tab {
-            number: 8,
+            number: 9,
             label: "comments",
             is_active: mode == PreviewMode::Comments,
             has_content: avail.comments,
@@ -1007,7 +1051,7 @@ pub(super) fn render_preview_tabs(
     // into the query); Tab/shift-tab cycle the same tabs.
     //
     // Order: primary action (Enter), preview navigation (ctrl-u/d scroll, then
-    // the Tab/alt-1…8 accelerators), then row actions, with Esc last.
+    // the Tab/alt-1…9 accelerators), then row actions, with Esc last.
     //
     // The controls line is intentionally NOT width-managed: skim clips it on the
     // right on a narrow pane, but it's only a reminder. Note the trade-off of
@@ -1016,7 +1060,7 @@ pub(super) fn render_preview_tabs(
     // managed) tab bar above — so on a narrow pane the only on-screen reminder of
     // them can clip away.
     let controls = cformat!(
-        "<dim,cyan>Enter: switch | ctrl-u/d: scroll | Tab/alt-1…8: preview | alt-c: create | alt-x: remove | alt-y: copy | alt-o: open | alt-r: refresh | alt-p: toggle | Esc: cancel</>"
+        "<dim,cyan>Enter: switch | ctrl-u/d: scroll | Tab/alt-1…9: preview | alt-c: create | alt-x: remove | alt-y: copy | alt-o: open | alt-r: refresh | alt-p: toggle | Esc: cancel</>"
     );
 
     // Each tab/segment already ends with a full reset (so styling never bleeds
@@ -1132,7 +1176,7 @@ impl PickerRow {
             PreviewMode::Pr => self.render_pr_pane_cached(width),
             PreviewMode::Comments => self.render_comments_pane(),
             // The local-checkout tabs (complete diff/working-tree/log/
-            // branch-diff/upstream/summary) compute locally for a local row; a `--prs` row has no
+            // branch-diff/upstream/summary/review) compute locally for a local row; a `--prs` row has no
             // checkout, so its `log` loads from the forge and the rest point at
             // the `pr` tab (see `render_listed_pr_mode`).
             _ => match &self.local {
@@ -1250,7 +1294,7 @@ impl PickerRow {
 
     /// Non-PR-tab content for a listed `--prs` row (no local checkout): the
     /// `log` tab loads commits in the background and reads from the cache; the
-    /// local-only tabs (complete diff, working-tree, branch-diff, upstream, summary) have no
+    /// local-only tabs (complete diff, working-tree, branch-diff, upstream, summary, review) have no
     /// PR equivalent, so they point the user at the `pr` tab.
     fn render_listed_pr_mode(&self, mode: PreviewMode) -> String {
         match mode {
@@ -1298,6 +1342,7 @@ impl PickerRow {
             PreviewMode::Log => ("Loading", "log"),
             PreviewMode::UpstreamDiff => ("Loading", "upstream diff"),
             PreviewMode::Summary => ("Generating", "summary"),
+            PreviewMode::Review => ("Loading", "review"),
             // `preview()` routes the PR-backed tabs around this path: `pr` renders
             // from the cached `pr_status` via `render_pr_pane`, and `comments`
             // through `render_comments_pane` (which uses the `--prs` rows' shared
@@ -1348,6 +1393,10 @@ impl PickerRow {
                 false,
             ),
             PreviewMode::Summary => (Self::loading_placeholder(PreviewMode::Summary), false),
+            PreviewMode::Review => (
+                super::review::compute_review_preview(repo, item, width),
+                false,
+            ),
             // PR and comments previews never precompute on worktree rows (no
             // git/LLM work) — the orchestrator never spawns these modes, and
             // `preview()` renders them directly (cached `pr_status` / the
@@ -1946,6 +1995,29 @@ mod tests {
         assert_eq!(out.spans[3].style.fg, None);
     }
 
+    #[test]
+    fn highlight_marked_reverses_only_the_gutter_glyph() {
+        use ratatui::style::Style;
+
+        let line = Line::from(vec![
+            Span::raw(" "),
+            Span::styled("+ feature", Style::default().fg(Color::Cyan)),
+            Span::raw("  ↑1"),
+        ]);
+        let out = highlight_marked(line);
+        let text: Vec<&str> = out.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, vec![" ", "+", " feature", "  ↑1"]);
+        let glyph = out.spans[1].style;
+        assert!(
+            glyph
+                .add_modifier
+                .contains(Modifier::REVERSED | Modifier::BOLD)
+        );
+        assert_eq!(glyph.fg, Some(Color::Cyan));
+        assert_eq!(out.spans[2].style, Style::default().fg(Color::Cyan));
+        assert_eq!(out.spans[3].style, Style::default());
+    }
+
     /// A minimal `LocalCheckout` for row construction in tests: a branch-only
     /// snapshot item, a demand channel with no worker behind it (requests
     /// recorded, never served), and defaulted local signals (no upstream, no
@@ -1986,7 +2058,7 @@ mod tests {
         }
     }
 
-    /// Width at which all eight full-form tabs fit, so the snapshots capture the
+    /// Width at which all nine full-form tabs fit, so the snapshots capture the
     /// full bar (the compact fallback has its own test).
     const WIDE: usize = 200;
 
@@ -2014,7 +2086,7 @@ mod tests {
         // (uncommitted changes, commits ahead, diverged from upstream) with
         // summaries enabled but no PR (tabs 1-6 available; tabs 7 pr and 8
         // comments dim). The active mode's label is bold, inactive available
-        // labels dim, and on the `pr` iteration tab 7 is active-but-empty —
+        // labels dim, and on the `pr` iteration tab 8 is active-but-empty —
         // exercising the rule that emptiness dims even the active tab. Verifies
         // labels and structure.
         let wt = TabAvailability::worktree(CONTENT_FULL, true, true, false);
@@ -2025,6 +2097,7 @@ mod tests {
             ("log", PreviewMode::Log),
             ("upstream_diff", PreviewMode::UpstreamDiff),
             ("summary", PreviewMode::Summary),
+            ("review", PreviewMode::Review),
             ("pr", PreviewMode::Pr),
         ] {
             assert_snapshot!(name, render_preview_tabs(mode, wt, WIDE));
@@ -2033,13 +2106,13 @@ mod tests {
         // Empty states. Three rows:
         // - `empty_upstream_and_summary`: diffs still loading (so tabs 1–3
         //   read as available), no upstream ref, summaries disabled, no PR — dims
-        //   tabs 5, 6, 7, and 8.
+        //   tabs 5, 6, 8, and 9 (review follows the diffs, not the summaries flag).
         // - `empty_all_local_diffs`: every diff *known* empty (clean working tree,
         //   no commits ahead, up to date with a present upstream) — dims tabs 1,
-        //   2, 3, 5, plus 6/7/8, leaving only `log`. This is the behavior the diff
+        //   2, 3, 5, plus 6–9, leaving only `log`. This is the behavior the diff
         //   tabs gained: a dimmed number once the diff is known empty.
         // - `pr_row`: a listed-PR row dims the complete/working/committed/
-        //   upstream/summary tabs but keeps log/pr/comments.
+        //   upstream/summary/review tabs but keeps log/pr/comments.
         assert_snapshot!(
             "empty_upstream_and_summary",
             render_preview_tabs(
@@ -2090,6 +2163,10 @@ mod tests {
         let changed_local = TabAvailability::worktree(CONTENT_FULL, true, true, false);
         assert_eq!(
             changed_local.cycle_from(PreviewMode::Summary, true),
+            PreviewMode::Review
+        );
+        assert_eq!(
+            changed_local.cycle_from(PreviewMode::Review, true),
             PreviewMode::UnifiedDiff,
             "cycling wraps past empty PR-backed tabs"
         );
@@ -2315,14 +2392,14 @@ mod tests {
         // A --prs row with the `pr` tab active, in a narrow pane.
         let compact = render_preview_tabs(PreviewMode::Pr, TabAvailability::listed_pr(), 40);
         let plain = compact.lines().next().unwrap().ansi_strip().to_string();
-        // Every digit 1-8 is present; only the active tab keeps its label.
-        for n in 1..=8 {
+        // Every digit 1-9 is present; only the active tab keeps its label.
+        for n in 1..=9 {
             assert!(
                 plain.contains(&n.to_string()),
                 "digit {n} present: {plain:?}"
             );
         }
-        assert!(plain.contains("7: pr"), "active tab labeled: {plain:?}");
+        assert!(plain.contains("8: pr"), "active tab labeled: {plain:?}");
         assert!(
             !plain.contains("comments"),
             "inactive label dropped: {plain:?}"
@@ -2334,7 +2411,7 @@ mod tests {
         // The same row in a wide pane uses the full bar (labels for all tabs).
         let full = render_preview_tabs(PreviewMode::Pr, TabAvailability::listed_pr(), WIDE);
         assert!(
-            full.contains("8: ") && full.contains("comments"),
+            full.contains("9: ") && full.contains("comments"),
             "wide pane keeps full labels"
         );
 
@@ -2818,14 +2895,14 @@ mod tests {
 
         // In Pr mode, `render_preview` assembles the tab bar plus the worktree PR
         // pane — the dispatch arm `SkimItem::preview` reaches once the picker-state
-        // state selects mode 7. The pane shows the title and the markdown body.
+        // state selects mode 8. The pane shows the title and the markdown body.
         let pr_pane = row.render_preview(PreviewMode::Pr, WIDE, 24);
         // Strip ANSI before checking the tab labels: the active `pr` tab is bold,
-        // so `7: pr` is split by an SGR escape in the raw string (the bar's own
+        // so `8: pr` is split by an SGR escape in the raw string (the bar's own
         // test, `test_render_preview_tabs`, snapshots the styled form).
         let bar = pr_pane.ansi_strip().to_string();
-        assert!(bar.contains("7: pr"), "pr tab: {bar:?}");
-        assert!(bar.contains("8: comments"), "comments tab: {bar:?}");
+        assert!(bar.contains("8: pr"), "pr tab: {bar:?}");
+        assert!(bar.contains("9: comments"), "comments tab: {bar:?}");
         assert!(
             pr_pane.contains("Fix the flaky retry"),
             "title: {pr_pane:?}"
@@ -3592,13 +3669,13 @@ mod tests {
         // This prevents bold/dim from bleeding into the " | " dividers
         let full_reset = "\x1b[0m";
 
-        // Count resets - should have one after each of the 8 tabs
-        assert_eq!(first_line.matches(full_reset).count(), 8);
+        // Count resets - should have one after each of the 9 tabs
+        assert_eq!(first_line.matches(full_reset).count(), 9);
 
         // The sequence should be: style + text + [22m + [0m + divider
         // Check that dividers come after full resets
         let parts: Vec<&str> = first_line.split(" | ").collect();
-        assert_eq!(parts.len(), 8);
+        assert_eq!(parts.len(), 9);
         assert!(parts.iter().all(|part| part.ends_with(full_reset)));
 
         // Controls line should end with full reset to ensure clean state for preview content
@@ -0,0 +1,374 @@
+//! The picker's key bindings: the built-in actions on their default keys, as
+//! rebound by `[switch.picker.keys]`, plus the custom actions configured under
+//! `[switch.picker.keys.actions]`.
+//!
+//! Resolved once, before skim starts. A key skim can't parse, or one that
+//! collides with a key already taken, is reported and skipped rather than
+//! failing the picker — a built-in falls back to its default key, a custom
+//! action goes unbound. The warnings land in the picker's stash and drain to
+//! stderr on exit, alongside the other picker diagnostics.
+
+use color_print::cformat;
+use skim::binds::parse_key;
+use worktrunk::config::PickerKeysConfig;
+use worktrunk::styling::warning_message;
+
+/// Keys the picker binds that aren't configurable, with what they do. Nothing
+/// may be rebound onto them. (`alt-h` / `alt-l` are bound to nothing, to
+/// suppress skim's horizontal scroll.)
+const FIXED_KEYS: &[(&str, &str)] = &[
+    ("enter", "switch"),
+    ("esc", "cancel"),
+    ("tab", "next preview tab"),
+    ("btab", "previous preview tab"),
+    ("alt-p", "preview toggle"),
+    ("alt-h", "scroll suppression"),
+    ("alt-l", "scroll suppression"),
+    ("ctrl-u", "preview scroll"),
+    ("ctrl-d", "preview scroll"),
+];
+
+/// A custom action: a command template bound to a key.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct CustomAction {
+    pub key: String,
+    pub command: String,
+}
+
+/// The resolved bindings. Every key here parses with [`parse_key`] and none
+/// collide with each other or with [`FIXED_KEYS`]. A built-in is `None` only
+/// when another action was moved onto its default key and it wasn't rebound.
+#[derive(Debug, PartialEq)]
+pub(super) struct PickerKeys {
+    pub create: Option<String>,
+    pub remove: Option<String>,
+    pub merge: Option<String>,
+    pub mark: Option<String>,
+    pub refresh: Option<String>,
+    pub copy_branch: Option<String>,
+    pub open_url: Option<String>,
+    /// Modifier for the numbered preview-tab keys (`alt` → `alt-1`…`alt-9`).
+    pub preview_tabs: String,
+    pub actions: Vec<CustomAction>,
+}
+
+impl PickerKeys {
+    /// Resolve `config` against the defaults, returning the bindings and a
+    /// warning for each entry that was ignored.
+    pub fn resolve(config: &PickerKeysConfig) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let mut taken = Vec::new();
+        for (key, purpose) in FIXED_KEYS {
+            if let Ok(event) = parse_key(key) {
+                taken.push((event, (*purpose).to_string()));
+            }
+        }
+
+        // Preview tabs first: nine keys from one modifier, checked as a unit.
+        let preview_tabs = match config.preview_tabs.as_deref() {
+            Some(modifier) => {
+                let events: Option<Vec<_>> = (1..=9)
+                    .map(|digit| parse_key(&format!("{modifier}-{digit}")).ok())
+                    .collect();
+                match events {
+                    Some(events) if events.iter().all(|e| claimant(&taken, e).is_none()) => {
+                        modifier.to_string()
+                    }
+                    Some(_) => {
+                        warnings.push(ignored_warning(
+                            "preview-tabs",
+                            modifier,
+                            "its keys are already bound",
+                        ));
+                        "alt".to_string()
+                    }
+                    None => {
+                        warnings.push(ignored_warning(
+                            "preview-tabs",
+                            modifier,
+                            "not a key modifier",
+                        ));
+                        "alt".to_string()
+                    }
+                }
+            }
+            None => "alt".to_string(),
+        };
+        for digit in 1..=9 {
+            if let Ok(event) = parse_key(&format!("{preview_tabs}-{digit}")) {
+                taken.push((event, "preview-tabs".to_string()));
+            }
+        }
+
+        // Keys the user chose claim theirs before the defaults do, so moving
+        // one action onto another's default key (`create = "alt-x"`) wins —
+        // the displaced action is left unbound, with a warning.
+        let builtins = [
+            ("create", config.create.as_deref(), "alt-c"),
+            ("remove", config.remove.as_deref(), "alt-x"),
+            ("merge", config.merge.as_deref(), "alt-m"),
+            ("mark", config.mark.as_deref(), "ctrl-space"),
+            ("refresh", config.refresh.as_deref(), "alt-r"),
+            ("copy-branch", config.copy_branch.as_deref(), "alt-y"),
+            ("open-url", config.open_url.as_deref(), "alt-o"),
+        ];
+        let mut resolved: [Option<String>; 7] = Default::default();
+        for (slot, &(name, configured, _)) in resolved.iter_mut().zip(&builtins) {
+            let Some(key) = configured else {
+                continue;
+            };
+            match parse_key(key) {
+                Ok(event) => match claimant(&taken, &event) {
+                    None => {
+                        taken.push((event, name.to_string()));
+                        *slot = Some(key.to_string());
+                    }
+                    Some(other) => warnings.push(ignored_warning(
+                        name,
+                        key,
+                        &format!("already bound to {other}"),
+                    )),
+                },
+                Err(_) => warnings.push(ignored_warning(name, key, "not a key")),
+            }
+        }
+        for (slot, &(name, _, default)) in resolved.iter_mut().zip(&builtins) {
+            if slot.is_some() {
+                continue;
+            }
+            let Ok(event) = parse_key(default) else {
+                continue;
+            };
+            match claimant(&taken, &event) {
+                None => {
+                    taken.push((event, name.to_string()));
+                    *slot = Some(default.to_string());
+                }
+                Some(other) => warnings.push(
+                    warning_message(cformat!(
+                        "<bold>{name}</> has no key: its default <bold>{default}</> is bound to {other}"
+                    ))
+                    .to_string(),
+                ),
+            }
+        }
+        let [create, remove, merge, mark, refresh, copy_branch, open_url] = resolved;
+
+        let mut actions = Vec::new();
+        for (key, command) in &config.actions {
+            let Ok(event) = parse_key(key) else {
+                warnings.push(ignored_action_warning(key, "not a key"));
+                continue;
+            };
+            if let Some(other) = claimant(&taken, &event) {
+                warnings.push(ignored_action_warning(
+                    key,
+                    &format!("already bound to {other}"),
+                ));
+                continue;
+            }
+            if command.trim().is_empty() {
+                warnings.push(ignored_action_warning(key, "empty command"));
+                continue;
+            }
+            taken.push((event, key.clone()));
+            actions.push(CustomAction {
+                key: key.clone(),
+                command: command.clone(),
+            });
+        }
+
+        (
+            Self {
+                create,
+                remove,
+                merge,
+                mark,
+                refresh,
+                copy_branch,
+                open_url,
+                preview_tabs,
+                actions,
+            },
+            warnings,
+        )
+    }
+}
+
+/// The name already bound to `event`, if any.
+fn claimant<'a, E: PartialEq>(taken: &'a [(E, String)], event: &E) -> Option<&'a str> {
+    taken
+        .iter()
+        .find(|(e, _)| e == event)
+        .map(|(_, name)| name.as_str())
+}
+
+/// Warning for a built-in rebinding (`name = "key"`) that was ignored.
+fn ignored_warning(name: &str, key: &str, reason: &str) -> String {
+    warning_message(cformat!(
+        "Ignoring <bold>[switch.picker.keys] {name} = \"{key}\"</>: {reason}"
+    ))
+    .to_string()
+}
+
+/// Warning for a custom action that was left unbound.
+fn ignored_action_warning(key: &str, reason: &str) -> String {
+    warning_message(cformat!(
+        "Ignoring <bold>[switch.picker.keys.actions] {key}</>: {reason}"
+    ))
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn resolve(toml: &str) -> (PickerKeys, Vec<String>) {
+        let config: PickerKeysConfig = toml::from_str(toml).unwrap();
+        let (keys, warnings) = PickerKeys::resolve(&config);
+        let warnings = warnings
+            .iter()
+            .map(|w| ansi_str::AnsiStr::ansi_strip(w.as_str()).into_owned())
+            .collect();
+        (keys, warnings)
+    }
+
+    #[test]
+    fn test_defaults() {
+        let (keys, warnings) = PickerKeys::resolve(&PickerKeysConfig::default());
+        assert!(warnings.is_empty());
+        assert_eq!(
+            keys,
+            PickerKeys {
+                create: Some("alt-c".into()),
+                remove: Some("alt-x".into()),
+                merge: Some("alt-m".into()),
+                mark: Some("ctrl-space".into()),
+                refresh: Some("alt-r".into()),
+                copy_branch: Some("alt-y".into()),
+                open_url: Some("alt-o".into()),
+                preview_tabs: "alt".into(),
+                actions: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_rebinds_and_actions() {
+        let (keys, warnings) = resolve(
+            r#"
+remove = "ctrl-x"
+refresh = "f5"
+preview-tabs = "ctrl"
+
+[actions]
+alt-t = "cargo test"
+alt-x = "echo {{ branch }}"
+"#,
+        );
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(keys.remove.as_deref(), Some("ctrl-x"));
+        assert_eq!(keys.refresh.as_deref(), Some("f5"));
+        assert_eq!(keys.preview_tabs, "ctrl");
+        // `alt-x` is free once `remove` moves off it.
+        assert_eq!(
+            keys.actions,
+            vec![
+                CustomAction {
+                    key: "alt-t".into(),
+                    command: "cargo test".into(),
+                },
+                CustomAction {
+                    key: "alt-x".into(),
+                    command: "echo {{ branch }}".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_and_colliding_keys_are_ignored() {
+        let (keys, warnings) = resolve(
+            r#"
+create = "hyper-c"
+remove = "ctrl-u"
+preview-tabs = "meta"
+
+[actions]
+alt-y = "echo copy"
+enter = "echo enter"
+alt-b = "  "
+alt-z = "echo ok"
+"#,
+        );
+        // Built-ins fall back to their defaults; bad actions go unbound.
+        assert_eq!(keys.create.as_deref(), Some("alt-c"));
+        assert_eq!(keys.remove.as_deref(), Some("alt-x"));
+        assert_eq!(keys.preview_tabs, "alt");
+        assert_eq!(
+            keys.actions,
+            vec![CustomAction {
+                key: "alt-z".into(),
+                command: "echo ok".into(),
+            }]
+        );
+        assert_eq!(
+            warnings,
+            vec![
+                r#"▲ Ignoring [switch.picker.keys] preview-tabs = "meta": not a key modifier"#,
+                r#"▲ Ignoring [switch.picker.keys] create = "hyper-c": not a key"#,
+                r#"▲ Ignoring [switch.picker.keys] remove = "ctrl-u": already bound to preview scroll"#,
+                "▲ Ignoring [switch.picker.keys.actions] alt-b: empty command",
+                "▲ Ignoring [switch.picker.keys.actions] alt-y: already bound to copy-branch",
+                "▲ Ignoring [switch.picker.keys.actions] enter: already bound to switch",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preview_tab_modifier_collision() {
+        let mut actions = BTreeMap::new();
+        actions.insert("ctrl-3".to_string(), "echo".to_string());
+        let config = PickerKeysConfig {
+            preview_tabs: Some("ctrl".into()),
+            remove: Some("ctrl-4".into()),
+            actions,
+            ..Default::default()
+        };
+        let (keys, warnings) = PickerKeys::resolve(&config);
+        // The tabs claim their keys before anything else, so the clashing
+        // `remove` and action lose.
+        assert_eq!(keys.preview_tabs, "ctrl");
+        assert_eq!(keys.remove.as_deref(), Some("alt-x"));
+        assert!(keys.actions.is_empty());
+        assert_eq!(warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_moving_onto_a_default_key_unbinds_the_displaced_action() {
+        let (keys, warnings) = resolve(
+            r#"
+create = "alt-x"
+"#,
+        );
+        assert_eq!(keys.create.as_deref(), Some("alt-x"));
+        assert_eq!(keys.remove, None);
+        assert_eq!(
+            warnings,
+            vec!["▲ remove has no key: its default alt-x is bound to create"]
+        );
+
+        // Rebinding the displaced action too keeps both.
+        let (keys, warnings) = resolve(
+            r#"
+create = "alt-x"
+remove = "alt-c"
+"#,
+        );
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(keys.create.as_deref(), Some("alt-x"));
+        assert_eq!(keys.remove.as_deref(), Some("alt-c"));
+    }
+}
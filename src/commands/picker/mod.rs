@@ -90,7 +90,10 @@
 //! # documented in benches/CLAUDE.md §"What's on the critical path?".
 //! ```
 
+mod actions;
+mod bulk;
 mod items;
+mod keys;
 mod log_formatter;
 mod os;
 mod pager;
@@ -101,6 +104,7 @@ mod preview_notify;
 mod preview_orchestrator;
 mod progressive_handler;
 mod prs;
+mod review;
 mod summary;
 
 use std::cell::RefCell;
@@ -133,6 +137,7 @@ use super::list::model::{BranchScope, ItemKind, ListItem};
 use super::list::progressive::RenderTarget;
 use super::list::render::PLACEHOLDER;
 use super::repository_ext::{RemoveTarget, RepositoryCliExt};
+use super::step::prune::RemovalLocks;
 use super::worktree::{RemovalPlan, SwitchPipeline};
 use crate::cli::SwitchFormat;
 use crate::output::{RemovalExecution, handle_remove_output};
@@ -261,6 +266,7 @@ enum RemovalEffect {
 /// The row is mutated optimistically; if the background removal finds the target
 /// survived ([`removal_target_still_present`]) it restores the row
 /// ([`restore_failed_removal`] / [`revert_morph`]) and stashes why.
+#[derive(Clone)]
 struct AltXRemover {
     /// The picker's row list (shared with [`PickerCollector`] and the handler).
     /// `apply` drops a row from it for the drop path; the callback then rebuilds
@@ -300,8 +306,22 @@ struct AltXRemover {
     /// immediately, not only when the stash drains on exit. See
     /// [`items::HeaderFlash`].
     header_flash: Arc<items::HeaderFlash>,
+    /// `wt step prune`'s removal locks, picker-lifetime: removals from quick
+    /// successive `alt-x` presses, or a bulk removal's workers, overlap on the
+    /// same worktree registry prune's concurrent removals do. See
+    /// [`RemovalLocks`].
+    locks: Arc<RemovalLocks>,
+    /// Open while [`bulk::remove_marked`] stages a bulk removal: each row's
+    /// background work queues here instead of spawning, and the bulk removal
+    /// runs the batch on its worker pool. `None` otherwise. See
+    /// [`dispatch`](Self::dispatch).
+    batch: Arc<Mutex<Option<Vec<RemovalWork>>>>,
 }
 
+/// A staged removal's background half — the git work, then the row restore
+/// if the target survived. Returns whether the target is gone.
+type RemovalWork = Box<dyn FnOnce() -> bool + Send>;
+
 /// How long a declined-`alt-x` header flash stays up before it self-clears and
 /// the column labels return — long enough to read, short enough not to linger.
 const HEADER_FLASH_DURATION: std::time::Duration = std::time::Duration::from_millis(2500);
@@ -367,10 +387,16 @@ impl AltXRemover {
     /// a `BranchOnly` deletion. `Worktree` removal itself is rooted at
     /// `main_path` (which may differ from the picker's startup repo in bare-repo
     /// setups).
+    ///
+    /// `locks` serializes the git work against other removals in flight the way
+    /// prune's workers are ([`RemovalLocks::acquire`]): a worktree teardown
+    /// holds the registry exclusively, and so does a removal whose approved
+    /// hooks run.
     fn do_removal(
         repo: &Repository,
         result: &RemovalPlan,
         approvals: &Approvals,
+        locks: &RemovalLocks,
     ) -> anyhow::Result<()> {
         match result {
             RemovalPlan::Worktree {
@@ -380,6 +406,11 @@ impl AltXRemover {
             } => {
                 let main_repo = Repository::at(main_path)?;
                 let plan = approved_removal_plan(repo, main_path, worktree_path, approvals)?;
+                // Exclusive while a `pre-remove` hook body runs, as in prune.
+                let _guards = locks.acquire(
+                    plan.has_hooks_for(worktree_path, &[HookType::PreRemove, HookType::PostRemove]),
+                    result,
+                );
                 let mut announcer = HookAnnouncer::new(&main_repo, false);
                 // The fate is dropped: the picker infers success by observing
                 // whether the target survived (`removal_target_still_present`),
@@ -398,6 +429,7 @@ impl AltXRemover {
                 deletion_mode,
                 ..
             } => {
+                let _guards = locks.acquire(false, result);
                 if !deletion_mode.should_keep() {
                     let default_branch = repo.default_branch();
                     let target = default_branch.as_deref().unwrap_or("HEAD");
@@ -448,15 +480,7 @@ impl AltXRemover {
         // from this shrunk list ([`resync_pool`]) without a `reload`, so `current`
         // holds its index and the row that slides up into the removed slot lands
         // under the cursor for free, query or no query.
-        let removed = {
-            let mut items = self.items.lock().unwrap();
-            let removed = items
-                .iter()
-                .position(|item| item.output().as_ref() == selected_output)
-                .map(|pos| (Arc::clone(&items[pos]), pos));
-            items.retain(|item| item.output().as_ref() != selected_output);
-            removed
-        };
+        let removed = self.drop_row(&selected_output);
 
         // A user-facing (label, noun) for the `kept` message, taken from the result
         // before it moves into the background thread.
@@ -468,32 +492,64 @@ impl AltXRemover {
         let render_tx = Arc::clone(&self.render_tx);
         let stashed_warnings = Arc::clone(&self.stashed_warnings);
         let header_flash = Arc::clone(&self.header_flash);
-        spawn_removal(format!("picker-remove-{selected_output}"), move || {
-            if let Err(e) = Self::do_removal(&repo, &result, &approvals) {
-                tracing::warn!(selected_output = %selected_output, error = %e, "picker: removal of '{selected_output}' errored: {e:#}");
-            }
-            // A removal that keeps its branch never reaches here — that's the
-            // morph path (`morph_and_remove_in_background`). So a surviving
-            // target means the removal itself failed: put the row back.
-            if removal_target_still_present(&repo, &result)
-                && let Some((item, pos)) = removed
-            {
-                restore_failed_removal(
-                    &items,
-                    &header_flash,
-                    &render_tx,
-                    &stashed_warnings,
-                    DroppedRow {
-                        item,
-                        pos,
-                        label: removal_label,
-                        noun: removal_noun,
-                    },
-                );
-            }
+        let locks = Arc::clone(&self.locks);
+        let name = format!("picker-remove-{selected_output}");
+        self.dispatch(
+            name,
+            Box::new(move || {
+                if let Err(e) = Self::do_removal(&repo, &result, &approvals, &locks) {
+                    tracing::warn!(selected_output = %selected_output, error = %e, "picker: removal of '{selected_output}' errored: {e:#}");
+                }
+                // A removal that keeps its branch never reaches here — that's the
+                // morph path (`morph_and_remove_in_background`). So a surviving
+                // target means the removal itself failed: put the row back.
+                if !removal_target_still_present(&repo, &result) {
+                    return true;
+                }
+                if let Some((item, pos)) = removed {
+                    restore_failed_removal(
+                        &items,
+                        &header_flash,
+                        &render_tx,
+                        &stashed_warnings,
+                        DroppedRow {
+                            item,
+                            pos,
+                            label: removal_label,
+                            noun: removal_noun,
+                        },
+                    );
+                }
+                false
+            }),
+        );
+    }
+
+    /// Run a staged removal's background work: queued on the open bulk batch
+    /// ([`batch`](Self::batch)), else on its own thread via [`spawn_removal`].
+    fn dispatch(&self, name: String, work: RemovalWork) {
+        if let Some(batch) = self.batch.lock().unwrap().as_mut() {
+            batch.push(work);
+            return;
+        }
+        spawn_removal(name, move || {
+            work();
         });
     }
 
+    /// Drop the row whose `output()` token is `selected_output` from the list,
+    /// returning it with its former position (for [`restore_failed_removal`]).
+    /// The caller rebuilds skim's pool from the shrunk list ([`resync_pool`]).
+    fn drop_row(&self, selected_output: &str) -> Option<(Arc<dyn SkimItem>, usize)> {
+        let mut items = self.items.lock().unwrap();
+        let removed = items
+            .iter()
+            .position(|item| item.output().as_ref() == selected_output)
+            .map(|pos| (Arc::clone(&items[pos]), pos));
+        items.retain(|item| item.output().as_ref() != selected_output);
+        removed
+    }
+
     /// Flash a one-line message in the header for a beat (see the free
     /// [`flash_header`] for the mechanism and generation guard). The synchronous
     /// keep/reject paths (on skim's event loop) reach it through this method; the
@@ -583,35 +639,67 @@ impl AltXRemover {
         planning_repo: Repository,
         result: RemovalPlan,
     ) -> RemovalEffect {
+        // Any gap (row not morphable, layout not yet handed over) means no clean
+        // in-place morph — drop the row instead, same end state.
+        let Some(revert) = self.morph_in_place(&selected_output, &branch) else {
+            self.drop_and_remove_in_background(selected_output, planning_repo, result);
+            return RemovalEffect::Dropped;
+        };
+
+        let repo = planning_repo.clone();
+        let approvals = Arc::clone(&self.approvals);
+        let render_tx = Arc::clone(&self.render_tx);
+        let stashed_warnings = Arc::clone(&self.stashed_warnings);
+        let header_flash = Arc::clone(&self.header_flash);
+        let locks = Arc::clone(&self.locks);
+        let name = format!("picker-morph-{branch}");
+        self.dispatch(
+            name,
+            Box::new(move || {
+                if let Err(e) = Self::do_removal(&repo, &result, &approvals, &locks) {
+                    tracing::warn!(branch = %branch, error = %e, "picker: removal of '{branch}' worktree errored: {e:#}");
+                }
+                // Only the worktree removal can realistically fail here; if it did,
+                // the worktree dir survives — undo the morph and say so.
+                if !removal_target_still_present(&repo, &result) {
+                    return true;
+                }
+                revert_morph(revert, &header_flash, &stashed_warnings, &render_tx);
+                false
+            }),
+        );
+
+        RemovalEffect::Morphed
+    }
+
+    /// Rewrite the selected worktree row as its `/ branch` row in place and
+    /// re-key its `alt-y`/`alt-o` shortcut entry to the branch token, returning
+    /// what [`revert_morph`] needs to undo it.
+    ///
+    /// `None` — nothing changed — when the row carries no
+    /// [`MorphHandle`](items::MorphHandle) or the layout hasn't landed yet; the
+    /// caller drops the row instead. Shared by the `alt-x` morph
+    /// ([`morph_and_remove_in_background`](Self::morph_and_remove_in_background))
+    /// and a custom action that removed the row's worktree
+    /// ([`actions::reconcile_row`]), which has nothing to revert.
+    fn morph_in_place(&self, selected_output: &str, branch: &str) -> Option<MorphRevert> {
         // Gather the row's shared morph handles and render the branch line on the
-        // live layout. Any gap (row not morphable, layout not yet handed over)
-        // means no clean in-place morph — drop the row instead, same end state.
+        // live layout.
         let default_branch = self.repo.default_branch();
-        let prepared = {
+        let slots = {
             let table = self.shortcut_table.lock().unwrap();
             let layout = self.layout_slot.lock().unwrap();
-            match (
-                table.get(&selected_output).and_then(|d| d.morph.as_ref()),
-                layout.as_ref(),
-            ) {
-                (Some(handle), Some(layout)) => {
-                    let (branch_line, branch_local) =
-                        build_morph_branch_row(layout, &handle.item, default_branch.as_deref());
-                    Some(MorphSlots {
-                        rendered: Arc::clone(&handle.rendered),
-                        morphed: Arc::clone(&handle.morphed),
-                        local_content: Arc::clone(&handle.local_content),
-                        branch_line,
-                        branch_local,
-                    })
-                }
-                _ => None,
+            let handle = table.get(selected_output).and_then(|d| d.morph.as_ref())?;
+            let (branch_line, branch_local) =
+                build_morph_branch_row(layout.as_ref()?, &handle.item, default_branch.as_deref());
+            MorphSlots {
+                rendered: Arc::clone(&handle.rendered),
+                morphed: Arc::clone(&handle.morphed),
+                local_content: Arc::clone(&handle.local_content),
+                branch_line,
+                branch_local,
             }
         };
-        let Some(slots) = prepared else {
-            self.drop_and_remove_in_background(selected_output, planning_repo, result);
-            return RemovalEffect::Dropped;
-        };
 
         // Snapshot the pre-morph display for the revert, then apply the morph.
         let original_rendered = slots.rendered.lock().unwrap().clone();
@@ -624,39 +712,21 @@ impl AltXRemover {
         // `output()`); the revert moves it back.
         {
             let mut table = self.shortcut_table.lock().unwrap();
-            if let Some(data) = table.remove(&selected_output) {
-                table.insert(branch.clone(), data);
+            if let Some(data) = table.remove(selected_output) {
+                table.insert(branch.to_string(), data);
             }
         }
 
-        let repo = planning_repo.clone();
-        let approvals = Arc::clone(&self.approvals);
-        let render_tx = Arc::clone(&self.render_tx);
-        let stashed_warnings = Arc::clone(&self.stashed_warnings);
-        let shortcut_table = Arc::clone(&self.shortcut_table);
-        let header_flash = Arc::clone(&self.header_flash);
-        let revert = MorphRevert {
+        Some(MorphRevert {
             rendered: slots.rendered,
             original_rendered,
             morphed: slots.morphed,
             local_content: slots.local_content,
             original_local,
-            shortcut_table,
-            branch_token: branch.clone(),
-            worktree_token: selected_output.clone(),
-        };
-        spawn_removal(format!("picker-morph-{branch}"), move || {
-            if let Err(e) = Self::do_removal(&repo, &result, &approvals) {
-                tracing::warn!(branch = %branch, error = %e, "picker: removal of '{branch}' worktree errored: {e:#}");
-            }
-            // Only the worktree removal can realistically fail here; if it did,
-            // the worktree dir survives — undo the morph and say so.
-            if removal_target_still_present(&repo, &result) {
-                revert_morph(revert, &header_flash, &stashed_warnings, &render_tx);
-            }
-        });
-
-        RemovalEffect::Morphed
+            shortcut_table: Arc::clone(&self.shortcut_table),
+            branch_token: branch.to_string(),
+            worktree_token: selected_output.to_string(),
+        })
     }
 
     /// Run the `alt-x` removal dispatch for the selected row.
@@ -1676,6 +1746,7 @@ pub fn handle_picker(
         term_width as f64,
         term_height as f64,
     ));
+    bulk::MarkedRows::reset();
     worktrunk::trace::instant("Picker layout detected");
 
     // Prime the current worktree's root / git-dir / branch caches with one
@@ -1893,11 +1964,30 @@ pub fn handle_picker(
         shortcut_table: Arc::clone(&shortcut_table),
         layout_slot: Arc::clone(&factory.layout_slot),
         header_flash: Arc::clone(&factory.header_flash),
+        locks: Arc::default(),
+        batch: Arc::default(),
     };
 
     // Half-page preview scroll: half of skim's usable height.
     let half_page = (preview::available_height(term_height) / 2).max(5);
 
+    // Resolve `[switch.picker.keys]` against the default keys. Entries that
+    // can't be bound are reported on exit with the other stashed warnings.
+    let (keys, key_warnings) = keys::PickerKeys::resolve(&config.switch_picker.keys());
+    stashed_warnings.lock().unwrap().extend(key_warnings);
+    let mut string_binds = Vec::new();
+    // Create new worktree with query as branch name (alt-c for "create")
+    if let Some(key) = &keys.create {
+        string_binds.push(format!("{key}:accept(create)"));
+    }
+    // Refresh the list (alt-r for "refresh"): `reload(refresh)` re-runs
+    // collect through PickerCollector, picking up worktrees/branches created
+    // outside the session (a teammate's push, a parallel agent) without
+    // reopening the picker.
+    if let Some(key) = &keys.refresh {
+        string_binds.push(format!("{key}:reload(refresh)"));
+    }
+
     // Configure skim options with Rust-based preview and mode switching keybindings
     let mut options = SkimOptionsBuilder::default()
         .height("90%".to_string())
@@ -1990,56 +2080,73 @@ pub fn handle_picker(
         // - Dark text (237) ensures readability regardless of terminal theme
         .color("fg:-1,bg:-1,header:-1,matched:108,current:237,current_bg:251,current_match:108")
         .cmd_collector(Rc::new(RefCell::new(collector)) as Rc<RefCell<dyn CommandCollector>>)
-        .bind(vec![
-            // Preview-tab switching (alt-1..alt-8 jump to a tab; tab / shift-tab
-            // cycle) is installed natively below via `install_preview_tab_keybindings`
-            // rather than here — those keys run Rust callbacks, not shell commands.
-            // Bare digits 1-8 stay unbound so they flow to the query input (a PR
-            // number, or digits within a branch name).
-            //
-            // Create new worktree with query as branch name (alt-c for "create")
-            "alt-c:accept(create)".to_string(),
-            // alt-x (remove) is installed natively below via
-            // `install_remove_keybinding` — a Custom callback that runs the removal
-            // synchronously and rebuilds skim's pool in place (no `reload`, so no
-            // cursor flash), which a string bind can't express.
-            // Refresh the list (alt-r for "refresh"): `reload(refresh)` re-runs
-            // collect through PickerCollector, picking up worktrees/branches
-            // created outside the session (a teammate's push, a parallel agent)
-            // without reopening the picker.
-            "alt-r:reload(refresh)".to_string(),
-            // Preview toggle (alt-p shows/hides preview)
-            // Note: skim doesn't support change-preview-window like fzf, only toggle
-            "alt-p:toggle-preview".to_string(),
-            // Suppress skim's default manual horizontal scroll (alt-h / alt-l map to
-            // ScrollLeft / ScrollRight in its built-in keymap). `no_hscroll(true)`
-            // above only zeros the *automatic* match-following shift; it doesn't gate
-            // the manual `manual_hscroll` offset these keys push, so they still slide
-            // each row's `display()` left under the fixed gutter — clipping the leading
-            // worktree-status sigil (`+`/`@`/`^`/`/`/`|`) and the branch name with no
-            // ellipsis. The row table is laid out to fit the pane, so there is nothing
-            // to scroll to; ignore both.
-            "alt-h:ignore".to_string(),
-            "alt-l:ignore".to_string(),
-            // Preview scrolling (half-page based on terminal height)
-            format!("ctrl-u:preview-up({half_page})"),
-            format!("ctrl-d:preview-down({half_page})"),
-        ])
+        .bind(
+            vec![
+                // Preview-tab switching (alt-1..alt-9 jump to a tab; tab / shift-tab
+                // cycle) is installed natively below via `install_preview_tab_keybindings`
+                // rather than here — those keys run Rust callbacks, not shell commands.
+                // Bare digits 1-8 stay unbound so they flow to the query input (a PR
+                // number, or digits within a branch name).
+                //
+                // The configurable create (alt-c) and refresh (alt-r) binds come
+                // from `string_binds`, chained on below. alt-x (remove) is installed
+                // natively below via `install_remove_keybinding` — a Custom callback
+                // that runs the removal synchronously and rebuilds skim's pool in place
+                // (no `reload`, so no cursor flash), which a string bind can't express.
+                // Preview toggle (alt-p shows/hides preview)
+                // Note: skim doesn't support change-preview-window like fzf, only toggle
+                "alt-p:toggle-preview".to_string(),
+                // Suppress skim's default manual horizontal scroll (alt-h / alt-l map to
+                // ScrollLeft / ScrollRight in its built-in keymap). `no_hscroll(true)`
+                // above only zeros the *automatic* match-following shift; it doesn't gate
+                // the manual `manual_hscroll` offset these keys push, so they still slide
+                // each row's `display()` left under the fixed gutter — clipping the leading
+                // worktree-status sigil (`+`/`@`/`^`/`/`/`|`) and the branch name with no
+                // ellipsis. The row table is laid out to fit the pane, so there is nothing
+                // to scroll to; ignore both.
+                "alt-h:ignore".to_string(),
+                "alt-l:ignore".to_string(),
+                // Preview scrolling (half-page based on terminal height)
+                format!("ctrl-u:preview-up({half_page})"),
+                format!("ctrl-d:preview-down({half_page})"),
+            ]
+            .into_iter()
+            .chain(string_binds)
+            .collect::<Vec<_>>(),
+        )
         // Legend/controls moved to preview window tabs (render_preview_tabs)
         .build()
         .map_err(|e| anyhow::anyhow!("Failed to build skim options: {}", e))?;
     // `.build()` parsed the string binds above into `options.keymap`; layer the
     // preview-tab switches on top as native `Action::Custom` callbacks (skim's
     // string bind API can't express a custom action).
-    install_preview_tab_keybindings(&mut options.keymap);
+    install_preview_tab_keybindings(&mut options.keymap, &keys.preview_tabs);
     // Row shortcuts (alt-y copy branch, alt-o open PR/MR URL) — native callbacks
     // that read the selected row off skim's `App` and run the OS action on a
     // background thread. Like the preview-tab keys, they can't be string binds.
-    install_shortcut_keybindings(&mut options.keymap, Arc::clone(&shortcut_table));
+    install_shortcut_keybindings(
+        &mut options.keymap,
+        Arc::clone(&shortcut_table),
+        keys.copy_branch.as_deref(),
+        keys.open_url.as_deref(),
+    );
+    // Custom actions: run a command template against the selected row (or each
+    // marked row) in the background, then reconcile the rows through the
+    // remover's shared state. `alt-m` (merge) is the built-in one.
+    actions::install_custom_action_keybindings(&mut options.keymap, &alt_x_remover, keys.actions);
+    if let Some(key) = &keys.merge {
+        bulk::install_merge_keybinding(&mut options.keymap, &alt_x_remover, key);
+    }
+    // Multi-select: mark rows for the bulk actions. See the `bulk` module.
+    if let Some(key) = &keys.mark {
+        bulk::install_mark_keybinding(&mut options.keymap, &alt_x_remover, key);
+    }
     // alt-x (remove): a Custom callback that runs the removal synchronously and
     // rebuilds skim's pool in place — no `reload`, so the cursor never flashes to
     // the top. Moves the `AltXRemover` in (the callback must be `Send`).
-    install_remove_keybinding(&mut options.keymap, alt_x_remover);
+    if let Some(key) = &keys.remove {
+        install_remove_keybinding(&mut options.keymap, alt_x_remover, key);
+    }
     worktrunk::trace::instant("Picker skim options built");
 
     // Spawn the collect pipeline (and the `--prs` thread when active). Each
@@ -2204,7 +2311,7 @@ pub fn handle_picker(
     Ok(())
 }
 
-/// Install the preview-tab switches into skim's keymap: alt-1…alt-8 jump to a
+/// Install the preview-tab switches into skim's keymap: alt-1…alt-9 jump to a
 /// tab, tab / shift-tab cycle forward / backward.
 ///
 /// skim's string bind API only maps keys to its built-in actions, so these go
@@ -2220,18 +2327,19 @@ pub fn handle_picker(
 /// `parse_key` produces). Shift-Tab is bound under every spelling crossterm
 /// might report (`btab` / `shift-btab` / `shift-tab`), mirroring skim's default
 /// keymap, so the cycle-back override holds regardless of terminal.
-fn install_preview_tab_keybindings(keymap: &mut skim::binds::KeyMap) {
+fn install_preview_tab_keybindings(keymap: &mut skim::binds::KeyMap, modifier: &str) {
     use skim::binds::parse_key;
 
-    // alt-N jumps to tab N (1-indexed, matching PreviewMode's discriminant).
+    // alt-N jumps to tab N (1-indexed, matching PreviewMode's discriminant);
+    // `[switch.picker.keys] preview-tabs` swaps the modifier.
     let switch_to = |mode: PreviewMode| {
         Action::Custom(ActionCallback::new_sync(move |_app| {
             PreviewStateData::select_mode(mode);
             Ok(vec![Event::RunPreview])
         }))
     };
-    for digit in 1..=8u8 {
-        if let Ok(key) = parse_key(&format!("alt-{digit}")) {
+    for digit in 1..=9u8 {
+        if let Ok(key) = parse_key(&format!("{modifier}-{digit}")) {
             keymap.insert(key, vec![switch_to(PreviewMode::from_u8(digit))]);
         }
     }
@@ -2290,11 +2398,16 @@ fn resolve_shortcut_url(table: &ShortcutTable, token: &str) -> Option<String> {
 /// thing they act on: `alt-y` on a detached worktree (no branch), `alt-o` on a
 /// row with no URL (a worktree whose PR hasn't resolved, or has none). Failures
 /// are logged, not surfaced — skim owns the terminal.
-fn install_shortcut_keybindings(keymap: &mut skim::binds::KeyMap, shortcut_table: ShortcutTable) {
+fn install_shortcut_keybindings(
+    keymap: &mut skim::binds::KeyMap,
+    shortcut_table: ShortcutTable,
+    copy_key: Option<&str>,
+    open_key: Option<&str>,
+) {
     use skim::binds::parse_key;
 
     // alt-y: copy the selected row's branch name to the system clipboard.
-    if let Ok(key) = parse_key("alt-y") {
+    if let Some(Ok(key)) = copy_key.map(parse_key) {
         let table = Arc::clone(&shortcut_table);
         keymap.insert(
             key,
@@ -2312,7 +2425,7 @@ fn install_shortcut_keybindings(keymap: &mut skim::binds::KeyMap, shortcut_table
     }
 
     // alt-o: open the selected row's PR/MR URL in the browser.
-    if let Ok(key) = parse_key("alt-o") {
+    if let Some(Ok(key)) = open_key.map(parse_key) {
         let table = Arc::clone(&shortcut_table);
         keymap.insert(
             key,
@@ -2348,19 +2461,24 @@ fn install_shortcut_keybindings(keymap: &mut skim::binds::KeyMap, shortcut_table
 /// `Rc<PipelineFactory>`. A native keymap insert (not a string bind) is required
 /// because a string bind can't express a Rust callback (like the preview-tab and
 /// row shortcuts).
-fn install_remove_keybinding(keymap: &mut skim::binds::KeyMap, remover: AltXRemover) {
+fn install_remove_keybinding(keymap: &mut skim::binds::KeyMap, remover: AltXRemover, key: &str) {
     use skim::binds::parse_key;
-    let Ok(key) = parse_key("alt-x") else {
+    let Ok(key) = parse_key(key) else {
         return;
     };
     let cb = Action::Custom(ActionCallback::new_sync(move |app| {
-        // The selected row's `output()` token identifies what to remove. No
-        // selection (empty list) → nothing to do.
-        let Some(selected) = app.item_list.selected() else {
+        // Marked rows go as one bulk removal; otherwise the selected row's
+        // `output()` token identifies what to remove. No selection (empty list)
+        // → nothing to do.
+        let marked = bulk::MarkedRows::take();
+        let effect = if !marked.is_empty() {
+            bulk::remove_marked(&remover, marked)
+        } else if let Some(selected) = app.item_list.selected() {
+            remover.apply(selected.item.output().into_owned())
+        } else {
             return Ok(Vec::new());
         };
-        let selected_output = selected.item.output().into_owned();
-        match remover.apply(selected_output) {
+        match effect {
             RemovalEffect::Dropped => {
                 // The row left `items`; rebuild skim's pool from the shrunk list so
                 // the matcher re-filters it in place — the cursor holds its index and
@@ -2535,6 +2653,7 @@ pub mod tests {
         switch_pipeline_repo,
     };
     use crate::commands::list::model::{BranchScope, ItemKind, ListItem, WorktreeData};
+    use crate::commands::step::prune::RemovalLocks;
     use crate::commands::worktree::RemovalPlan;
     use insta::assert_yaml_snapshot;
     use skim::prelude::SkimItem;
@@ -2542,7 +2661,7 @@ pub mod tests {
     use std::path::Path;
     use std::sync::{Arc, Mutex, OnceLock};
     use std::time::{Duration, Instant};
-    use worktrunk::config::{Approvals, CommitGenerationConfig};
+    use worktrunk::config::{ApprovalRecord, Approvals, CommitGenerationConfig};
     use worktrunk::git::BranchDeletionMode;
 
     #[test]
@@ -2627,7 +2746,7 @@ pub mod tests {
         // uniform `from_u8` loop — `from_u8`/`next`/`prev` are unit-tested in
         // `preview`, and the `switch_picker` PTY tests drive the keys end-to-end.
         let mut keymap = KeyMap::default();
-        install_preview_tab_keybindings(&mut keymap);
+        install_preview_tab_keybindings(&mut keymap, "alt");
 
         let mut specs: Vec<String> = (1..=8).map(|d| format!("alt-{d}")).collect();
         specs.extend(["tab", "btab", "shift-btab", "shift-tab"].map(String::from));
@@ -2657,7 +2776,7 @@ pub mod tests {
         // PTY tests; here we just assert the wiring, mirroring the tab test above.
         let mut keymap = KeyMap::default();
         let table = Arc::new(Mutex::new(std::collections::HashMap::new()));
-        install_shortcut_keybindings(&mut keymap, table);
+        install_shortcut_keybindings(&mut keymap, table, Some("alt-y"), Some("alt-o"));
 
         for spec in ["alt-y", "alt-o"] {
             let key = parse_key(spec).expect("known key spec parses");
@@ -2861,7 +2980,13 @@ pub mod tests {
             branch_checked_out_at: None,
         };
 
-        AltXRemover::do_removal(&repo, &result, &Approvals::default()).unwrap();
+        AltXRemover::do_removal(
+            &repo,
+            &result,
+            &Approvals::default(),
+            &RemovalLocks::default(),
+        )
+        .unwrap();
         assert!(!wt_path.exists(), "worktree should be removed");
 
         let output = repo.run_command(&["branch", "--list", "feature"]).unwrap();
@@ -2884,7 +3009,13 @@ pub mod tests {
             integration_reason: None,
             branch_checked_out_at: None,
         };
-        AltXRemover::do_removal(&repo, &result, &Approvals::default()).unwrap();
+        AltXRemover::do_removal(
+            &repo,
+            &result,
+            &Approvals::default(),
+            &RemovalLocks::default(),
+        )
+        .unwrap();
 
         let output = repo.run_command(&["branch", "--list", "feature"]).unwrap();
         assert!(output.is_empty(), "integrated branch should be deleted");
@@ -2911,7 +3042,13 @@ pub mod tests {
             integration_reason: None,
             branch_checked_out_at: None,
         };
-        AltXRemover::do_removal(&repo, &result, &Approvals::default()).unwrap();
+        AltXRemover::do_removal(
+            &repo,
+            &result,
+            &Approvals::default(),
+            &RemovalLocks::default(),
+        )
+        .unwrap();
 
         // Branch should be retained — SafeDelete won't delete unmerged branches
         let output = repo.run_command(&["branch", "--list", "unmerged"]).unwrap();
@@ -2959,7 +3096,13 @@ pub mod tests {
             branch_checked_out_at: None,
         };
 
-        AltXRemover::do_removal(&repo, &result, &Approvals::default()).unwrap();
+        AltXRemover::do_removal(
+            &repo,
+            &result,
+            &Approvals::default(),
+            &RemovalLocks::default(),
+        )
+        .unwrap();
         assert!(!wt_path.exists(), "detached worktree should be removed");
     }
 
@@ -3002,7 +3145,13 @@ pub mod tests {
         let checkout = test.home_path().join("repo.row-branch-raced-checkout");
         test.run_git(&["worktree", "add", checkout.to_str().unwrap(), "row-branch"]);
 
-        AltXRemover::do_removal(&planning_repo, &plan, &Approvals::default()).unwrap();
+        AltXRemover::do_removal(
+            &planning_repo,
+            &plan,
+            &Approvals::default(),
+            &RemovalLocks::default(),
+        )
+        .unwrap();
         assert!(
             removal_target_still_present(&planning_repo, &plan),
             "the picker must observe the retained branch and restore its row"
@@ -3112,7 +3261,7 @@ pub mod tests {
         // Empty approvals → `approve_readonly` drops the unapproved project
         // `pre-remove` pipeline from the plan, so it never runs.
         let approvals = Approvals::default();
-        AltXRemover::do_removal(&repo, &result, &approvals).unwrap();
+        AltXRemover::do_removal(&repo, &result, &approvals, &RemovalLocks::default()).unwrap();
         assert!(!wt_path.exists(), "worktree should be removed");
         assert!(!marker.exists(), "unapproved pre-remove hook must not run");
     }
@@ -3301,6 +3450,8 @@ pub mod tests {
             shortcut_table: Arc::clone(&factory.shortcut_table),
             layout_slot: Arc::clone(&factory.layout_slot),
             header_flash: Arc::clone(&factory.header_flash),
+            locks: Arc::default(),
+            batch: Arc::default(),
         }
     }
 
@@ -3872,7 +4023,14 @@ pub mod tests {
         let approvals_path = approvals_dir.path().join("approvals.toml");
         let mut approvals = Approvals::default();
         approvals
-            .approve_commands(pid, vec!["false".to_string()], &approvals_path)
+            .approve_commands(
+                pid,
+                vec![ApprovalRecord {
+                    command: "false".to_string(),
+                    ..Default::default()
+                }],
+                &approvals_path,
+            )
             .unwrap();
 
         // Build the row from the git-reported worktree path, not the raw temp
@@ -3899,6 +4057,8 @@ pub mod tests {
             shortcut_table: Arc::new(Mutex::new(std::collections::HashMap::new())),
             layout_slot: Arc::new(Mutex::new(None)),
             header_flash: Arc::new(super::items::HeaderFlash::default()),
+            locks: Arc::default(),
+            batch: Arc::default(),
         };
 
         remover.apply(token.clone());
@@ -4503,6 +4663,8 @@ pub mod tests {
             shortcut_table: Arc::new(Mutex::new(std::collections::HashMap::new())),
             layout_slot: Arc::new(Mutex::new(None)),
             header_flash: Arc::clone(&header_flash),
+            locks: Arc::default(),
+            batch: Arc::default(),
         };
 
         remover.keep_current_worktree_row();
@@ -4569,6 +4731,8 @@ pub mod tests {
             shortcut_table: Arc::new(Mutex::new(std::collections::HashMap::new())),
             layout_slot: Arc::new(Mutex::new(None)),
             header_flash: Arc::clone(&header_flash),
+            locks: Arc::default(),
+            batch: Arc::default(),
         };
 
         remover.apply(token.clone());
@@ -4641,6 +4805,8 @@ pub mod tests {
             shortcut_table: Arc::new(Mutex::new(std::collections::HashMap::new())),
             layout_slot: Arc::new(Mutex::new(None)),
             header_flash: Arc::clone(&header_flash),
+            locks: Arc::default(),
+            batch: Arc::default(),
         };
 
         remover.apply(token.clone());
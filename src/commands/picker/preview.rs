@@ -14,16 +14,19 @@ use std::sync::atomic::{AtomicI8, AtomicU8, Ordering};
 /// 4. Log: Commit history since diverging from the default branch (git log with merge-base)
 /// 5. UpstreamDiff: Diff vs upstream tracking branch (ahead/behind)
 /// 6. Summary: LLM-generated branch summary (requires [commit.generation] config)
-/// 7. Pr: The selected row's PR/MR, rendered from already-fetched data (no network)
-/// 8. Comments: The PR/MR's comment thread (background forge fetch on any row
+/// 7. Review: The cached `wt step review` findings for the branch's current diff
+///    (read-only — the picker never runs a review)
+/// 8. Pr: The selected row's PR/MR, rendered from already-fetched data (no network)
+/// 9. Comments: The PR/MR's comment thread (background forge fetch on any row
 ///    whose branch has an open PR — worktree row or `--prs` row alike)
 ///
 /// A mode whose content is structurally absent for the current row is rendered
 /// de-emphasized in the tab bar (see `TabAvailability` / `render_preview_tabs`):
 /// tab 5 when the branch has no upstream, tab 6 when summaries are disabled or
-/// the branch has nothing to summarize, the unified/working-tree/branch-diff/
-/// upstream/summary tabs on a `--prs` row (no local worktree), and the PR-backed tabs 7
-/// (pr) and 8 (comments) when the row's branch has no PR. The PR-backed tabs are
+/// the branch has nothing to summarize, tab 7 when the branch has nothing to
+/// review, the unified/working-tree/branch-diff/upstream/summary/review tabs on
+/// a `--prs` row (no local worktree), and the PR-backed tabs 8
+/// (pr) and 9 (comments) when the row's branch has no PR. The PR-backed tabs are
 /// available together, by the same rule, on every row — `--prs` only decides
 /// whether a PR row is listed, not how these tabs behave.
 ///
@@ -31,7 +34,7 @@ use std::sync::atomic::{AtomicI8, AtomicU8, Ordering};
 /// - Tabs 1–3 decompose all changes into working-tree and committed diffs
 /// - Tab 4 shows commits (related to "main↕" counts)
 /// - Tab 5 corresponds to "Remote⇅" column
-/// - Tab 7 corresponds to the "CI" column's PR/MR
+/// - Tab 8 corresponds to the "CI" column's PR/MR
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(super) enum PreviewMode {
     UnifiedDiff = 1,
@@ -40,8 +43,9 @@ pub(super) enum PreviewMode {
     Log = 4,
     UpstreamDiff = 5,
     Summary = 6,
-    Pr = 7,
-    Comments = 8,
+    Review = 7,
+    Pr = 8,
+    Comments = 9,
 }
 
 impl PreviewMode {
@@ -52,20 +56,21 @@ impl PreviewMode {
             4 => Self::Log,
             5 => Self::UpstreamDiff,
             6 => Self::Summary,
-            7 => Self::Pr,
-            8 => Self::Comments,
+            7 => Self::Review,
+            8 => Self::Pr,
+            9 => Self::Comments,
             _ => Self::UnifiedDiff,
         }
     }
 
     /// The next tab, wrapping `Comments` → `UnifiedDiff` (tab key).
     pub(super) fn next(self) -> Self {
-        Self::from_u8(if self as u8 >= 8 { 1 } else { self as u8 + 1 })
+        Self::from_u8(if self as u8 >= 9 { 1 } else { self as u8 + 1 })
     }
 
     /// The previous tab, wrapping `UnifiedDiff` → `Comments` (shift-tab key).
     pub(super) fn prev(self) -> Self {
-        Self::from_u8(if self as u8 <= 1 { 8 } else { self as u8 - 1 })
+        Self::from_u8(if self as u8 <= 1 { 9 } else { self as u8 - 1 })
     }
 
     /// Whether this tab is in [`LOCAL_GIT_MODES`].
@@ -74,18 +79,20 @@ impl PreviewMode {
     }
 }
 
-/// The five tabs computed from local git alone — the one canonical set both
+/// The six tabs computed from local git alone — the one canonical set both
 /// preview producers consume: the orchestrator precomputes exactly these
 /// (plus summaries), and the demand worker serves them on a cache miss (see
-/// `PreviewDemand`), so the two can't drift. Summary is excluded (an LLM
-/// call on tab navigation would be a surprise cost); Pr and Comments
+/// `PreviewDemand`), so the two can't drift. Review belongs here because its
+/// pane only hashes the diff and reads the review cache. Summary is excluded
+/// (an LLM call on tab navigation would be a surprise cost); Pr and Comments
 /// render/fetch through their own paths.
-pub(super) const LOCAL_GIT_MODES: [PreviewMode; 5] = [
+pub(super) const LOCAL_GIT_MODES: [PreviewMode; 6] = [
     PreviewMode::UnifiedDiff,
     PreviewMode::WorkingTree,
     PreviewMode::BranchDiff,
     PreviewMode::Log,
     PreviewMode::UpstreamDiff,
+    PreviewMode::Review,
 ];
 
 /// Typical terminal character aspect ratio (width/height).
@@ -236,7 +243,7 @@ impl PreviewLayout {
 /// One picker runs per process, so a single process-wide value is the source of
 /// truth: `PickerRow::preview` reads it to choose
 /// what to render, and the keymap's `Action::Custom` callbacks (installed in
-/// `super::install_preview_tab_keybindings`) write it on alt-1…alt-8 / tab /
+/// `super::install_preview_tab_keybindings`) write it on alt-1…alt-9 / tab /
 /// shift-tab, then re-run the preview.
 ///
 /// It lives in memory rather than on disk. Tab switching used to write a digit
@@ -263,7 +270,7 @@ impl PreviewStateData {
         effective_mode(PREVIEW_SELECTION.load(Ordering::Relaxed), default_mode)
     }
 
-    /// Jump to a specific tab (alt-1…alt-8), making the choice sticky.
+    /// Jump to a specific tab (alt-1…alt-9), making the choice sticky.
     pub(super) fn select_mode(mode: PreviewMode) {
         PREVIEW_SELECTION.store(mode as u8, Ordering::Relaxed);
     }
@@ -320,8 +327,9 @@ mod tests {
         assert_eq!(PreviewMode::from_u8(4), PreviewMode::Log);
         assert_eq!(PreviewMode::from_u8(5), PreviewMode::UpstreamDiff);
         assert_eq!(PreviewMode::from_u8(6), PreviewMode::Summary);
-        assert_eq!(PreviewMode::from_u8(7), PreviewMode::Pr);
-        assert_eq!(PreviewMode::from_u8(8), PreviewMode::Comments);
+        assert_eq!(PreviewMode::from_u8(7), PreviewMode::Review);
+        assert_eq!(PreviewMode::from_u8(8), PreviewMode::Pr);
+        assert_eq!(PreviewMode::from_u8(9), PreviewMode::Comments);
         // Invalid values default to UnifiedDiff
         assert_eq!(PreviewMode::from_u8(0), PreviewMode::UnifiedDiff);
         assert_eq!(PreviewMode::from_u8(99), PreviewMode::UnifiedDiff);
@@ -503,6 +511,7 @@ mod tests {
                 PreviewMode::Log,
                 PreviewMode::UpstreamDiff,
                 PreviewMode::Summary,
+                PreviewMode::Review,
                 PreviewMode::Pr,
                 PreviewMode::Comments,
             ]
@@ -111,7 +111,7 @@
 //! choke point: it inserts into the cache and pokes [`super::preview_notify`] so a
 //! compute that lands after skim already drew the pane repaints without a
 //! keystroke. Precompute is tiered — [`PreviewOrchestrator::spawn_initial_precompute`]
-//! at skeleton time (item 0 × the five local modes + summary, plus the default
+//! at skeleton time (item 0 × the six local modes + summary, plus the default
 //! tab for cheap branch-only rows) and [`PreviewOrchestrator::spawn_deferred_summaries`]
 //! after the row drain (summaries for the remaining rows). Off-screen worktree
 //! panes are demand-loaded instead of speculatively duplicating live worktree
@@ -706,7 +706,7 @@ impl PreviewOrchestrator {
     /// Spawn the skeleton-time pre-compute tier.
     ///
     /// Fires at `on_skeleton`. Two layers of work:
-    /// - First item × all 5 modes + first item summary — the user lands on
+    /// - First item × all 6 modes + first item summary — the user lands on
     ///   row 0 and frequently tab-cycles modes there.
     /// - Branch-only items 1..N × [`INITIAL_MODE`] — pre-warms the committed
     ///   diff they can serve cheaply from the SHA-keyed cache. Off-screen
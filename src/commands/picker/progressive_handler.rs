@@ -21,7 +21,7 @@
 //! terminal `on_collect_complete` forces one final unthrottled frame.
 //!
 //! Preview work is staged in two tiers:
-//! - `on_skeleton` fires the first item's 5 modes + first-item summary,
+//! - `on_skeleton` fires the first item's 6 modes + first-item summary,
 //!   plus the default-tab mode for branch-only items 1..N (their committed
 //!   diff is cheap from the SHA-keyed cache; off-screen worktree rows are
 //!   demand-loaded when selected). It also fills the static Summary hint
@@ -1590,7 +1590,7 @@ mod tests {
     }
 
     /// Preview pre-compute is tiered. After `on_skeleton`:
-    /// - First item gets all 5 modes (the user's landing row).
+    /// - First item gets all 6 modes (the user's landing row).
     /// - Branch-only items 1..N get only `UnifiedDiff` (the picker's initial
     ///   tab) so quick j/k navigation hits warm content. Worktree-backed rows
     ///   are demand-loaded instead; that split is pinned in
@@ -1628,13 +1628,14 @@ mod tests {
             );
         }
 
-        // First item: all 5 modes spawned at skeleton time.
+        // First item: all 6 modes spawned at skeleton time.
         for mode in [
             PreviewMode::UnifiedDiff,
             PreviewMode::WorkingTree,
             PreviewMode::BranchDiff,
             PreviewMode::Log,
             PreviewMode::UpstreamDiff,
+            PreviewMode::Review,
         ] {
             assert!(
                 handler.preview_cache.contains_key(&("alpha".into(), mode)),
@@ -1706,8 +1707,8 @@ mod tests {
             "no work should be spawned when on_skeleton never fired"
         );
 
-        // Case 2: single-item skeleton — first-item phase covered the 5
-        // modes plus the static Summary hint (6 entries total). Nothing
+        // Case 2: single-item skeleton — first-item phase covered the 6
+        // modes plus the static Summary hint (7 entries total). Nothing
         // left to defer; on_collect_complete must not add any entries.
         let (handler, _test, _rx) = make_handler();
         let items = vec![ListItem::new_branch("aaa".into(), "solo".into())];
@@ -1720,6 +1721,7 @@ mod tests {
             PreviewMode::Log,
             PreviewMode::BranchDiff,
             PreviewMode::UpstreamDiff,
+            PreviewMode::Review,
             PreviewMode::Summary,
         ] {
             assert!(
@@ -1727,7 +1729,7 @@ mod tests {
                 "first-item phase should have cached {mode:?}"
             );
         }
-        assert_eq!(before, 6, "first-item phase populates exactly 6 entries");
+        assert_eq!(before, 7, "first-item phase populates exactly 7 entries");
 
         handler.on_collect_complete();
         handler.orchestrator.wait_for_idle();
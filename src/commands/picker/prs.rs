@@ -1998,8 +1998,8 @@ mod tests {
         // Assert on the ANSI-stripped bar so the check targets the tab labels
         // themselves, not a coincidental substring of the styled controls line.
         let bar = plain(&text);
-        assert!(bar.contains("7: pr"), "pr tab present: {bar:?}");
-        assert!(bar.contains("8: comments"), "comments tab present: {bar:?}");
+        assert!(bar.contains("8: pr"), "pr tab present: {bar:?}");
+        assert!(bar.contains("9: comments"), "comments tab present: {bar:?}");
         assert!(
             text.contains("Title") && !text.contains("Not checked out locally"),
             "PR details are the automatic pane: {text:?}"
@@ -2163,7 +2163,7 @@ mod tests {
     fn comments_tab_reads_cache_then_placeholder() {
         // Like the log tab, the deferred `comments` tab reads the shared cache
         // keyed by the row's output token, with a loading placeholder (pointing
-        // at alt-8) on a miss.
+        // at alt-9) on a miss.
         let cache: PreviewCache = Arc::new(DashMap::new());
         let pr = pr_item_with_cache(entry(RefType::Mr, 7, "t"), 120, None, Arc::clone(&cache));
 
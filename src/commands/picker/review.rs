@@ -0,0 +1,103 @@
+//! Review tab for the interactive selector.
+//!
+//! Thin adapter over `crate::review` that renders a branch's cached review.
+//! Unlike the summary tab it never calls the LLM: a review is generated by
+//! `wt step review`, and this pane only reports what the cache holds for the
+//! branch's current diff.
+
+use anstyle::Reset;
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::styling::{HINT_SYMBOL, INFO_SYMBOL};
+
+use super::super::list::model::ListItem;
+use crate::review::CachedReview;
+use crate::summary::{compute_combined_diff, hash_diff};
+
+/// Compute the Review-tab pane for one item: the cached review for the
+/// branch's current combined diff, rendered as markdown, or a hint pointing at
+/// `wt step review` when there isn't one.
+///
+/// The pane matches on the diff hash `wt step review` records with each
+/// review, so a review goes stale (and the pane falls back to the hint) as
+/// soon as the branch changes.
+pub(super) fn compute_review_preview(repo: &Repository, item: &ListItem, width: usize) -> String {
+    let reset = Reset;
+    let branch = item.branch_name();
+    let worktree_path = item.worktree_data().map(|d| d.path.as_path());
+    let Some(combined) = compute_combined_diff(branch, item.head(), worktree_path, repo) else {
+        return cformat!(
+            "{INFO_SYMBOL}{reset} <bold>{branch}</>{reset} has no changes to review\n"
+        );
+    };
+
+    match CachedReview::read_for_diff(repo, branch, &hash_diff(&combined.diff)) {
+        Some(cached) => {
+            crate::md_help::render_markdown_in_help_with_width(&cached.review, Some(width))
+        }
+        None => cformat!(
+            "{HINT_SYMBOL} <dim>No review for the current changes — run</> wt step review<dim> in this worktree</>\n"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::list::model::{ItemKind, WorktreeData};
+    use std::fs;
+    use worktrunk::testing::TestRepo;
+
+    #[test]
+    fn test_review_preview_reads_cache_for_current_diff() {
+        let t = TestRepo::new();
+        t.repo
+            .run_command(&["config", "worktrunk.default-branch", "main"])
+            .unwrap();
+        fs::write(t.path().join("README.md"), "# Project\n").unwrap();
+        t.repo.run_command(&["add", "README.md"]).unwrap();
+        t.repo.run_command(&["commit", "-m", "initial"]).unwrap();
+        t.repo.run_command(&["checkout", "-b", "feature"]).unwrap();
+        fs::write(t.path().join("new.txt"), "new content\n").unwrap();
+        t.repo.run_command(&["add", "new.txt"]).unwrap();
+        t.repo
+            .run_command(&["commit", "-m", "add new file"])
+            .unwrap();
+        let head = t
+            .repo
+            .run_command(&["rev-parse", "HEAD"])
+            .unwrap()
+            .trim()
+            .to_string();
+        let repo = Repository::at(t.path()).unwrap();
+
+        let mut item = ListItem::new_branch(head.clone(), "feature".to_string());
+        item.kind = ItemKind::Worktree(Box::new(WorktreeData {
+            path: t.path().to_path_buf(),
+            ..Default::default()
+        }));
+
+        let miss = compute_review_preview(&repo, &item, 80);
+        assert!(miss.contains("No review for the current changes"), "{miss}");
+
+        let combined = compute_combined_diff("feature", &head, Some(t.path()), &repo).unwrap();
+        CachedReview {
+            review: "- [suggestion] new.txt:1 — add a trailing summary".to_string(),
+            branch: "feature".to_string(),
+            diff_hash: hash_diff(&combined.diff),
+            generated_at: 1,
+        }
+        .write(&repo, "key");
+
+        let hit = compute_review_preview(&repo, &item, 80);
+        assert!(hit.contains("add a trailing summary"), "{hit}");
+
+        // A new change invalidates the cached review.
+        fs::write(t.path().join("new.txt"), "changed\n").unwrap();
+        let stale = compute_review_preview(&repo, &item, 80);
+        assert!(
+            stale.contains("No review for the current changes"),
+            "{stale}"
+        );
+    }
+}
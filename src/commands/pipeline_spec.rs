@@ -3,6 +3,8 @@ use std::path::PathBuf;
 
 use worktrunk::HookType;
 use worktrunk::config::TemplateContext;
+use worktrunk::container::Container;
+use worktrunk::sandbox::SandboxPolicy;
 
 use super::hook_filter::HookSource;
 
@@ -38,9 +40,22 @@ pub enum PipelineStepSpec {
         /// foreground's `HookCommandFailed { command_name }`.
         template_name: String,
         template: String,
+        /// Sandbox to run the command in (project hooks under `[hooks] sandbox`).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        sandbox: Option<SandboxPolicy>,
+        /// Container to run the command in (project hooks of a running
+        /// `[container]`); takes precedence over `sandbox`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        container: Option<Container>,
     },
     Concurrent {
         commands: Vec<PipelineCommandSpec>,
+        /// See `PipelineStepSpec::Single::sandbox`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        sandbox: Option<SandboxPolicy>,
+        /// See `PipelineStepSpec::Single::container`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        container: Option<Container>,
     },
 }
 
@@ -72,6 +87,13 @@ mod tests {
                     name: Some("install".into()),
                     template_name: "user:install".into(),
                     template: "npm install".into(),
+                    sandbox: Some(SandboxPolicy {
+                        writable: vec!["/tmp/test-worktree".into()],
+                        read_only: vec!["/tmp/test-worktree/.git".into()],
+                        required: 1,
+                        allow_network: false,
+                    }),
+                    container: None,
                 },
                 PipelineStepSpec::Concurrent {
                     commands: vec![
@@ -86,6 +108,11 @@ mod tests {
                             template: "echo {{ vars.tag }}".into(),
                         },
                     ],
+                    sandbox: None,
+                    container: Some(Container {
+                        runtime: "docker".into(),
+                        name: "wt-test-worktree-12345678".into(),
+                    }),
                 },
             ],
         };
@@ -106,16 +133,31 @@ mod tests {
                 name,
                 template_name,
                 template,
+                sandbox,
+                ..
             } => {
                 assert_eq!(name.as_deref(), Some("install"));
                 assert_eq!(template_name, "user:install");
                 assert_eq!(template, "npm install");
+                assert_eq!(
+                    sandbox.as_ref().map(|s| s.writable.clone()),
+                    Some(vec!["/tmp/test-worktree".into()])
+                );
             }
             _ => panic!("expected Single step"),
         }
         match &roundtripped.steps[1] {
-            PipelineStepSpec::Concurrent { commands } => {
+            PipelineStepSpec::Concurrent {
+                commands,
+                sandbox,
+                container,
+            } => {
                 assert_eq!(commands.len(), 2);
+                assert!(sandbox.is_none());
+                assert_eq!(
+                    container.as_ref().map(|c| c.name.as_str()),
+                    Some("wt-test-worktree-12345678")
+                );
                 assert_eq!(commands[0].name.as_deref(), Some("build"));
                 assert!(commands[1].template.contains("vars.tag"));
             }
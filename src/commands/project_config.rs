@@ -1,7 +1,8 @@
 use std::fmt;
 
 use color_print::cformat;
-use worktrunk::config::{Command, ProjectConfig};
+use worktrunk::config::{Command, ProjectConfig, ProjectContainerConfig};
+use worktrunk::container::approval_template;
 use worktrunk::git::HookType;
 use worktrunk::styling::{format_bash_with_gutter, format_with_gutter};
 
@@ -9,19 +10,24 @@ use worktrunk::styling::{format_bash_with_gutter, format_with_gutter};
 #[derive(Clone)]
 pub enum Phase {
     Hook(HookType),
-    Alias,
+    /// A step of the named alias.
+    Alias(String),
     /// Project-level commit-message append fragment — not a shell command.
     /// Approving records the raw fragment as "approved" so subsequent LLM
     /// calls include it without re-prompting.
     CommitTemplateAppend,
+    /// The `[container]` every worktree's container is created from. The
+    /// template is its `build`/`run` description, not a literal command.
+    Container,
 }
 
 impl fmt::Display for Phase {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Phase::Hook(hook_type) => write!(f, "{hook_type}"),
-            Phase::Alias => write!(f, "alias"),
+            Phase::Alias(_) => write!(f, "alias"),
             Phase::CommitTemplateAppend => write!(f, "commit-template-append"),
+            Phase::Container => write!(f, "container"),
         }
     }
 }
@@ -45,6 +51,24 @@ impl ApprovableCommand {
         }
     }
 
+    /// Build an approvable for the project's `[container]`, described by
+    /// [`approval_template`].
+    pub fn container(config: &ProjectContainerConfig) -> Self {
+        Self {
+            phase: Phase::Container,
+            command: Command::new(None, approval_template(config)),
+        }
+    }
+
+    /// Where the command runs, as approvals scope it: the hook type,
+    /// `alias:<name>`, `commit-template-append`, or `container`.
+    pub fn scope(&self) -> String {
+        match &self.phase {
+            Phase::Alias(alias) => format!("alias:{alias}"),
+            phase => phase.to_string(),
+        }
+    }
+
     /// `phase name:` label shown before the command body, in the approval
     /// prompt and the approvals listing.
     pub fn label(&self) -> String {
@@ -99,7 +123,7 @@ pub fn collect_commands_for_aliases(project_config: &ProjectConfig) -> Vec<Appro
         .iter()
         .flat_map(|(alias_name, alias_cfg)| {
             alias_cfg.commands().map(move |cmd| ApprovableCommand {
-                phase: Phase::Alias,
+                phase: Phase::Alias(alias_name.clone()),
                 command: Command::new(
                     Some(cmd.name.clone().unwrap_or_else(|| alias_name.clone())),
                     cmd.template.clone(),
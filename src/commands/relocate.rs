@@ -35,7 +35,6 @@ use worktrunk::styling::{
 
 use super::backup;
 use super::commit::{CommitGenerator, StageMode};
-use super::worktree::compute_worktree_path;
 
 // ============================================================================
 // Types representing each stage of the pipeline
@@ -200,7 +199,7 @@ pub fn gather_candidates(
             continue; // Detached HEAD worktrees can't be relocated
         };
 
-        match compute_worktree_path(repo, branch, config) {
+        match repo.expected_worktree_path(branch, config) {
             Ok(expected) => {
                 // Check if paths differ (canonical comparison)
                 let actual_canonical = wt.path.canonicalize().unwrap_or_else(|_| wt.path.clone());
@@ -0,0 +1,320 @@
+//! Worktrees on `[remotes.<name>]` hosts.
+//!
+//! `wt switch --on <name>`, `wt remove --on <name>` and `wt merge --on <name>`
+//! run the host's own `wt` in its clone of the repository, over the
+//! configured `ssh` command — so hooks, approvals and path templates all
+//! resolve on the host, exactly as if the command were typed there. `wt list`
+//! merges each host's worktrees into its table from the host's
+//! `wt list --format=json` (see `list::remote_hosts`).
+
+use std::collections::HashMap;
+use std::io::IsTerminal;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{RemoteHostConfig, expand_template};
+use worktrunk::git::{Repository, WorktrunkError};
+use worktrunk::shell_exec::{Cmd, ShellConfig, ShellEscapeMode};
+
+use crate::cli::{MergeArgs, RemoveArgs, SwitchArgs, SwitchFormat};
+use crate::commands::list::remote_hosts::RemoteItem;
+
+/// Budget for a host's `wt list --format=json`; a host that doesn't answer in
+/// time is reported and left out of the table.
+const LIST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// A resolved `[remotes.<name>]` entry.
+pub(crate) struct RemoteHost {
+    pub(crate) name: String,
+    config: RemoteHostConfig,
+    /// `path` with `{{ repo }}` expanded.
+    path: String,
+}
+
+impl RemoteHost {
+    /// Look up `[remotes.<name>]` in user config.
+    pub(crate) fn resolve(repo: &Repository, name: &str) -> anyhow::Result<Self> {
+        let config = repo
+            .user_config()
+            .remotes
+            .get(name)
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(cformat!(
+                    "No remote host <bold>{name}</>; define it under <bold>[remotes.{name}]</> in user config"
+                ))
+            })?;
+        Self::new(repo, name, config)
+    }
+
+    /// Every configured host by name, in name order.
+    pub(crate) fn all(repo: &Repository) -> Vec<(String, anyhow::Result<Self>)> {
+        repo.user_config()
+            .remotes
+            .iter()
+            .map(|(name, config)| (name.clone(), Self::new(repo, name, config.clone())))
+            .collect()
+    }
+
+    fn new(repo: &Repository, name: &str, config: RemoteHostConfig) -> anyhow::Result<Self> {
+        let repo_path = repo.repo_path()?;
+        let repo_name = repo_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let vars = HashMap::from([("repo", repo_name)]);
+        let path = expand_template(
+            &config.path,
+            &vars,
+            ShellEscapeMode::Literal,
+            repo,
+            &format!("remotes.{name}.path"),
+        )?;
+        Ok(Self {
+            name: name.to_string(),
+            config,
+            path,
+        })
+    }
+
+    /// The host's worktrees, from its `wt list --format=json`.
+    pub(crate) fn list(&self) -> anyhow::Result<Vec<RemoteItem>> {
+        let args = [
+            "--config-set",
+            "list.json-schema=1",
+            "list",
+            "--format=json",
+        ]
+        .map(String::from);
+        let command = self.ssh_command(&self.remote_command(&self.path, &args), false);
+        let shell = ShellConfig::get()?;
+        let output = Cmd::new(shell.executable.to_string_lossy())
+            .args(shell.args.iter().cloned())
+            .arg(command)
+            .timeout(LIST_TIMEOUT)
+            .run()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("{}", stderr.trim());
+        }
+        serde_json::from_slice(&output.stdout)
+            .with_context(|| format!("Unexpected `wt list` output from {}", self.name))
+    }
+
+    /// Run `wt <args>` on the host in `dir`, with the terminal attached.
+    ///
+    /// The host's `wt` reports its own failures, so a non-zero exit forwards
+    /// the code without a second error line.
+    fn run(&self, dir: &str, args: &[String]) -> anyhow::Result<()> {
+        let interactive = std::io::stdin().is_terminal();
+        let command = self.ssh_command(&self.remote_command(dir, args), interactive);
+        Cmd::shell(command)
+            .stdin(std::process::Stdio::inherit())
+            .forward_signals()
+            .stream()
+            .map_err(|err| match err.downcast_ref::<WorktrunkError>() {
+                Some(WorktrunkError::ChildProcessExited {
+                    code, signal: None, ..
+                }) => WorktrunkError::AlreadyDisplayed { exit_code: *code }.into(),
+                _ => err,
+            })
+    }
+
+    /// `wt -C <dir> <args>` for the remote shell. A leading `~/` stays
+    /// unquoted so the remote shell expands it to the remote home.
+    fn remote_command(&self, dir: &str, args: &[String]) -> String {
+        let dir = match dir.strip_prefix("~/") {
+            Some(rest) => format!("~/{}", escape(rest)),
+            None => escape(dir),
+        };
+        let mut command = format!("{} -C {dir}", self.config.wt());
+        for arg in args {
+            command.push(' ');
+            command.push_str(&escape(arg));
+        }
+        command
+    }
+
+    /// The local shell command: `<ssh> [-t] <host> '<remote command>'`.
+    fn ssh_command(&self, remote_command: &str, tty: bool) -> String {
+        let tty = if tty { " -t" } else { "" };
+        format!(
+            "{}{tty} {} {}",
+            self.config.ssh(),
+            escape(&self.config.host),
+            escape(remote_command)
+        )
+    }
+}
+
+fn escape(s: &str) -> String {
+    shell_escape::unix::escape(s.into()).into_owned()
+}
+
+/// Push `--flag` when `set`.
+fn flag(args: &mut Vec<String>, set: bool, name: &str) {
+    if set {
+        args.push(name.to_string());
+    }
+}
+
+fn format_args(args: &mut Vec<String>, format: SwitchFormat) {
+    if format == SwitchFormat::Json {
+        args.push("--format=json".to_string());
+    }
+}
+
+/// `wt switch --on <name>`: switch to (or create) the worktree on the host.
+///
+/// The local shell stays put — the worktree is on another machine — so the
+/// host's `wt` runs with `--no-cd`. `--execute` runs there, in the worktree,
+/// with the terminal attached.
+pub(crate) fn handle_switch(args: SwitchArgs, yes: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let host = RemoteHost::resolve(&repo, args.on.as_deref().unwrap_or_default())?;
+
+    let mut forwarded = vec!["switch".to_string()];
+    forwarded.extend(args.branch);
+    flag(&mut forwarded, args.create, "--create");
+    if let Some(base) = args.base {
+        forwarded.extend(["--base".to_string(), base]);
+    }
+    flag(&mut forwarded, args.clobber, "--clobber");
+    flag(&mut forwarded, !args.hooks.resolve(), "--no-hooks");
+    flag(&mut forwarded, yes, "--yes");
+    forwarded.push("--no-cd".to_string());
+    format_args(&mut forwarded, args.format);
+    if let Some(execute) = args.execute {
+        forwarded.extend(["--execute".to_string(), execute]);
+        if !args.execute_args.is_empty() {
+            forwarded.push("--".to_string());
+            forwarded.extend(args.execute_args);
+        }
+    }
+    host.run(&host.path, &forwarded)
+}
+
+/// `wt remove --on <name>`: remove worktrees on the host, defaulting to the
+/// current branch's.
+///
+/// Removal runs in the foreground there: a background removal would outlive
+/// the SSH session that started it.
+pub(crate) fn handle_remove(args: RemoveArgs, yes: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let host = RemoteHost::resolve(&repo, args.on.as_deref().unwrap_or_default())?;
+
+    let mut forwarded = vec!["remove".to_string()];
+    if args.branches.is_empty() {
+        forwarded.push(current_branch(&repo)?);
+    } else {
+        forwarded.extend(args.branches);
+    }
+    flag(&mut forwarded, args.no_delete_branch, "--no-delete-branch");
+    flag(&mut forwarded, args.delete_branch, "--delete-branch");
+    flag(&mut forwarded, args.force_delete, "--force-delete");
+    flag(&mut forwarded, args.force, "--force");
+    flag(&mut forwarded, args.reap, "--reap");
+    flag(&mut forwarded, !args.hooks.resolve(), "--no-hooks");
+    flag(&mut forwarded, yes, "--yes");
+    forwarded.push("--foreground".to_string());
+    format_args(&mut forwarded, args.format);
+    host.run(&host.path, &forwarded)
+}
+
+/// `wt merge --on <name>`: merge the current branch's worktree on the host.
+///
+/// The host's `wt merge` acts on the worktree it runs in, so this looks the
+/// branch's worktree up in the host's `wt list` and runs there.
+pub(crate) fn handle_merge(args: MergeArgs, yes: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let host = RemoteHost::resolve(&repo, args.on.as_deref().unwrap_or_default())?;
+    let branch = current_branch(&repo)?;
+    let path = host
+        .list()?
+        .into_iter()
+        .find(|item| item.branch.as_deref() == Some(branch.as_str()))
+        .and_then(|item| item.path)
+        .ok_or_else(|| {
+            anyhow::anyhow!(cformat!(
+                "No worktree for <bold>{branch}</> on <bold>{}</>",
+                host.name
+            ))
+        })?;
+
+    let mut forwarded = vec!["merge".to_string()];
+    forwarded.extend(args.target);
+    flag(&mut forwarded, args.squash, "--squash");
+    flag(&mut forwarded, args.no_squash, "--no-squash");
+    flag(&mut forwarded, args.commit, "--commit");
+    flag(&mut forwarded, args.no_commit, "--no-commit");
+    flag(&mut forwarded, args.rebase, "--rebase");
+    flag(&mut forwarded, args.no_rebase, "--no-rebase");
+    flag(&mut forwarded, args.remove, "--remove");
+    flag(&mut forwarded, args.no_remove, "--no-remove");
+    flag(&mut forwarded, args.ff, "--ff");
+    flag(&mut forwarded, args.no_ff, "--no-ff");
+    flag(&mut forwarded, args.verify, "--verify");
+    flag(
+        &mut forwarded,
+        args.no_hooks || args.no_verify,
+        "--no-hooks",
+    );
+    if let Some(stage) = args.stage {
+        let stage = clap::ValueEnum::to_possible_value(&stage)
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default();
+        forwarded.extend(["--stage".to_string(), stage]);
+    }
+    flag(&mut forwarded, yes, "--yes");
+    format_args(&mut forwarded, args.format);
+    host.run(&path.to_string_lossy(), &forwarded)
+}
+
+/// The local current branch, which names the host's worktree when no branch
+/// is given.
+fn current_branch(repo: &Repository) -> anyhow::Result<String> {
+    repo.current_worktree().branch()?.ok_or_else(|| {
+        anyhow::anyhow!("HEAD is detached; name the branch whose worktree to act on")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host(path: &str) -> RemoteHost {
+        RemoteHost {
+            name: "devbox".to_string(),
+            config: RemoteHostConfig {
+                host: "me@devbox".to_string(),
+                path: path.to_string(),
+                ssh: Some("ssh -p 2222".to_string()),
+                wt: None,
+            },
+            path: path.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_remote_command_keeps_home_unquoted() {
+        let host = host("~/src/my repo");
+        let args = ["switch", "feat's"].map(String::from);
+        assert_eq!(
+            host.remote_command(&host.path, &args),
+            r#"wt -C ~/'src/my repo' switch 'feat'\''s'"#
+        );
+        assert_eq!(
+            host.remote_command("/srv/repo", &[]),
+            "wt -C /srv/repo".to_string()
+        );
+    }
+
+    #[test]
+    fn test_ssh_command_quotes_remote_command_once() {
+        let host = host("/srv/repo");
+        assert_eq!(
+            host.ssh_command("wt -C /srv/repo list", true),
+            "ssh -p 2222 -t 'me@devbox' 'wt -C /srv/repo list'"
+        );
+    }
+}
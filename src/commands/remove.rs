@@ -263,6 +263,12 @@ pub fn handle_remove_command(args: RemoveArgs, yes: bool) -> anyhow::Result<()>
         .context("Failed to load config")
         .and_then(|config| {
             let repo = Repository::current().context("Failed to remove worktree")?;
+            // `--yes` approves the `[container]` section too, so the removed
+            // worktree's container goes with it; otherwise only a saved
+            // approval lets removal run the runtime.
+            if yes {
+                super::container::approve(&repo, true)?;
+            }
 
             // CLI flags override config; otherwise fall through to [remove] delete-branch
             // (defaults to true).
@@ -5,7 +5,7 @@ use anyhow::{Context, bail};
 use color_print::cformat;
 use worktrunk::git::{
     BranchDeletionMode, GitError, IntegrationReason, RefSnapshot, Repository, WorktreeInfo,
-    parse_porcelain_z, parse_untracked_files,
+    parse_untracked_files,
 };
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{eprintln, format_with_gutter, suggest_command, warning_message};
@@ -65,37 +65,6 @@ pub trait RepositoryCliExt {
         worktrees: Option<&[WorktreeInfo]>,
         snapshot: Option<&RefSnapshot>,
     ) -> anyhow::Result<RemovalPlan>;
-
-    /// Refuse the push when target-worktree changes overlap the push range.
-    ///
-    /// Uncommitted changes at paths the push range doesn't touch are left
-    /// alone — the two-tree merge in `advance_target` carries them in place —
-    /// so this only names the files that genuinely conflict, before anything
-    /// moves.
-    ///
-    /// The caller has already established that `target_worktree` exists on disk
-    /// (`MergeContext::prepare` refuses a registered-but-missing worktree), so
-    /// the status read here is free to fail if the directory is gone.
-    ///
-    /// Ignored files are deliberately out of scope — they're absent from the
-    /// `git status --porcelain` read this works from, and matching git there is
-    /// the decision, not an oversight. The module spec in
-    /// `commands/worktree/push.rs` says why.
-    fn ensure_no_target_conflicts(
-        &self,
-        target_worktree: Option<&PathBuf>,
-        target_branch: &str,
-    ) -> anyhow::Result<()>;
-
-    /// Check if HEAD is a linear extension of the target branch.
-    ///
-    /// Returns true when:
-    /// 1. The merge-base equals target's SHA (target hasn't advanced), AND
-    /// 2. There are no merge commits between target and HEAD (history is linear)
-    ///
-    /// This detects branches that have merged the target into themselves — such
-    /// branches need rebasing to linearize history even though merge-base equals target.
-    fn is_rebased_onto(&self, target: &str) -> anyhow::Result<bool>;
 }
 
 impl RepositoryCliExt for Repository {
@@ -444,86 +413,6 @@ impl RepositoryCliExt for Repository {
             branch_checked_out_at,
         })
     }
-
-    fn ensure_no_target_conflicts(
-        &self,
-        target_worktree: Option<&PathBuf>,
-        target_branch: &str,
-    ) -> anyhow::Result<()> {
-        let Some(wt_path) = target_worktree else {
-            return Ok(());
-        };
-
-        // `-uall` lists individual files inside untracked directories — the
-        // default collapses them to a single `dir/` entry, which can never
-        // match a file path in the push range — and, being explicit, it
-        // overrides a user's `status.showUntrackedFiles=no`. `-z` handles
-        // filenames with spaces and renames ("XY path\0" for normal files,
-        // "XY new_path\0old_path\0" for renames/copies).
-        let wt = self.worktree_at(wt_path);
-        let wt_status_output = wt.run_command(&["status", "--porcelain", "-z", "-uall"])?;
-        if wt_status_output.trim().is_empty() {
-            return Ok(());
-        }
-
-        let push_files = self.changed_files(target_branch, "HEAD")?;
-        let wt_files: Vec<String> = parse_porcelain_z(&wt_status_output);
-
-        let overlapping: Vec<String> = push_files
-            .iter()
-            .filter(|f| wt_files.contains(f))
-            .cloned()
-            .collect();
-
-        if !overlapping.is_empty() {
-            return Err(GitError::ConflictingChanges {
-                target_branch: target_branch.to_string(),
-                files: overlapping,
-                worktree_path: wt_path.clone(),
-            }
-            .into());
-        }
-
-        Ok(())
-    }
-
-    fn is_rebased_onto(&self, target: &str) -> anyhow::Result<bool> {
-        // Orphan branches have no common ancestor, so they can't be "rebased onto" target
-        let Some(merge_base) = self.merge_base("HEAD", target)? else {
-            return Ok(false);
-        };
-        // `merge_base` peels an annotated tag to the commit it points at; a bare
-        // `rev-parse` returns the tag object's own SHA. Comparing the two forms
-        // never matches, so an annotated-tag target would always be reported as
-        // needing a rebase — and `wt step rebase <annotated-tag>` would replay
-        // nothing while announcing "Rebased onto <tag>". Peel both sides.
-        let target_sha = self
-            .run_command(&[
-                "rev-parse",
-                "--verify",
-                "--end-of-options",
-                &format!("{target}^{{commit}}"),
-            ])?
-            .trim()
-            .to_string();
-
-        if merge_base != target_sha {
-            return Ok(false); // Target has advanced past merge-base
-        }
-
-        // Check for merge commits — if present, history is not linear
-        let merge_commits = self
-            .run_command(&[
-                "rev-list",
-                "--merges",
-                "--end-of-options",
-                &format!("{}..HEAD", target),
-            ])?
-            .trim()
-            .to_string();
-
-        Ok(merge_commits.is_empty())
-    }
 }
 
 /// Check if the current worktree is the primary worktree (should not be removed).
@@ -641,7 +530,7 @@ pub(crate) fn warn_about_untracked_files(status_output: &str) -> anyhow::Result<
 #[cfg(test)]
 mod tests {
     use super::*;
-    use worktrunk::git::{BranchDeletionOutcome, execute_branch_deletion};
+    use worktrunk::git::{BranchDeletionOutcome, execute_branch_deletion, parse_porcelain_z};
     use worktrunk::testing::TestRepo;
 
     /// A branch-only plan is only a snapshot of topology. If another process
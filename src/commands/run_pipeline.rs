@@ -12,7 +12,8 @@
 //! 2. Open a [`Repository`] from the worktree path in the spec.
 //! 3. Walk steps in order. For each step, expand templates and spawn shell
 //!    children (see Execution model). Abort on the first serial step failure.
-//! 4. Exit. Log files in `.git/wt/logs/` are the only artifacts.
+//! 4. Exit. Log files in `.git/wt/logs/` are the only artifacts, plus a
+//!    `pipeline-failed` notification when `[notify]` asks for one.
 //!
 //! ## Execution model
 //!
@@ -63,7 +64,10 @@ use std::process::{Child, ExitStatus, Stdio};
 use anyhow::Context;
 
 use worktrunk::config::TemplateContext;
+use worktrunk::container::Container;
 use worktrunk::git::{Repository, WorktrunkError};
+use worktrunk::notify::{Notification, notify};
+use worktrunk::sandbox::{SandboxPolicy, sandboxed};
 use worktrunk::shell_exec::{ShellConfig, scrub_git_discovery_env_vars};
 use worktrunk::trace::CommandTrace;
 
@@ -95,6 +99,15 @@ pub fn run_pipeline() -> anyhow::Result<()> {
     fs::create_dir_all(&spec.log_dir)
         .with_context(|| format!("failed to create log directory: {}", spec.log_dir.display()))?;
 
+    let result = run_steps(&spec, &repo);
+    if let Err(err) = &result {
+        notify_failure(&spec, &repo, err);
+    }
+    result
+}
+
+/// Walk the spec's steps in order, aborting on the first failed serial step.
+fn run_steps(spec: &PipelineSpec, repo: &Repository) -> anyhow::Result<()> {
     let mut cmd_index = 0usize;
 
     for step in &spec.steps {
@@ -103,22 +116,46 @@ pub fn run_pipeline() -> anyhow::Result<()> {
                 template,
                 template_name,
                 name,
+                sandbox,
+                container,
             } => {
                 let log_name = command_log_name(name.as_deref(), cmd_index);
-                let log_file = create_command_log(&spec, &log_name)?;
+                let log_file = create_command_log(spec, &log_name)?;
                 let step_ctx = step_context(&spec.context, name.as_deref());
-                let expanded = expand_shell_template(template, &step_ctx, &repo, template_name)?;
+                let expanded = expand_shell_template(template, &step_ctx, repo, template_name)?;
                 let step_json = step_ctx.to_json();
-                let (mut child, mut trace) =
-                    spawn_shell_command(&expanded, &spec.worktree_path, &step_json, log_file)?;
+                let command = sandboxed(
+                    sandbox.as_ref(),
+                    container.as_ref(),
+                    expanded.clone(),
+                    &spec.worktree_path,
+                )?;
+                let (mut child, mut trace) = spawn_shell_command(
+                    &command,
+                    &spec.worktree_path,
+                    &step_json,
+                    log_file,
+                    &trace_label(spec, &log_name),
+                )?;
                 let status = wait_resolving(&mut child, &mut trace, &expanded)?;
                 if !status.success() {
                     return Err(failure_error(&status, name.as_deref().unwrap_or(&expanded)));
                 }
                 cmd_index += 1;
             }
-            PipelineStepSpec::Concurrent { commands } => {
-                run_concurrent_group(commands, &spec, &repo, &mut cmd_index)?;
+            PipelineStepSpec::Concurrent {
+                commands,
+                sandbox,
+                container,
+            } => {
+                run_concurrent_group(
+                    commands,
+                    sandbox.as_ref(),
+                    container.as_ref(),
+                    spec,
+                    repo,
+                    &mut cmd_index,
+                )?;
             }
         }
     }
@@ -126,6 +163,22 @@ pub fn run_pipeline() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Raise the `pipeline-failed` notification for a failed run.
+///
+/// The runner's own stdout/stderr go to a log file nobody watches, so this is
+/// the only place a background failure can surface before the next `wt list`.
+fn notify_failure(spec: &PipelineSpec, repo: &Repository, err: &anyhow::Error) {
+    notify(
+        &repo.user_config().notify,
+        &Notification::pipeline_failed(
+            &spec.hook_type.to_string(),
+            &spec.branch,
+            spec.worktree_path.clone(),
+            &err.to_string(),
+        ),
+    );
+}
+
 /// Build a per-step context, injecting `hook_name` when the step has a name.
 ///
 /// The shared pipeline context has `hook_name` stripped (it varies per step).
@@ -152,6 +205,7 @@ fn spawn_shell_command(
     worktree_path: &Path,
     context_json: &str,
     log_file: fs::File,
+    label: &str,
 ) -> anyhow::Result<(Child, CommandTrace)> {
     let shell = ShellConfig::get()?;
     let log_err = log_file
@@ -161,7 +215,9 @@ fn spawn_shell_command(
     // child is waited on (see `wait_resolving`). The step is fed its own
     // `context_json` on stdin, so mark it stdin-reading — the same command
     // across worktrees isn't a duplicate (different per-worktree input).
-    let mut trace = CommandTrace::new(None, expanded).reads_stdin(true);
+    let mut trace = CommandTrace::new(None, expanded)
+        .reads_stdin(true)
+        .label(Some(label));
     let mut command = shell.command(expanded);
     command
         .current_dir(worktree_path)
@@ -223,6 +279,8 @@ fn wait_resolving(
 /// error semantics).
 fn run_concurrent_group(
     commands: &[super::pipeline_spec::PipelineCommandSpec],
+    sandbox: Option<&SandboxPolicy>,
+    container: Option<&Container>,
     spec: &PipelineSpec,
     repo: &Repository,
     cmd_index: &mut usize,
@@ -243,8 +301,14 @@ fn run_concurrent_group(
             let expanded =
                 expand_shell_template(&cmd.template, &cmd_ctx, repo, &cmd.template_name)?;
             let cmd_json = cmd_ctx.to_json();
-            let (mut child, mut trace) =
-                spawn_shell_command(&expanded, &spec.worktree_path, &cmd_json, log_file)?;
+            let command = sandboxed(sandbox, container, expanded.clone(), &spec.worktree_path)?;
+            let (mut child, mut trace) = spawn_shell_command(
+                &command,
+                &spec.worktree_path,
+                &cmd_json,
+                log_file,
+                &trace_label(spec, &log_name),
+            )?;
             *cmd_index += 1;
 
             if serial {
@@ -282,6 +346,12 @@ fn run_concurrent_group(
     ))
 }
 
+/// Trace label for a step, in the shape foreground hooks use
+/// (`post-start user:server`).
+fn trace_label(spec: &PipelineSpec, log_name: &str) -> String {
+    format!("{} {}:{log_name}", spec.hook_type, spec.source)
+}
+
 /// Derive the log file name for a command.
 ///
 /// Named commands use their name; unnamed commands use `cmd-{index}`.
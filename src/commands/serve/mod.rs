@@ -0,0 +1,390 @@
+//! `wt serve` — worktrunk over JSON-RPC for editor and agent integrations.
+//!
+//! One long-lived process answers what integrations otherwise get by running
+//! `wt list --format=json` over and over. Besides skipping process startup,
+//! the server keeps one [`Repository`] across requests: each request re-reads
+//! refs, worktrees, status and config through [`Repository::refreshed`], but
+//! the SHA-keyed caches behind `wt list`'s costliest probes (merge bases,
+//! merge-tree outcomes, diff stats) stay warm in memory.
+//!
+//! Methods (params are objects; see the `*Params` structs for fields):
+//!
+//! | Method | Does |
+//! |---|---|
+//! | `list` | `wt list --format=json` (schema 2) |
+//! | `switch` | Path of a branch's worktree, creating it when needed |
+//! | `remove` | `wt remove` via [`worktrunk::api`] |
+//! | `merge` | `wt merge` via [`worktrunk::api`] |
+//! | `hooks/show` | `wt hook show --format=json` |
+//! | `vars/get`, `vars/set`, `vars/list` | `wt config state vars` |
+//!
+//! Mutations run hooks unless `hooks: false`. There is no prompt to fall
+//! back on, so project hooks must already be approved.
+//!
+//! The server also sends a `changed` notification (`{"refs": bool,
+//! "worktrees": bool}`) whenever refs or the worktree set change — by the
+//! server's hand or anyone else's; see [`watch`].
+
+pub(super) mod rpc;
+mod watch;
+
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Context;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use worktrunk::api::{
+    ConfiguredHooks, CreateOptions, MergeOptions, RemoveOptions, SkipHooks, Worktrunk,
+};
+use worktrunk::config::Approvals;
+use worktrunk::git::{BranchDeletionMode, Repository};
+use worktrunk::styling::{eprintln, info_message};
+
+use rpc::{Outbox, Request, RpcError};
+
+/// Handle `wt serve`: answer requests on stdin, or on connections to a Unix
+/// socket at `socket`, until the input closes (stdio) or the process is
+/// killed (socket).
+pub(crate) fn handle_serve(socket: Option<PathBuf>) -> anyhow::Result<()> {
+    let repo = Repository::current().context("Failed to start server")?;
+    let git_common_dir = repo.git_common_dir().to_path_buf();
+    let server = Arc::new(Server::new(repo)?);
+
+    let subscribers: Arc<Mutex<Vec<Outbox>>> = Arc::default();
+    {
+        let subscribers = Arc::clone(&subscribers);
+        std::thread::spawn(move || watch::watch(&git_common_dir, &subscribers));
+    }
+
+    match socket {
+        None => {
+            let outbox = Outbox::new(std::io::stdout());
+            subscribers.lock().unwrap().push(outbox.clone());
+            server.serve_connection(std::io::stdin().lock(), &outbox)
+        }
+        Some(path) => serve_socket(&server, &subscribers, &path),
+    }
+}
+
+#[cfg(unix)]
+fn serve_socket(
+    server: &Arc<Server>,
+    subscribers: &Mutex<Vec<Outbox>>,
+    path: &Path,
+) -> anyhow::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+    use std::os::unix::net::UnixListener;
+
+    // A server that was killed leaves its socket behind, and binding over it
+    // fails; anything other than a socket is someone else's file.
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            anyhow::bail!("{} exists and is not a socket", path.display());
+        }
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove stale socket {}", path.display()))?;
+    }
+    let listener = UnixListener::bind(path)
+        .with_context(|| format!("Failed to listen on {}", path.display()))?;
+    eprintln!(
+        "{}",
+        info_message(format!("Listening on {}", path.display()))
+    );
+
+    for stream in listener.incoming() {
+        let stream = stream.context("Failed to accept connection")?;
+        let outbox = Outbox::new(stream.try_clone()?);
+        subscribers.lock().unwrap().push(outbox.clone());
+        let server = Arc::clone(server);
+        std::thread::spawn(move || {
+            let input = std::io::BufReader::new(stream);
+            if let Err(e) = server.serve_connection(input, &outbox) {
+                tracing::debug!("Connection closed: {e:#}");
+            }
+        });
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn serve_socket(
+    _server: &Arc<Server>,
+    _subscribers: &Mutex<Vec<Outbox>>,
+    _path: &Path,
+) -> anyhow::Result<()> {
+    anyhow::bail!("--socket needs Unix domain sockets; use --stdio on this platform")
+}
+
+struct Server {
+    /// The warm repository; see the module docs.
+    repo: Mutex<Repository>,
+    /// Where [`Worktrunk`] handles open — the main worktree, or the bare
+    /// repository, which no operation removes.
+    root: PathBuf,
+}
+
+impl Server {
+    fn new(repo: Repository) -> anyhow::Result<Self> {
+        let root = repo.repo_path()?.to_path_buf();
+        Ok(Self {
+            repo: Mutex::new(repo),
+            root,
+        })
+    }
+
+    /// A repository for one request: fresh reads, warm SHA-keyed caches. The
+    /// request's own cache fills carry into the next one.
+    fn repo(&self) -> Repository {
+        let mut warm = self.repo.lock().unwrap_or_else(|e| e.into_inner());
+        *warm = warm.refreshed();
+        warm.clone()
+    }
+
+    fn worktrunk(&self, hooks: bool) -> anyhow::Result<Worktrunk> {
+        let wt = Worktrunk::open(&self.root)?;
+        Ok(if hooks {
+            wt.with_hooks(ConfiguredHooks::new())
+        } else {
+            wt.with_hooks(SkipHooks)
+        })
+    }
+
+    /// Answer requests until the input ends. Fails only when the peer can't
+    /// be read from or written to.
+    fn serve_connection(&self, input: impl BufRead, outbox: &Outbox) -> anyhow::Result<()> {
+        rpc::serve_lines(input, outbox, |request| self.dispatch(request))
+    }
+
+    fn dispatch(&self, request: &Request) -> Result<Value, RpcError> {
+        match request.method.as_str() {
+            "list" => self.list(request.params()?),
+            "switch" => self.switch(request.params()?),
+            "remove" => self.remove(request.params()?),
+            "merge" => self.merge(request.params()?),
+            "hooks/show" => self.hooks_show(request.params()?),
+            "vars/get" => self.vars_get(request.params()?),
+            "vars/set" => self.vars_set(request.params()?),
+            "vars/list" => self.vars_list(request.params()?),
+            method => Err(RpcError::method_not_found(method)),
+        }
+    }
+
+    fn list(&self, params: ListParams) -> Result<Value, RpcError> {
+        let envelope = crate::commands::list::json_envelope(
+            &self.repo(),
+            params.branches,
+            params.remotes,
+            params.full,
+        )?;
+        Ok(serde_json::to_value(envelope).map_err(anyhow::Error::from)?)
+    }
+
+    /// The branch's worktree, created first when it has none. Unlike `wt
+    /// switch`, nothing changes directory — the client goes to `path`.
+    fn switch(&self, params: SwitchParams) -> Result<Value, RpcError> {
+        if !params.create
+            && let Some(path) = self.repo().usable_worktree_for_branch(&params.branch)?
+        {
+            return Ok(json!({
+                "branch": params.branch,
+                "path": path,
+                "created": false,
+                "warnings": [],
+            }));
+        }
+        let mut options = CreateOptions::new(&params.branch).create_branch(params.create);
+        if let Some(base) = params.base {
+            options = options.base(base);
+        }
+        let created = self.worktrunk(params.hooks)?.create_worktree(&options)?;
+        Ok(json!({
+            "branch": created.branch,
+            "path": created.path,
+            "created": true,
+            "warnings": created.warnings,
+        }))
+    }
+
+    fn remove(&self, params: RemoveParams) -> Result<Value, RpcError> {
+        let options = RemoveOptions::new(&params.branch)
+            .deletion_mode(BranchDeletionMode::from_flags(
+                params.keep_branch,
+                params.force_delete,
+            ))
+            .force(params.force);
+        let removed = self.worktrunk(params.hooks)?.remove_worktree(&options)?;
+        Ok(json!({
+            "branch": params.branch,
+            "path": removed.path,
+            "branch_deleted": removed.branch_deleted,
+            "warnings": removed.warnings,
+        }))
+    }
+
+    fn merge(&self, params: MergeParams) -> Result<Value, RpcError> {
+        let mut options = MergeOptions::new(&params.branch)
+            .rebase(params.rebase)
+            .remove(params.remove);
+        if let Some(target) = params.target {
+            options = options.target(target);
+        }
+        if let Some(message) = params.squash_message {
+            options = options.squash(message);
+        }
+        let merged = self.worktrunk(params.hooks)?.merge(&options)?;
+        Ok(json!({
+            "branch": merged.branch,
+            "target": merged.target,
+            "commit": merged.commit,
+            "squashed": merged.squashed,
+            "rebased": merged.rebased,
+            "removed": merged.removed.map(|removed| json!({
+                "path": removed.path,
+                "branch_deleted": removed.branch_deleted,
+            })),
+            "warnings": merged.warnings,
+        }))
+    }
+
+    fn hooks_show(&self, params: HooksShowParams) -> Result<Value, RpcError> {
+        let filter = params
+            .hook_type
+            .as_deref()
+            .map(crate::cli::parse_hook_type)
+            .transpose()
+            .map_err(|e| RpcError::invalid_params(format!("{e:#}")))?;
+        let repo = self.repo();
+        let project_config = repo
+            .project_config()
+            .context("Failed to load project config")?;
+        let approvals = Approvals::load().context("Failed to load approvals")?;
+        let project_id = repo.project_identifier().ok();
+        let records = crate::commands::hook_show_records(
+            repo.user_config(),
+            project_config,
+            &approvals,
+            project_id.as_deref(),
+            filter,
+            None,
+        )?;
+        Ok(Value::Array(records))
+    }
+
+    fn vars_get(&self, params: VarParams) -> Result<Value, RpcError> {
+        crate::commands::config::validate_vars_key(&params.key)
+            .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+        let repo = self.repo();
+        let value = repo.config_value(&vars_key(&params.branch, &params.key))?;
+        Ok(json!(value))
+    }
+
+    fn vars_set(&self, params: VarSetParams) -> Result<Value, RpcError> {
+        crate::commands::config::validate_vars_key(&params.key)
+            .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+        let repo = self.repo();
+        repo.set_config(&vars_key(&params.branch, &params.key), &params.value)?;
+        Ok(Value::Null)
+    }
+
+    fn vars_list(&self, params: BranchParams) -> Result<Value, RpcError> {
+        Ok(json!(self.repo().vars_entries(&params.branch)))
+    }
+}
+
+fn vars_key(branch: &str, key: &str) -> String {
+    format!("worktrunk.state.{branch}.vars.{key}")
+}
+
+fn yes() -> bool {
+    true
+}
+
+/// `list`: the `wt list` flags of the same names.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ListParams {
+    #[serde(default)]
+    branches: bool,
+    #[serde(default)]
+    remotes: bool,
+    #[serde(default)]
+    full: bool,
+}
+
+/// `switch`: `create` and `base` as for `wt switch --create --base`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SwitchParams {
+    branch: String,
+    #[serde(default)]
+    create: bool,
+    #[serde(default)]
+    base: Option<String>,
+    #[serde(default = "yes")]
+    hooks: bool,
+}
+
+/// `remove`: `force`, `keep_branch` and `force_delete` as for `wt remove
+/// --force`, `--no-delete-branch` and `-D`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RemoveParams {
+    branch: String,
+    #[serde(default)]
+    force: bool,
+    #[serde(default)]
+    keep_branch: bool,
+    #[serde(default)]
+    force_delete: bool,
+    #[serde(default = "yes")]
+    hooks: bool,
+}
+
+/// `merge`: see [`MergeOptions`]. Squashing needs a message, since the
+/// server doesn't generate one.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MergeParams {
+    branch: String,
+    #[serde(default)]
+    target: Option<String>,
+    #[serde(default)]
+    squash_message: Option<String>,
+    #[serde(default = "yes")]
+    rebase: bool,
+    #[serde(default = "yes")]
+    remove: bool,
+    #[serde(default = "yes")]
+    hooks: bool,
+}
+
+/// `hooks/show`: optionally one hook type (`pre-merge`, …).
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct HooksShowParams {
+    #[serde(default)]
+    hook_type: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BranchParams {
+    branch: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VarParams {
+    branch: String,
+    key: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VarSetParams {
+    branch: String,
+    key: String,
+    value: String,
+}
@@ -0,0 +1,247 @@
+//! JSON-RPC 2.0 framing: one message per line, newline-delimited.
+//!
+//! Line framing rather than LSP's `Content-Length` headers: every client
+//! language can read a line, serde_json never emits a raw newline, and the
+//! MCP stdio transport frames the same way — `wt mcp` shares this module.
+//! Batches aren't supported.
+
+use std::io::{BufRead, Write};
+use std::sync::{Arc, Mutex};
+
+use serde_json::{Value, json};
+
+/// Invalid JSON.
+const PARSE_ERROR: i64 = -32700;
+/// Valid JSON, but not a request object.
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// The method ran and failed; `data` carries the failure's kind when typed.
+const OPERATION_FAILED: i64 = -32000;
+
+/// A request, or a notification when `id` is `None`.
+#[derive(Debug)]
+pub(crate) struct Request {
+    pub id: Option<Value>,
+    pub method: String,
+    pub params: Value,
+}
+
+impl Request {
+    /// Parse one line. A malformed line comes back as the error response to
+    /// send — with the request's id when one could be read.
+    pub fn parse(line: &str) -> Result<Self, Value> {
+        let value: Value = serde_json::from_str(line).map_err(|e| {
+            response(
+                Value::Null,
+                Err(RpcError::new(PARSE_ERROR, format!("Parse error: {e}"))),
+            )
+        })?;
+        let id = value.get("id").cloned();
+        let invalid = |message: &str| {
+            response(
+                id.clone().unwrap_or(Value::Null),
+                Err(RpcError::new(INVALID_REQUEST, message)),
+            )
+        };
+        if value.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+            return Err(invalid("Invalid request: jsonrpc must be \"2.0\""));
+        }
+        let Some(method) = value.get("method").and_then(Value::as_str) else {
+            return Err(invalid("Invalid request: method must be a string"));
+        };
+        let params = match value.get("params") {
+            None | Some(Value::Null) => Value::Object(Default::default()),
+            Some(params @ (Value::Object(_) | Value::Array(_))) => params.clone(),
+            Some(_) => return Err(invalid("Invalid request: params must be an object")),
+        };
+        Ok(Self {
+            id,
+            method: method.to_string(),
+            params,
+        })
+    }
+
+    /// Deserialize the params into a method's parameter struct.
+    pub fn params<T: serde::de::DeserializeOwned>(&self) -> Result<T, RpcError> {
+        serde_json::from_value(self.params.clone())
+            .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid params: {e}")))
+    }
+}
+
+/// An error response's `error` member.
+#[derive(Debug)]
+pub(crate) struct RpcError {
+    code: i64,
+    message: String,
+    data: Option<Value>,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(METHOD_NOT_FOUND, format!("Method not found: {method}"))
+    }
+
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(INVALID_PARAMS, message)
+    }
+
+    /// A failed operation. The message is the error chain as `wt` would print
+    /// it, minus styling; `data.kind` names typed failures a client may want
+    /// to branch on (`"UncommittedChanges"`, `"HookCommandFailed"`, …).
+    pub fn operation_failed(error: &anyhow::Error) -> Self {
+        use anstream::adapter::strip_str;
+        use worktrunk::git::{GitError, WorktrunkError};
+
+        let kind = if let Some(e) = error.downcast_ref::<GitError>() {
+            Some(variant_name(e))
+        } else {
+            error.downcast_ref::<WorktrunkError>().map(variant_name)
+        };
+        Self {
+            code: OPERATION_FAILED,
+            message: strip_str(&format!("{error:#}")).to_string(),
+            data: kind.map(|kind| json!({ "kind": kind })),
+        }
+    }
+}
+
+/// The enum variant name from a `Debug` rendering — `Foo { .. }` or `Foo(..)`
+/// gives `Foo`.
+fn variant_name(error: &impl std::fmt::Debug) -> String {
+    let debug = format!("{error:?}");
+    debug
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+impl From<anyhow::Error> for RpcError {
+    fn from(error: anyhow::Error) -> Self {
+        Self::operation_failed(&error)
+    }
+}
+
+/// A response to the request with `id`.
+pub(crate) fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(RpcError {
+            code,
+            message,
+            data,
+        }) => {
+            let mut error = json!({ "code": code, "message": message });
+            if let Some(data) = data {
+                error["data"] = data;
+            }
+            json!({ "jsonrpc": "2.0", "id": id, "error": error })
+        }
+    }
+}
+
+/// A server-to-client notification.
+pub(crate) fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+/// The write half of a connection, shared between the request loop and the
+/// change watcher so their messages never interleave mid-line.
+#[derive(Clone)]
+pub(crate) struct Outbox(Arc<Mutex<Box<dyn Write + Send>>>);
+
+impl Outbox {
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self(Arc::new(Mutex::new(Box::new(writer))))
+    }
+
+    /// Write one message. Fails once the peer has gone away.
+    pub fn send(&self, message: &Value) -> std::io::Result<()> {
+        let mut line = serde_json::to_vec(message)?;
+        line.push(b'\n');
+        let mut writer = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        writer.write_all(&line)?;
+        writer.flush()
+    }
+}
+
+/// Answer requests read from `input` with `dispatch` until the input ends.
+/// Fails only when the peer can't be read from or written to.
+pub(crate) fn serve_lines(
+    input: impl BufRead,
+    outbox: &Outbox,
+    dispatch: impl Fn(&Request) -> Result<Value, RpcError>,
+) -> anyhow::Result<()> {
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let request = match Request::parse(&line) {
+            Ok(request) => request,
+            Err(response) => {
+                outbox.send(&response)?;
+                continue;
+            }
+        };
+        let result = dispatch(&request);
+        // Notifications (no id) get no response, failed or not.
+        if let Some(id) = request.id {
+            outbox.send(&response(id, result))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rejects_malformed_requests() {
+        let err = Request::parse("{not json").unwrap_err();
+        assert_eq!(err["error"]["code"], PARSE_ERROR);
+        assert_eq!(err["id"], Value::Null);
+
+        let err = Request::parse(r#"{"jsonrpc":"1.0","id":7,"method":"list"}"#).unwrap_err();
+        assert_eq!(err["error"]["code"], INVALID_REQUEST);
+        assert_eq!(err["id"], 7);
+
+        let err =
+            Request::parse(r#"{"jsonrpc":"2.0","id":1,"method":"list","params":3}"#).unwrap_err();
+        assert_eq!(err["error"]["code"], INVALID_REQUEST);
+    }
+
+    #[test]
+    fn parse_defaults_missing_params_to_an_object() {
+        let request = Request::parse(r#"{"jsonrpc":"2.0","method":"list"}"#).unwrap();
+        assert_eq!(request.id, None);
+        assert_eq!(request.params, json!({}));
+    }
+
+    #[test]
+    fn operation_failed_names_typed_errors() {
+        let error: anyhow::Error = worktrunk::git::GitError::WorktreeNotFound {
+            branch: "feature".into(),
+        }
+        .into();
+        let typed = response(json!(1), Err(RpcError::operation_failed(&error)));
+        assert_eq!(typed["error"]["code"], OPERATION_FAILED);
+        assert_eq!(typed["error"]["data"]["kind"], "WorktreeNotFound");
+        let message = typed["error"]["message"].as_str().unwrap();
+        assert!(!message.contains('\x1b'), "{message}");
+
+        let error = anyhow::anyhow!("plain");
+        let untyped = response(json!(1), Err(RpcError::operation_failed(&error)));
+        assert!(untyped["error"].get("data").is_none());
+    }
+}
@@ -0,0 +1,122 @@
+//! Change detection for `wt serve` notifications.
+//!
+//! Polls file metadata under the git common dir rather than running git: a
+//! `stat` walk of `refs/` costs microseconds, where `git for-each-ref` every
+//! half second would be the fan-out the server exists to avoid. Anything git
+//! does to a ref or a worktree touches one of these files.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::time::Duration;
+
+use serde_json::json;
+
+use super::rpc::{self, Outbox};
+
+/// How often the watcher checks for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The notification sent when refs or worktrees change.
+pub(super) const CHANGED: &str = "changed";
+
+/// Hashes of the files that move when refs, or the worktree set, change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Fingerprint {
+    refs: u64,
+    worktrees: u64,
+}
+
+impl Fingerprint {
+    pub(super) fn capture(git_common_dir: &Path) -> Self {
+        let mut refs = DefaultHasher::new();
+        for name in ["HEAD", "packed-refs"] {
+            hash_entry(&git_common_dir.join(name), &mut refs);
+        }
+        for dir in ["refs", "reftable"] {
+            hash_tree(&git_common_dir.join(dir), &mut refs);
+        }
+
+        // Each linked worktree's admin dir holds its HEAD, `gitdir` (where it
+        // lives) and `locked`; an add or prune adds or drops the dir.
+        let mut worktrees = DefaultHasher::new();
+        hash_tree(&git_common_dir.join("worktrees"), &mut worktrees);
+
+        Self {
+            refs: refs.finish(),
+            worktrees: worktrees.finish(),
+        }
+    }
+}
+
+/// Poll for changes and notify every connection still listening, dropping
+/// the ones that have gone away. Runs for the life of the server.
+pub(super) fn watch(git_common_dir: &Path, subscribers: &std::sync::Mutex<Vec<Outbox>>) {
+    let mut last = Fingerprint::capture(git_common_dir);
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let current = Fingerprint::capture(git_common_dir);
+        if current == last {
+            continue;
+        }
+        let message = rpc::notification(
+            CHANGED,
+            json!({
+                "refs": current.refs != last.refs,
+                "worktrees": current.worktrees != last.worktrees,
+            }),
+        );
+        last = current;
+
+        let mut subscribers = subscribers.lock().unwrap_or_else(|e| e.into_inner());
+        subscribers.retain(|outbox| outbox.send(&message).is_ok());
+    }
+}
+
+/// Hash a file's path, length and mtime; a missing file hashes as absent.
+fn hash_entry(path: &Path, hasher: &mut DefaultHasher) {
+    path.hash(hasher);
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        metadata.len().hash(hasher);
+        metadata.modified().ok().hash(hasher);
+    }
+}
+
+/// Hash every file below `dir`, in sorted order so the walk is stable.
+fn hash_tree(dir: &Path, hasher: &mut DefaultHasher) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut paths: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            hash_tree(&path, hasher);
+        } else {
+            hash_entry(&path, hasher);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use worktrunk::testing::TestRepo;
+
+    #[test]
+    fn fingerprint_tracks_refs_and_worktrees_separately() {
+        let mut test = TestRepo::with_initial_commit();
+        let common_dir = test.root_path().join(".git");
+        let before = Fingerprint::capture(&common_dir);
+        assert_eq!(Fingerprint::capture(&common_dir), before);
+
+        test.run_git(&["branch", "topic"]);
+        let after_branch = Fingerprint::capture(&common_dir);
+        assert_ne!(after_branch.refs, before.refs);
+        assert_eq!(after_branch.worktrees, before.worktrees);
+
+        test.add_worktree("feature");
+        let after_worktree = Fingerprint::capture(&common_dir);
+        assert_ne!(after_worktree.worktrees, after_branch.worktrees);
+    }
+}
@@ -24,6 +24,7 @@ use dunce::canonicalize;
 
 use ansi_str::AnsiStr;
 use anyhow::{Context, Result};
+use worktrunk::activity::Activity;
 use worktrunk::git::{Repository, WorkingTree};
 use worktrunk::styling::{
     ColorChoice, fix_dim_after_color_reset, println, terminal_width_for_statusline,
@@ -38,6 +39,8 @@ use crate::output::print_json;
 struct ClaudeCodeContext {
     /// Working directory from `.workspace.current_dir`
     current_dir: String,
+    /// Session ID from `.session_id`, for the agent activity heartbeat
+    session_id: Option<String>,
     /// Model name from `.model.display_name`
     model_name: Option<String>,
     /// Context window usage percentage from `.context_window.used_percentage`
@@ -74,6 +77,12 @@ impl ClaudeCodeContext {
             .and_then(|v| v.as_str())?
             .to_string();
 
+        let session_id = v
+            .get("session_id")
+            .and_then(|v| v.as_str())
+            .filter(|id| !id.is_empty())
+            .map(String::from);
+
         let model_name = v
             .pointer("/model/display_name")
             .and_then(|v| v.as_str())
@@ -87,6 +96,7 @@ impl ClaudeCodeContext {
 
         Some(Self {
             current_dir,
+            session_id,
             model_name,
             context_used_percentage,
             rate_limits,
@@ -748,19 +758,29 @@ pub fn run(format: StatuslineFormat) -> Result<()> {
     let claude_code = matches!(format, StatuslineFormat::ClaudeCode);
 
     // Get context from stdin (claude-code mode only)
-    let (workspace_dir, model_name, context_used_percentage, rate_limits) = if claude_code {
-        let ctx = ClaudeCodeContext::from_stdin();
-        let dir = ctx.as_ref().map(|c| PathBuf::from(&c.current_dir));
-        let model = ctx.as_ref().and_then(|c| c.model_name.clone());
-        let context_pct = ctx.as_ref().and_then(|c| c.context_used_percentage);
-        let limits = ctx.map(|c| c.rate_limits).unwrap_or_default();
-        (dir, model, context_pct, limits)
-    } else {
-        (None, None, None, Vec::new())
-    };
+    let (workspace_dir, session_id, model_name, context_used_percentage, rate_limits) =
+        if claude_code {
+            let ctx = ClaudeCodeContext::from_stdin();
+            let dir = ctx.as_ref().map(|c| PathBuf::from(&c.current_dir));
+            let session_id = ctx.as_ref().and_then(|c| c.session_id.clone());
+            let model = ctx.as_ref().and_then(|c| c.model_name.clone());
+            let context_pct = ctx.as_ref().and_then(|c| c.context_used_percentage);
+            let limits = ctx.map(|c| c.rate_limits).unwrap_or_default();
+            (dir, session_id, model, context_pct, limits)
+        } else {
+            (None, None, None, None, Vec::new())
+        };
 
     let repo = Repository::current().ok();
 
+    // Claude Code redraws the statusline throughout a live session, so each
+    // render doubles as a heartbeat for the session the plugin's hooks
+    // recorded (`wt config state activity`). Rate-limited and never creates a
+    // record.
+    if let (Some(repo), Some(session_id)) = (&repo, &session_id) {
+        Activity::for_repo(repo).heartbeat("claude", session_id, worktrunk::utils::epoch_now());
+    }
+
     // The worktree this statusline describes. Claude Code names its session's
     // directory on stdin, which wins; every other caller is described by the
     // directory `wt` itself was pointed at, which is `current_worktree()` and
@@ -873,6 +893,7 @@ fn statusline_options(repo: &Repository) -> CollectOptions {
         summary_enabled: false,
         has_llm_command: false,
         has_url_template: url_template.is_some(),
+        has_container: false,
     };
     CollectOptions {
         url_template,
@@ -1131,6 +1152,7 @@ mod tests {
 
         let ctx = ClaudeCodeContext::parse(json).expect("should parse");
         assert_eq!(ctx.current_dir, "/home/user/project");
+        assert_eq!(ctx.session_id.as_deref(), Some("abc123"));
         assert_eq!(ctx.model_name, Some("Opus".to_string()));
     }
 
@@ -1,16 +1,20 @@
 //! `wt step copy-ignored` — copy gitignored files matching `.worktreeinclude`.
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use color_print::cformat;
-use worktrunk::copy::{copy_dir_recursive, copy_leaf};
+use worktrunk::copy::{
+    LeafSync, SyncOptions, SyncStats, copy_dir_recursive, copy_leaf, sync_dir_recursive, sync_leaf,
+};
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
 use worktrunk::progress::{Progress, format_bytes};
+use worktrunk::store::Store;
 use worktrunk::styling::{
     eprintln, format_with_gutter, hint_message, info_message, println, success_message, verbosity,
+    warning_message,
 };
 
 use crate::output::print_json;
@@ -24,11 +28,19 @@ use super::shared::{list_and_filter_ignored_entries, resolve_copy_ignored_config
 /// and gitignore patterns are copied. Without `.worktreeinclude`, all gitignored
 /// files are copied. Uses COW (reflink) when available for efficient copying of
 /// large directories like `target/`.
+///
+/// With `sync`, existing destinations are compared and only changed files are
+/// rewritten (see [`sync_entries`]); `sync` carries its own dry-run flag. With
+/// `store` (or `[step.copy-ignored] store`), files are hardlinked from the
+/// repository's shared [`Store`] instead of copied.
+#[allow(clippy::too_many_arguments)]
 pub fn step_copy_ignored(
     from: Option<&str>,
     to: Option<&str>,
     dry_run: bool,
     force: bool,
+    sync: Option<SyncOptions>,
+    store: bool,
     require_include: bool,
     format: crate::cli::SwitchFormat,
 ) -> anyhow::Result<()> {
@@ -88,6 +100,18 @@ pub fn step_copy_ignored(
         return Ok(());
     }
 
+    // LFS files are tracked, not ignored, so nothing below copies them; a
+    // destination checked out as pointers (created outside `wt switch`) is
+    // hydrated from the shared `.git/lfs` cache instead.
+    let previewing = dry_run || sync.as_ref().is_some_and(|options| options.dry_run);
+    if !previewing && worktrunk::git::lfs::uses_lfs(&repo) {
+        worktrunk::lifecycle::hydrate_lfs(&repo, &dest_path, &mut |notice| {
+            if !json_mode {
+                notice.print();
+            }
+        });
+    }
+
     let worktree_paths: Vec<PathBuf> = repo
         .list_worktrees()?
         .iter()
@@ -128,12 +152,30 @@ pub fn step_copy_ignored(
         return Ok(());
     }
 
-    let entries_to_copy = list_and_filter_ignored_entries(
+    let mut entries_to_copy = list_and_filter_ignored_entries(
         &source_path,
         &source_context,
         &worktree_paths,
         &copy_ignored_config.exclude,
     )?;
+    // Git's listing stops at submodule boundaries; descend into each
+    // submodule initialized on both sides. Entries stay absolute, so they
+    // land at the same relative path in the destination.
+    let submodules = worktrunk::git::submodule::initialized(&source_path).unwrap_or_else(|err| {
+        log::debug!("Skipping submodules of {}: {err:#}", source_path.display());
+        Vec::new()
+    });
+    for submodule in submodules {
+        if !dest_path.join(&submodule).join(".git").exists() {
+            continue;
+        }
+        entries_to_copy.extend(list_and_filter_ignored_entries(
+            &source_path.join(&submodule),
+            &source_context,
+            &worktree_paths,
+            &copy_ignored_config.exclude,
+        )?);
+    }
 
     if entries_to_copy.is_empty() {
         if json_mode {
@@ -155,6 +197,26 @@ pub fn step_copy_ignored(
 
     let verbose = verbosity();
 
+    if let Some(options) = sync {
+        // `--store` conflicts with `--sync` on the command line; the config
+        // default can't, so say that it doesn't apply.
+        if copy_ignored_config.store() {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "<bold>[step.copy-ignored] store</> doesn't apply to <bold>--sync</>; syncing with plain copies"
+                ))
+            );
+        }
+        return sync_entries(
+            &entries_to_copy,
+            &source_path,
+            &dest_path,
+            options,
+            json_mode,
+        );
+    }
+
     if dry_run {
         if json_mode {
             let entries: Vec<_> = entries_to_copy
@@ -235,6 +297,8 @@ pub fn step_copy_ignored(
         Progress::start("Copying")
     };
 
+    let store = (store || copy_ignored_config.store()).then(|| Store::for_repo(&repo));
+
     for (src_entry, is_dir) in &entries_to_copy {
         let relative = src_entry
             .strip_prefix(&source_path)
@@ -243,11 +307,19 @@ pub fn step_copy_ignored(
 
         if *is_dir {
             // A pure copy deletes no source, so the skip count has nothing to guard.
-            let _skipped =
-                copy_dir_recursive(src_entry, &dest_entry, Some(&dest_path), force, &progress)
-                    .with_context(|| {
-                        format!("copying directory {}", format_path_for_display(relative))
-                    })?;
+            let _skipped = match &store {
+                Some(store) => store.link_dir_recursive(
+                    src_entry,
+                    &dest_entry,
+                    Some(&dest_path),
+                    force,
+                    &progress,
+                ),
+                None => {
+                    copy_dir_recursive(src_entry, &dest_entry, Some(&dest_path), force, &progress)
+                }
+            }
+            .with_context(|| format!("copying directory {}", format_path_for_display(relative)))?;
         } else {
             if let Some(parent) = dest_entry.parent() {
                 fs::create_dir_all(parent).with_context(|| {
@@ -257,7 +329,11 @@ pub fn step_copy_ignored(
                     )
                 })?;
             }
-            if let Some(bytes) = copy_leaf(src_entry, &dest_entry, Some(&dest_path), force)? {
+            let copied = match &store {
+                Some(store) => store.link_leaf(src_entry, &dest_entry, Some(&dest_path), force)?,
+                None => copy_leaf(src_entry, &dest_entry, Some(&dest_path), force)?,
+            };
+            if let Some(bytes) = copied {
                 progress.record(bytes);
             }
         }
@@ -281,7 +357,7 @@ pub fn step_copy_ignored(
                 })
             })
             .collect();
-        let payload = serde_json::json!({
+        let mut payload = serde_json::json!({
             "outcome": "copied",
             "dry_run": false,
             "from": source_path,
@@ -290,18 +366,187 @@ pub fn step_copy_ignored(
             "files": copied_count,
             "bytes": copied_bytes,
         });
+        // With the store, `stored_bytes` is the new content it took on; the
+        // rest of `bytes` arrived as links to existing objects.
+        if let Some(store) = &store {
+            payload["stored_bytes"] = store.added_bytes().into();
+        }
         print_json(&payload)?;
     } else {
         // Show summary
         let file_word = if copied_count == 1 { "file" } else { "files" };
-        eprintln!(
-            "{}",
-            success_message(format!(
+        let message = match &store {
+            Some(store) => format!(
+                "Linked {copied_count} {file_word} · {} ({} new in store)",
+                format_bytes(copied_bytes),
+                format_bytes(store.added_bytes())
+            ),
+            None => format!(
                 "Copied {copied_count} {file_word} · {}",
                 format_bytes(copied_bytes)
+            ),
+        };
+        eprintln!("{}", success_message(message));
+    }
+
+    Ok(())
+}
+
+/// `--sync`: bring each selected entry up to date, reporting per-entry deltas.
+///
+/// The dry run walks and compares exactly like the real sync, so its plan
+/// carries the same counts; like the copy preview it goes to stdout.
+fn sync_entries(
+    entries: &[(PathBuf, bool)],
+    source_path: &Path,
+    dest_path: &Path,
+    options: SyncOptions,
+    json_mode: bool,
+) -> anyhow::Result<()> {
+    let verbose = verbosity();
+    let progress = if options.dry_run || verbose >= 1 || json_mode {
+        Progress::disabled()
+    } else {
+        Progress::start("Syncing")
+    };
+
+    let mut total = SyncStats::default();
+    let mut per_entry = Vec::with_capacity(entries.len());
+    for (src_entry, is_dir) in entries {
+        let relative = src_entry
+            .strip_prefix(source_path)
+            .unwrap_or(src_entry.as_path());
+        let dest_entry = dest_path.join(relative);
+
+        let stats = if *is_dir {
+            sync_dir_recursive(src_entry, &dest_entry, Some(dest_path), options, &progress)
+                .with_context(|| {
+                    format!("syncing directory {}", format_path_for_display(relative))
+                })?
+        } else {
+            if !options.dry_run
+                && let Some(parent) = dest_entry.parent()
+            {
+                fs::create_dir_all(parent).with_context(|| {
+                    format!(
+                        "creating directory for {}",
+                        format_path_for_display(relative)
+                    )
+                })?;
+            }
+            let outcome = sync_leaf(
+                src_entry,
+                &dest_entry,
+                Some(dest_path),
+                options.compare,
+                options.dry_run,
+            )?;
+            if let LeafSync::Created(bytes) | LeafSync::Updated(bytes) = outcome {
+                progress.record(bytes);
+            }
+            let mut stats = SyncStats::default();
+            stats.record(outcome);
+            stats
+        };
+        total.merge(stats);
+        per_entry.push((relative, *is_dir, stats));
+    }
+    progress.finish();
+
+    if json_mode {
+        let entries: Vec<_> = per_entry
+            .iter()
+            .map(|(relative, is_dir, stats)| {
+                serde_json::json!({
+                    "path": relative,
+                    "kind": if *is_dir { "dir" } else { "file" },
+                    "created": stats.created,
+                    "updated": stats.updated,
+                    "unchanged": stats.unchanged,
+                    "deleted": stats.deleted,
+                    "bytes": stats.bytes,
+                })
+            })
+            .collect();
+        let payload = serde_json::json!({
+            "outcome": if options.dry_run { "planned" } else { "synced" },
+            "dry_run": options.dry_run,
+            "from": source_path,
+            "to": dest_path,
+            "entries": entries,
+            "files": total.changed(),
+            "bytes": total.bytes,
+            "created": total.created,
+            "updated": total.updated,
+            "unchanged": total.unchanged,
+            "deleted": total.deleted,
+        });
+        return print_json(&payload);
+    }
+
+    let items: Vec<String> = per_entry
+        .iter()
+        .map(|(relative, is_dir, stats)| {
+            let entry_type = if *is_dir { "dir" } else { "file" };
+            format!(
+                "{} ({entry_type}): {}",
+                format_path_for_display(relative),
+                describe_sync(stats)
+            )
+        })
+        .collect();
+    let entry_word = if items.len() == 1 { "entry" } else { "entries" };
+
+    if options.dry_run {
+        println!(
+            "{}",
+            info_message(format!(
+                "Would sync {} {entry_word}:\n{}",
+                items.len(),
+                format_with_gutter(&items.join("\n"), None)
             ))
         );
+        return Ok(());
     }
 
+    if verbose >= 1 {
+        eprintln!(
+            "{}",
+            info_message(format!(
+                "Synced {} {entry_word}:\n{}",
+                items.len(),
+                format_with_gutter(&items.join("\n"), None)
+            ))
+        );
+    }
+    let changed = total.changed();
+    let file_word = if changed == 1 { "file" } else { "files" };
+    eprintln!(
+        "{}",
+        success_message(format!(
+            "Synced {changed} {file_word} · {} ({})",
+            format_bytes(total.bytes),
+            describe_sync(&total)
+        ))
+    );
     Ok(())
 }
+
+/// `3 new, 2 updated, 10 unchanged, 1 deleted`, omitting zero counts.
+fn describe_sync(stats: &SyncStats) -> String {
+    let parts: Vec<String> = [
+        (stats.created, "new"),
+        (stats.updated, "updated"),
+        (stats.unchanged, "unchanged"),
+        (stats.deleted, "deleted"),
+    ]
+    .into_iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, label)| format!("{count} {label}"))
+    .collect();
+    if parts.is_empty() {
+        "empty".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
@@ -31,9 +31,18 @@ pub fn step_diff(
         .merge_base("HEAD", &integration_target)?
         .context("No common ancestor with target branch")?;
 
+    // Submodule changes render as their content diff rather than a pair of
+    // commit hashes, unless the user chose a `diff.submodule` format. Ahead
+    // of `extra_args`, so a `--submodule=…` there still wins.
+    let mut args = Vec::with_capacity(extra_args.len() + 1);
+    if repo.config_value("diff.submodule")?.is_none() {
+        args.push("--submodule=diff".to_string());
+    }
+    args.extend_from_slice(extra_args);
+
     // Stream diff to stdout — git handles pager and coloring.
     wt.prepare_diff_with_untracked([merge_base])?
-        .stream(extra_args)?;
+        .stream(&args)?;
 
     Ok(())
 }
@@ -4,9 +4,9 @@
 //! stream results to the main thread, which queues per-candidate jobs
 //! (removals and skip lines) in scan-completion order onto a worker pool
 //! sized like rayon's ([`RemovalJob`]). Checks and hook-free removals hold
-//! the read side of [`RemovalContext::check_lock`] and run concurrently; the
+//! the read side of [`RemovalLocks::check_lock`] and run concurrently; the
 //! exceptional removals serialize on the write side
-//! ([`removal_needs_write`]). A second lock, [`RemovalContext::registry_lock`],
+//! ([`removal_needs_write`]). A second lock, [`RemovalLocks::registry_lock`],
 //! serializes worktree-registry teardowns (`git worktree remove`) against each
 //! other and against concurrent registry reads ([`removal_mutates_registry`]) —
 //! a git-level TOCTOU on `.git/worktrees/` that the two-locks split keeps out
@@ -21,8 +21,8 @@
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::RwLock;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::time::Duration;
 
 use anyhow::Context;
@@ -235,6 +235,14 @@ struct RemovalContext<'a> {
     repo: &'a Repository,
     foreground: bool,
     hook_plan: &'a ApprovedHookPlan,
+    locks: &'a RemovalLocks,
+}
+
+/// The two locks that let removals run concurrently without tripping over each
+/// other or over git. Shared by prune's removal workers and the picker's bulk
+/// removal queue (`picker::AltXRemover`), which face the same races.
+#[derive(Default)]
+pub(crate) struct RemovalLocks {
     /// Coordinates the parallel workers (scan checks and removals, both on
     /// the read side) against the few removals that need exclusivity (write
     /// side — see [`removal_needs_write`]).
@@ -254,7 +262,7 @@ struct RemovalContext<'a> {
     /// force arm, which prune never uses, and its snapshot-miss arm,
     /// unreachable here because the chain captures the snapshot immediately
     /// before consulting it.)
-    check_lock: &'a RwLock<()>,
+    pub(crate) check_lock: RwLock<()>,
     /// Serializes access to the worktree registry (`.git/worktrees/`),
     /// independently of `check_lock`.
     ///
@@ -273,10 +281,51 @@ struct RemovalContext<'a> {
     /// `list_worktrees` snapshot), so it must keep overlapping removals rather
     /// than serialize behind them. Removals acquire `check_lock` first, then
     /// `registry_lock`, so the two never deadlock.
-    registry_lock: &'a RwLock<()>,
+    pub(crate) registry_lock: RwLock<()>,
+}
+
+/// Guards held for the duration of one removal (see [`RemovalLocks::acquire`]):
+/// one side of each lock. Only held, never read.
+pub(crate) struct RemovalGuards<'a> {
+    _check: LockSide<'a>,
+    _registry: LockSide<'a>,
+}
+
+/// One side of an `RwLock<()>` — exactly one of the two is `Some`.
+type LockSide<'a> = (
+    Option<RwLockReadGuard<'a, ()>>,
+    Option<RwLockWriteGuard<'a, ()>>,
+);
+
+/// The write side of `lock` when `write`, else the read side. The guards
+/// protect `()` — there is no shared state to corrupt, so a poisoned lock is
+/// meaningless here. Recover the guard rather than `.expect()`-ing: a panic
+/// elsewhere should surface as itself, not as a cascade of secondary poison
+/// panics on every later removal/reader.
+fn lock_side(lock: &RwLock<()>, write: bool) -> LockSide<'_> {
+    if write {
+        (None, Some(lock.write().unwrap_or_else(|e| e.into_inner())))
+    } else {
+        (Some(lock.read().unwrap_or_else(|e| e.into_inner())), None)
+    }
 }
 
-/// Which removals must hold the write side of [`RemovalContext::check_lock`]
+impl RemovalLocks {
+    /// Take the guards for executing `plan`: the write side of `check_lock`
+    /// when `exclusive` (see [`removal_needs_write`]), the read side
+    /// otherwise; then the side of `registry_lock` [`removal_mutates_registry`]
+    /// calls for. Always in that order (fixed order → no deadlock).
+    pub(crate) fn acquire(&self, exclusive: bool, plan: &RemovalPlan) -> RemovalGuards<'_> {
+        let check = lock_side(&self.check_lock, exclusive);
+        let registry = lock_side(&self.registry_lock, removal_mutates_registry(plan));
+        RemovalGuards {
+            _check: check,
+            _registry: registry,
+        }
+    }
+}
+
+/// Which removals must hold the write side of [`RemovalLocks::check_lock`]
 /// instead of joining the parallel (read-side) fan-out:
 ///
 /// - **Hook-bearing worktree removals** — the `pre-remove` body runs
@@ -302,7 +351,7 @@ struct RemovalContext<'a> {
 /// `BranchOnly` plan carries as `prune_entry`. Naming one entry bounds what
 /// each *deletes* but not what `git worktree remove` *reads* (it enumerates
 /// every sibling), so those teardowns are not safe to overlap — that is
-/// [`RemovalContext::registry_lock`]'s job, orthogonal to `check_lock`: they
+/// [`RemovalLocks::registry_lock`]'s job, orthogonal to `check_lock`: they
 /// take its write side via [`removal_mutates_registry`] and so serialize
 /// against each other and against the scan's registry reads. See the
 /// concurrency section on
@@ -326,7 +375,7 @@ fn removal_needs_write(kind: CandidateKind, plan: &RemovalPlan, ctx: &RemovalCon
 
 /// Whether a removal's execution unregisters a worktree entry — a `git worktree
 /// remove` teardown, which enumerates every sibling's `commondir` and so must
-/// hold the write side of [`RemovalContext::registry_lock`].
+/// hold the write side of [`RemovalLocks::registry_lock`].
 ///
 /// A `Worktree` plan always tears down (either the rename fast path's scoped
 /// prune or the direct `git worktree remove` fallback); a `BranchOnly` plan
@@ -365,12 +414,20 @@ fn try_remove(
     if matches!(candidate.kind, CandidateKind::StaleDetached) {
         // Output side: no exclusive output here (no spinner, no hook stream),
         // so join the parallel read side of `check_lock`.
-        let _read = ctx.check_lock.read().unwrap_or_else(|e| e.into_inner());
+        let _read = ctx
+            .locks
+            .check_lock
+            .read()
+            .unwrap_or_else(|e| e.into_inner());
         // Registry side: `git worktree remove` on this stale entry is a
         // teardown that enumerates every sibling's `commondir`, so hold the
         // write side of `registry_lock` — no concurrent teardown or registry
         // read may overlap it (issue #3661).
-        let _registry = ctx.registry_lock.write().unwrap_or_else(|e| e.into_inner());
+        let _registry = ctx
+            .locks
+            .registry_lock
+            .write()
+            .unwrap_or_else(|e| e.into_inner());
         // Name the stale entry rather than sweeping the repository, so a
         // sibling whose directory is merely absent right now (unmounted
         // volume, half-finished `mv`) keeps its registration. `gather_check_items`
@@ -386,40 +443,16 @@ fn try_remove(
     }
 
     let plan = plan.context("candidate arrived without a removal plan")?;
-    // Read side for the parallel default, write side for the exclusive cases
-    // (see `removal_needs_write`). The guards protect `()` — there is no
-    // shared state to corrupt, so a poisoned lock is meaningless here.
-    // Recover the guard rather than `.expect()`-ing: a panic elsewhere should
-    // surface as itself, not as a cascade of secondary poison panics on every
-    // later removal/reader.
-    let (_read, _write) = if removal_needs_write(candidate.kind, &plan, ctx) {
-        (
-            None,
-            Some(ctx.check_lock.write().unwrap_or_else(|e| e.into_inner())),
-        )
-    } else {
-        (
-            Some(ctx.check_lock.read().unwrap_or_else(|e| e.into_inner())),
-            None,
-        )
-    };
-    // Registry side, acquired *after* `check_lock` (fixed order → no deadlock).
-    // A removal that unregisters a worktree entry (`git worktree remove`) takes
-    // the write side so it can't overlap another teardown's `commondir` read or
-    // a branch delete's `list_worktrees` probe; one that only reads the registry
-    // takes the read side and keeps running concurrently. See `registry_lock`'s
-    // spec and `removal_mutates_registry` (issue #3661).
-    let (_registry_read, _registry_write) = if removal_mutates_registry(&plan) {
-        (
-            None,
-            Some(ctx.registry_lock.write().unwrap_or_else(|e| e.into_inner())),
-        )
-    } else {
-        (
-            Some(ctx.registry_lock.read().unwrap_or_else(|e| e.into_inner())),
-            None,
-        )
-    };
+    // Read side of `check_lock` for the parallel default, write side for the
+    // exclusive cases (see `removal_needs_write`); then `registry_lock`'s
+    // write side for a removal that unregisters a worktree entry (`git
+    // worktree remove`), so it can't overlap another teardown's `commondir`
+    // read or a branch delete's `list_worktrees` probe, and its read side
+    // otherwise. See `registry_lock`'s spec and `removal_mutates_registry`
+    // (issue #3661).
+    let _guards = ctx
+        .locks
+        .acquire(removal_needs_write(candidate.kind, &plan, ctx), &plan);
 
     let mut announcer = HookAnnouncer::new(ctx.repo, true);
     // `SynchronousForNonCurrent`: a rename-failure fallback completes inline,
@@ -1135,14 +1168,12 @@ pub fn step_prune(
         .and_then(|p| std::fs::read(p).ok());
     let mut skipped_approval: Vec<SkippedApproval> = Vec::new();
 
-    let check_lock = RwLock::new(());
-    let registry_lock = RwLock::new(());
+    let locks = RemovalLocks::default();
     let removal_ctx = RemovalContext {
         repo: &repo,
         foreground,
         hook_plan: &hook_plan,
-        check_lock: &check_lock,
-        registry_lock: &registry_lock,
+        locks: &locks,
     };
     // Flipped by the first failing removal: the rest of the queue drains
     // without executing (matching the serial loop's abort-on-first-error),
@@ -1168,7 +1199,7 @@ pub fn step_prune(
             let check_items_ref = &check_items;
             let integration_target_ref = integration_target.as_str();
             let current_path_ref = current_root.as_path();
-            let check_lock_ref = &check_lock;
+            let check_lock_ref = &locks.check_lock;
             s.spawn(move || {
                 check_items_ref
                     .par_iter()
@@ -1231,6 +1262,7 @@ pub fn step_prune(
                                 // exclusive output window (spinner, hook
                                 // stream).
                                 let _read = removal_ctx_ref
+                                    .locks
                                     .check_lock
                                     .read()
                                     .unwrap_or_else(|e| e.into_inner());
@@ -5,8 +5,6 @@ use color_print::cformat;
 use worktrunk::git::{ErrorExt, InProgressOperation, Repository};
 use worktrunk::styling::{eprintln, progress_message, success_message};
 
-use super::super::repository_ext::RepositoryCliExt;
-
 /// Result of a rebase operation
 pub enum RebaseResult {
     /// Rebase occurred. `fast_forward` distinguishes the two flavors.
@@ -0,0 +1,484 @@
+//! `wt step resolve` — LLM-assisted resolution of a stopped rebase.
+//!
+//! `wt step rebase` (and `wt merge`, which runs it) leaves a conflicting
+//! rebase open. This step resolves it one conflicted file at a time: the
+//! base, target, and branch versions of the file go to the configured LLM
+//! along with the commits involved, the proposal is written into the
+//! worktree, and git's combined diff of it is shown for approval. Approved
+//! files are staged; once none remain, the rebase continues — and if the next
+//! commit conflicts too, the loop starts over.
+//!
+//! Nothing is staged or committed without a yes: interactively per file, or
+//! for the whole run with `--yes`. A declined proposal puts the conflicted
+//! file back exactly as git left it.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use color_print::cformat;
+use minijinja::Environment;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{
+    CommandError, ErrorExt, GitError, InProgressOperation, WorkingTree, WorktrunkError,
+};
+use worktrunk::styling::{
+    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+    warning_message,
+};
+
+use super::super::context::CommandEnv;
+use crate::cli::SwitchFormat;
+use crate::llm::{execute_llm_command, watch_llm_command};
+use crate::output::print_json;
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
+
+/// Byte budget for the three versions of a file sent in one prompt. Larger
+/// files are left for manual resolution: the model has to echo the whole
+/// file back, and a truncated echo would silently drop content.
+const RESOLVE_BUDGET: usize = 100_000;
+
+/// Target-side commits listed per file for context.
+const MAX_TARGET_COMMITS: usize = 10;
+
+/// Template for conflict resolution prompts.
+///
+/// In a rebase, git's "ours" is the target (plus the commits already
+/// replayed) and "theirs" is the branch commit being replayed, so the prompt
+/// names them by role instead.
+const RESOLVE_TEMPLATE: &str = r#"<task>Resolve the conflict in {{ path }} left by a rebase. Output the complete resolved file.</task>
+
+<format>
+- Output only the file content — no explanations, quotes, or code fences
+- Remove every conflict marker
+- Keep the changes from both sides; where they cannot both hold, apply the branch's intent on top of the target
+</format>
+
+<branch_commit>
+{{ branch_commit }}
+</branch_commit>
+{% if target_commits %}
+<target_commits>
+{% for commit in target_commits %}- {{ commit }}
+{% endfor %}</target_commits>
+{% endif %}
+<base>
+{{ base }}
+</base>
+
+<target>
+{{ target }}
+</target>
+
+<branch>
+{{ branch }}
+</branch>
+
+<conflicted>
+{{ conflicted }}
+</conflicted>
+"#;
+
+/// The three versions of a conflicted file plus the commits that produced them.
+struct ConflictInput<'a> {
+    path: &'a str,
+    base: &'a str,
+    target: &'a str,
+    branch: &'a str,
+    conflicted: &'a str,
+    branch_commit: &'a str,
+    target_commits: &'a [String],
+}
+
+/// Why a conflicted file was left for manual resolution.
+enum Skip {
+    /// One side deleted the file (modify/delete) — there's no text to merge.
+    Deleted,
+    /// A version isn't UTF-8.
+    Binary,
+    /// The versions exceed [`RESOLVE_BUDGET`].
+    TooLarge,
+    /// The LLM failed, or its output still holds conflict markers.
+    Proposal(String),
+    /// The user declined the proposal.
+    Declined,
+}
+
+impl Skip {
+    fn describe(&self) -> String {
+        match self {
+            Skip::Deleted => "deleted on one side".to_string(),
+            Skip::Binary => "binary file".to_string(),
+            Skip::TooLarge => "too large to send".to_string(),
+            Skip::Proposal(reason) => reason.clone(),
+            Skip::Declined => "declined".to_string(),
+        }
+    }
+}
+
+/// Handle `wt step resolve`.
+///
+/// Requires a rebase stopped on conflicts and a configured
+/// `[commit.generation] command`. Exits non-zero when files are left
+/// unresolved, with the rebase still open for manual resolution.
+pub fn step_resolve(yes: bool, format: SwitchFormat) -> anyhow::Result<()> {
+    let json_mode = format == SwitchFormat::Json;
+    let env = CommandEnv::for_action(UserConfig::load().context("Failed to load config")?)?;
+    let wt = env.repo.worktree_at(&env.worktree_path);
+
+    if wt.operation_in_progress()? != Some(InProgressOperation::Rebase) {
+        return Err(GitError::Other {
+            message: cformat!(
+                "No rebase in progress; <bold>wt step resolve</> continues a rebase stopped on conflicts"
+            ),
+        }
+        .into());
+    }
+
+    let generation = env.resolved().commit_generation.clone();
+    let Some(command) = generation.command.filter(|c| !c.trim().is_empty()) else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Conflict resolution needs an LLM command; configure <bold>[commit.generation] command</>"
+            ),
+        }
+        .into());
+    };
+
+    // Fail before the first LLM call rather than after it: every proposal
+    // needs an answer, and without a terminal there's no one to give it.
+    if !yes && !std::io::IsTerminal::is_terminal(&std::io::stdin()) {
+        return Err(GitError::NotInteractive.into());
+    }
+
+    let mut resolved: Vec<String> = Vec::new();
+    let mut unresolved: Vec<(String, Skip)> = Vec::new();
+
+    loop {
+        let paths = wt.unmerged_paths()?;
+        for path in &paths {
+            match resolve_file(&wt, &env.worktree_path, path, &command, yes, json_mode)? {
+                Ok(()) => resolved.push(path.clone()),
+                Err(skip) => unresolved.push((path.clone(), skip)),
+            }
+        }
+        if !unresolved.is_empty() {
+            break;
+        }
+
+        let output = wt.continue_rebase()?;
+        if wt.operation_in_progress()? != Some(InProgressOperation::Rebase) {
+            if !output.status.success() {
+                return Err(CommandError::from_failed_output(
+                    "git",
+                    &["rebase", "--continue"],
+                    &output,
+                )
+                .into());
+            }
+            break;
+        }
+        // Still rebasing: the next commit stopped on conflicts of its own.
+        // Anything else (an empty commit, a failing hook) isn't ours to handle.
+        if wt.unmerged_paths()?.is_empty() {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "<bold>git rebase --continue</> stopped without conflicts: {}",
+                    CommandError::from_failed_output("git", &["rebase", "--continue"], &output)
+                        .combined_output()
+                ),
+            }
+            .into());
+        }
+    }
+
+    let completed = unresolved.is_empty();
+    if json_mode {
+        print_json(&serde_json::json!({
+            "outcome": if completed { "completed" } else { "unresolved" },
+            "resolved": resolved,
+            "unresolved": unresolved
+                .iter()
+                .map(|(path, skip)| serde_json::json!({
+                    "path": path,
+                    "reason": skip.describe(),
+                }))
+                .collect::<Vec<_>>(),
+        }))?;
+    } else if completed {
+        let count = resolved.len();
+        let plural = if count == 1 { "" } else { "s" };
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Resolved <bold>{count}</> conflicted file{plural}; rebase complete"
+            ))
+        );
+    } else {
+        let list = unresolved
+            .iter()
+            .map(|(path, skip)| format!("{path} ({})", skip.describe()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        eprintln!(
+            "{}",
+            warning_message("Rebase still in progress; some conflicts need manual resolution")
+        );
+        eprintln!("{}", format_with_gutter(&list, None));
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Resolve and <underline>git add</> them, then run <underline>git rebase --continue</>"
+            ))
+        );
+    }
+
+    if completed {
+        Ok(())
+    } else {
+        Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into())
+    }
+}
+
+/// Propose, review, and stage a resolution for one conflicted file.
+///
+/// The outer `Result` carries failures that stop the whole run (git plumbing,
+/// I/O); the inner one says whether this file was resolved. On any inner
+/// `Err` the file is left exactly as git wrote it.
+fn resolve_file(
+    wt: &WorkingTree<'_>,
+    root: &Path,
+    path: &str,
+    command: &str,
+    yes: bool,
+    json_mode: bool,
+) -> anyhow::Result<Result<(), Skip>> {
+    let (Some(target), Some(branch)) = (wt.unmerged_stage(path, 2)?, wt.unmerged_stage(path, 3)?)
+    else {
+        return Ok(Err(Skip::Deleted));
+    };
+    // An add/add conflict has no common ancestor; an empty base says so.
+    let base = wt.unmerged_stage(path, 1)?.unwrap_or_default();
+    let file_path: PathBuf = root.join(path);
+    let conflicted_bytes =
+        std::fs::read(&file_path).with_context(|| format!("Failed to read {path}"))?;
+
+    let (Ok(base), Ok(target), Ok(branch), Ok(conflicted)) = (
+        String::from_utf8(base),
+        String::from_utf8(target),
+        String::from_utf8(branch),
+        std::str::from_utf8(&conflicted_bytes),
+    ) else {
+        return Ok(Err(Skip::Binary));
+    };
+    if base.len() + target.len() + branch.len() > RESOLVE_BUDGET {
+        return Ok(Err(Skip::TooLarge));
+    }
+
+    let branch_commit = wt
+        .run_command(&["log", "-1", "--format=%h %s%n%n%b", "REBASE_HEAD"])
+        .map(|s| s.trim().to_string())
+        .unwrap_or_default();
+    let target_commits: Vec<String> = wt
+        .run_command(&[
+            "log",
+            &format!("--max-count={MAX_TARGET_COMMITS}"),
+            "--format=%h %s",
+            "REBASE_HEAD..HEAD",
+            "--",
+            path,
+        ])
+        .map(|s| s.lines().map(str::to_string).collect())
+        .unwrap_or_default();
+
+    let prompt = render_prompt(&ConflictInput {
+        path,
+        base: &base,
+        target: &target,
+        branch: &branch,
+        conflicted,
+        branch_commit: &branch_commit,
+        target_commits: &target_commits,
+    })?;
+
+    if !json_mode {
+        eprintln!(
+            "{}",
+            progress_message(cformat!("Resolving <bold>{path}</>..."))
+        );
+    }
+    let proposal = {
+        let _watchdog = watch_llm_command(command);
+        execute_llm_command(command, &prompt)
+    };
+    let proposal = match proposal {
+        Ok(text) => text,
+        Err(e) => {
+            return Ok(Err(Skip::Proposal(format!(
+                "LLM command failed: {}",
+                e.display_message()
+            ))));
+        }
+    };
+    let proposal = strip_code_fence(&proposal);
+    if has_conflict_markers(proposal) {
+        return Ok(Err(Skip::Proposal(
+            "proposal still has conflict markers".into(),
+        )));
+    }
+    // The command's output is trimmed; restore the trailing newline the
+    // sides agree on so the resolution doesn't introduce a no-EOL diff.
+    let mut proposal = proposal.to_string();
+    if target.ends_with('\n') || branch.ends_with('\n') {
+        proposal.push('\n');
+    }
+
+    std::fs::write(&file_path, &proposal).with_context(|| format!("Failed to write {path}"))?;
+
+    // On an unmerged path `git diff` is the combined diff: the proposal
+    // against both the target and branch versions at once.
+    let diff = wt.run_command(&["--no-pager", "diff", "--no-color", "--", path])?;
+    let show_diff = || {
+        eprintln!(
+            "{}",
+            info_message(cformat!("Proposed resolution for <bold>{path}</>:"))
+        );
+        eprintln!("{}", format_with_gutter(diff.trim_end(), None));
+    };
+
+    let accepted = if yes {
+        if !json_mode {
+            show_diff();
+        }
+        true
+    } else {
+        show_diff();
+        prompt_yes_no_preview(&cformat!("Stage resolution of <bold>{path}</>?"), show_diff)?
+            == PromptResponse::Accepted
+    };
+
+    if !accepted {
+        std::fs::write(&file_path, &conflicted_bytes)
+            .with_context(|| format!("Failed to restore {path}"))?;
+        return Ok(Err(Skip::Declined));
+    }
+
+    wt.run_command(&["add", "--", path])
+        .with_context(|| format!("Failed to stage {path}"))?;
+    Ok(Ok(()))
+}
+
+/// Render the resolution prompt for one file.
+fn render_prompt(input: &ConflictInput<'_>) -> anyhow::Result<String> {
+    let env = Environment::new();
+    let tmpl = env.template_from_str(RESOLVE_TEMPLATE)?;
+    Ok(tmpl.render(minijinja::context! {
+        path => input.path,
+        base => input.base,
+        target => input.target,
+        branch => input.branch,
+        conflicted => input.conflicted,
+        branch_commit => input.branch_commit,
+        target_commits => input.target_commits,
+    })?)
+}
+
+/// Drop a Markdown code fence wrapped around the whole response. Models
+/// fence file content despite the prompt; the fence lines are never part of
+/// the file.
+fn strip_code_fence(text: &str) -> &str {
+    let Some(rest) = text.strip_prefix("```") else {
+        return text;
+    };
+    let Some((_, body)) = rest.split_once('\n') else {
+        return text;
+    };
+    match body.trim_end().strip_suffix("```") {
+        Some(inner) => inner.strip_suffix('\n').unwrap_or(inner),
+        None => text,
+    }
+}
+
+/// Whether `text` still carries git's conflict markers. The `=======`
+/// separator alone is ordinary content (a Markdown setext rule), so only the
+/// opening and closing markers count.
+fn has_conflict_markers(text: &str) -> bool {
+    text.lines()
+        .any(|line| line.starts_with("<<<<<<< ") || line.starts_with(">>>>>>> "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_code_fence_unwraps_fenced_output() {
+        assert_eq!(strip_code_fence("```rust\nfn a() {}\n```"), "fn a() {}");
+        assert_eq!(strip_code_fence("```\na\nb\n```\n"), "a\nb");
+        // Unfenced and unterminated responses pass through untouched.
+        assert_eq!(strip_code_fence("plain\ntext"), "plain\ntext");
+        assert_eq!(strip_code_fence("```\nno close"), "```\nno close");
+    }
+
+    #[test]
+    fn conflict_markers_ignore_bare_separator() {
+        assert!(has_conflict_markers("a\n<<<<<<< HEAD\nb\n"));
+        assert!(has_conflict_markers(">>>>>>> abc123 (feat)\n"));
+        assert!(!has_conflict_markers("Title\n=======\n\nbody\n"));
+    }
+
+    #[test]
+    fn render_prompt_labels_sides_by_role() {
+        let commits = vec!["abc1234 Update shared.txt in main".to_string()];
+        let prompt = render_prompt(&ConflictInput {
+            path: "shared.txt",
+            base: "initial\n",
+            target: "main version\n",
+            branch: "feature version\n",
+            conflicted: "<<<<<<< HEAD\nmain version\n=======\nfeature version\n>>>>>>> def5678 (feat)\n",
+            branch_commit: "def5678 feat",
+            target_commits: &commits,
+        })
+        .unwrap();
+        insta::assert_snapshot!(prompt, @r"
+        <task>Resolve the conflict in shared.txt left by a rebase. Output the complete resolved file.</task>
+
+        <format>
+        - Output only the file content — no explanations, quotes, or code fences
+        - Remove every conflict marker
+        - Keep the changes from both sides; where they cannot both hold, apply the branch's intent on top of the target
+        </format>
+
+        <branch_commit>
+        def5678 feat
+        </branch_commit>
+
+        <target_commits>
+        - abc1234 Update shared.txt in main
+        </target_commits>
+
+        <base>
+        initial
+
+        </base>
+
+        <target>
+        main version
+
+        </target>
+
+        <branch>
+        feature version
+
+        </branch>
+
+        <conflicted>
+        <<<<<<< HEAD
+        main version
+        =======
+        feature version
+        >>>>>>> def5678 (feat)
+
+        </conflicted>
+        ");
+    }
+}
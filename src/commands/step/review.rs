@@ -0,0 +1,121 @@
+//! `wt step review` — LLM code review of a branch.
+//!
+//! Thin command layer over [`crate::review`]: resolves the branch and its
+//! worktree, runs the cached review pipeline, prints the findings, and with
+//! `--check` turns `[blocking]` findings into a non-zero exit so the step can
+//! gate `wt merge` from a `pre-merge` hook.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitError, WorktrunkError};
+use worktrunk::styling::{
+    eprintln, format_with_gutter, info_message, println, progress_message, success_message,
+    warning_message,
+};
+
+use crate::cli::SwitchFormat;
+use crate::llm::watch_llm_command;
+use crate::output::print_json;
+use crate::review::{DEFAULT_REVIEW_TEMPLATE, blocking_findings, generate_review};
+use crate::summary::compute_combined_diff;
+
+use super::super::context::CommandEnv;
+
+/// Handle `wt step review`.
+///
+/// Reviews `branch` (defaults to the current worktree's branch) against its
+/// comparison base, including uncommitted changes. The review goes to stdout;
+/// status lines go to stderr. With `check`, blocking findings exit 1.
+pub fn step_review(
+    branch: Option<&str>,
+    check: bool,
+    refresh: bool,
+    format: SwitchFormat,
+) -> anyhow::Result<()> {
+    let json_mode = format == SwitchFormat::Json;
+    let config = UserConfig::load().context("Failed to load config")?;
+    let env = match branch {
+        Some(b) => CommandEnv::for_selector(config, b)?,
+        None => CommandEnv::for_action(config)?,
+    };
+    let repo = &env.repo;
+    let wt = repo.worktree_at(&env.worktree_path);
+    let branch = env.require_branch("review")?.to_string();
+
+    let generation = env.resolved().commit_generation.clone();
+    let Some(command) = generation.command.filter(|c| !c.trim().is_empty()) else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Reviews need an LLM command; configure <bold>[commit.generation] command</>"
+            ),
+        }
+        .into());
+    };
+    let template = generation
+        .review_template
+        .unwrap_or_else(|| DEFAULT_REVIEW_TEMPLATE.to_string());
+
+    let head = wt.head_sha()?.context("Branch has no commits to review")?;
+    let Some(combined) = compute_combined_diff(&branch, &head, Some(&env.worktree_path), repo)
+    else {
+        if json_mode {
+            print_json(&serde_json::json!({
+                "branch": branch,
+                "review": null,
+                "blocking": [],
+                "cached": false,
+            }))?;
+        } else {
+            eprintln!(
+                "{}",
+                info_message(cformat!("<bold>{branch}</> has no changes to review"))
+            );
+        }
+        return Ok(());
+    };
+
+    if !json_mode {
+        eprintln!(
+            "{}",
+            progress_message(cformat!("Reviewing <bold>{branch}</>..."))
+        );
+    }
+    let review = {
+        let _watchdog = watch_llm_command(&command);
+        generate_review(repo, &branch, combined, &template, &command, refresh)?
+    };
+    let blocking = blocking_findings(&review.text);
+
+    if json_mode {
+        print_json(&serde_json::json!({
+            "branch": branch,
+            "review": review.text,
+            "blocking": blocking,
+            "cached": review.cached,
+        }))?;
+    } else {
+        println!("{}", review.text);
+        if blocking.is_empty() {
+            eprintln!(
+                "{}",
+                success_message(cformat!("No blocking findings for <bold>{branch}</>"))
+            );
+        } else {
+            let count = blocking.len();
+            let plural = if count == 1 { "" } else { "s" };
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "<bold>{count}</> blocking finding{plural} for <bold>{branch}</>"
+                ))
+            );
+            eprintln!("{}", format_with_gutter(&blocking.join("\n"), None));
+        }
+    }
+
+    if check && !blocking.is_empty() {
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+    }
+    Ok(())
+}
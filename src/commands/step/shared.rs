@@ -81,6 +81,7 @@ fn default_copy_ignored_excludes() -> Vec<String> {
 pub(super) fn resolve_copy_ignored_config(repo: &Repository) -> anyhow::Result<CopyIgnoredConfig> {
     let mut config = CopyIgnoredConfig {
         exclude: default_copy_ignored_excludes(),
+        store: None,
     };
     let user_config = repo.user_config();
     let project_config = repo
@@ -3,7 +3,7 @@
 //! Push changes to target branch with safety checks. Both fast-forward push and
 //! `--no-ff` merge share common scaffolding (target resolution, fast-forward check,
 //! conflict check, progress/success output) extracted into [`MergeContext`], and
-//! both land through [`advance_target`].
+//! both land through [`Repository::advance_branch`].
 //!
 //! # Destination-worktree safety follows git
 //!
@@ -27,14 +27,12 @@ use std::path::PathBuf;
 
 use anyhow::Context;
 use color_print::cformat;
-use worktrunk::git::{ErrorExt, GitError, Repository};
-use worktrunk::path::format_path_for_display;
+use worktrunk::git::{GitError, Repository};
 use worktrunk::styling::{
-    eprintln, format_with_gutter, info_message, progress_message, success_message, warning_message,
+    eprintln, format_with_gutter, info_message, progress_message, success_message,
 };
 
 use super::types::MergeOperations;
-use crate::commands::repository_ext::RepositoryCliExt;
 
 /// Distinguishes a standalone push from a fast-forward push driven by `wt merge`.
 ///
@@ -124,14 +122,14 @@ impl MergeContext {
 
         let target_branch = repo.require_target_branch(target)?;
         // A registered worktree git calls prunable can't receive the sync in
-        // `advance_target` — git dies trying to cd into it. Refusing upfront
+        // `Repository::advance_branch` — git dies trying to cd into it. Refusing upfront
         // gives a clear answer, and names the `git worktree prune` that clears
         // the registration.
         let target_worktree_path = repo.usable_worktree_for_branch(&target_branch)?;
 
         if let Some(path) = &target_worktree_path {
             // The target gets the same gate the source got above, for a
-            // reason the source's comment doesn't cover: `advance_target`
+            // reason the source's comment doesn't cover: `advance_branch`
             // syncs the target worktree with `read-tree -m -u`, which refuses
             // an unmerged index but not an open operation whose index is
             // momentarily clean. A target paused mid-rebase or mid-cherry-pick
@@ -184,8 +182,8 @@ impl MergeContext {
 
         // Refuse when uncommitted changes in the target worktree overlap the
         // push range. Non-overlapping changes stay in place: the two-tree
-        // merge in `advance_target` carries them through untouched.
-        repo.ensure_no_target_conflicts(target_worktree_path.as_ref(), &target_branch)?;
+        // merge in `advance_branch` carries them through untouched.
+        repo.ensure_no_target_conflicts(target_worktree_path.as_deref(), &target_branch)?;
 
         // TODO(#3519 follow-up): when `target_branch` was behind its upstream
         // (see `Repository::span_upstream`), this count mixes the carried
@@ -349,132 +347,6 @@ fn format_up_to_date_context(operations: Option<MergeOperations>) -> String {
     }
 }
 
-// ---------------------------------------------------------------------------
-// Target update
-// ---------------------------------------------------------------------------
-
-/// Advance `target_branch` to `new_sha` and sync its checked-out worktree.
-///
-/// The worktrees share one object store, so nothing needs transferring: the
-/// update is a compare-and-swap `update-ref` against `old_sha`, which fails
-/// cleanly if another process advanced the target since
-/// [`MergeContext::prepare`] snapshotted it.
-///
-/// The worktree sync is git's documented lenient `push-to-checkout` policy
-/// (githooks(5)): `update-index --refresh` then a two-tree merge
-/// (`read-tree -m -u`). The two-tree merge leaves uncommitted changes at paths
-/// the update doesn't touch exactly where they are — staged entries stay
-/// staged — and refuses the update when a dirty path overlaps it. (`reset
-/// --keep` can't express this sync: HEAD already points at `new_sha` through
-/// the branch, so it sees old == new and is a no-op.) On refusal the ref
-/// update is rolled back with a second compare-and-swap, so the branch and
-/// its worktree move together or not at all.
-///
-/// `reflog_message` labels the target branch's reflog entry — the recovery
-/// affordance for the one operation that moves a branch the user isn't
-/// standing on, where every other mover (`push`, `merge`, `reset`) names
-/// itself.
-fn advance_target(
-    repo: &Repository,
-    target_branch: &str,
-    target_worktree_path: Option<&PathBuf>,
-    old_sha: &str,
-    new_sha: &str,
-    reflog_message: &str,
-) -> anyhow::Result<()> {
-    let target_ref = format!("refs/heads/{target_branch}");
-    repo.run_command(&[
-        "update-ref",
-        "-m",
-        reflog_message,
-        &target_ref,
-        new_sha,
-        old_sha,
-    ])
-    .map_err(|e| GitError::PushFailed {
-        target_branch: target_branch.to_string(),
-        error: format!("Failed to update ref: {}", e.display_message()),
-    })?;
-
-    let Some(wt_path) = target_worktree_path else {
-        return Ok(());
-    };
-    let target_wt = repo.worktree_at(wt_path);
-    // Refresh first: `read-tree -m -u` trusts index stat data, so an entry
-    // that is merely stat-dirty would otherwise refuse as "not uptodate".
-    // `submodule.recurse=false`: the sync moves the superproject only —
-    // recursing into submodules re-introduces #1604's failure mode when the
-    // user has `submodule.recurse=true` (read-tree is on git's always-recurse
-    // list).
-    let sync_result = target_wt
-        .run_command(&["update-index", "-q", "--refresh"])
-        .and_then(|_| {
-            target_wt.run_command(&[
-                "-c",
-                "submodule.recurse=false",
-                "read-tree",
-                "-m",
-                "-u",
-                old_sha,
-                new_sha,
-            ])
-        });
-    let e = match sync_result {
-        Ok(_) => {
-            // The compare-and-swap guarded the ref update, not the sync that
-            // follows it: a commit racing into the target worktree inside
-            // that window is built against the pre-sync index, so its tree
-            // reverts the pushed content while keeping it in the ancestry — a
-            // state the old fast-forward path couldn't reach because
-            // receive-pack re-verifies the expected old value when committing
-            // the ref. Re-read the ref so that interleaving surfaces instead
-            // of being reported as a clean merge. The push range is in the
-            // tip's ancestry either way, so this warns rather than fails.
-            let tip_after = repo.run_command(&["rev-parse", &target_ref])?;
-            if tip_after.trim() != new_sha {
-                eprintln!(
-                    "{}",
-                    warning_message(cformat!(
-                        "<bold>{target_branch}</> moved while its worktree was being synced; check <bold>git -C {} status</>",
-                        format_path_for_display(wt_path)
-                    ))
-                );
-            }
-            return Ok(());
-        }
-        Err(e) => e,
-    };
-
-    // The sync refused — a conflicting change appeared in the race window
-    // after the upfront check, or the index is locked; git's own error names
-    // the cause. unpack-trees runs its refusal checks before writing anything,
-    // so the worktree is normally untouched — only a write error partway
-    // through the update phase (ENOSPC, a read-only file) can leave a subset
-    // of files already carrying the new content, and git's error says which
-    // write failed. Either way, put the ref back so the branch and its
-    // worktree stay consistent.
-    let context = match repo.run_command(&[
-        "update-ref",
-        "-m",
-        "wt: rollback (worktree sync failed)",
-        &target_ref,
-        old_sha,
-        new_sha,
-    ]) {
-        Ok(_) => {
-            format!("Syncing the {target_branch} worktree failed; the ref change was rolled back")
-        }
-        // Only a third writer moving the ref inside this window can land here;
-        // report the state that remains rather than guessing at a fix.
-        Err(rollback_err) => format!(
-            "Syncing the {target_branch} worktree failed, and the ref change could not be \
-             rolled back ({rollback_err}); {target_branch} has advanced but its worktree \
-             was not synced"
-        ),
-    };
-    Err(e).context(context)
-}
-
 // ---------------------------------------------------------------------------
 // Fast-forward push
 // ---------------------------------------------------------------------------
@@ -484,7 +356,8 @@ fn advance_target(
 /// The `operations` parameter indicates which merge operations occurred (commit, squash, rebase).
 /// Pass `None` for standalone push operations where these concepts don't apply.
 ///
-/// Uncommitted changes in the target worktree don't move: [`advance_target`]'s
+/// Uncommitted changes in the target worktree don't move:
+/// [`Repository::advance_branch`]'s
 /// two-tree merge carries them in place, and [`MergeContext::prepare`] already
 /// refused any that overlap the push range.
 pub fn handle_push(
@@ -507,10 +380,9 @@ pub fn handle_push(
         });
     }
 
-    advance_target(
-        &ctx.repo,
+    ctx.repo.advance_branch(
         &ctx.target_branch,
-        ctx.target_worktree_path.as_ref(),
+        ctx.target_worktree_path.as_deref(),
         &ctx.target_tip,
         &ctx.head_sha,
         kind.reflog_message(),
@@ -530,7 +402,7 @@ pub fn handle_push(
 
 /// Merge to target branch using `--no-ff` (creates a merge commit).
 ///
-/// Uses git plumbing (`commit-tree` + [`advance_target`]) to create a merge
+/// Uses git plumbing (`commit-tree` + [`Repository::advance_branch`]) to create a merge
 /// commit on the target branch without needing to check it out. This is safe
 /// because [`MergeContext::prepare`] verified that the target is an ancestor
 /// of the feature tip, so the feature tree is the correct integration result.
@@ -584,10 +456,9 @@ pub fn handle_no_ff_merge(
         .trim()
         .to_string();
 
-    advance_target(
-        &ctx.repo,
+    ctx.repo.advance_branch(
         &ctx.target_branch,
-        ctx.target_worktree_path.as_ref(),
+        ctx.target_worktree_path.as_deref(),
         &ctx.target_tip,
         &merge_sha,
         if operations.is_some() {
@@ -613,87 +484,6 @@ pub fn handle_no_ff_merge(
 mod tests {
     use super::*;
     use crate::commands::worktree::types::MergeOperations;
-    use worktrunk::testing::TestRepo;
-
-    /// `advance_target` moves the branch and worktree together while leaving
-    /// non-overlapping uncommitted changes — staged entries included — exactly
-    /// in place, and refuses atomically otherwise: an overlapping dirty file
-    /// rolls the ref update back, and a stale `old_sha` (a concurrently
-    /// advanced target) fails the compare-and-swap before anything moves.
-    /// These paths have no deterministic trigger through the CLI — the upfront
-    /// conflict check catches everything slower than a race — so they are
-    /// proven here.
-    #[test]
-    fn advance_target_carries_dirty_files_and_refuses_atomically() {
-        let test = TestRepo::with_initial_commit();
-        let repo = Repository::at(test.root_path()).unwrap();
-        let root = test.root_path().to_path_buf();
-        std::fs::write(root.join("x.txt"), "base-x").unwrap();
-        test.run_git(&["add", "x.txt"]);
-        test.run_git(&["commit", "-m", "add x"]);
-        let old = test.git_output(&["rev-parse", "HEAD"]);
-
-        // Build the incoming commit on a side branch, then return to main so
-        // the worktree sits at `old` with a clean tree.
-        test.run_git(&["switch", "-c", "incoming"]);
-        std::fs::write(root.join("x.txt"), "incoming-x").unwrap();
-        test.run_git(&["commit", "-am", "change x"]);
-        let new = test.git_output(&["rev-parse", "HEAD"]);
-        test.run_git(&["switch", "main"]);
-
-        // Non-overlapping uncommitted state: an unstaged edit, a staged new
-        // file, and an untracked file.
-        std::fs::write(root.join("file.txt"), "unstaged-edit").unwrap();
-        std::fs::write(root.join("staged.txt"), "staged").unwrap();
-        test.run_git(&["add", "staged.txt"]);
-        std::fs::write(root.join("untracked.txt"), "untracked").unwrap();
-
-        advance_target(&repo, "main", Some(&root), &old, &new, "test").unwrap();
-        assert_eq!(test.git_output(&["rev-parse", "main"]), new);
-        assert_eq!(
-            std::fs::read_to_string(root.join("x.txt")).unwrap(),
-            "incoming-x"
-        );
-        // Unstaged edit still unstaged, staged entry still staged (and only
-        // it), untracked file still untracked.
-        assert_eq!(test.git_output(&["diff", "--name-only"]), "file.txt");
-        assert_eq!(
-            test.git_output(&["diff", "--cached", "--name-only"]),
-            "staged.txt"
-        );
-        assert_eq!(
-            test.git_output(&["ls-files", "--others", "--exclude-standard"]),
-            "untracked.txt"
-        );
-
-        // Overlap: a dirty edit to a file the next update changes. The sync
-        // refuses and the ref update is rolled back.
-        test.run_git(&["switch", "-c", "incoming2", "incoming"]);
-        std::fs::write(root.join("x.txt"), "incoming2-x").unwrap();
-        test.run_git(&["commit", "-am", "change x again"]);
-        let new2 = test.git_output(&["rev-parse", "HEAD"]);
-        test.run_git(&["switch", "main"]);
-        std::fs::write(root.join("x.txt"), "local-edit").unwrap();
-
-        let err = advance_target(&repo, "main", Some(&root), &new, &new2, "test").unwrap_err();
-        assert!(
-            err.to_string().contains("rolled back"),
-            "unexpected error: {err:#}"
-        );
-        assert_eq!(test.git_output(&["rev-parse", "main"]), new);
-        assert_eq!(
-            std::fs::read_to_string(root.join("x.txt")).unwrap(),
-            "local-edit"
-        );
-
-        // Stale old_sha: the compare-and-swap fails before anything moves.
-        let err = advance_target(&repo, "main", Some(&root), &old, &new2, "test").unwrap_err();
-        assert!(
-            err.to_string().contains("Can't push"),
-            "unexpected error: {err:#}"
-        );
-        assert_eq!(test.git_output(&["rev-parse", "main"]), new);
-    }
 
     #[test]
     fn test_format_operations_note() {
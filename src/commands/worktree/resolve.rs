@@ -1,15 +1,14 @@
 //! Worktree path computation and display.
 //!
-//! Where a worktree *belongs* — the `worktree-path` template, the expected-path
-//! check that names a worktree in output, and the bare-repo template prompt.
+//! Where a worktree *belongs* — the expected-path check that names a worktree
+//! in output and the bare-repo template prompt. The `worktree-path` template
+//! itself is applied by [`Repository::expected_worktree_path`].
 //! Turning what the user typed into a worktree is the opposite direction and
 //! lives in [`Repository::resolve_worktree`](worktrunk::git::Repository::resolve_worktree).
 
 use std::io::IsTerminal;
-use std::path::PathBuf;
 
 use color_print::cformat;
-use normalize_path::NormalizePath;
 use worktrunk::config::UserConfig;
 use worktrunk::git::Repository;
 use worktrunk::path::{format_path_for_display, paths_match};
@@ -19,53 +18,11 @@ use worktrunk::styling::{
 
 use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
 
-/// Compute the expected worktree path for a branch name.
-///
-/// For the default branch, returns the repo root (main worktree location).
-/// For other branches, applies the `worktree-path` template from config.
-///
-/// Uses cached values from Repository for `default_branch` and `is_bare`.
-pub fn compute_worktree_path(
-    repo: &Repository,
-    branch: &str,
-    config: &UserConfig,
-) -> anyhow::Result<PathBuf> {
-    let repo_root = repo.repo_path()?;
-    let default_branch = repo.default_branch().unwrap_or_default();
-    let is_bare = repo.is_bare()?;
-
-    // Default branch lives at repo root (main worktree), not a templated path.
-    // Exception: bare repos have no main worktree, so all branches use templated paths.
-    if !is_bare && branch == default_branch {
-        return Ok(repo_root.to_path_buf());
-    }
-
-    let repo_name = repo_root
-        .file_name()
-        .ok_or_else(|| {
-            anyhow::anyhow!(
-                "Repository path has no filename: {}",
-                format_path_for_display(repo_root)
-            )
-        })?
-        .to_str()
-        .ok_or_else(|| {
-            anyhow::anyhow!(
-                "Repository path contains invalid UTF-8: {}",
-                format_path_for_display(repo_root)
-            )
-        })?;
-
-    let project = repo.project_identifier().ok();
-    let expanded_path = config.format_path(repo_name, branch, repo, project.as_deref())?;
-
-    Ok(repo_root.join(expanded_path).normalize())
-}
-
 /// Check if a worktree is at its expected path based on config template.
 ///
-/// Returns true if the worktree's actual path matches what `compute_worktree_path`
-/// would generate for its branch. Detached HEAD always returns false (no expected path).
+/// Returns true if the worktree's actual path matches what
+/// [`Repository::expected_worktree_path`] would generate for its branch.
+/// Detached HEAD always returns false (no expected path).
 ///
 /// Uses canonicalization to handle symlinks and relative paths correctly.
 /// Uses cached values from Repository for `default_branch` and `is_bare`.
@@ -75,7 +32,8 @@ pub fn is_worktree_at_expected_path(
     config: &UserConfig,
 ) -> bool {
     match &wt.branch {
-        Some(branch) => compute_worktree_path(repo, branch, config)
+        Some(branch) => repo
+            .expected_worktree_path(branch, config)
             .map(|expected| paths_match(&wt.path, &expected))
             .unwrap_or(false),
         None => false,
@@ -89,7 +47,7 @@ pub fn is_worktree_at_expected_path(
 /// - If branch differs from expected location: `dir_name (on **branch**)` (both bolded)
 /// - If detached HEAD: `dir_name (detached)` (dir_name bolded)
 ///
-/// "Consistent" means the worktree path matches `compute_worktree_path(branch)`,
+/// "Consistent" means the worktree path matches `Repository::expected_worktree_path(branch)`,
 /// which returns repo root for default branch and templated path for others.
 pub fn worktree_display_name(
     wt: &worktrunk::git::WorktreeInfo,
@@ -13,8 +13,8 @@ use dunce::canonicalize;
 use serde::Serialize;
 use worktrunk::HookType;
 use worktrunk::config::{
-    UserConfig, ValidationScope, VarScope, referenced_vars_for_templates, template_references_var,
-    validate_template,
+    ProjectContainerConfig, UserConfig, ValidationScope, VarScope, referenced_vars_for_templates,
+    template_references_var, validate_template,
 };
 use worktrunk::git::remote_ref::{
     self, AzureDevOpsProvider, GitHubProvider, GitLabProvider, GiteaProvider, RemoteRefInfo,
@@ -24,6 +24,7 @@ use worktrunk::git::{
     ForgeKind, GitError, GitRemoteUrl, RefType, Repository, ResolvedWorktree, Selector,
     SwitchSuggestionCtx, current_or_recover,
 };
+use worktrunk::lifecycle::{self, NewWorktree, Notice, Setup};
 use worktrunk::shell_exec::{
     ShellEscapeMode, directive_shell_escape_mode, shell_cwd, shell_escape_for,
 };
@@ -32,7 +33,7 @@ use worktrunk::styling::{
     suggest_command, warning_message,
 };
 
-use super::resolve::{compute_worktree_path, offer_bare_repo_worktree_path_fix};
+use super::resolve::offer_bare_repo_worktree_path_fix;
 use super::types::{CreationMethod, SwitchBranchInfo, SwitchPlan, SwitchResult};
 use crate::cli::{SwitchArgs, SwitchFormat};
 use crate::commands::backup::back_up_clobbered_path_now;
@@ -738,6 +739,8 @@ fn validate_worktree_creation(
 /// * `remote_ref` - The ref to track (e.g., "pull/123/head" or "merge-requests/101/head")
 /// * `fork_push_url` - URL to push to, or `None` if push isn't supported (prefixed branch)
 /// * `label` - Human-readable label for error messages (e.g., "PR #123" or "MR !101")
+/// * `envs` - Environment for `git worktree add` (e.g., `GIT_LFS_SKIP_SMUDGE`)
+#[allow(clippy::too_many_arguments)]
 fn setup_fork_branch(
     repo: &Repository,
     branch: &str,
@@ -746,6 +749,7 @@ fn setup_fork_branch(
     fork_push_url: Option<&str>,
     worktree_path: &Path,
     label: &str,
+    envs: &[(&str, &str)],
 ) -> anyhow::Result<()> {
     // Create local branch from FETCH_HEAD
     // Use -- to prevent branch names starting with - from being interpreted as flags
@@ -781,12 +785,13 @@ fn setup_fork_branch(
     let git_args = ["worktree", "add", "--", worktree_path_str.as_ref(), branch];
     repo.run_command_delayed_stream(
         &git_args,
+        envs,
         Repository::SLOW_OPERATION_DELAY_MS,
         Some(
             progress_message(cformat!("Creating worktree for <bold>{}</>...", branch)).to_string(),
         ),
     )
-    .map_err(|e| worktree_creation_error(&e, branch.to_string(), None))?;
+    .map_err(|e| lifecycle::worktree_creation_error(&e, branch.to_string(), None))?;
 
     Ok(())
 }
@@ -821,7 +826,7 @@ fn plan_switch(
     // remote prefix.
     //
     // Resolving before the path template is also the fast path: an existing
-    // worktree answers without the ~7 git commands `compute_worktree_path` runs.
+    // worktree answers without the ~7 git commands `expected_worktree_path` runs.
     match repo.resolve_selector(&target.selector)? {
         ResolvedWorktree::Worktree { path, branch } => {
             // A registration whose directory is gone or broken has nothing to
@@ -851,7 +856,7 @@ fn plan_switch(
     }
 
     // Phase 3: Compute expected path (only needed for create)
-    let expected_path = compute_worktree_path(repo, target.selector.token(), config)?;
+    let expected_path = repo.expected_worktree_path(target.selector.token(), config)?;
 
     // Phase 4: Validate we can create at this path
     let needs_clobber_backup = validate_worktree_creation(
@@ -877,6 +882,8 @@ fn plan_switch(
 /// Takes a `SwitchPlan` from `plan_switch()` and executes it.
 /// For `SwitchPlan::Existing`, just records history.
 /// For `SwitchPlan::Create`, creates the worktree and runs hooks.
+/// `container` is the approved `[container]`, started for the destination
+/// either way — before `pre-start` hooks, so they run inside it.
 fn execute_switch(
     repo: &Repository,
     plan: SwitchPlan,
@@ -884,6 +891,7 @@ fn execute_switch(
     force: bool,
     run_hooks: bool,
     hook_plan: &ApprovedHookPlan,
+    container: Option<&ProjectContainerConfig>,
 ) -> anyhow::Result<(SwitchResult, SwitchBranchInfo)> {
     match plan {
         SwitchPlan::Existing {
@@ -906,6 +914,16 @@ fn execute_switch(
                 let _ = repo.set_switch_previous(new_previous.as_deref());
             }
 
+            if let Some(container) = container {
+                lifecycle::start_container(
+                    repo,
+                    container,
+                    &path,
+                    branch.as_deref(),
+                    &mut Notice::print,
+                );
+            }
+
             let result = if already_at_worktree {
                 SwitchResult::AlreadyAt(path)
             } else {
@@ -940,6 +958,11 @@ fn execute_switch(
                 );
             }
 
+            // Decided before `git worktree add`: in an LFS repository it
+            // checks LFS files out as pointers, which `setup.run` hydrates
+            // from the shared cache instead of downloading every object again.
+            let setup = Setup::new(repo, config, container.cloned());
+
             // Execute based on creation method
             let (created_branch, base_branch, from_remote) = match &method {
                 CreationMethod::Regular {
@@ -947,99 +970,23 @@ fn execute_switch(
                     base_branch,
                     base_pr_upstream,
                 } => {
-                    // Check if local branch exists BEFORE git worktree add (for DWIM detection)
-                    let branch_handle = repo.branch(&branch);
-                    let local_branch_existed =
-                        !create_branch && branch_handle.exists_locally().unwrap_or(false);
-
-                    // Build git worktree add command. Options come first, then
-                    // `--` separates them from the path and any positional ref,
-                    // so branch/base names that begin with `-` cannot be
-                    // misinterpreted by git as flags. `-b <branch>` keeps the
-                    // branch as the *value* of `-b`, which is safe even when
-                    // the branch name starts with `-`.
-                    let worktree_path_str = worktree_path.to_string_lossy();
-                    let mut args: Vec<&str> = vec!["worktree", "add"];
-
-                    // For DWIM fallback: when the branch doesn't exist locally,
-                    // git worktree add relies on DWIM to auto-create it from a
-                    // remote tracking branch. DWIM fails in repos without configured
-                    // fetch refspecs (bare repos, single-branch clones). Explicitly
-                    // create from the tracking ref in that case.
-                    let tracking_ref;
-
-                    let trailing_ref: Option<&str> = if *create_branch {
-                        args.push("-b");
-                        args.push(&branch);
-                        base_branch.as_deref()
-                    } else if !local_branch_existed {
-                        // Explicit -b when there's exactly one remote tracking ref.
-                        // Git's DWIM relies on the fetch refspec including this branch,
-                        // which may not hold in single-branch clones or bare repos.
-                        let remotes = branch_handle.remotes().unwrap_or_default();
-                        if remotes.len() == 1 {
-                            tracking_ref = format!("{}/{}", remotes[0], branch);
-                            args.extend(["-b", &branch]);
-                            Some(tracking_ref.as_str())
-                        } else {
-                            // Multiple or zero remotes: let git's DWIM handle (or error)
-                            Some(branch.as_str())
-                        }
-                    } else {
-                        Some(branch.as_str())
-                    };
-
-                    args.push("--");
-                    args.push(worktree_path_str.as_ref());
-                    if let Some(r) = trailing_ref {
-                        args.push(r);
-                    }
-
-                    // Delayed streaming: silent if fast, shows progress if slow
-                    let progress_msg = Some(
-                        progress_message(cformat!("Creating worktree for <bold>{}</>...", branch))
+                    let from_remote = lifecycle::add_worktree(
+                        repo,
+                        &NewWorktree {
+                            branch: &branch,
+                            create_branch: *create_branch,
+                            base: base_branch.as_deref(),
+                            path: &worktree_path,
+                        },
+                        &setup,
+                        Some(
+                            progress_message(cformat!(
+                                "Creating worktree for <bold>{}</>...",
+                                branch
+                            ))
                             .to_string(),
-                    );
-                    if let Err(e) = repo.run_command_delayed_stream(
-                        &args,
-                        Repository::SLOW_OPERATION_DELAY_MS,
-                        progress_msg,
-                    ) {
-                        // A new branch whose name is a path prefix of (or sits
-                        // under) an existing branch can't be created: git stores
-                        // refs as file paths, so `release` and `release/2026.4`
-                        // can't coexist. Surface that as a clear, actionable
-                        // error instead of git's raw "cannot lock ref" text.
-                        if *create_branch
-                            && let Some(conflicting) =
-                                detect_branch_namespace_conflict(repo, &branch)
-                        {
-                            return Err(GitError::BranchNamespaceConflict {
-                                branch: branch.clone(),
-                                conflicting,
-                            }
-                            .into());
-                        }
-                        return Err(worktree_creation_error(
-                            &e,
-                            branch.clone(),
-                            base_branch.clone(),
-                        )
-                        .into());
-                    }
-
-                    // Safety: unset unsafe upstream when creating a new branch from a remote
-                    // tracking branch. When `git worktree add -b feature origin/main` runs,
-                    // git sets feature to track origin/main. This is dangerous because
-                    // `git push` would push to main instead of the feature branch.
-                    // See: https://github.com/max-sixty/worktrunk/issues/713
-                    if *create_branch
-                        && let Some(base) = base_branch
-                        && repo.is_remote_tracking_branch(base)
-                    {
-                        // Unset the upstream to prevent accidental pushes
-                        branch_handle.unset_upstream()?;
-                    }
+                        ),
+                    )?;
 
                     // `--base pr:N` / `--base mr:N` against a same-repo PR/MR: the
                     // user asked for a custom local name pointing at an existing
@@ -1056,13 +1003,6 @@ fn execute_switch(
                         )?;
                     }
 
-                    // Report tracking info when the branch was auto-created from a remote
-                    let from_remote = if !create_branch && !local_branch_existed {
-                        branch_handle.upstream()?
-                    } else {
-                        None
-                    };
-
                     (*create_branch, base_branch.clone(), from_remote)
                 }
 
@@ -1090,6 +1030,7 @@ fn execute_switch(
                         fork_push_url.as_deref(),
                         &worktree_path,
                         &label,
+                        setup.add_envs(),
                     );
 
                     if let Err(e) = setup_result {
@@ -1152,6 +1093,8 @@ fn execute_switch(
                 CreationMethod::Regular { .. } => (None, None),
             };
 
+            setup.run(repo, &worktree_path, Some(&branch), &mut Notice::print);
+
             // Execute pre-start commands. `hook_repo` roots the render context
             // in the new worktree (created just above); the commands come from
             // the frozen `hook_plan`, selected at the gate from the invoking
@@ -1198,44 +1141,6 @@ fn execute_switch(
     }
 }
 
-/// Detect a git ref directory/file (D/F) conflict for a branch about to be
-/// created, returning an existing branch it collides with.
-///
-/// Git stores refs as file paths under `refs/heads/`, so a branch name can't
-/// be both a file and a directory: creating `release` fails when
-/// `release/2026.4` exists, and creating `release/foo` fails when `release`
-/// exists. This inspects the cached local-branch inventory (no extra
-/// subprocess) for either shape and returns the first colliding branch.
-fn detect_branch_namespace_conflict(repo: &Repository, branch: &str) -> Option<String> {
-    let prefix = format!("{branch}/");
-    repo.local_branches()
-        .ok()?
-        .iter()
-        .map(|b| b.name.as_str())
-        .find(|name| {
-            // `branch` is a directory prefix of an existing branch, or an
-            // existing branch is a directory prefix of `branch`.
-            name.starts_with(&prefix) || branch.starts_with(&format!("{name}/"))
-        })
-        .map(String::from)
-}
-
-/// Build a `GitError::WorktreeCreationFailed` from a failed `git worktree add`,
-/// extracting the underlying command output for the error message.
-fn worktree_creation_error(
-    err: &anyhow::Error,
-    branch: String,
-    base_branch: Option<String>,
-) -> GitError {
-    let (output, command) = Repository::extract_failed_command(err);
-    GitError::WorktreeCreationFailed {
-        branch,
-        base_branch,
-        error: output,
-        command,
-    }
-}
-
 /// Format the last fetch time as a self-contained phrase for error hint parentheticals.
 ///
 /// Returns e.g. "last fetched 3h ago" or "last fetched just now".
@@ -1648,6 +1553,9 @@ impl SwitchPipeline<'_> {
         // hooks but continue with the worktree operation. Switch hooks resolve
         // their config from the invoking worktree — see `approve_switch_hooks`.
         let (hooks_approved, hook_plan) = approve_switch_hooks(repo, config, &plan, yes, verify)?;
+        // `[container]` has its own approval: declining it skips the container,
+        // not the hooks (which then run on the host).
+        let container = crate::commands::container::approve(repo, yes)?;
 
         // Pre-flight: validate all templates before mutation (worktree
         // creation). Catches syntax errors and undefined variables early so a
@@ -1656,8 +1564,15 @@ impl SwitchPipeline<'_> {
         validate_switch_templates(repo, config, &plan, execute, execute_args, hooks_approved)?;
 
         // Execute the validated plan.
-        let (result, branch_info) =
-            execute_switch(repo, plan, config, yes, hooks_approved, &hook_plan)?;
+        let (result, branch_info) = execute_switch(
+            repo,
+            plan,
+            config,
+            yes,
+            hooks_approved,
+            &hook_plan,
+            container.as_ref(),
+        )?;
 
         // --format=json: write structured result to stdout. All behavior
         // (hooks, --execute, shell integration) proceeds normally — format only
@@ -1793,6 +1708,17 @@ impl SwitchPipeline<'_> {
                     .collect();
                 format!("{} {}", expanded_cmd, escaped_args.join(" "))
             };
+            // With the destination's container running, the command runs
+            // there instead (an interactive `exec` when stdin is a terminal).
+            let full_cmd = match crate::commands::container::running(repo, result.path()) {
+                Some(container) => crate::commands::container::exec_command(
+                    &container,
+                    &full_cmd,
+                    result.path(),
+                    escape_mode,
+                ),
+                None => full_cmd,
+            };
             execute_user_command(&full_cmd, hooks_display_path.as_deref())?;
         }
 
@@ -148,7 +148,7 @@ pub(crate) fn maybe_handle_env_completion() -> bool {
 
     // Determine the index of the word being completed.
     // - Bash/Zsh: Pass `_CLAP_COMPLETE_INDEX` env var with the cursor position
-    // - Fish/Nushell: Append the current token as the last argument, so index = len - 1
+    // - Fish/Nushell/Xonsh/Elvish: Append the current token as the last argument, so index = len - 1
     let index: usize = std::env::var("_CLAP_COMPLETE_INDEX")
         .ok()
         .and_then(|i| i.parse().ok())
@@ -224,10 +224,10 @@ pub(crate) fn maybe_handle_env_completion() -> bool {
     let separator = ifs.as_deref().unwrap_or("\n");
 
     // Shell-specific separator between value and description
-    // zsh uses ":", fish/nushell use "\t", bash doesn't support descriptions
+    // zsh uses ":", fish/nushell/xonsh/elvish use "\t", bash doesn't support descriptions
     let help_sep = match shell_name.as_ref() {
         "zsh" => Some(":"),
-        "fish" | "nu" => Some("\t"),
+        "fish" | "nu" | "xonsh" | "elvish" => Some("\t"),
         _ => None,
     };
 
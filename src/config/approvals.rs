@@ -6,13 +6,30 @@
 //!
 //! File format:
 //! ```toml
-//! [projects."github.com/user/repo"]
-//! approved-commands = [
-//!     "npm install",
-//!     "npm test",
-//! ]
+//! [[projects."github.com/user/repo".approvals]]
+//! command = "npm install"
+//! scope = "post-start"
+//! config-hash = "sha256:9f86d0…"
+//! approved-at = 1767225600
+//! expires-at = 1769817600
+//! approver = "alice@example.com"
+//! host = "alice-laptop"
 //! ```
 //!
+//! Each approval records the scope it was granted for (a hook type,
+//! `alias:<name>`, or `commit-template-append`) and approves the command
+//! there only. It carries the hash of the project config it was approved
+//! from, who approved it on which machine, and optionally when it lapses.
+//! The hash doesn't gate anything — an unchanged template stays approved
+//! when the rest of the config moves — but `wt config approvals list` flags
+//! approvals whose config has changed since, so a config edited in a PR
+//! branch is visible before its commands run.
+//!
+//! **Bare commands**: Files written before approvals carried provenance list
+//! `approved-commands = ["npm install", …]` per project. These still approve
+//! their command in every scope, with no expiry; approving the command again
+//! replaces the bare entry with a record.
+//!
 //! **Fallback**: When `approvals.toml` doesn't exist, `approved-commands` are
 //! silently read from `config.toml` for backward compatibility. Once any approval
 //! is saved (creating `approvals.toml`), it becomes the authoritative source.
@@ -42,12 +59,99 @@ pub struct Approvals {
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
 struct ApprovedProject {
+    /// Bare templates, approved in every scope without expiry — the format
+    /// before approvals carried provenance.
     #[serde(
         default,
         rename = "approved-commands",
         skip_serializing_if = "Vec::is_empty"
     )]
     approved_commands: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    approvals: Vec<ApprovalRecord>,
+}
+
+impl ApprovedProject {
+    fn is_empty(&self) -> bool {
+        self.approved_commands.is_empty() && self.approvals.is_empty()
+    }
+
+    /// Every approved template, bare entries first, each once.
+    fn commands(&self) -> Vec<&str> {
+        let mut commands: Vec<&str> = Vec::new();
+        let all = self
+            .approved_commands
+            .iter()
+            .chain(self.approvals.iter().map(|r| &r.command));
+        for command in all {
+            if !commands.contains(&command.as_str()) {
+                commands.push(command);
+            }
+        }
+        commands
+    }
+}
+
+/// One approved command with its provenance, stored as a
+/// `[[projects."…".approvals]]` entry.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ApprovalRecord {
+    /// The approved template, as written in the project config.
+    pub command: String,
+    /// Where the approval applies: a hook type (`post-start`),
+    /// `alias:<name>`, or `commit-template-append`. A record without one
+    /// applies everywhere, like a bare `approved-commands` entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// `sha256:<hex>` of the project config file the command was approved
+    /// from. Informational: see the module docs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_hash: Option<String>,
+    /// Unix seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approved_at: Option<u64>,
+    /// Unix seconds after which the approval no longer applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// Who approved: git `user.email`, falling back to the login name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approver: Option<String>,
+    /// The machine the approval was made on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+}
+
+impl ApprovalRecord {
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|at| now >= at)
+    }
+
+    /// Whether this record approves `normalized_command` (already passed
+    /// through [`normalize_template_vars`]) in `scope` at `now`.
+    fn covers(&self, normalized_command: &str, scope: &str, now: u64) -> bool {
+        self.scope.as_deref().is_none_or(|s| s == scope)
+            && !self.is_expired(now)
+            && normalize_template_vars(&self.command) == normalized_command
+    }
+
+    /// Whether `other` approves the same command in the same scope.
+    fn same_slot(&self, other: &Self) -> bool {
+        self.scope == other.scope
+            && normalize_template_vars(&self.command) == normalize_template_vars(&other.command)
+    }
+}
+
+/// Hash of a project config file's contents, as stored in
+/// [`ApprovalRecord::config_hash`].
+pub fn config_hash(content: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    use std::fmt::Write as _;
+    let mut out = String::from("sha256:");
+    for b in Sha256::digest(content) {
+        let _ = write!(out, "{b:02x}");
+    }
+    out
 }
 
 // =========================================================================
@@ -182,12 +286,23 @@ impl Approvals {
             projects_table.set_implicit(true);
 
             for (project_id, project_config) in &self.projects {
-                if project_config.approved_commands.is_empty() {
+                if project_config.is_empty() {
                     continue;
                 }
                 let mut project_table = toml_edit::Table::new();
-                let commands = format_multiline_array(project_config.approved_commands.iter());
-                project_table["approved-commands"] = toml_edit::value(commands);
+                project_table.set_implicit(true);
+                if !project_config.approved_commands.is_empty() {
+                    let commands = format_multiline_array(project_config.approved_commands.iter());
+                    project_table["approved-commands"] = toml_edit::value(commands);
+                }
+                if !project_config.approvals.is_empty() {
+                    let records = project_config
+                        .approvals
+                        .iter()
+                        .map(record_table)
+                        .collect::<Result<_, _>>()?;
+                    project_table["approvals"] = toml_edit::Item::ArrayOfTables(records);
+                }
                 projects_table[project_id] = toml_edit::Item::Table(project_table);
             }
 
@@ -230,22 +345,49 @@ impl Approvals {
     /// is ever a pattern: [`Self::approve_commands`] records under the exact
     /// project identifier and refuses one that contains `*`, so approving a
     /// command in one repository never widens to another.
-    pub fn is_command_approved(&self, project: &str, command: &str) -> bool {
+    ///
+    /// `scope` is where the command is about to run — a hook type
+    /// (`post-start`), `alias:<name>`, or `commit-template-append`. A record
+    /// approves its own scope only, and not once it has expired; a bare
+    /// `approved-commands` entry approves every scope.
+    pub fn is_command_approved(&self, project: &str, command: &str, scope: &str) -> bool {
         let normalized_command = normalize_template_vars(command);
+        let now = crate::utils::epoch_now();
         crate::config::matching_project_keys(&self.projects, project)
             .into_iter()
             .any(|p| {
                 p.approved_commands
                     .iter()
                     .any(|c| normalize_template_vars(c) == normalized_command)
+                    || p.approvals
+                        .iter()
+                        .any(|r| r.covers(&normalized_command, scope, now))
             })
     }
 
+    /// The record approving `command` in `scope` for `project`, if the
+    /// approval has one — a bare `approved-commands` entry doesn't. Exact key
+    /// only, like [`Self::stale_approvals`].
+    pub fn approval_record(
+        &self,
+        project: &str,
+        command: &str,
+        scope: &str,
+    ) -> Option<&ApprovalRecord> {
+        let normalized_command = normalize_template_vars(command);
+        let now = crate::utils::epoch_now();
+        self.projects
+            .get(project)?
+            .approvals
+            .iter()
+            .find(|r| r.covers(&normalized_command, scope, now))
+    }
+
     /// Iterate over projects and their approved commands.
-    pub fn projects(&self) -> impl Iterator<Item = (&str, &[String])> {
+    pub fn projects(&self) -> impl Iterator<Item = (&str, Vec<&str>)> {
         self.projects
             .iter()
-            .map(|(id, p)| (id.as_str(), p.approved_commands.as_slice()))
+            .map(|(id, p)| (id.as_str(), p.commands()))
     }
 
     /// Pattern keys whose approved commands cover `project` — the entries
@@ -258,7 +400,7 @@ impl Approvals {
             .iter()
             .filter(|(key, p)| {
                 key.contains('*')
-                    && !p.approved_commands.is_empty()
+                    && !p.is_empty()
                     && super::user::project_match::matches(key, project)
             })
             .map(|(key, _)| key.as_str())
@@ -282,10 +424,54 @@ impl Approvals {
         self.projects
             .get(project)
             .map(|p| {
-                p.approved_commands
-                    .iter()
+                p.commands()
+                    .into_iter()
                     .filter(|c| !normalized.contains(&normalize_template_vars(c)))
-                    .map(String::as_str)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Records for `project` that have expired. Exact key only, like
+    /// [`Self::stale_approvals`].
+    pub fn expired_approvals(&self, project: &str) -> Vec<&ApprovalRecord> {
+        let now = crate::utils::epoch_now();
+        self.projects
+            .get(project)
+            .map(|p| p.approvals.iter().filter(|r| r.is_expired(now)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Unexpired records for `project` whose command is still among
+    /// `templates` but which were approved from a project config other than
+    /// the one hashing to `config_hash` — the config changed since, or the
+    /// approval came from another branch's. Records without a hash, and
+    /// every record when there's no config to compare against, don't count.
+    /// Exact key only, like [`Self::stale_approvals`].
+    pub fn config_changed_approvals<'a>(
+        &'a self,
+        project: &str,
+        templates: &[&str],
+        config_hash: Option<&str>,
+    ) -> Vec<&'a ApprovalRecord> {
+        let Some(config_hash) = config_hash else {
+            return Vec::new();
+        };
+        let normalized: Vec<_> = templates
+            .iter()
+            .map(|t| normalize_template_vars(t))
+            .collect();
+        let now = crate::utils::epoch_now();
+        self.projects
+            .get(project)
+            .map(|p| {
+                p.approvals
+                    .iter()
+                    .filter(|r| {
+                        !r.is_expired(now)
+                            && r.config_hash.as_deref().is_some_and(|h| h != config_hash)
+                            && normalized.contains(&normalize_template_vars(&r.command))
+                    })
                     .collect()
             })
             .unwrap_or_default()
@@ -349,6 +535,7 @@ impl Approvals {
                     project_id.clone(),
                     ApprovedProject {
                         approved_commands: project_config.approved_commands.clone(),
+                        approvals: Vec::new(),
                     },
                 );
             }
@@ -357,7 +544,12 @@ impl Approvals {
         Ok(approvals)
     }
 
-    /// Add multiple approved commands in a single locked operation.
+    /// Record multiple approvals in a single locked operation.
+    ///
+    /// Each record replaces any earlier one for the same command and scope,
+    /// and a bare `approved-commands` entry for the command. Re-recording an
+    /// unexpired approval with the same config hash and expiry is a no-op, so
+    /// its original `approved-at` and approver stand.
     ///
     /// Refuses a `project` containing `*`: reads treat such a key as a pattern
     /// (see `config::user::project_match`), so a persisted entry would approve
@@ -368,7 +560,7 @@ impl Approvals {
     pub fn approve_commands(
         &mut self,
         project: String,
-        commands: Vec<String>,
+        records: Vec<ApprovalRecord>,
         approvals_path: &Path,
     ) -> Result<(), ConfigError> {
         if project.contains('*') {
@@ -377,19 +569,27 @@ impl Approvals {
                  pattern, so the entry would apply to every repository it matches"
             )));
         }
+        let now = crate::utils::epoch_now();
         self.with_locked_mutation(approvals_path, |approvals| {
             let entry = approvals.projects.entry(project).or_default();
             let mut changed = false;
-            for command in commands {
-                let normalized = normalize_template_vars(&command);
-                if !entry
-                    .approved_commands
-                    .iter()
-                    .any(|c| normalize_template_vars(c) == normalized)
-                {
-                    entry.approved_commands.push(command);
-                    changed = true;
+            for record in records {
+                let unchanged = entry.approvals.iter().any(|r| {
+                    r.same_slot(&record)
+                        && r.config_hash == record.config_hash
+                        && r.expires_at == record.expires_at
+                        && !r.is_expired(now)
+                });
+                if unchanged {
+                    continue;
                 }
+                let normalized = normalize_template_vars(&record.command);
+                entry
+                    .approved_commands
+                    .retain(|c| normalize_template_vars(c) != normalized);
+                entry.approvals.retain(|r| !r.same_slot(&record));
+                entry.approvals.push(record);
+                changed = true;
             }
             changed
         })
@@ -406,7 +606,7 @@ impl Approvals {
             let Some(project_config) = approvals.projects.get_mut(&project) else {
                 return false;
             };
-            if project_config.approved_commands.is_empty() {
+            if project_config.is_empty() {
                 return false;
             }
             approvals.projects.remove(&project);
@@ -414,26 +614,39 @@ impl Approvals {
         })
     }
 
-    /// Remove approvals for `project` that match none of `templates` (the
-    /// project's current command set) and save. Returns the removed commands.
+    /// Remove `project`'s stale approvals and save. Returns the removed
+    /// commands. Stale means any of:
     ///
-    /// Staleness is recomputed under the file lock via [`Self::stale_approvals`],
-    /// so an approval another process records between the caller's read and
-    /// this call is never removed by mistake.
+    /// - matching none of `templates` (the project's current command set) —
+    ///   [`Self::stale_approvals`];
+    /// - expired — [`Self::expired_approvals`];
+    /// - approved from a config other than the one hashing to `config_hash` —
+    ///   [`Self::config_changed_approvals`].
+    ///
+    /// Staleness is recomputed under the file lock, so an approval another
+    /// process records between the caller's read and this call is never
+    /// removed by mistake.
     pub fn revoke_stale(
         &mut self,
         project: &str,
         templates: &[&str],
+        config_hash: Option<&str>,
         approvals_path: &Path,
     ) -> Result<Vec<String>, ConfigError> {
         let mut removed = Vec::new();
         self.with_locked_mutation(approvals_path, |approvals| {
-            let stale: Vec<String> = approvals
+            let unlisted: Vec<String> = approvals
                 .stale_approvals(project, templates)
                 .into_iter()
                 .map(String::from)
                 .collect();
-            if stale.is_empty() {
+            let stale_records: Vec<ApprovalRecord> = approvals
+                .expired_approvals(project)
+                .into_iter()
+                .chain(approvals.config_changed_approvals(project, templates, config_hash))
+                .cloned()
+                .collect();
+            if unlisted.is_empty() && stale_records.is_empty() {
                 return false;
             }
             let Some(project_config) = approvals.projects.get_mut(project) else {
@@ -441,11 +654,19 @@ impl Approvals {
             };
             project_config
                 .approved_commands
-                .retain(|c| !stale.contains(c));
-            if project_config.approved_commands.is_empty() {
+                .retain(|c| !unlisted.contains(c));
+            project_config
+                .approvals
+                .retain(|r| !unlisted.contains(&r.command) && !stale_records.contains(r));
+            if project_config.is_empty() {
                 approvals.projects.remove(project);
             }
-            removed = stale;
+            removed = unlisted;
+            for record in stale_records {
+                if !removed.contains(&record.command) {
+                    removed.push(record.command);
+                }
+            }
             true
         })?;
         Ok(removed)
@@ -467,6 +688,16 @@ impl Approvals {
 // TOML formatting helpers
 // =========================================================================
 
+/// One `[[projects."…".approvals]]` entry, fields in declaration order.
+fn record_table(record: &ApprovalRecord) -> Result<toml_edit::Table, ConfigError> {
+    let serialized = toml::to_string(record)
+        .map_err(|e| ConfigError(format!("Failed to serialize approval: {e}")))?;
+    let doc: toml_edit::DocumentMut = serialized
+        .parse()
+        .map_err(|e| ConfigError(format!("Failed to serialize approval: {e}")))?;
+    Ok(doc.as_table().clone())
+}
+
 /// Format a string array as multiline TOML for readability.
 fn format_multiline_array<'a>(items: impl Iterator<Item = &'a String>) -> toml_edit::Array {
     let mut array: toml_edit::Array = items.collect();
@@ -487,6 +718,17 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    /// Unscoped records for `commands`, with no provenance.
+    fn records(commands: &[&str]) -> Vec<ApprovalRecord> {
+        commands
+            .iter()
+            .map(|command| ApprovalRecord {
+                command: command.to_string(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
     fn test_dir() -> (TempDir, PathBuf) {
         let temp_dir = TempDir::new().unwrap();
         let approvals_path = temp_dir.path().join("approvals.toml");
@@ -516,7 +758,7 @@ mod tests {
     #[test]
     fn test_empty_approvals() {
         let approvals = Approvals::default();
-        assert!(!approvals.is_command_approved("any/project", "any command"));
+        assert!(!approvals.is_command_approved("any/project", "any command", "post-start"));
     }
 
     #[test]
@@ -531,12 +773,26 @@ approved-commands = ["npm install"]
         )
         .unwrap();
 
-        assert!(approvals.is_command_approved("git.company.example/owner/repo", "npm install"));
-        assert!(
-            approvals.is_command_approved("git.company.example/group/team/repo", "npm install")
-        );
-        assert!(!approvals.is_command_approved("github.com/owner/repo", "npm install"));
-        assert!(!approvals.is_command_approved("git.company.example/owner/repo", "npm test"));
+        assert!(approvals.is_command_approved(
+            "git.company.example/owner/repo",
+            "npm install",
+            "post-start"
+        ));
+        assert!(approvals.is_command_approved(
+            "git.company.example/group/team/repo",
+            "npm install",
+            "post-start"
+        ));
+        assert!(!approvals.is_command_approved(
+            "github.com/owner/repo",
+            "npm install",
+            "post-start"
+        ));
+        assert!(!approvals.is_command_approved(
+            "git.company.example/owner/repo",
+            "npm test",
+            "post-start"
+        ));
     }
 
     #[test]
@@ -585,13 +841,17 @@ approved-commands = ["make"]
         let err = approvals
             .approve_commands(
                 "git.company.example/owner/re*po".to_string(),
-                vec!["npm install".to_string()],
+                records(&["npm install"]),
                 &path,
             )
             .unwrap_err();
         assert!(err.to_string().contains("pattern"), "got: {err}");
         assert!(
-            !approvals.is_command_approved("git.company.example/owner/re*po", "npm install"),
+            !approvals.is_command_approved(
+                "git.company.example/owner/re*po",
+                "npm install",
+                "post-start"
+            ),
             "nothing may be recorded in memory"
         );
         assert!(!path.exists(), "nothing may be written to disk");
@@ -616,14 +876,22 @@ approved-commands = ["npm install"]
         approvals
             .approve_commands(
                 "git.company.example/owner/repo".to_string(),
-                vec!["npm test".to_string()],
+                records(&["npm test"]),
                 &path,
             )
             .unwrap();
 
-        assert!(approvals.is_command_approved("git.company.example/owner/repo", "npm test"));
+        assert!(approvals.is_command_approved(
+            "git.company.example/owner/repo",
+            "npm test",
+            "post-start"
+        ));
         assert!(
-            !approvals.is_command_approved("git.company.example/other/repo", "npm test"),
+            !approvals.is_command_approved(
+                "git.company.example/other/repo",
+                "npm test",
+                "post-start"
+            ),
             "the pattern entry must not have absorbed the new approval"
         );
     }
@@ -648,11 +916,19 @@ approved-commands = ["npm test"]
             .unwrap();
 
         assert!(
-            !approvals.is_command_approved("git.company.example/owner/repo", "npm test"),
+            !approvals.is_command_approved(
+                "git.company.example/owner/repo",
+                "npm test",
+                "post-start"
+            ),
             "the repository's own approval is gone"
         );
         assert!(
-            approvals.is_command_approved("git.company.example/owner/repo", "npm install"),
+            approvals.is_command_approved(
+                "git.company.example/owner/repo",
+                "npm install",
+                "post-start"
+            ),
             "the pattern entry other repositories share is untouched"
         );
     }
@@ -665,14 +941,18 @@ approved-commands = ["npm test"]
         approvals
             .approve_commands(
                 "github.com/user/repo".to_string(),
-                vec!["npm install".to_string()],
+                records(&["npm install"]),
                 &path,
             )
             .unwrap();
 
-        assert!(approvals.is_command_approved("github.com/user/repo", "npm install"));
-        assert!(!approvals.is_command_approved("github.com/user/repo", "npm test"));
-        assert!(!approvals.is_command_approved("github.com/other/repo", "npm install"));
+        assert!(approvals.is_command_approved("github.com/user/repo", "npm install", "post-start"));
+        assert!(!approvals.is_command_approved("github.com/user/repo", "npm test", "post-start"));
+        assert!(!approvals.is_command_approved(
+            "github.com/other/repo",
+            "npm install",
+            "post-start"
+        ));
     }
 
     #[test]
@@ -683,14 +963,14 @@ approved-commands = ["npm test"]
         approvals
             .approve_commands(
                 "github.com/user/repo".to_string(),
-                vec!["npm install".to_string()],
+                records(&["npm install"]),
                 &path,
             )
             .unwrap();
         approvals
             .approve_commands(
                 "github.com/user/repo".to_string(),
-                vec!["npm install".to_string()],
+                records(&["npm install"]),
                 &path,
             )
             .unwrap();
@@ -699,7 +979,7 @@ approved-commands = ["npm test"]
             .projects
             .get("github.com/user/repo")
             .unwrap()
-            .approved_commands
+            .approvals
             .len();
         assert_eq!(count, 1);
     }
@@ -712,13 +992,13 @@ approved-commands = ["npm test"]
         approvals
             .approve_commands(
                 "github.com/user/repo".to_string(),
-                vec!["npm install".to_string(), "npm test".to_string()],
+                records(&["npm install", "npm test"]),
                 &path,
             )
             .unwrap();
 
-        assert!(approvals.is_command_approved("github.com/user/repo", "npm install"));
-        assert!(approvals.is_command_approved("github.com/user/repo", "npm test"));
+        assert!(approvals.is_command_approved("github.com/user/repo", "npm install", "post-start"));
+        assert!(approvals.is_command_approved("github.com/user/repo", "npm test", "post-start"));
     }
 
     #[test]
@@ -729,14 +1009,14 @@ approved-commands = ["npm test"]
         approvals
             .approve_commands(
                 "github.com/user/repo1".to_string(),
-                vec!["npm install".to_string(), "npm test".to_string()],
+                records(&["npm install", "npm test"]),
                 &path,
             )
             .unwrap();
         approvals
             .approve_commands(
                 "github.com/user/repo2".to_string(),
-                vec!["cargo build".to_string()],
+                records(&["cargo build"]),
                 &path,
             )
             .unwrap();
@@ -756,14 +1036,14 @@ approved-commands = ["npm test"]
         approvals
             .approve_commands(
                 "github.com/user/repo1".to_string(),
-                vec!["npm install".to_string()],
+                records(&["npm install"]),
                 &path,
             )
             .unwrap();
         approvals
             .approve_commands(
                 "github.com/user/repo2".to_string(),
-                vec!["cargo build".to_string()],
+                records(&["cargo build"]),
                 &path,
             )
             .unwrap();
@@ -780,15 +1060,15 @@ approved-commands = ["npm test"]
         approvals
             .approve_commands(
                 "github.com/user/repo".to_string(),
-                vec!["npm install".to_string(), "npm test".to_string()],
+                records(&["npm install", "npm test"]),
                 &path,
             )
             .unwrap();
 
         // Load from disk
         let loaded = load_from_path(&path).unwrap();
-        assert!(loaded.is_command_approved("github.com/user/repo", "npm install"));
-        assert!(loaded.is_command_approved("github.com/user/repo", "npm test"));
+        assert!(loaded.is_command_approved("github.com/user/repo", "npm install", "post-start"));
+        assert!(loaded.is_command_approved("github.com/user/repo", "npm test", "post-start"));
     }
 
     #[cfg(unix)]
@@ -806,6 +1086,7 @@ approved-commands = ["npm test"]
             "github.com/user/repo".to_string(),
             super::ApprovedProject {
                 approved_commands: vec!["npm install".to_string()],
+                approvals: Vec::new(),
             },
         );
         original.save_to(&path).unwrap();
@@ -824,6 +1105,7 @@ approved-commands = ["npm test"]
             "github.com/user/repo".to_string(),
             super::ApprovedProject {
                 approved_commands: vec!["npm test".to_string()],
+                approvals: Vec::new(),
             },
         );
         let err = replacement.save_to(&path).unwrap_err();
@@ -835,8 +1117,8 @@ approved-commands = ["npm test"]
 
         assert_eq!(std::fs::read_to_string(&path).unwrap(), original_content);
         let loaded = Approvals::load_from_file(&path).unwrap();
-        assert!(loaded.is_command_approved("github.com/user/repo", "npm install"));
-        assert!(!loaded.is_command_approved("github.com/user/repo", "npm test"));
+        assert!(loaded.is_command_approved("github.com/user/repo", "npm install", "post-start"));
+        assert!(!loaded.is_command_approved("github.com/user/repo", "npm test", "post-start"));
     }
 
     #[test]
@@ -844,21 +1126,33 @@ approved-commands = ["npm test"]
         let (_temp_dir, path) = test_dir();
 
         let mut approvals = Approvals::default();
+        let mut records = records(&["npm install", "npm test"]);
+        records[0] = ApprovalRecord {
+            scope: Some("post-start".to_string()),
+            config_hash: Some(config_hash(b"[post-start]\ninstall = \"npm install\"\n")),
+            approved_at: Some(1_767_225_600),
+            expires_at: Some(1_767_830_400),
+            approver: Some("alice@example.com".to_string()),
+            host: Some("alice-laptop".to_string()),
+            ..records[0].clone()
+        };
         approvals
-            .approve_commands(
-                "github.com/user/repo".to_string(),
-                vec!["npm install".to_string(), "npm test".to_string()],
-                &path,
-            )
+            .approve_commands("github.com/user/repo".to_string(), records, &path)
             .unwrap();
 
         let content = std::fs::read_to_string(&path).unwrap();
         insta::assert_snapshot!(content, @r#"
-        [projects."github.com/user/repo"]
-        approved-commands = [
-            "npm install",
-            "npm test",
-        ]
+        [[projects."github.com/user/repo".approvals]]
+        command = "npm install"
+        scope = "post-start"
+        config-hash = "sha256:e1dad9c174ff40cd2f19915380783100ffdc1d44d2e38dc37de6698faad4e0ce"
+        approved-at = 1767225600
+        expires-at = 1767830400
+        approver = "alice@example.com"
+        host = "alice-laptop"
+
+        [[projects."github.com/user/repo".approvals]]
+        command = "npm test"
         "#);
     }
 
@@ -871,13 +1165,13 @@ approved-commands = ["npm test"]
         approvals
             .approve_commands(
                 "project".to_string(),
-                vec!["echo {{ repo_root }}".to_string()],
+                records(&["echo {{ repo_root }}"]),
                 &path,
             )
             .unwrap();
 
         // Should match with canonical variable name
-        assert!(approvals.is_command_approved("project", "echo {{ repo_path }}"));
+        assert!(approvals.is_command_approved("project", "echo {{ repo_path }}", "post-start"));
     }
 
     #[test]
@@ -886,14 +1180,10 @@ approved-commands = ["npm test"]
 
         let mut approvals = Approvals::default();
         approvals
-            .approve_commands(
-                "project".to_string(),
-                vec!["echo repo_root".to_string()],
-                &path,
-            )
+            .approve_commands("project".to_string(), records(&["echo repo_root"]), &path)
             .unwrap();
 
-        assert!(!approvals.is_command_approved("project", "echo repo_path"));
+        assert!(!approvals.is_command_approved("project", "echo repo_path", "post-start"));
     }
 
     #[test]
@@ -917,7 +1207,7 @@ approved-commands = ["npm test"]
                     approvals
                         .approve_commands(
                             "github.com/user/repo".to_string(),
-                            vec![format!("command_{i}")],
+                            records(&[&format!("command_{i}")]),
                             &config_path,
                         )
                         .unwrap();
@@ -957,9 +1247,13 @@ approved-commands = ["cargo build"]
         .unwrap();
 
         let approvals = Approvals::load_from_config_file(&config_path).unwrap();
-        assert!(approvals.is_command_approved("github.com/user/repo", "npm install"));
-        assert!(approvals.is_command_approved("github.com/user/repo", "npm test"));
-        assert!(approvals.is_command_approved("github.com/other/repo", "cargo build"));
+        assert!(approvals.is_command_approved("github.com/user/repo", "npm install", "post-start"));
+        assert!(approvals.is_command_approved("github.com/user/repo", "npm test", "post-start"));
+        assert!(approvals.is_command_approved(
+            "github.com/other/repo",
+            "cargo build",
+            "post-start"
+        ));
     }
 
     /// When `approvals.toml` doesn't exist but `config.toml` is a sibling with
@@ -986,14 +1280,14 @@ approved-commands = ["npm install"]
         approvals
             .approve_commands(
                 "github.com/user/repo".to_string(),
-                vec!["npm test".to_string()],
+                records(&["npm test"]),
                 &approvals_path,
             )
             .unwrap();
 
         // Both the fallback command and the new one should be present
-        assert!(approvals.is_command_approved("github.com/user/repo", "npm install"));
-        assert!(approvals.is_command_approved("github.com/user/repo", "npm test"));
+        assert!(approvals.is_command_approved("github.com/user/repo", "npm install", "post-start"));
+        assert!(approvals.is_command_approved("github.com/user/repo", "npm test", "post-start"));
 
         // approvals.toml should now exist with both commands
         let content = std::fs::read_to_string(&approvals_path).unwrap();
@@ -1080,11 +1374,11 @@ approved-command = ["npm test"]
         let (_temp_dir, path) = test_dir();
         let mut approvals = Approvals::default();
         approvals
-            .approve_commands("project-a".to_string(), vec!["cmd1".to_string()], &path)
+            .approve_commands("project-a".to_string(), records(&["cmd1"]), &path)
             .unwrap();
         // Revoke a project that doesn't exist — should be a no-op
         approvals.revoke_project("nonexistent", &path).unwrap();
-        assert!(approvals.is_command_approved("project-a", "cmd1"));
+        assert!(approvals.is_command_approved("project-a", "cmd1", "post-start"));
     }
 
     #[test]
@@ -1105,12 +1399,14 @@ approved-command = ["npm test"]
             "empty-project".to_string(),
             super::ApprovedProject {
                 approved_commands: vec![],
+                approvals: Vec::new(),
             },
         );
         approvals.projects.insert(
             "real-project".to_string(),
             super::ApprovedProject {
                 approved_commands: vec!["cmd1".to_string()],
+                approvals: Vec::new(),
             },
         );
         // Call save_to directly so the empty project reaches the save logic
@@ -1126,7 +1422,7 @@ approved-command = ["npm test"]
         let (_temp_dir, path) = test_dir();
         let mut approvals = Approvals::default();
         approvals
-            .approve_commands("project-a".to_string(), vec!["cmd1".to_string()], &path)
+            .approve_commands("project-a".to_string(), records(&["cmd1"]), &path)
             .unwrap();
         // Manually clear the commands (without removing the project entry)
         approvals
@@ -1152,11 +1448,7 @@ approved-command = ["npm test"]
         approvals
             .approve_commands(
                 "project".to_string(),
-                vec![
-                    "npm test".to_string(),
-                    "echo {{ repo_root }}".to_string(),
-                    "removed command".to_string(),
-                ],
+                records(&["npm test", "echo {{ repo_root }}", "removed command"]),
                 &path,
             )
             .unwrap();
@@ -1181,45 +1473,160 @@ approved-command = ["npm test"]
         approvals
             .approve_commands(
                 "project".to_string(),
-                vec![
-                    "npm test".to_string(),
-                    "echo {{ repo_root }}".to_string(),
-                    "removed command".to_string(),
-                ],
+                records(&["npm test", "echo {{ repo_root }}", "removed command"]),
                 &path,
             )
             .unwrap();
 
         let templates = ["npm test", "echo {{ repo_path }}"];
         let removed = approvals
-            .revoke_stale("project", &templates, &path)
+            .revoke_stale("project", &templates, None, &path)
             .unwrap();
         assert_eq!(removed, vec!["removed command"]);
-        assert!(approvals.is_command_approved("project", "npm test"));
-        assert!(approvals.is_command_approved("project", "echo {{ repo_root }}"));
+        assert!(approvals.is_command_approved("project", "npm test", "post-start"));
+        assert!(approvals.is_command_approved("project", "echo {{ repo_root }}", "post-start"));
 
         // Nothing stale left — a second call is a no-op.
         let removed = approvals
-            .revoke_stale("project", &templates, &path)
+            .revoke_stale("project", &templates, None, &path)
             .unwrap();
         assert!(removed.is_empty());
 
         // Every remaining approval stale — the project entry disappears.
-        let removed = approvals.revoke_stale("project", &[], &path).unwrap();
+        let removed = approvals.revoke_stale("project", &[], None, &path).unwrap();
         assert_eq!(removed.len(), 2);
         assert!(!approvals.projects.contains_key("project"));
     }
 
+    #[test]
+    fn test_record_approves_its_own_scope_until_it_expires() {
+        // Far enough either side of any test epoch.
+        let approvals: Approvals = toml::from_str(
+            r#"
+[[projects."project".approvals]]
+command = "npm install"
+scope = "post-start"
+
+[[projects."project".approvals]]
+command = "make deploy"
+scope = "alias:deploy"
+expires-at = 1
+
+[[projects."project".approvals]]
+command = "cargo test"
+expires-at = 99999999999
+"#,
+        )
+        .unwrap();
+
+        assert!(approvals.is_command_approved("project", "npm install", "post-start"));
+        assert!(!approvals.is_command_approved("project", "npm install", "alias:setup"));
+        // Expired.
+        assert!(!approvals.is_command_approved("project", "make deploy", "alias:deploy"));
+        // Unscoped records approve everywhere.
+        assert!(approvals.is_command_approved("project", "cargo test", "pre-merge"));
+        assert!(approvals.is_command_approved("project", "cargo test", "alias:test"));
+
+        let expired: Vec<_> = approvals
+            .expired_approvals("project")
+            .into_iter()
+            .map(|r| r.command.as_str())
+            .collect();
+        assert_eq!(expired, vec!["make deploy"]);
+    }
+
+    #[test]
+    fn test_recording_replaces_a_bare_entry() {
+        let (_temp_dir, path) = test_dir();
+        std::fs::write(
+            &path,
+            r#"
+[projects."project"]
+approved-commands = ["npm install", "npm test"]
+"#,
+        )
+        .unwrap();
+
+        let mut approvals = Approvals::default();
+        let record = ApprovalRecord {
+            command: "npm install".to_string(),
+            scope: Some("post-start".to_string()),
+            ..Default::default()
+        };
+        approvals
+            .approve_commands("project".to_string(), vec![record.clone()], &path)
+            .unwrap();
+
+        let loaded = load_from_path(&path).unwrap();
+        let project = loaded.projects.get("project").unwrap();
+        assert_eq!(project.approved_commands, vec!["npm test".to_string()]);
+        assert_eq!(project.approvals, vec![record]);
+        // Now scoped: the bare entry no longer approves it elsewhere.
+        assert!(!loaded.is_command_approved("project", "npm install", "alias:setup"));
+        assert!(loaded.is_command_approved("project", "npm test", "alias:setup"));
+    }
+
+    #[test]
+    fn test_config_changed_approvals_and_revoke_stale() {
+        let (_temp_dir, path) = test_dir();
+        let old_hash = config_hash(b"old");
+        let new_hash = config_hash(b"new");
+
+        let mut approvals = Approvals::default();
+        let record = |command: &str, hash: Option<&str>| ApprovalRecord {
+            command: command.to_string(),
+            scope: Some("post-start".to_string()),
+            config_hash: hash.map(String::from),
+            ..Default::default()
+        };
+        approvals
+            .approve_commands(
+                "project".to_string(),
+                vec![
+                    record("npm install", Some(&old_hash)),
+                    record("npm test", Some(&new_hash)),
+                    record("npm run lint", None),
+                    record("npm run gone", Some(&old_hash)),
+                ],
+                &path,
+            )
+            .unwrap();
+
+        let templates = ["npm install", "npm test", "npm run lint"];
+        let changed: Vec<_> = approvals
+            .config_changed_approvals("project", &templates, Some(&new_hash))
+            .into_iter()
+            .map(|r| r.command.as_str())
+            .collect();
+        // Only commands still in the config; a hashless record never counts.
+        assert_eq!(changed, vec!["npm install"]);
+        // Still approved — the hash is informational.
+        assert!(approvals.is_command_approved("project", "npm install", "post-start"));
+        assert!(
+            approvals
+                .config_changed_approvals("project", &templates, None)
+                .is_empty()
+        );
+
+        let removed = approvals
+            .revoke_stale("project", &templates, Some(&new_hash), &path)
+            .unwrap();
+        assert_eq!(removed, vec!["npm run gone", "npm install"]);
+        assert!(!approvals.is_command_approved("project", "npm install", "post-start"));
+        assert!(approvals.is_command_approved("project", "npm test", "post-start"));
+        assert!(approvals.is_command_approved("project", "npm run lint", "post-start"));
+    }
+
     #[test]
     fn test_projects_accessor() {
         let (_temp_dir, path) = test_dir();
 
         let mut approvals = Approvals::default();
         approvals
-            .approve_commands("project1".to_string(), vec!["cmd1".to_string()], &path)
+            .approve_commands("project1".to_string(), records(&["cmd1"]), &path)
             .unwrap();
         approvals
-            .approve_commands("project2".to_string(), vec!["cmd2".to_string()], &path)
+            .approve_commands("project2".to_string(), records(&["cmd2"]), &path)
             .unwrap();
 
         let projects: Vec<_> = approvals.projects().collect();
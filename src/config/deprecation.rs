@@ -85,9 +85,11 @@ static WARNED_DEPRECATED_PATHS: LazyLock<Mutex<HashSet<PathBuf>>> =
 /// so multiple deprecated configs (user + project) share a single hint line.
 static DEPRECATION_HINT_EMITTED: OnceLock<()> = OnceLock::new();
 
-/// Latch that silences config deprecation/unknown-field warnings for the rest
-/// of the process. Set by shell completion, picker, statusline, and help paths
-/// — surfaces where stderr output would appear above the user's prompt or TUI.
+/// Latch that silences config load warnings (parse failures, deprecations,
+/// unknown fields) for the rest of the process. Set by shell completion,
+/// picker, statusline, and help paths — surfaces where stderr output would
+/// appear above the user's prompt or TUI — and by commands that report config
+/// problems themselves.
 static SUPPRESS_WARNINGS: OnceLock<()> = OnceLock::new();
 
 pub fn suppress_warnings() {
@@ -501,7 +503,7 @@ impl DeprecationKind {
     /// setting only matters to consumers of that surface — and still render
     /// on the pull surfaces (`wt config show`, the `wt config update`
     /// preview), where the user asked for details.
-    fn is_pending_default(&self) -> bool {
+    pub(crate) fn is_pending_default(&self) -> bool {
         matches!(self, Self::JsonSchemaUnset)
     }
 }
@@ -1620,109 +1622,78 @@ fn format_warning_lines<'a>(
 ) -> String {
     use std::fmt::Write;
     let mut out = String::new();
+    for message in deprecation_messages(kinds) {
+        let _ = writeln!(out, "{}", warning_message(format!("{label}: {message}")));
+    }
+    out
+}
+
+/// One styled sentence per deprecated pattern, without the file label. The
+/// commit-generation kind yields one per affected section. Shared by the load
+/// warnings and `wt config validate`.
+pub fn deprecation_messages<'a>(
+    kinds: impl IntoIterator<Item = &'a DeprecationKind>,
+) -> Vec<String> {
+    let mut out = Vec::new();
 
     for kind in kinds {
         match kind {
             DeprecationKind::TemplateVar { old, new } => {
-                let _ = writeln!(
-                    out,
-                    "{}",
-                    warning_message(cformat!(
-                        "{label}: template variable <bold>{old}</> is deprecated in favor of <bold>{new}</>"
-                    ))
-                );
+                out.push(cformat!(
+                    "template variable <bold>{old}</> is deprecated in favor of <bold>{new}</>"
+                ));
             }
             DeprecationKind::CommitGeneration(commit_gen) => {
                 if commit_gen.has_top_level {
-                    let _ = writeln!(
-                        out,
-                        "{}",
-                        warning_message(cformat!(
-                            "{label}: <bold>[commit-generation]</> is deprecated in favor of <bold>[commit.generation]</>"
-                        ))
-                    );
+                    out.push(cformat!(
+                        "<bold>[commit-generation]</> is deprecated in favor of <bold>[commit.generation]</>"
+                    ));
                 }
                 for k in &commit_gen.project_keys {
-                    let _ = writeln!(
-                        out,
-                        "{}",
-                        warning_message(cformat!(
-                            "{label}: <bold>[projects.\"{k}\".commit-generation]</> is deprecated in favor of <bold>[projects.\"{k}\".commit.generation]</>"
-                        ))
-                    );
+                    out.push(cformat!(
+                        "<bold>[projects.\"{k}\".commit-generation]</> is deprecated in favor of <bold>[projects.\"{k}\".commit.generation]</>"
+                    ));
                 }
             }
             DeprecationKind::ApprovedCommands => {
-                let _ = writeln!(
-                    out,
-                    "{}",
-                    warning_message(cformat!(
-                        "{label}: <bold>approved-commands</> under <bold>[projects]</> is deprecated in favor of <bold>approvals.toml</>"
-                    ))
-                );
+                out.push(cformat!(
+                    "<bold>approved-commands</> under <bold>[projects]</> is deprecated in favor of <bold>approvals.toml</>"
+                ));
             }
             DeprecationKind::Select => {
-                let _ = writeln!(
-                    out,
-                    "{}",
-                    warning_message(cformat!(
-                        "{label}: <bold>[select]</> is deprecated in favor of <bold>[switch.picker]</>"
-                    ))
-                );
+                out.push(cformat!(
+                    "<bold>[select]</> is deprecated in favor of <bold>[switch.picker]</>"
+                ));
             }
             DeprecationKind::CiSection => {
-                let _ = writeln!(
-                    out,
-                    "{}",
-                    warning_message(cformat!(
-                        "{label}: <bold>[ci]</> is deprecated in favor of <bold>[forge]</>"
-                    ))
-                );
+                out.push(cformat!(
+                    "<bold>[ci]</> is deprecated in favor of <bold>[forge]</>"
+                ));
             }
             DeprecationKind::NoFf => {
-                let _ = writeln!(
-                    out,
-                    "{}",
-                    warning_message(cformat!(
-                        "{label}: <bold>merge.no-ff</> is deprecated in favor of <bold>merge.ff</> (inverted)"
-                    ))
-                );
+                out.push(cformat!(
+                    "<bold>merge.no-ff</> is deprecated in favor of <bold>merge.ff</> (inverted)"
+                ));
             }
             DeprecationKind::NoCd => {
-                let _ = writeln!(
-                    out,
-                    "{}",
-                    warning_message(cformat!(
-                        "{label}: <bold>switch.no-cd</> is deprecated in favor of <bold>switch.cd</> (inverted)"
-                    ))
-                );
+                out.push(cformat!(
+                    "<bold>switch.no-cd</> is deprecated in favor of <bold>switch.cd</> (inverted)"
+                ));
             }
             DeprecationKind::SwitchPickerTimeout => {
-                let _ = writeln!(
-                    out,
-                    "{}",
-                    warning_message(cformat!(
-                        "{label}: <bold>switch.picker.timeout-ms</> is no longer used — the picker now renders progressively"
-                    ))
-                );
+                out.push(cformat!(
+                    "<bold>switch.picker.timeout-ms</> is no longer used — the picker now renders progressively"
+                ));
             }
             DeprecationKind::ListTaskTimeout => {
-                let _ = writeln!(
-                    out,
-                    "{}",
-                    warning_message(cformat!(
-                        "{label}: <bold>list.task-timeout-ms</> is no longer used — <bold>list.timeout-ms</> bounds the collect phase"
-                    ))
-                );
+                out.push(cformat!(
+                    "<bold>list.task-timeout-ms</> is no longer used — <bold>list.timeout-ms</> bounds the collect phase"
+                ));
             }
             DeprecationKind::JsonSchemaUnset => {
-                let _ = writeln!(
-                    out,
-                    "{}",
-                    warning_message(cformat!(
-                        "{label}: <bold>[list] json-schema</> is unset; a future release switches the JSON default to schema 2"
-                    ))
-                );
+                out.push(cformat!(
+                    "<bold>[list] json-schema</> is unset; a future release switches the JSON default to schema 2"
+                ));
             }
         }
     }
@@ -1811,6 +1782,7 @@ const USER_ONLY_COMMIT_GENERATION_PATHS: &[&str] = &[
     "commit.generation.template-file",
     "commit.generation.squash-template",
     "commit.generation.squash-template-file",
+    "commit.generation.review-template",
 ];
 
 /// Returns the config where a misplaced *nested* key belongs.
@@ -15,6 +15,7 @@
 use std::collections::{BTreeSet, HashMap};
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::path::Path;
 use std::sync::Arc;
 
 use color_print::cformat;
@@ -31,6 +32,7 @@ use crate::styling::{
     eprintln, error_message, format_bash_with_gutter, format_with_gutter, hint_message,
     info_message, verbosity,
 };
+use crate::trace::Span;
 
 /// Active-context vars: point at the branch the operation acts on.
 ///
@@ -67,10 +69,10 @@ pub const EXEC_BASE_VARS: &[&str] = &["cwd"];
 /// Template variables available in every context: the concatenation of
 /// [`ACTIVE_VARS`], [`REPO_VARS`], and [`EXEC_BASE_VARS`].
 ///
-/// Populated by `build_hook_context()` in `command_executor.rs`. Operation-
-/// context vars (`base`, `target`, `pr_*`) and infrastructure vars
-/// (`hook_type`, `hook_name`) are not in the base set — they're added per-
-/// scope by `hook_extras` and the hook runner itself.
+/// Populated by [`build_hook_context`]. Operation-context vars (`base`,
+/// `target`, `pr_*`) and infrastructure vars (`hook_type`, `hook_name`) are
+/// not in the base set — they're added per-scope by `hook_extras` and the hook
+/// runner itself.
 pub fn base_vars() -> Vec<&'static str> {
     let mut v = Vec::with_capacity(ACTIVE_VARS.len() + REPO_VARS.len() + EXEC_BASE_VARS.len());
     v.extend_from_slice(ACTIVE_VARS);
@@ -206,6 +208,168 @@ impl VarScope<'_> {
     }
 }
 
+/// Resolve the template variables for one command invocation in `worktree_path`,
+/// acting on `branch` (`None` for a detached HEAD).
+///
+/// The sole producer of [`TemplateContext`], which owns what happens to the
+/// result: expansion, the JSON a hook child reads on stdin, the `-v` table.
+/// The CLI builds through `CommandContext`; the library API's
+/// [`ConfiguredHooks`](crate::api::ConfiguredHooks) calls this directly.
+///
+/// `scope` decides how much to resolve. [`VarScope::Referenced`] skips the git
+/// lookups behind vars the templates don't name (`var_commit` rev-parse,
+/// `var_default_branch` cold detection, `branch().upstream()`), and follows
+/// from who reads the finished context, not from what kind of command is
+/// running:
+///
+/// - **`Referenced`** when only the templates read it. A var they don't name
+///   would be computed and discarded. Aliases pass `referenced_vars_for_config`
+///   (extended via `alias_context_filter`); `wt switch --execute` passes
+///   `referenced_vars_for_templates` over its command and trailing args.
+/// - **`All`** when something reads keys the `{{ }}` templates never mention.
+///   Either the child receives the whole context as JSON on stdin and may pull
+///   keys out of it (e.g. via `jq`) — hook pipelines, `wt step for-each` — or
+///   the command's output *is* the variable listing, which filtering would
+///   narrow to what the body happens to reference: `wt step eval -v`, and the
+///   hook pipeline's own `format_hook_variables` table.
+///
+/// The template-preview paths (`render_hook_commands`, `wt config alias`) fit
+/// neither case and still pass `All`: they expand and print one line per
+/// configured command, so filtering would be correct but saves nothing an
+/// interactive display would notice.
+pub fn build_hook_context(
+    repo: &Repository,
+    worktree_path: &Path,
+    branch: Option<&str>,
+    extra_vars: &[(&str, &str)],
+    scope: VarScope<'_>,
+) -> anyhow::Result<TemplateContext> {
+    let repo_root = repo.repo_path()?;
+    let repo_name = repo_root
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("unknown");
+
+    // Convert paths to POSIX format for Git Bash compatibility on Windows.
+    // This avoids shell escaping of `:` and `\` characters in Windows paths.
+    let worktree = to_posix_path(&worktree_path.to_string_lossy());
+    let worktree_name = worktree_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("unknown");
+
+    let repo_path = to_posix_path(&repo_root.to_string_lossy());
+
+    // Cheap vars (already in scope, no I/O) are populated unconditionally —
+    // skipping them saves no work and would just turn the verbose table's
+    // `(unused)` label into noise. Only the expensive blocks below
+    // (subprocesses, git config / remote lookups) consult `scope`.
+    let mut map = HashMap::new();
+    map.insert("repo".into(), repo_name.into());
+    map.insert("branch".into(), branch.unwrap_or("HEAD").into());
+    map.insert("worktree_name".into(), worktree_name.into());
+    map.insert("repo_path".into(), repo_path.clone());
+    map.insert("worktree_path".into(), worktree.clone());
+    // Deprecated aliases (kept for backward compatibility)
+    map.insert("main_worktree".into(), repo_name.into());
+    map.insert("repo_root".into(), repo_path);
+    map.insert("worktree".into(), worktree);
+
+    if let Some(parsed_remote) = repo.primary_remote_parsed_url() {
+        map.insert("owner".into(), parsed_remote.owner().to_string());
+        map.insert("remote_repo".into(), parsed_remote.repo().to_string());
+    }
+
+    // Default branch
+    if scope.wants("default_branch") {
+        let _span = Span::new("var_default_branch");
+        if let Some(default_branch) = repo.default_branch() {
+            map.insert("default_branch".into(), default_branch);
+        }
+    }
+
+    // Primary worktree path (where established files live)
+    if scope.wants("primary_worktree_path") || scope.wants("main_worktree_path") {
+        let _span = Span::new("var_primary_worktree");
+        if let Ok(Some(path)) = repo.primary_worktree() {
+            let path_str = to_posix_path(&path.to_string_lossy());
+            if scope.wants("primary_worktree_path") {
+                map.insert("primary_worktree_path".into(), path_str.clone());
+            }
+            // Deprecated alias
+            if scope.wants("main_worktree_path") {
+                map.insert("main_worktree_path".into(), path_str);
+            }
+        }
+    }
+
+    // Resolve commit from the Active branch, not HEAD at discovery path.
+    // This ensures {{ commit }} follows the Active branch even when the
+    // CommandContext points to a different worktree than where we're running.
+    // Detached HEAD (`branch == None`) must read HEAD from
+    // `worktree_path`, not the running worktree: `wt step for-each`
+    // iterates over sibling worktrees, and a sibling on detached HEAD has a
+    // different HEAD than the worktree `wt` runs in. Branched contexts go
+    // through `rev-parse <branch>`, which is repo-wide.
+    if scope.wants("commit") || scope.wants("short_commit") {
+        let _span = Span::new("var_commit");
+        let commit = match branch {
+            Some(branch) => repo
+                .run_command(&["rev-parse", "--verify", "--end-of-options", branch])
+                .ok()
+                .map(|s| s.trim().to_owned()),
+            None => repo.worktree_at(worktree_path).head_sha().ok().flatten(),
+        };
+        if let Some(commit) = commit {
+            if scope.wants("short_commit")
+                && let Ok(short) = repo.short_sha(&commit)
+            {
+                map.insert("short_commit".into(), short);
+            }
+            if scope.wants("commit") {
+                map.insert("commit".into(), commit);
+            }
+        }
+    }
+
+    if scope.wants("remote") || scope.wants("remote_url") || scope.wants("upstream") {
+        let _span = Span::new("var_remote");
+        if let Ok(remote) = repo.primary_remote() {
+            if scope.wants("remote") {
+                map.insert("remote".into(), remote.to_string());
+            }
+            // Add remote URL for conditional hook execution (e.g., GitLab vs GitHub)
+            if scope.wants("remote_url")
+                && let Some(url) = repo.remote_url(&remote)
+            {
+                map.insert("remote_url".into(), url);
+            }
+            if scope.wants("upstream")
+                && let Some(branch) = branch
+                && let Ok(Some(upstream)) = repo.branch(branch).upstream()
+            {
+                map.insert("upstream".into(), upstream);
+            }
+        }
+    }
+
+    // Execution directory — always where the hook command runs, even when
+    // worktree_path points to an Active identity that doesn't exist on disk.
+    map.insert(
+        "cwd".into(),
+        to_posix_path(&worktree_path.to_string_lossy()),
+    );
+
+    // Caller-set bindings (e.g., merge target, switch base, alias args).
+    // Aliases pre-filter via `AliasOptions::parse`, hooks pass everything;
+    // either way the value is already computed, so insert unconditionally.
+    for (k, v) in extra_vars {
+        map.insert((*k).into(), (*v).into());
+    }
+
+    Ok(TemplateContext::from_vars(map))
+}
+
 /// The context in which a template will be expanded.
 ///
 /// Validation uses this to answer "which variables are available here?" —
@@ -398,15 +562,16 @@ pub fn format_base_variables(ctx: &TemplateContext) -> String {
 /// - [`ALIAS_ARGS_KEY`] (`args`) — always present in alias scope (`run_alias`
 ///   inserts it after `build_hook_context`), so include it so the verbose
 ///   table renders the row.
-/// - `branch` when the body references `vars` — `expand_template` reads
-///   `branch` out of the context map to look up `{{ vars.X }}` from git
-///   config at execution time. Bare `{{ vars }}`, `{{ vars.X }}`, and
-///   `{{ vars["X"] }}` all surface `vars` in `undeclared_variables`.
+/// - `branch` when the body references `vars` or `ports` — `expand_template`
+///   reads `branch` out of the context map to look up `{{ vars.X }}` from git
+///   config, and `{{ ports.X }}` from the port registry, at execution time.
+///   Bare `{{ vars }}`, `{{ vars.X }}`, and `{{ vars["X"] }}` all surface
+///   `vars` in `undeclared_variables`.
 ///
 /// New implicit dependencies (template-level reads from the context map that
 /// `undeclared_variables` doesn't see) belong here, not at each call site.
 pub fn alias_context_filter(mut referenced: BTreeSet<String>) -> BTreeSet<String> {
-    let needs_branch = referenced.contains("vars");
+    let needs_branch = referenced.contains("vars") || referenced.contains("ports");
     referenced.insert(ALIAS_ARGS_KEY.to_string());
     if needs_branch {
         referenced.insert("branch".to_string());
@@ -484,13 +649,50 @@ impl Object for LiteralVars {
     }
 }
 
+/// The `ports` object: `{{ ports.<service> }}` is the branch's port for that
+/// service from the [`Ports`](crate::ports::Ports) registry — assigned on first
+/// access when `assign` is set, otherwise only what is already recorded. A
+/// registry that can't answer leaves the attribute undefined, which errors
+/// like any undefined variable.
+#[derive(Debug)]
+struct BranchPorts {
+    registry: crate::ports::Ports,
+    branch: String,
+    assign: bool,
+}
+
+impl Object for BranchPorts {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        let service = key.as_str()?;
+        let port = if self.assign {
+            self.registry.allocate(&self.branch, service).map(Some)
+        } else {
+            self.registry
+                .for_branch(&self.branch)
+                .map(|mut ports| ports.remove(service))
+        };
+        match port {
+            Ok(port) => port.map(Value::from),
+            Err(err) => {
+                tracing::debug!("Failed to look up port {}.{service}: {err:#}", self.branch);
+                None
+            }
+        }
+    }
+}
+
 /// How template expansion resolves `{{ vars.<key> }}`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VarsMode {
     /// Read values from git config — what a command runs with.
     Resolve,
     /// Render each reference back as `{{ vars.<key> }}`, for a preview.
+    /// `{{ ports.<service> }}` likewise, so a preview assigns no port.
     Literal,
+    /// Read values as [`Self::Resolve`] does, but leave the port registry
+    /// alone: `{{ ports.<service> }}` is only a port already assigned. For
+    /// read-only listings such as `wt list`'s URL column.
+    Recorded,
 }
 
 /// Space-join POSIX-shell-escaped args — the canonical rendering of
@@ -504,7 +706,7 @@ fn shell_join(args: &[String]) -> String {
 }
 
 /// Hash a string to a port in range 10000-19999.
-fn string_to_port(s: &str) -> u16 {
+pub fn string_to_port(s: &str) -> u16 {
     let mut h = std::collections::hash_map::DefaultHasher::new();
     s.hash(&mut h);
     10000 + (h.finish() % 10000) as u16
@@ -864,6 +1066,35 @@ fn build_undefined_vars_error(
 /// every template user gets the same filters, functions, and
 /// undefined-behavior settings.
 pub fn template_environment(repo: &Repository) -> Environment<'static> {
+    let mut env = filter_environment();
+
+    // Register worktree_path_of_branch function for looking up branch worktree paths.
+    // Returns raw paths — shell escaping is applied by the formatter at output time.
+    let repo_clone = repo.clone();
+    env.add_function("worktree_path_of_branch", move |branch: String| -> String {
+        repo_clone
+            .worktree_for_branch(&branch)
+            .ok()
+            .flatten()
+            .map(|p| to_posix_path(&p.to_string_lossy()))
+            .unwrap_or_default()
+    });
+
+    env
+}
+
+/// [`template_environment`] for checks that run outside any repository
+/// (`wt config validate`). `worktree_path_of_branch` is registered so calls to
+/// it resolve, but it always returns an empty string.
+fn repoless_template_environment() -> Environment<'static> {
+    let mut env = filter_environment();
+    env.add_function("worktree_path_of_branch", |_branch: String| String::new());
+    env
+}
+
+/// The filters and undefined-behavior settings shared by every template
+/// environment; the repo-backed functions are layered on by the callers.
+fn filter_environment() -> Environment<'static> {
     let mut env = Environment::new();
     // SemiStrict: errors on undefined variable use (printing, iteration) but allows
     // truthiness checks ({% if var %}). This catches typos while supporting optional vars.
@@ -897,18 +1128,6 @@ pub fn template_environment(repo: &Repository) -> Environment<'static> {
     });
     env.add_filter("codename", codename_filter);
 
-    // Register worktree_path_of_branch function for looking up branch worktree paths.
-    // Returns raw paths — shell escaping is applied by the formatter at output time.
-    let repo_clone = repo.clone();
-    env.add_function("worktree_path_of_branch", move |branch: String| -> String {
-        repo_clone
-            .worktree_for_branch(&branch)
-            .ok()
-            .flatten()
-            .map(|p| to_posix_path(&p.to_string_lossy()))
-            .unwrap_or_default()
-    });
-
     env
 }
 
@@ -1012,6 +1231,27 @@ pub fn validate_template(
     scope: ValidationScope,
     repo: &Repository,
     name: &str,
+) -> Result<(), TemplateExpandError> {
+    validate_template_in(&template_environment(repo), template, scope, name)
+}
+
+/// [`validate_template`] without a repository, for `wt config validate`.
+///
+/// Identical checks; `worktree_path_of_branch` renders as an empty string
+/// during the trial expansion instead of looking up a worktree.
+pub fn validate_template_without_repo(
+    template: &str,
+    scope: ValidationScope,
+    name: &str,
+) -> Result<(), TemplateExpandError> {
+    validate_template_in(&repoless_template_environment(), template, scope, name)
+}
+
+fn validate_template_in(
+    env: &Environment<'static>,
+    template: &str,
+    scope: ValidationScope,
+    name: &str,
 ) -> Result<(), TemplateExpandError> {
     let available = vars_available_in(scope);
     let mut context: HashMap<String, minijinja::Value> = available
@@ -1024,6 +1264,11 @@ pub fn validate_template(
         "vars".to_string(),
         minijinja::Value::from_serialize(std::collections::BTreeMap::<String, String>::new()),
     );
+    // Every `ports.<service>` is defined; validation assigns none.
+    context.insert(
+        "ports".to_string(),
+        Value::from_object(LiteralVars("ports".to_string())),
+    );
     // In alias and hook scopes, inject `args` as an empty sequence so
     // `{{ args }}`, `{{ args[0] | default(...) }}`, `{{ args | length }}`,
     // and `{% for a in args %}…{% endfor %}` all validate.
@@ -1034,14 +1279,13 @@ pub fn validate_template(
         );
     }
 
-    let env = template_environment(repo);
-
     let tmpl = env
         .template_from_named_str(name, template)
         .map_err(|e| build_template_error(&e, template, name, Vec::new()))?;
 
     let mut allowed: BTreeSet<String> = available.iter().map(|k| k.to_string()).collect();
     allowed.insert("vars".to_string());
+    allowed.insert("ports".to_string());
     let mut undefined: Vec<String> = tmpl
         .undeclared_variables(false)
         .into_iter()
@@ -1205,7 +1449,7 @@ pub fn expand_template_with(
                     Value::from_object(LiteralVars("vars".to_string())),
                 );
             }
-            VarsMode::Resolve => {
+            VarsMode::Resolve | VarsMode::Recorded => {
                 if let Some(branch) = vars.get("branch") {
                     context.insert(
                         "vars".to_string(),
@@ -1215,6 +1459,29 @@ pub fn expand_template_with(
             }
         }
     }
+    // `ports` the same way, from the port registry.
+    if tmpl.undeclared_variables(false).contains("ports") {
+        match vars_mode {
+            VarsMode::Literal => {
+                context.insert(
+                    "ports".to_string(),
+                    Value::from_object(LiteralVars("ports".to_string())),
+                );
+            }
+            VarsMode::Resolve | VarsMode::Recorded => {
+                if let Some(branch) = vars.get("branch") {
+                    context.insert(
+                        "ports".to_string(),
+                        Value::from_object(BranchPorts {
+                            registry: crate::ports::Ports::for_repo(repo),
+                            branch: branch.to_string(),
+                            assign: vars_mode == VarsMode::Resolve,
+                        }),
+                    );
+                }
+            }
+        }
+    }
 
     let result = tmpl
         .render(minijinja::Value::from_object(context))
@@ -3308,4 +3575,57 @@ mod tests {
             validate_list_column_template("{{ branch | nosuch }}", &test.repo, "t").unwrap_err();
         assert!(err.message.contains("nosuch"), "got: {}", err.message);
     }
+
+    #[test]
+    fn test_ports_template() {
+        let test = test_repo();
+        let hook = ValidationScope::Hook(HookType::PostCreate);
+        assert!(validate_template("{{ ports.web }}", hook, &test.repo, "test").is_ok());
+
+        // A preview renders the reference and assigns nothing.
+        let vars = HashMap::from([("branch", "feat")]);
+        let preview = expand_template_with(
+            "--port {{ ports.web }}",
+            &vars,
+            ShellEscapeMode::Posix,
+            &test.repo,
+            "test",
+            VarsMode::Literal,
+        )
+        .unwrap();
+        assert_eq!(preview, "--port {{ ports.web }}");
+        let registry = crate::ports::Ports::for_repo(&test.repo);
+        assert!(registry.all().unwrap().is_empty());
+
+        // A read-only listing doesn't assign one either.
+        let recorded = |template| {
+            expand_template_with(
+                template,
+                &vars,
+                ShellEscapeMode::Literal,
+                &test.repo,
+                "test",
+                VarsMode::Recorded,
+            )
+        };
+        assert!(recorded("{{ ports.web }}").is_err());
+        assert!(registry.all().unwrap().is_empty());
+
+        // Resolving assigns the branch a port and records it.
+        let port = expand_template(
+            "{{ ports.web }}",
+            &vars,
+            ShellEscapeMode::Literal,
+            &test.repo,
+            "test",
+        )
+        .unwrap();
+        assert_eq!(
+            port,
+            registry.for_branch("feat").unwrap()["web"].to_string(),
+            "{:?}",
+            registry.all()
+        );
+        assert_eq!(recorded("{{ ports.web }}").unwrap(), port);
+    }
 }
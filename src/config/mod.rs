@@ -22,10 +22,13 @@ pub(crate) mod deprecation;
 mod expansion;
 mod hooks;
 mod project;
+mod schema;
 #[cfg(test)]
 mod test;
 mod unknown_tree;
 mod user;
+#[cfg(feature = "cli")]
+mod validate;
 
 /// Trait for worktrunk config types (user and project config).
 ///
@@ -140,7 +143,7 @@ pub fn is_user_project_override_key(key: &str) -> bool {
 }
 
 // Re-export public types
-pub use approvals::{Approvals, approvals_path, require_approvals_path};
+pub use approvals::{ApprovalRecord, Approvals, approvals_path, require_approvals_path};
 pub use commands::{Command, CommandConfig, HookStep, append_aliases};
 pub use deprecation::CheckAndMigrateResult;
 pub use deprecation::ConfigFileKind;
@@ -148,6 +151,7 @@ pub use deprecation::DeprecationInfo;
 pub use deprecation::check_and_migrate;
 pub use deprecation::compute_migrated_content;
 pub use deprecation::copy_approved_commands_to_approvals_file;
+pub use deprecation::deprecation_messages;
 pub use deprecation::detect_deprecations;
 pub use deprecation::format_deprecation_details;
 pub use deprecation::format_deprecation_warnings;
@@ -165,16 +169,22 @@ pub use deprecation::{DeprecationKind, Deprecations};
 pub use expansion::{
     ACTIVE_VARS, ALIAS_ARGS_KEY, DEPRECATED_TEMPLATE_VARS, EXEC_BASE_VARS, REPO_VARS,
     TemplateContext, TemplateExpandError, ValidationScope, VarScope, VarsMode,
-    alias_context_filter, base_vars, expand_template, format_alias_variables,
-    format_base_variables, format_hook_variables, redact_credentials, referenced_vars_for_config,
-    referenced_vars_for_templates, sanitize_branch_name, sanitize_db, short_hash,
-    template_environment, template_references_var, validate_list_column_template,
-    validate_template, validate_template_syntax, vars_available_in, vars_map_to_value,
+    alias_context_filter, base_vars, build_hook_context, expand_template, expand_template_with,
+    format_alias_variables, format_base_variables, format_hook_variables, redact_credentials,
+    referenced_vars_for_config, referenced_vars_for_templates, sanitize_branch_name, sanitize_db,
+    short_hash, string_to_port, template_environment, template_references_var,
+    validate_list_column_template, validate_template, validate_template_syntax,
+    validate_template_without_repo, vars_available_in, vars_map_to_value,
 };
 pub use hooks::HooksConfig;
 pub use project::{
-    ProjectCiConfig, ProjectCommitConfig, ProjectCommitGenerationConfig, ProjectConfig,
-    ProjectForgeConfig, ProjectListConfig, valid_project_config_keys,
+    ContainerRuntime, ProjectCiConfig, ProjectCommitConfig, ProjectCommitGenerationConfig,
+    ProjectConfig, ProjectContainerConfig, ProjectForgeConfig, ProjectListConfig,
+    valid_project_config_keys,
+};
+pub use schema::{
+    PROJECT_CONFIG_SCHEMA_URL, USER_CONFIG_SCHEMA_URL, config_schema_document, config_schema_url,
+    schema_directive,
 };
 pub use unknown_tree::{
     UnknownAnalysis, UnknownTree, UnknownWarning, collect_unknown_warnings, compute_unknown_tree,
@@ -182,12 +192,16 @@ pub use unknown_tree::{
 pub(crate) use user::LoadError;
 pub(crate) use user::project_match::matching_keys as matching_project_keys;
 pub use user::{
-    CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, ListColumnConfig, ListConfig,
-    MergeConfig, RemoveConfig, ResolvedConfig, StageMode, StepConfig, SwitchConfig,
-    SwitchPickerConfig, UserConfig, UserProjectOverrides, config_path, config_path_for_display,
-    default_config_path, default_system_config_path, require_config_path, set_config_overrides,
-    set_config_path, system_config_path, valid_user_config_keys,
+    CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, HookPolicyConfig, ListColumnConfig,
+    ListConfig, LogsConfig, MergeConfig, NotifyConfig, NotifyEventConfig, NotifySink,
+    PickerKeysConfig, RemoteHostConfig, RemoveConfig, ResolvedConfig, SandboxMode, StageMode,
+    StepConfig, SubmoduleMode, SwitchConfig, SwitchPickerConfig, UserConfig, UserProjectOverrides,
+    config_path, config_path_for_display, default_config_path, default_system_config_path,
+    require_config_path, set_config_overrides, set_config_path, system_config_path,
+    valid_user_config_keys,
 };
+#[cfg(feature = "cli")]
+pub use validate::{ConfigDiagnostic, DiagnosticCode, Severity, validate_config};
 
 #[cfg(test)]
 mod tests {
@@ -580,6 +594,7 @@ task2 = "echo 'Task 2 running' > task2.txt"
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
 
@@ -125,6 +125,83 @@ pub struct ProjectForgeConfig {
     pub hostname: Option<String>,
 }
 
+/// Project-level container configuration: one container per worktree.
+///
+/// `wt switch` starts the worktree's container (creating it on first use)
+/// with the worktree mounted at its own path; project hooks and `--execute`
+/// commands then run inside it, and removing the worktree removes it.
+///
+/// # Example
+///
+/// ```toml
+/// [container]
+/// image = "node:22"
+/// ports = ["{{ branch | hash_port }}:3000"]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProjectContainerConfig {
+    /// Image to run. Mutually exclusive with `build`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+
+    /// Build context, relative to the worktree, to build the image from.
+    /// The image is tagged by the Dockerfile's content, so it is rebuilt
+    /// only when the Dockerfile changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build: Option<String>,
+
+    /// Dockerfile, relative to the worktree (default: `Dockerfile` in the
+    /// build context).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dockerfile: Option<String>,
+
+    /// Published ports as `host:container`, rendered as templates — e.g.
+    /// `"{{ branch | hash_port }}:3000"`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ports: Vec<String>,
+
+    /// Extra arguments to the runtime's `run` (e.g. `["--env", "CI=1"]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+
+    /// The container's main process (default: `["sleep", "infinity"]`, which
+    /// keeps the container up for hooks and `--execute` to run in).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+
+    /// Container runtime: `docker` or `podman` (default: `docker` when on
+    /// `PATH`, else `podman`). Project config is checked in, so it can't name
+    /// an arbitrary program.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<ContainerRuntime>,
+}
+
+/// The container runtimes `[container] runtime` may name.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerRuntime {
+    Docker,
+    Podman,
+}
+
+impl ContainerRuntime {
+    /// The program to run.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Docker => "docker",
+            Self::Podman => "podman",
+        }
+    }
+}
+
+impl ProjectContainerConfig {
+    /// Returns true if a container is configured.
+    pub fn is_configured(&self) -> bool {
+        self.image.is_some() || self.build.is_some()
+    }
+}
+
 impl ProjectListConfig {
     /// Returns true if any list configuration is set.
     pub fn is_configured(&self) -> bool {
@@ -229,6 +306,10 @@ pub struct ProjectConfig {
     #[serde(default, skip_serializing_if = "is_default")]
     pub step: StepConfig,
 
+    /// Per-worktree container (image, ports, runtime)
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub container: ProjectContainerConfig,
+
     /// Command aliases for `wt <name>`.
     ///
     /// Each alias maps a name to a [`CommandConfig`] — a string for a single
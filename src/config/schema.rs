@@ -0,0 +1,185 @@
+//! Published JSON Schemas for the config files.
+//!
+//! `wt config schema` prints these, the docs sync commits them under
+//! `docs/public/schema/`, and `wt config create` points new files at them with
+//! a `#:schema` directive so taplo-based editors (Even Better TOML) complete
+//! and validate keys as they are typed.
+//!
+//! The documents are the schemars derivations of [`UserConfig`] and
+//! [`ProjectConfig`], closed at the top level: a key that isn't a field is an
+//! editor error, which is what catches a user-only setting written into
+//! `.config/wt.toml`. Deprecated top-level keys stay accepted (flagged
+//! `deprecated`) so an old config isn't a wall of red before
+//! `wt config update` runs.
+
+use super::deprecation::{ConfigFileKind, DEPRECATED_SECTION_KEYS};
+use super::{ProjectConfig, UserConfig, WorktrunkConfig};
+
+/// Where the user config schema is published.
+pub const USER_CONFIG_SCHEMA_URL: &str = "https://worktrunk.dev/schema/user-config.json";
+
+/// Where the project config schema is published.
+pub const PROJECT_CONFIG_SCHEMA_URL: &str = "https://worktrunk.dev/schema/project-config.json";
+
+/// The published URL of the schema for a config file. System config shares
+/// the user config schema.
+pub fn config_schema_url(kind: ConfigFileKind) -> &'static str {
+    match kind {
+        ConfigFileKind::User | ConfigFileKind::System => USER_CONFIG_SCHEMA_URL,
+        ConfigFileKind::Project => PROJECT_CONFIG_SCHEMA_URL,
+    }
+}
+
+/// The `#:schema` directive line taplo reads from the top of a TOML file.
+pub fn schema_directive(kind: ConfigFileKind) -> String {
+    format!("#:schema {}", config_schema_url(kind))
+}
+
+/// The complete JSON Schema document for a config file.
+pub fn config_schema_document(kind: ConfigFileKind) -> serde_json::Value {
+    match kind {
+        ConfigFileKind::User | ConfigFileKind::System => {
+            document::<UserConfig>(kind, "Worktrunk user config")
+        }
+        ConfigFileKind::Project => {
+            document::<ProjectConfig>(kind, "Worktrunk project config (.config/wt.toml)")
+        }
+    }
+}
+
+fn document<C>(kind: ConfigFileKind, title: &str) -> serde_json::Value
+where
+    C: WorktrunkConfig + schemars::JsonSchema,
+{
+    let schema = schemars::SchemaGenerator::default().into_root_schema_for::<C>();
+    let mut value = serde_json::to_value(&schema).expect("schema serializes");
+    let object = value.as_object_mut().expect("schema is an object");
+    object.insert("$id".to_string(), config_schema_url(kind).into());
+    object.insert("title".to_string(), title.into());
+
+    let properties = object
+        .entry("properties")
+        .or_insert_with(|| serde_json::json!({}))
+        .as_object_mut()
+        .expect("properties is an object");
+    // Renamed hooks are still read (and migrated by `wt config update`).
+    for (old, new) in [("pre-create", "pre-start"), ("post-create", "post-start")] {
+        properties.entry(old).or_insert_with(|| {
+            serde_json::json!({
+                "$ref": "#/$defs/CommandConfig",
+                "deprecated": true,
+                "description": format!("Deprecated: renamed to `{new}`. Run `wt config update` to migrate."),
+            })
+        });
+    }
+    for section in DEPRECATED_SECTION_KEYS {
+        if !C::is_valid_key(section.canonical_top_key) {
+            continue;
+        }
+        let description = format!(
+            "Deprecated: moved to {}. Run `wt config update` to migrate.",
+            section.canonical_display
+        );
+        let property = properties
+            .entry(section.key)
+            .or_insert_with(|| serde_json::json!({ "type": "object" }));
+        if let Some(property) = property.as_object_mut() {
+            property.insert("deprecated".to_string(), true.into());
+            property.insert("description".to_string(), description.into());
+        }
+    }
+    object.insert("additionalProperties".to_string(), false.into());
+    value
+}
+
+#[cfg(all(test, feature = "cli"))]
+mod tests {
+    use super::*;
+
+    fn validator(kind: ConfigFileKind) -> jsonschema::Validator {
+        jsonschema::validator_for(&config_schema_document(kind)).unwrap()
+    }
+
+    fn instance(toml: &str) -> serde_json::Value {
+        serde_json::to_value(toml.parse::<toml::Table>().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_example_configs_match_schema() {
+        let user = include_str!("../../dev/config.example.toml");
+        let project = include_str!("../../dev/wt.example.toml");
+        let uncomment = |s: &str| {
+            s.lines()
+                .map(|l| l.strip_prefix("# ").unwrap_or(l))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        for (kind, contents) in [
+            (ConfigFileKind::User, user),
+            (ConfigFileKind::Project, project),
+        ] {
+            let value = instance(contents);
+            let errors: Vec<String> = validator(kind)
+                .iter_errors(&value)
+                .map(|e| e.to_string())
+                .collect();
+            assert!(errors.is_empty(), "{kind:?}: {errors:?}");
+            // The commented-out example values are valid too, where they parse.
+            if let Ok(table) = uncomment(contents).parse::<toml::Table>() {
+                let value = serde_json::to_value(table).unwrap();
+                assert!(validator(kind).is_valid(&value), "{kind:?} uncommented");
+            }
+        }
+    }
+
+    #[test]
+    fn test_user_only_key_rejected_in_project_schema() {
+        let value = instance("skip-shell-integration-prompt = true\n");
+        assert!(validator(ConfigFileKind::User).is_valid(&value));
+        assert!(!validator(ConfigFileKind::Project).is_valid(&value));
+
+        let value = instance("[forge]\nplatform = \"github\"\n");
+        assert!(validator(ConfigFileKind::Project).is_valid(&value));
+        assert!(!validator(ConfigFileKind::User).is_valid(&value));
+    }
+
+    #[test]
+    fn test_hook_forms_aliases_and_columns() {
+        let value = instance(
+            r#"
+pre-start = "npm ci"
+pre-create = "legacy"
+
+[post-start]
+server = "npm run dev"
+
+[[pre-merge]]
+lint = "npm run lint"
+[[pre-merge]]
+test = "npm test"
+
+[aliases]
+deploy = "make deploy"
+
+[list.custom-columns.Ticket]
+template = "{{ branch }}"
+"#,
+        );
+        let errors: Vec<String> = validator(ConfigFileKind::User)
+            .iter_errors(&value)
+            .map(|e| e.to_string())
+            .collect();
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+
+    #[test]
+    fn test_deprecated_sections_accepted_where_canonical_is_valid() {
+        let user = config_schema_document(ConfigFileKind::User);
+        assert_eq!(user["properties"]["commit-generation"]["deprecated"], true);
+        assert!(user["properties"].get("ci").is_none());
+
+        let project = config_schema_document(ConfigFileKind::Project);
+        assert_eq!(project["properties"]["ci"]["deprecated"], true);
+        assert_eq!(project["$id"], PROJECT_CONFIG_SCHEMA_URL);
+    }
+}
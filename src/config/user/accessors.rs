@@ -13,8 +13,8 @@ use crate::shell_exec::ShellEscapeMode;
 use super::UserConfig;
 use super::merge::Merge;
 use super::sections::{
-    CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, ListConfig, MergeConfig, RemoveConfig,
-    StepConfig, SwitchConfig, SwitchPickerConfig,
+    CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, HookPolicyConfig, ListConfig,
+    MergeConfig, RemoveConfig, StepConfig, SwitchConfig, SwitchPickerConfig,
 };
 
 /// Default worktree path template
@@ -173,6 +173,11 @@ impl UserConfig {
         self.merged_project_config(project, &self.step, |config| &config.step)
     }
 
+    /// Returns the hook execution policy (`[hooks]`) for a specific project.
+    pub fn hook_policy(&self, project: Option<&str>) -> HookPolicyConfig {
+        self.merged_project_config(project, &self.hook_policy, |config| &config.hook_policy)
+    }
+
     /// Returns the `wt step copy-ignored` config for a specific project.
     pub fn copy_ignored(&self, project: Option<&str>) -> CopyIgnoredConfig {
         self.step(project).copy_ignored.unwrap_or_default()
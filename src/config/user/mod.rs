@@ -64,9 +64,10 @@ pub use path::{
 pub use resolved::ResolvedConfig;
 pub use schema::valid_user_config_keys;
 pub use sections::{
-    CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, ListColumnConfig, ListConfig,
-    MergeConfig, RemoveConfig, StageMode, StepConfig, SwitchConfig, SwitchPickerConfig,
-    UserProjectOverrides,
+    CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, HookPolicyConfig, ListColumnConfig,
+    ListConfig, LogsConfig, MergeConfig, NotifyConfig, NotifyEventConfig, NotifySink,
+    PickerKeysConfig, RemoteHostConfig, RemoveConfig, SandboxMode, StageMode, StepConfig,
+    SubmoduleMode, SwitchConfig, SwitchPickerConfig, UserProjectOverrides,
 };
 
 /// Describes a problem encountered during config loading. Each variant
@@ -575,10 +576,27 @@ pub struct UserConfig {
     #[serde(default, skip_serializing_if = "super::is_default")]
     pub step: sections::StepConfig,
 
+    /// Hook execution policy (`[hooks]`): sandboxing for project hooks
+    #[serde(default, rename = "hooks", skip_serializing_if = "super::is_default")]
+    pub hook_policy: sections::HookPolicyConfig,
+
     /// Command aliases for `wt <name>`
     #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
     pub aliases: std::collections::BTreeMap<String, crate::config::commands::CommandConfig>,
 
+    /// Log retention (`[logs]`): rotated `commands.jsonl` generations
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub logs: sections::LogsConfig,
+
+    /// Notifications (`[notify]`): pipeline failures, CI changes, markers
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub notify: sections::NotifyConfig,
+
+    /// Hosts for remote worktrees (`[remotes.<name>]`), keyed by the name
+    /// `--on` takes
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub remotes: std::collections::BTreeMap<String, sections::RemoteHostConfig>,
+
     /// Skip the first-run shell integration prompt
     #[serde(
         default,
@@ -82,6 +82,13 @@ pub struct CommitGenerationConfig {
     #[serde(default, rename = "squash-template-file")]
     pub squash_template_file: Option<String>,
 
+    /// Inline template for the `wt step review` prompt
+    /// Available variables: {{ git_diff }}, {{ git_diff_stat }}, {{ branch }}, {{ repo }}
+    ///
+    /// *(Experimental — may change in future releases.)*
+    #[serde(default, rename = "review-template")]
+    pub review_template: Option<String>,
+
     /// Inline text appended to the commit and squash prompts inside a
     /// `<user-guidance>` block, after the main template's `<style>`
     /// section. Rendered as a minijinja template with the same variables
@@ -136,6 +143,10 @@ impl Merge for CommitGenerationConfig {
             template_file,
             squash_template,
             squash_template_file,
+            review_template: other
+                .review_template
+                .clone()
+                .or_else(|| self.review_template.clone()),
             template_append: other
                 .template_append
                 .clone()
@@ -170,7 +181,7 @@ pub struct ListColumnConfig {
     pub width: Option<usize>,
 
     /// Drop order when the terminal narrows; lower = kept longer (default: 9,
-    /// alongside the URL column; built-in columns range 0-13)
+    /// alongside the URL column; built-in columns range 0-14)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub priority: Option<u8>,
 }
@@ -212,7 +223,7 @@ pub struct ListConfig {
     /// these columns appear (a subset and/or reorder); empty means the default
     /// set. Built-ins are kebab identifiers (`branch`, `status`, `working-diff`,
     /// `ahead-behind`, `branch-diff`, `summary`, `upstream`, `ci`, `path`,
-    /// `url`, `commit`, `age`, `message`); custom columns are named by their
+    /// `url`, `agent`, `commit`, `age`, `message`); custom columns are named by their
     /// `[list.custom-columns]` header, so a selection mixes both in one list. A
     /// built-in wins over a custom header that collides with its name. The
     /// gutter type indicator always shows. A custom column omitted from a
@@ -447,6 +458,10 @@ pub struct SwitchPickerConfig {
     /// Example: `pager = "delta --paging=never"`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pager: Option<String>,
+
+    /// Key bindings for the picker's actions. See [`PickerKeysConfig`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keys: Option<PickerKeysConfig>,
 }
 
 impl SwitchPickerConfig {
@@ -454,12 +469,103 @@ impl SwitchPickerConfig {
     pub fn pager(&self) -> Option<&str> {
         self.pager.as_deref()
     }
+
+    /// Picker key bindings (default: every action on its built-in key)
+    pub fn keys(&self) -> PickerKeysConfig {
+        self.keys.clone().unwrap_or_default()
+    }
 }
 
 impl Merge for SwitchPickerConfig {
     fn merge_with(&self, other: &Self) -> Self {
         Self {
             pager: other.pager.clone().or_else(|| self.pager.clone()),
+            keys: merge_optional(self.keys.as_ref(), other.keys.as_ref()),
+        }
+    }
+}
+
+/// Key bindings for the `wt switch` picker (`[switch.picker.keys]`).
+///
+/// Each built-in field rebinds one action to a skim key chord (`ctrl-x`,
+/// `alt-m`, `f5`); unset fields keep the default key. `actions` binds extra
+/// keys to command templates run against the selected row — or each marked
+/// row.
+///
+/// ```toml
+/// [switch.picker.keys]
+/// remove = "ctrl-x"
+///
+/// [switch.picker.keys.actions]
+/// alt-e = "code {{ worktree_path }}"
+/// alt-t = "tmux new-window -c {{ worktree_path }}"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct PickerKeysConfig {
+    /// Create a worktree named by the query (default: `alt-c`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create: Option<String>,
+
+    /// Remove the selected worktree/branch, or every marked row (default:
+    /// `alt-x`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove: Option<String>,
+
+    /// Merge the selected worktree, or every marked one (default: `alt-m`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge: Option<String>,
+
+    /// Mark or unmark the selected row for a bulk action (default:
+    /// `ctrl-space`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark: Option<String>,
+
+    /// Refresh the list (default: `alt-r`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh: Option<String>,
+
+    /// Copy the selected branch name (default: `alt-y`)
+    #[serde(rename = "copy-branch", skip_serializing_if = "Option::is_none")]
+    pub copy_branch: Option<String>,
+
+    /// Open the selected row's PR/MR URL (default: `alt-o`)
+    #[serde(rename = "open-url", skip_serializing_if = "Option::is_none")]
+    pub open_url: Option<String>,
+
+    /// Modifier for the numbered preview-tab keys (default: `alt`, giving
+    /// `alt-1`–`alt-9`)
+    #[serde(rename = "preview-tabs", skip_serializing_if = "Option::is_none")]
+    pub preview_tabs: Option<String>,
+
+    /// Custom actions, keyed by key chord. Each value is a command template
+    /// (the same variables as hooks) run in the selected row's worktree, or in
+    /// the repository root for a row without one.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub actions: BTreeMap<String, String>,
+}
+
+impl Merge for PickerKeysConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        // Actions merge per key, like `[list.custom-columns]`: a project
+        // layer adds or overrides individual bindings.
+        let mut actions = self.actions.clone();
+        actions.extend(other.actions.iter().map(|(k, v)| (k.clone(), v.clone())));
+        Self {
+            create: other.create.clone().or_else(|| self.create.clone()),
+            remove: other.remove.clone().or_else(|| self.remove.clone()),
+            merge: other.merge.clone().or_else(|| self.merge.clone()),
+            mark: other.mark.clone().or_else(|| self.mark.clone()),
+            refresh: other.refresh.clone().or_else(|| self.refresh.clone()),
+            copy_branch: other
+                .copy_branch
+                .clone()
+                .or_else(|| self.copy_branch.clone()),
+            open_url: other.open_url.clone().or_else(|| self.open_url.clone()),
+            preview_tabs: other
+                .preview_tabs
+                .clone()
+                .or_else(|| self.preview_tabs.clone()),
+            actions,
         }
     }
 }
@@ -474,6 +580,10 @@ pub struct SwitchConfig {
     /// Picker settings for the interactive selector
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub picker: Option<SwitchPickerConfig>,
+
+    /// Initialize submodules in new worktrees (default: leave them)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submodules: Option<SubmoduleMode>,
 }
 
 impl SwitchConfig {
@@ -488,6 +598,81 @@ impl Merge for SwitchConfig {
         Self {
             cd: other.cd.or(self.cd),
             picker: merge_optional(self.picker.as_ref(), other.picker.as_ref()),
+            submodules: other.submodules.or(self.submodules),
+        }
+    }
+}
+
+/// How `wt switch --create` initializes a new worktree's submodules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SubmoduleMode {
+    /// `git submodule update --init --recursive`, cloning from each URL
+    Init,
+    /// Clone from the main worktree's submodules, sharing their objects
+    Reference,
+}
+
+/// How project hook commands are isolated when they run.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum SandboxMode {
+    /// Run project hooks with the user's full privileges
+    #[default]
+    Off,
+    /// Run project hooks under bubblewrap (`bwrap`), Linux only
+    Bubblewrap,
+}
+
+/// Hook execution policy, under `[hooks]`.
+///
+/// Governs project hooks — the commands from a repository's `.config/wt.toml`.
+/// The user's own hooks always run unsandboxed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct HookPolicyConfig {
+    /// Sandbox project hooks (default: "off")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<SandboxMode>,
+
+    /// Let sandboxed hooks reach the network (default: false)
+    #[serde(
+        default,
+        rename = "allow-network",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub allow_network: Option<bool>,
+
+    /// Paths sandboxed hooks may write besides the worktree (`~` expands)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub writable: Vec<String>,
+}
+
+impl HookPolicyConfig {
+    /// The sandbox mode (default: off).
+    pub fn sandbox(&self) -> SandboxMode {
+        self.sandbox.unwrap_or_default()
+    }
+
+    /// Whether sandboxed hooks may reach the network (default: false).
+    pub fn allow_network(&self) -> bool {
+        self.allow_network.unwrap_or(false)
+    }
+}
+
+impl Merge for HookPolicyConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        let mut writable = self.writable.clone();
+        for path in &other.writable {
+            if !writable.contains(path) {
+                writable.push(path.clone());
+            }
+        }
+        Self {
+            sandbox: other.sandbox.or(self.sandbox),
+            allow_network: other.allow_network.or(self.allow_network),
+            writable,
         }
     }
 }
@@ -498,6 +683,11 @@ pub struct CopyIgnoredConfig {
     /// Gitignore-style patterns to exclude from `wt step copy-ignored`.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub exclude: Vec<String>,
+
+    /// Deduplicate copies through the shared store in `.git/wt/store/`
+    /// (default: false). Same as passing `--store`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub store: Option<bool>,
 }
 
 impl CopyIgnoredConfig {
@@ -508,7 +698,15 @@ impl CopyIgnoredConfig {
                 exclude.push(pattern.clone());
             }
         }
-        Self { exclude }
+        Self {
+            exclude,
+            store: other.store.or(self.store),
+        }
+    }
+
+    /// Whether copies go through the shared store (default: false).
+    pub fn store(&self) -> bool {
+        self.store.unwrap_or(false)
     }
 }
 
@@ -518,6 +716,122 @@ impl Merge for CopyIgnoredConfig {
     }
 }
 
+/// Log retention, under `[logs]`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct LogsConfig {
+    /// Gzipped generations of `commands.jsonl` to keep when it rotates
+    /// (default: one uncompressed `commands.jsonl.old`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep: Option<u32>,
+}
+
+/// Where a notification is delivered.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotifySink {
+    /// OSC 9 escape to the controlling terminal (iTerm2, WezTerm, Ghostty, kitty)
+    Osc9,
+    /// OSC 777 escape to the controlling terminal (foot, rxvt-unicode, VTE)
+    Osc777,
+    /// Desktop notification via `notify-send`
+    NotifySend,
+    /// The `[notify] command`, with the notification as JSON on stdin
+    Command,
+    /// HTTP POST of the notification as JSON to the `[notify] webhook` URL
+    Webhook,
+}
+
+/// Sinks for one notification event. Absent sinks default to `osc9`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct NotifyEventConfig {
+    /// Sinks to deliver to (default: `["osc9"]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sinks: Option<Vec<NotifySink>>,
+
+    /// Values that trigger the event: CI statuses for `[notify.ci]`
+    /// (`passed`, `failed`, `running`, `conflicts`, `no-ci`), marker
+    /// values for `[notify.marker]`. Empty matches every change. Unused by
+    /// `[notify.pipeline-failed]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on: Vec<String>,
+}
+
+impl NotifyEventConfig {
+    /// Configured sinks, or `osc9` when unset.
+    pub fn sinks(&self) -> Vec<NotifySink> {
+        self.sinks.clone().unwrap_or_else(|| vec![NotifySink::Osc9])
+    }
+
+    /// Whether `value` is one of the configured triggers (any value when none are).
+    pub fn triggers_on(&self, value: &str) -> bool {
+        self.on.is_empty() || self.on.iter().any(|v| v == value)
+    }
+}
+
+/// Notifications, under `[notify]`. Each event notifies only when its table
+/// is present.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct NotifyConfig {
+    /// Shell command for the `command` sink; receives the notification as
+    /// JSON on stdin
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// Local `http://` URL for the `webhook` sink (`localhost` or a loopback
+    /// address)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+
+    /// A background hook pipeline (`post-start`, `post-merge`, …) failed
+    #[serde(
+        default,
+        rename = "pipeline-failed",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pipeline_failed: Option<NotifyEventConfig>,
+
+    /// A branch's CI status changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ci: Option<NotifyEventConfig>,
+
+    /// A branch marker was set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marker: Option<NotifyEventConfig>,
+}
+
+/// A host under `[remotes.<name>]` holding a clone of the repository, for
+/// `wt switch --on <name>` and friends.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct RemoteHostConfig {
+    /// SSH destination (`host`, `user@host`, or a `~/.ssh/config` alias)
+    pub host: String,
+
+    /// Path of the repository's clone on the host. Supports `{{ repo }}`
+    /// (the local repository's directory name); `~` is the remote home.
+    pub path: String,
+
+    /// Command that opens the connection, followed by the destination and
+    /// the remote command (default: `ssh`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssh: Option<String>,
+
+    /// `wt` executable on the host (default: `wt`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wt: Option<String>,
+}
+
+impl RemoteHostConfig {
+    /// The connection command, `ssh` when unset.
+    pub fn ssh(&self) -> &str {
+        self.ssh.as_deref().unwrap_or("ssh")
+    }
+
+    /// The remote `wt` executable, `wt` when unset.
+    pub fn wt(&self) -> &str {
+        self.wt.as_deref().unwrap_or("wt")
+    }
+}
+
 /// Configuration for `wt step` subcommands.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
 pub struct StepConfig {
@@ -614,6 +928,11 @@ pub struct UserProjectOverrides {
     #[serde(default, skip_serializing_if = "is_default")]
     pub step: StepConfig,
 
+    /// Hook execution policy — e.g. `sandbox = "off"` for a repository
+    /// trusted more than the global default.
+    #[serde(default, rename = "hooks", skip_serializing_if = "is_default")]
+    pub hook_policy: HookPolicyConfig,
+
     /// Forge platform and API hostname for the matched repositories.
     ///
     /// Same shape as the repository's own `[forge]` block, which wins field by
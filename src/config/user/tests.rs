@@ -561,6 +561,38 @@ delete-branch = false
     assert!(!parsed.remove(Some("github.com/user/repo")).delete_branch());
 }
 
+#[test]
+fn test_hook_policy_parse_and_project_override() {
+    // `[hooks]` sits beside the flattened top-level hook keys.
+    let toml = r#"
+post-start = "npm install"
+
+[hooks]
+sandbox = "bubblewrap"
+writable = ["~/.cache"]
+
+[projects."github.com/user/*".hooks]
+writable = ["/opt/build"]
+
+[projects."github.com/user/repo".hooks]
+sandbox = "off"
+"#;
+    let parsed = UserConfig::load_from_str(toml).unwrap();
+    assert!(parsed.hooks.post_create.is_some());
+
+    let global = parsed.hook_policy(None);
+    assert_eq!(global.sandbox(), SandboxMode::Bubblewrap);
+    assert!(!global.allow_network());
+
+    // Pattern entry extends `writable`; the more specific entry turns the
+    // sandbox off.
+    let other = parsed.hook_policy(Some("github.com/user/other"));
+    assert_eq!(other.sandbox(), SandboxMode::Bubblewrap);
+    assert_eq!(other.writable, ["~/.cache", "/opt/build"]);
+    let repo = parsed.hook_policy(Some("github.com/user/repo"));
+    assert_eq!(repo.sandbox(), SandboxMode::Off);
+}
+
 #[test]
 fn test_remove_config_merge() {
     let base = RemoveConfig {
@@ -856,6 +888,7 @@ fn test_merge_commit_generation_config() {
         template_file: Some("~/.config/template.txt".to_string()),
         squash_template: None,
         squash_template_file: None,
+        review_template: None,
         template_append: None,
     };
     let override_config = CommitGenerationConfig {
@@ -864,6 +897,7 @@ fn test_merge_commit_generation_config() {
         template_file: None,                                  // Fall back to base
         squash_template: None,
         squash_template_file: None,
+        review_template: None,
         template_append: None,
     };
 
@@ -945,6 +979,7 @@ fn test_commit_generation_merge_squash_template_mutual_exclusivity() {
     // Merged result should only have squash_template (project wins)
     let global = CommitGenerationConfig {
         squash_template_file: Some("~/.config/squash.txt".to_string()),
+        review_template: None,
         ..Default::default()
     };
     let project = CommitGenerationConfig {
@@ -963,6 +998,7 @@ fn test_commit_generation_merge_squash_template_mutual_exclusivity() {
     };
     let project = CommitGenerationConfig {
         squash_template_file: Some("project-squash.txt".to_string()),
+        review_template: None,
         ..Default::default()
     };
 
@@ -1215,6 +1251,7 @@ fn test_switch_picker_config_accessor_methods() {
 
     let config = SwitchPickerConfig {
         pager: Some("delta --paging=never".to_string()),
+        ..Default::default()
     };
     assert_eq!(config.pager(), Some("delta --paging=never"));
 }
@@ -1236,9 +1273,11 @@ fn test_switch_picker_merge() {
 
     let base = SwitchPickerConfig {
         pager: Some("delta".to_string()),
+        ..Default::default()
     };
     let override_config = SwitchPickerConfig {
         pager: None, // Fall back to base
+        ..Default::default()
     };
 
     let merged = base.merge_with(&override_config);
@@ -1253,11 +1292,15 @@ fn test_switch_config_merge() {
     let base = SwitchConfig {
         picker: Some(SwitchPickerConfig {
             pager: Some("delta".to_string()),
+            ..Default::default()
         }),
         ..Default::default()
     };
     let other = SwitchConfig {
-        picker: Some(SwitchPickerConfig { pager: None }),
+        picker: Some(SwitchPickerConfig {
+            pager: None,
+            ..Default::default()
+        }),
         ..Default::default()
     };
     let merged = base.merge_with(&other);
@@ -1331,6 +1374,25 @@ fn test_switch_config_cd_merge() {
     assert!(merged.cd()); // default true
 }
 
+#[test]
+fn test_switch_config_submodules() {
+    use crate::config::user::{Merge, SubmoduleMode, SwitchConfig};
+
+    let config: SwitchConfig = toml::from_str(r#"submodules = "reference""#).unwrap();
+    assert_eq!(config.submodules, Some(SubmoduleMode::Reference));
+    assert!(toml::from_str::<SwitchConfig>(r#"submodules = "clone""#).is_err());
+
+    // Other overrides base; base preserved when other is unset
+    let base = SwitchConfig {
+        submodules: Some(SubmoduleMode::Init),
+        ..Default::default()
+    };
+    let merged = base.merge_with(&config);
+    assert_eq!(merged.submodules, Some(SubmoduleMode::Reference));
+    let merged = base.merge_with(&SwitchConfig::default());
+    assert_eq!(merged.submodules, Some(SubmoduleMode::Init));
+}
+
 #[test]
 fn test_switch_config_cd_from_toml() {
     let toml = r#"
@@ -1413,6 +1475,7 @@ fn test_switch_picker_project_override() {
         switch: SwitchConfig {
             picker: Some(SwitchPickerConfig {
                 pager: Some("delta".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         },
@@ -1425,6 +1488,7 @@ fn test_switch_picker_project_override() {
             switch: SwitchConfig {
                 picker: Some(SwitchPickerConfig {
                     pager: Some("bat".to_string()),
+                    ..Default::default()
                 }),
                 ..Default::default()
             },
@@ -1487,6 +1551,7 @@ fn test_resolved_config_for_project() {
         switch: SwitchConfig {
             picker: Some(SwitchPickerConfig {
                 pager: Some("less".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         },
@@ -1643,6 +1708,25 @@ exclude = [".repo-local/", ".entire/"]
     );
 }
 
+#[test]
+fn test_copy_ignored_store_project_overrides_global() {
+    let config = UserConfig::load_from_str(
+        r#"
+[step.copy-ignored]
+store = true
+
+[projects."github.com/user/repo".step.copy-ignored]
+store = false
+"#,
+    )
+    .unwrap();
+
+    assert!(config.copy_ignored(None).store());
+    assert!(!config.copy_ignored(Some("github.com/user/repo")).store());
+    assert!(config.copy_ignored(Some("github.com/other/repo")).store());
+    assert!(!CopyIgnoredConfig::default().store());
+}
+
 #[test]
 fn test_deprecated_commit_generation_migrated_on_load() {
     // [commit-generation] is migrated to [commit.generation] at the TOML level
@@ -2136,8 +2220,8 @@ fn test_valid_user_config_keys_all_deserialize() {
             "worktree-path" => {
                 scalar_lines.push(format!("{key} = \"test-value\""));
             }
-            "list" | "commit" | "merge" | "remove" | "switch" | "step" | "select"
-            | "commit-generation" | "aliases" => {
+            "list" | "commit" | "merge" | "remove" | "switch" | "step" | "hooks" | "select"
+            | "commit-generation" | "aliases" | "logs" | "notify" | "remotes" => {
                 // Table sections with minimal content
                 table_lines.push(format!("[{key}]"));
             }
@@ -3079,6 +3163,7 @@ fn test_save_to_existing_file_writes_project_sections() {
             switch: SwitchConfig {
                 cd: Some(false),
                 picker: None,
+                submodules: None,
             },
             ..Default::default()
         },
@@ -3538,6 +3623,7 @@ fn test_save_to_new_file_expands_nested_project_inline_tables() {
             switch: SwitchConfig {
                 cd: Some(false),
                 picker: None,
+                submodules: None,
             },
             ..Default::default()
         },
@@ -4185,3 +4271,38 @@ hostname = "github.company.example"
     );
     assert_eq!(config.forge_hostname(None), None);
 }
+
+#[test]
+fn test_switch_picker_keys_project_merges_per_key() {
+    let config = UserConfig::load_from_str(
+        r#"
+[switch.picker.keys]
+remove = "ctrl-x"
+
+[switch.picker.keys.actions]
+alt-m = "wt merge"
+alt-t = "tmux new-window -c {{ worktree_path }}"
+
+[projects."github.com/user/repo".switch.picker.keys]
+refresh = "f5"
+
+[projects."github.com/user/repo".switch.picker.keys.actions]
+alt-t = "zellij action new-tab --cwd {{ worktree_path }}"
+"#,
+    )
+    .unwrap();
+
+    let global = config.switch_picker(None).keys();
+    assert_eq!(global.remove.as_deref(), Some("ctrl-x"));
+    assert_eq!(global.refresh, None);
+    assert_eq!(global.actions.len(), 2);
+
+    let project = config.switch_picker(Some("github.com/user/repo")).keys();
+    assert_eq!(project.remove.as_deref(), Some("ctrl-x"));
+    assert_eq!(project.refresh.as_deref(), Some("f5"));
+    assert_eq!(project.actions["alt-m"], "wt merge");
+    assert_eq!(
+        project.actions["alt-t"],
+        "zellij action new-tab --cwd {{ worktree_path }}"
+    );
+}
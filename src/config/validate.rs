@@ -0,0 +1,945 @@
+//! Static validation of config files for `wt config validate`.
+//!
+//! Loading a config surfaces problems piecemeal: serde stops at the first type
+//! error, unknown keys warn and are dropped, and a template is only checked
+//! when the hook that owns it runs. [`validate_config`] collects everything in
+//! one pass, each finding pinned to a line and column in the source:
+//!
+//! - TOML syntax errors (nothing else is checked when the file doesn't parse).
+//! - Values that don't match the JSON Schema derived from the config type.
+//! - Unknown and misplaced keys, classified by
+//!   [`collect_unknown_warnings`](crate::config::collect_unknown_warnings),
+//!   with a near-miss suggestion drawn from the schema.
+//! - Deprecated patterns (warnings — `wt config update` migrates them).
+//! - Every hook and alias template: minijinja syntax, then the variables it
+//!   references against the ones that hook type (or alias) actually receives,
+//!   then a trial render with placeholder values.
+//!
+//! Findings are plain data; `wt config validate` renders them as text with
+//! source snippets or as JSON.
+
+use std::ops::Range;
+use std::str::FromStr;
+
+use ansi_str::AnsiStr;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use super::{
+    ConfigFileKind, ProjectConfig, UnknownWarning, UserConfig, ValidationScope, WorktrunkConfig,
+};
+use crate::git::HookType;
+use crate::styling::did_you_mean;
+
+/// How serious a finding is. Any [`Severity::Error`] fails validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// What kind of problem a [`ConfigDiagnostic`] reports. Serialized in
+/// kebab-case as the stable `code` field of the JSON output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, strum::Display)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum DiagnosticCode {
+    /// The file is not valid TOML.
+    Syntax,
+    /// A value doesn't match the config schema (wrong type, bad enum value).
+    Schema,
+    /// A key no config type knows — usually a typo.
+    UnknownKey,
+    /// A key that is valid, but in the other config file.
+    MisplacedKey,
+    /// A deprecated pattern `wt config update` would migrate.
+    Deprecated,
+    /// A hook or alias template that minijinja can't parse.
+    TemplateSyntax,
+    /// A template variable that doesn't exist in any context.
+    UnknownVariable,
+    /// A template variable that exists, but is never set where this template
+    /// runs (e.g. `base` in a `pre-merge` hook).
+    UnavailableVariable,
+    /// A template that parses but fails a trial render.
+    TemplateRender,
+}
+
+/// One finding from [`validate_config`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigDiagnostic {
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+    /// Plain-text description, without styling.
+    pub message: String,
+    /// Dotted key path the finding belongs to, when it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// 1-based line of the offending source, when it could be located.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// 1-based column (in characters) on `line`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    /// A replacement to try: the closest valid key or variable name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl ConfigDiagnostic {
+    fn new(severity: Severity, code: DiagnosticCode, message: String) -> Self {
+        Self {
+            severity,
+            code,
+            message,
+            key: None,
+            line: None,
+            column: None,
+            suggestion: None,
+        }
+    }
+
+    fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    fn with_suggestion(mut self, suggestion: Option<String>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    /// Resolve a byte offset into `contents` to the line/column fields.
+    fn at(mut self, contents: &str, offset: Option<usize>) -> Self {
+        if let Some(offset) = offset {
+            let (line, column) = line_column(contents, offset);
+            self.line = Some(line);
+            self.column = Some(column);
+        }
+        self
+    }
+}
+
+/// Validate `contents` as a config file of the given kind. Returns every
+/// finding in source order; an empty vec means the file is clean.
+pub fn validate_config(contents: &str, kind: ConfigFileKind) -> Vec<ConfigDiagnostic> {
+    match kind {
+        ConfigFileKind::User | ConfigFileKind::System => validate_as::<UserConfig>(contents, kind),
+        ConfigFileKind::Project => validate_as::<ProjectConfig>(contents, kind),
+    }
+}
+
+fn validate_as<C>(contents: &str, kind: ConfigFileKind) -> Vec<ConfigDiagnostic>
+where
+    C: WorktrunkConfig + schemars::JsonSchema,
+{
+    let doc = match toml_edit::Document::parse(contents) {
+        Ok(doc) => doc,
+        Err(e) => {
+            let message = e.message().trim_end().to_string();
+            return vec![
+                ConfigDiagnostic::new(Severity::Error, DiagnosticCode::Syntax, message)
+                    .at(contents, e.span().map(|s| s.start)),
+            ];
+        }
+    };
+    let root = doc.as_table();
+
+    let mut out = Vec::new();
+    let invalid = check_schema::<C>(contents, root, &mut out);
+    // Unknown-key detection needs content that deserializes, so run it with
+    // the values the schema rejected cut out.
+    let typed = without_paths(contents, &invalid);
+    check_unknown_keys::<C>(&typed, root, &mut out);
+    check_deprecations(contents, kind, &mut out);
+    check_templates(contents, root, kind, &mut out);
+
+    // Source order reads naturally; unlocated findings (deprecations) last.
+    out.sort_by_key(|d| (d.line.is_none(), d.line, d.column));
+    out
+}
+
+// ==================== Schema ====================
+
+/// Validate the parsed TOML against the JsonSchema of `C`. The schema is the
+/// primary check because it reports every mismatch with its path; serde runs
+/// as a backstop for the rare shape the schema accepts but a custom
+/// deserializer rejects.
+///
+/// Returns the paths of the values the schema rejected.
+fn check_schema<C>(
+    contents: &str,
+    root: &toml_edit::Table,
+    out: &mut Vec<ConfigDiagnostic>,
+) -> Vec<Vec<Segment>>
+where
+    C: WorktrunkConfig + schemars::JsonSchema,
+{
+    let schema = config_schema::<C>();
+    let instance = contents
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|table| serde_json::to_value(table).ok());
+    let mut invalid = Vec::new();
+    if let Some(instance) = instance {
+        let validator =
+            jsonschema::validator_for(&schema).expect("schemars emits a valid JSON Schema");
+        for error in validator.iter_errors(&instance) {
+            let segments: Vec<Segment> = error
+                .instance_path()
+                .iter()
+                .map(|segment| match segment {
+                    jsonschema::paths::LocationSegment::Property(key) => {
+                        Segment::Key(key.into_owned())
+                    }
+                    jsonschema::paths::LocationSegment::Index(i) => Segment::Index(i),
+                })
+                .collect();
+            let mut diagnostic =
+                ConfigDiagnostic::new(Severity::Error, DiagnosticCode::Schema, error.to_string())
+                    .at(contents, locate(root, &segments, Target::Value));
+            if !segments.is_empty() {
+                diagnostic = diagnostic.with_key(display_path(&segments));
+            }
+            out.push(diagnostic);
+            invalid.push(segments);
+        }
+    }
+    if !invalid.is_empty() {
+        return invalid;
+    }
+    if let Err(e) = toml::from_str::<C>(contents) {
+        out.push(
+            ConfigDiagnostic::new(
+                Severity::Error,
+                DiagnosticCode::Schema,
+                e.message().trim_end().to_string(),
+            )
+            .at(contents, e.span().map(|s| s.start)),
+        );
+    }
+    invalid
+}
+
+/// `contents` with the keys holding each path removed. A path into an array
+/// removes the whole array; an empty path (the document itself) is ignored.
+fn without_paths(contents: &str, paths: &[Vec<Segment>]) -> String {
+    if paths.is_empty() {
+        return contents.to_string();
+    }
+    let Ok(mut doc) = contents.parse::<toml_edit::DocumentMut>() else {
+        return contents.to_string();
+    };
+    for path in paths {
+        let keys: Vec<&str> = path
+            .iter()
+            .map_while(|segment| match segment {
+                Segment::Key(key) => Some(key.as_str()),
+                Segment::Index(_) => None,
+            })
+            .collect();
+        remove_path(doc.as_table_mut(), &keys);
+    }
+    doc.to_string()
+}
+
+fn remove_path(table: &mut dyn toml_edit::TableLike, keys: &[&str]) {
+    match keys {
+        [] => {}
+        [last] => {
+            table.remove(last);
+        }
+        [first, rest @ ..] => {
+            if let Some(next) = table
+                .get_mut(first)
+                .and_then(|item| item.as_table_like_mut())
+            {
+                remove_path(next, rest);
+            }
+        }
+    }
+}
+
+/// The JSON Schema for config type `C`, as a JSON value.
+fn config_schema<C: schemars::JsonSchema>() -> serde_json::Value {
+    let schema = schemars::SchemaGenerator::default().into_root_schema_for::<C>();
+    serde_json::to_value(schema).expect("schema serializes to JSON")
+}
+
+/// Property names the schema allows at `path` (an object's `properties`,
+/// following `$ref`s, `Option` wrappers, and map values). Empty when the path
+/// leads somewhere without named properties.
+fn schema_keys_at(schema: &serde_json::Value, path: &[String]) -> Vec<String> {
+    let mut node = resolve(schema, schema);
+    for key in path {
+        let Some(next) = object_schema(schema, node).and_then(|obj| {
+            obj.get("properties")
+                .and_then(|p| p.get(key))
+                .or_else(|| obj.get("additionalProperties").filter(|a| a.is_object()))
+        }) else {
+            return Vec::new();
+        };
+        node = resolve(schema, next);
+    }
+    object_schema(schema, node)
+        .and_then(|obj| obj.get("properties"))
+        .and_then(|p| p.as_object())
+        .map(|props| props.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Follow a local `$ref` (`#/$defs/Name`) to its definition.
+fn resolve<'a>(root: &'a serde_json::Value, node: &'a serde_json::Value) -> &'a serde_json::Value {
+    node.get("$ref")
+        .and_then(|r| r.as_str())
+        .and_then(|r| r.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+        .unwrap_or(node)
+}
+
+/// The object-shaped schema at `node`: the node itself when it has properties,
+/// otherwise the first `anyOf`/`oneOf`/`allOf` branch that does (schemars
+/// wraps `Option<T>` as `anyOf: [T, null]`).
+fn object_schema<'a>(
+    root: &'a serde_json::Value,
+    node: &'a serde_json::Value,
+) -> Option<&'a serde_json::Map<String, serde_json::Value>> {
+    let obj = node.as_object()?;
+    if obj.contains_key("properties") || obj.contains_key("additionalProperties") {
+        return Some(obj);
+    }
+    ["anyOf", "oneOf", "allOf"]
+        .iter()
+        .filter_map(|k| obj.get(*k).and_then(|v| v.as_array()))
+        .flatten()
+        .find_map(|branch| object_schema(root, resolve(root, branch)))
+}
+
+// ==================== Unknown keys ====================
+
+fn check_unknown_keys<C>(contents: &str, root: &toml_edit::Table, out: &mut Vec<ConfigDiagnostic>)
+where
+    C: WorktrunkConfig + schemars::JsonSchema,
+{
+    let warnings = super::collect_unknown_warnings::<C>(contents);
+    if warnings.is_empty() {
+        return;
+    }
+    let schema = config_schema::<C>();
+    for warning in warnings {
+        let (code, path, message) = match &warning {
+            UnknownWarning::TopLevelUnknown { key } => (
+                DiagnosticCode::UnknownKey,
+                key.as_str(),
+                format!("unknown key `{key}`"),
+            ),
+            UnknownWarning::NestedUnknown { path } => (
+                DiagnosticCode::UnknownKey,
+                path.as_str(),
+                format!("unknown key `{path}`"),
+            ),
+            UnknownWarning::TopLevelWrongConfig {
+                key,
+                other_description,
+            } => (
+                DiagnosticCode::MisplacedKey,
+                key.as_str(),
+                super::with_scope_note(
+                    format!("key `{key}` belongs in {other_description}"),
+                    other_description,
+                    key,
+                ),
+            ),
+            UnknownWarning::NestedWrongConfig {
+                path,
+                other_description,
+            } => (
+                DiagnosticCode::MisplacedKey,
+                path.as_str(),
+                super::with_scope_note(
+                    format!("key `{path}` belongs in {other_description}"),
+                    other_description,
+                    path,
+                ),
+            ),
+            UnknownWarning::TopLevelDeprecatedWrongConfig {
+                key,
+                other_description,
+                canonical_display,
+            } => (
+                DiagnosticCode::MisplacedKey,
+                key.as_str(),
+                format!("key `{key}` belongs in {other_description} as {canonical_display}"),
+            ),
+        };
+
+        // Warnings carry dotted paths; a project identifier like
+        // `github.com/user/repo` contains dots itself, so split against the
+        // document rather than on every dot.
+        let segments = split_dotted(root, path);
+        let suggestion = (code == DiagnosticCode::UnknownKey)
+            .then(|| {
+                let (leaf, parent) = segments.split_last()?;
+                did_you_mean(leaf, schema_keys_at(&schema, parent))
+                    .into_iter()
+                    .find(|candidate| candidate != leaf)
+            })
+            .flatten();
+        let keyed: Vec<Segment> = segments.into_iter().map(Segment::Key).collect();
+        out.push(
+            ConfigDiagnostic::new(Severity::Error, code, message)
+                .with_key(path)
+                .with_suggestion(suggestion)
+                .at(contents, locate(root, &keyed, Target::Key)),
+        );
+    }
+}
+
+// ==================== Deprecations ====================
+
+fn check_deprecations(contents: &str, kind: ConfigFileKind, out: &mut Vec<ConfigDiagnostic>) {
+    let deprecations = super::detect_deprecations(contents, kind);
+    // Pending defaults aren't problems with the file; they nag at the surface
+    // that reads the setting.
+    let patterns = deprecations.iter().filter(|k| !k.is_pending_default());
+    for message in super::deprecation_messages(patterns) {
+        out.push(ConfigDiagnostic::new(
+            Severity::Warning,
+            DiagnosticCode::Deprecated,
+            message.ansi_strip().into_owned(),
+        ));
+    }
+}
+
+// ==================== Templates ====================
+
+/// One template string found in the document, with where it came from.
+struct TemplateSite {
+    scope: ValidationScope,
+    /// Label used in error messages, e.g. `post-start:install` or `alias deploy`.
+    label: String,
+    /// Dotted key path of the string.
+    key: String,
+    template: String,
+    /// Byte range of the string literal in the source.
+    span: Option<Range<usize>>,
+}
+
+fn check_templates(
+    contents: &str,
+    root: &toml_edit::Table,
+    kind: ConfigFileKind,
+    out: &mut Vec<ConfigDiagnostic>,
+) {
+    let mut sites = Vec::new();
+    collect_command_sites(root, "", &mut sites);
+    if kind != ConfigFileKind::Project
+        && let Some(projects) = root.get("projects").and_then(|p| p.as_table_like())
+    {
+        for (id, item) in projects.iter() {
+            if let Some(table) = item.as_table_like() {
+                collect_command_sites(table, &format!("projects.\"{id}\"."), &mut sites);
+            }
+        }
+    }
+    for site in sites {
+        check_template(contents, &site, out);
+    }
+}
+
+/// Hooks (top-level keys named after a hook type) and `[aliases]` entries
+/// within one table — the config root or a `[projects."<id>"]` override.
+fn collect_command_sites(
+    table: &dyn toml_edit::TableLike,
+    prefix: &str,
+    sites: &mut Vec<TemplateSite>,
+) {
+    for (key, item) in table.iter() {
+        if let Ok(hook_type) = HookType::from_str(key) {
+            let base = format!("{prefix}{key}");
+            collect_strings(
+                Node::Item(item),
+                &base,
+                None,
+                &mut |name, key, template, span| {
+                    sites.push(TemplateSite {
+                        scope: ValidationScope::Hook(hook_type),
+                        label: match name {
+                            Some(name) => format!("{hook_type}:{name}"),
+                            None => format!("{hook_type} hook"),
+                        },
+                        key,
+                        template,
+                        span,
+                    });
+                },
+            );
+        }
+    }
+    let Some(aliases) = table.get("aliases").and_then(|a| a.as_table_like()) else {
+        return;
+    };
+    for (alias, item) in aliases.iter() {
+        let base = format!("{prefix}aliases.{alias}");
+        collect_strings(
+            Node::Item(item),
+            &base,
+            None,
+            &mut |name, key, template, span| {
+                sites.push(TemplateSite {
+                    scope: ValidationScope::Alias,
+                    label: match name {
+                        Some(name) => format!("alias {alias}:{name}"),
+                        None => format!("alias {alias}"),
+                    },
+                    key,
+                    template,
+                    span,
+                });
+            },
+        );
+    }
+}
+
+/// Receives `(step name, key path, template, span)` for each string leaf.
+type StringVisitor<'a> = dyn FnMut(Option<&str>, String, String, Option<Range<usize>>) + 'a;
+
+/// Visit every string leaf under a command config — the string, named-table,
+/// and pipeline forms all bottom out in template strings. `name` is the
+/// nearest table key (the step name), if any.
+fn collect_strings(node: Node<'_>, key: &str, name: Option<&str>, visit: &mut StringVisitor<'_>) {
+    if let Some(s) = node.as_str() {
+        visit(name, key.to_string(), s.to_string(), node.span());
+        return;
+    }
+    if let Some(table) = node.as_table_like() {
+        for (k, item) in table.iter() {
+            collect_strings(Node::Item(item), &format!("{key}.{k}"), Some(k), visit);
+        }
+        return;
+    }
+    for (i, child) in node.elements().into_iter().enumerate() {
+        collect_strings(child, &format!("{key}[{i}]"), name, visit);
+    }
+}
+
+fn check_template(contents: &str, site: &TemplateSite, out: &mut Vec<ConfigDiagnostic>) {
+    let start = site.span.as_ref().map(|s| s.start);
+    let error = |code: DiagnosticCode, message: String| {
+        ConfigDiagnostic::new(Severity::Error, code, message).with_key(site.key.clone())
+    };
+
+    if let Err(e) = super::validate_template_syntax(&site.template, &site.label) {
+        out.push(error(DiagnosticCode::TemplateSyntax, e.message).at(contents, start));
+        return;
+    }
+
+    let available = super::vars_available_in(site.scope);
+    let referenced = super::referenced_vars_for_templates([site.template.as_str()]);
+    let mut var_problem = false;
+    for var in &referenced {
+        if var == "vars" || available.contains(&var.as_str()) {
+            continue;
+        }
+        var_problem = true;
+        let offset = var_offset(contents, site.span.as_ref(), var).or(start);
+        let receivers = scopes_providing(var);
+        let diagnostic = if receivers.is_empty() {
+            let suggestion = did_you_mean(var, available.iter().map(|v| v.to_string()))
+                .into_iter()
+                .next();
+            error(
+                DiagnosticCode::UnknownVariable,
+                format!("{} references undefined variable `{var}`", site.label),
+            )
+            .with_suggestion(suggestion)
+        } else {
+            error(
+                DiagnosticCode::UnavailableVariable,
+                format!(
+                    "{} references `{var}`, which {} never receive; it is set for {}",
+                    site.label,
+                    scope_plural(site.scope),
+                    receivers.join(" and ")
+                ),
+            )
+        };
+        out.push(diagnostic.at(contents, offset));
+    }
+
+    // Trial render, as the hook pre-flight does. `{{ vars.X }}` resolves from
+    // git config at run time, so those templates stop at the checks above.
+    if !var_problem
+        && !referenced.contains("vars")
+        && let Err(e) =
+            super::validate_template_without_repo(&site.template, site.scope, &site.label)
+    {
+        out.push(error(DiagnosticCode::TemplateRender, e.message).at(contents, start));
+    }
+}
+
+/// Where a template variable is set: hook types (as `pre-start hooks`) and
+/// `aliases`. Empty when no context knows the name.
+fn scopes_providing(var: &str) -> Vec<String> {
+    let hooks: Vec<String> = HookType::iter()
+        .filter(|&h| super::vars_available_in(ValidationScope::Hook(h)).contains(&var))
+        .map(|h| h.to_string())
+        .collect();
+    let mut out = Vec::new();
+    if !hooks.is_empty() {
+        out.push(format!("{} hooks", hooks.join(", ")));
+    }
+    if super::vars_available_in(ValidationScope::Alias).contains(&var) {
+        out.push("aliases".to_string());
+    }
+    out
+}
+
+fn scope_plural(scope: ValidationScope) -> String {
+    match scope {
+        ValidationScope::Hook(hook_type) => format!("{hook_type} hooks"),
+        ValidationScope::Alias => "aliases".to_string(),
+        ValidationScope::SwitchExecute => "--execute commands".to_string(),
+    }
+}
+
+/// Offset of the first whole-word occurrence of `var` inside the string
+/// literal at `span`, so a variable finding points at the variable rather
+/// than the opening quote.
+fn var_offset(contents: &str, span: Option<&Range<usize>>, var: &str) -> Option<usize> {
+    let span = span?;
+    let literal = contents.get(span.clone())?;
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    literal.match_indices(var).find_map(|(i, _)| {
+        let before = literal[..i].chars().next_back();
+        let after = literal[i + var.len()..].chars().next();
+        (!before.is_some_and(is_ident) && !after.is_some_and(is_ident)).then_some(span.start + i)
+    })
+}
+
+// ==================== Locating ====================
+
+/// One step of a path into the document.
+#[derive(Debug, Clone)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Whether [`locate`] should point at the key or the value it holds.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Key,
+    Value,
+}
+
+/// A position in the document: items hold tables and values; array elements
+/// are bare values, and `[[array-of-tables]]` elements bare tables.
+#[derive(Clone, Copy)]
+enum Node<'a> {
+    Item(&'a toml_edit::Item),
+    Value(&'a toml_edit::Value),
+    Table(&'a toml_edit::Table),
+}
+
+impl<'a> Node<'a> {
+    fn as_str(self) -> Option<&'a str> {
+        match self {
+            Node::Item(item) => item.as_str(),
+            Node::Value(value) => value.as_str(),
+            Node::Table(_) => None,
+        }
+    }
+
+    fn as_table_like(self) -> Option<&'a dyn toml_edit::TableLike> {
+        match self {
+            Node::Item(item) => item.as_table_like(),
+            Node::Value(value) => value
+                .as_inline_table()
+                .map(|t| t as &dyn toml_edit::TableLike),
+            Node::Table(table) => Some(table),
+        }
+    }
+
+    fn elements(self) -> Vec<Node<'a>> {
+        let array = match self {
+            Node::Item(toml_edit::Item::ArrayOfTables(tables)) => {
+                return tables.iter().map(Node::Table).collect();
+            }
+            Node::Item(item) => item.as_array(),
+            Node::Value(value) => value.as_array(),
+            Node::Table(_) => None,
+        };
+        array
+            .map(|a| a.iter().map(Node::Value).collect())
+            .unwrap_or_default()
+    }
+
+    fn span(self) -> Option<Range<usize>> {
+        match self {
+            Node::Item(item) => item.span(),
+            Node::Value(value) => value.span(),
+            Node::Table(table) => table.span(),
+        }
+    }
+}
+
+/// Byte offset of the key or value at `path`, falling back to the nearest
+/// located ancestor (implicit tables have no span of their own).
+fn locate(root: &toml_edit::Table, path: &[Segment], target: Target) -> Option<usize> {
+    let mut table: Option<&dyn toml_edit::TableLike> = Some(root);
+    let mut node: Option<Node<'_>> = None;
+    let mut best: Option<usize> = None;
+    for (i, segment) in path.iter().enumerate() {
+        let last = i + 1 == path.len();
+        match segment {
+            Segment::Key(key) => {
+                let Some((k, item)) = table.and_then(|t| t.get_key_value(key)) else {
+                    break;
+                };
+                let key_start = k.span().map(|s| s.start);
+                best = key_start.or(best);
+                if last && target == Target::Value {
+                    best = item.span().map(|s| s.start).or(best);
+                }
+                node = Some(Node::Item(item));
+            }
+            Segment::Index(index) => {
+                let Some(child) = node.and_then(|n| n.elements().into_iter().nth(*index)) else {
+                    break;
+                };
+                best = child.span().map(|s| s.start).or(best);
+                node = Some(child);
+            }
+        }
+        table = node.and_then(|n| n.as_table_like());
+    }
+    best
+}
+
+/// Split a dotted key path into segments, preferring the longest key that
+/// exists in the document at each level so dotted keys (project identifiers)
+/// stay whole.
+fn split_dotted(root: &toml_edit::Table, path: &str) -> Vec<String> {
+    let parts: Vec<&str> = path.split('.').collect();
+    let mut out = Vec::new();
+    let mut table: Option<&dyn toml_edit::TableLike> = Some(root);
+    let mut i = 0;
+    while i < parts.len() {
+        let found = (i + 1..=parts.len()).rev().find_map(|end| {
+            let key = parts[i..end].join(".");
+            let item = table?.get(&key)?;
+            Some((end, key, item))
+        });
+        match found {
+            Some((end, key, item)) => {
+                out.push(key);
+                table = item.as_table_like();
+                i = end;
+            }
+            None => {
+                out.push(parts[i].to_string());
+                table = None;
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+fn display_path(segments: &[Segment]) -> String {
+    let mut out = String::new();
+    for segment in segments {
+        match segment {
+            Segment::Key(key) => {
+                if !out.is_empty() {
+                    out.push('.');
+                }
+                out.push_str(key);
+            }
+            Segment::Index(i) => out.push_str(&format!("[{i}]")),
+        }
+    }
+    out
+}
+
+/// 1-based line and character column of byte `offset` in `contents`.
+fn line_column(contents: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(contents.len());
+    let before = &contents[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(diagnostics: &[ConfigDiagnostic]) -> Vec<String> {
+        diagnostics.iter().map(|d| d.code.to_string()).collect()
+    }
+
+    #[test]
+    fn test_clean_configs_have_no_findings() {
+        let user = "worktree-path = \"../{{ repo }}.{{ branch | sanitize }}\"\n\
+                    post-start = \"echo {{ branch }}\"\n\n\
+                    [merge]\nsquash = true\n\n\
+                    [aliases]\ndeploy = \"make deploy {{ args }}\"\n";
+        assert!(validate_config(user, ConfigFileKind::User).is_empty());
+
+        let project = "[post-start]\ninstall = \"npm ci\"\n\n\
+                       [pre-merge]\ntest = \"cargo test --target-dir {{ target_worktree_path }}\"\n";
+        assert!(validate_config(project, ConfigFileKind::Project).is_empty());
+    }
+
+    #[test]
+    fn test_example_configs_validate() {
+        for (contents, kind) in [
+            (
+                include_str!("../../dev/config.example.toml"),
+                ConfigFileKind::User,
+            ),
+            (
+                include_str!("../../dev/wt.example.toml"),
+                ConfigFileKind::Project,
+            ),
+        ] {
+            let findings = validate_config(contents, kind);
+            assert!(findings.is_empty(), "{kind:?}: {findings:?}");
+        }
+    }
+
+    #[test]
+    fn test_syntax_error_located() {
+        let d = validate_config("[merge\nsquash = true\n", ConfigFileKind::User);
+        assert_eq!(codes(&d), ["syntax"]);
+        assert_eq!((d[0].line, d[0].column), (Some(1), Some(7)));
+    }
+
+    #[test]
+    fn test_schema_error_located() {
+        let d = validate_config("[merge]\nsquash = \"yes\"\n", ConfigFileKind::User);
+        assert_eq!(codes(&d), ["schema"]);
+        assert_eq!(d[0].key.as_deref(), Some("merge.squash"));
+        assert_eq!((d[0].line, d[0].column), (Some(2), Some(10)));
+    }
+
+    #[test]
+    fn test_schema_error_does_not_hide_unknown_keys() {
+        let d = validate_config(
+            "[merge]\nsquas = true\n\n[list]\nfull = \"yes\"\n",
+            ConfigFileKind::User,
+        );
+        assert_eq!(codes(&d), ["unknown-key", "schema"]);
+        assert_eq!(d[0].key.as_deref(), Some("merge.squas"));
+    }
+
+    #[test]
+    fn test_unknown_key_suggestion() {
+        let d = validate_config("[merge]\nsquas = true\n", ConfigFileKind::User);
+        assert_eq!(codes(&d), ["unknown-key"]);
+        assert_eq!(d[0].key.as_deref(), Some("merge.squas"));
+        assert_eq!(d[0].suggestion.as_deref(), Some("squash"));
+        assert_eq!((d[0].line, d[0].column), (Some(2), Some(1)));
+
+        let d = validate_config("worktree-pth = \"x\"\n", ConfigFileKind::User);
+        assert_eq!(d[0].suggestion.as_deref(), Some("worktree-path"));
+    }
+
+    #[test]
+    fn test_unknown_key_under_dotted_project_id() {
+        let d = validate_config(
+            "[projects.\"github.com/user/repo\".merge]\nverify = false\nsquas = true\n",
+            ConfigFileKind::User,
+        );
+        assert_eq!(codes(&d), ["unknown-key"]);
+        assert_eq!(
+            d[0].key.as_deref(),
+            Some("projects.github.com/user/repo.merge.squas")
+        );
+        assert_eq!(d[0].suggestion.as_deref(), Some("squash"));
+        assert_eq!(d[0].line, Some(3));
+    }
+
+    #[test]
+    fn test_misplaced_key() {
+        let d = validate_config(
+            "skip-shell-integration-prompt = true\n",
+            ConfigFileKind::Project,
+        );
+        assert_eq!(codes(&d), ["misplaced-key"]);
+    }
+
+    #[test]
+    fn test_template_findings() {
+        let d = validate_config(
+            "pre-merge = \"echo {{ base }}\"\n\
+             post-start = \"echo {{ brnch }}\"\n\
+             post-merge = \"echo {{ branch \"\n\
+             [aliases]\nship = \"wt merge {{ args }} {{ hook_type }}\"\n",
+            ConfigFileKind::Project,
+        );
+        assert_eq!(
+            codes(&d),
+            [
+                "unavailable-variable",
+                "unknown-variable",
+                "template-syntax",
+                "unavailable-variable"
+            ]
+        );
+        // Points at the variable, not the opening quote.
+        assert_eq!((d[0].line, d[0].column), (Some(1), Some(22)));
+        assert!(
+            d[0].message
+                .contains("pre-switch, post-switch, pre-start, post-start hooks"),
+            "{}",
+            d[0].message
+        );
+        assert_eq!(d[1].suggestion.as_deref(), Some("branch"));
+        assert_eq!(d[3].key.as_deref(), Some("aliases.ship"));
+    }
+
+    #[test]
+    fn test_pipeline_and_project_override_templates() {
+        let d = validate_config(
+            "[[projects.\"github.com/user/repo\".post-start]]\n\
+             build = \"make {{ nope }}\"\n",
+            ConfigFileKind::User,
+        );
+        assert_eq!(codes(&d), ["unknown-variable"]);
+        assert!(
+            d[0].message.starts_with("post-start:build"),
+            "{}",
+            d[0].message
+        );
+
+        let d = validate_config(
+            "post-start = [\"ok {{ branch }}\", { a = \"{{ base }}\", b = \"{{ bad_var }}\" }]\n",
+            ConfigFileKind::Project,
+        );
+        assert_eq!(codes(&d), ["unknown-variable"]);
+        assert_eq!(d[0].key.as_deref(), Some("post-start[1].b"));
+    }
+
+    #[test]
+    fn test_vars_templates_skip_render() {
+        // `vars.*` resolves from git config at run time; only syntax and
+        // variable names are checked.
+        let d = validate_config(
+            "post-start = \"echo {{ vars.port }}\"\n",
+            ConfigFileKind::Project,
+        );
+        assert!(d.is_empty(), "{d:?}");
+    }
+
+    #[test]
+    fn test_deprecation_is_warning() {
+        let d = validate_config("[merge]\nno-ff = true\n", ConfigFileKind::User);
+        assert_eq!(codes(&d), ["deprecated"]);
+        assert_eq!(d[0].severity, Severity::Warning);
+    }
+}
@@ -0,0 +1,501 @@
+//! Per-worktree containers for projects with a `[container]` section.
+//!
+//! Each worktree gets its own container, named from the worktree path and
+//! labelled with it. The worktree is mounted at its own path (and the
+//! repository's git directory beside it, so `git` works inside), which keeps
+//! every path a hook or `--execute` command sees identical on both sides.
+//!
+//! Like [`crate::sandbox`], a command is moved into the container by
+//! wrapping its shell string: [`Container::wrap`] turns it into
+//! `<runtime> exec -i -w <cwd> <name> sh -c '<command>'`, so the foreground
+//! executor, the concurrent executor, and the background pipeline runner keep
+//! their existing spawn paths.
+//!
+//! The runtime is `docker` or `podman`, found on `PATH`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Approvals, ProjectContainerConfig};
+use crate::git::Repository;
+use crate::shell_exec::Cmd;
+
+/// Label carrying the worktree path a container belongs to.
+pub const WORKTREE_LABEL: &str = "worktrunk.worktree";
+
+/// The approvals scope a `[container]` is recorded under.
+const APPROVAL_SCOPE: &str = "container";
+
+/// Whether a worktree's container is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerState {
+    Running,
+    /// Created or exited; `wt switch` starts it again.
+    Stopped,
+}
+
+impl ContainerState {
+    fn from_status(status: &str) -> Self {
+        if status == "running" {
+            Self::Running
+        } else {
+            Self::Stopped
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Stopped => "stopped",
+        }
+    }
+}
+
+/// One worktree's container: which runtime manages it and its name.
+///
+/// Serialized into the background pipeline spec so the detached runner
+/// execs into the same container.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Container {
+    pub runtime: String,
+    pub name: String,
+}
+
+impl Container {
+    /// The container `config` gives the worktree at `worktree`.
+    pub fn for_worktree(config: &ProjectContainerConfig, worktree: &Path) -> Self {
+        Self {
+            runtime: runtime(config),
+            name: container_name(worktree),
+        }
+    }
+
+    /// The container's state, or `None` when it doesn't exist.
+    pub fn state(&self) -> anyhow::Result<Option<ContainerState>> {
+        Ok(states(&self.runtime, std::slice::from_ref(&self.name))?.remove(&self.name))
+    }
+
+    /// `command` rewritten to run inside the container, with `cwd` as its
+    /// working directory. Stdin is kept open (`-i`) so hooks still read their
+    /// JSON context.
+    pub fn wrap(&self, command: &str, cwd: &Path) -> String {
+        self.exec_words(command, cwd, false)
+            .iter()
+            .map(|word| shell_escape::unix::escape(word.into()).into_owned())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The `exec` invocation that runs `command` in `cwd`, with a terminal
+    /// allocated when `tty`.
+    pub fn exec_words(&self, command: &str, cwd: &Path, tty: bool) -> Vec<String> {
+        vec![
+            self.runtime.clone(),
+            "exec".to_string(),
+            if tty { "-it" } else { "-i" }.to_string(),
+            "-w".to_string(),
+            cwd.to_string_lossy().into_owned(),
+            self.name.clone(),
+            "sh".to_string(),
+            "-c".to_string(),
+            command.to_string(),
+        ]
+    }
+
+    /// Create and start the container from `spec`.
+    pub fn create(&self, spec: &RunSpec) -> anyhow::Result<()> {
+        self.runtime_command(self.run_args(spec))
+    }
+
+    /// Start the existing, stopped container.
+    pub fn start(&self) -> anyhow::Result<()> {
+        self.runtime_command(vec!["start".to_string(), self.name.clone()])
+    }
+
+    /// Stop and delete the container.
+    pub fn remove(&self) -> anyhow::Result<()> {
+        self.runtime_command(vec!["rm".to_string(), "-f".to_string(), self.name.clone()])
+    }
+
+    /// The `run` arguments that create the container from `spec`.
+    ///
+    /// `--init` reaps zombies and forwards `SIGTERM`, so a `sleep infinity`
+    /// main process doesn't hold `rm -f` for the stop timeout.
+    pub fn run_args(&self, spec: &RunSpec) -> Vec<String> {
+        let worktree = spec.worktree.to_string_lossy().into_owned();
+        let mut args = vec![
+            "run".to_string(),
+            "-d".to_string(),
+            "--init".to_string(),
+            "--name".to_string(),
+            self.name.clone(),
+            "--label".to_string(),
+            format!("{WORKTREE_LABEL}={worktree}"),
+            "-v".to_string(),
+            format!("{worktree}:{worktree}"),
+        ];
+        if let Some(git_dir) = &spec.git_dir {
+            let git_dir = git_dir.to_string_lossy();
+            args.extend(["-v".to_string(), format!("{git_dir}:{git_dir}")]);
+        }
+        args.extend(["-w".to_string(), worktree]);
+        for port in &spec.ports {
+            args.extend(["-p".to_string(), port.clone()]);
+        }
+        args.extend(spec.args.iter().cloned());
+        args.push(spec.image.clone());
+        args.extend(spec.command.iter().cloned());
+        args
+    }
+
+    fn runtime_command(&self, args: Vec<String>) -> anyhow::Result<()> {
+        run_runtime(&self.runtime, &args).map(drop)
+    }
+}
+
+/// What a new container runs: the image, what it mounts, and what it
+/// publishes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunSpec {
+    pub image: String,
+    pub worktree: PathBuf,
+    /// The repository's common git directory, mounted so `git` works in a
+    /// linked worktree (whose `.git` file points there).
+    pub git_dir: Option<PathBuf>,
+    /// Rendered `host:container` port mappings.
+    pub ports: Vec<String>,
+    pub args: Vec<String>,
+    pub command: Vec<String>,
+}
+
+/// Where a configured container's image comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageSource {
+    /// Run `image` as configured; the runtime pulls it if needed.
+    Pull(String),
+    /// Build `dockerfile` in `context` as `tag`, unless `tag` exists.
+    Build {
+        tag: String,
+        context: PathBuf,
+        dockerfile: PathBuf,
+    },
+}
+
+impl ImageSource {
+    /// The image `config` runs for the worktree at `worktree` of the
+    /// repository named `repo_name`. A built image is tagged with a hash of
+    /// its Dockerfile, so editing the Dockerfile builds a fresh image and an
+    /// unchanged one is reused.
+    pub fn resolve(
+        config: &ProjectContainerConfig,
+        worktree: &Path,
+        repo_name: &str,
+    ) -> anyhow::Result<Self> {
+        match (&config.image, &config.build) {
+            (Some(_), Some(_)) => {
+                anyhow::bail!("[container] sets both image and build; keep one")
+            }
+            (Some(image), None) => Ok(Self::Pull(image.clone())),
+            (None, Some(build)) => {
+                let context = worktree.join(build);
+                let dockerfile = match &config.dockerfile {
+                    Some(dockerfile) => worktree.join(dockerfile),
+                    None => context.join("Dockerfile"),
+                };
+                let contents = std::fs::read(&dockerfile).with_context(|| {
+                    format!(
+                        "Failed to read {}",
+                        crate::path::format_path_for_display(&dockerfile)
+                    )
+                })?;
+                Ok(Self::Build {
+                    tag: format!("wt-{}:{:08x}", sanitize(repo_name), fnv1a(&contents)),
+                    context,
+                    dockerfile,
+                })
+            }
+            (None, None) => anyhow::bail!("[container] needs an image or a build context"),
+        }
+    }
+
+    /// The image name containers run.
+    pub fn image(&self) -> &str {
+        match self {
+            Self::Pull(image) => image,
+            Self::Build { tag, .. } => tag,
+        }
+    }
+}
+
+/// Whether `runtime` already has `image`.
+pub fn image_exists(runtime: &str, image: &str) -> bool {
+    run_runtime(
+        runtime,
+        &[
+            "image".to_string(),
+            "inspect".to_string(),
+            image.to_string(),
+        ],
+    )
+    .is_ok()
+}
+
+/// Build `dockerfile` in `context` as `tag`, streaming the build output to
+/// stderr.
+pub fn build_image(
+    runtime: &str,
+    tag: &str,
+    context: &Path,
+    dockerfile: &Path,
+) -> anyhow::Result<()> {
+    Cmd::new(runtime)
+        .args([
+            "build".to_string(),
+            "-t".to_string(),
+            tag.to_string(),
+            "-f".to_string(),
+            dockerfile.to_string_lossy().into_owned(),
+            context.to_string_lossy().into_owned(),
+        ])
+        .stdout(std::process::Stdio::from(std::io::stderr()))
+        .stream()
+}
+
+/// The project's `[container]`, when it configures one.
+pub fn configured(repo: &Repository) -> Option<ProjectContainerConfig> {
+    repo.load_project_config()
+        .ok()
+        .flatten()
+        .map(|config| config.container)
+        .filter(ProjectContainerConfig::is_configured)
+}
+
+/// The project's `[container]` when the user saved an approval of it
+/// (`wt config approvals add`, or a `wt switch` prompt). `None` otherwise:
+/// project config is untrusted, so callers that can't ask leave the runtime
+/// alone.
+pub fn approved(repo: &Repository) -> Option<ProjectContainerConfig> {
+    let config = configured(repo)?;
+    let (Ok(project_id), Ok(approvals)) = (repo.project_identifier(), Approvals::load()) else {
+        return None;
+    };
+    approvals
+        .is_command_approved(&project_id, &approval_template(&config), APPROVAL_SCOPE)
+        .then_some(config)
+}
+
+/// The worktree's container under `config`, when it's running.
+pub fn running(config: &ProjectContainerConfig, worktree: &Path) -> Option<Container> {
+    let container = Container::for_worktree(config, worktree);
+    matches!(container.state(), Ok(Some(ContainerState::Running))).then_some(container)
+}
+
+/// The `[container]` as the runtime invocations it stands for, with templates
+/// unrendered: an optional `build` line, then `run`. Approving it approves
+/// exactly these fields, so any change to them asks again.
+pub fn approval_template(config: &ProjectContainerConfig) -> String {
+    let quote = |s: &str| shell_escape::unix::escape(s.into()).into_owned();
+    let mut lines = Vec::new();
+    let image = match &config.build {
+        Some(build) => {
+            let mut line = format!("build {}", quote(build));
+            if let Some(dockerfile) = &config.dockerfile {
+                line.push_str(&format!(" -f {}", quote(dockerfile)));
+            }
+            lines.push(line);
+            "<built image>".to_string()
+        }
+        None => config.image.as_deref().map(quote).unwrap_or_default(),
+    };
+    let mut run = "run".to_string();
+    for port in &config.ports {
+        run.push_str(&format!(" -p {}", quote(port)));
+    }
+    for arg in &config.args {
+        run.push(' ');
+        run.push_str(&quote(arg));
+    }
+    run.push(' ');
+    run.push_str(&image);
+    for word in config.command.iter().flatten() {
+        run.push(' ');
+        run.push_str(&quote(word));
+    }
+    lines.push(run);
+    lines.join("\n")
+}
+
+/// The runtime `config` names, else `docker` when on `PATH`, else `podman`.
+pub fn runtime(config: &ProjectContainerConfig) -> String {
+    if let Some(runtime) = &config.runtime {
+        return runtime.as_str().to_string();
+    }
+    if which::which("docker").is_err() && which::which("podman").is_ok() {
+        return "podman".to_string();
+    }
+    "docker".to_string()
+}
+
+/// The states of the named containers, in one `inspect`. Names missing from
+/// the map don't exist.
+///
+/// `inspect` exits non-zero when any name is missing but still reports the
+/// others, so the exit status is ignored; a runtime that can't run at all is
+/// an error.
+pub fn states(runtime: &str, names: &[String]) -> anyhow::Result<HashMap<String, ContainerState>> {
+    if names.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let output = Cmd::new(runtime)
+        .args(["inspect", "--type", "container", "--format"])
+        .arg("{{.Name}} {{.State.Status}}")
+        .args(names.iter().cloned())
+        .run()
+        .with_context(|| format!("Failed to run {runtime}"))?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (name, status) = line.trim().rsplit_once(' ')?;
+            // Docker prefixes names with `/`; Podman doesn't.
+            let name = name.trim_start_matches('/');
+            Some((name.to_string(), ContainerState::from_status(status)))
+        })
+        .collect())
+}
+
+/// The container name for the worktree at `worktree`: its directory name,
+/// plus a hash of the full path so same-named worktrees of different
+/// repositories don't collide.
+pub fn container_name(worktree: &Path) -> String {
+    let dir = worktree
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    format!(
+        "wt-{}-{:08x}",
+        sanitize(&dir),
+        fnv1a(worktree.to_string_lossy().as_bytes())
+    )
+}
+
+/// `s` restricted to the characters container and image names allow.
+fn sanitize(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-') {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// 32-bit FNV-1a: stable across releases, unlike `DefaultHasher`, so names
+/// and tags stay put when `wt` is upgraded.
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, &byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+fn run_runtime(runtime: &str, args: &[String]) -> anyhow::Result<std::process::Output> {
+    let output = Cmd::new(runtime)
+        .args(args.iter().cloned())
+        .run()
+        .with_context(|| format!("Failed to run {runtime}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{runtime} {} failed: {}", args[0], stderr.trim());
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container() -> Container {
+        Container {
+            runtime: "docker".to_string(),
+            name: "wt-app.feat-12345678".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_container_name_is_stable_and_sanitized() {
+        let name = container_name(Path::new("/src/My App.feat"));
+        assert_eq!(name, "wt-my-app.feat-1f0273ca");
+        assert_ne!(name, container_name(Path::new("/other/My App.feat")));
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(
+            container().wrap("npm ci && echo 'done'", Path::new("/src/app.feat")),
+            r#"docker exec -i -w /src/app.feat wt-app.feat-12345678 sh -c 'npm ci && echo '\''done'\'''"#
+        );
+    }
+
+    #[test]
+    fn test_run_args() {
+        let spec = RunSpec {
+            image: "node:22".to_string(),
+            worktree: PathBuf::from("/src/app.feat"),
+            git_dir: Some(PathBuf::from("/src/app/.git")),
+            ports: vec!["12345:3000".to_string()],
+            args: vec!["--env".to_string(), "CI=1".to_string()],
+            command: vec!["sleep".to_string(), "infinity".to_string()],
+        };
+        assert_eq!(
+            container().run_args(&spec).join(" "),
+            "run -d --init --name wt-app.feat-12345678 --label worktrunk.worktree=/src/app.feat \
+             -v /src/app.feat:/src/app.feat -v /src/app/.git:/src/app/.git -w /src/app.feat \
+             -p 12345:3000 --env CI=1 node:22 sleep infinity"
+        );
+    }
+
+    #[test]
+    fn test_image_source() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Dockerfile"), "FROM alpine\n").unwrap();
+        let config = |image: Option<&str>, build: Option<&str>| ProjectContainerConfig {
+            image: image.map(String::from),
+            build: build.map(String::from),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            ImageSource::resolve(&config(Some("node:22"), None), dir.path(), "app").unwrap(),
+            ImageSource::Pull("node:22".to_string())
+        );
+        let built = ImageSource::resolve(&config(None, Some(".")), dir.path(), "My App").unwrap();
+        assert!(built.image().starts_with("wt-my-app:"), "{built:?}");
+        std::fs::write(dir.path().join("Dockerfile"), "FROM debian\n").unwrap();
+        let rebuilt = ImageSource::resolve(&config(None, Some(".")), dir.path(), "My App").unwrap();
+        assert_ne!(built.image(), rebuilt.image());
+
+        assert!(ImageSource::resolve(&config(Some("a"), Some(".")), dir.path(), "app").is_err());
+        assert!(ImageSource::resolve(&config(None, None), dir.path(), "app").is_err());
+    }
+
+    #[test]
+    fn test_state_from_status() {
+        assert_eq!(
+            ContainerState::from_status("running"),
+            ContainerState::Running
+        );
+        assert_eq!(
+            ContainerState::from_status("exited"),
+            ContainerState::Stopped
+        );
+        assert_eq!(
+            ContainerState::from_status("created"),
+            ContainerState::Stopped
+        );
+    }
+}
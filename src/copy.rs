@@ -12,6 +12,11 @@
 //! Callers that want low-priority I/O (e.g. `step_copy_ignored`) should call
 //! [`crate::priority::lower_current_process`] before starting work.
 //!
+//! [`sync_dir_recursive`] / [`sync_leaf`] are the incremental variants: an
+//! existing destination is compared (size + mtime, or contents) and only
+//! rewritten when it differs, optionally deleting destination entries the
+//! source no longer has.
+//!
 //! Every successful leaf copy calls `progress.record(bytes)` on the caller's
 //! [`Progress`], which both feeds the TTY spinner (when enabled) and
 //! accumulates the `(files, bytes)` totals the caller reads back via
@@ -22,7 +27,6 @@ use std::fs;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
-use std::sync::atomic::{AtomicUsize, Ordering};
 
 use anyhow::Context;
 use rayon::prelude::*;
@@ -164,7 +168,7 @@ pub fn copy_leaf(
     Ok(Some(bytes))
 }
 
-fn ensure_path_within_root(path: &Path, root: &Path) -> anyhow::Result<()> {
+pub(crate) fn ensure_path_within_root(path: &Path, root: &Path) -> anyhow::Result<()> {
     let canonical_root = canonicalize_with_parents(root);
     let canonical_path = canonicalize_with_parents(path);
 
@@ -228,29 +232,101 @@ pub fn copy_dir_recursive(
     force: bool,
     progress: &Progress,
 ) -> anyhow::Result<usize> {
-    // Phase 1: Walk directories iteratively, creating dest dirs and collecting leaves.
-    let mut skipped = 0usize;
-    let mut leaves = Vec::new();
-    // The bool marks the tree's own root: the caller named it, so its absence
-    // is a real error, while a subdirectory that disappears mid-walk is the
-    // same concurrent-build race `copy_leaf` skips over.
+    copy_dir_recursive_with(src, dest, root, force, progress, |src, dest, force| {
+        copy_leaf(src, dest, None, force)
+    })
+}
+
+/// [`copy_dir_recursive`] with a caller-supplied leaf copy.
+///
+/// `copy` receives `(src, dest, force)` for each file or symlink and has the
+/// contract of [`copy_leaf`]: `Some(bytes)` when it wrote the leaf, `None`
+/// when it skipped one. The walk, root guard, skip counting and directory
+/// permissions are unchanged — this is how [`crate::store`] materializes a
+/// tree as hardlinks instead of copies.
+#[must_use = "a caller that deletes the source must refuse on a non-zero skip count"]
+pub fn copy_dir_recursive_with<F>(
+    src: &Path,
+    dest: &Path,
+    root: Option<&Path>,
+    force: bool,
+    progress: &Progress,
+    copy: F,
+) -> anyhow::Result<usize>
+where
+    F: Fn(&Path, &Path, bool) -> anyhow::Result<Option<u64>> + Sync,
+{
+    let walk = walk_tree(src, dest, root, |dest_dir, _| {
+        fs::create_dir_all(dest_dir)
+            .with_context(|| format!("creating directory {}", dest_dir.display()))?;
+        Ok(true)
+    })?;
+
+    let outcomes = walk.for_each_leaf(|leaf| {
+        let copied = copy(&leaf.src, &leaf.dest, force)?;
+        if let Some(bytes) = copied {
+            progress.record(bytes);
+        }
+        Ok(copied)
+    })?;
+    let skipped_leaves = outcomes.iter().filter(|copied| copied.is_none()).count();
+    let vanished_dirs = walk.restore_dir_permissions()?;
+
+    Ok(walk.skipped + skipped_leaves + vanished_dirs)
+}
+
+/// Directories and leaves collected by [`walk_tree`].
+struct Walk {
+    leaves: Vec<CopyLeaf>,
+    /// `(src, dest)` pairs for the destination directories the walk created,
+    /// in walk order, whose permissions are restored after the leaves land.
+    dirs: Vec<(PathBuf, PathBuf)>,
+    /// Subdirectories and entries that vanished between listing and reading.
+    skipped: usize,
+}
+
+/// Walk `src` iteratively, collecting the files and symlinks to mirror under
+/// `dest` — the shared first phase of [`copy_dir_recursive_with`] and
+/// [`sync_dir_recursive`].
+///
+/// Each directory's source listing is read before `prepare_dir(dest_dir,
+/// entries)` runs, so a subtree that vanished leaves nothing behind
+/// at the destination. `prepare_dir` is where the caller creates (or, for a
+/// dry run, doesn't create) the destination directory; it returns whether it
+/// did, which is what queues the directory for
+/// [`Walk::restore_dir_permissions`].
+///
+/// A subdirectory or entry that disappears mid-walk is skipped and counted;
+/// the tree's own root is the exception — the caller named it, so its absence
+/// is an error. Non-regular files (sockets, FIFOs) are dropped uncounted.
+fn walk_tree<F>(
+    src: &Path,
+    dest: &Path,
+    root: Option<&Path>,
+    mut prepare_dir: F,
+) -> anyhow::Result<Walk>
+where
+    F: FnMut(&Path, &[fs::DirEntry]) -> anyhow::Result<bool>,
+{
+    let mut walk = Walk {
+        leaves: Vec::new(),
+        dirs: Vec::new(),
+        skipped: 0,
+    };
+    // The bool marks the tree's own root.
     let mut dir_stack = vec![(src.to_path_buf(), dest.to_path_buf(), true)];
-    #[cfg(unix)]
-    let mut dirs_for_perms: Vec<(PathBuf, PathBuf)> = Vec::new();
 
     while let Some((src_dir, dest_dir, is_root)) = dir_stack.pop() {
         if let Some(root) = root {
             ensure_path_within_root(&dest_dir, root)?;
         }
 
-        // Read the source before creating the destination directory, so a
-        // subtree that vanished leaves no empty directory behind.
         let entries = match fs::read_dir(&src_dir).and_then(|it| it.collect::<Result<Vec<_>, _>>())
         {
             Ok(entries) => entries,
             Err(e) if e.kind() == ErrorKind::NotFound && !is_root => {
                 tracing::debug!(path = %src_dir.display(), "skipping vanished directory: {}", src_dir.display());
-                skipped += 1;
+                walk.skipped += 1;
                 continue;
             }
             Err(e) => {
@@ -259,10 +335,9 @@ pub fn copy_dir_recursive(
             }
         };
 
-        fs::create_dir_all(&dest_dir)
-            .with_context(|| format!("creating directory {}", dest_dir.display()))?;
-        #[cfg(unix)]
-        dirs_for_perms.push((src_dir.clone(), dest_dir.clone()));
+        if prepare_dir(&dest_dir, &entries)? {
+            walk.dirs.push((src_dir.clone(), dest_dir.clone()));
+        }
 
         for entry in entries {
             let file_type = match entry.file_type() {
@@ -270,7 +345,7 @@ pub fn copy_dir_recursive(
                 // The entry was listed but is gone already — same race.
                 Err(e) if e.kind() == ErrorKind::NotFound => {
                     tracing::debug!(path = %entry.path().display(), "skipping vanished entry: {}", entry.path().display());
-                    skipped += 1;
+                    walk.skipped += 1;
                     continue;
                 }
                 Err(e) => {
@@ -284,7 +359,7 @@ pub fn copy_dir_recursive(
             if file_type.is_dir() {
                 dir_stack.push((src_path, dest_path, false));
             } else if file_type.is_file() || file_type.is_symlink() {
-                leaves.push(CopyLeaf {
+                walk.leaves.push(CopyLeaf {
                     src: src_path,
                     dest: dest_path,
                 });
@@ -294,53 +369,353 @@ pub fn copy_dir_recursive(
         }
     }
 
-    // Phase 2: Copy all leaves in parallel.
-    let skipped_leaves = AtomicUsize::new(0);
-    COPY_POOL.install(|| {
-        leaves
-            .par_iter()
-            .try_for_each(|leaf| -> anyhow::Result<()> {
-                match copy_leaf(&leaf.src, &leaf.dest, None, force)? {
-                    Some(bytes) => progress.record(bytes),
-                    None => {
-                        skipped_leaves.fetch_add(1, Ordering::Relaxed);
-                    }
+    Ok(walk)
+}
+
+impl Walk {
+    /// Run `action` on every collected leaf in parallel on the copy pool,
+    /// returning the outcomes in no particular order.
+    fn for_each_leaf<T, F>(&self, action: F) -> anyhow::Result<Vec<T>>
+    where
+        T: Send,
+        F: Fn(&CopyLeaf) -> anyhow::Result<T> + Sync,
+    {
+        COPY_POOL.install(|| self.leaves.par_iter().map(&action).collect())
+    }
+
+    /// Give each created destination directory its source's permissions,
+    /// returning how many sources had vanished by then.
+    ///
+    /// Must run after the leaves are written — a source without write
+    /// permission (e.g. 0o555) applied first would make the destination
+    /// read-only and fail the copies into it. A source directory that went
+    /// away after its contents were copied leaves the destination with default
+    /// permissions rather than failing a batch that otherwise succeeded.
+    fn restore_dir_permissions(&self) -> anyhow::Result<usize> {
+        let mut vanished = 0;
+        #[cfg(unix)]
+        for (src_dir, dest_dir) in &self.dirs {
+            let src_perms = match fs::metadata(src_dir) {
+                Ok(meta) => meta.permissions(),
+                Err(e) if e.kind() == ErrorKind::NotFound => {
+                    tracing::debug!(path = %src_dir.display(), "skipping permissions for vanished directory: {}", src_dir.display());
+                    vanished += 1;
+                    continue;
                 }
-                Ok(())
-            })
-    })?;
-    skipped += skipped_leaves.into_inner();
+                Err(e) => {
+                    return Err(anyhow::Error::from(e)
+                        .context(format!("reading permissions for {}", src_dir.display())));
+                }
+            };
+            fs::set_permissions(dest_dir, src_perms)
+                .with_context(|| format!("setting permissions on {}", dest_dir.display()))?;
+        }
+        Ok(vanished)
+    }
+}
 
-    // Phase 3: Preserve source directory permissions AFTER copying contents.
-    // Must be done after copying — if the source lacks write permission (e.g., 0o555),
-    // setting it before copying would make the destination read-only and fail the copies.
-    #[cfg(unix)]
-    for (src_dir, dest_dir) in &dirs_for_perms {
-        let src_perms = match fs::metadata(src_dir) {
-            Ok(meta) => meta.permissions(),
-            // The source directory went away after its contents were copied.
-            // The destination is already written; leave it with the default
-            // permissions rather than failing a batch that otherwise succeeded.
-            Err(e) if e.kind() == ErrorKind::NotFound => {
-                tracing::debug!(path = %src_dir.display(), "skipping permissions for vanished directory: {}", src_dir.display());
-                skipped += 1;
-                continue;
+/// How [`sync_leaf`] decides that a destination file is already up to date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncCompare {
+    /// Same size and modification time — rsync's default quick check. Synced
+    /// files carry the source's mtime, so an unchanged file costs one stat.
+    SizeAndMtime,
+    /// Same size and byte-for-byte identical contents, regardless of mtime.
+    Content,
+}
+
+/// Options for [`sync_dir_recursive`].
+#[derive(Debug, Clone, Copy)]
+pub struct SyncOptions {
+    pub compare: SyncCompare,
+    /// Remove destination entries that no longer exist at the source.
+    pub delete: bool,
+    /// Walk and compare without writing anything; the returned stats describe
+    /// what a real sync would do.
+    pub dry_run: bool,
+}
+
+/// What [`sync_leaf`] did with one file or symlink.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeafSync {
+    /// The destination didn't exist; the source was copied (`bytes`).
+    Created(u64),
+    /// The destination differed and was replaced (`bytes`).
+    Updated(u64),
+    /// The destination already matched the source.
+    Unchanged,
+    /// The source vanished before it could be copied.
+    Skipped,
+}
+
+/// Per-tree counts from a sync, in leaves (files and symlinks).
+///
+/// `deleted` counts destination paths removed — a stale directory counts once,
+/// however much it held.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct SyncStats {
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub deleted: usize,
+    /// Bytes written by the created and updated leaves.
+    pub bytes: u64,
+}
+
+impl SyncStats {
+    /// Fold one leaf's outcome into the counts.
+    pub fn record(&mut self, outcome: LeafSync) {
+        match outcome {
+            LeafSync::Created(bytes) => {
+                self.created += 1;
+                self.bytes += bytes;
             }
-            Err(e) => {
-                return Err(anyhow::Error::from(e)
-                    .context(format!("reading permissions for {}", src_dir.display())));
+            LeafSync::Updated(bytes) => {
+                self.updated += 1;
+                self.bytes += bytes;
+            }
+            LeafSync::Unchanged => self.unchanged += 1,
+            LeafSync::Skipped => {}
+        }
+    }
+
+    /// Add another tree's counts to these.
+    pub fn merge(&mut self, other: SyncStats) {
+        self.created += other.created;
+        self.updated += other.updated;
+        self.unchanged += other.unchanged;
+        self.deleted += other.deleted;
+        self.bytes += other.bytes;
+    }
+
+    /// Leaves written (created or updated).
+    pub fn changed(&self) -> usize {
+        self.created + self.updated
+    }
+}
+
+/// Bring a single destination file or symlink up to date with `src`.
+///
+/// Unlike [`copy_leaf`], an existing destination is compared rather than
+/// skipped or blindly replaced: one that matches under `compare` is left
+/// alone, one that differs is replaced. A destination *directory* where the
+/// source has a file is removed first, so the destination ends up mirroring the
+/// source's shape. Copied regular files take the source's modification time,
+/// which is what lets the next [`SyncCompare::SizeAndMtime`] pass recognize
+/// them.
+///
+/// A source that vanished is [`LeafSync::Skipped`] without touching the
+/// destination, as in [`copy_leaf`]. With `dry_run`, nothing is written and the
+/// returned outcome is what a real sync would report.
+pub fn sync_leaf(
+    src: &Path,
+    dest: &Path,
+    root: Option<&Path>,
+    compare: SyncCompare,
+    dry_run: bool,
+) -> anyhow::Result<LeafSync> {
+    if let Some(root) = root {
+        ensure_path_within_root(dest.parent().unwrap_or(dest), root)?;
+    }
+    let src_meta = match src.symlink_metadata() {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            tracing::debug!(path = %src.display(), "skipping vanished source: {}", src.display());
+            return Ok(LeafSync::Skipped);
+        }
+        Err(e) => {
+            return Err(
+                anyhow::Error::from(e).context(format!("reading metadata for {}", src.display()))
+            );
+        }
+    };
+
+    let existed = match dest.symlink_metadata() {
+        Ok(dest_meta) => {
+            if leaf_matches(src, &src_meta, dest, &dest_meta, compare)? {
+                return Ok(LeafSync::Unchanged);
             }
+            if !dry_run && dest_meta.is_dir() {
+                fs::remove_dir_all(dest)
+                    .with_context(|| format!("removing directory {}", dest.display()))?;
+            }
+            true
+        }
+        // `NotADirectory`: a dry run over a destination file the real sync
+        // would have replaced with this leaf's parent directory.
+        Err(e) if matches!(e.kind(), ErrorKind::NotFound | ErrorKind::NotADirectory) => false,
+        Err(e) => {
+            return Err(
+                anyhow::Error::from(e).context(format!("reading metadata for {}", dest.display()))
+            );
+        }
+    };
+
+    let bytes = src_meta.len();
+    if !dry_run {
+        if copy_leaf(src, dest, None, true)?.is_none() {
+            return Ok(LeafSync::Skipped);
+        }
+        if src_meta.is_file()
+            && let Ok(mtime) = src_meta.modified()
+        {
+            set_mtime(dest, mtime)
+                .with_context(|| format!("setting modification time on {}", dest.display()))?;
+        }
+    }
+    Ok(if existed {
+        LeafSync::Updated(bytes)
+    } else {
+        LeafSync::Created(bytes)
+    })
+}
+
+/// Whether an existing destination already matches the source leaf.
+fn leaf_matches(
+    src: &Path,
+    src_meta: &fs::Metadata,
+    dest: &Path,
+    dest_meta: &fs::Metadata,
+    compare: SyncCompare,
+) -> anyhow::Result<bool> {
+    let (src_type, dest_type) = (src_meta.file_type(), dest_meta.file_type());
+    if src_type.is_symlink() || dest_type.is_symlink() {
+        return Ok(src_type.is_symlink()
+            && dest_type.is_symlink()
+            && fs::read_link(src).ok() == fs::read_link(dest).ok());
+    }
+    if !dest_type.is_file() || src_meta.len() != dest_meta.len() {
+        return Ok(false);
+    }
+    match compare {
+        SyncCompare::SizeAndMtime => Ok(matches!(
+            (src_meta.modified(), dest_meta.modified()),
+            (Ok(src_mtime), Ok(dest_mtime)) if src_mtime == dest_mtime
+        )),
+        SyncCompare::Content => files_equal(src, dest)
+            .with_context(|| format!("comparing {} with {}", src.display(), dest.display())),
+    }
+}
+
+/// Byte-for-byte comparison of two files already known to have equal length.
+fn files_equal(a: &Path, b: &Path) -> std::io::Result<bool> {
+    use std::io::Read;
+
+    let (mut a, mut b) = (fs::File::open(a)?, fs::File::open(b)?);
+    let (mut buf_a, mut buf_b) = (vec![0u8; 64 * 1024], vec![0u8; 64 * 1024]);
+    loop {
+        let n = a.read(&mut buf_a)?;
+        if n == 0 {
+            return Ok(true);
+        }
+        b.read_exact(&mut buf_b[..n])?;
+        if buf_a[..n] != buf_b[..n] {
+            return Ok(false);
+        }
+    }
+}
+
+/// Stamp `path` with `mtime`. Setting explicit times needs ownership, not write
+/// access, so a read-only handle suffices on Unix (the file was just created by
+/// us); Windows needs a writable handle for `SetFileTime`.
+fn set_mtime(path: &Path, mtime: std::time::SystemTime) -> std::io::Result<()> {
+    #[cfg(unix)]
+    let file = fs::File::open(path)?;
+    #[cfg(not(unix))]
+    let file = fs::OpenOptions::new().write(true).open(path)?;
+    file.set_modified(mtime)
+}
+
+/// Incrementally sync a directory tree, copying only what changed.
+///
+/// The rsync-style counterpart of [`copy_dir_recursive`]: the same iterative
+/// walk, parallel leaf pass on the copy pool, and root guard, but each leaf
+/// goes through [`sync_leaf`], so unchanged files cost a stat (or a read, under
+/// [`SyncCompare::Content`]) instead of a copy. With `options.delete`,
+/// destination entries absent from the source are removed as the walk reaches
+/// their directory. A destination file where the source has a directory is
+/// replaced by the directory.
+///
+/// Vanished sources are skipped as in [`copy_dir_recursive`]; the tree's own
+/// root must exist. Each created or updated leaf is recorded on `progress`.
+pub fn sync_dir_recursive(
+    src: &Path,
+    dest: &Path,
+    root: Option<&Path>,
+    options: SyncOptions,
+    progress: &Progress,
+) -> anyhow::Result<SyncStats> {
+    let mut stats = SyncStats::default();
+    let walk = walk_tree(src, dest, root, |dest_dir, entries| {
+        // A file or symlink where the source has a directory is replaced.
+        let dest_is_dir = match dest_dir.symlink_metadata() {
+            Ok(meta) if meta.is_dir() => true,
+            Ok(_) => {
+                if !options.dry_run {
+                    remove_if_exists(dest_dir)?;
+                }
+                false
+            }
+            Err(_) => false,
         };
-        fs::set_permissions(dest_dir, src_perms)
-            .with_context(|| format!("setting permissions on {}", dest_dir.display()))?;
+
+        if options.delete && dest_is_dir {
+            let src_names: std::collections::HashSet<_> =
+                entries.iter().map(|e| e.file_name()).collect();
+            for stale in fs::read_dir(dest_dir)
+                .with_context(|| format!("reading directory {}", dest_dir.display()))?
+            {
+                let stale = stale?;
+                if src_names.contains(&stale.file_name()) {
+                    continue;
+                }
+                let path = stale.path();
+                if !options.dry_run {
+                    // `file_type` doesn't follow symlinks, so a symlink to a
+                    // directory is unlinked, never descended into.
+                    if stale.file_type()?.is_dir() {
+                        fs::remove_dir_all(&path)
+                    } else {
+                        fs::remove_file(&path)
+                    }
+                    .with_context(|| format!("removing {}", path.display()))?;
+                }
+                stats.deleted += 1;
+            }
+        }
+
+        if options.dry_run {
+            return Ok(false);
+        }
+        fs::create_dir_all(dest_dir)
+            .with_context(|| format!("creating directory {}", dest_dir.display()))?;
+        Ok(true)
+    })?;
+
+    let outcomes = walk.for_each_leaf(|leaf| {
+        let outcome = sync_leaf(
+            &leaf.src,
+            &leaf.dest,
+            None,
+            options.compare,
+            options.dry_run,
+        )?;
+        if let LeafSync::Created(bytes) | LeafSync::Updated(bytes) = outcome {
+            progress.record(bytes);
+        }
+        Ok(outcome)
+    })?;
+    for outcome in outcomes {
+        stats.record(outcome);
     }
+    walk.restore_dir_permissions()?;
 
-    Ok(skipped)
+    Ok(stats)
 }
 
 /// Remove a file, ignoring "not found" errors. Reports whether one was removed,
 /// which is what tells `copy_leaf` whether a later skip would leave a hole.
-fn remove_if_exists(path: &Path) -> anyhow::Result<bool> {
+pub(crate) fn remove_if_exists(path: &Path) -> anyhow::Result<bool> {
     if let Err(e) = fs::remove_file(path) {
         anyhow::ensure!(e.kind() == ErrorKind::NotFound, e);
         return Ok(false);
@@ -544,4 +919,119 @@ mod tests {
         );
         assert!(!dest.exists());
     }
+
+    fn sync_options(compare: SyncCompare, delete: bool, dry_run: bool) -> SyncOptions {
+        SyncOptions {
+            compare,
+            delete,
+            dry_run,
+        }
+    }
+
+    #[test]
+    fn test_sync_leaf_creates_then_recognizes_unchanged() {
+        // A synced file carries the source's mtime, so the next quick check
+        // sees it as unchanged without reading it.
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        let dest = dir.path().join("dest");
+        fs::write(&src, b"content").unwrap();
+
+        let sync = |compare| sync_leaf(&src, &dest, None, compare, false).unwrap();
+        assert_eq!(sync(SyncCompare::SizeAndMtime), LeafSync::Created(7));
+        assert_eq!(sync(SyncCompare::SizeAndMtime), LeafSync::Unchanged);
+
+        // Same size, different contents and mtime: the quick check replaces it.
+        fs::write(&dest, b"CONTENT").unwrap();
+        let stale = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1);
+        set_mtime(&dest, stale).unwrap();
+        assert_eq!(sync(SyncCompare::SizeAndMtime), LeafSync::Updated(7));
+        assert_eq!(fs::read(&dest).unwrap(), b"content");
+    }
+
+    #[test]
+    fn test_sync_leaf_content_compare_ignores_mtime() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        let dest = dir.path().join("dest");
+        fs::write(&src, b"same").unwrap();
+        fs::write(&dest, b"same").unwrap();
+        let stale = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1);
+        set_mtime(&dest, stale).unwrap();
+
+        assert_eq!(
+            sync_leaf(&src, &dest, None, SyncCompare::Content, false).unwrap(),
+            LeafSync::Unchanged
+        );
+        assert_eq!(
+            sync_leaf(&src, &dest, None, SyncCompare::SizeAndMtime, true).unwrap(),
+            LeafSync::Updated(4),
+            "the quick check would rewrite it"
+        );
+    }
+
+    #[test]
+    fn test_sync_dir_recursive_delete_and_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        let dest = dir.path().join("dest");
+        fs::create_dir_all(src.join("nested")).unwrap();
+        fs::write(src.join("keep"), b"keep").unwrap();
+        fs::write(src.join("nested").join("new"), b"new").unwrap();
+        fs::create_dir_all(dest.join("gone-dir")).unwrap();
+        fs::write(dest.join("gone-dir").join("x"), b"x").unwrap();
+        fs::write(dest.join("gone"), b"gone").unwrap();
+
+        let first = sync_dir_recursive(
+            &src,
+            &dest,
+            None,
+            sync_options(SyncCompare::SizeAndMtime, true, false),
+            &Progress::disabled(),
+        )
+        .unwrap();
+        assert_eq!(
+            first,
+            SyncStats {
+                created: 2,
+                updated: 0,
+                unchanged: 0,
+                deleted: 2,
+                bytes: 7,
+            }
+        );
+        assert!(!dest.join("gone").exists());
+        assert!(!dest.join("gone-dir").exists());
+
+        // A dry run reports the pending change and writes nothing.
+        fs::write(src.join("keep"), b"changed").unwrap();
+        fs::write(dest.join("extra"), b"extra").unwrap();
+        let planned = sync_dir_recursive(
+            &src,
+            &dest,
+            None,
+            sync_options(SyncCompare::SizeAndMtime, true, true),
+            &Progress::disabled(),
+        )
+        .unwrap();
+        assert_eq!(
+            (planned.updated, planned.unchanged, planned.deleted),
+            (1, 1, 1)
+        );
+        assert_eq!(fs::read(dest.join("keep")).unwrap(), b"keep");
+        assert!(dest.join("extra").exists());
+
+        // Without `delete`, extra destination entries stay.
+        let synced = sync_dir_recursive(
+            &src,
+            &dest,
+            None,
+            sync_options(SyncCompare::SizeAndMtime, false, false),
+            &Progress::disabled(),
+        )
+        .unwrap();
+        assert_eq!((synced.updated, synced.deleted), (1, 0));
+        assert_eq!(fs::read(dest.join("keep")).unwrap(), b"changed");
+        assert!(dest.join("extra").exists());
+    }
 }
@@ -1,7 +1,7 @@
 //! Display utilities for terminal output.
 //!
 //! This module provides utility functions for:
-//! - Relative time formatting
+//! - Relative time and duration formatting
 //! - Path manipulation and shortening
 
 use std::path::{Component, Path};
@@ -16,14 +16,23 @@ pub(crate) fn format_relative_time_short(timestamp: i64) -> String {
     format_relative_time_impl(timestamp, epoch_now() as i64)
 }
 
-fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
-    const MINUTE: i64 = 60;
-    const HOUR: i64 = MINUTE * 60;
-    const DAY: i64 = HOUR * 24;
-    const WEEK: i64 = DAY * 7;
-    const MONTH: i64 = DAY * 30;
-    const YEAR: i64 = DAY * 365;
+const MINUTE: i64 = 60;
+const HOUR: i64 = MINUTE * 60;
+const DAY: i64 = HOUR * 24;
+const WEEK: i64 = DAY * 7;
+const MONTH: i64 = DAY * 30;
+const YEAR: i64 = DAY * 365;
+
+const UNITS: &[(i64, &str)] = &[
+    (YEAR, "y"),
+    (MONTH, "mo"),
+    (WEEK, "w"),
+    (DAY, "d"),
+    (HOUR, "h"),
+    (MINUTE, "m"),
+];
 
+fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
     let seconds_ago = now - timestamp;
 
     if seconds_ago < 0 {
@@ -34,15 +43,6 @@ fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
         return "now".to_string();
     }
 
-    const UNITS: &[(i64, &str)] = &[
-        (YEAR, "y"),
-        (MONTH, "mo"),
-        (WEEK, "w"),
-        (DAY, "d"),
-        (HOUR, "h"),
-        (MINUTE, "m"),
-    ];
-
     for &(unit_seconds, abbrev) in UNITS {
         let value = seconds_ago / unit_seconds;
         if value > 0 {
@@ -53,6 +53,18 @@ fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
     "now".to_string()
 }
 
+/// Format a duration in its largest whole unit (e.g., "45s", "14m", "2h")
+pub(crate) fn format_duration_short(seconds: u64) -> String {
+    let seconds = i64::try_from(seconds).unwrap_or(i64::MAX);
+    UNITS
+        .iter()
+        .find(|&&(unit_seconds, _)| seconds >= unit_seconds)
+        .map_or_else(
+            || format!("{seconds}s"),
+            |&(unit_seconds, abbrev)| format!("{}{abbrev}", seconds / unit_seconds),
+        )
+}
+
 /// Shorten a path relative to the main worktree.
 ///
 /// Returns paths relative to main worktree using `..` components where needed:
@@ -171,6 +183,16 @@ mod tests {
         assert_eq!(shorten_path(&sibling, &main_worktree), "../project.feature");
     }
 
+    #[test]
+    fn test_format_duration_short() {
+        assert_eq!(format_duration_short(0), "0s");
+        assert_eq!(format_duration_short(59), "59s");
+        assert_eq!(format_duration_short(60), "1m");
+        assert_eq!(format_duration_short(14 * 60 + 59), "14m");
+        assert_eq!(format_duration_short(2 * 3600), "2h");
+        assert_eq!(format_duration_short(9 * 86400), "1w");
+    }
+
     #[test]
     fn test_format_relative_time_short_public() {
         // Test the public function (uses epoch_now internally)
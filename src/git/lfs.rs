@@ -0,0 +1,350 @@
+//! Git LFS awareness for worktree creation, `copy-ignored`, and `wt list`.
+//!
+//! # Why
+//!
+//! Checking out a worktree in an LFS repository runs the smudge filter for
+//! every LFS file, which downloads each object the worktree needs — once per
+//! worktree, even though the main worktree already fetched them all into
+//! `.git/lfs/objects`, which every worktree shares.
+//!
+//! # How
+//!
+//! `wt switch --create` runs `git worktree add` with
+//! [`SKIP_SMUDGE_ENV`] set, so LFS files are checked out as pointer files,
+//! then [`hydrate`]s them: each pointer whose object is in the shared cache
+//! is replaced by the object — reflinked where the filesystem supports it,
+//! copied otherwise — and `git update-index` re-records it so the worktree
+//! stays clean. Objects missing from the cache stay
+//! pointers; `git lfs pull` fetches them.
+//!
+//! None of this needs the `git-lfs` binary: LFS files are found by the
+//! `filter=lfs` attribute (`git ls-files ':(attr:filter=lfs)'`), and pointers
+//! are parsed directly. Hydrated files only read as clean when git can run
+//! the LFS clean filter, though, so hydration is skipped unless
+//! `filter.lfs.clean` or `filter.lfs.process` is configured.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use super::repository::path_to_logging_context;
+use super::{Repository, WorkingTree};
+use crate::shell_exec::Cmd;
+
+/// Environment variable that makes the LFS smudge filter emit pointers.
+pub const SKIP_SMUDGE_ENV: &str = "GIT_LFS_SKIP_SMUDGE";
+
+/// Pointer files are at most this large (LFS spec).
+const MAX_POINTER_SIZE: u64 = 1024;
+
+/// The first line of every pointer file.
+const POINTER_VERSION: &str = "version https://git-lfs.github.com/spec/v1";
+
+/// A parsed LFS pointer file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pointer {
+    /// SHA-256 of the object, hex.
+    pub oid: String,
+    /// Object size in bytes.
+    pub size: u64,
+}
+
+/// Parse an LFS pointer file, `None` when `contents` isn't one.
+pub fn parse_pointer(contents: &[u8]) -> Option<Pointer> {
+    let text = std::str::from_utf8(contents).ok()?;
+    let mut lines = text.lines();
+    if lines.next()? != POINTER_VERSION {
+        return None;
+    }
+    let (mut oid, mut size) = (None, None);
+    for line in lines {
+        if let Some(hex) = line.strip_prefix("oid sha256:") {
+            oid = (hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                .then(|| hex.to_ascii_lowercase());
+        } else if let Some(n) = line.strip_prefix("size ") {
+            size = n.parse().ok();
+        }
+    }
+    Some(Pointer {
+        oid: oid?,
+        size: size?,
+    })
+}
+
+/// Whether `.gitattributes` contents route any path through the LFS filter.
+pub fn attributes_use_lfs(contents: &str) -> bool {
+    contents.lines().any(|line| {
+        let line = line.trim();
+        !line.starts_with('#')
+            && line
+                .split_whitespace()
+                .skip(1)
+                .any(|attr| attr == "filter=lfs")
+    })
+}
+
+/// Whether the repository uses LFS: some `.gitattributes` at `HEAD` sets
+/// `filter=lfs`. An unborn `HEAD` reads as no.
+pub fn uses_lfs(repo: &Repository) -> bool {
+    repo.current_worktree()
+        .head_sha()
+        .ok()
+        .flatten()
+        .is_some_and(|sha| uses_lfs_at(repo, &sha))
+}
+
+/// [`uses_lfs`] at commit `sha`, for callers that already know `HEAD`.
+///
+/// Searching the tree walks every directory, so the answer — fixed for a
+/// commit — is cached in [`crate::git::sha_cache`] and computed once per
+/// commit rather than on every `wt list`.
+pub fn uses_lfs_at(repo: &Repository, sha: &str) -> bool {
+    if let Some(cached) = crate::git::sha_cache::uses_lfs(repo, sha) {
+        return cached;
+    }
+    // `git grep` narrows to the attribute files mentioning the filter (exit
+    // 1 when none does); parsing them then rules out comments and patterns.
+    let Ok(output) = repo.run_command_output(&[
+        "grep",
+        "-l",
+        "-F",
+        "-e",
+        "filter=lfs",
+        sha,
+        "--",
+        ".gitattributes",
+        "*/.gitattributes",
+    ]) else {
+        return false;
+    };
+    let files = match output.status.code() {
+        Some(0) => String::from_utf8_lossy(&output.stdout).into_owned(),
+        Some(1) => String::new(),
+        // Not a commit here (yet): answer no, but don't cache it.
+        _ => return false,
+    };
+    let uses = files.lines().any(|blob| {
+        repo.run_command(&["cat-file", "blob", blob])
+            .is_ok_and(|contents| attributes_use_lfs(&contents))
+    });
+    crate::git::sha_cache::put_uses_lfs(repo, sha, uses);
+    uses
+}
+
+/// Whether git can run the LFS clean filter, without which hydrated files
+/// read as modified.
+pub fn filter_configured(repo: &Repository) -> bool {
+    ["filter.lfs.process", "filter.lfs.clean"]
+        .iter()
+        .any(|key| repo.config_value(key).ok().flatten().is_some())
+}
+
+/// Where the shared cache keeps the object with `oid`.
+pub fn object_path(git_common_dir: &Path, oid: &str) -> PathBuf {
+    git_common_dir
+        .join("lfs")
+        .join("objects")
+        .join(&oid[..2])
+        .join(&oid[2..4])
+        .join(oid)
+}
+
+/// LFS files of a worktree: how many it tracks and how many are still
+/// pointers.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+pub struct LfsState {
+    /// Tracked files with the `filter=lfs` attribute.
+    pub files: usize,
+    /// Of those, files checked out as pointers rather than content.
+    pub pointers: usize,
+}
+
+impl LfsState {
+    /// Files holding their content.
+    pub fn hydrated(&self) -> usize {
+        self.files - self.pointers
+    }
+}
+
+/// Tracked LFS files of `worktree`, relative to its root.
+fn lfs_paths(worktree: &WorkingTree<'_>) -> anyhow::Result<Vec<String>> {
+    let output = worktree.run_command(&["ls-files", "-z", "--", ":(attr:filter=lfs)"])?;
+    Ok(output
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// The pointer a checked-out file holds, `None` when it holds content (or
+/// is missing).
+fn read_pointer(path: &Path) -> Option<Pointer> {
+    let meta = fs::symlink_metadata(path).ok()?;
+    if !meta.is_file() || meta.len() > MAX_POINTER_SIZE {
+        return None;
+    }
+    parse_pointer(&fs::read(path).ok()?)
+}
+
+/// The LFS state of `worktree`.
+pub fn state(worktree: &WorkingTree<'_>) -> anyhow::Result<LfsState> {
+    let paths = lfs_paths(worktree)?;
+    let root = worktree.path();
+    let pointers = paths
+        .iter()
+        .filter(|path| read_pointer(&root.join(path)).is_some())
+        .count();
+    Ok(LfsState {
+        files: paths.len(),
+        pointers,
+    })
+}
+
+/// What [`hydrate`] did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HydrateStats {
+    /// Pointers replaced by their object.
+    pub hydrated: usize,
+    /// Pointers whose object isn't in the shared cache.
+    pub missing: usize,
+    /// Bytes of hydrated content.
+    pub bytes: u64,
+}
+
+/// Replace `worktree`'s pointer files with their objects from the shared
+/// cache, then re-record them in the index so they read as unchanged.
+pub fn hydrate(worktree: &WorkingTree<'_>) -> anyhow::Result<HydrateStats> {
+    let common_dir = worktree.repo().git_common_dir().to_path_buf();
+    let root = worktree.path();
+    let mut stats = HydrateStats::default();
+    let mut hydrated = Vec::new();
+    for relative in lfs_paths(worktree)? {
+        let path = root.join(&relative);
+        let Some(pointer) = read_pointer(&path) else {
+            continue;
+        };
+        let object = object_path(&common_dir, &pointer.oid);
+        // A partial download or a corrupt cache entry is as good as missing.
+        if fs::metadata(&object).map(|meta| meta.len()).ok() != Some(pointer.size) {
+            stats.missing += 1;
+            continue;
+        }
+        replace_with_object(&path, &object)
+            .with_context(|| format!("Failed to hydrate {relative}"))?;
+        stats.hydrated += 1;
+        stats.bytes += pointer.size;
+        hydrated.push(relative);
+    }
+    if !hydrated.is_empty() {
+        // `update-index --refresh` isn't enough: git reads a size change as a
+        // modification without consulting the clean filter. Re-recording the
+        // paths runs it, which yields the same pointer blob.
+        let mut paths = hydrated.join("\0");
+        paths.push('\0');
+        let output = Cmd::new("git")
+            .args(["update-index", "-q", "-z", "--stdin"])
+            .current_dir(root)
+            .context(path_to_logging_context(root))
+            .stdin_bytes(paths)
+            .run()?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "git update-index failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+    }
+    Ok(stats)
+}
+
+/// Swap `path` for a clone of `object`, keeping `path`'s permissions. The
+/// clone lands beside `path` first so the swap is a rename.
+fn replace_with_object(path: &Path, object: &Path) -> anyhow::Result<()> {
+    let permissions = fs::metadata(path)?.permissions();
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".wt-lfs");
+    let temp = PathBuf::from(temp);
+    let result = reflink_copy::reflink_or_copy(object, &temp)
+        .map_err(anyhow::Error::from)
+        .and_then(|_| Ok(fs::set_permissions(&temp, permissions)?))
+        .and_then(|()| Ok(fs::rename(&temp, path)?));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OID: &str = "4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393";
+
+    #[test]
+    fn test_parse_pointer() {
+        let pointer = format!("{POINTER_VERSION}\noid sha256:{OID}\nsize 12345\n");
+        assert_eq!(
+            parse_pointer(pointer.as_bytes()),
+            Some(Pointer {
+                oid: OID.to_string(),
+                size: 12345
+            })
+        );
+        assert_eq!(parse_pointer(b"binary\0content"), None);
+        assert_eq!(
+            parse_pointer(format!("{POINTER_VERSION}\noid sha256:abc\nsize 1\n").as_bytes()),
+            None
+        );
+        assert_eq!(
+            parse_pointer(format!("{POINTER_VERSION}\noid sha256:{OID}\n").as_bytes()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_attributes_use_lfs() {
+        assert!(attributes_use_lfs(
+            "*.psd filter=lfs diff=lfs merge=lfs -text\n"
+        ));
+        assert!(!attributes_use_lfs(
+            "# *.psd filter=lfs\n*.sh text eol=lf\n"
+        ));
+        assert!(!attributes_use_lfs("filter=lfs\n"));
+    }
+
+    #[test]
+    fn test_uses_lfs_is_cached_per_commit() {
+        let test = crate::testing::TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+        let plain = test.git_output(&["rev-parse", "HEAD"]);
+        assert!(!uses_lfs(&repo));
+
+        fs::write(
+            test.root_path().join(".gitattributes"),
+            "*.bin filter=lfs diff=lfs merge=lfs -text\n",
+        )
+        .unwrap();
+        test.run_git(&["add", ".gitattributes"]);
+        test.run_git(&["commit", "-m", "Track binaries with LFS"]);
+        let lfs = test.git_output(&["rev-parse", "HEAD"]);
+        assert!(uses_lfs(&repo));
+
+        // Both answers are cached by commit, the "no" included.
+        assert_eq!(crate::git::sha_cache::uses_lfs(&repo, &plain), Some(false));
+        assert_eq!(crate::git::sha_cache::uses_lfs(&repo, &lfs), Some(true));
+        crate::git::sha_cache::put_uses_lfs(&repo, &lfs, false);
+        assert!(!uses_lfs_at(&repo, &lfs));
+    }
+
+    #[test]
+    fn test_object_path() {
+        assert_eq!(
+            object_path(Path::new("/repo/.git"), OID),
+            Path::new("/repo/.git/lfs/objects/4d/7a").join(OID)
+        );
+    }
+}
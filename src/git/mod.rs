@@ -7,6 +7,7 @@ mod ci_platform;
 mod diff;
 mod error;
 pub mod fsmonitor;
+pub mod lfs;
 mod parse;
 #[cfg(unix)]
 pub mod reap;
@@ -14,6 +15,7 @@ pub mod recover;
 pub mod remote_ref;
 pub mod remove;
 mod repository;
+pub mod submodule;
 mod url;
 
 #[cfg(test)]
@@ -70,7 +72,7 @@ pub use error::{
     // Render a single error via Diagnostic if it implements one
     try_render_diagnostic,
 };
-pub use parse::{parse_porcelain_z, parse_untracked_files};
+pub use parse::{PorcelainStatus, parse_porcelain_z, parse_untracked_files};
 pub use recover::{current_or_recover, cwd_removed_hint};
 pub use remove::{
     BranchDeletionMode, BranchDeletionOutcome, BranchDeletionResult, RemovalOutput, RemoveOptions,
@@ -103,7 +105,9 @@ pub(crate) use url::{canonical_url_path_segment, url_path_segments_eq};
 /// 4. [`TreesMatch`](Self::TreesMatch) - tree SHA comparison (~100-300ms)
 /// 5. [`MergeAddsNothing`](Self::MergeAddsNothing) - merge simulation (~500ms-2s)
 /// 6. [`PatchIdMatch`](Self::PatchIdMatch) - patch-id matching when merge-tree conflicts (~1-3s)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::IntoStaticStr)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::IntoStaticStr, strum::EnumString,
+)]
 #[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub enum IntegrationReason {
@@ -137,6 +137,80 @@ impl DefaultBranchName {
     }
 }
 
+/// Working-tree change flags read from `git status --porcelain` (v1) output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PorcelainStatus {
+    /// Index changes: added, modified, copied, or type-changed.
+    pub staged: bool,
+    /// Worktree changes: modified, intent-to-add, or type-changed.
+    pub modified: bool,
+    /// Untracked files.
+    pub untracked: bool,
+    /// Renames in the index.
+    pub renamed: bool,
+    /// Deletions in the index or the worktree.
+    pub deleted: bool,
+    /// Unmerged paths (merge conflicts).
+    pub conflicted: bool,
+}
+
+impl PorcelainStatus {
+    /// Read the flags from newline-separated porcelain v1 output.
+    pub fn parse(status_output: &str) -> Self {
+        let mut status = Self::default();
+
+        for line in status_output.lines() {
+            if line.len() < 2 {
+                continue;
+            }
+
+            let bytes = line.as_bytes();
+            let index_status = bytes[0] as char;
+            let worktree_status = bytes[1] as char;
+
+            if index_status == '?' && worktree_status == '?' {
+                status.untracked = true;
+            }
+
+            // Worktree changes: M = modified, A = intent-to-add (git add -N), T = type change (file↔symlink)
+            if matches!(worktree_status, 'M' | 'A' | 'T') {
+                status.modified = true;
+            }
+
+            // Index changes: A = added, M = modified, C = copied, T = type change (file↔symlink)
+            if matches!(index_status, 'A' | 'M' | 'C' | 'T') {
+                status.staged = true;
+            }
+
+            if index_status == 'R' {
+                status.renamed = true;
+            }
+
+            if index_status == 'D' || worktree_status == 'D' {
+                status.deleted = true;
+            }
+
+            // Detect unmerged/conflicting paths (porcelain v1 two-letter codes)
+            // Only U codes and AA/DD indicate actual merge conflicts.
+            // AD/DA are normal staging states (staged then deleted, or deleted then restored).
+            let is_unmerged_pair = matches!(
+                (index_status, worktree_status),
+                ('U', _) | (_, 'U') | ('A', 'A') | ('D', 'D')
+            );
+            if is_unmerged_pair {
+                status.conflicted = true;
+            }
+        }
+
+        status
+    }
+
+    /// Whether any change is present.
+    pub fn is_dirty(&self) -> bool {
+        self.staged || self.modified || self.untracked || self.renamed || self.deleted
+    }
+}
+
 /// Parse `git status --porcelain -z` output into a list of affected filenames.
 ///
 /// The -z format uses NUL separators and handles renames specially:
@@ -963,6 +963,25 @@ impl Repository {
         }
     }
 
+    /// Hash of the project config's contents, as approvals record it
+    /// ([`ApprovalRecord::config_hash`](crate::config::approvals::ApprovalRecord::config_hash)).
+    ///
+    /// Reads the same source [`load_project_config`](Self::load_project_config)
+    /// does — the on-disk file, else the default branch's committed copy — so
+    /// the hash identifies the config whose commands are being approved.
+    /// `None` when there's no project config.
+    pub fn project_config_hash(&self) -> anyhow::Result<Option<String>> {
+        let contents = match self.project_config_path()? {
+            Some(path) if path.exists() => std::fs::read(&path)
+                .with_context(|| format!("Failed to read {}", format_path_for_display(&path)))?,
+            _ => match self.default_branch_project_config_content() {
+                Some((contents, _)) => contents.into_bytes(),
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(crate::config::approvals::config_hash(&contents)))
+    }
+
     /// Load the project configuration (.config/wt.toml) if it exists.
     ///
     /// Result is cached in the repository's shared cache (same for all clones).
@@ -167,6 +167,52 @@ impl Repository {
         Ok(result)
     }
 
+    /// Check if HEAD is a linear extension of the target branch.
+    ///
+    /// Returns true when:
+    /// 1. The merge-base equals target's SHA (target hasn't advanced), AND
+    /// 2. There are no merge commits between target and HEAD (history is linear)
+    ///
+    /// This detects branches that have merged the target into themselves — such
+    /// branches need rebasing to linearize history even though merge-base equals target.
+    pub fn is_rebased_onto(&self, target: &str) -> anyhow::Result<bool> {
+        // Orphan branches have no common ancestor, so they can't be "rebased onto" target
+        let Some(merge_base) = self.merge_base("HEAD", target)? else {
+            return Ok(false);
+        };
+        // `merge_base` peels an annotated tag to the commit it points at; a bare
+        // `rev-parse` returns the tag object's own SHA. Comparing the two forms
+        // never matches, so an annotated-tag target would always be reported as
+        // needing a rebase — and `wt step rebase <annotated-tag>` would replay
+        // nothing while announcing "Rebased onto <tag>". Peel both sides.
+        let target_sha = self
+            .run_command(&[
+                "rev-parse",
+                "--verify",
+                "--end-of-options",
+                &format!("{target}^{{commit}}"),
+            ])?
+            .trim()
+            .to_string();
+
+        if merge_base != target_sha {
+            return Ok(false); // Target has advanced past merge-base
+        }
+
+        // Check for merge commits — if present, history is not linear
+        let merge_commits = self
+            .run_command(&[
+                "rev-list",
+                "--merges",
+                "--end-of-options",
+                &format!("{}..HEAD", target),
+            ])?
+            .trim()
+            .to_string();
+
+        Ok(merge_commits.is_empty())
+    }
+
     /// Check if a branch has file changes beyond the merge-base with target.
     ///
     /// Uses merge-base (cached) to find common ancestor, then two-dot diff to
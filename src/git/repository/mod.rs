@@ -736,6 +736,32 @@ impl Repository {
         })
     }
 
+    /// A handle on the same repository that re-reads everything that can go
+    /// stale — refs, worktrees, working-tree status, config — while keeping
+    /// the caches keyed by commit SHAs (merge bases, trees, merge-tree
+    /// outcomes, diff stats), which no later change can invalidate.
+    ///
+    /// For long-lived processes like `wt serve`, which must see each change
+    /// but shouldn't re-pay the expensive probes on every request.
+    pub fn refreshed(&self) -> Self {
+        let cache = RepoCache {
+            merge_base: self.cache.merge_base.clone(),
+            commit_tree: self.cache.commit_tree.clone(),
+            merge_tree: self.cache.merge_tree.clone(),
+            diff_stats: self.cache.diff_stats.clone(),
+            ..RepoCache::default()
+        };
+        if let Some(path) = self.cache.repo_path.get() {
+            let _ = cache.repo_path.set(path.clone());
+        }
+        Self {
+            discovery_path: self.discovery_path.clone(),
+            git_common_dir: self.git_common_dir.clone(),
+            cache: Arc::new(cache),
+            temporary_object_directory: None,
+        }
+    }
+
     /// If this repository's object database is read-only, return a clone whose
     /// object-writing git plumbing is redirected into a temporary object
     /// database (with the real database as a read-only alternate); otherwise
@@ -1879,13 +1905,18 @@ impl Repository {
     /// ones like `worktree add` on large repos). Routing through `Cmd` is what
     /// gives the command its `[wt-trace]` record and directive scrubbing — see
     /// that method for the full contract.
+    ///
+    /// `envs` are set on the git process (e.g. `GIT_LFS_SKIP_SMUDGE` for
+    /// `worktree add` in an LFS repository).
     pub fn run_command_delayed_stream(
         &self,
         args: &[&str],
+        envs: &[(&str, &str)],
         delay_ms: i64,
         progress_message: Option<String>,
     ) -> anyhow::Result<()> {
-        Cmd::new("git")
+        envs.iter()
+            .fold(Cmd::new("git"), |cmd, (key, val)| cmd.env(key, val))
             .args(args.iter().copied())
             .current_dir(&self.discovery_path)
             .context(self.logging_context())
@@ -2030,8 +2061,12 @@ fn parse_config_list_z(stdout: &[u8]) -> indexmap::IndexMap<String, Vec<String>>
 /// Shared by [`Repository::prewarm_user_config`] (preload thread on `main`)
 /// and [`Repository::user_config`] (on-demand path for tests and callers
 /// that bypass prewarm). Both routes go through the same formatting so the
-/// stderr output is byte-identical regardless of which path runs.
+/// stderr output is byte-identical regardless of which path runs. Silent
+/// once [`crate::config::suppress_warnings`] has latched.
 fn emit_user_config_warnings(warnings: &[LoadError]) {
+    if crate::config::warnings_suppressed() {
+        return;
+    }
     for warning in warnings {
         match warning {
             LoadError::File { path, kind, err } => {
@@ -4,12 +4,14 @@
 //! content-addressed SHAs — diffing commit A against commit B is the same
 //! today as last week. One variant (working-tree conflict checks) uses a
 //! composite key that includes a tree SHA; see
-//! [`Repository::has_merge_conflicts_by_tree_with_base_sha`]. No TTL, no invalidation
+//! [`Repository::has_merge_conflicts_by_tree_with_base_sha`]. Another (LFS
+//! detection) is keyed on a single commit. No TTL, no invalidation
 //! logic, only a per-kind LRU size bound to prevent unbounded growth.
 //!
 //! Layout: `.git/wt/cache/{kind}/{key}.json` where `kind` is one of
 //! `merge-tree-conflicts`, `merge-add-probe`, `is-ancestor`,
-//! `has-added-changes`, `diff-stats`, `ahead-behind`, or `merge-base`.
+//! `has-added-changes`, `diff-stats`, `ahead-behind`, `merge-base`, or
+//! `uses-lfs`.
 //! Symmetric kinds sort the SHA pair so `(A, B)` and `(B, A)` hit the same
 //! entry; asymmetric kinds preserve ordering. See [`crate::cache`] for
 //! read/write/clear mechanics, torn-write semantics, and the
@@ -32,6 +34,7 @@ const KIND_HAS_ADDED_CHANGES: &str = "has-added-changes";
 const KIND_DIFF_STATS: &str = "diff-stats";
 const KIND_AHEAD_BEHIND: &str = "ahead-behind";
 const KIND_MERGE_BASE: &str = "merge-base";
+const KIND_USES_LFS: &str = "uses-lfs";
 
 /// All cache kind identifiers, used by [`clear_all`].
 const ALL_KINDS: &[&str] = &[
@@ -42,6 +45,7 @@ const ALL_KINDS: &[&str] = &[
     KIND_DIFF_STATS,
     KIND_AHEAD_BEHIND,
     KIND_MERGE_BASE,
+    KIND_USES_LFS,
 ];
 
 /// Build a symmetric filename from a SHA pair (order-independent).
@@ -261,6 +265,24 @@ pub(super) fn put_merge_base(repo: &Repository, sha1: &str, sha2: &str, value: &
     );
 }
 
+// uses-lfs (single commit)
+
+/// Look up a cached [`crate::git::lfs::uses_lfs_at`] result for `sha`.
+pub(crate) fn uses_lfs(repo: &Repository, sha: &str) -> Option<bool> {
+    cache::read(repo, KIND_USES_LFS, &format!("{sha}.json"))
+}
+
+/// Store a [`crate::git::lfs::uses_lfs_at`] result for `sha`.
+pub(crate) fn put_uses_lfs(repo: &Repository, sha: &str, value: bool) {
+    cache::write_with_lru(
+        repo,
+        KIND_USES_LFS,
+        &format!("{sha}.json"),
+        &value,
+        MAX_ENTRIES_PER_KIND,
+    );
+}
+
 // Maintenance
 
 /// Clear all cached SHA-keyed entries, returning the count removed. Called
@@ -615,7 +615,7 @@ fn extract_failed_command_from_command_error() {
     // A buffered `git` failure (Repository::run_command,
     // WorkingTree::run_command) surfaces as a typed CommandError, possibly
     // wrapped by `.context(...)`. extract_failed_command must walk the
-    // chain so callers like switch::worktree_creation_error keep working.
+    // chain so callers like lifecycle::worktree_creation_error keep working.
     use crate::git::CommandError;
     use anyhow::Context;
 
@@ -717,6 +717,94 @@ fn commit_details_many_returns_subject_with_spaces() {
     assert!(sha2.starts_with(short2.as_str()));
 }
 
+/// `Repository::advance_branch` moves the branch and worktree together while leaving
+/// non-overlapping uncommitted changes — staged entries included — exactly
+/// in place, and refuses atomically otherwise: an overlapping dirty file
+/// rolls the ref update back, and a stale `old_sha` (a concurrently
+/// advanced target) fails the compare-and-swap before anything moves.
+/// These paths have no deterministic trigger through the CLI — the upfront
+/// conflict check catches everything slower than a race — so they are
+/// proven here.
+#[test]
+fn advance_branch_carries_dirty_files_and_refuses_atomically() {
+    use crate::git::Repository;
+    use crate::testing::TestRepo;
+
+    let test = TestRepo::with_initial_commit();
+    let repo = Repository::at(test.root_path()).unwrap();
+    let root = test.root_path().to_path_buf();
+    std::fs::write(root.join("x.txt"), "base-x").unwrap();
+    test.run_git(&["add", "x.txt"]);
+    test.run_git(&["commit", "-m", "add x"]);
+    let old = test.git_output(&["rev-parse", "HEAD"]);
+
+    // Build the incoming commit on a side branch, then return to main so
+    // the worktree sits at `old` with a clean tree.
+    test.run_git(&["switch", "-c", "incoming"]);
+    std::fs::write(root.join("x.txt"), "incoming-x").unwrap();
+    test.run_git(&["commit", "-am", "change x"]);
+    let new = test.git_output(&["rev-parse", "HEAD"]);
+    test.run_git(&["switch", "main"]);
+
+    // Non-overlapping uncommitted state: an unstaged edit, a staged new
+    // file, and an untracked file.
+    std::fs::write(root.join("file.txt"), "unstaged-edit").unwrap();
+    std::fs::write(root.join("staged.txt"), "staged").unwrap();
+    test.run_git(&["add", "staged.txt"]);
+    std::fs::write(root.join("untracked.txt"), "untracked").unwrap();
+
+    repo.advance_branch("main", Some(&root), &old, &new, "test")
+        .unwrap();
+    assert_eq!(test.git_output(&["rev-parse", "main"]), new);
+    assert_eq!(
+        std::fs::read_to_string(root.join("x.txt")).unwrap(),
+        "incoming-x"
+    );
+    // Unstaged edit still unstaged, staged entry still staged (and only
+    // it), untracked file still untracked.
+    assert_eq!(test.git_output(&["diff", "--name-only"]), "file.txt");
+    assert_eq!(
+        test.git_output(&["diff", "--cached", "--name-only"]),
+        "staged.txt"
+    );
+    assert_eq!(
+        test.git_output(&["ls-files", "--others", "--exclude-standard"]),
+        "untracked.txt"
+    );
+
+    // Overlap: a dirty edit to a file the next update changes. The sync
+    // refuses and the ref update is rolled back.
+    test.run_git(&["switch", "-c", "incoming2", "incoming"]);
+    std::fs::write(root.join("x.txt"), "incoming2-x").unwrap();
+    test.run_git(&["commit", "-am", "change x again"]);
+    let new2 = test.git_output(&["rev-parse", "HEAD"]);
+    test.run_git(&["switch", "main"]);
+    std::fs::write(root.join("x.txt"), "local-edit").unwrap();
+
+    let err = repo
+        .advance_branch("main", Some(&root), &new, &new2, "test")
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("rolled back"),
+        "unexpected error: {err:#}"
+    );
+    assert_eq!(test.git_output(&["rev-parse", "main"]), new);
+    assert_eq!(
+        std::fs::read_to_string(root.join("x.txt")).unwrap(),
+        "local-edit"
+    );
+
+    // Stale old_sha: the compare-and-swap fails before anything moves.
+    let err = repo
+        .advance_branch("main", Some(&root), &old, &new2, "test")
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("Can't push"),
+        "unexpected error: {err:#}"
+    );
+    assert_eq!(test.git_output(&["rev-parse", "main"]), new);
+}
+
 #[test]
 fn abbrev_len_follows_core_abbrev_and_covers_absent_objects() {
     use crate::git::Repository;
@@ -2193,3 +2281,27 @@ fn worktree_path_not_ours_names_a_normalized_path() {
         "the refusal must name where the occupant belongs:\n{refusal}"
     );
 }
+
+/// `refreshed` re-reads the worktree list a repository had cached, while the
+/// commit-keyed merge-base cache carries over.
+#[test]
+fn refreshed_rereads_worktrees_and_keeps_sha_caches() {
+    use crate::git::Repository;
+    use crate::testing::TestRepo;
+
+    let mut test = TestRepo::with_initial_commit();
+    let repo = Repository::at(test.root_path()).unwrap();
+    assert_eq!(repo.list_worktrees().unwrap().len(), 1);
+    let base = test.head_sha();
+    test.commit("second");
+    let head = test.head_sha();
+    repo.merge_base(&base, &head).unwrap();
+    assert_eq!(repo.cache.merge_base.len(), 1);
+
+    test.add_worktree("feature");
+    // The original handle still answers from its cache.
+    assert_eq!(repo.list_worktrees().unwrap().len(), 1);
+    let refreshed = repo.refreshed();
+    assert_eq!(refreshed.list_worktrees().unwrap().len(), 2);
+    assert_eq!(refreshed.cache.merge_base.len(), 1);
+}
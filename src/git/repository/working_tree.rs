@@ -52,7 +52,7 @@ fn has_initialized_submodules_from_status(status: &str) -> bool {
 /// Structured rather than a display string so [`Rebase`](Self::Rebase) can be
 /// recognized without matching on user-visible text. The `strum` name is the
 /// `wt list --format=json` value for the operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr, strum::EnumString)]
 #[strum(serialize_all = "snake_case")]
 pub enum InProgressOperation {
     Merge,
@@ -557,6 +557,41 @@ impl<'a> WorkingTree<'a> {
             .collect())
     }
 
+    /// Content of an unmerged path at one index stage, or `None` when that
+    /// side has no version of the file (an add/add conflict has no base; a
+    /// modify/delete conflict is missing one side).
+    ///
+    /// Stage 1 is the merge base, stage 2 "ours" and stage 3 "theirs". During
+    /// a rebase "ours" is the target plus the commits already replayed, and
+    /// "theirs" is the branch commit being replayed — the reverse of a merge.
+    /// `path` is relative to the worktree root, as
+    /// [`unmerged_paths`](Self::unmerged_paths) reports it.
+    pub fn unmerged_stage(&self, path: &str, stage: u8) -> anyhow::Result<Option<Vec<u8>>> {
+        let spec = format!(":{stage}:{path}");
+        let output = self.run_command_output(&["cat-file", "blob", &spec])?;
+        Ok(output.status.success().then_some(output.stdout))
+    }
+
+    /// Continue a stopped rebase once its conflicts are staged.
+    ///
+    /// `GIT_EDITOR=true` keeps each replayed commit's message as-is: git opens
+    /// the editor for a commit whose conflicts were resolved, and with
+    /// captured stdio that editor would hang waiting on a terminal it can't
+    /// reach. The raw output is returned so the caller can tell a completed
+    /// rebase from one that stopped on the next commit's conflicts.
+    pub fn continue_rebase(&self) -> anyhow::Result<std::process::Output> {
+        self.repo
+            .with_object_store_env(
+                Cmd::new("git")
+                    .args(["rebase", "--continue"])
+                    .current_dir(&self.path)
+                    .context(path_to_logging_context(&self.path))
+                    .env("GIT_EDITOR", "true"),
+            )
+            .run()
+            .context("Failed to execute: git rebase --continue")
+    }
+
     /// Fail when the index still holds unresolved conflicts.
     ///
     /// A precondition for the commands that stage on the user's behalf
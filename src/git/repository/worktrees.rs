@@ -7,11 +7,14 @@ use std::sync::{LazyLock, Mutex};
 use anyhow::Context as _;
 use color_print::cformat;
 use dunce::canonicalize;
+use normalize_path::NormalizePath;
 
 use super::{
     GitError, Repository, ResolvedWorktree, Selector, WorktreeInfo, is_valid_branch_name,
     normalize_selector, resolve_input_path,
 };
+use crate::config::UserConfig;
+use crate::git::{ErrorExt, parse_porcelain_z};
 use crate::path::{format_path_for_display, paths_match};
 use crate::styling::{
     eprintln, format_with_gutter, hint_message, suggest_command, warning_message,
@@ -69,6 +72,49 @@ impl Repository {
             .map(Vec::as_slice)
     }
 
+    /// Compute the expected worktree path for a branch name.
+    ///
+    /// For the default branch, returns the repo root (main worktree location).
+    /// For other branches, applies the `worktree-path` template from `config`.
+    ///
+    /// Uses cached values for `default_branch` and `is_bare`.
+    pub fn expected_worktree_path(
+        &self,
+        branch: &str,
+        config: &UserConfig,
+    ) -> anyhow::Result<PathBuf> {
+        let repo_root = self.repo_path()?;
+        let default_branch = self.default_branch().unwrap_or_default();
+        let is_bare = self.is_bare()?;
+
+        // Default branch lives at repo root (main worktree), not a templated path.
+        // Exception: bare repos have no main worktree, so all branches use templated paths.
+        if !is_bare && branch == default_branch {
+            return Ok(repo_root.to_path_buf());
+        }
+
+        let repo_name = repo_root
+            .file_name()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Repository path has no filename: {}",
+                    format_path_for_display(repo_root)
+                )
+            })?
+            .to_str()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Repository path contains invalid UTF-8: {}",
+                    format_path_for_display(repo_root)
+                )
+            })?;
+
+        let project = self.project_identifier().ok();
+        let expanded_path = config.format_path(repo_name, branch, self, project.as_deref())?;
+
+        Ok(repo_root.join(expanded_path).normalize())
+    }
+
     /// Find the worktree path for a given branch, if one exists.
     ///
     /// A branch normally maps to at most one worktree, but `git worktree add
@@ -115,6 +161,189 @@ impl Repository {
         Ok(Some(path))
     }
 
+    /// Advance `target_branch` to `new_sha` and sync its checked-out worktree.
+    ///
+    /// The worktrees share one object store, so nothing needs transferring: the
+    /// update is a compare-and-swap `update-ref` against `old_sha`, which fails
+    /// cleanly if another process advanced the target since the caller
+    /// snapshotted it.
+    ///
+    /// The worktree sync is git's documented lenient `push-to-checkout` policy
+    /// (githooks(5)): `update-index --refresh` then a two-tree merge
+    /// (`read-tree -m -u`). The two-tree merge leaves uncommitted changes at paths
+    /// the update doesn't touch exactly where they are — staged entries stay
+    /// staged — and refuses the update when a dirty path overlaps it. (`reset
+    /// --keep` can't express this sync: HEAD already points at `new_sha` through
+    /// the branch, so it sees old == new and is a no-op.) On refusal the ref
+    /// update is rolled back with a second compare-and-swap, so the branch and
+    /// its worktree move together or not at all.
+    ///
+    /// `reflog_message` labels the target branch's reflog entry — the recovery
+    /// affordance for the one operation that moves a branch the user isn't
+    /// standing on, where every other mover (`push`, `merge`, `reset`) names
+    /// itself.
+    pub fn advance_branch(
+        &self,
+        target_branch: &str,
+        target_worktree_path: Option<&Path>,
+        old_sha: &str,
+        new_sha: &str,
+        reflog_message: &str,
+    ) -> anyhow::Result<()> {
+        let target_ref = format!("refs/heads/{target_branch}");
+        self.run_command(&[
+            "update-ref",
+            "-m",
+            reflog_message,
+            &target_ref,
+            new_sha,
+            old_sha,
+        ])
+        .map_err(|e| GitError::PushFailed {
+            target_branch: target_branch.to_string(),
+            error: format!("Failed to update ref: {}", e.display_message()),
+        })?;
+
+        let Some(wt_path) = target_worktree_path else {
+            return Ok(());
+        };
+        let target_wt = self.worktree_at(wt_path);
+        // Refresh first: `read-tree -m -u` trusts index stat data, so an entry
+        // that is merely stat-dirty would otherwise refuse as "not uptodate".
+        // `submodule.recurse=false`: the sync moves the superproject only —
+        // recursing into submodules re-introduces #1604's failure mode when the
+        // user has `submodule.recurse=true` (read-tree is on git's always-recurse
+        // list).
+        let sync_result = target_wt
+            .run_command(&["update-index", "-q", "--refresh"])
+            .and_then(|_| {
+                target_wt.run_command(&[
+                    "-c",
+                    "submodule.recurse=false",
+                    "read-tree",
+                    "-m",
+                    "-u",
+                    old_sha,
+                    new_sha,
+                ])
+            });
+        let e = match sync_result {
+            Ok(_) => {
+                // The compare-and-swap guarded the ref update, not the sync that
+                // follows it: a commit racing into the target worktree inside
+                // that window is built against the pre-sync index, so its tree
+                // reverts the pushed content while keeping it in the ancestry — a
+                // state the old fast-forward path couldn't reach because
+                // receive-pack re-verifies the expected old value when committing
+                // the ref. Re-read the ref so that interleaving surfaces instead
+                // of being reported as a clean merge. The push range is in the
+                // tip's ancestry either way, so this warns rather than fails.
+                let tip_after = self.run_command(&["rev-parse", &target_ref])?;
+                if tip_after.trim() != new_sha {
+                    eprintln!(
+                        "{}",
+                        warning_message(cformat!(
+                            "<bold>{target_branch}</> moved while its worktree was being synced; check <bold>git -C {} status</>",
+                            format_path_for_display(wt_path)
+                        ))
+                    );
+                }
+                return Ok(());
+            }
+            Err(e) => e,
+        };
+
+        // The sync refused — a conflicting change appeared in the race window
+        // after the upfront check, or the index is locked; git's own error names
+        // the cause. unpack-trees runs its refusal checks before writing anything,
+        // so the worktree is normally untouched — only a write error partway
+        // through the update phase (ENOSPC, a read-only file) can leave a subset
+        // of files already carrying the new content, and git's error says which
+        // write failed. Either way, put the ref back so the branch and its
+        // worktree stay consistent.
+        let context = match self.run_command(&[
+            "update-ref",
+            "-m",
+            "wt: rollback (worktree sync failed)",
+            &target_ref,
+            old_sha,
+            new_sha,
+        ]) {
+            Ok(_) => {
+                format!(
+                    "Syncing the {target_branch} worktree failed; the ref change was rolled back"
+                )
+            }
+            // Only a third writer moving the ref inside this window can land here;
+            // report the state that remains rather than guessing at a fix.
+            Err(rollback_err) => format!(
+                "Syncing the {target_branch} worktree failed, and the ref change could not be \
+                 rolled back ({rollback_err}); {target_branch} has advanced but its worktree \
+                 was not synced"
+            ),
+        };
+        Err(e).context(context)
+    }
+
+    /// Refuse the push when target-worktree changes overlap the push range,
+    /// `target_branch..HEAD` as seen from this repository's worktree.
+    ///
+    /// Uncommitted changes at paths the push range doesn't touch are left
+    /// alone — the two-tree merge in [`Self::advance_branch`] carries them in
+    /// place — so this only names the files that genuinely conflict, before
+    /// anything moves.
+    ///
+    /// The caller has already established that `target_worktree` exists on disk
+    /// ([`Self::usable_worktree_for_branch`] refuses a registered-but-missing
+    /// worktree), so the status read here is free to fail if the directory is
+    /// gone.
+    ///
+    /// Ignored files are deliberately out of scope — they're absent from the
+    /// `git status --porcelain` read this works from, and matching git there is
+    /// the decision, not an oversight. The module spec in
+    /// `commands/worktree/push.rs` says why.
+    pub fn ensure_no_target_conflicts(
+        &self,
+        target_worktree: Option<&Path>,
+        target_branch: &str,
+    ) -> anyhow::Result<()> {
+        let Some(wt_path) = target_worktree else {
+            return Ok(());
+        };
+
+        // `-uall` lists individual files inside untracked directories — the
+        // default collapses them to a single `dir/` entry, which can never
+        // match a file path in the push range — and, being explicit, it
+        // overrides a user's `status.showUntrackedFiles=no`. `-z` handles
+        // filenames with spaces and renames ("XY path\0" for normal files,
+        // "XY new_path\0old_path\0" for renames/copies).
+        let wt = self.worktree_at(wt_path);
+        let wt_status_output = wt.run_command(&["status", "--porcelain", "-z", "-uall"])?;
+        if wt_status_output.trim().is_empty() {
+            return Ok(());
+        }
+
+        let push_files = self.changed_files(target_branch, "HEAD")?;
+        let wt_files: Vec<String> = parse_porcelain_z(&wt_status_output);
+
+        let overlapping: Vec<String> = push_files
+            .iter()
+            .filter(|f| wt_files.contains(f))
+            .cloned()
+            .collect();
+
+        if !overlapping.is_empty() {
+            return Err(GitError::ConflictingChanges {
+                target_branch: target_branch.to_string(),
+                files: overlapping,
+                worktree_path: wt_path.to_path_buf(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
     /// Whether nothing can run in the worktree registered at `path`.
     ///
     /// Two independent ways for that to be true, and neither implies the other,
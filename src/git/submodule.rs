@@ -0,0 +1,312 @@
+//! Submodules in worktrees: initializing them from the primary worktree's
+//! copies, and detecting drift for `wt list`.
+//!
+//! # Initialization
+//!
+//! A new worktree starts with its submodules uninitialized. `git submodule
+//! update --init` clones each one from its URL — over the network, once per
+//! worktree, although the primary worktree already holds every submodule's
+//! repository under `<git-dir>/modules/<name>`.
+//!
+//! [`init_from_reference`] clones from those repositories instead: the
+//! submodule URL is overridden for the one command to point at the primary
+//! worktree's copy, which is also passed as `--reference`, so objects are
+//! borrowed through `alternates` rather than copied or fetched. The clone's
+//! `origin` is then pointed back at the real URL, so `git fetch` inside the
+//! submodule behaves as usual. Nested submodules recurse the same way, from
+//! the primary copy's own `modules/`. A submodule the primary worktree never
+//! initialized has nothing to borrow from and is left uninitialized.
+//!
+//! # Drift
+//!
+//! [`drift`] reads the submodule field of `git status --porcelain=v2`
+//! (`S<c><m><u>`), restricted to the paths `.gitmodules` declares: a
+//! submodule whose checkout differs from the commit the superproject records
+//! is out of date; one with tracked or untracked changes is dirty.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::WorkingTree;
+use super::repository::path_to_logging_context;
+use crate::shell_exec::Cmd;
+
+/// Submodules that no longer match the superproject.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+pub struct SubmoduleDrift {
+    /// A submodule's checked-out commit differs from the recorded one.
+    pub out_of_date: bool,
+    /// A submodule has uncommitted or untracked changes.
+    pub dirty: bool,
+}
+
+impl SubmoduleDrift {
+    /// Whether any submodule drifted.
+    pub fn any(&self) -> bool {
+        self.out_of_date || self.dirty
+    }
+
+    /// Parse `git status --porcelain=v2` output, looking only at submodule
+    /// entries.
+    pub fn from_status_v2(status: &str) -> Self {
+        let mut drift = Self::default();
+        for line in status.lines() {
+            // Ordinary (`1`), renamed (`2`), and unmerged (`u`) entries carry
+            // the submodule field third: `N...` for files, `S<c><m><u>` for
+            // submodules.
+            let mut fields = line.split(' ');
+            let (Some("1" | "2" | "u"), Some(_), Some(sub)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+            let sub = sub.as_bytes();
+            if sub.len() != 4 || sub[0] != b'S' {
+                continue;
+            }
+            drift.out_of_date |= sub[1] == b'C';
+            drift.dirty |= sub[2] == b'M' || sub[3] == b'U';
+        }
+        drift
+    }
+}
+
+/// A submodule declared in `.gitmodules`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submodule {
+    pub name: String,
+    /// Path relative to the superproject root.
+    pub path: String,
+}
+
+/// Parse `git config --get-regexp '^submodule\..*\.path$'` output.
+fn parse_submodule_paths(output: &str) -> Vec<Submodule> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (key, path) = line.split_once(' ')?;
+            let name = key.strip_prefix("submodule.")?.strip_suffix(".path")?;
+            Some(Submodule {
+                name: name.to_string(),
+                path: path.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn git(dir: &Path) -> Cmd {
+    Cmd::new("git")
+        .current_dir(dir)
+        .context(path_to_logging_context(dir))
+}
+
+fn run(cmd: Cmd, what: &str) -> anyhow::Result<String> {
+    let output = cmd.run()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "{what} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The submodules `.gitmodules` declares in the checkout at `dir`; empty
+/// without a `.gitmodules`.
+pub fn declared(dir: &Path) -> anyhow::Result<Vec<Submodule>> {
+    if !dir.join(".gitmodules").is_file() {
+        return Ok(Vec::new());
+    }
+    // Exit status 1 means no key matched.
+    let output = git(dir)
+        .args([
+            "config",
+            "-f",
+            ".gitmodules",
+            "--get-regexp",
+            r"^submodule\..*\.path$",
+        ])
+        .run()?;
+    Ok(parse_submodule_paths(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Paths (relative to `dir`) of the initialized submodules under `dir`,
+/// nested ones included, parents before children.
+pub fn initialized(dir: &Path) -> anyhow::Result<Vec<String>> {
+    if !dir.join(".gitmodules").is_file() {
+        return Ok(Vec::new());
+    }
+    let status = run(
+        git(dir).args(["submodule", "status", "--recursive"]),
+        "git submodule status",
+    )?;
+    Ok(status
+        .lines()
+        .filter(|line| !line.starts_with('-'))
+        .filter_map(|line| line.get(1..)?.split(' ').nth(1).map(str::to_string))
+        .collect())
+}
+
+/// How the worktree's submodules differ from what its superproject records.
+pub fn drift(worktree: &WorkingTree<'_>) -> anyhow::Result<SubmoduleDrift> {
+    let submodules = declared(worktree.path())?;
+    if submodules.is_empty() {
+        return Ok(SubmoduleDrift::default());
+    }
+    let mut args = vec![
+        "--no-optional-locks",
+        "status",
+        "--porcelain=v2",
+        // With `=no`, git stops looking for untracked files inside
+        // submodules too; the pathspec keeps the superproject's out.
+        "--untracked-files=normal",
+        "--ignore-submodules=none",
+        "--",
+    ];
+    args.extend(submodules.iter().map(|submodule| submodule.path.as_str()));
+    Ok(SubmoduleDrift::from_status_v2(
+        &worktree.run_command(&args)?,
+    ))
+}
+
+/// Initialize (recursively) the submodules of the worktree at `dir` from
+/// their URLs: `git submodule update --init --recursive`.
+pub fn init(dir: &Path) -> anyhow::Result<()> {
+    run(
+        git(dir).args(["submodule", "update", "--init", "--recursive"]),
+        "git submodule update",
+    )
+    .map(drop)
+}
+
+/// What [`init_from_reference`] did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReferenceInit {
+    /// Submodules cloned from the reference, nested ones included.
+    pub initialized: usize,
+    /// Paths of submodules with no reference copy, left uninitialized.
+    pub missing: Vec<String>,
+}
+
+/// Initialize the submodules of the checkout at `dir` from the repositories
+/// under `<reference_git_dir>/modules/`, without touching the network, then
+/// their nested submodules likewise. Already-initialized submodules are
+/// left alone.
+pub fn init_from_reference(dir: &Path, reference_git_dir: &Path) -> anyhow::Result<ReferenceInit> {
+    let mut result = ReferenceInit::default();
+    init_from_reference_into(dir, reference_git_dir, "", &mut result)?;
+    Ok(result)
+}
+
+fn init_from_reference_into(
+    dir: &Path,
+    reference_git_dir: &Path,
+    prefix: &str,
+    result: &mut ReferenceInit,
+) -> anyhow::Result<()> {
+    for submodule in declared(dir)? {
+        let checkout = dir.join(&submodule.path);
+        let display_path = format!("{prefix}{}", submodule.path);
+        let source: PathBuf = reference_git_dir.join("modules").join(&submodule.name);
+        if !checkout.join(".git").exists() {
+            if !source.join("HEAD").is_file() {
+                result.missing.push(display_path);
+                continue;
+            }
+            clone_from_reference(dir, &submodule, &source)?;
+            result.initialized += 1;
+        }
+        init_from_reference_into(&checkout, &source, &format!("{display_path}/"), result)?;
+    }
+    Ok(())
+}
+
+fn clone_from_reference(dir: &Path, submodule: &Submodule, source: &Path) -> anyhow::Result<()> {
+    let source = source.to_string_lossy();
+    let url_key = format!("submodule.{}.url", submodule.name);
+    // Local clones of submodules are refused by default (CVE-2022-39253);
+    // the source is the user's own repository, so allow them here.
+    run(
+        git(dir).args([
+            "-c",
+            "protocol.file.allow=always",
+            "-c",
+            &format!("{url_key}={source}"),
+            "submodule",
+            "update",
+            "--init",
+            "--reference",
+            &source,
+            "--",
+            &submodule.path,
+        ]),
+        "git submodule update",
+    )?;
+    // The clone's `origin` is the local copy; point it at the real URL.
+    let url = run(git(dir).args(["config", "--get", &url_key]), "git config").or_else(|_| {
+        run(
+            git(dir).args(["config", "-f", ".gitmodules", "--get", &url_key]),
+            "git config",
+        )
+    })?;
+    run(
+        git(&dir.join(&submodule.path)).args(["remote", "set-url", "origin", url.trim()]),
+        "git remote set-url",
+    )
+    .map(drop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drift_from_status_v2() {
+        let oid = "477da45c1b82bc1909f073aaa9463be0b8f8b2bf";
+        let line =
+            |sub: &str, path: &str| format!("1 .M {sub} 160000 160000 160000 {oid} {oid} {path}\n");
+        assert_eq!(
+            SubmoduleDrift::from_status_v2(""),
+            SubmoduleDrift::default()
+        );
+        assert_eq!(
+            SubmoduleDrift::from_status_v2(&line("SC..", "lib")),
+            SubmoduleDrift {
+                out_of_date: true,
+                dirty: false
+            }
+        );
+        assert_eq!(
+            SubmoduleDrift::from_status_v2(&(line("S.M.", "a") + &line("S..U", "b"))),
+            SubmoduleDrift {
+                out_of_date: false,
+                dirty: true
+            }
+        );
+        // Regular files never count.
+        assert!(!SubmoduleDrift::from_status_v2(&line("N...", "file")).any());
+        assert!(!SubmoduleDrift::from_status_v2("? untracked\n").any());
+    }
+
+    #[test]
+    fn test_parse_submodule_paths() {
+        assert_eq!(
+            parse_submodule_paths("submodule.lib.path lib\nsubmodule.a.b.path vendor/a b\n"),
+            vec![
+                Submodule {
+                    name: "lib".to_string(),
+                    path: "lib".to_string()
+                },
+                Submodule {
+                    name: "a.b".to_string(),
+                    path: "vendor/a b".to_string()
+                },
+            ]
+        );
+    }
+}
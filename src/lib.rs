@@ -3,24 +3,34 @@
 //! Worktrunk is a CLI tool — see <https://worktrunk.dev> for documentation
 //! and the [README](https://github.com/max-sixty/worktrunk) for an overview.
 //!
-//! The library API is not stable. If you're building tooling that integrates
-//! with worktrunk, please [open an issue](https://github.com/max-sixty/worktrunk/issues)
+//! Tooling that drives worktrees — creating, removing, merging, listing —
+//! should use [`api`], the stable surface. The other modules are `wt`'s
+//! internals and change without notice; if [`api`] lacks something you need,
+//! please [open an issue](https://github.com/max-sixty/worktrunk/issues)
 //! to discuss your use case.
 
+pub mod activity;
+pub mod api;
 pub mod cache;
 pub mod command_log;
 pub mod config;
+pub mod container;
 pub mod copy;
 pub mod docs;
 pub mod git;
+pub mod lifecycle;
+pub mod notify;
 pub mod path;
+pub mod ports;
 pub mod priority;
 pub mod progress;
 pub mod remove_dir;
+pub mod sandbox;
 pub mod shell;
 pub mod shell_exec;
 #[cfg(unix)]
 pub mod signal_forwarder;
+pub mod store;
 pub mod styling;
 pub mod sync;
 pub mod trace;
@@ -0,0 +1,508 @@
+//! Creating and removing worktrees: the steps every creation and removal
+//! takes, whether `wt switch`/`wt remove` or [`crate::api`] drives it.
+//!
+//! - [`add_worktree`] runs `git worktree add` for a branch, with LFS files
+//!   checked out as pointers in an LFS repository ([`Setup::add_envs`]).
+//! - [`Setup::run`] then hydrates those pointers from the shared cache,
+//!   initializes submodules per `[switch] submodules`, and starts the
+//!   approved `[container]` — all before `pre-start` hooks, so they see the
+//!   finished worktree.
+//! - [`tear_down`] removes a worktree's container and releases its
+//!   `{{ ports.* }}`, after `pre-remove` hooks and before the directory goes.
+//!
+//! None of these steps fail the operation: the worktree is usable without
+//! them, so failures are reported as warnings with a hint to recover. Every
+//! message goes to the caller as a [`Notice`] — `wt` prints them, the library
+//! API returns the warnings.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use color_print::cformat;
+
+use crate::config::{ProjectContainerConfig, SubmoduleMode, UserConfig, expand_template};
+use crate::container::{Container, ContainerState, ImageSource, RunSpec};
+use crate::git::{GitError, Repository, lfs, submodule};
+use crate::ports::Ports;
+use crate::progress::format_bytes;
+use crate::shell_exec::ShellEscapeMode;
+use crate::styling::{
+    FormattedMessage, eprintln, hint_message, info_message, progress_message, success_message,
+    warning_message,
+};
+
+/// A message from a creation or removal step, styled like the rest of `wt`'s
+/// output.
+#[derive(Debug, Clone)]
+pub enum Notice {
+    /// A slow step is starting.
+    Progress(String),
+    Info(String),
+    Success(String),
+    /// A step failed or fell short; the operation went on.
+    Warning(String),
+    /// How to recover from the preceding warning.
+    Hint(String),
+}
+
+impl Notice {
+    /// The message with its symbol and color, as `wt` prints it.
+    pub fn styled(&self) -> FormattedMessage {
+        match self {
+            Self::Progress(message) => progress_message(message),
+            Self::Info(message) => info_message(message),
+            Self::Success(message) => success_message(message),
+            Self::Warning(message) => warning_message(message),
+            Self::Hint(message) => hint_message(message),
+        }
+    }
+
+    /// Print to stderr, as `wt` does.
+    pub fn print(self) {
+        eprintln!("{}", self.styled());
+    }
+}
+
+/// A worktree for [`add_worktree`] to create.
+#[derive(Debug, Clone, Copy)]
+pub struct NewWorktree<'a> {
+    pub branch: &'a str,
+    /// Create `branch` rather than check out an existing one.
+    pub create_branch: bool,
+    /// Commit-ish a created branch starts from; `HEAD` when unset.
+    pub base: Option<&'a str>,
+    pub path: &'a Path,
+}
+
+/// What a new worktree gets besides its checkout, decided before
+/// `git worktree add` runs.
+#[derive(Debug, Clone, Default)]
+pub struct Setup {
+    /// The repository uses LFS: check LFS files out as pointers, then
+    /// hydrate them.
+    pub lfs: bool,
+    /// `[switch] submodules`.
+    pub submodules: Option<SubmoduleMode>,
+    /// The approved `[container]`.
+    pub container: Option<ProjectContainerConfig>,
+}
+
+impl Setup {
+    pub fn new(
+        repo: &Repository,
+        config: &UserConfig,
+        container: Option<ProjectContainerConfig>,
+    ) -> Self {
+        Self {
+            lfs: lfs::uses_lfs(repo),
+            submodules: config
+                .switch(repo.project_identifier().ok().as_deref())
+                .submodules,
+            container,
+        }
+    }
+
+    /// Environment for `git worktree add`.
+    pub fn add_envs(&self) -> &'static [(&'static str, &'static str)] {
+        if self.lfs {
+            &[(lfs::SKIP_SMUDGE_ENV, "1")]
+        } else {
+            &[]
+        }
+    }
+
+    /// Set up the worktree `git worktree add` just created at `worktree`.
+    pub fn run(
+        &self,
+        repo: &Repository,
+        worktree: &Path,
+        branch: Option<&str>,
+        report: &mut dyn FnMut(Notice),
+    ) {
+        if self.lfs {
+            hydrate_lfs(repo, worktree, report);
+        }
+        if let Some(mode) = self.submodules {
+            init_submodules(repo, worktree, mode, report);
+        }
+        if let Some(container) = &self.container {
+            start_container(repo, container, worktree, branch, report);
+        }
+    }
+}
+
+/// Run `git worktree add` for `new`, returning the remote-tracking branch
+/// (`origin/feat`) an existing branch was checked out from, when it only
+/// existed on a remote. `progress` is shown if git is slow.
+///
+/// A created branch whose base is a remote-tracking branch gets no upstream:
+/// `git worktree add -b feature origin/main` makes `feature` track
+/// `origin/main`, so a bare `git push` would push to main (#713).
+pub fn add_worktree(
+    repo: &Repository,
+    new: &NewWorktree<'_>,
+    setup: &Setup,
+    progress: Option<String>,
+) -> anyhow::Result<Option<String>> {
+    let branch = new.branch;
+    // Check if local branch exists BEFORE git worktree add (for DWIM detection)
+    let branch_handle = repo.branch(branch);
+    let local_branch_existed =
+        !new.create_branch && branch_handle.exists_locally().unwrap_or(false);
+
+    // Options come first, then `--` separates them from the path and any
+    // positional ref, so branch/base names that begin with `-` cannot be
+    // misinterpreted by git as flags. `-b <branch>` keeps the branch as the
+    // *value* of `-b`, which is safe even when the branch name starts with `-`.
+    let path_str = new.path.to_string_lossy();
+    let mut args: Vec<&str> = vec!["worktree", "add"];
+
+    // For DWIM fallback: when the branch doesn't exist locally, git worktree
+    // add relies on DWIM to auto-create it from a remote tracking branch. DWIM
+    // fails in repos without configured fetch refspecs (bare repos,
+    // single-branch clones). Explicitly create from the tracking ref in that
+    // case.
+    let tracking_ref;
+    let trailing_ref: Option<&str> = if new.create_branch {
+        args.extend(["-b", branch]);
+        new.base
+    } else if !local_branch_existed {
+        // Explicit -b when there's exactly one remote tracking ref.
+        let remotes = branch_handle.remotes().unwrap_or_default();
+        if remotes.len() == 1 {
+            tracking_ref = format!("{}/{}", remotes[0], branch);
+            args.extend(["-b", branch]);
+            Some(tracking_ref.as_str())
+        } else {
+            // Multiple or zero remotes: let git's DWIM handle (or error)
+            Some(branch)
+        }
+    } else {
+        Some(branch)
+    };
+    args.extend(["--", path_str.as_ref()]);
+    args.extend(trailing_ref);
+
+    if let Err(e) = repo.run_command_delayed_stream(
+        &args,
+        setup.add_envs(),
+        Repository::SLOW_OPERATION_DELAY_MS,
+        progress,
+    ) {
+        // A new branch whose name is a path prefix of (or sits under) an
+        // existing branch can't be created: git stores refs as file paths, so
+        // `release` and `release/2026.4` can't coexist. Surface that as a
+        // clear, actionable error instead of git's raw "cannot lock ref" text.
+        if new.create_branch
+            && let Some(conflicting) = branch_namespace_conflict(repo, branch)
+        {
+            return Err(GitError::BranchNamespaceConflict {
+                branch: branch.to_string(),
+                conflicting,
+            }
+            .into());
+        }
+        return Err(
+            worktree_creation_error(&e, branch.to_string(), new.base.map(str::to_string)).into(),
+        );
+    }
+
+    if new.create_branch
+        && let Some(base) = new.base
+        && repo.is_remote_tracking_branch(base)
+    {
+        branch_handle.unset_upstream()?;
+    }
+
+    if !new.create_branch && !local_branch_existed {
+        branch_handle.upstream()
+    } else {
+        Ok(None)
+    }
+}
+
+/// Detect a git ref directory/file (D/F) conflict for a branch about to be
+/// created, returning an existing branch it collides with.
+///
+/// Git stores refs as file paths under `refs/heads/`, so a branch name can't
+/// be both a file and a directory: creating `release` fails when
+/// `release/2026.4` exists, and creating `release/foo` fails when `release`
+/// exists. This inspects the cached local-branch inventory (no extra
+/// subprocess) for either shape and returns the first colliding branch.
+fn branch_namespace_conflict(repo: &Repository, branch: &str) -> Option<String> {
+    let prefix = format!("{branch}/");
+    repo.local_branches()
+        .ok()?
+        .iter()
+        .map(|b| b.name.as_str())
+        .find(|name| {
+            // `branch` is a directory prefix of an existing branch, or an
+            // existing branch is a directory prefix of `branch`.
+            name.starts_with(&prefix) || branch.starts_with(&format!("{name}/"))
+        })
+        .map(String::from)
+}
+
+/// Build a `GitError::WorktreeCreationFailed` from a failed `git worktree add`,
+/// extracting the underlying command output for the error message.
+pub fn worktree_creation_error(
+    err: &anyhow::Error,
+    branch: String,
+    base_branch: Option<String>,
+) -> GitError {
+    let (output, command) = Repository::extract_failed_command(err);
+    GitError::WorktreeCreationFailed {
+        branch,
+        base_branch,
+        error: output,
+        command,
+    }
+}
+
+/// Tear down what a worktree has besides its checkout: its container, if
+/// `container` gives it one, and its `{{ ports.* }}`, which go back to the
+/// pool. Best-effort — the removal goes on regardless.
+pub fn tear_down(
+    repo: &Repository,
+    worktree: &Path,
+    branch: Option<&str>,
+    container: Option<&ProjectContainerConfig>,
+    report: &mut dyn FnMut(Notice),
+) {
+    if let Some(config) = container {
+        remove_container(config, worktree, report);
+    }
+    if let Some(branch) = branch
+        && let Err(err) = Ports::for_repo(repo).release(branch)
+    {
+        log::debug!("Failed to release ports of {branch}: {err:#}");
+    }
+}
+
+/// Hydrate the LFS pointers of the worktree at `worktree` from the shared
+/// cache. Failures warn: the worktree is usable with pointers, and
+/// `git lfs pull` recovers.
+pub fn hydrate_lfs(repo: &Repository, worktree: &Path, report: &mut dyn FnMut(Notice)) {
+    let wt = repo.worktree_at(worktree);
+    if !lfs::filter_configured(repo) {
+        let pointers = lfs::state(&wt).map_or(0, |state| state.pointers);
+        if pointers > 0 {
+            report(Notice::Warning(format!(
+                "{pointers} LFS {} left as pointers: the LFS filter isn't configured",
+                files_word(pointers)
+            )));
+            report(Notice::Hint(cformat!(
+                "Install git-lfs and run <underline>git lfs install</>, then <underline>git lfs pull</> in the worktree"
+            )));
+        }
+        return;
+    }
+    let stats = match lfs::hydrate(&wt) {
+        Ok(stats) => stats,
+        Err(err) => {
+            report(Notice::Warning(format!(
+                "Failed to hydrate LFS files: {err:#}"
+            )));
+            return;
+        }
+    };
+    if stats.hydrated > 0 {
+        report(Notice::Info(format!(
+            "Hydrated {} LFS {} from the shared cache · {}",
+            stats.hydrated,
+            files_word(stats.hydrated),
+            format_bytes(stats.bytes)
+        )));
+    }
+    if stats.missing > 0 {
+        report(Notice::Warning(format!(
+            "{} LFS {} not in the local cache, left as pointers",
+            stats.missing,
+            files_word(stats.missing)
+        )));
+        report(Notice::Hint(cformat!(
+            "To download them, run <underline>git lfs pull</> in the worktree"
+        )));
+    }
+}
+
+fn files_word(count: usize) -> &'static str {
+    if count == 1 { "file" } else { "files" }
+}
+
+/// Initialize the submodules of the new worktree at `worktree`. Failures
+/// warn: the worktree is usable without its submodules, and
+/// `git submodule update --init` recovers.
+fn init_submodules(
+    repo: &Repository,
+    worktree: &Path,
+    mode: SubmoduleMode,
+    report: &mut dyn FnMut(Notice),
+) {
+    if submodule::declared(worktree).is_ok_and(|declared| declared.is_empty()) {
+        return;
+    }
+    let result = match mode {
+        SubmoduleMode::Init => {
+            report(Notice::Progress("Initializing submodules...".to_string()));
+            submodule::init(worktree).map(|()| None)
+        }
+        SubmoduleMode::Reference => reference_git_dir(repo)
+            .and_then(|reference| submodule::init_from_reference(worktree, &reference))
+            .map(Some),
+    };
+    match result {
+        Ok(None) => {}
+        Ok(Some(init)) => {
+            if init.initialized > 0 {
+                report(Notice::Success(format!(
+                    "Initialized {} {} from the main worktree",
+                    init.initialized,
+                    if init.initialized == 1 {
+                        "submodule"
+                    } else {
+                        "submodules"
+                    }
+                )));
+            }
+            if !init.missing.is_empty() {
+                report(Notice::Warning(cformat!(
+                    "Not initialized in the main worktree, skipped: <bold>{}</>",
+                    init.missing.join(", ")
+                )));
+                report(Notice::Hint(cformat!(
+                    "To clone them, run <underline>git submodule update --init --recursive</> in the worktree"
+                )));
+            }
+        }
+        Err(err) => {
+            report(Notice::Warning(format!(
+                "Failed to initialize submodules: {err:#}"
+            )));
+        }
+    }
+}
+
+/// The git directory whose `modules/` holds the primary worktree's
+/// submodule repositories.
+fn reference_git_dir(repo: &Repository) -> anyhow::Result<PathBuf> {
+    let primary = repo
+        .primary_worktree()?
+        .ok_or_else(|| anyhow::anyhow!("no main worktree to reference"))?;
+    repo.worktree_at(primary).git_dir()
+}
+
+/// Make sure the worktree at `worktree` has its container running — creating
+/// it, and building its image, the first time — warning on failure.
+pub fn start_container(
+    repo: &Repository,
+    config: &ProjectContainerConfig,
+    worktree: &Path,
+    branch: Option<&str>,
+    report: &mut dyn FnMut(Notice),
+) {
+    if let Err(err) = try_start_container(repo, config, worktree, branch, report) {
+        report(Notice::Warning(format!("Container not started: {err:#}")));
+    }
+}
+
+fn try_start_container(
+    repo: &Repository,
+    config: &ProjectContainerConfig,
+    worktree: &Path,
+    branch: Option<&str>,
+    report: &mut dyn FnMut(Notice),
+) -> anyhow::Result<()> {
+    let container = Container::for_worktree(config, worktree);
+    let name = &container.name;
+    match container.state()? {
+        Some(ContainerState::Running) => return Ok(()),
+        Some(ContainerState::Stopped) => {
+            report(Notice::Progress(cformat!(
+                "Starting container <bold>{name}</>..."
+            )));
+            return container.start();
+        }
+        None => {}
+    }
+
+    let repo_name = repo
+        .repo_path()?
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let image = ImageSource::resolve(config, worktree, &repo_name)?;
+    if let ImageSource::Build {
+        tag,
+        context,
+        dockerfile,
+    } = &image
+        && !crate::container::image_exists(&container.runtime, tag)
+    {
+        report(Notice::Progress(cformat!(
+            "Building image <bold>{tag}</>..."
+        )));
+        crate::container::build_image(&container.runtime, tag, context, dockerfile)?;
+    }
+
+    let worktree_str = worktree.to_string_lossy();
+    let worktree_name = worktree
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let vars = HashMap::from([
+        ("branch", branch.unwrap_or("HEAD")),
+        ("repo", repo_name.as_str()),
+        ("worktree_name", worktree_name.as_str()),
+        ("worktree_path", worktree_str.as_ref()),
+    ]);
+    let ports = config
+        .ports
+        .iter()
+        .map(|port| {
+            expand_template(
+                port,
+                &vars,
+                ShellEscapeMode::Literal,
+                repo,
+                "container.ports",
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    report(Notice::Progress(cformat!(
+        "Creating container <bold>{name}</>..."
+    )));
+    container.create(&RunSpec {
+        image: image.image().to_string(),
+        worktree: worktree.to_path_buf(),
+        git_dir: Some(repo.git_common_dir().to_path_buf()),
+        ports,
+        args: config.args.clone(),
+        command: config
+            .command
+            .clone()
+            .unwrap_or_else(|| vec!["sleep".to_string(), "infinity".to_string()]),
+    })
+}
+
+/// Remove the worktree's container, if it has one. A failure warns.
+fn remove_container(
+    config: &ProjectContainerConfig,
+    worktree: &Path,
+    report: &mut dyn FnMut(Notice),
+) {
+    let container = Container::for_worktree(config, worktree);
+    if !matches!(container.state(), Ok(Some(_))) {
+        return;
+    }
+    let name = &container.name;
+    report(Notice::Progress(cformat!(
+        "Removing container <bold>{name}</>..."
+    )));
+    if let Err(err) = container.remove() {
+        report(Notice::Warning(cformat!(
+            "Failed to remove container <bold>{name}</>: {err:#}"
+        )));
+    }
+}
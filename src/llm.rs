@@ -100,7 +100,7 @@ pub(crate) fn render_llm_invocation(command: &str) -> anyhow::Result<String> {
 /// ([`generate_summary_core`](crate::summary::generate_summary_core), up to 8
 /// under a semaphore), where
 /// per-call spinners would interleave.
-fn watch_llm_command(command: &str) -> worktrunk::progress::Watchdog {
+pub(crate) fn watch_llm_command(command: &str) -> worktrunk::progress::Watchdog {
     let invocation = render_llm_invocation(command).ok();
     worktrunk::progress::Watchdog::start("the commit generation command", invocation.as_deref())
 }
@@ -1313,6 +1313,7 @@ mod tests {
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let context = commit_context("my diff", "feature", None, "repo");
@@ -1329,6 +1330,7 @@ mod tests {
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let context = commit_context("diff", "main", None, "repo");
@@ -1344,6 +1346,7 @@ mod tests {
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let context = commit_context("diff", "main", None, "repo");
@@ -1362,6 +1365,7 @@ mod tests {
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let commits = vec!["commit1".to_string(), "commit2".to_string()];
@@ -1643,6 +1647,7 @@ mod tests {
                     .to_string(),
             ),
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let commit_details = vec![
@@ -1675,6 +1680,7 @@ mod tests {
                     .to_string(),
             ),
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let commit_details = vec![
@@ -1714,6 +1720,7 @@ mod tests {
             template_file: None,
             squash_template: Some("{% for x in commits %}{{ x }".to_string()),
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let commit_details = vec![];
@@ -1730,6 +1737,7 @@ mod tests {
             template_file: None,
             squash_template: Some("  \n  ".to_string()),
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let commit_details = vec![];
@@ -1750,6 +1758,7 @@ mod tests {
                     .to_string(),
             ),
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let commit_details = vec![
@@ -1804,6 +1813,7 @@ Diff follows:
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
 
@@ -1860,6 +1870,7 @@ Single commit: {{ commits[0] }}
                     .to_string(),
             ),
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
 
@@ -1917,6 +1928,7 @@ Single commit: {{ commits[0] }}
             template_file: Some(template_path.to_string_lossy().to_string()),
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let context = commit_context("my diff", "feature", None, "myrepo");
@@ -1939,6 +1951,7 @@ Single commit: {{ commits[0] }}
             template_file: Some("/nonexistent/path/template.txt".to_string()),
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let context = commit_context("diff", "main", None, "repo");
@@ -1965,6 +1978,7 @@ Single commit: {{ commits[0] }}
             template_file: None,
             squash_template: None,
             squash_template_file: Some(template_path.to_string_lossy().to_string()),
+            review_template: None,
             template_append: None,
         };
         let commit_details = vec![
@@ -1997,6 +2011,7 @@ Single commit: {{ commits[0] }}
             template_file: Some("~/nonexistent_template_for_test.txt".to_string()),
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let context = commit_context("diff", "main", None, "repo");
@@ -2021,6 +2036,7 @@ Single commit: {{ commits[0] }}
             template_file: None,
             squash_template: None,
             squash_template_file: None,
+            review_template: None,
             template_append: None,
         };
         let context = commit_context("diff", "feature", None, "repo");
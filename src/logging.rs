@@ -298,6 +298,7 @@ impl WtTraceFields {
 /// ✗ git rev-list [.]  100ms  fatal: …      cmd_errored
 /// · Showed skeleton                        instant (milestone)
 /// ◷ build_hook_context  8.2ms              span (in-process)
+/// » wt list --full                         session (the invocation)
 /// ```
 ///
 /// The leading glyph names the line type at a glance; durations render via
@@ -328,6 +329,7 @@ fn format_wt_trace(f: &WtTraceFields) -> String {
         }
         Some("instant") => format!("· {}", f.event.as_deref().unwrap_or("")),
         Some("span") => format!("◷ {}  {}", f.span.as_deref().unwrap_or(""), dur(f.dur_us)),
+        Some("session") => format!("» {}", f.cmd.as_deref().unwrap_or("")),
         other => format!("· {}", other.unwrap_or("<unknown>")),
     }
 }
@@ -962,6 +964,14 @@ mod tests {
         };
         assert_eq!(format_wt_trace(&f), "◷ build_hook_context  8.2ms");
 
+        // session — `»` with the command line
+        let f = WtTraceFields {
+            kind: Some("session".into()),
+            cmd: Some("wt list --full".into()),
+            ..Default::default()
+        };
+        assert_eq!(format_wt_trace(&f), "» wt list --full");
+
         // Defensive fallback: a future/unknown kind renders a visible line
         // rather than silently vanishing.
         let f = WtTraceFields {
@@ -3,6 +3,7 @@ use clap::error::ErrorKind as ClapErrorKind;
 use color_print::cformat;
 use std::process;
 use worktrunk::config::{set_config_overrides, set_config_path};
+use worktrunk::copy::{SyncCompare, SyncOptions};
 use worktrunk::git::{
     ErrorExt, Repository, WorktrunkError, current_or_recover, cwd_removed_hint, set_base_path,
 };
@@ -26,6 +27,7 @@ mod logging;
 mod md_help;
 mod output;
 mod pager;
+mod review;
 mod summary;
 
 // Re-export invocation utilities at crate level for use by other modules
@@ -33,36 +35,40 @@ pub(crate) use invocation::{
     binary_name, invocation_path, is_git_subcommand, was_invoked_with_explicit_path,
 };
 
-pub(crate) use crate::cli::{OutputFormat, StatuslineFormat};
+pub(crate) use crate::cli::{ListFormat, StatuslineFormat};
 
 use crate::output::print_json;
 
 use commands::commit::HookGate;
+use commands::config::{ActivityListOptions, HistoryOptions};
 use commands::handle_picker;
 use commands::worktree::{PushKind, PushOutcome, PushResult, handle_no_ff_merge, handle_push};
 use commands::{
     HookCliArgs, MergeFlagOverrides, MergeOptions, RebaseResult, SquashResult, add_approvals,
-    clear_approvals, flag_pair, handle_alias_dry_run, handle_alias_show, handle_cache_clear,
-    handle_cache_get, handle_claude_install, handle_claude_install_statusline,
-    handle_claude_uninstall, handle_codex_install, handle_codex_uninstall, handle_completions,
-    handle_config_create, handle_config_show, handle_config_update, handle_configure_shell,
+    clear_approvals, flag_pair, handle_activity_clear, handle_activity_end,
+    handle_activity_history, handle_activity_list, handle_activity_set, handle_alias_dry_run,
+    handle_alias_show, handle_cache_clear, handle_cache_get, handle_claude_install,
+    handle_claude_install_statusline, handle_claude_uninstall, handle_codex_install,
+    handle_codex_uninstall, handle_completions, handle_config_create, handle_config_schema,
+    handle_config_show, handle_config_update, handle_config_validate, handle_configure_shell,
     handle_custom_command, handle_hints_clear, handle_hints_get, handle_hook_show, handle_init,
-    handle_list, handle_logs_list, handle_logs_profile, handle_merge, handle_opencode_install,
-    handle_opencode_uninstall, handle_promote, handle_rebase, handle_remove_command,
-    handle_show_theme, handle_squash, handle_state_clear, handle_state_clear_all, handle_state_get,
-    handle_state_set, handle_state_show, handle_switch_command, handle_unconfigure_shell,
-    handle_vars_clear, handle_vars_get, handle_vars_list, handle_vars_set, list_approvals,
-    run_hook, step_commit, step_copy_ignored, step_diff, step_eval, step_for_each, step_prune,
-    step_relocate, step_tether,
+    handle_list, handle_logs_export, handle_logs_history, handle_logs_list, handle_logs_profile,
+    handle_merge, handle_opencode_install, handle_opencode_uninstall, handle_promote,
+    handle_rebase, handle_remove_command, handle_show_theme, handle_squash, handle_state_clear,
+    handle_state_clear_all, handle_state_get, handle_state_set, handle_state_show, handle_store_gc,
+    handle_store_get, handle_switch_command, handle_unconfigure_shell, handle_vars_clear,
+    handle_vars_get, handle_vars_list, handle_vars_set, list_approvals, run_hook, step_commit,
+    step_copy_ignored, step_diff, step_eval, step_for_each, step_prune, step_relocate,
+    step_resolve, step_review, step_tether,
 };
 
 use cli::{
-    ApprovalsCommand, CacheAction, CiStatusAction, Cli, Commands, ConfigAliasCommand,
-    ConfigCommand, ConfigPluginsClaudeCommand, ConfigPluginsCodexCommand, ConfigPluginsCommand,
-    ConfigPluginsOpencodeCommand, ConfigShellCommand, DefaultBranchAction, GlobalFormatFlag,
-    HintsAction, HookCommand, HookOptions, ListArgs, ListSubcommand, LogsAction, MarkerAction,
-    MergeArgs, PreviousBranchAction, StateCommand, StateWrite, StepCommand, SwitchFormat,
-    VarsAction,
+    ActivityAction, ApprovalsCommand, CacheAction, CiStatusAction, Cli, Commands,
+    ConfigAliasCommand, ConfigCommand, ConfigPluginsClaudeCommand, ConfigPluginsCodexCommand,
+    ConfigPluginsCommand, ConfigPluginsOpencodeCommand, ConfigShellCommand, DefaultBranchAction,
+    GlobalFormatFlag, HintsAction, HookCommand, HookOptions, ListArgs, ListSubcommand, LogsAction,
+    MarkerAction, MergeArgs, PreviousBranchAction, StateCommand, StateWrite, StepCommand,
+    StoreAction, SwitchFormat, VarsAction,
 };
 
 /// Render a clap error to stderr, appending a wt-specific nested-subcommand
@@ -165,7 +171,9 @@ fn handle_hook_command(action: HookCommand, yes: bool) -> anyhow::Result<()> {
             );
             match action {
                 ApprovalsCommand::List { format } => list_approvals(format),
-                ApprovalsCommand::Add { all } => add_approvals(all, yes),
+                ApprovalsCommand::Add { all, expires_in } => {
+                    add_approvals(all, expires_in.as_deref(), yes)
+                }
                 ApprovalsCommand::Clear { global, stale } => clear_approvals(global, stale),
             }
         }
@@ -373,6 +381,10 @@ fn handle_step_command(
             to,
             dry_run,
             force,
+            sync,
+            checksum,
+            delete,
+            store,
             require_include,
             format,
         } => step_copy_ignored(
@@ -380,6 +392,16 @@ fn handle_step_command(
             to.as_deref(),
             dry_run,
             force,
+            sync.then_some(SyncOptions {
+                compare: if checksum {
+                    SyncCompare::Content
+                } else {
+                    SyncCompare::SizeAndMtime
+                },
+                delete,
+                dry_run,
+            }),
+            store,
             require_include,
             format,
         ),
@@ -430,6 +452,13 @@ fn handle_step_command(
             clobber,
             format,
         } => step_relocate(branches, dry_run, commit, clobber, format),
+        StepCommand::Resolve { format } => step_resolve(yes, format),
+        StepCommand::Review {
+            branch,
+            check,
+            refresh,
+            format,
+        } => step_review(branch.as_deref(), check, refresh, format),
         StepCommand::Tether { command } => step_tether(&command, working_dir.as_deref()),
         StepCommand::External(args) => commands::step_alias(args, yes),
     }
@@ -480,6 +509,18 @@ fn handle_state_command(action: StateCommand, yes: bool) -> anyhow::Result<()> {
                 Some(CacheAction::Clear) => handle_cache_clear(),
             }
         }
+        StateCommand::Store {
+            action,
+            format: GlobalFormatFlag { format },
+        } => {
+            if let Some(verb) = action.as_ref().and_then(StateWrite::write_verb) {
+                guard_format_on_write(verb, format)?;
+            }
+            match action {
+                Some(StoreAction::Get) | None => handle_store_get(format),
+                Some(StoreAction::Gc) => handle_store_gc(),
+            }
+        }
         StateCommand::DefaultBranch { action } => match action {
             Some(DefaultBranchAction::Get) | None => {
                 handle_state_get("default-branch", None, SwitchFormat::Text)
@@ -539,6 +580,42 @@ fn handle_state_command(action: StateCommand, yes: bool) -> anyhow::Result<()> {
                 }
             }
         }
+        StateCommand::Activity {
+            action,
+            format: GlobalFormatFlag { format },
+        } => {
+            if let Some(verb) = action.as_ref().and_then(StateWrite::write_verb) {
+                guard_format_on_write(verb, format)?;
+            }
+            match action {
+                Some(ActivityAction::List {
+                    state,
+                    older_than,
+                    branch,
+                }) => handle_activity_list(
+                    ActivityListOptions {
+                        states: state,
+                        older_than,
+                        branch,
+                    },
+                    format,
+                ),
+                None => handle_activity_list(
+                    ActivityListOptions {
+                        states: Vec::new(),
+                        older_than: None,
+                        branch: None,
+                    },
+                    format,
+                ),
+                Some(ActivityAction::History { branch, limit }) => {
+                    handle_activity_history(branch, limit, format)
+                }
+                Some(ActivityAction::Set { state, session }) => handle_activity_set(state, session),
+                Some(ActivityAction::End { session }) => handle_activity_end(session),
+                Some(ActivityAction::Clear) => handle_activity_clear(),
+            }
+        }
         StateCommand::Logs {
             action,
             format: GlobalFormatFlag { format },
@@ -549,6 +626,35 @@ fn handle_state_command(action: StateCommand, yes: bool) -> anyhow::Result<()> {
             match action {
                 Some(LogsAction::Get) | None => handle_logs_list(format),
                 Some(LogsAction::Profile { file }) => handle_logs_profile(file, format),
+                Some(LogsAction::History {
+                    branch,
+                    hook,
+                    label,
+                    failed,
+                    since,
+                    until,
+                    limit,
+                    stats,
+                    follow,
+                }) => handle_logs_history(
+                    HistoryOptions {
+                        branch,
+                        hook,
+                        label,
+                        failed,
+                        since,
+                        until,
+                        limit,
+                        stats,
+                        follow,
+                    },
+                    format,
+                ),
+                Some(LogsAction::Export {
+                    file,
+                    output,
+                    endpoint,
+                }) => handle_logs_export(file, output, endpoint),
                 Some(LogsAction::Clear) => handle_state_clear("logs", None, false),
             }
         }
@@ -639,9 +745,13 @@ fn handle_config_command(action: ConfigCommand, yes: bool) -> anyhow::Result<()>
         ConfigCommand::Create { project } => handle_config_create(project),
         ConfigCommand::Show { full, format } => handle_config_show(full, format),
         ConfigCommand::Update { print } => handle_config_update(yes, print),
+        ConfigCommand::Validate { format } => handle_config_validate(format),
+        ConfigCommand::Schema { project, .. } => handle_config_schema(project),
         ConfigCommand::Approvals { action } => match action {
             ApprovalsCommand::List { format } => list_approvals(format),
-            ApprovalsCommand::Add { all } => add_approvals(all, yes),
+            ApprovalsCommand::Add { all, expires_in } => {
+                add_approvals(all, expires_in.as_deref(), yes)
+            }
             ApprovalsCommand::Clear { global, stale } => clear_approvals(global, stale),
         },
         ConfigCommand::Alias { action } => match action {
@@ -755,7 +865,9 @@ fn init_rayon_thread_pool() {
         .build_global();
 }
 
-fn parse_cli() -> Cli {
+/// Parse argv into [`Cli`], alongside the name of the top-level command clap
+/// matched (for the trace `session` record).
+fn parse_cli() -> (Cli, Option<String>) {
     // Apply -C / --config before help handling so `wt -C other --help`
     // and `wt --config custom.toml step --help` resolve aliases against the
     // requested repo and user config (not the process cwd / default config).
@@ -787,7 +899,8 @@ fn parse_cli() -> Cli {
         .unwrap_or_else(|e| {
             enhance_and_exit_error(e);
         });
-    Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit())
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    (cli, matches.subcommand_name().map(str::to_owned))
 }
 
 fn apply_global_options(
@@ -871,11 +984,35 @@ fn parse_early_globals() -> EarlyGlobals {
     }
 }
 
-fn init_command_log(command_line: &str) {
+fn init_command_log(command_line: &str, subcommand: Option<&str>) {
     // Initialize command log for always-on logging of hooks and LLM commands.
     // Directory and file are created lazily on first log_command() call.
-    if let Ok(repo) = worktrunk::git::Repository::current() {
-        worktrunk::command_log::init(&repo.wt_logs_dir(), command_line);
+    let repo = worktrunk::git::Repository::current().ok();
+    if let Some(repo) = &repo {
+        worktrunk::command_log::init(
+            &repo.wt_logs_dir(),
+            command_line,
+            repo.user_config().logs.keep,
+        );
+    }
+
+    // Anchor the `-vv` trace to wall time and name the run, for exporters.
+    // Gated because the branch lookup can spawn git, which a run without a
+    // trace subscriber shouldn't pay for.
+    if tracing::enabled!(target: worktrunk::trace::WT_TRACE_TARGET, tracing::Level::DEBUG) {
+        let repo_path = repo
+            .as_ref()
+            .and_then(|repo| repo.repo_path().ok())
+            .map(|path| path.to_string_lossy().into_owned());
+        let branch = repo
+            .as_ref()
+            .and_then(|repo| repo.current_worktree().branch().ok().flatten());
+        worktrunk::trace::session(
+            command_line,
+            subcommand,
+            repo_path.as_deref(),
+            branch.as_deref(),
+        );
     }
 }
 
@@ -898,9 +1035,10 @@ fn handle_merge_command(args: MergeArgs, yes: bool) -> anyhow::Result<()> {
 /// - **TUI / stderr-sensitive output** (`select`, `switch` picker mode,
 ///   `list statusline`) — warnings on stderr would land above the picker
 ///   or shell prompt and visually break it.
-/// - **`config update`** — the handler renders the deprecations and a diff
-///   itself, so the prewarm-time warning + `wt config update` hint is
-///   redundant noise above its own UI.
+/// - **`config update`** / **`config validate`** — the handler renders the
+///   deprecations itself (as a diff, or as located findings), so the
+///   prewarm-time warning + `wt config update` hint is redundant noise above
+///   its own UI.
 ///
 /// Read from `Cli::command` before `Repository::prewarm` so the suppress
 /// latch beats `prewarm_user_config`'s warning-emission path. The handler
@@ -914,7 +1052,7 @@ fn command_suppresses_warnings(command: Option<&Commands>) -> bool {
             matches!(args.subcommand, Some(ListSubcommand::Statusline { .. }))
         }
         Some(Commands::Config {
-            action: ConfigCommand::Update { .. },
+            action: ConfigCommand::Update { .. } | ConfigCommand::Validate { .. },
         }) => true,
         _ => false,
     }
@@ -931,9 +1069,20 @@ fn dispatch_command(
         Commands::Hook { action } => handle_hook_command(action, yes),
         Commands::Select { branches, remotes } => handle_select_command(branches, remotes),
         Commands::List(args) => handle_list_command(args),
+        Commands::Switch(args) if args.on.is_some() => {
+            commands::remote_host::handle_switch(args, yes)
+        }
         Commands::Switch(args) => handle_switch_command(args, yes),
+        Commands::Remove(args) if args.on.is_some() => {
+            commands::remote_host::handle_remove(args, yes)
+        }
         Commands::Remove(args) => handle_remove_command(args, yes),
+        Commands::Merge(args) if args.on.is_some() => {
+            commands::remote_host::handle_merge(args, yes)
+        }
         Commands::Merge(args) => handle_merge_command(args, yes),
+        Commands::Serve(args) => commands::serve::handle_serve(args.socket),
+        Commands::Mcp => commands::mcp::handle_mcp(),
         // `working_dir` is the top-level `-C <path>` flag, applied as the
         // child's current directory so global `-C` works for custom
         // subcommands the same way it does for built-ins.
@@ -1128,7 +1277,7 @@ fn main() {
     // Tell crossterm to always emit ANSI sequences
     crossterm::style::force_color_output(true);
 
-    let cli = parse_cli();
+    let (cli, subcommand) = parse_cli();
 
     let Cli {
         directory,
@@ -1186,7 +1335,7 @@ fn main() {
         .join(" ");
     {
         let _span = worktrunk::trace::Span::new("init_command_log");
-        init_command_log(&command_line);
+        init_command_log(&command_line, subcommand.as_deref());
     }
 
     let Some(command) = command else {
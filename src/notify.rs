@@ -0,0 +1,343 @@
+//! Notifications for work that finishes out of sight.
+//!
+//! Background hook pipelines, CI runs and agents change state while nobody is
+//! looking at `wt list`. Three events notify when `[notify]` in user config
+//! has a table for them:
+//!
+//! - `pipeline-failed` — a detached `wt hook run-pipeline` step failed
+//! - `ci` — a branch's CI status changed between two fetches
+//! - `marker` — a branch marker was set (agent plugins set `💬` while waiting)
+//!
+//! Each event lists its [`NotifySink`]s. Delivery is best-effort: a missing
+//! `notify-send` or `curl`, a closed terminal or an unreachable webhook is
+//! logged at debug level and never fails the command that raised the event.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::config::{NotifyConfig, NotifyEventConfig, NotifySink};
+use crate::shell_exec::{Cmd, ShellConfig};
+
+/// Upper bound on a `notify-send` or `[notify] command` run.
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Upper bound on a webhook `curl` run.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, strum::IntoStaticStr)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum NotifyEvent {
+    PipelineFailed,
+    Ci,
+    Marker,
+}
+
+/// One notification, as the `command` and `webhook` sinks receive it.
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub event: NotifyEvent,
+    pub title: String,
+    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worktree: Option<PathBuf>,
+    /// The value the event's `on` list matches: the new CI status or marker.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl Notification {
+    /// A background pipeline for `hook` failed with `error`.
+    pub fn pipeline_failed(hook: &str, branch: &str, worktree: PathBuf, error: &str) -> Self {
+        Self {
+            event: NotifyEvent::PipelineFailed,
+            title: format!("{hook} failed on {branch}"),
+            body: error.to_string(),
+            branch: Some(branch.to_string()),
+            worktree: Some(worktree),
+            value: None,
+        }
+    }
+
+    /// `branch`'s CI status went from `from` to `to` (kebab-case status names).
+    pub fn ci(branch: &str, from: &str, to: &str) -> Self {
+        Self {
+            event: NotifyEvent::Ci,
+            title: format!("CI {to} on {branch}"),
+            body: format!("{from} → {to}"),
+            branch: Some(branch.to_string()),
+            worktree: None,
+            value: Some(to.to_string()),
+        }
+    }
+
+    /// `branch`'s marker was set to `marker`.
+    pub fn marker(branch: &str, marker: &str) -> Self {
+        Self {
+            event: NotifyEvent::Marker,
+            title: branch.to_string(),
+            body: format!("Marker set to {marker}"),
+            branch: Some(branch.to_string()),
+            worktree: None,
+            value: Some(marker.to_string()),
+        }
+    }
+}
+
+impl NotifyConfig {
+    /// The event's table, if that event notifies.
+    fn event(&self, event: NotifyEvent) -> Option<&NotifyEventConfig> {
+        match event {
+            NotifyEvent::PipelineFailed => self.pipeline_failed.as_ref(),
+            NotifyEvent::Ci => self.ci.as_ref(),
+            NotifyEvent::Marker => self.marker.as_ref(),
+        }
+    }
+}
+
+/// Deliver `notification` to its event's sinks, if the event is configured
+/// and its `on` list matches the notification's value.
+pub fn notify(config: &NotifyConfig, notification: &Notification) {
+    let Some(event) = config.event(notification.event) else {
+        return;
+    };
+    if let Some(value) = &notification.value
+        && !event.triggers_on(value)
+    {
+        return;
+    }
+    for sink in event.sinks() {
+        if let Err(e) = deliver(config, sink, notification) {
+            log::debug!(
+                "{} notification via {sink:?} failed: {e:#}",
+                <&str>::from(notification.event)
+            );
+        }
+    }
+}
+
+fn deliver(
+    config: &NotifyConfig,
+    sink: NotifySink,
+    notification: &Notification,
+) -> anyhow::Result<()> {
+    match sink {
+        NotifySink::Osc9 | NotifySink::Osc777 => {
+            write_to_terminal(&osc_sequence(sink, &notification.title, &notification.body))
+        }
+        NotifySink::NotifySend => {
+            let output = Cmd::new("notify-send")
+                .args([
+                    "--app-name=worktrunk",
+                    &notification.title,
+                    &notification.body,
+                ])
+                .timeout(COMMAND_TIMEOUT)
+                .run()?;
+            anyhow::ensure!(
+                output.status.success(),
+                "notify-send exited {}",
+                output.status
+            );
+            Ok(())
+        }
+        NotifySink::Command => {
+            let command = config
+                .command
+                .as_deref()
+                .ok_or_else(|| anyhow::anyhow!("no [notify] command configured"))?;
+            let shell = ShellConfig::get()?;
+            let output = Cmd::new(shell.executable.to_string_lossy())
+                .args(shell.args.iter().cloned())
+                .arg(command)
+                .stdin_bytes(serde_json::to_vec(notification)?)
+                .timeout(COMMAND_TIMEOUT)
+                .run()?;
+            anyhow::ensure!(output.status.success(), "command exited {}", output.status);
+            Ok(())
+        }
+        NotifySink::Webhook => {
+            let url = config
+                .webhook
+                .as_deref()
+                .ok_or_else(|| anyhow::anyhow!("no [notify] webhook configured"))?;
+            post_json(url, serde_json::to_vec(notification)?)
+        }
+    }
+}
+
+/// The terminal notification escape for an OSC sink. Control characters are
+/// dropped so a title or body can't end the sequence early, and OSC 777's
+/// field separator is replaced in the title.
+fn osc_sequence(sink: NotifySink, title: &str, body: &str) -> String {
+    let clean = |s: &str| s.chars().filter(|c| !c.is_control()).collect::<String>();
+    if sink == NotifySink::Osc777 {
+        format!(
+            "\x1b]777;notify;{};{}\x07",
+            clean(title).replace(';', ","),
+            clean(body)
+        )
+    } else {
+        format!("\x1b]9;{}: {}\x07", clean(title), clean(body))
+    }
+}
+
+/// Write to the controlling terminal rather than stdout/stderr, which
+/// background pipelines redirect to log files and `wt list` may pipe.
+#[cfg(unix)]
+fn write_to_terminal(sequence: &str) -> anyhow::Result<()> {
+    let mut tty = std::fs::OpenOptions::new().write(true).open("/dev/tty")?;
+    tty.write_all(sequence.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn write_to_terminal(sequence: &str) -> anyhow::Result<()> {
+    use std::io::IsTerminal;
+    let mut stderr = std::io::stderr();
+    anyhow::ensure!(stderr.is_terminal(), "stderr is not a terminal");
+    stderr.write_all(sequence.as_bytes())?;
+    Ok(())
+}
+
+/// Reject webhook URLs that aren't `http://` on `localhost` or a loopback
+/// address.
+fn ensure_local_url(url: &str) -> anyhow::Result<()> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| anyhow::anyhow!("webhook must be an http:// URL: {url}"))?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = match authority.strip_prefix('[') {
+        Some(bracketed) => bracketed.split(']').next().unwrap_or_default(),
+        None => authority.split(':').next().unwrap_or_default(),
+    };
+    let local = host == "localhost"
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback());
+    anyhow::ensure!(local, "webhook host must be localhost or loopback: {host}");
+    Ok(())
+}
+
+/// POST `body` as JSON to a local webhook with `curl`, requiring a 2xx
+/// response. Proxies are bypassed so the request stays on this machine.
+fn post_json(url: &str, body: Vec<u8>) -> anyhow::Result<()> {
+    ensure_local_url(url)?;
+    let max_time = WEBHOOK_TIMEOUT.as_secs().to_string();
+    let output = Cmd::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--fail",
+            "--proto",
+            "=http",
+            "--noproxy",
+            "*",
+            "--max-time",
+            &max_time,
+            "--header",
+            "Content-Type: application/json",
+            "--data-binary",
+            "@-",
+            url,
+        ])
+        .stdin_bytes(body)
+        .timeout(WEBHOOK_TIMEOUT)
+        .run()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "curl exited {}: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_osc_sequence() {
+        assert_eq!(
+            osc_sequence(NotifySink::Osc9, "post-start failed", "exit 1"),
+            "\x1b]9;post-start failed: exit 1\x07"
+        );
+        assert_eq!(
+            osc_sequence(NotifySink::Osc777, "a;b", "line\x07\nbreak"),
+            "\x1b]777;notify;a,b;linebreak\x07"
+        );
+    }
+
+    #[test]
+    fn test_ensure_local_url() {
+        assert!(ensure_local_url("http://localhost:8080/hooks/wt").is_ok());
+        assert!(ensure_local_url("http://127.0.0.1").is_ok());
+        assert!(ensure_local_url("http://[::1]:9000/x").is_ok());
+        assert!(ensure_local_url("https://localhost/x").is_err());
+        assert!(ensure_local_url("http://example.com/x").is_err());
+        assert!(ensure_local_url("http://localhost.example.com/x").is_err());
+    }
+
+    #[test]
+    fn test_notify_posts_matching_events_to_webhook() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            // Read the headers, then as much body as Content-Length names.
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let length: usize = head
+                        .lines()
+                        .find_map(|l| l.strip_prefix("Content-Length: "))
+                        .unwrap()
+                        .parse()
+                        .unwrap();
+                    if body.len() >= length {
+                        break;
+                    }
+                }
+                assert!(n > 0, "connection closed before the body arrived");
+            }
+            stream
+                .write_all(b"HTTP/1.1 204 No Content\r\n\r\n")
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let config = NotifyConfig {
+            webhook: Some(format!("http://127.0.0.1:{port}/wt")),
+            marker: Some(NotifyEventConfig {
+                sinks: Some(vec![NotifySink::Webhook]),
+                on: vec!["💬".to_string()],
+            }),
+            ..Default::default()
+        };
+        let marker = |value: &str| Notification::marker("feature", value);
+        // Not in `on`: no connection, so the server sees only the second.
+        notify(&config, &marker("🤖"));
+        notify(&config, &marker("💬"));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /wt HTTP/1.1\r\n"), "{request}");
+        let body = request.split("\r\n\r\n").nth(1).unwrap();
+        let json: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(json["event"], "marker");
+        assert_eq!(json["value"], "💬");
+        assert_eq!(json["branch"], "feature");
+    }
+}
@@ -69,6 +69,8 @@ pub struct ConcurrentCommand<'a> {
     pub context_json: &'a str,
     /// Optional label for `commands.jsonl` tracing.
     pub log_label: Option<&'a str>,
+    /// Branch recorded with the `commands.jsonl` entry.
+    pub log_branch: Option<&'a str>,
     /// Directive file env vars to pass through to the child. See
     /// `DirectivePassthrough` for the trust model (CD passthrough, EXEC scrub).
     pub directives: &'a DirectivePassthrough,
@@ -329,7 +331,9 @@ fn spawn_child(
     // spawn → wait span (the child keeps running while we drain its output).
     // Each child is fed its own `context_json` on stdin, so mark it stdin-reading
     // — the same command across worktrees isn't a duplicate (different input).
-    let mut trace = CommandTrace::new(None, cmd.expanded).reads_stdin(true);
+    let mut trace = CommandTrace::new(None, cmd.expanded)
+        .reads_stdin(true)
+        .label(cmd.log_label);
     let mut child = match command.spawn() {
         Ok(child) => child,
         Err(e) => {
@@ -430,7 +434,13 @@ fn collect_outcome(spawned: SpawnedChild, cmd: &ConcurrentCommand<'_>) -> anyhow
     let normalized_code = exit_code.or_else(|| signal.map(|s| 128 + s));
 
     if let Some(label) = log_label {
-        log_command(&label, &cmd_str, normalized_code, Some(duration));
+        log_command(
+            &label,
+            cmd.log_branch,
+            &cmd_str,
+            normalized_code,
+            Some(duration),
+        );
     }
 
     if status.success() {
@@ -500,6 +510,7 @@ mod tests {
             working_dir: &wd,
             context_json: "{}",
             log_label,
+            log_branch: None,
             directives,
             scrub_git_discovery: false,
         }];
@@ -30,6 +30,7 @@ use worktrunk::git::{
     BranchDeletionMode, BranchDeletionOutcome, BranchDeletionResult, RemoveOptions,
     execute_branch_deletion, remove_worktree_with_cleanup, stage_worktree_removal,
 };
+use worktrunk::lifecycle::{self, Notice};
 use worktrunk::path::format_path_for_display;
 use worktrunk::progress::{Progress, format_stats_paren};
 use worktrunk::remove_dir::remove_dir_with_progress;
@@ -1965,6 +1966,23 @@ fn handle_removed_worktree_output(
 
     execute_pre_remove_hooks_if_needed(&repo, &ctx)?;
 
+    // The container goes once `pre-remove` hooks (which run inside it) are
+    // done, while the directory is still at its original path, and its
+    // `{{ ports.* }}` go back to the pool with it. The picker
+    // owns the terminal, so the silent path drops the progress line.
+    let silent = matches!(ctx.execution, RemovalExecution::Silent);
+    lifecycle::tear_down(
+        &repo,
+        ctx.worktree_path,
+        ctx.branch_name,
+        crate::commands::container::approved(&repo).as_ref(),
+        &mut |notice| {
+            if !(silent && matches!(notice, Notice::Progress(_))) {
+                notice.print();
+            }
+        },
+    );
+
     // No re-validation after `pre-remove` hooks: the pre-rename `ensure_clean`
     // in the removal core catches a hook-dirtied worktree, and the branch
     // deletion re-decides against fresh refs (`delete_branch_if_safe`'s CAS)
@@ -2091,11 +2109,13 @@ fn remove_removed_worktree_silently(
 /// like a top-level command the user typed). See issue #3373.
 ///
 /// [`INHERITED_GIT_PATH_VARS`]: worktrunk::shell_exec::INHERITED_GIT_PATH_VARS
+#[allow(clippy::too_many_arguments)]
 pub fn execute_shell_command(
     working_dir: &std::path::Path,
     command: &str,
     stdin_content: Option<&str>,
     command_log_label: Option<&str>,
+    command_log_branch: Option<&str>,
     directives: DirectivePassthrough,
     redirect_stdout_to_stderr: bool,
     scrub_git_discovery: bool,
@@ -2125,7 +2145,7 @@ pub fn execute_shell_command(
     }
 
     if let Some(label) = command_log_label {
-        cmd = cmd.external(label);
+        cmd = cmd.external(label).external_branch(command_log_branch);
     }
 
     if let Some(content) = stdin_content {
@@ -0,0 +1,203 @@
+//! Per-worktree port allocation, recorded in `.git/wt/ports.json`.
+//!
+//! `{{ branch | hash_port }}` is stateless: two branches can hash to the same
+//! port, and nothing checks that the port is free. The registry instead
+//! assigns each branch's named services (`{{ ports.web }}`, `{{ ports.db }}`)
+//! a port that no other branch holds and that was free to bind when assigned,
+//! and keeps it until the worktree is removed — so every hook, the `wt list`
+//! URL column and `wt step eval` agree on it.
+//!
+//! Allocation starts from the `hash_port` of `<branch>-<service>`, so a fresh
+//! registry hands out the same ports a `hash_port` template would, and probes
+//! upward (wrapping within 10000–19999) past taken or busy ports. It runs
+//! under an exclusive lock on `ports.json.lock`, since concurrent hooks of
+//! different worktrees allocate at once.
+//!
+//! Entries are keyed by branch, like `wt config state vars` — worktrunk pairs
+//! each branch with one worktree.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::ErrorKind;
+use std::net::TcpListener;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use fs2::FileExt;
+
+use crate::config::string_to_port;
+use crate::git::Repository;
+use crate::utils::write_atomically;
+
+/// Lowest port the registry assigns (matches `hash_port`).
+const PORT_MIN: u16 = 10000;
+/// Number of ports in the range (10000–19999).
+const PORT_COUNT: u16 = 10000;
+
+/// Branch → service → port.
+pub type PortMap = BTreeMap<String, BTreeMap<String, u16>>;
+
+/// The port registry of a repository.
+#[derive(Debug)]
+pub struct Ports {
+    path: PathBuf,
+}
+
+impl Ports {
+    pub fn for_repo(repo: &Repository) -> Self {
+        Self {
+            path: repo.wt_dir().join("ports.json"),
+        }
+    }
+
+    /// Every recorded assignment; none before the first one. A registry that
+    /// doesn't parse is an error rather than empty, so an allocation can't
+    /// hand out ports it no longer sees and overwrite the record.
+    pub fn all(&self) -> anyhow::Result<PortMap> {
+        let json = match fs::read_to_string(&self.path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(PortMap::new()),
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to read {}", self.path.display()));
+            }
+        };
+        serde_json::from_str(&json)
+            .with_context(|| format!("Failed to parse {}", self.path.display()))
+    }
+
+    /// The ports recorded for `branch`.
+    pub fn for_branch(&self, branch: &str) -> anyhow::Result<BTreeMap<String, u16>> {
+        Ok(self.all()?.remove(branch).unwrap_or_default())
+    }
+
+    /// The port of `branch`'s `service`, assigning one on first use.
+    pub fn allocate(&self, branch: &str, service: &str) -> anyhow::Result<u16> {
+        self.allocate_with(branch, service, is_free)
+    }
+
+    fn allocate_with(
+        &self,
+        branch: &str,
+        service: &str,
+        is_free: impl Fn(u16) -> bool,
+    ) -> anyhow::Result<u16> {
+        let _lock = self.lock()?;
+        let mut all = self.all()?;
+        if let Some(&port) = all.get(branch).and_then(|ports| ports.get(service)) {
+            return Ok(port);
+        }
+        let taken: std::collections::HashSet<u16> = all
+            .values()
+            .flat_map(|ports| ports.values().copied())
+            .collect();
+        let start = string_to_port(&format!("{branch}-{service}")) - PORT_MIN;
+        let port = (0..PORT_COUNT)
+            .map(|offset| PORT_MIN + (start + offset) % PORT_COUNT)
+            .find(|port| !taken.contains(port) && is_free(*port))
+            .context("No free port in 10000-19999")?;
+        all.entry(branch.to_string())
+            .or_default()
+            .insert(service.to_string(), port);
+        self.save(&all)?;
+        Ok(port)
+    }
+
+    /// Drop `branch`'s ports, returning how many it held.
+    pub fn release(&self, branch: &str) -> anyhow::Result<usize> {
+        let _lock = self.lock()?;
+        let mut all = self.all()?;
+        let Some(ports) = all.remove(branch) else {
+            return Ok(0);
+        };
+        self.save(&all)?;
+        Ok(ports.len())
+    }
+
+    /// Exclusive lock for a read-modify-write, released on drop.
+    fn lock(&self) -> anyhow::Result<File> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let lock_path = self.path.with_extension("json.lock");
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)
+            .with_context(|| format!("Failed to open {}", lock_path.display()))?;
+        file.lock_exclusive()
+            .context("Failed to lock the port registry")?;
+        Ok(file)
+    }
+
+    /// Replace the registry with `all`. Callers hold [`Self::lock`]; the write
+    /// goes through a temp file and a rename, so a reader outside the lock sees
+    /// the old registry or the new one, never a partial file.
+    fn save(&self, all: &PortMap) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(all)?;
+        write_atomically(&self.path, &(json + "\n"))
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+}
+
+/// Whether `port` can be bound on the loopback interface right now.
+fn is_free(port: u16) -> bool {
+    TcpListener::bind(("127.0.0.1", port)).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> (tempfile::TempDir, Ports) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wt").join("ports.json");
+        (dir, Ports { path })
+    }
+
+    #[test]
+    fn test_allocate_is_stable_and_unique() {
+        let (_dir, ports) = registry();
+        let web = ports.allocate_with("feat", "web", |_| true).unwrap();
+        assert_eq!(web, string_to_port("feat-web"));
+        assert_eq!(ports.allocate_with("feat", "web", |_| true).unwrap(), web);
+
+        // A port another branch holds, or one that's busy, is skipped.
+        let db = ports
+            .allocate_with("feat", "db", |port| port != string_to_port("feat-db"))
+            .unwrap();
+        assert_ne!(db, string_to_port("feat-db"));
+        assert_ne!(db, web);
+
+        assert_eq!(
+            ports.for_branch("feat").unwrap(),
+            BTreeMap::from([("db".to_string(), db), ("web".to_string(), web)])
+        );
+    }
+
+    #[test]
+    fn test_release() {
+        let (_dir, ports) = registry();
+        ports.allocate_with("feat", "web", |_| true).unwrap();
+        ports.allocate_with("other", "web", |_| true).unwrap();
+        assert_eq!(ports.release("feat").unwrap(), 1);
+        assert_eq!(ports.release("feat").unwrap(), 0);
+        assert!(ports.for_branch("feat").unwrap().is_empty());
+        assert_eq!(ports.all().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_unparsable_registry_is_an_error() {
+        let (_dir, ports) = registry();
+        ports.allocate_with("feat", "web", |_| true).unwrap();
+        fs::write(&ports.path, "{ not json").unwrap();
+
+        assert!(ports.all().is_err());
+        assert!(ports.allocate_with("other", "web", |_| true).is_err());
+        assert!(ports.release("feat").is_err());
+        // Nothing overwrote the record.
+        assert_eq!(fs::read_to_string(&ports.path).unwrap(), "{ not json");
+    }
+}
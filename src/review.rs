@@ -0,0 +1,385 @@
+//! LLM code review of a branch.
+//!
+//! Where [`crate::summary`] describes a branch, a review critiques it: the
+//! same combined diff (branch diff + working tree diff, through the same
+//! `prepare_diff` filtering) goes to the configured LLM with a review
+//! template, and the findings come back one per line, each tagged
+//! `[blocking]` or `[suggestion]`.
+//!
+//! Reviews are cached in `.git/wt/cache/review/{sanitized_branch}/{key}.json`
+//! with the same content-addressed layout, prune-on-write, and torn-write
+//! semantics as summaries (see the [`crate::summary`] module docs). The key
+//! hashes the rendered prompt — diff and `review-template` together — and the
+//! LLM command, so changing either produces a fresh review. The picker's
+//! review tab only ever reads this cache — a review costs a slow LLM call, so
+//! it's generated by `wt step review`, never by tab navigation.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use minijinja::Environment;
+use serde::{Deserialize, Serialize};
+use worktrunk::cache;
+use worktrunk::git::Repository;
+use worktrunk::path::sanitize_for_filename;
+use worktrunk::utils::epoch_now;
+
+use crate::llm::{execute_llm_command, prepare_diff};
+use crate::summary::{CombinedDiff, hash_diff};
+
+/// Subdirectory of `.git/wt/cache/` holding cached reviews.
+const KIND: &str = "review";
+
+/// Tag marking a finding that should stop a merge.
+const BLOCKING_TAG: &str = "[blocking]";
+
+/// Cached review stored in `.git/wt/cache/review/{branch}/{key}.json`.
+///
+/// Mirrors [`crate::summary::CachedSummary`]: the cache key (see
+/// [`cache_key`]) lives in the filename, and `branch` keeps the un-sanitized
+/// name for display.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedReview {
+    pub review: String,
+    /// Original branch name (for display in `wt config state get`).
+    pub branch: String,
+    /// Hash of the combined diff reviewed, for readers that don't resolve
+    /// the template and command (the picker's review tab).
+    #[serde(default)]
+    pub diff_hash: String,
+    /// Unix timestamp when the review was generated.
+    #[serde(default)]
+    pub generated_at: u64,
+}
+
+/// Default template for review generation.
+///
+/// The `[blocking]` / `[suggestion]` tags are the contract with
+/// [`blocking_findings`] — a custom `review-template` has to ask for them too
+/// for `wt step review --check` to gate anything.
+pub(crate) const DEFAULT_REVIEW_TEMPLATE: &str = r#"<task>Review this branch's changes before it merges.</task>
+
+<format>
+- One finding per line, as a bullet: "- [blocking] path:line — problem" or "- [suggestion] path:line — problem"
+- [blocking] only for defects that must not merge: bugs, data loss, security holes, broken builds or tests
+- [suggestion] for everything else worth raising; skip style nits a formatter would catch
+- If nothing is worth raising, output exactly: No findings
+- Output only the findings — no preamble, summary, or code blocks
+</format>
+
+<context>
+Repository: {{ repo }}
+Branch: {{ branch }}
+</context>
+
+<diffstat>
+{{ git_diff_stat }}
+</diffstat>
+
+<diff>
+{{ git_diff }}
+</diff>
+"#;
+
+impl CachedReview {
+    /// Root of all review cache entries: `.git/wt/cache/review/`.
+    pub(crate) fn cache_root(repo: &Repository) -> PathBuf {
+        cache::cache_dir(repo, KIND)
+    }
+
+    /// Per-branch directory holding one file per cache key.
+    fn branch_dir(repo: &Repository, branch: &str) -> PathBuf {
+        Self::cache_root(repo).join(sanitize_for_filename(branch))
+    }
+
+    /// Full path for a specific (branch, key) pair.
+    pub(crate) fn cache_file(repo: &Repository, branch: &str, key: &str) -> PathBuf {
+        Self::branch_dir(repo, branch).join(format!("{key}.json"))
+    }
+
+    /// Read the cached review for a branch at a specific cache key.
+    pub(crate) fn read(repo: &Repository, branch: &str, key: &str) -> Option<Self> {
+        cache::read_json(&Self::cache_file(repo, branch, key))
+    }
+
+    /// The branch's cached review if it covers the diff with `diff_hash`,
+    /// whatever template and command produced it.
+    pub(crate) fn read_for_diff(repo: &Repository, branch: &str, diff_hash: &str) -> Option<Self> {
+        freshest_entry(&Self::branch_dir(repo, branch)).filter(|r| r.diff_hash == diff_hash)
+    }
+
+    /// Write the review at `{branch}/{key}.json` and prune sibling keys.
+    pub(crate) fn write(&self, repo: &Repository, key: &str) {
+        cache::write_json(&Self::cache_file(repo, &self.branch, key), self);
+        cache::sweep_lru(&Self::branch_dir(repo, &self.branch), 1);
+    }
+
+    /// List one cached review per branch, newest-first with branch-name
+    /// tiebreak.
+    pub(crate) fn list_all(repo: &Repository) -> Vec<Self> {
+        let Ok(branch_dirs) = fs::read_dir(Self::cache_root(repo)) else {
+            return Vec::new();
+        };
+        let mut out: Vec<Self> = branch_dirs
+            .filter_map(|e| e.ok())
+            .filter_map(|entry| {
+                if !entry.file_type().ok()?.is_dir() {
+                    return None;
+                }
+                freshest_entry(&entry.path())
+            })
+            .collect();
+        out.sort_by(|a, b| {
+            b.generated_at
+                .cmp(&a.generated_at)
+                .then_with(|| a.branch.cmp(&b.branch))
+        });
+        out
+    }
+
+    /// Clear all cached reviews, returning the count of `.json` entries removed.
+    pub(crate) fn clear_all(repo: &Repository) -> anyhow::Result<usize> {
+        let root = Self::cache_root(repo);
+        let branch_dirs = match fs::read_dir(&root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => {
+                return Err(
+                    anyhow::Error::new(e).context(format!("failed to read {}", root.display()))
+                );
+            }
+        };
+
+        let mut cleared = 0;
+        for entry in branch_dirs.flatten() {
+            if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                continue;
+            }
+            let dir = entry.path();
+            cleared += cache::clear_json_files(&dir)?;
+            let _ = fs::remove_dir(&dir);
+        }
+        Ok(cleared)
+    }
+}
+
+/// The cached review with the newest `generated_at` in a branch directory.
+fn freshest_entry(dir: &Path) -> Option<CachedReview> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_str().is_some_and(|s| s.ends_with(".json")))
+        .filter_map(|e| cache::read_json::<CachedReview>(&e.path()))
+        .max_by_key(|r| r.generated_at)
+}
+
+/// Findings tagged `[blocking]`, as written by the model (bullet stripped).
+///
+/// Matching is per line and case-insensitive on the tag, so `- [BLOCKING] …`
+/// and `* [blocking] …` both count; a tag mid-sentence doesn't.
+pub(crate) fn blocking_findings(review: &str) -> Vec<&str> {
+    review
+        .lines()
+        .map(|line| {
+            let line = line.trim_start();
+            line.strip_prefix("- ")
+                .or_else(|| line.strip_prefix("* "))
+                .unwrap_or(line)
+                .trim()
+        })
+        .filter(|finding| {
+            finding
+                .get(..BLOCKING_TAG.len())
+                .is_some_and(|tag| tag.eq_ignore_ascii_case(BLOCKING_TAG))
+        })
+        .collect()
+}
+
+/// Render the review prompt.
+pub(crate) fn render_prompt(
+    template: &str,
+    diff: &str,
+    stat: &str,
+    branch: &str,
+    repo_name: &str,
+) -> anyhow::Result<String> {
+    let env = Environment::new();
+    let tmpl = env.template_from_str(template)?;
+    Ok(tmpl.render(minijinja::context! {
+        git_diff => diff,
+        git_diff_stat => stat,
+        branch => branch,
+        repo => repo_name,
+    })?)
+}
+
+/// Cache key for a review: the rendered prompt (diff and template) and the
+/// LLM command that answers it.
+pub(crate) fn cache_key(prompt: &str, llm_command: &str) -> String {
+    hash_diff(&format!("{llm_command}\0{prompt}"))
+}
+
+/// A review and whether it came from the cache.
+pub(crate) struct Review {
+    pub text: String,
+    pub cached: bool,
+}
+
+/// Review pipeline: cache check → prompt → LLM → cache write.
+///
+/// `combined` is the branch's diff from
+/// [`crate::summary::compute_combined_diff`]. A review is reused until the
+/// diff, the template, or the LLM command changes (see [`cache_key`]).
+/// `refresh` skips the cache read (the result is still written).
+pub(crate) fn generate_review(
+    repo: &Repository,
+    branch: &str,
+    combined: CombinedDiff,
+    template: &str,
+    llm_command: &str,
+    refresh: bool,
+) -> anyhow::Result<Review> {
+    let diff_hash = hash_diff(&combined.diff);
+    let repo_name = repo
+        .repo_path()
+        .ok()
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str())
+        .unwrap_or("repo")
+        .to_string();
+    let prepared = prepare_diff(combined.diff, combined.stat);
+    let prompt = render_prompt(template, &prepared.diff, &prepared.stat, branch, &repo_name)?;
+    let key = cache_key(&prompt, llm_command);
+    if !refresh && let Some(cached) = CachedReview::read(repo, branch, &key) {
+        return Ok(Review {
+            text: cached.review,
+            cached: true,
+        });
+    }
+    let text = execute_llm_command(llm_command, &prompt)?;
+
+    CachedReview {
+        review: text.clone(),
+        branch: branch.to_string(),
+        diff_hash,
+        generated_at: epoch_now(),
+    }
+    .write(repo, &key);
+
+    Ok(Review {
+        text,
+        cached: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use worktrunk::testing::TestRepo;
+
+    #[test]
+    fn cache_key_covers_prompt_and_command() {
+        let key = cache_key("prompt", "llm");
+        assert_eq!(key, cache_key("prompt", "llm"));
+        assert_ne!(key, cache_key("other prompt", "llm"));
+        assert_ne!(key, cache_key("prompt", "other-llm"));
+    }
+
+    #[test]
+    fn blocking_findings_match_tagged_bullets() {
+        let review = "\
+- [blocking] src/lib.rs:10 — unwrap on user input panics
+* [BLOCKING] src/db.rs:4 — migration drops a column
+- [suggestion] src/lib.rs:20 — name is unclear
+- [suggestion] mentions [blocking] mid-sentence
+[blocking] unbulleted finding
+";
+        assert_eq!(
+            blocking_findings(review),
+            vec![
+                "[blocking] src/lib.rs:10 — unwrap on user input panics",
+                "[BLOCKING] src/db.rs:4 — migration drops a column",
+                "[blocking] unbulleted finding",
+            ]
+        );
+        assert!(blocking_findings("No findings").is_empty());
+    }
+
+    #[test]
+    fn render_prompt_fills_default_template() {
+        let prompt = render_prompt(
+            DEFAULT_REVIEW_TEMPLATE,
+            "diff content here",
+            "stat content here",
+            "feature",
+            "repo",
+        )
+        .unwrap();
+        insta::assert_snapshot!(prompt, @r#"
+        <task>Review this branch's changes before it merges.</task>
+
+        <format>
+        - One finding per line, as a bullet: "- [blocking] path:line — problem" or "- [suggestion] path:line — problem"
+        - [blocking] only for defects that must not merge: bugs, data loss, security holes, broken builds or tests
+        - [suggestion] for everything else worth raising; skip style nits a formatter would catch
+        - If nothing is worth raising, output exactly: No findings
+        - Output only the findings — no preamble, summary, or code blocks
+        </format>
+
+        <context>
+        Repository: repo
+        Branch: feature
+        </context>
+
+        <diffstat>
+        stat content here
+        </diffstat>
+
+        <diff>
+        diff content here
+        </diff>
+        "#);
+    }
+
+    #[test]
+    fn cache_roundtrip_keeps_one_entry_per_branch() {
+        let t = TestRepo::new();
+        t.repo
+            .run_command(&["commit", "--allow-empty", "-m", "init"])
+            .unwrap();
+        let repo = Repository::at(t.path()).unwrap();
+
+        let review = |text: &str, at: u64| CachedReview {
+            review: text.to_string(),
+            branch: "feature/x".to_string(),
+            diff_hash: "d1ff".to_string(),
+            generated_at: at,
+        };
+        review("first", 1).write(&repo, "aaaa");
+        assert_eq!(
+            CachedReview::read(&repo, "feature/x", "aaaa")
+                .unwrap()
+                .review,
+            "first"
+        );
+
+        // A new diff hash replaces the old entry. `sweep_lru` orders by mtime,
+        // so keep the two writes out of the same coarse mtime tick.
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        review("second", 2).write(&repo, "bbbb");
+        assert!(CachedReview::read(&repo, "feature/x", "aaaa").is_none());
+        let all = CachedReview::list_all(&repo);
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].review, "second");
+        assert_eq!(
+            CachedReview::read_for_diff(&repo, "feature/x", "d1ff")
+                .unwrap()
+                .review,
+            "second"
+        );
+        assert!(CachedReview::read_for_diff(&repo, "feature/x", "0ther").is_none());
+
+        assert_eq!(CachedReview::clear_all(&repo).unwrap(), 1);
+        assert!(CachedReview::list_all(&repo).is_empty());
+    }
+}
@@ -0,0 +1,438 @@
+//! Sandboxed execution for project hook commands.
+//!
+//! A project hook is a command from a repository's `.config/wt.toml`. Once
+//! approved it runs with the user's full privileges — which is what a hook
+//! checked in by a teammate wants, and not what one arriving with a fork's
+//! pull request (`wt switch pr:N`) should get. `[hooks] sandbox` in the user
+//! config opts into running project hooks confined:
+//!
+//! - the filesystem is read-only, except the worktree, its git directory
+//!   (index, `HEAD`), a private `/tmp`, and any paths listed in
+//!   `[hooks] writable`;
+//! - within those, what git reads to decide what to run stays read-only: the
+//!   worktree's `.git` link, the repository's `config` and `hooks/`, and the
+//!   files tying a linked worktree to the repository — otherwise a hook could
+//!   plant code that the user's next `git` command runs unconfined. In the
+//!   main worktree, whose git directory is the repository's, its refs and
+//!   objects stay read-only too, as they are from a linked worktree;
+//! - there is no network unless `[hooks] allow-network = true`;
+//! - the command runs in fresh PID, IPC and UTS namespaces, in its own session
+//!   (so it can't push keystrokes into the terminal), and dies with `wt`.
+//!
+//! The user's own hooks never run sandboxed: they come from the user's config.
+//!
+//! ## Mechanism
+//!
+//! Bubblewrap (`bwrap`) builds the namespaces. Landlock would need no external
+//! binary, but applying it from Rust is unsafe FFI and the crate has
+//! `#![forbid(unsafe_code)]` — the same reason [`crate::priority`] shells out.
+//!
+//! Like [`crate::priority::command`], the policy is applied by wrapping:
+//! [`SandboxPolicy::wrap`] turns a shell command string into one that runs it
+//! under `bwrap … -- sh -c '<command>'`. Wrapping the string keeps the
+//! foreground executor, the concurrent executor, and the background pipeline
+//! runner on their existing spawn paths.
+//!
+//! A sandbox that can't be built fails the command rather than running it
+//! unconfined: no `bwrap` on `PATH`, or a platform other than Linux.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{HookPolicyConfig, SandboxMode, UserConfig};
+use crate::container::Container;
+use crate::git::Repository;
+use crate::path::{expand_tilde, format_path_for_display};
+
+/// The confinement one hook command runs under.
+///
+/// Resolved once per hook pipeline from `[hooks]`, and serialized into the
+/// background pipeline spec so the detached runner applies the same policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxPolicy {
+    /// Paths bound writable, in order: the worktree, its git directory, then
+    /// the configured `writable` entries.
+    pub writable: Vec<PathBuf>,
+    /// Paths bound read-only over the writable ones, where they exist.
+    #[serde(default)]
+    pub read_only: Vec<PathBuf>,
+    /// How many leading `writable` entries must exist. The rest are bound only
+    /// if present, so a configured cache directory that hasn't been created
+    /// yet doesn't fail every hook.
+    pub required: usize,
+    pub allow_network: bool,
+}
+
+impl SandboxPolicy {
+    /// The policy `config` puts project hooks in `worktree` under, or `None`
+    /// when sandboxing is off. `git_dir` and `common_dir` are the worktree's
+    /// git directory and the repository's shared one.
+    pub fn resolve(
+        config: &HookPolicyConfig,
+        worktree: &Path,
+        git_dir: Option<&Path>,
+        common_dir: Option<&Path>,
+    ) -> Option<Self> {
+        match config.sandbox() {
+            SandboxMode::Off => None,
+            SandboxMode::Bubblewrap => {
+                let mut writable = vec![worktree.to_path_buf()];
+                writable.extend(git_dir.map(Path::to_path_buf));
+                let required = writable.len();
+                writable.extend(
+                    config
+                        .writable
+                        .iter()
+                        .map(|path| expand_tilde(Path::new(path)).into_owned()),
+                );
+                // `writable` granting the repository's git directory opens its
+                // refs and objects to every worktree's hooks, the main one's
+                // included.
+                let repo_writable = common_dir.is_some_and(|common_dir| {
+                    writable[required..]
+                        .iter()
+                        .any(|path| common_dir.starts_with(path))
+                });
+                Some(Self {
+                    read_only: protected_git_paths(worktree, git_dir, common_dir, repo_writable),
+                    writable,
+                    required,
+                    allow_network: config.allow_network(),
+                })
+            }
+        }
+    }
+
+    /// The policy the user config puts `repo`'s project hooks under when they
+    /// run in `worktree`. User hooks are never sandboxed, so their callers
+    /// don't ask.
+    pub fn for_project_hooks(
+        repo: &Repository,
+        config: &UserConfig,
+        worktree: &Path,
+    ) -> Option<Self> {
+        let project_id = repo.project_identifier().ok();
+        let git_dir = repo.worktree_at(worktree).git_dir().ok();
+        Self::resolve(
+            &config.hook_policy(project_id.as_deref()),
+            worktree,
+            git_dir.as_deref(),
+            Some(repo.git_common_dir()),
+        )
+    }
+
+    /// `command` rewritten to run inside the sandbox, with `cwd` as its
+    /// working directory.
+    pub fn wrap(&self, command: &str, cwd: &Path) -> anyhow::Result<String> {
+        if !cfg!(target_os = "linux") {
+            anyhow::bail!(
+                "Sandboxed hooks need Linux; set [hooks] sandbox = \"off\" to run project hooks unsandboxed"
+            );
+        }
+        let bwrap = which::which("bwrap").map_err(|_| {
+            anyhow::anyhow!(
+                "Sandboxed hooks need bubblewrap, and bwrap isn't on PATH; install it, or set [hooks] sandbox = \"off\""
+            )
+        })?;
+        let mut words = vec![bwrap.to_string_lossy().into_owned()];
+        words.extend(self.bwrap_args(command, cwd));
+        Ok(words
+            .iter()
+            .map(|word| shell_escape::unix::escape(word.into()).into_owned())
+            .collect::<Vec<_>>()
+            .join(" "))
+    }
+
+    /// The `bwrap` arguments that run `command` in `cwd` under this policy.
+    ///
+    /// Mount order matters: the private `/tmp` is laid over the read-only
+    /// root first, so a worktree under `/tmp` is bound on top of it.
+    pub fn bwrap_args(&self, command: &str, cwd: &Path) -> Vec<String> {
+        let mut args: Vec<String> = ["--die-with-parent", "--new-session", "--unshare-all"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        if self.allow_network {
+            args.push("--share-net".into());
+        }
+        args.extend(
+            [
+                "--ro-bind",
+                "/",
+                "/",
+                "--dev",
+                "/dev",
+                "--proc",
+                "/proc",
+                "--tmpfs",
+                "/tmp",
+            ]
+            .map(String::from),
+        );
+        for (i, path) in self.writable.iter().enumerate() {
+            let bind = if i < self.required {
+                "--bind"
+            } else {
+                "--bind-try"
+            };
+            let path = path.to_string_lossy().into_owned();
+            args.extend([bind.to_string(), path.clone(), path]);
+        }
+        for path in &self.read_only {
+            let path = path.to_string_lossy().into_owned();
+            args.extend(["--ro-bind-try".to_string(), path.clone(), path]);
+        }
+        args.extend([
+            "--chdir".to_string(),
+            cwd.to_string_lossy().into_owned(),
+            "--".to_string(),
+            "sh".to_string(),
+            "-c".to_string(),
+            command.to_string(),
+        ]);
+        args
+    }
+}
+
+/// Paths under the writable binds that decide what git executes: rewriting
+/// them would let a hook run code outside the sandbox the next time the user
+/// runs `git` here.
+///
+/// A linked worktree's `.git` file and its `commondir`/`gitdir` point git at
+/// a repository, and `config.worktree` and the shared `config` can set
+/// `core.hooksPath`, `core.fsmonitor` and friends. When the git directory is
+/// the shared one (the main worktree), the other worktrees' pointers live in
+/// it too, so `worktrees/` is protected whole — as are the repository's refs
+/// and objects, which a linked worktree's hook can't write either, unless
+/// `repo_writable` says the user's `writable` list grants them. Binding
+/// just the per-worktree files writable instead wouldn't work: git replaces
+/// `index` and `HEAD` by renaming a lock file over them, which a bind mount
+/// refuses.
+fn protected_git_paths(
+    worktree: &Path,
+    git_dir: Option<&Path>,
+    common_dir: Option<&Path>,
+    repo_writable: bool,
+) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    let gitlink = worktree.join(".git");
+    if gitlink.is_file() {
+        paths.push(gitlink);
+    }
+    if let Some(git_dir) = git_dir {
+        paths.extend(
+            ["config.worktree", "commondir", "gitdir"]
+                .iter()
+                .map(|name| git_dir.join(name)),
+        );
+    }
+    if let Some(common_dir) = common_dir {
+        paths.push(common_dir.join("config"));
+        // A repository initialized without templates has no `hooks/`, and a
+        // hook could create one; make sure there's a directory to cover.
+        let hooks = common_dir.join("hooks");
+        let _ = std::fs::create_dir(&hooks);
+        paths.push(hooks);
+        if git_dir == Some(common_dir) {
+            paths.push(common_dir.join("worktrees"));
+        }
+        if git_dir == Some(common_dir) && !repo_writable {
+            // Like `hooks/`: a missing `packed-refs` could be planted.
+            let packed_refs = common_dir.join("packed-refs");
+            let _ = std::fs::OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(&packed_refs);
+            paths.extend(["refs", "objects"].map(|name| common_dir.join(name)));
+            paths.push(packed_refs);
+        }
+    }
+    paths
+}
+
+/// `command` wrapped to run inside `container`, else inside `sandbox`, or
+/// unchanged without either. A running container is the project hook's
+/// confinement, so it takes the sandbox's place rather than nesting in it.
+pub fn sandboxed(
+    sandbox: Option<&SandboxPolicy>,
+    container: Option<&Container>,
+    command: String,
+    cwd: &Path,
+) -> anyhow::Result<String> {
+    match (container, sandbox) {
+        (Some(container), _) => Ok(container.wrap(&command, cwd)),
+        (None, Some(policy)) => policy.wrap(&command, cwd),
+        (None, None) => Ok(command),
+    }
+}
+
+/// One line describing what `config` does to project hooks, for the approval
+/// prompt — or `None` when they run unsandboxed.
+pub fn describe(config: &HookPolicyConfig) -> Option<String> {
+    match config.sandbox() {
+        SandboxMode::Off => None,
+        SandboxMode::Bubblewrap => {
+            let mut writes = "the worktree".to_string();
+            for path in &config.writable {
+                writes.push_str(", ");
+                writes.push_str(&format_path_for_display(&expand_tilde(Path::new(path))));
+            }
+            let network = if config.allow_network() {
+                "network allowed"
+            } else {
+                "no network"
+            };
+            Some(format!(
+                "sandboxed with bubblewrap: writes limited to {writes}; {network}"
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bubblewrap(allow_network: bool, writable: &[&str]) -> HookPolicyConfig {
+        HookPolicyConfig {
+            sandbox: Some(SandboxMode::Bubblewrap),
+            allow_network: Some(allow_network),
+            writable: writable.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_off_is_none() {
+        let config = HookPolicyConfig::default();
+        assert_eq!(
+            SandboxPolicy::resolve(&config, Path::new("/w"), None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bwrap_args() {
+        let policy = SandboxPolicy::resolve(
+            &bubblewrap(false, &["/var/cache/build"]),
+            Path::new("/repo.feature"),
+            Some(Path::new("/repo/.git/worktrees/feature")),
+            Some(Path::new("/repo/.git")),
+        )
+        .unwrap();
+        assert_eq!(
+            policy.bwrap_args("npm install", Path::new("/repo.feature")),
+            [
+                "--die-with-parent",
+                "--new-session",
+                "--unshare-all",
+                "--ro-bind",
+                "/",
+                "/",
+                "--dev",
+                "/dev",
+                "--proc",
+                "/proc",
+                "--tmpfs",
+                "/tmp",
+                "--bind",
+                "/repo.feature",
+                "/repo.feature",
+                "--bind",
+                "/repo/.git/worktrees/feature",
+                "/repo/.git/worktrees/feature",
+                "--bind-try",
+                "/var/cache/build",
+                "/var/cache/build",
+                "--ro-bind-try",
+                "/repo/.git/worktrees/feature/config.worktree",
+                "/repo/.git/worktrees/feature/config.worktree",
+                "--ro-bind-try",
+                "/repo/.git/worktrees/feature/commondir",
+                "/repo/.git/worktrees/feature/commondir",
+                "--ro-bind-try",
+                "/repo/.git/worktrees/feature/gitdir",
+                "/repo/.git/worktrees/feature/gitdir",
+                "--ro-bind-try",
+                "/repo/.git/config",
+                "/repo/.git/config",
+                "--ro-bind-try",
+                "/repo/.git/hooks",
+                "/repo/.git/hooks",
+                "--chdir",
+                "/repo.feature",
+                "--",
+                "sh",
+                "-c",
+                "npm install",
+            ]
+        );
+    }
+
+    /// In the main worktree the writable git directory is the repository's, so
+    /// its refs and objects are laid read-only over it.
+    #[test]
+    fn test_resolve_main_worktree_protects_refs_and_objects() {
+        let dir = tempfile::tempdir().unwrap();
+        let git_dir = dir.path().join(".git");
+        std::fs::create_dir(&git_dir).unwrap();
+        let policy = SandboxPolicy::resolve(
+            &bubblewrap(false, &[]),
+            dir.path(),
+            Some(&git_dir),
+            Some(&git_dir),
+        )
+        .unwrap();
+        assert_eq!(policy.writable, [dir.path(), git_dir.as_path()]);
+        for name in [
+            "refs",
+            "objects",
+            "packed-refs",
+            "worktrees",
+            "config",
+            "hooks",
+        ] {
+            assert!(
+                policy.read_only.contains(&git_dir.join(name)),
+                "{name} not protected: {:?}",
+                policy.read_only
+            );
+        }
+        assert!(git_dir.join("packed-refs").is_file());
+
+        // Listing the repository's `.git` in `writable` opens them again.
+        let git_dir_str = git_dir.to_str().unwrap();
+        let policy = SandboxPolicy::resolve(
+            &bubblewrap(false, &[git_dir_str]),
+            dir.path(),
+            Some(&git_dir),
+            Some(&git_dir),
+        )
+        .unwrap();
+        assert!(!policy.read_only.contains(&git_dir.join("refs")));
+        assert!(policy.read_only.contains(&git_dir.join("worktrees")));
+    }
+
+    #[test]
+    fn test_bwrap_args_network() {
+        let policy =
+            SandboxPolicy::resolve(&bubblewrap(true, &[]), Path::new("/w"), None, None).unwrap();
+        let args = policy.bwrap_args("true", Path::new("/w"));
+        assert!(args.contains(&"--share-net".to_string()));
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe(&HookPolicyConfig::default()), None);
+        assert_eq!(
+            describe(&bubblewrap(false, &["/opt/cache"])).as_deref(),
+            Some(
+                "sandboxed with bubblewrap: writes limited to the worktree, /opt/cache; no network"
+            )
+        );
+        assert_eq!(
+            describe(&bubblewrap(true, &[])).as_deref(),
+            Some("sandboxed with bubblewrap: writes limited to the worktree; network allowed")
+        );
+    }
+}
@@ -9,7 +9,7 @@ use std::fs;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 
-use super::paths::{home_dir_required, powershell_profile_paths};
+use super::paths::{home_dir_required, line_based_config_paths, powershell_profile_paths};
 
 /// Detect if a line contains shell integration for a specific command.
 ///
@@ -233,9 +233,10 @@ fn is_execution_context(line: &str) -> bool {
         return line_lower.contains("out-string");
     }
 
-    // POSIX shells (bash, zsh, fish) and nushell
+    // POSIX shells (bash, zsh, fish), nushell, elvish and xonsh
     line.contains("eval")
         || line.contains("source")
+        || line.contains("execx") // xonsh runs code from a string
         || line.contains(". <(") // POSIX dot command with process substitution
         || line.contains(". =(") // zsh dot command with =() substitution
         || line.contains("save") // nushell pipe to save
@@ -565,6 +566,10 @@ pub fn scan_for_detection_details(cmd: &str) -> Result<Vec<FileDetectionResult>,
     // Add PowerShell profiles
     config_files.extend(powershell_profile_paths(&home));
 
+    // Add xonsh and elvish rc files
+    config_files.extend(line_based_config_paths(super::Shell::Xonsh, &home));
+    config_files.extend(line_based_config_paths(super::Shell::Elvish, &home));
+
     // Deduplicate and scan
     let mut seen = HashSet::new();
     for path in config_files {
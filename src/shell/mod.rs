@@ -68,6 +68,8 @@ pub enum Shell {
     #[strum(serialize = "powershell")]
     #[cfg_attr(feature = "cli", clap(name = "powershell"))]
     PowerShell,
+    Xonsh,
+    Elvish,
 }
 
 impl Shell {
@@ -80,14 +82,16 @@ impl Shell {
             Shell::Fish,
             Shell::Nushell,
             Shell::PowerShell,
+            Shell::Xonsh,
+            Shell::Elvish,
         ]
     }
 
     /// Whether this shell uses a standalone wrapper file as integration.
     ///
     /// Wrapper-based shells (Fish, Nushell) install a complete function file that the shell
-    /// autoloads. The file itself IS the integration. Eval-based shells (Bash, Zsh, PowerShell)
-    /// add an `eval`/`source` line to an existing config file like `.bashrc`.
+    /// autoloads. The file itself IS the integration. Eval-based shells (Bash, Zsh, PowerShell,
+    /// Xonsh, Elvish) add an `eval`/`source` line to an existing config file like `.bashrc`.
     ///
     /// This distinction matters for:
     /// - Installation: wrapper files are written whole; eval lines are appended
@@ -120,6 +124,8 @@ impl Shell {
             Self::Fish => "WORKTRUNK_TEST_FISH_INSTALLED",
             Self::Nushell => "WORKTRUNK_TEST_NUSHELL_ENV",
             Self::PowerShell => "WORKTRUNK_TEST_POWERSHELL_INSTALLED",
+            Self::Xonsh => "WORKTRUNK_TEST_XONSH_INSTALLED",
+            Self::Elvish => "WORKTRUNK_TEST_ELVISH_INSTALLED",
         };
         if let Ok(val) = std::env::var(env_var) {
             return val == "1";
@@ -131,6 +137,8 @@ impl Shell {
             Self::Fish => &["fish"],
             Self::Nushell => &["nu"],
             Self::PowerShell => &["pwsh", "powershell"],
+            Self::Xonsh => &["xonsh"],
+            Self::Elvish => &["elvish"],
         };
         binaries.iter().any(|b| which::which(b).is_ok())
     }
@@ -202,6 +210,20 @@ impl Shell {
                     "if (Get-Command {cmd} -ErrorAction SilentlyContinue) {{ Invoke-Expression (& {cmd} config shell init powershell | Out-String) }}",
                 )
             }
+            Self::Xonsh => {
+                // `shutil.which` sees only PATH, so the check is for the binary
+                // even once the alias below exists.
+                format!(
+                    "if __import__(\"shutil\").which(\"{cmd}\"): execx($({cmd} config shell init xonsh))",
+                )
+            }
+            Self::Elvish => {
+                // `slurp` joins the output into one string; `eval` takes code,
+                // not a list of lines.
+                format!(
+                    "if (has-external {cmd}) {{ eval ({cmd} config shell init elvish | slurp) }}",
+                )
+            }
         }
     }
 
@@ -287,6 +309,17 @@ impl ShellInit {
                 let template = PowerShellTemplate { cmd: &self.cmd };
                 template.render()
             }
+            Shell::Xonsh => {
+                let template = XonshTemplate {
+                    cmd: &self.cmd,
+                    cmd_ident: python_ident(&self.cmd),
+                };
+                template.render()
+            }
+            Shell::Elvish => {
+                let template = ElvishTemplate { cmd: &self.cmd };
+                template.render()
+            }
         }
     }
 
@@ -318,6 +351,14 @@ pub fn clap_completer_ident(cmd: &str) -> String {
     cmd.replace('-', "_")
 }
 
+/// `cmd` as a Python identifier, for the names the xonsh template defines.
+///
+/// A command name may contain `-` and `.` (see [`validate_shell_command_name`]);
+/// neither is valid in a Python name.
+fn python_ident(cmd: &str) -> String {
+    cmd.replace(['-', '.'], "_")
+}
+
 /// Bash shell template
 #[derive(Template)]
 #[template(path = "bash.sh", escape = "none")]
@@ -367,6 +408,21 @@ struct PowerShellTemplate<'a> {
     cmd: &'a str,
 }
 
+/// Xonsh template
+#[derive(Template)]
+#[template(path = "xonsh.xsh", escape = "none")]
+struct XonshTemplate<'a> {
+    cmd: &'a str,
+    cmd_ident: String,
+}
+
+/// Elvish template
+#[derive(Template)]
+#[template(path = "elvish.elv", escape = "none")]
+struct ElvishTemplate<'a> {
+    cmd: &'a str,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,6 +442,8 @@ mod tests {
             "POWERSHELL".parse::<Shell>(),
             Ok(Shell::PowerShell)
         ));
+        assert!(matches!("xonsh".parse::<Shell>(), Ok(Shell::Xonsh)));
+        assert!(matches!("elvish".parse::<Shell>(), Ok(Shell::Elvish)));
         assert!("invalid".parse::<Shell>().is_err());
     }
 
@@ -395,6 +453,8 @@ mod tests {
         assert_eq!(Shell::Fish.to_string(), "fish");
         assert_eq!(Shell::Zsh.to_string(), "zsh");
         assert_eq!(Shell::PowerShell.to_string(), "powershell");
+        assert_eq!(Shell::Xonsh.to_string(), "xonsh");
+        assert_eq!(Shell::Elvish.to_string(), "elvish");
     }
 
     #[test]
@@ -407,6 +467,8 @@ mod tests {
             "config_line_powershell",
             Shell::PowerShell.config_line("wt")
         );
+        insta::assert_snapshot!("config_line_xonsh", Shell::Xonsh.config_line("wt"));
+        insta::assert_snapshot!("config_line_elvish", Shell::Elvish.config_line("wt"));
     }
 
     #[test]
@@ -425,6 +487,14 @@ mod tests {
             "config_line_powershell_custom",
             Shell::PowerShell.config_line("git-wt")
         );
+        insta::assert_snapshot!(
+            "config_line_xonsh_custom",
+            Shell::Xonsh.config_line("git-wt")
+        );
+        insta::assert_snapshot!(
+            "config_line_elvish_custom",
+            Shell::Elvish.config_line("git-wt")
+        );
     }
 
     #[test]
@@ -435,6 +505,8 @@ mod tests {
             Shell::Fish,
             Shell::Nushell,
             Shell::PowerShell,
+            Shell::Xonsh,
+            Shell::Elvish,
         ] {
             let init = ShellInit::with_prefix(shell, "wt".to_string());
             let output = init.generate().expect("Failed to generate");
@@ -502,6 +574,8 @@ mod tests {
             Shell::Fish,
             Shell::Nushell,
             Shell::PowerShell,
+            Shell::Xonsh,
+            Shell::Elvish,
         ];
         for shell in shells {
             let result = shell.config_paths("wt");
@@ -524,6 +598,8 @@ mod tests {
             Shell::Fish,
             Shell::Nushell,
             Shell::PowerShell,
+            Shell::Xonsh,
+            Shell::Elvish,
         ];
         for shell in shells {
             let result = shell.completion_path("wt");
@@ -652,7 +728,9 @@ mod tests {
             Shell::Zsh,
             Shell::Fish,
             Shell::Nushell,
-            Shell::PowerShell
+            Shell::PowerShell,
+            Shell::Xonsh,
+            Shell::Elvish
         )]
         shell: Shell,
         #[values("wt", "git-wt")] prefix: &str,
@@ -209,9 +209,31 @@ pub fn powershell_profile_paths(home: &std::path::Path) -> Vec<PathBuf> {
     }
 }
 
+/// Elvish's rc file locations, current first.
+///
+/// Elvish reads `rc.elv` from its config directory (`$XDG_CONFIG_HOME/elvish`,
+/// `~/.config/elvish`, or `%RoamingAppData%\elvish` on Windows); versions
+/// before 0.17 read `~/.elvish/rc.elv`, which is kept so an existing file there
+/// is the one updated.
+fn elvish_rc_paths(home: &std::path::Path) -> Vec<PathBuf> {
+    let config_dir = if cfg!(windows) {
+        dirs::config_dir().unwrap_or_else(|| home.join("AppData").join("Roaming"))
+    } else {
+        std::env::var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .ok()
+            .filter(|p| p.is_absolute())
+            .unwrap_or_else(|| home.join(".config"))
+    };
+    vec![
+        config_dir.join("elvish").join("rc.elv"),
+        home.join(".elvish").join("rc.elv"),
+    ]
+}
+
 /// Rc/profile files scanned line-by-line for integration lines.
 ///
-/// Bash/Zsh/PowerShell integration is one line in an rc file, so these paths
+/// Bash/Zsh/PowerShell/Xonsh/Elvish integration is one line in an rc file, so these paths
 /// are independent of the binary name; Fish and Nushell use per-name wrapper
 /// files instead and have no line-based config. `config_paths` builds on this
 /// for the line-based shells, so install and uninstall cannot drift apart.
@@ -228,6 +250,14 @@ pub fn line_based_config_paths(shell: super::Shell, home: &std::path::Path) -> V
             vec![zdotdir.join(".zshrc")]
         }
         super::Shell::PowerShell => powershell_profile_paths(home),
+        super::Shell::Xonsh => {
+            // xonsh reads both; `~/.xonshrc` is the long-standing default
+            vec![
+                home.join(".xonshrc"),
+                home.join(".config").join("xonsh").join("rc.xsh"),
+            ]
+        }
+        super::Shell::Elvish => elvish_rc_paths(home),
         super::Shell::Fish | super::Shell::Nushell => Vec::new(),
     }
 }
@@ -240,9 +270,11 @@ pub(super) fn config_paths(shell: super::Shell, cmd: &str) -> Result<Vec<PathBuf
     let home = home_dir_required()?;
 
     Ok(match shell {
-        super::Shell::Bash | super::Shell::Zsh | super::Shell::PowerShell => {
-            line_based_config_paths(shell, &home)
-        }
+        super::Shell::Bash
+        | super::Shell::Zsh
+        | super::Shell::PowerShell
+        | super::Shell::Xonsh
+        | super::Shell::Elvish => line_based_config_paths(shell, &home),
         super::Shell::Fish => {
             // For fish, we write to functions/ which is autoloaded on first use.
             // This ensures PATH is fully configured before our function loads,
@@ -334,6 +366,11 @@ pub(super) fn completion_path(shell: super::Shell, cmd: &str) -> Result<PathBuf,
             // Return a dummy path that won't be used
             home.join(format!(".{}-powershell-completions", cmd))
         }
+        super::Shell::Xonsh | super::Shell::Elvish => {
+            // Completions are registered inline by the init script, so the
+            // rc file that loads it is where they live
+            line_based_config_paths(shell, &home).swap_remove(0)
+        }
     })
 }
 
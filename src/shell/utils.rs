@@ -44,6 +44,8 @@ pub fn shell_from_name(shell_name: &str) -> Option<Shell> {
         ("bash", Shell::Bash),
         ("zsh", Shell::Zsh),
         ("fish", Shell::Fish),
+        ("xonsh", Shell::Xonsh),
+        ("elvish", Shell::Elvish),
     ] {
         if name_matches_shell(&name_lower, prefix) {
             return Some(shell);
@@ -124,7 +126,7 @@ const TRANSPARENT_INTERPRETERS: &[&str] = &["sh", "dash", "ash", "busybox"];
 /// letting a supported shell further up the tree (or `$SHELL`) claim the
 /// session.
 const UNSUPPORTED_SHELLS: &[&str] = &[
-    "tcsh", "csh", "ksh", "mksh", "oksh", "loksh", "yash", "elvish", "xonsh", "oil", "osh",
+    "tcsh", "csh", "ksh", "mksh", "oksh", "loksh", "yash", "oil", "osh",
 ];
 
 /// True when `name` is `prefix` optionally followed by a version-ish suffix:
@@ -487,6 +489,8 @@ mod tests {
     #[case::powershell("powershell", Some(Shell::PowerShell))]
     #[case::pwsh("pwsh", Some(Shell::PowerShell))]
     #[case::pwsh_preview("pwsh-preview", Some(Shell::PowerShell))]
+    #[case::xonsh("xonsh", Some(Shell::Xonsh))]
+    #[case::elvish("elvish", Some(Shell::Elvish))]
     #[case::unknown("tcsh", None)]
     #[case::unknown_csh("csh", None)]
     // Names that merely start with a shell name must not match — the
@@ -509,6 +513,7 @@ mod tests {
     #[case::tcsh("tcsh", Some(None))]
     #[case::ksh("ksh", Some(None))]
     #[case::ksh93("ksh93", Some(None))]
+    #[case::elvish("elvish", Some(Some(Shell::Elvish)))]
     #[case::sh_transparent("sh", None)]
     #[case::dash_transparent("dash", None)]
     #[case::git_transparent("git", None)]
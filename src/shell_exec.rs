@@ -1107,6 +1107,8 @@ pub struct Cmd {
     /// When set, log this command to the command log after execution.
     /// The label identifies what triggered the command (e.g., "pre-merge user:lint").
     external_label: Option<String>,
+    /// Branch recorded with the command-log entry (see [`Cmd::external_branch`]).
+    external_branch: Option<String>,
     /// When set, re-adds `WORKTRUNK_DIRECTIVE_CD_FILE` after the security scrub
     /// in `apply_common_settings`. Used by aliases and foreground hooks — their
     /// shell bodies may emit cd directives (the file holds a raw path, no shell
@@ -1122,15 +1124,17 @@ pub struct Cmd {
 
 struct ExternalCommandLog {
     label: Option<String>,
+    branch: Option<String>,
     cmd_str: String,
     started_at: Option<Instant>,
 }
 
 impl ExternalCommandLog {
-    fn new(label: Option<String>, cmd_str: String) -> Self {
+    fn new(label: Option<String>, branch: Option<String>, cmd_str: String) -> Self {
         let started_at = label.as_ref().map(|_| Instant::now());
         Self {
             label,
+            branch,
             cmd_str,
             started_at,
         }
@@ -1139,7 +1143,13 @@ impl ExternalCommandLog {
     fn record(&self, exit_code: Option<i32>) {
         if let Some(label) = &self.label {
             let duration = self.started_at.as_ref().map(Instant::elapsed);
-            crate::command_log::log_command(label, &self.cmd_str, exit_code, duration);
+            crate::command_log::log_command(
+                label,
+                self.branch.as_deref(),
+                &self.cmd_str,
+                exit_code,
+                duration,
+            );
         }
     }
 }
@@ -1257,6 +1267,7 @@ impl Cmd {
             share_parent_pgroup: false,
             forward_signals: false,
             external_label: None,
+            external_branch: None,
             directive_cd_file: None,
             directive_exec_file: None,
         }
@@ -1523,6 +1534,14 @@ impl Cmd {
         self
     }
 
+    /// Record the branch the command ran for with its command-log entry, so
+    /// `wt config state logs history --branch` can find it. Only meaningful
+    /// alongside [`external`](Self::external).
+    pub fn external_branch(mut self, branch: Option<&str>) -> Self {
+        self.external_branch = branch.map(str::to_string);
+        self
+    }
+
     /// Pass the CD directive file through to the child process.
     ///
     /// By default, `Cmd` scrubs all directive file env vars from child
@@ -1570,14 +1589,19 @@ impl Cmd {
         );
 
         let cmd_str = self.command_string();
-        let external_log = ExternalCommandLog::new(self.external_label.clone(), cmd_str.clone());
+        let external_log = ExternalCommandLog::new(
+            self.external_label.clone(),
+            self.external_branch.clone(),
+            cmd_str.clone(),
+        );
         self.log_run_start(&cmd_str);
 
         // Limit concurrent commands (background threads only; see CMD_SEMAPHORE)
         let _guard = (!is_foreground_thread()).then(|| semaphore().acquire());
 
         let mut trace = CommandTrace::new(self.context.as_deref(), &cmd_str)
-            .reads_stdin(self.stdin_data.is_some());
+            .reads_stdin(self.stdin_data.is_some())
+            .label(self.external_label.as_deref());
 
         // Checked after the permit, not before: a command can be cancelled
         // while parked on the semaphore, and that is the common case in a
@@ -1910,7 +1934,11 @@ impl Cmd {
         };
 
         let cmd_str = self.command_string();
-        let external_log = ExternalCommandLog::new(self.external_label.take(), cmd_str.clone());
+        let external_log = ExternalCommandLog::new(
+            self.external_label.take(),
+            self.external_branch.take(),
+            cmd_str.clone(),
+        );
         self.log_stream_start(&cmd_str, &exec_mode);
         self.apply_common_settings(&mut cmd);
 
@@ -1987,7 +2015,8 @@ impl Cmd {
         // signal-handler install — so a pre-spawn early return can't drop the
         // guard unresolved, and the duration brackets the child.
         let mut trace = CommandTrace::new(self.context.as_deref(), &cmd_str)
-            .reads_stdin(self.stdin_data.is_some());
+            .reads_stdin(self.stdin_data.is_some())
+            .label(external_log.label.as_deref());
         let mut child = match cmd.spawn() {
             Ok(child) => child,
             Err(e) => {
@@ -2144,7 +2173,8 @@ impl Cmd {
         let cmd_str = self.command_string();
         self.log_delayed_stream_start(&cmd_str, delay_ms);
 
-        let mut trace = CommandTrace::new(self.context.as_deref(), &cmd_str);
+        let mut trace = CommandTrace::new(self.context.as_deref(), &cmd_str)
+            .label(self.external_label.as_deref());
 
         let mut cmd = self.direct_command();
         self.apply_common_settings(&mut cmd);
@@ -0,0 +1,507 @@
+//! Content-addressed file store shared by worktrees.
+//!
+//! On filesystems without reflink (ext4, NFS), [`crate::copy`] falls back to
+//! full copies, so every worktree holds its own multi-GB `target/`. The store
+//! under `.git/wt/store/` deduplicates those copies: each regular file is
+//! hashed (SHA-256), its contents land once under
+//! `objects/<2 hex>/<62 hex>`, and worktrees get a hardlink to that object.
+//!
+//! Objects are read-only. A hardlink shares its inode, so a tool writing a
+//! linked file in place would change it in every worktree; with the write bits
+//! cleared, an in-place write fails instead, while tools that replace their
+//! outputs (write a new file, rename over the old one) give that worktree its
+//! own copy on first write. The executable bit is part of an object's identity
+//! (`<hash>.x`), since every link shares one mode.
+//!
+//! An object's link count is its reference count. Once no worktree links it —
+//! the worktree was removed, or a build replaced the file — only the store's
+//! own entry remains, and [`Store::gc`] drops it. Link counts are a Unix
+//! notion; elsewhere `gc` keeps every object.
+//!
+//! Where a hardlink is impossible (store and worktree on different
+//! filesystems, or a filesystem without hardlinks), the leaf is copied from the
+//! object instead: it still lands, just without sharing.
+
+use std::fs;
+use std::io::{self, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::Context;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::copy::{copy_dir_recursive_with, copy_leaf, ensure_path_within_root, remove_if_exists};
+use crate::git::Repository;
+use crate::progress::Progress;
+
+/// Suffix marking an object stored with its executable bits set.
+const EXECUTABLE_SUFFIX: &str = ".x";
+
+/// How often a file is stored again after a concurrent [`Store::gc`] removed
+/// its object (or the object's fan-out directory) mid-link.
+const MAX_INSERT_ATTEMPTS: usize = 3;
+
+/// A content-addressed store rooted at a directory (normally `.git/wt/store/`).
+///
+/// Tracks the bytes it added during its lifetime, so a caller can report how
+/// much of a copy was new content versus links to existing objects.
+#[derive(Debug)]
+pub struct Store {
+    root: PathBuf,
+    added_bytes: AtomicU64,
+}
+
+/// Object count and size, as reported by [`Store::usage`] and [`Store::gc`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct StoreUsage {
+    pub objects: usize,
+    pub bytes: u64,
+    /// Objects no worktree links any more — what `gc` would drop.
+    pub unreferenced: usize,
+    pub unreferenced_bytes: u64,
+}
+
+impl Store {
+    /// The repository's store: `<git-common-dir>/wt/store/`.
+    pub fn for_repo(repo: &Repository) -> Self {
+        Self::at(repo.wt_dir().join("store"))
+    }
+
+    pub fn at(root: PathBuf) -> Self {
+        Self {
+            root,
+            added_bytes: AtomicU64::new(0),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn objects_dir(&self) -> PathBuf {
+        self.root.join("objects")
+    }
+
+    /// Bytes of new content this handle added to the store.
+    pub fn added_bytes(&self) -> u64 {
+        self.added_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Materialize `src` at `dest` as a hardlink to its store object.
+    ///
+    /// Same contract as [`copy_leaf`]: `Some(bytes)` when the leaf was
+    /// written, `None` when it was skipped because `dest` exists (without
+    /// `force`) or `src` vanished. Symlinks aren't stored; they're copied as
+    /// `copy_leaf` would.
+    pub fn link_leaf(
+        &self,
+        src: &Path,
+        dest: &Path,
+        root: Option<&Path>,
+        force: bool,
+    ) -> anyhow::Result<Option<u64>> {
+        if let Some(root) = root {
+            ensure_path_within_root(dest.parent().unwrap_or(dest), root)?;
+        }
+        if !force && dest.symlink_metadata().is_ok() {
+            return Ok(None);
+        }
+        let src_meta = match src.symlink_metadata() {
+            Ok(meta) => meta,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                tracing::debug!(path = %src.display(), "skipping vanished source: {}", src.display());
+                return Ok(None);
+            }
+            Err(e) => {
+                return Err(anyhow::Error::from(e)
+                    .context(format!("reading metadata for {}", src.display())));
+            }
+        };
+        if !src_meta.is_file() {
+            return copy_leaf(src, dest, None, force);
+        }
+
+        let mut removed_dest = false;
+        let mut attempts = 0;
+        loop {
+            // Store the object before touching the destination, so a source
+            // that vanishes mid-read costs nothing at `dest`.
+            let object = match self.insert(src, &src_meta) {
+                Ok(object) => object,
+                Err(e) if e.kind() == ErrorKind::NotFound => {
+                    tracing::debug!(path = %src.display(), "skipping vanished source: {}", src.display());
+                    return Ok(None);
+                }
+                Err(e) => {
+                    return Err(
+                        anyhow::Error::from(e).context(format!("storing {}", src.display()))
+                    );
+                }
+            };
+
+            if force && !removed_dest {
+                remove_if_exists(dest)?;
+                removed_dest = true;
+            }
+            match fs::hard_link(&object, dest) {
+                Ok(()) => {}
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    tracing::debug!(path = %dest.display(), "skipping existing destination: {}", dest.display());
+                    return Ok(None);
+                }
+                // A concurrent `gc` dropped the object between `insert`
+                // finding it and the link; store it again.
+                Err(e)
+                    if e.kind() == ErrorKind::NotFound
+                        && attempts < MAX_INSERT_ATTEMPTS
+                        && object.symlink_metadata().is_err() =>
+                {
+                    tracing::debug!(object = %object.display(), "object removed by gc, storing again");
+                    attempts += 1;
+                    continue;
+                }
+                Err(e) => {
+                    tracing::debug!(path = %dest.display(), "hardlink failed, copying instead: {e}");
+                    reflink_copy::reflink_or_copy(&object, dest)
+                        .with_context(|| format!("copying {}", src.display()))?;
+                    // A private copy is writable like its source.
+                    fs::set_permissions(dest, src_meta.permissions())
+                        .context("setting destination file permissions")?;
+                }
+            }
+            return Ok(Some(src_meta.len()));
+        }
+    }
+
+    /// [`crate::copy::copy_dir_recursive`], with every regular file
+    /// materialized through [`Store::link_leaf`].
+    #[must_use = "a caller that deletes the source must refuse on a non-zero skip count"]
+    pub fn link_dir_recursive(
+        &self,
+        src: &Path,
+        dest: &Path,
+        root: Option<&Path>,
+        force: bool,
+        progress: &Progress,
+    ) -> anyhow::Result<usize> {
+        copy_dir_recursive_with(src, dest, root, force, progress, |src, dest, force| {
+            self.link_leaf(src, dest, None, force)
+        })
+    }
+
+    /// Add `src`'s contents to the store, returning the object path.
+    ///
+    /// The content is hashed once to find an existing object; a miss copies
+    /// the file into `tmp/`, hashing again as it writes, and publishes it
+    /// under the hash of what was actually copied — a source rewritten
+    /// between the two reads still lands under its true key. Publishing is a
+    /// hardlink, so two writers racing on the same content both end up
+    /// pointing at the first one's object.
+    fn insert(&self, src: &Path, src_meta: &fs::Metadata) -> io::Result<PathBuf> {
+        let executable = is_executable(src_meta);
+        let (hash, _) = read_hashing(src, |_| Ok(()))?;
+        let object = self.object_path(&hash, executable);
+        if object.symlink_metadata().is_ok() {
+            return Ok(object);
+        }
+
+        let tmp_dir = self.root.join("tmp");
+        fs::create_dir_all(&tmp_dir)?;
+        let mut tmp = tempfile::NamedTempFile::new_in(&tmp_dir)?;
+        let (hash, len) = read_hashing(src, |chunk| tmp.write_all(chunk))?;
+        if let Ok(mtime) = src_meta.modified() {
+            tmp.as_file().set_modified(mtime)?;
+        }
+        tmp.as_file()
+            .set_permissions(read_only_permissions(src_meta))?;
+
+        let object = self.object_path(&hash, executable);
+        let mut attempts = 0;
+        loop {
+            if let Some(parent) = object.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            match fs::hard_link(tmp.path(), &object) {
+                Ok(()) => {
+                    self.added_bytes.fetch_add(len, Ordering::Relaxed);
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+                // A concurrent `gc` removed the emptied fan-out directory
+                // between `create_dir_all` and the link; create it again.
+                Err(e)
+                    if e.kind() == ErrorKind::NotFound
+                        && attempts < MAX_INSERT_ATTEMPTS
+                        && tmp.path().exists() =>
+                {
+                    attempts += 1;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            }
+            return Ok(object);
+        }
+    }
+
+    fn object_path(&self, hash: &str, executable: bool) -> PathBuf {
+        let (fanout, rest) = hash.split_at(2);
+        let name = if executable {
+            format!("{rest}{EXECUTABLE_SUFFIX}")
+        } else {
+            rest.to_string()
+        };
+        self.objects_dir().join(fanout).join(name)
+    }
+
+    /// Count objects and their size, and how many no worktree references.
+    pub fn usage(&self) -> anyhow::Result<StoreUsage> {
+        let mut usage = StoreUsage::default();
+        for (_, meta) in self.objects()? {
+            usage.objects += 1;
+            usage.bytes += meta.len();
+            if !is_referenced(&meta) {
+                usage.unreferenced += 1;
+                usage.unreferenced_bytes += meta.len();
+            }
+        }
+        Ok(usage)
+    }
+
+    /// Drop objects no worktree links any more, returning what was removed
+    /// (`objects`/`bytes`; the `unreferenced` fields stay zero).
+    ///
+    /// Also sweeps `tmp/` leftovers from interrupted inserts.
+    pub fn gc(&self) -> anyhow::Result<StoreUsage> {
+        let mut removed = StoreUsage::default();
+        for (path, meta) in self.objects()? {
+            if is_referenced(&meta) {
+                continue;
+            }
+            match fs::remove_file(&path) {
+                Ok(()) => {
+                    removed.objects += 1;
+                    removed.bytes += meta.len();
+                }
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => {
+                    return Err(
+                        anyhow::Error::from(e).context(format!("removing {}", path.display()))
+                    );
+                }
+            }
+            if let Some(parent) = path.parent() {
+                // Only succeeds once the fan-out directory is empty.
+                let _ = fs::remove_dir(parent);
+            }
+        }
+        let _ = fs::remove_dir_all(self.root.join("tmp"));
+        Ok(removed)
+    }
+
+    /// Remove the whole store, returning how many objects it held.
+    ///
+    /// Worktrees keep their linked files — removing the store only drops its
+    /// own link to each object.
+    pub fn clear(&self) -> anyhow::Result<usize> {
+        let objects = self.objects()?.len();
+        match fs::remove_dir_all(&self.root) {
+            Ok(()) => Ok(objects),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(0),
+            Err(e) => {
+                Err(anyhow::Error::from(e).context(format!("removing {}", self.root.display())))
+            }
+        }
+    }
+
+    /// Every object file with its metadata. An absent store has none.
+    fn objects(&self) -> anyhow::Result<Vec<(PathBuf, fs::Metadata)>> {
+        let objects_dir = self.objects_dir();
+        let fanouts = match fs::read_dir(&objects_dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(
+                    anyhow::Error::from(e).context(format!("reading {}", objects_dir.display()))
+                );
+            }
+        };
+        let mut out = Vec::new();
+        for fanout in fanouts.flatten() {
+            let Ok(entries) = fs::read_dir(fanout.path()) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                if let Ok(meta) = entry.path().symlink_metadata()
+                    && meta.is_file()
+                {
+                    out.push((entry.path(), meta));
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Read `path` to the end, passing each chunk to `sink`; returns the
+/// SHA-256 of what was read and its length.
+fn read_hashing(
+    path: &Path,
+    mut sink: impl FnMut(&[u8]) -> io::Result<()>,
+) -> io::Result<(String, u64)> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    let mut len = 0u64;
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        sink(&buf[..n])?;
+        len += n as u64;
+    }
+    Ok((hex(&hasher.finalize()), len))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    use std::fmt::Write as _;
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        let _ = write!(out, "{b:02x}");
+    }
+    out
+}
+
+#[cfg(unix)]
+fn is_executable(meta: &fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    meta.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(_meta: &fs::Metadata) -> bool {
+    false
+}
+
+/// Read (and, for executables, execute) for everyone; write for no one.
+#[cfg(unix)]
+fn read_only_permissions(src_meta: &fs::Metadata) -> fs::Permissions {
+    use std::os::unix::fs::PermissionsExt;
+    let exec = if is_executable(src_meta) { 0o111 } else { 0 };
+    fs::Permissions::from_mode(0o444 | exec)
+}
+
+#[cfg(not(unix))]
+fn read_only_permissions(src_meta: &fs::Metadata) -> fs::Permissions {
+    let mut perms = src_meta.permissions();
+    perms.set_readonly(true);
+    perms
+}
+
+/// Whether any link besides the store's own entry points at this object.
+#[cfg(unix)]
+fn is_referenced(meta: &fs::Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    meta.nlink() > 1
+}
+
+#[cfg(not(unix))]
+fn is_referenced(_meta: &fs::Metadata) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn link_leaf_dedups_and_gc_drops_unreferenced() {
+        use std::os::unix::fs::MetadataExt;
+
+        let temp = tempfile::tempdir().unwrap();
+        let store = Store::at(temp.path().join("store"));
+        let src = temp.path().join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("a.bin"), "same bytes").unwrap();
+        fs::write(src.join("b.bin"), "same bytes").unwrap();
+
+        let wt1 = temp.path().join("wt1");
+        let wt2 = temp.path().join("wt2");
+        for wt in [&wt1, &wt2] {
+            let _ = store
+                .link_dir_recursive(&src, wt, None, false, &Progress::disabled())
+                .unwrap();
+        }
+
+        // Four leaves, one object; only the first insert added bytes.
+        let usage = store.usage().unwrap();
+        assert_eq!(usage.objects, 1);
+        assert_eq!(usage.bytes, 10);
+        assert_eq!(usage.unreferenced, 0);
+        assert_eq!(store.added_bytes(), 10);
+        assert_eq!(fs::metadata(wt1.join("a.bin")).unwrap().nlink(), 5);
+        assert!(
+            fs::metadata(wt2.join("b.bin"))
+                .unwrap()
+                .permissions()
+                .readonly()
+        );
+        assert_eq!(fs::read_to_string(wt2.join("b.bin")).unwrap(), "same bytes");
+
+        // While any worktree still links the object, gc keeps it.
+        fs::remove_dir_all(&wt1).unwrap();
+        assert_eq!(store.gc().unwrap().objects, 0);
+        fs::remove_dir_all(&wt2).unwrap();
+        assert_eq!(store.usage().unwrap().unreferenced, 1);
+        let removed = store.gc().unwrap();
+        assert_eq!((removed.objects, removed.bytes), (1, 10));
+        assert_eq!(store.usage().unwrap(), StoreUsage::default());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn executable_bit_is_part_of_the_key() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempfile::tempdir().unwrap();
+        let store = Store::at(temp.path().join("store"));
+        let plain = temp.path().join("plain");
+        let script = temp.path().join("script");
+        fs::write(&plain, "#!/bin/sh\n").unwrap();
+        fs::write(&script, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        store
+            .link_leaf(&plain, &temp.path().join("plain-link"), None, false)
+            .unwrap();
+        store
+            .link_leaf(&script, &temp.path().join("script-link"), None, false)
+            .unwrap();
+
+        assert_eq!(store.usage().unwrap().objects, 2);
+        let mode = fs::metadata(temp.path().join("script-link"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o555);
+    }
+
+    #[test]
+    fn link_leaf_skips_existing_without_force() {
+        let temp = tempfile::tempdir().unwrap();
+        let store = Store::at(temp.path().join("store"));
+        let src = temp.path().join("src.txt");
+        let dest = temp.path().join("dest.txt");
+        fs::write(&src, "new").unwrap();
+        fs::write(&dest, "old").unwrap();
+
+        assert_eq!(store.link_leaf(&src, &dest, None, false).unwrap(), None);
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "old");
+        assert_eq!(store.link_leaf(&src, &dest, None, true).unwrap(), Some(3));
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "new");
+        assert_eq!(store.clear().unwrap(), 1);
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "new");
+    }
+}
@@ -38,7 +38,7 @@ pub use format::{
 pub use highlighting::format_toml;
 pub use hyperlink::{Stream, hyperlink, strip_osc8_hyperlinks, supports_hyperlinks};
 pub use line::{StyledLine, StyledString, truncate_visible};
-pub use suggest::{suggest_command, suggest_command_in_dir};
+pub use suggest::{did_you_mean, suggest_command, suggest_command_in_dir};
 
 // ============================================================================
 // Verbosity
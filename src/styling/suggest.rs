@@ -36,6 +36,9 @@
 //! let cmd = suggest_command_in_dir(Path::new("/tmp/repo"), "config", &["update"], &[]);
 //! assert_eq!(cmd, "wt -C /tmp/repo config update");
 //! ```
+//!
+//! [`did_you_mean`] ranks near-miss names (subcommands, aliases, config keys)
+//! for "did you mean" tips.
 
 use shell_escape::unix::escape;
 use std::borrow::Cow;
@@ -80,6 +83,26 @@ pub fn suggest_command_in_dir(
     format_command(Some(working_dir), subcommand, args, flags)
 }
 
+/// Return candidates similar to `query`, sorted by descending Jaro–Winkler
+/// similarity, filtered by `score > 0.7`, and deduplicated while preserving
+/// order. The 0.7 threshold matches clap's internal `did_you_mean` so
+/// wt-synthesized "unrecognized subcommand" tips read identically to clap's
+/// native output — keep them aligned if clap ever changes it.
+pub fn did_you_mean(query: &str, candidates: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut scored: Vec<(f64, String)> = candidates
+        .into_iter()
+        .map(|candidate| (strsim::jaro_winkler(query, &candidate), candidate))
+        .filter(|(score, _)| *score > 0.7)
+        .collect();
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    let mut seen = std::collections::HashSet::new();
+    scored
+        .into_iter()
+        .filter(|(_, n)| seen.insert(n.clone()))
+        .map(|(_, n)| n)
+        .collect()
+}
+
 fn format_command(
     working_dir: Option<&Path>,
     subcommand: &str,
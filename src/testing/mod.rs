@@ -984,6 +984,8 @@ pub fn configure_cli_command(cmd: &mut Command) {
     // of debug-level `[wt-trace]` records. Tests that need warn-level output
     // can opt in after command construction.
     cmd.env_remove("RUST_LOG");
+    // Pin the hostname approval records carry
+    cmd.env("WORKTRUNK_TEST_HOSTNAME", "test-host");
     // Treat Claude as not installed by default (tests can override with "1")
     cmd.env("WORKTRUNK_TEST_CLAUDE_INSTALLED", "0");
     // Treat Codex as not installed by default (tests can override with "1")
@@ -187,6 +187,7 @@ mod tests {
                 duration: Duration::from_millis(duration_ms),
                 result: TraceResult::Completed { success: true },
                 reads_stdin: false,
+                label: None,
             },
             start_time_us,
             thread_id,
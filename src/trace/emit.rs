@@ -22,14 +22,21 @@
 //! # Fields
 //!
 //! Typed structured fields on each event: `kind`, `ts`, `tid`, `seq`, `cmd`,
-//! `dur_us`, `ok`, `err`, `event`, `span`, `context`, `stdin`. `seq` is a
+//! `dur_us`, `ok`, `err`, `event`, `span`, `context`, `stdin`, `label`. `seq` is a
 //! process-global monotonic command counter (command records only); the same
 //! value is printed into the per-command header in `subprocess.log`, so a raw
 //! output block there joins back to its command record via the `seq` field in
 //! `trace.jsonl`. `stdin` (command records, [`CommandTrace::reads_stdin`])
 //! marks a command that consumed stdin the `cmd` string doesn't capture, so the
 //! cache analysis in `super::profile` can skip it — `trace.jsonl` carries it;
-//! the human line omits it.
+//! the human line omits it. `label` (command records, omitted when unset) is
+//! the command-log label of a user-configured command — `pre-merge user:lint`
+//! for a hook step, `commit.generation` for an LLM call — so exporters can
+//! tell those apart from wt's own subprocesses.
+//!
+//! One `session` record per run ([`session`]) anchors the monotonic `ts`
+//! clock to wall time and names the invocation: `unix_us`, `pid`, `cmd` (the
+//! command line), `subcommand`, `repo`, `branch`.
 //!
 //! This split — structured fields at the emission site, rendering at the
 //! layer — means emit sites carry no string-formatting noise.
@@ -134,6 +141,7 @@ static CMD_SEQ: AtomicU64 = AtomicU64::new(1);
 fn command_completed(trace: &CommandTrace, dur_us: u64, ok: bool) {
     let cmd = trace.cmd.as_str();
     let (ts, tid, seq, stdin) = (trace.start_ts_us, trace.tid, trace.seq, trace.reads_stdin);
+    let label = trace.label.as_deref();
     match trace.context.as_deref() {
         Some(ctx) => tracing::debug!(
             target: WT_TRACE_TARGET,
@@ -146,6 +154,7 @@ fn command_completed(trace: &CommandTrace, dur_us: u64, ok: bool) {
             dur_us,
             ok,
             stdin,
+            label,
         ),
         None => tracing::debug!(
             target: WT_TRACE_TARGET,
@@ -157,6 +166,7 @@ fn command_completed(trace: &CommandTrace, dur_us: u64, ok: bool) {
             dur_us,
             ok,
             stdin,
+            label,
         ),
     }
 }
@@ -169,6 +179,7 @@ fn command_errored(trace: &CommandTrace, dur_us: u64, err: impl Display) {
     let err = err.to_string();
     let cmd = trace.cmd.as_str();
     let (ts, tid, seq, stdin) = (trace.start_ts_us, trace.tid, trace.seq, trace.reads_stdin);
+    let label = trace.label.as_deref();
     match trace.context.as_deref() {
         Some(ctx) => tracing::debug!(
             target: WT_TRACE_TARGET,
@@ -181,6 +192,7 @@ fn command_errored(trace: &CommandTrace, dur_us: u64, err: impl Display) {
             dur_us,
             err = %err,
             stdin,
+            label,
         ),
         None => tracing::debug!(
             target: WT_TRACE_TARGET,
@@ -192,6 +204,7 @@ fn command_errored(trace: &CommandTrace, dur_us: u64, err: impl Display) {
             dur_us,
             err = %err,
             stdin,
+            label,
         ),
     }
 }
@@ -236,6 +249,8 @@ pub struct CommandTrace {
     /// cache analysis never treats it as a duplicate — its real input isn't in
     /// `cmd`, so two runs with identical `cmd` may be entirely different work.
     reads_stdin: bool,
+    /// Command-log label of a user-configured command (hook step, LLM call).
+    label: Option<String>,
     resolved: bool,
 }
 
@@ -251,6 +266,7 @@ impl CommandTrace {
             tid: thread_id(),
             seq: CMD_SEQ.fetch_add(1, Ordering::Relaxed),
             reads_stdin: false,
+            label: None,
             resolved: false,
         }
     }
@@ -264,6 +280,14 @@ impl CommandTrace {
         self
     }
 
+    /// Label the record with the command-log label of a user-configured
+    /// command (`pre-merge user:lint`, `commit.generation`). `None` leaves the
+    /// record unlabeled — wt's own subprocesses.
+    pub fn label(mut self, label: Option<&str>) -> Self {
+        self.label = label.map(ToOwned::to_owned);
+        self
+    }
+
     /// The command's monotonic sequence number — the key shared by this
     /// command's `trace.jsonl` record and its raw output block
     /// (`subprocess.log`).
@@ -343,6 +367,29 @@ pub fn instant(event: &str) {
     );
 }
 
+/// Emit the `session` record that anchors this run's trace: the wall-clock
+/// time matching the current `ts`, the process, the command line, and the
+/// repo and branch it ran in. Call once, early; exporters convert every
+/// other record's `ts` to wall time through it.
+pub fn session(cmd: &str, subcommand: Option<&str>, repo: Option<&str>, branch: Option<&str>) {
+    let unix_us = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_micros() as u64)
+        .unwrap_or(0);
+    tracing::debug!(
+        target: WT_TRACE_TARGET,
+        kind = "session",
+        ts = now_us(),
+        tid = thread_id(),
+        unix_us,
+        pid = u64::from(std::process::id()),
+        cmd,
+        subcommand,
+        repo,
+        branch,
+    );
+}
+
 /// Emit a completed in-process span (a named region of code that ran).
 ///
 /// Spans are the in-process counterpart to `command_completed`: subprocess
@@ -11,6 +11,8 @@
 //! - [`timeline`] — the per-record view behind `wt-perf timeline`
 //! - [`chrome`] — Chrome Trace Format export for visual critical-path
 //!   inspection in <https://ui.perfetto.dev> or chrome://tracing
+//! - [`otlp`] — OpenTelemetry (OTLP/JSON) export behind
+//!   `wt config state logs export`, for a tracing backend
 //!
 //! Capture with `wt -vv <cmd>`, or let the `wt-perf timeline` helper run the
 //! capture and render in one step (`cargo run -p wt-perf -- timeline -- list
@@ -18,13 +20,17 @@
 
 pub mod chrome;
 pub mod emit;
+pub mod otlp;
 pub mod parse;
 pub mod profile;
 pub mod timeline;
 
 // Re-export main types for convenience
 pub use chrome::to_chrome_trace;
-pub use emit::{CommandTrace, Span, WT_TRACE_TARGET, instant, now_us, thread_id};
-pub use parse::{TraceEntry, TraceEntryKind, TraceResult, parse_lines};
+pub use emit::{CommandTrace, Span, WT_TRACE_TARGET, instant, now_us, session, thread_id};
+pub use otlp::to_otlp_json;
+pub use parse::{
+    TraceEntry, TraceEntryKind, TraceResult, TraceSession, parse_lines, parse_session,
+};
 pub use profile::{CacheReport, Profile};
 pub use timeline::render_timeline;
@@ -0,0 +1,398 @@
+//! OpenTelemetry (OTLP/JSON) export of a captured trace.
+//!
+//! Converts trace entries to an OTLP `ExportTraceServiceRequest` — the JSON
+//! body an OpenTelemetry collector accepts at `POST /v1/traces` — so a `wt`
+//! invocation lands in a tracing backend as one trace:
+//!
+//! - **Root span** `wt <subcommand>`, covering the whole capture
+//! - **Client spans** (`kind: 3`) for every subprocess — git, forge CLIs,
+//!   hook steps, LLM calls — categorized by the `worktrunk.category` attribute
+//! - **Internal spans** (`kind: 1`) for in-process spans (`build_hook_context`)
+//! - **Events** on the root span for instants (`Showed skeleton`)
+//!
+//! A record nests under the innermost in-process span on the same thread that
+//! encloses it, else under the root.
+//!
+//! The repo, branch and command line travel as resource attributes
+//! (`worktrunk.repo`, `worktrunk.branch`, `process.command_line`), read from
+//! the capture's [`TraceSession`]. Its wall-clock anchor turns the monotonic
+//! `ts` into absolute `startTimeUnixNano`/`endTimeUnixNano`.
+//!
+//! Trace and span IDs derive from the session, so exporting the same capture
+//! twice produces the same IDs and a backend deduplicates rather than doubling.
+//!
+//! # Format Reference
+//!
+//! - [OTLP JSON encoding](https://opentelemetry.io/docs/specs/otlp/#json-protobuf-encoding)
+//! - [Trace semantic conventions](https://opentelemetry.io/docs/specs/semconv/general/trace/)
+
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use super::{TraceEntry, TraceEntryKind, TraceResult, TraceSession};
+use crate::git::HookType;
+
+/// `SPAN_KIND_INTERNAL`
+const SPAN_KIND_INTERNAL: u8 = 1;
+/// `SPAN_KIND_CLIENT`
+const SPAN_KIND_CLIENT: u8 = 3;
+/// `STATUS_CODE_ERROR`
+const STATUS_CODE_ERROR: u8 = 2;
+
+/// Convert trace entries to an OTLP/JSON `ExportTraceServiceRequest`.
+///
+/// `session` supplies the resource attributes and the wall-clock anchor. A
+/// capture without a `session` record (from an older `wt`) passes a synthetic
+/// one — see [`TraceSession`].
+pub fn to_otlp_json(entries: &[TraceEntry], session: &TraceSession) -> Value {
+    let trace_id = hex_digest(&[
+        session.command.as_bytes(),
+        &session.unix_time_us.to_le_bytes(),
+        &session.pid.unwrap_or(0).to_le_bytes(),
+    ])[..32]
+        .to_string();
+    let span_id =
+        |index: usize| hex_digest(&[trace_id.as_bytes(), &index.to_le_bytes()])[..16].to_string();
+    let root_id = span_id(0);
+    let nanos = |ts_us: u64| (session.unix_time_of(ts_us) * 1000).to_string();
+
+    let bounds = |entry: &TraceEntry| {
+        let start = entry.start_time_us.unwrap_or(session.ts_us);
+        let duration = match &entry.kind {
+            TraceEntryKind::Command { duration, .. } | TraceEntryKind::Span { duration, .. } => {
+                duration.as_micros() as u64
+            }
+            TraceEntryKind::Instant { .. } => 0,
+        };
+        (start, start + duration)
+    };
+
+    let root_start = entries
+        .iter()
+        .map(|e| bounds(e).0)
+        .min()
+        .unwrap_or(session.ts_us);
+    let root_end = entries
+        .iter()
+        .map(|e| bounds(e).1)
+        .max()
+        .unwrap_or(session.ts_us)
+        .max(root_start);
+
+    // The innermost in-process span on the same thread enclosing `index`.
+    let parent_of = |index: usize| -> Option<usize> {
+        let entry = &entries[index];
+        let (start, end) = bounds(entry);
+        entries
+            .iter()
+            .enumerate()
+            .filter(|&(i, candidate)| {
+                i != index
+                    && matches!(candidate.kind, TraceEntryKind::Span { .. })
+                    && candidate.thread_id == entry.thread_id
+            })
+            .filter(|&(i, candidate)| {
+                let (s, e) = bounds(candidate);
+                // Spans record on completion, so of two with identical bounds
+                // the inner one comes first.
+                s <= start && end <= e && ((s, e) != (start, end) || i > index)
+            })
+            .min_by_key(|&(_, candidate)| {
+                let (s, e) = bounds(candidate);
+                e - s
+            })
+            .map(|(i, _)| i)
+    };
+
+    let mut events = Vec::new();
+    let mut spans = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let (start, end) = bounds(entry);
+        let parent = parent_of(index).map_or_else(|| root_id.clone(), |i| span_id(i + 1));
+        let mut attributes = Vec::new();
+        if let Some(context) = &entry.context {
+            attributes.push(attribute("worktrunk.context", context));
+        }
+        if let Some(tid) = entry.thread_id {
+            attributes.push(int_attribute("thread.id", tid));
+        }
+
+        let (name, kind, status) = match &entry.kind {
+            TraceEntryKind::Instant { name } => {
+                events.push(json!({
+                    "timeUnixNano": nanos(start),
+                    "name": name,
+                    "attributes": attributes,
+                }));
+                continue;
+            }
+            TraceEntryKind::Span { name, .. } => {
+                attributes.push(attribute("worktrunk.category", "wt"));
+                (name.clone(), SPAN_KIND_INTERNAL, json!({}))
+            }
+            TraceEntryKind::Command {
+                command,
+                result,
+                label,
+                ..
+            } => {
+                attributes.push(attribute("process.command_line", command));
+                attributes.push(attribute(
+                    "worktrunk.category",
+                    category(command, label.as_deref()),
+                ));
+                if let Some(label) = label {
+                    attributes.push(attribute("worktrunk.label", label));
+                }
+                let status = match result {
+                    TraceResult::Completed { success: true } => json!({}),
+                    TraceResult::Completed { success: false } => json!({
+                        "code": STATUS_CODE_ERROR,
+                        "message": "exited with non-zero status",
+                    }),
+                    TraceResult::Error { message } => json!({
+                        "code": STATUS_CODE_ERROR,
+                        "message": message,
+                    }),
+                };
+                let name = label.clone().unwrap_or_else(|| {
+                    // `git rev-parse`, `gh pr` — the program and its subcommand.
+                    command
+                        .split_whitespace()
+                        .take(2)
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                });
+                (name, SPAN_KIND_CLIENT, status)
+            }
+        };
+
+        spans.push(json!({
+            "traceId": trace_id,
+            "spanId": span_id(index + 1),
+            "parentSpanId": parent,
+            "name": name,
+            "kind": kind,
+            "startTimeUnixNano": nanos(start),
+            "endTimeUnixNano": nanos(end),
+            "attributes": attributes,
+            "status": status,
+        }));
+    }
+
+    let root_name = match &session.subcommand {
+        Some(subcommand) => format!("wt {subcommand}"),
+        None => "wt".to_string(),
+    };
+    let mut root_attributes = vec![attribute("process.command_line", &session.command)];
+    if let Some(subcommand) = &session.subcommand {
+        root_attributes.push(attribute("worktrunk.command", subcommand));
+    }
+    spans.insert(
+        0,
+        json!({
+            "traceId": trace_id,
+            "spanId": root_id,
+            "name": root_name,
+            "kind": SPAN_KIND_INTERNAL,
+            "startTimeUnixNano": nanos(root_start),
+            "endTimeUnixNano": nanos(root_end),
+            "attributes": root_attributes,
+            "events": events,
+            "status": {},
+        }),
+    );
+
+    let mut resource = vec![
+        attribute("service.name", "worktrunk"),
+        attribute("service.version", env!("CARGO_PKG_VERSION")),
+        attribute("process.command_line", &session.command),
+    ];
+    if let Some(pid) = session.pid {
+        resource.push(int_attribute("process.pid", pid));
+    }
+    if let Some(subcommand) = &session.subcommand {
+        resource.push(attribute("worktrunk.command", subcommand));
+    }
+    if let Some(repo) = &session.repo {
+        resource.push(attribute("worktrunk.repo", repo));
+    }
+    if let Some(branch) = &session.branch {
+        resource.push(attribute("worktrunk.branch", branch));
+    }
+
+    json!({
+        "resourceSpans": [{
+            "resource": { "attributes": resource },
+            "scopeSpans": [{
+                "scope": { "name": "worktrunk", "version": env!("CARGO_PKG_VERSION") },
+                "spans": spans,
+            }],
+        }],
+    })
+}
+
+/// The `worktrunk.category` of a subprocess: `llm` for commit-message
+/// generation, `hook` for hook steps, `command` for other user-configured
+/// commands, `git`/`network` by program as in [`super::chrome`], else
+/// `process`.
+fn category(command: &str, label: Option<&str>) -> &'static str {
+    if let Some(label) = label {
+        return if label.ends_with(".generation") {
+            "llm"
+        } else if label
+            .split_once(' ')
+            .is_some_and(|(hook, _)| hook.parse::<HookType>().is_ok())
+        {
+            "hook"
+        } else {
+            "command"
+        };
+    }
+    if command.starts_with("git ") {
+        "git"
+    } else if ["gh ", "glab ", "az ", "tea "]
+        .iter()
+        .any(|prefix| command.starts_with(prefix))
+    {
+        "network"
+    } else {
+        "process"
+    }
+}
+
+fn attribute(key: &str, value: &str) -> Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+/// OTLP/JSON encodes 64-bit integers as strings.
+fn int_attribute(key: &str, value: u64) -> Value {
+    json!({ "key": key, "value": { "intValue": value.to_string() } })
+}
+
+fn hex_digest(parts: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::{parse_lines, parse_session};
+
+    const CAPTURE: &str = concat!(
+        r#"{"kind":"session","ts":100,"tid":1,"unix_us":1700000000000100,"pid":42,"cmd":"wt list","subcommand":"list","repo":"/repo","branch":"main"}"#,
+        "\n",
+        r#"{"kind":"span","ts":200,"tid":1,"span":"collect","dur_us":1000}"#,
+        "\n",
+        r#"{"kind":"cmd_completed","ts":300,"tid":1,"cmd":"git status --porcelain","dur_us":100,"ok":true,"context":"feature"}"#,
+        "\n",
+        r#"{"kind":"cmd_completed","ts":400,"tid":2,"cmd":"gh pr list","dur_us":500,"ok":false}"#,
+        "\n",
+        r#"{"kind":"cmd_errored","ts":500,"tid":1,"cmd":"npm run lint","dur_us":50,"err":"spawn failed","label":"pre-merge user:lint"}"#,
+        "\n",
+        r#"{"kind":"cmd_completed","ts":600,"tid":3,"cmd":"llm -m haiku","dur_us":2000,"ok":true,"label":"commit.generation"}"#,
+        "\n",
+        r#"{"kind":"instant","ts":700,"tid":1,"event":"Showed skeleton"}"#,
+    );
+
+    fn export() -> Value {
+        let session = parse_session(CAPTURE).unwrap();
+        to_otlp_json(&parse_lines(CAPTURE), &session)
+    }
+
+    fn attr<'a>(attributes: &'a Value, key: &str) -> Option<&'a Value> {
+        attributes
+            .as_array()?
+            .iter()
+            .find(|a| a["key"] == key)
+            .map(|a| &a["value"])
+    }
+
+    #[test]
+    fn test_resource_carries_session() {
+        let otlp = export();
+        let resource = &otlp["resourceSpans"][0]["resource"]["attributes"];
+        assert_eq!(
+            attr(resource, "service.name").unwrap()["stringValue"],
+            "worktrunk"
+        );
+        assert_eq!(
+            attr(resource, "worktrunk.repo").unwrap()["stringValue"],
+            "/repo"
+        );
+        assert_eq!(
+            attr(resource, "worktrunk.branch").unwrap()["stringValue"],
+            "main"
+        );
+        assert_eq!(
+            attr(resource, "worktrunk.command").unwrap()["stringValue"],
+            "list"
+        );
+        assert_eq!(attr(resource, "process.pid").unwrap()["intValue"], "42");
+    }
+
+    #[test]
+    fn test_spans_nest_and_categorize() {
+        let otlp = export();
+        let spans = otlp["resourceSpans"][0]["scopeSpans"][0]["spans"]
+            .as_array()
+            .unwrap();
+        // Root + span + 4 commands; the instant is an event, not a span.
+        assert_eq!(spans.len(), 6);
+        let root = &spans[0];
+        assert_eq!(root["name"], "wt list");
+        assert_eq!(root["startTimeUnixNano"], "1700000000000200000");
+        assert_eq!(root["endTimeUnixNano"], "1700000000002600000");
+        assert_eq!(root["events"][0]["name"], "Showed skeleton");
+
+        let by_name = |name: &str| spans.iter().find(|s| s["name"] == name).unwrap();
+        let collect = by_name("collect");
+        assert_eq!(collect["parentSpanId"], root["spanId"]);
+
+        // Enclosed by `collect` on the same thread.
+        let status = by_name("git status");
+        assert_eq!(status["parentSpanId"], collect["spanId"]);
+        assert_eq!(status["kind"], SPAN_KIND_CLIENT);
+        assert_eq!(
+            attr(&status["attributes"], "worktrunk.category").unwrap()["stringValue"],
+            "git"
+        );
+        assert_eq!(status["startTimeUnixNano"], "1700000000000300000");
+        assert_eq!(status["endTimeUnixNano"], "1700000000000400000");
+
+        // Different thread → root, even though `collect` spans it in time.
+        let pr = by_name("gh pr");
+        assert_eq!(pr["parentSpanId"], root["spanId"]);
+        assert_eq!(pr["status"]["code"], STATUS_CODE_ERROR);
+
+        let hook = by_name("pre-merge user:lint");
+        assert_eq!(
+            attr(&hook["attributes"], "worktrunk.category").unwrap()["stringValue"],
+            "hook"
+        );
+        assert_eq!(hook["status"]["message"], "spawn failed");
+
+        let llm = by_name("commit.generation");
+        assert_eq!(
+            attr(&llm["attributes"], "worktrunk.category").unwrap()["stringValue"],
+            "llm"
+        );
+    }
+
+    #[test]
+    fn test_ids_are_stable() {
+        let (a, b) = (export(), export());
+        let spans = &a["resourceSpans"][0]["scopeSpans"][0]["spans"];
+        assert_eq!(spans, &b["resourceSpans"][0]["scopeSpans"][0]["spans"]);
+        assert_eq!(spans[0]["traceId"].as_str().unwrap().len(), 32);
+        assert_eq!(spans[0]["spanId"].as_str().unwrap().len(), 16);
+    }
+}
@@ -10,8 +10,11 @@
 //! {"kind":"cmd_errored","ts":1234567,"tid":3,"seq":3,"context":"main","cmd":"git merge-base","dur_us":100000,"err":"fatal: ..."}
 //! {"kind":"instant","ts":1234567,"tid":3,"event":"Showed skeleton"}
 //! {"kind":"span","ts":1234567,"tid":3,"span":"build_hook_context","dur_us":8200}
+//! {"kind":"session","ts":1234567,"tid":1,"unix_us":1700000000000000,"pid":4242,"cmd":"wt list","subcommand":"list","repo":"/path/to/repo","branch":"main"}
 //! ```
 //!
+//! The `session` record isn't a [`TraceEntry`]; [`parse_session`] reads it.
+//!
 //! `seq` (a per-command counter, command records only) is ignored here — no
 //! consumer needs it; it correlates a record with its raw output block in
 //! `subprocess.log`. `stdin` (bool, command records, omitted → `false`) flags a
@@ -46,6 +49,10 @@ pub enum TraceEntryKind {
         /// may be entirely different work. From the `stdin` JSON field
         /// (omitted → `false`).
         reads_stdin: bool,
+        /// Command-log label of a user-configured command — a hook step
+        /// (`pre-merge user:lint`) or LLM call (`commit.generation`). From the
+        /// `label` JSON field; `None` for wt's own subprocesses.
+        label: Option<String>,
     },
     /// An instant event (milestone marker with no duration)
     Instant {
@@ -134,6 +141,10 @@ fn parse_line(line: &str) -> Option<TraceEntry> {
         .get("stdin")
         .and_then(serde_json::Value::as_bool)
         .unwrap_or(false);
+    let label = obj
+        .get("label")
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
 
     let kind = match obj.get("kind")?.as_str()? {
         "cmd_completed" => TraceEntryKind::Command {
@@ -143,6 +154,7 @@ fn parse_line(line: &str) -> Option<TraceEntry> {
                 success: obj.get("ok")?.as_bool()?,
             },
             reads_stdin,
+            label,
         },
         "cmd_errored" => TraceEntryKind::Command {
             command: obj.get("cmd")?.as_str()?.to_string(),
@@ -155,6 +167,7 @@ fn parse_line(line: &str) -> Option<TraceEntry> {
                     .to_string(),
             },
             reads_stdin,
+            label,
         },
         "instant" => TraceEntryKind::Instant {
             name: obj.get("event")?.as_str()?.to_string(),
@@ -179,6 +192,55 @@ pub fn parse_lines(input: &str) -> Vec<TraceEntry> {
     input.lines().filter_map(parse_line).collect()
 }
 
+/// The `session` record of a capture: which invocation it was, and the
+/// wall-clock anchor for its monotonic `ts` clock.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceSession {
+    /// The full command line (`wt list --full`)
+    pub command: String,
+    /// The `wt` subcommand (`list`), when one was parsed
+    pub subcommand: Option<String>,
+    /// Repository the run started in
+    pub repo: Option<String>,
+    /// Branch checked out where the run started
+    pub branch: Option<String>,
+    /// Process ID of the run
+    pub pid: Option<u64>,
+    /// Trace-clock `ts` at which [`unix_time_us`](Self::unix_time_us) was read
+    pub ts_us: u64,
+    /// Wall-clock time (microseconds since Unix epoch) at `ts_us`
+    pub unix_time_us: u64,
+}
+
+impl TraceSession {
+    /// Wall-clock microseconds for a trace-clock `ts`.
+    pub fn unix_time_of(&self, ts_us: u64) -> u64 {
+        self.unix_time_us.saturating_sub(self.ts_us) + ts_us
+    }
+}
+
+/// Find the `session` record in a capture. `None` for captures from before
+/// the record existed, or runs that never reached it.
+pub fn parse_session(input: &str) -> Option<TraceSession> {
+    input.lines().find_map(|line| {
+        let line = line.trim();
+        if !line.starts_with('{') || !line.contains(r#""kind":"session""#) {
+            return None;
+        }
+        let value: serde_json::Value = serde_json::from_str(line).ok()?;
+        let str_field = |key: &str| value.get(key).and_then(|v| v.as_str()).map(str::to_string);
+        Some(TraceSession {
+            command: str_field("cmd")?,
+            subcommand: str_field("subcommand"),
+            repo: str_field("repo"),
+            branch: str_field("branch"),
+            pid: value.get("pid").and_then(serde_json::Value::as_u64),
+            ts_us: value.get("ts")?.as_u64()?,
+            unix_time_us: value.get("unix_us")?.as_u64()?,
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +286,35 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_label() {
+        let line = r#"{"kind":"cmd_completed","cmd":"npm run lint","dur_us":5000,"ok":true,"label":"pre-merge user:lint"}"#;
+        let entry = parse_line(line).unwrap();
+        assert!(matches!(
+            &entry.kind,
+            TraceEntryKind::Command { label: Some(label), .. } if label == "pre-merge user:lint"
+        ));
+    }
+
+    #[test]
+    fn test_parse_session() {
+        let input = concat!(
+            r#"{"kind":"span","ts":10,"tid":1,"span":"init_logging","dur_us":5}"#,
+            "\n",
+            r#"{"kind":"session","ts":400,"tid":1,"unix_us":1700000000000400,"pid":42,"cmd":"wt list","subcommand":"list","repo":"/repo","branch":"main"}"#,
+        );
+        let session = parse_session(input).unwrap();
+        assert_eq!(session.command, "wt list");
+        assert_eq!(session.subcommand.as_deref(), Some("list"));
+        assert_eq!(session.branch.as_deref(), Some("main"));
+        assert_eq!(session.pid, Some(42));
+        assert_eq!(session.unix_time_of(10), 1_700_000_000_000_010);
+        // Session records aren't trace entries.
+        assert_eq!(parse_lines(input).len(), 1);
+
+        assert!(parse_session(r#"{"kind":"instant","ts":1,"event":"x"}"#).is_none());
+    }
+
     #[test]
     fn test_parse_ok_false() {
         let line = r#"{"kind":"cmd_completed","cmd":"git diff","dur_us":5000,"ok":false}"#;
@@ -883,6 +883,7 @@ mod tests {
                 duration: Duration::from_micros(dur_us),
                 result: TraceResult::Completed { success: ok },
                 reads_stdin: false,
+                label: None,
             },
             start_time_us: Some(ts_us),
             thread_id: Some(tid),
@@ -899,6 +900,7 @@ mod tests {
                 duration: Duration::from_micros(dur_us),
                 result: TraceResult::Completed { success: true },
                 reads_stdin: true,
+                label: None,
             },
             start_time_us: None,
             thread_id: None,
@@ -1204,6 +1206,7 @@ mod tests {
                     message: message.to_string(),
                 },
                 reads_stdin: false,
+                label: None,
             },
             start_time_us: None,
             thread_id: None,
@@ -1215,6 +1218,7 @@ mod tests {
                 duration: Duration::from_micros(dur_us),
                 result: TraceResult::Completed { success: true },
                 reads_stdin: false,
+                label: None,
             },
             start_time_us: None,
             thread_id: None,
@@ -1241,6 +1245,7 @@ mod tests {
                 duration: Duration::from_micros(dur_us),
                 result: TraceResult::Completed { success: true },
                 reads_stdin: false,
+                label: None,
             },
             start_time_us: None,
             thread_id: None,
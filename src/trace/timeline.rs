@@ -163,6 +163,7 @@ mod tests {
                 duration: Duration::from_micros(dur_us),
                 result: TraceResult::Completed { success: ok },
                 reads_stdin: false,
+                label: None,
             },
             start_time_us: Some(ts_us),
             thread_id: Some(tid),
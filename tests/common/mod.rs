@@ -7,6 +7,7 @@
 pub use worktrunk::testing::mock_commands;
 pub use worktrunk::testing::*;
 
+pub mod json_rpc;
 pub mod list_snapshots;
 // Progressive output tests use PTY and are Unix-only for now
 #[cfg(unix)]
@@ -572,6 +573,36 @@ pub fn shell_command(
     cmd
 }
 
+/// Run a `wt` command that must succeed, returning its `(stdout, stderr)`.
+/// A failure panics with the arguments and both streams.
+pub fn run_wt(cmd: &mut std::process::Command) -> (String, String) {
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt {:?} failed\nstdout: {}\nstderr: {}",
+        cmd.get_args().collect::<Vec<_>>(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+/// Approval records for `commands`, unscoped and without provenance — each
+/// approves its command in every hook and alias, like a bare
+/// `approved-commands` entry.
+pub fn approval_records(commands: &[&str]) -> Vec<worktrunk::config::ApprovalRecord> {
+    commands
+        .iter()
+        .map(|command| worktrunk::config::ApprovalRecord {
+            command: command.to_string(),
+            ..Default::default()
+        })
+        .collect()
+}
+
 // =============================================================================
 // Snapshot settings functions (insta)
 // =============================================================================
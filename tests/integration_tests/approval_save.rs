@@ -1,3 +1,4 @@
+use crate::common::approval_records;
 use insta::assert_snapshot;
 use std::fs;
 use tempfile::TempDir;
@@ -18,7 +19,7 @@ fn test_approval_saves_to_disk() {
     approvals
         .approve_commands(
             "github.com/test/repo".to_string(),
-            vec!["test command".to_string()],
+            approval_records(&["test command"]),
             &approvals_path,
         )
         .unwrap();
@@ -33,14 +34,12 @@ fn test_approval_saves_to_disk() {
     // Verify TOML structure
     let toml_content = fs::read_to_string(&approvals_path).unwrap();
     assert_snapshot!(toml_content, @r#"
-    [projects."github.com/test/repo"]
-    approved-commands = [
-        "test command",
-    ]
+    [[projects."github.com/test/repo".approvals]]
+    command = "test command"
     "#);
 
     // Verify approval is in memory
-    assert!(approvals.is_command_approved("github.com/test/repo", "test command"));
+    assert!(approvals.is_command_approved("github.com/test/repo", "test command", "post-start"));
 }
 
 #[test]
@@ -54,14 +53,14 @@ fn test_duplicate_approvals_not_saved_twice() {
     approvals
         .approve_commands(
             "github.com/test/repo".to_string(),
-            vec!["test".to_string()],
+            approval_records(&["test"]),
             &approvals_path,
         )
         .ok();
     approvals
         .approve_commands(
             "github.com/test/repo".to_string(),
-            vec!["test".to_string()],
+            approval_records(&["test"]),
             &approvals_path,
         )
         .ok();
@@ -69,10 +68,8 @@ fn test_duplicate_approvals_not_saved_twice() {
     // Verify only one entry exists by reading the saved file
     let toml_content = fs::read_to_string(&approvals_path).unwrap();
     assert_snapshot!(toml_content, @r#"
-    [projects."github.com/test/repo"]
-    approved-commands = [
-        "test",
-    ]
+    [[projects."github.com/test/repo".approvals]]
+    command = "test"
     "#);
 }
 
@@ -87,44 +84,42 @@ fn test_multiple_project_approvals() {
     approvals
         .approve_commands(
             "github.com/user1/repo1".to_string(),
-            vec!["npm install".to_string()],
+            approval_records(&["npm install"]),
             &approvals_path,
         )
         .unwrap();
     approvals
         .approve_commands(
             "github.com/user2/repo2".to_string(),
-            vec!["cargo build".to_string()],
+            approval_records(&["cargo build"]),
             &approvals_path,
         )
         .unwrap();
     approvals
         .approve_commands(
             "github.com/user1/repo1".to_string(),
-            vec!["npm test".to_string()],
+            approval_records(&["npm test"]),
             &approvals_path,
         )
         .unwrap();
 
     // Verify all approvals exist
-    assert!(approvals.is_command_approved("github.com/user1/repo1", "npm install"));
-    assert!(approvals.is_command_approved("github.com/user2/repo2", "cargo build"));
-    assert!(approvals.is_command_approved("github.com/user1/repo1", "npm test"));
-    assert!(!approvals.is_command_approved("github.com/user1/repo1", "cargo build"));
+    assert!(approvals.is_command_approved("github.com/user1/repo1", "npm install", "post-start"));
+    assert!(approvals.is_command_approved("github.com/user2/repo2", "cargo build", "post-start"));
+    assert!(approvals.is_command_approved("github.com/user1/repo1", "npm test", "post-start"));
+    assert!(!approvals.is_command_approved("github.com/user1/repo1", "cargo build", "post-start"));
 
     // Verify file structure
     let toml_content = fs::read_to_string(&approvals_path).unwrap();
     assert_snapshot!(toml_content, @r#"
-    [projects."github.com/user1/repo1"]
-    approved-commands = [
-        "npm install",
-        "npm test",
-    ]
-
-    [projects."github.com/user2/repo2"]
-    approved-commands = [
-        "cargo build",
-    ]
+    [[projects."github.com/user1/repo1".approvals]]
+    command = "npm install"
+
+    [[projects."github.com/user1/repo1".approvals]]
+    command = "npm test"
+
+    [[projects."github.com/user2/repo2".approvals]]
+    command = "cargo build"
     "#);
 }
 
@@ -158,7 +153,7 @@ fn test_isolated_config_safety() {
     approvals
         .approve_commands(
             "github.com/safety-test/repo".to_string(),
-            vec!["THIS SHOULD NOT APPEAR IN USER APPROVALS".to_string()],
+            approval_records(&["THIS SHOULD NOT APPEAR IN USER APPROVALS"]),
             &approvals_path,
         )
         .unwrap();
@@ -216,7 +211,7 @@ fn test_approval_saves_to_new_approvals_file() {
     approvals
         .approve_commands(
             "github.com/test/nested".to_string(),
-            vec!["test command".to_string()],
+            approval_records(&["test command"]),
             &approvals_path,
         )
         .unwrap();
@@ -228,10 +223,8 @@ fn test_approval_saves_to_new_approvals_file() {
     // Verify content
     let content = fs::read_to_string(&approvals_path).unwrap();
     assert_snapshot!(content, @r#"
-    [projects."github.com/test/nested"]
-    approved-commands = [
-        "test command",
-    ]
+    [[projects."github.com/test/nested".approvals]]
+    command = "test command"
     "#);
 }
 
@@ -309,7 +302,7 @@ fn test_concurrent_approve_preserves_all_approvals() {
     approvals_a
         .approve_commands(
             "github.com/user/repo".to_string(),
-            vec!["npm install".to_string()],
+            approval_records(&["npm install"]),
             &approvals_path,
         )
         .unwrap();
@@ -326,7 +319,7 @@ fn test_concurrent_approve_preserves_all_approvals() {
     approvals_b
         .approve_commands(
             "github.com/user/repo".to_string(),
-            vec!["npm test".to_string()],
+            approval_records(&["npm test"]),
             &approvals_path,
         )
         .unwrap();
@@ -361,14 +354,14 @@ fn test_concurrent_revoke_preserves_all_changes() {
     setup_approvals
         .approve_commands(
             "github.com/user/repo".to_string(),
-            vec!["npm install".to_string()],
+            approval_records(&["npm install"]),
             &approvals_path,
         )
         .unwrap();
     setup_approvals
         .approve_commands(
             "github.com/user/repo".to_string(),
-            vec!["npm test".to_string()],
+            approval_records(&["npm test"]),
             &approvals_path,
         )
         .unwrap();
@@ -413,7 +406,7 @@ fn test_concurrent_approve_different_projects() {
     approvals_a
         .approve_commands(
             "github.com/user/project1".to_string(),
-            vec!["npm install".to_string()],
+            approval_records(&["npm install"]),
             &approvals_path,
         )
         .unwrap();
@@ -423,7 +416,7 @@ fn test_concurrent_approve_different_projects() {
     approvals_b
         .approve_commands(
             "github.com/user/project2".to_string(),
-            vec!["cargo build".to_string()],
+            approval_records(&["cargo build"]),
             &approvals_path,
         )
         .unwrap();
@@ -481,7 +474,7 @@ fn test_truly_concurrent_approve_with_threads() {
                 approvals
                     .approve_commands(
                         "github.com/user/repo".to_string(),
-                        vec![format!("command_{i}")],
+                        approval_records(&[&format!("command_{i}")]),
                         &approvals_path,
                     )
                     .unwrap();
@@ -559,7 +552,7 @@ fn test_permission_error_prevents_save() {
     let mut approvals = Approvals::default();
     let result = approvals.approve_commands(
         "github.com/test/readonly".to_string(),
-        vec!["test command".to_string()],
+        approval_records(&["test command"]),
         &approvals_path,
     );
 
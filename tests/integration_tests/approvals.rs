@@ -1,9 +1,9 @@
 //! Integration tests for the `wt config approvals` subcommands
 
 use crate::common::{
-    BareRepoTest, TestRepo, TestRepoBase, make_snapshot_cmd, make_snapshot_cmd_with_global_flags,
-    repo, set_temp_home_env, setup_snapshot_settings, setup_snapshot_settings_with_home,
-    setup_temp_snapshot_settings, temp_home, wt_command,
+    BareRepoTest, TestRepo, TestRepoBase, approval_records, make_snapshot_cmd,
+    make_snapshot_cmd_with_global_flags, repo, set_temp_home_env, setup_snapshot_settings,
+    setup_snapshot_settings_with_home, setup_temp_snapshot_settings, temp_home, wt_command,
 };
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
@@ -84,11 +84,11 @@ template-append = "Use conventional commits."
     approvals
         .approve_commands(
             project_id,
-            vec![
-                "cargo test".to_string(),
-                "echo deploying {{ branch }}".to_string(),
-                "some removed command".to_string(),
-            ],
+            approval_records(&[
+                "cargo test",
+                "echo deploying {{ branch }}",
+                "some removed command",
+            ]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -108,7 +108,7 @@ fn test_list_approvals_all_approved(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec!["cargo test".to_string()],
+            approval_records(&["cargo test"]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -127,7 +127,7 @@ fn test_list_approvals_stale_only(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec!["orphan command".to_string()],
+            approval_records(&["orphan command"]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -135,6 +135,60 @@ fn test_list_approvals_stale_only(repo: TestRepo) {
     snapshot_list_approvals("list_approvals_stale_only", &repo);
 }
 
+/// Approvals recorded from an earlier version of the project config still
+/// approve their unchanged commands, but list as stale with who approved them
+/// and where; an expired approval lists as stale and no longer approves.
+#[rstest]
+fn test_list_approvals_config_changed_and_expired(repo: TestRepo) {
+    repo.run_git(&["remote", "remove", "origin"]);
+    repo.write_project_config(
+        r#"pre-merge = "cargo test"
+post-start = "npm install"
+"#,
+    );
+    repo.commit("Add config");
+    snapshot_add_approvals(
+        "add_approvals_expires_in",
+        &repo,
+        &["--yes", "--expires-in", "7d"],
+    );
+
+    insta::assert_snapshot!(fs::read_to_string(repo.test_approvals_path()).unwrap(), @r#"
+    [[projects."_REPO_".approvals]]
+    command = "npm install"
+    scope = "post-start"
+    config-hash = "sha256:f66fd670617be543b6a6f686c5caaec628d4cfceac9f7bab75461e8acbaf31c1"
+    approved-at = 1735776000
+    expires-at = 1736380800
+    approver = "test@example.com"
+    host = "test-host"
+
+    [[projects."_REPO_".approvals]]
+    command = "cargo test"
+    scope = "pre-merge"
+    config-hash = "sha256:f66fd670617be543b6a6f686c5caaec628d4cfceac9f7bab75461e8acbaf31c1"
+    approved-at = 1735776000
+    expires-at = 1736380800
+    approver = "test@example.com"
+    host = "test-host"
+    "#);
+
+    // Someone edits the config — a new command, the approved ones untouched.
+    repo.write_project_config(
+        r#"pre-merge = "cargo test"
+post-start = "npm install"
+post-switch = "echo switched"
+"#,
+    );
+    // And one approval has lapsed.
+    let path = repo.test_approvals_path();
+    let recorded = fs::read_to_string(path).unwrap();
+    let recorded = recorded.replacen("expires-at = 1736380800", "expires-at = 1", 1);
+    fs::write(path, recorded).unwrap();
+
+    snapshot_list_approvals("list_approvals_config_changed", &repo);
+}
+
 // ============================================================================
 // add-approvals tests
 // ============================================================================
@@ -284,7 +338,7 @@ fn test_clear_approvals_with_approvals(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec!["echo 'test'".to_string()],
+            approval_records(&["echo 'test'"]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -312,7 +366,7 @@ fn test_clear_approvals_global_with_approvals(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec!["echo 'test'".to_string()],
+            approval_records(&["echo 'test'"]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -369,7 +423,7 @@ fn test_clear_approvals_exact_cleared_pattern_remains(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec!["echo 'test'".to_string()],
+            approval_records(&["echo 'test'"]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -391,7 +445,7 @@ fn test_clear_approvals_after_clear(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec!["echo 'test'".to_string()],
+            approval_records(&["echo 'test'"]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -418,11 +472,11 @@ fn test_clear_approvals_stale(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec![
-                "cargo test".to_string(),
-                "some removed command".to_string(),
-                "other removed command".to_string(),
-            ],
+            approval_records(&[
+                "cargo test",
+                "some removed command",
+                "other removed command",
+            ]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -442,7 +496,7 @@ fn test_clear_approvals_stale_none(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec!["cargo test".to_string()],
+            approval_records(&["cargo test"]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -462,7 +516,7 @@ fn test_clear_approvals_stale_no_config(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec!["orphan command".to_string()],
+            approval_records(&["orphan command"]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -545,21 +599,21 @@ lint = "echo 'third'"
     approvals
         .approve_commands(
             project_id.clone(),
-            vec!["echo 'first'".to_string()],
+            approval_records(&["echo 'first'"]),
             repo.test_approvals_path(),
         )
         .unwrap();
     approvals
         .approve_commands(
             project_id.clone(),
-            vec!["echo 'second'".to_string()],
+            approval_records(&["echo 'second'"]),
             repo.test_approvals_path(),
         )
         .unwrap();
     approvals
         .approve_commands(
             project_id,
-            vec!["echo 'third'".to_string()],
+            approval_records(&["echo 'third'"]),
             repo.test_approvals_path(),
         )
         .unwrap();
@@ -586,7 +640,7 @@ fn test_add_approvals_all_already_approved(repo: TestRepo) {
     approvals
         .approve_commands(
             repo.project_id(),
-            vec!["echo 'test'".to_string()],
+            approval_records(&["echo 'test'"]),
             repo.test_approvals_path(),
         )
         .unwrap();
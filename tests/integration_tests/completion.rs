@@ -843,11 +843,13 @@ fn test_complete_step_subcommands(repo: TestRepo) {
     assert!(subcommands.contains(&"promote"), "Missing promote");
     assert!(subcommands.contains(&"prune"), "Missing prune");
     assert!(subcommands.contains(&"relocate"), "Missing relocate");
+    assert!(subcommands.contains(&"resolve"), "Missing resolve");
+    assert!(subcommands.contains(&"review"), "Missing review");
     assert!(subcommands.contains(&"tether"), "Missing tether");
     assert_eq!(
         subcommands.len(),
-        12,
-        "Should have exactly 12 step subcommands"
+        14,
+        "Should have exactly 14 step subcommands"
     );
 }
 
@@ -974,7 +976,8 @@ fn test_complete_init_shell_all_variations(repo: TestRepo) {
     assert!(shells.contains(&"fish"));
     assert!(shells.contains(&"zsh"));
     assert!(shells.contains(&"nu"));
-    assert!(!shells.contains(&"elvish"));
+    assert!(shells.contains(&"xonsh"));
+    assert!(shells.contains(&"elvish"));
     assert!(!shells.contains(&"nushell")); // clap name is "nu", not "nushell"
 
     // Test 2: Partial input "fi" - filters to fish
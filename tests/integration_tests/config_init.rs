@@ -62,9 +62,13 @@ fn test_config_init_creates_file(temp_home: TempDir) {
         ");
     });
 
-    // Verify file was actually created
+    // Verify file was actually created, pointing editors at the schema
     let config_path = global_config_dir.join("config.toml");
-    assert!(config_path.exists());
+    let contents = fs::read_to_string(&config_path).unwrap();
+    assert!(
+        contents.starts_with("#:schema https://worktrunk.dev/schema/user-config.json\n"),
+        "{contents}"
+    );
 }
 
 #[rstest]
@@ -86,9 +90,10 @@ fn test_config_create_project_creates_file(repo: TestRepo) {
 
     // Verify file was actually created
     let config_path = repo.root_path().join(".config/wt.toml");
+    let contents = fs::read_to_string(&config_path).expect("Project config file should be created");
     assert!(
-        config_path.exists(),
-        "Project config file should be created"
+        contents.starts_with("#:schema https://worktrunk.dev/schema/project-config.json\n"),
+        "{contents}"
     );
 }
 
@@ -679,6 +679,226 @@ fn test_logs_profile_real_capture_has_key_intervals(repo: TestRepo) {
     );
 }
 
+/// End-to-end: a real `wt -vv list` capture exports as one OTLP trace whose
+/// resource names the repo, branch and command, with a client span per git
+/// subprocess.
+#[rstest]
+fn test_logs_export_real_capture(repo: TestRepo) {
+    let mut list = wt_command();
+    repo.configure_wt_cmd(&mut list);
+    list.args(["-vv", "list"]);
+    list.current_dir(repo.root_path());
+    assert!(list.output().unwrap().status.success());
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "state", "logs", "export"]);
+    cmd.current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let resource_spans = &json["resourceSpans"][0];
+    let resource = resource_spans["resource"]["attributes"].as_array().unwrap();
+    let attr = |key: &str| {
+        resource
+            .iter()
+            .find(|a| a["key"] == key)
+            .map(|a| a["value"]["stringValue"].clone())
+    };
+    assert_eq!(attr("worktrunk.command"), Some("list".into()));
+    assert_eq!(attr("worktrunk.branch"), Some("main".into()));
+    assert!(attr("worktrunk.repo").is_some(), "resource: {resource:?}");
+
+    let spans = resource_spans["scopeSpans"][0]["spans"].as_array().unwrap();
+    assert_eq!(spans[0]["name"], "wt list");
+    assert!(
+        spans
+            .iter()
+            .any(|s| s["kind"] == 3 && s["name"].as_str().unwrap().starts_with("git ")),
+        "no git client span: {spans:?}"
+    );
+}
+
+/// `--output` writes the OTLP/JSON to a file instead of stdout.
+#[rstest]
+fn test_logs_export_to_file(repo: TestRepo) {
+    let trace = repo.root_path().join("captured.log");
+    std::fs::write(&trace, PROFILE_FIXTURE_TRACE).unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args([
+        "config",
+        "state",
+        "logs",
+        "export",
+        "captured.log",
+        "--output",
+        "otlp.json",
+    ]);
+    cmd.current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let written = std::fs::read_to_string(repo.root_path().join("otlp.json")).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&written).unwrap();
+    // A capture without a `session` record still exports, as a bare `wt` root.
+    assert_eq!(
+        json["resourceSpans"][0]["scopeSpans"][0]["spans"][0]["name"],
+        "wt"
+    );
+}
+
+/// A `commands.jsonl` fixture spanning two branches and two hook types, dated
+/// the day before `TEST_EPOCH` (2025-01-02T00:00:00Z).
+const HISTORY_FIXTURE: &str = r#"{"ts":"2025-01-01T10:00:00Z","wt":"wt switch","label":"pre-start user:install","branch":"feature","cmd":"npm ci","exit":0,"dur_ms":4000}
+{"ts":"2025-01-01T11:00:00Z","wt":"wt merge","label":"pre-merge project:test","branch":"feature","cmd":"cargo test","exit":101,"dur_ms":9000}
+{"ts":"2025-01-01T12:00:00Z","wt":"wt merge","label":"pre-merge project:test","branch":"bugfix","cmd":"cargo test","exit":0,"dur_ms":7000}
+{"ts":"2025-01-01T23:30:00Z","wt":"wt step commit","label":"commit.generation","cmd":"claude -p","exit":0,"dur_ms":2000}
+"#;
+
+fn wt_history_cmd(repo: &TestRepo, args: &[&str]) -> Command {
+    let log_dir = repo.root_path().join(".git/wt/logs");
+    std::fs::create_dir_all(&log_dir).unwrap();
+    std::fs::write(log_dir.join("commands.jsonl"), HISTORY_FIXTURE).unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "state", "logs", "history"]);
+    cmd.args(args);
+    cmd.current_dir(repo.root_path());
+    cmd
+}
+
+fn history_labels(output: &std::process::Output) -> Vec<String> {
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    json.as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["label"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Filters combine: branch, hook type and failure each narrow the result.
+#[rstest]
+fn test_logs_history_filters(repo: TestRepo) {
+    let output = wt_history_cmd(&repo, &["--branch", "feature", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        history_labels(&output),
+        ["pre-start user:install", "pre-merge project:test"]
+    );
+
+    let output = wt_history_cmd(&repo, &["--hook", "pre-merge", "--failed", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 1);
+    assert_eq!(json[0]["branch"], "feature");
+    assert_eq!(json[0]["exit"], 101);
+
+    // One hour before TEST_EPOCH leaves only the LLM entry.
+    let output = wt_history_cmd(&repo, &["--since", "1h", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(history_labels(&output), ["commit.generation"]);
+}
+
+/// `--stats` groups by label, busiest first.
+#[rstest]
+fn test_logs_history_stats_json(repo: TestRepo) {
+    let output = wt_history_cmd(&repo, &["--stats", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stdout), @r#"
+    [
+      {
+        "label": "pre-merge project:test",
+        "runs": 2,
+        "failed": 1,
+        "p50_ms": 7000,
+        "p95_ms": 9000,
+        "max_ms": 9000,
+        "total_ms": 16000
+      },
+      {
+        "label": "pre-start user:install",
+        "runs": 1,
+        "failed": 0,
+        "p50_ms": 4000,
+        "p95_ms": 4000,
+        "max_ms": 4000,
+        "total_ms": 4000
+      },
+      {
+        "label": "commit.generation",
+        "runs": 1,
+        "failed": 0,
+        "p50_ms": 2000,
+        "p95_ms": 2000,
+        "max_ms": 2000,
+        "total_ms": 2000
+      }
+    ]
+    "#);
+}
+
+#[rstest]
+fn test_logs_history_invalid_since(repo: TestRepo) {
+    let output = wt_history_cmd(&repo, &["--since", "yesterday-ish"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--since"), "stderr: {stderr}");
+}
+
+/// A real hook run records the branch it ran for, so `--branch` finds it.
+#[rstest]
+fn test_logs_history_records_hook_branch(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[pre-start]
+marker = "echo started"
+"#,
+    );
+    let mut switch = wt_command();
+    repo.configure_wt_cmd(&mut switch);
+    switch.args(["switch", "--create", "feature", "--yes"]);
+    switch.current_dir(repo.root_path());
+    let output = switch.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args([
+        "config",
+        "state",
+        "logs",
+        "history",
+        "--branch",
+        "feature",
+        "--format=json",
+    ]);
+    cmd.current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(history_labels(&output), ["pre-start user:marker"]);
+}
+
 /// Without a trace, the error names the missing file and points at `-vv`.
 #[rstest]
 fn test_logs_profile_missing(repo: TestRepo) {
@@ -1003,6 +1223,42 @@ fn test_state_set_marker_branch_default(repo: TestRepo) {
     assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "🚧");
 }
 
+/// Setting a marker listed under `[notify.marker] on` notifies; others don't.
+#[rstest]
+fn test_state_set_marker_notifies(repo: TestRepo) {
+    let notified = repo.root_path().join("notified.json");
+    repo.write_test_config(&format!(
+        r#"[notify]
+command = "cat >> '{}'"
+
+[notify.marker]
+sinks = ["command"]
+on = ["💬"]
+"#,
+        notified.display()
+    ));
+
+    for marker in ["🤖", "💬"] {
+        let output = wt_state_cmd(&repo, "marker", "set", &[marker])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&notified).unwrap()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "event": "marker",
+            "title": "main",
+            "body": "Marker set to 💬",
+            "branch": "main",
+            "value": "💬",
+        })
+    );
+}
+
 #[rstest]
 fn test_state_set_marker_branch_specific(repo: TestRepo) {
     repo.git_command()
@@ -1573,6 +1829,9 @@ fn test_state_cache_bare_get_empty(repo: TestRepo) {
     [36mSUMMARY CACHE[39m
     [107m [0m (none)
 
+    [36mREVIEW CACHE[39m
+    [107m [0m (none)
+
     [36mGIT COMMANDS CACHE[39m
     [107m [0m (none)
 
@@ -1594,6 +1853,7 @@ fn test_state_cache_get_json_empty(repo: TestRepo) {
       "hints": [],
       "max_pr_number": null,
       "previous_branch": null,
+      "reviews": [],
       "summaries": []
     }
     "#);
@@ -1639,6 +1899,7 @@ fn test_state_cache_pr_number_ratchet(repo: TestRepo) {
       "hints": [],
       "max_pr_number": 3035,
       "previous_branch": null,
+      "reviews": [],
       "summaries": []
     }
     "#);
@@ -1766,6 +2027,9 @@ fn test_state_get_empty(repo: TestRepo) {
         [36mBRANCH MARKERS[39m
         [107m [0m (none)
 
+        [36mAGENT SESSIONS[39m
+        [107m [0m (none)
+
         [36mVARS[39m
         [107m [0m (none)
 
@@ -1775,6 +2039,9 @@ fn test_state_get_empty(repo: TestRepo) {
         [36mSUMMARY CACHE[39m
         [107m [0m (none)
 
+        [36mREVIEW CACHE[39m
+        [107m [0m (none)
+
         [36mGIT COMMANDS CACHE[39m
         [107m [0m (none)
 
@@ -1792,6 +2059,9 @@ fn test_state_get_empty(repo: TestRepo) {
 
         [36mTRASH[39m @ _REPO_/.git/wt/trash
         [107m [0m (none)
+
+        [36mSTORE[39m @ _REPO_/.git/wt/store
+        [107m [0m (none)
         ");
     });
 }
@@ -1974,6 +2244,7 @@ fn test_state_get_json_empty(repo: TestRepo) {
     assert!(output.status.success());
     assert_snapshot!(String::from_utf8_lossy(&output.stdout), @r#"
     {
+      "agent_sessions": [],
       "ci_status": [],
       "command_log": [],
       "default_branch": null,
@@ -1985,6 +2256,14 @@ fn test_state_get_json_empty(repo: TestRepo) {
       "max_pr_number": null,
       "previous_branch": null,
       "remote_head_branch": null,
+      "reviews": [],
+      "store": {
+        "bytes": 0,
+        "objects": 0,
+        "path": "_REPO_/.git/wt/store",
+        "unreferenced": 0,
+        "unreferenced_bytes": 0
+      },
       "summaries": [],
       "trash": [],
       "vars": []
@@ -2057,6 +2336,7 @@ fn test_state_get_json_comprehensive(repo: TestRepo) {
     settings.bind(|| {
         assert_snapshot!(normalized, @r#"
         {
+          "agent_sessions": [],
           "ci_status": [
             {
               "branch": "feature",
@@ -2081,6 +2361,14 @@ fn test_state_get_json_comprehensive(repo: TestRepo) {
           "max_pr_number": null,
           "previous_branch": "feature",
           "remote_head_branch": null,
+          "reviews": [],
+          "store": {
+            "bytes": 0,
+            "objects": 0,
+            "path": "_REPO_/.git/wt/store",
+            "unreferenced": 0,
+            "unreferenced_bytes": 0
+          },
           "summaries": [
             {
               "branch": "feature",
@@ -2145,6 +2433,7 @@ fn test_state_get_json_with_logs(repo: TestRepo) {
     settings.bind(|| {
         assert_snapshot!(normalized, @r#"
         {
+          "agent_sessions": [],
           "ci_status": [],
           "command_log": [
             {
@@ -2184,6 +2473,14 @@ fn test_state_get_json_with_logs(repo: TestRepo) {
           "max_pr_number": null,
           "previous_branch": null,
           "remote_head_branch": null,
+          "reviews": [],
+          "store": {
+            "bytes": 0,
+            "objects": 0,
+            "path": "_REPO_/.git/wt/store",
+            "unreferenced": 0,
+            "unreferenced_bytes": 0
+          },
           "summaries": [],
           "trash": [],
           "vars": []
@@ -3271,3 +3568,199 @@ fn state_branch_flag_resolves_selectors(mut repo: TestRepo) {
         String::from_utf8_lossy(&get.stderr)
     );
 }
+
+// ============================================================================
+// activity
+// ============================================================================
+
+/// The activity directory `wt config state activity` writes.
+fn activity_dir(repo: &TestRepo) -> PathBuf {
+    repo.root_path().join(".git/wt/activity")
+}
+
+/// Rewind every session's `state_since` by `secs`, as if each had been in its
+/// state that long.
+fn age_sessions(repo: &TestRepo, secs: u64) {
+    for entry in std::fs::read_dir(activity_dir(repo).join("sessions")).unwrap() {
+        let path = entry.unwrap().path();
+        let mut session: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        session["state_since"] = serde_json::json!(TEST_EPOCH - secs);
+        std::fs::write(&path, session.to_string()).unwrap();
+    }
+}
+
+#[rstest]
+fn test_activity_set_list_and_end(repo: TestRepo) {
+    // Reports are silent: plugins call them from every agent hook.
+    let output = wt_state_cmd(
+        &repo,
+        "activity",
+        "set",
+        &["waiting", "--tool", "claude", "--session", "s1"],
+    )
+    .output()
+    .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty() && output.stderr.is_empty());
+
+    wt_state_cmd(
+        &repo,
+        "activity",
+        "set",
+        &["working", "--tool", "codex", "--session", "s2"],
+    )
+    .output()
+    .unwrap();
+
+    let output = wt_state_cmd(&repo, "activity", "list", &[])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!("activity_list", String::from_utf8_lossy(&output.stdout));
+
+    let output = wt_state_cmd(
+        &repo,
+        "activity",
+        "end",
+        &["--tool", "claude", "--session", "s1"],
+    )
+    .output()
+    .unwrap();
+    assert!(output.status.success());
+
+    let output = wt_state_cmd(&repo, "activity", "list", &["--format=json"])
+        .output()
+        .unwrap();
+    let sessions: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let tools: Vec<&str> = sessions
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["tool"].as_str().unwrap())
+        .collect();
+    assert_eq!(tools, ["codex"]);
+}
+
+#[rstest]
+fn test_activity_list_filters(repo: TestRepo) {
+    wt_state_cmd(
+        &repo,
+        "activity",
+        "set",
+        &["waiting", "--tool", "claude", "--session", "s1"],
+    )
+    .output()
+    .unwrap();
+    wt_state_cmd(&repo, "activity", "set", &["idle", "--tool", "codex"])
+        .output()
+        .unwrap();
+    age_sessions(&repo, 15 * 60);
+
+    let list = |args: &[&str]| {
+        let output = wt_state_cmd(&repo, "activity", "list", args)
+            .arg("--format=json")
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let sessions: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        sessions
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["tool"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    // "Which agents have been waiting on me for more than 10 minutes"
+    assert_eq!(
+        list(&["--state", "waiting", "--older-than", "10m"]),
+        ["claude"]
+    );
+    assert!(list(&["--state", "waiting", "--older-than", "1h"]).is_empty());
+    assert_eq!(list(&["--state", "waiting,idle"]).len(), 2);
+    assert!(list(&["--branch", "feature"]).is_empty());
+
+    let output = wt_state_cmd(&repo, "activity", "list", &["--older-than", "soon"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[31m✗[39m [31mInvalid [1m--older-than[22m value [1msoon[22m; expected a duration ([1m10m[22m, [1m2h[22m)[39m");
+}
+
+#[rstest]
+fn test_activity_stdin_session_id(repo: TestRepo) {
+    let mut child = wt_state_cmd(
+        &repo,
+        "activity",
+        "set",
+        &["working", "--tool", "claude", "--stdin"],
+    )
+    .stdin(Stdio::piped())
+    .spawn()
+    .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(br#"{"session_id":"abc-123","hook_event_name":"UserPromptSubmit"}"#)
+        .unwrap();
+    assert!(child.wait().unwrap().success());
+
+    let output = wt_state_cmd(&repo, "activity", "list", &["--format=json"])
+        .output()
+        .unwrap();
+    let sessions: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(sessions[0]["session_id"], "abc-123");
+    assert_eq!(sessions[0]["state"], "working");
+}
+
+#[rstest]
+fn test_activity_history_and_clear(repo: TestRepo) {
+    for state in ["working", "waiting", "waiting", "idle"] {
+        wt_state_cmd(
+            &repo,
+            "activity",
+            "set",
+            &[state, "--tool", "claude", "--session", "s1"],
+        )
+        .output()
+        .unwrap();
+    }
+    wt_state_cmd(
+        &repo,
+        "activity",
+        "end",
+        &["--tool", "claude", "--session", "s1"],
+    )
+    .output()
+    .unwrap();
+
+    // A repeated state only refreshes the session; it adds no event.
+    let output = wt_state_cmd(&repo, "activity", "history", &[])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!("activity_history", String::from_utf8_lossy(&output.stdout));
+
+    let output = wt_state_cmd(&repo, "activity", "history", &["-n", "1", "--format=json"])
+        .output()
+        .unwrap();
+    let events: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(events.as_array().unwrap().len(), 1);
+    assert!(events[0]["state"].is_null());
+
+    let output = wt_state_cmd(&repo, "activity", "clear", &[])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mCleared agent activity[39m");
+    let output = wt_state_cmd(&repo, "activity", "history", &[])
+        .output()
+        .unwrap();
+    assert!(output.stdout.is_empty());
+}
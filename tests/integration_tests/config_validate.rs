@@ -0,0 +1,101 @@
+use crate::common::{TestRepo, repo, setup_snapshot_settings, wt_command};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+fn validate_cmd(repo: &TestRepo, args: &[&str]) -> std::process::Command {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "validate"])
+        .args(args)
+        .current_dir(repo.root_path());
+    cmd
+}
+
+#[rstest]
+fn test_config_validate_clean(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = "../{{ repo }}.{{ branch | sanitize }}"
+"#,
+    );
+    repo.write_project_config(
+        r#"[post-start]
+install = "npm ci --prefix {{ worktree_path }}"
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(validate_cmd(&repo, &[]));
+    });
+}
+
+#[rstest]
+fn test_config_validate_reports_all_findings(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[merge]
+squas = true
+
+[list]
+full = "yes"
+"#,
+    );
+    repo.write_project_config(
+        r#"[post-start]
+install = "npm ci --prefix {{ worktre_path }}"
+
+[pre-merge]
+check = "echo {{ base }}"
+broken = "echo {{ branch"
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(validate_cmd(&repo, &[]));
+    });
+}
+
+#[rstest]
+fn test_config_validate_deprecation_is_warning(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = "../{{ main_worktree }}.{{ branch }}"
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(validate_cmd(&repo, &[]));
+    });
+}
+
+#[rstest]
+fn test_config_validate_json(repo: TestRepo) {
+    repo.write_test_config("");
+    repo.write_project_config(
+        r#"[post-start]
+install = "npm ci --prefix {{ brnch }}"
+"#,
+    );
+
+    let output = validate_cmd(&repo, &["--format=json"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["valid"], false);
+    let files = json["files"].as_array().unwrap();
+    assert_eq!(
+        files
+            .iter()
+            .map(|f| f["kind"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        ["user", "project"]
+    );
+    assert!(files[0]["diagnostics"].as_array().unwrap().is_empty());
+    let diagnostic = &files[1]["diagnostics"][0];
+    assert_eq!(diagnostic["severity"], "error");
+    assert_eq!(diagnostic["code"], "unknown-variable");
+    assert_eq!(diagnostic["key"], "post-start.install");
+    assert_eq!(diagnostic["line"], 2);
+    assert_eq!(diagnostic["column"], 31);
+    assert_eq!(diagnostic["suggestion"], "branch");
+}
@@ -1230,6 +1230,8 @@ fn test_uninstall_shell(repo: TestRepo, temp_home: TempDir) {
         [2m↳[22m [2mNo [4mbash[24m shell extension & completions in ~/.bashrc[22m
         [2m↳[22m [2mNo [4mfish[24m shell extension in ~/.config/fish/functions[22m
         [2m↳[22m [2mNo [4mnu[24m shell extension & completions in ~/.local/share/nushell/vendor/autoload[22m
+        [2m↳[22m [2mNo [4mxonsh[24m shell extension & completions in ~/.xonshrc[22m
+        [2m↳[22m [2mNo [4melvish[24m shell extension & completions in ~/.config/elvish/rc.elv[22m
         [2m↳[22m [2mNo [4mfish[24m completions in ~/.config/fish/completions[22m
 
         [32m✓[39m [32mRemoved integration from 1 shell[39m
@@ -1296,6 +1298,8 @@ fn test_uninstall_shell_multiple(repo: TestRepo, temp_home: TempDir) {
         [107m [0m [2m[0m[2m[35mif[0m[2m [0m[2m[34mcommand[0m[2m [0m[2m[36m-v[0m[2m wt [0m[2m[36m>[0m[2m/dev/null [0m[2m[33m2[0m[2m>&1; [0m[2m[35mthen[0m[2m [0m[2m[34meval[0m[2m [0m[2m[32m"$([0m[2m[34mcommand[0m[2m wt config shell init zsh)"[0m[2m; [0m[2m[35mfi[0m
         [2m↳[22m [2mNo [4mfish[24m shell extension in ~/.config/fish/functions[22m
         [2m↳[22m [2mNo [4mnu[24m shell extension & completions in ~/.local/share/nushell/vendor/autoload[22m
+        [2m↳[22m [2mNo [4mxonsh[24m shell extension & completions in ~/.xonshrc[22m
+        [2m↳[22m [2mNo [4melvish[24m shell extension & completions in ~/.config/elvish/rc.elv[22m
         [2m↳[22m [2mNo [4mfish[24m completions in ~/.config/fish/completions[22m
 
         [32m✓[39m [32mRemoved integration from 2 shells[39m
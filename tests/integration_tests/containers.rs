@@ -0,0 +1,200 @@
+//! `[container]`: a container per worktree, started by `wt switch`, shown by
+//! `wt list`, and removed by `wt remove`.
+//!
+//! The runtime is a stub `docker` on `PATH`: it records container states as
+//! files, logs every invocation, and runs `exec` commands locally from the
+//! requested directory — enough to see what worktrunk asks of docker/podman.
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::common::{TestRepo, repo, run_wt, wait_for_worktree_removed};
+use rstest::rstest;
+
+/// Write the stub runtime and a project `[container]`, committed so new
+/// worktrees carry it. Returns the stub's state directory (holding `log`).
+fn setup_container(repo: &TestRepo) -> PathBuf {
+    let state = repo.home_path().join("runtime");
+    fs::create_dir_all(&state).unwrap();
+    fs::create_dir_all(runtime_bin(repo)).unwrap();
+    let runtime = runtime_bin(repo).join("docker");
+    fs::write(
+        &runtime,
+        format!(
+            r#"#!/bin/sh
+state='{}'
+echo "$*" >> "$state/log"
+case "$1" in
+  inspect)
+    shift 4
+    for name; do
+      [ -f "$state/$name" ] && echo "/$name $(cat "$state/$name")"
+    done
+    exit 0 ;;
+  run)
+    while [ "$1" != "--name" ]; do shift; done
+    echo running > "$state/$2" ;;
+  start) echo running > "$state/$2" ;;
+  rm) rm -f "$state/$3" ;;
+  exec) cd "$4" && exec sh -c "$8" ;;
+esac
+"#,
+            state.display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&runtime, fs::Permissions::from_mode(0o755)).unwrap();
+
+    repo.write_project_config(
+        r#"pre-start = "echo hook > from-hook.txt"
+
+[container]
+image = "node:22"
+ports = ["{{ branch | hash_port }}:3000"]
+"#,
+    );
+    repo.commit("Add container config");
+    state
+}
+
+/// The directory holding the stub `docker`, put first on `PATH`.
+fn runtime_bin(repo: &TestRepo) -> PathBuf {
+    repo.home_path().join("runtime-bin")
+}
+
+/// A `wt` command with the stub runtime first on `PATH`.
+fn wt_command(repo: &TestRepo) -> Command {
+    let path = format!(
+        "{}:{}",
+        runtime_bin(repo).display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    let mut cmd = repo.wt_command();
+    cmd.env("PATH", path);
+    cmd
+}
+
+fn log_lines(state: &Path) -> Vec<String> {
+    fs::read_to_string(state.join("log"))
+        .unwrap_or_default()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+/// `switch --create` creates the container with the worktree mounted and a
+/// port published, runs the project hook and `--execute` inside it; once the
+/// approval is saved, `list` shows it running; `remove` removes it.
+#[rstest]
+fn test_container_lifecycle(repo: TestRepo) {
+    let state = setup_container(&repo);
+    let worktree = repo.root_path().parent().unwrap().join("repo.feat");
+    let worktree_str = worktree.to_str().unwrap();
+
+    run_wt(wt_command(&repo).args([
+        "switch",
+        "--create",
+        "feat",
+        "--yes",
+        "-x",
+        "echo exec > from-execute.txt",
+    ]));
+    let log = log_lines(&state);
+    let run = log
+        .iter()
+        .find(|line| line.starts_with("run "))
+        .expect("container created");
+    assert!(
+        run.contains(&format!("-v {worktree_str}:{worktree_str}")),
+        "{run}"
+    );
+    assert!(run.contains(":3000 node:22 sleep infinity"), "{run}");
+    assert!(
+        log.iter()
+            .any(|line| line.starts_with("exec -i -w")
+                && line.ends_with("echo hook > from-hook.txt")),
+        "{log:#?}"
+    );
+    assert!(worktree.join("from-hook.txt").is_file());
+    assert!(worktree.join("from-execute.txt").is_file());
+
+    run_wt(wt_command(&repo).args(["config", "approvals", "add", "--yes"]));
+
+    let json: serde_json::Value = serde_json::from_str(
+        &run_wt(wt_command(&repo).args([
+            "--config-set",
+            "list.json-schema=1",
+            "list",
+            "--format=json",
+        ]))
+        .0,
+    )
+    .unwrap();
+    let with_container: Vec<_> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|row| !row["container"].is_null())
+        .map(|row| {
+            (
+                row["branch"].as_str().unwrap(),
+                row["container"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(with_container, [("feat", "running")]);
+    let table = run_wt(wt_command(&repo).args(["list"])).0;
+    assert!(table.contains("Container"), "table:\n{table}");
+
+    run_wt(wt_command(&repo).args(["remove", "feat", "--force", "--yes"]));
+    wait_for_worktree_removed(&worktree);
+    assert!(
+        log_lines(&state)
+            .iter()
+            .any(|line| line.starts_with("rm -f wt-repo.feat-")),
+        "{:#?}",
+        log_lines(&state)
+    );
+}
+
+/// Switching to a worktree whose container stopped starts it again rather
+/// than creating another.
+#[rstest]
+fn test_switch_restarts_stopped_container(repo: TestRepo) {
+    let state = setup_container(&repo);
+    run_wt(wt_command(&repo).args(["switch", "--create", "feat", "--yes"]));
+    let name = fs::read_dir(&state)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .find(|name| name.starts_with("wt-repo.feat-"))
+        .expect("container recorded");
+    fs::write(state.join(&name), "exited\n").unwrap();
+
+    run_wt(wt_command(&repo).args(["switch", "feat", "--yes"]));
+    let log = log_lines(&state);
+    assert!(log.contains(&format!("start {name}")), "{log:#?}");
+    assert_eq!(
+        log.iter().filter(|line| line.starts_with("run ")).count(),
+        1,
+        "{log:#?}"
+    );
+}
+
+/// Until the `[container]` section is approved, `list` and `remove` never run
+/// the runtime: `--yes` on `switch` approves it for that run only.
+#[rstest]
+fn test_unapproved_container_leaves_runtime_alone(repo: TestRepo) {
+    let state = setup_container(&repo);
+    let worktree = repo.root_path().parent().unwrap().join("repo.feat");
+    run_wt(wt_command(&repo).args(["switch", "--create", "feat", "--yes"]));
+    fs::remove_file(state.join("log")).unwrap();
+
+    let table = run_wt(wt_command(&repo).args(["list"])).0;
+    assert!(!table.contains("Container"), "table:\n{table}");
+    run_wt(wt_command(&repo).args(["remove", "feat", "--force"]));
+    wait_for_worktree_removed(&worktree);
+    assert_eq!(log_lines(&state), Vec::<String>::new());
+}
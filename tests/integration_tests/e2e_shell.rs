@@ -200,6 +200,61 @@ fn test_shell_integration_cd_alias_does_not_break_switch(#[case] shell: &str, re
     );
 }
 
+/// Xonsh and elvish aren't installed on CI runners by default, so these run
+/// where the shell is on PATH and pass trivially elsewhere.
+#[rstest]
+#[case("xonsh")]
+#[case("elvish")]
+fn test_tier2_shell_integration_switch_and_remove(#[case] shell: &str, repo: TestRepo) {
+    if which::which(shell).is_err() {
+        eprintln!("{shell} not on PATH; skipping");
+        return;
+    }
+    let init_code = generate_init_code(&repo, shell);
+    let bin_path = wt_bin_dir();
+
+    let print_pwd = |marker: &str| match shell {
+        "xonsh" => format!(r#"print("{marker}", $PWD)"#),
+        _ => format!(r#"echo "{marker} "$pwd"#),
+    };
+
+    // Top-level statements in xonsh can't be indented
+    let script = [
+        path_export_syntax(shell, &bin_path),
+        init_code,
+        "wt switch --create combo-branch".to_string(),
+        print_pwd("__PWD_AFTER_SWITCH__"),
+        "wt remove".to_string(),
+        print_pwd("__PWD_AFTER_REMOVE__"),
+    ]
+    .join("\n");
+
+    let output = execute_shell_script(&repo, shell, &script);
+
+    assert!(
+        !output.contains("__WORKTRUNK"),
+        "Directive leakage detected in shell output:\n{}",
+        output
+    );
+
+    let after_switch = extract_pwd_marker(&output, "__PWD_AFTER_SWITCH__").unwrap();
+    let after_remove = extract_pwd_marker(&output, "__PWD_AFTER_REMOVE__").unwrap();
+
+    assert!(
+        after_switch.contains("combo-branch"),
+        "{shell} should cd into combo-branch worktree, saw: {}\nFull output:\n{}",
+        after_switch,
+        output,
+    );
+    let repo_root = repo.root_path().to_string_lossy();
+    assert!(
+        after_remove.ends_with(repo_root.as_ref()),
+        "{shell} should cd back to repo root {} after remove, got: {}",
+        repo_root,
+        after_remove
+    );
+}
+
 fn extract_pwd_marker(output: &str, marker: &str) -> Option<String> {
     output
         .lines()
@@ -181,3 +181,53 @@ fn test_eval_commit_matches_head_sha(repo: TestRepo) {
         "eval commit should match HEAD SHA"
     );
 }
+
+/// `{{ ports.<service> }}` assigns each branch's service its own port once,
+/// and `wt remove` hands the branch's ports back.
+#[rstest]
+fn test_eval_ports_registry(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let eval = |dir: &std::path::Path, template: &str| {
+        let output = repo
+            .wt_command()
+            .current_dir(dir)
+            .args(["step", "eval", template])
+            .output()
+            .expect("run wt step eval");
+        assert!(
+            output.status.success(),
+            "wt step eval failed: stderr={}",
+            String::from_utf8_lossy(&output.stderr),
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+
+    let main_web = eval(repo.root_path(), "{{ ports.web }}");
+    assert_eq!(eval(repo.root_path(), "{{ ports.web }}"), main_web);
+    let feature_ports = eval(&feature, "{{ ports.web }},{{ ports.db }}");
+    let (feature_web, feature_db) = feature_ports.split_once(',').unwrap();
+    let mut all = vec![main_web.as_str(), feature_web, feature_db];
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), 3, "ports collide: {main_web},{feature_ports}");
+
+    let registry = repo.root_path().join(".git/wt/ports.json");
+    let recorded: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&registry).unwrap()).unwrap();
+    assert_eq!(recorded["feature"]["db"].to_string(), feature_db);
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let recorded: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&registry).unwrap()).unwrap();
+    assert!(recorded.get("feature").is_none(), "{recorded}");
+    assert_eq!(recorded["main"]["web"].to_string(), main_web);
+}
@@ -37,6 +37,8 @@ fn snapshot_init(test_name: &str, repo: &TestRepo, shell: &str, extra_args: &[&s
 #[case("bash")]
 #[case("fish")]
 #[case("zsh")]
+#[case("xonsh")]
+#[case("elvish")]
 fn test_init(#[case] shell: &str, repo: TestRepo) {
     snapshot_init(&format!("init_{}", shell), &repo, shell, &[]);
 }
@@ -63,8 +65,8 @@ fn test_init_invalid_shell(repo: TestRepo) {
         ----- stdout -----
 
         ----- stderr -----
-        [1m[31merror:[0m invalid value '[1m[33minvalid-shell[0m' for '[1m[36m<bash|fish|nu|zsh|powershell>[0m'
-          [possible values: [1m[32mbash[0m, [1m[32mfish[0m, [1m[32mnu[0m, [1m[32mzsh[0m, [1m[32mpowershell[0m]
+        [1m[31merror:[0m invalid value '[1m[33minvalid-shell[0m' for '[1m[36m<bash|fish|nu|zsh|powershell|xonsh|elvish>[0m'
+          [possible values: [1m[32mbash[0m, [1m[32mfish[0m, [1m[32mnu[0m, [1m[32mzsh[0m, [1m[32mpowershell[0m, [1m[32mxonsh[0m, [1m[32melvish[0m]
 
         For more information, try '[1m[36m--help[0m'.
         ");
@@ -77,6 +79,8 @@ fn test_init_invalid_shell(repo: TestRepo) {
 #[case("nu")]
 #[case("powershell")]
 #[case("zsh")]
+#[case("xonsh")]
+#[case("elvish")]
 fn test_init_rejects_unsafe_cmd(#[case] shell: &str, repo: TestRepo) {
     let mut cmd = wt_command();
     repo.configure_wt_cmd(&mut cmd);
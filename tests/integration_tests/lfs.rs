@@ -0,0 +1,209 @@
+//! Git LFS: `wt switch --create` checks LFS files out as pointers and
+//! hydrates them from the shared `.git/lfs/objects` cache, `copy-ignored`
+//! hydrates a destination left with pointers, and `wt list` shows the state.
+//! Worktrees created through `wt serve` get the same treatment.
+//!
+//! git-lfs itself isn't needed: a stub clean filter stores objects in the
+//! cache and emits pointers, as git-lfs does, and a stub smudge filter logs
+//! whether `GIT_LFS_SKIP_SMUDGE` was set and passes the pointer through.
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use crate::common::json_rpc::JsonRpcChild;
+use crate::common::{TestRepo, repo, run_wt};
+use rstest::rstest;
+use serde_json::json;
+
+const CONTENT: &str = "large binary content\n";
+
+/// Configure the stub LFS filters and commit two LFS files, one of whose
+/// objects is then dropped from the cache. Returns the smudge log.
+fn setup_lfs(repo: &TestRepo) -> PathBuf {
+    let bin = repo.home_path().join("lfs-stub");
+    fs::create_dir_all(&bin).unwrap();
+    let log = bin.join("smudge.log");
+    let clean = bin.join("clean");
+    fs::write(
+        &clean,
+        r#"#!/bin/sh
+tmp=$(mktemp)
+cat > "$tmp"
+if head -n 1 "$tmp" | grep -q '^version https://git-lfs.github.com/spec/v1$'; then
+  cat "$tmp"; rm -f "$tmp"; exit 0
+fi
+oid=$(sha256sum "$tmp" | cut -d' ' -f1)
+size=$(wc -c < "$tmp" | tr -d ' ')
+dir="$(git rev-parse --git-common-dir)/lfs/objects/$(echo "$oid" | cut -c1-2)/$(echo "$oid" | cut -c3-4)"
+mkdir -p "$dir" && mv "$tmp" "$dir/$oid"
+printf 'version https://git-lfs.github.com/spec/v1\noid sha256:%s\nsize %s\n' "$oid" "$size"
+"#,
+    )
+    .unwrap();
+    let smudge = bin.join("smudge");
+    fs::write(
+        &smudge,
+        format!(
+            "#!/bin/sh\necho \"skip=$GIT_LFS_SKIP_SMUDGE\" >> '{}'\ncat\n",
+            log.display()
+        ),
+    )
+    .unwrap();
+    for script in [&clean, &smudge] {
+        fs::set_permissions(script, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    repo.run_git(&["config", "filter.lfs.clean", clean.to_str().unwrap()]);
+    repo.run_git(&["config", "filter.lfs.smudge", smudge.to_str().unwrap()]);
+    repo.run_git(&["config", "filter.lfs.required", "true"]);
+
+    fs::write(
+        repo.root_path().join(".gitattributes"),
+        "*.bin filter=lfs diff=lfs merge=lfs -text\n",
+    )
+    .unwrap();
+    fs::write(repo.root_path().join("cached.bin"), CONTENT).unwrap();
+    fs::write(repo.root_path().join("uncached.bin"), "not fetched\n").unwrap();
+    repo.commit("Add LFS files");
+
+    let uncached = repo.git_output(&["show", "HEAD:uncached.bin"]);
+    let oid = uncached
+        .lines()
+        .find_map(|line| line.strip_prefix("oid sha256:"))
+        .expect("uncached.bin committed as a pointer")
+        .to_string();
+    fs::remove_file(
+        repo.root_path()
+            .join(".git/lfs/objects")
+            .join(&oid[..2])
+            .join(&oid[2..4])
+            .join(&oid),
+    )
+    .unwrap();
+    log
+}
+
+fn is_pointer(path: &Path) -> bool {
+    fs::read_to_string(path)
+        .unwrap()
+        .starts_with("version https://git-lfs.github.com/spec/v1")
+}
+
+/// The new worktree's LFS files come from the cache, not the smudge filter;
+/// an object the cache lacks stays a pointer; the worktree reads clean; and
+/// `wt list` reports one of two files hydrated.
+#[rstest]
+fn test_switch_create_hydrates_lfs_from_cache(repo: TestRepo) {
+    let log = setup_lfs(&repo);
+    let worktree = repo.root_path().parent().unwrap().join("repo.feat");
+
+    let (_, stderr) = run_wt(
+        repo.wt_command()
+            .args(["switch", "--create", "feat", "--yes"]),
+    );
+    assert!(
+        stderr.contains("Hydrated 1 LFS file from the shared cache"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("1 LFS file not in the local cache"),
+        "{stderr}"
+    );
+    assert_eq!(
+        fs::read_to_string(worktree.join("cached.bin")).unwrap(),
+        CONTENT
+    );
+    assert!(is_pointer(&worktree.join("uncached.bin")));
+    let smudges = fs::read_to_string(&log).unwrap();
+    assert!(
+        smudges.lines().all(|line| line == "skip=1"),
+        "smudge ran without GIT_LFS_SKIP_SMUDGE:\n{smudges}"
+    );
+    let status = repo
+        .git_command()
+        .current_dir(&worktree)
+        .args(["status", "--porcelain"])
+        .run()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&status.stdout), "");
+
+    let (stdout, _) = run_wt(repo.wt_command().args([
+        "--config-set",
+        "list.json-schema=1",
+        "list",
+        "--format=json",
+    ]));
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let feat = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|row| row["branch"] == "feat")
+        .unwrap();
+    assert_eq!(feat["lfs"], serde_json::json!({"files": 2, "pointers": 1}));
+    let (table, _) = run_wt(repo.wt_command().args(["list"]));
+    assert!(table.contains("LFS"), "table:\n{table}");
+    assert!(table.contains("1/2"), "table:\n{table}");
+}
+
+/// A worktree created outside worktrunk with pointers is hydrated by
+/// `copy-ignored`.
+#[rstest]
+fn test_copy_ignored_hydrates_lfs_pointers(repo: TestRepo) {
+    setup_lfs(&repo);
+    let worktree = repo.root_path().parent().unwrap().join("repo.plain");
+    let output = repo
+        .git_command()
+        .env("GIT_LFS_SKIP_SMUDGE", "1")
+        .args(["worktree", "add", "-b", "plain"])
+        .arg(worktree.to_str().unwrap())
+        .run()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(is_pointer(&worktree.join("cached.bin")));
+
+    run_wt(
+        repo.wt_command()
+            .args(["step", "copy-ignored", "--to", "plain"]),
+    );
+    assert_eq!(
+        fs::read_to_string(worktree.join("cached.bin")).unwrap(),
+        CONTENT
+    );
+}
+
+/// `wt serve`'s `switch` creates worktrees through the same setup as
+/// `wt switch`: pointers checked out, then hydrated from the cache, with the
+/// uncached object reported in the result's warnings.
+#[rstest]
+fn test_serve_switch_hydrates_lfs_from_cache(repo: TestRepo) {
+    let log = setup_lfs(&repo);
+    let mut cmd = repo.wt_command();
+    cmd.args(["serve", "--stdio"]);
+    let mut rpc = JsonRpcChild::spawn(cmd);
+
+    let id = rpc.send_request(
+        "switch",
+        json!({"branch": "feat", "create": true, "hooks": false}),
+    );
+    let response = rpc.recv("switch response");
+    assert_eq!(response["id"], id, "{response}");
+    let created = &response["result"];
+    let worktree = PathBuf::from(created["path"].as_str().unwrap());
+
+    assert_eq!(
+        fs::read_to_string(worktree.join("cached.bin")).unwrap(),
+        CONTENT
+    );
+    assert!(is_pointer(&worktree.join("uncached.bin")));
+    assert_eq!(
+        created["warnings"],
+        json!(["1 LFS file not in the local cache, left as pointers"])
+    );
+    let smudges = fs::read_to_string(&log).unwrap();
+    assert!(
+        smudges.lines().all(|line| line == "skip=1"),
+        "smudge ran without GIT_LFS_SKIP_SMUDGE:\n{smudges}"
+    );
+}
@@ -379,6 +379,61 @@ fn test_list_json_schema_2_envelope(mut repo: TestRepo) {
     });
 }
 
+/// `--format=ndjson` streams a header, one `item` per row, patches, and `end`;
+/// applying the patches reproduces the schema-2 envelope's items exactly.
+#[rstest]
+fn test_list_ndjson_patches_converge_to_schema_2(mut repo: TestRepo) {
+    repo.write_test_config("[list]\njson-schema = 2\n");
+    repo.add_worktree("feature");
+    std::fs::write(repo.worktree_path("feature").join("new.txt"), "x").unwrap();
+
+    let run = |format: &str| {
+        let output = repo.wt_command().args(["list", format]).output().unwrap();
+        assert!(
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        output.stdout
+    };
+
+    let envelope: serde_json::Value = serde_json::from_slice(&run("--format=json")).unwrap();
+    let stream = run("--format=ndjson");
+    let records: Vec<serde_json::Value> = stream
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| serde_json::from_slice(line).unwrap())
+        .collect();
+
+    assert_eq!(records[0]["type"], "header");
+    assert_eq!(records[0]["schema"], 2);
+    assert_eq!(records[0]["repo"], envelope["repo"]);
+    assert_eq!(records.last().unwrap()["type"], "end");
+
+    let mut items: Vec<serde_json::Value> = Vec::new();
+    for record in &records[1..records.len() - 1] {
+        match record["type"].as_str().unwrap() {
+            "item" => {
+                assert_eq!(record["index"], items.len());
+                items.push(record["item"].clone());
+            }
+            "patch" => {
+                let item = items[record["index"].as_u64().unwrap() as usize]
+                    .as_object_mut()
+                    .unwrap();
+                for (key, value) in record["set"].as_object().into_iter().flatten() {
+                    item.insert(key.clone(), value.clone());
+                }
+                for key in record["unset"].as_array().into_iter().flatten() {
+                    item.remove(key.as_str().unwrap());
+                }
+            }
+            other => panic!("unexpected record type {other}"),
+        }
+    }
+    assert_eq!(serde_json::Value::from(items), envelope["items"]);
+}
+
 /// `[list] json-schema` selects the output schema: unset emits schema 1
 /// plus a one-time nag, an explicit value is silent, and anything except
 /// 1 or 2 is an error.
@@ -1183,6 +1238,62 @@ fn test_list_json_with_user_marker(mut repo: TestRepo) {
     });
 }
 
+/// Record an agent session in `worktree` the way the plugins do.
+fn report_agent_state(repo: &TestRepo, worktree: &std::path::Path, tool: &str, state: &str) {
+    let output = repo
+        .wt_command()
+        .current_dir(worktree)
+        .args(["config", "state", "activity", "set", state, "--tool", tool])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_list_with_agent_sessions(mut repo: TestRepo) {
+    repo.commit_with_age("Initial commit", DAY);
+    let feature = repo.add_worktree("feature");
+    repo.add_worktree("idle-branch");
+    report_agent_state(&repo, &feature, "claude", "waiting");
+    report_agent_state(&repo, &feature, "codex", "working");
+
+    // The Agent column shows the most recently active session (a tie under
+    // the pinned test clock goes to the earlier tool name) plus a count of the
+    // others; worktrees without sessions leave it blank.
+    assert_cmd_snapshot!(list_snapshots::command(&repo, repo.root_path()));
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--format=json"])
+        .output()
+        .unwrap();
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let feature_item = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    let states: Vec<&str> = feature_item["agents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|agent| agent["state"].as_str().unwrap())
+        .collect();
+    assert_eq!(states.len(), 2);
+    assert!(states.contains(&"working") && states.contains(&"waiting"));
+    let main_item = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "main")
+        .unwrap();
+    assert!(main_item.get("agents").is_none());
+}
+
 #[rstest]
 fn test_list_json_with_git_operation(mut repo: TestRepo) {
     // Test JSON output includes git_operation field when worktree is in rebase state
@@ -2732,6 +2843,44 @@ url = "http://localhost:{{ branch | hash_port }}"
     );
 }
 
+/// A `{{ ports.<service> }}` URL shows ports already assigned and assigns
+/// none itself: listing is read-only.
+#[rstest]
+fn test_list_url_does_not_assign_ports(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.write_project_config("[list]\nurl = \"http://localhost:{{ ports.web }}\"\n");
+    let registry = repo.root_path().join(".git/wt/ports.json");
+
+    let list_json = |repo: &TestRepo| {
+        let output = repo
+            .wt_command()
+            .args(["list", "--format=json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap()
+    };
+    list_json(&repo);
+    assert!(!registry.exists());
+
+    let eval = repo
+        .wt_command()
+        .current_dir(&feature)
+        .args(["step", "eval", "{{ ports.web }}"])
+        .output()
+        .unwrap();
+    assert!(eval.status.success(), "{eval:?}");
+    let port = String::from_utf8_lossy(&eval.stdout).trim().to_string();
+    let before = std::fs::read_to_string(&registry).unwrap();
+
+    let listed = list_json(&repo).to_string();
+    assert!(
+        listed.contains(&format!("http://localhost:{port}")),
+        "{listed}"
+    );
+    assert_eq!(std::fs::read_to_string(&registry).unwrap(), before);
+}
+
 #[rstest]
 fn test_list_no_progressive_flag(mut repo: TestRepo) {
     repo.add_worktree("feature");
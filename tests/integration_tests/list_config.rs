@@ -946,6 +946,86 @@ template = "{{ branch | codename }}"
     });
 }
 
+/// Document formats render the table's columns: the `[list] columns`
+/// selection (custom columns included) picks and orders them, and a cell
+/// carrying the Markdown delimiter is escaped.
+#[rstest]
+fn test_list_format_markdown_follows_column_selection(repo: TestRepo) {
+    fs::write(
+        repo.test_config_path(),
+        r#"[list]
+columns = ["branch", "Ticket", "working-diff"]
+
+[list.custom-columns.Ticket]
+template = "{{ vars.ticket }}"
+"#,
+    )
+    .unwrap();
+    repo.run_git(&[
+        "config",
+        "worktrunk.state.feature-a.vars.ticket",
+        "JIRA-1|2",
+    ]);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        cmd.args(["list", "--format=markdown"])
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+/// CSV and HTML carry the same cells as Markdown, in their own escaping.
+#[rstest]
+fn test_list_format_csv_and_html(repo: TestRepo) {
+    fs::write(
+        repo.test_config_path(),
+        r#"[list]
+columns = ["branch", "Ticket"]
+
+[list.custom-columns.Ticket]
+template = "{{ vars.ticket }}"
+"#,
+    )
+    .unwrap();
+    repo.run_git(&[
+        "config",
+        "worktrunk.state.feature-a.vars.ticket",
+        "<JIRA-1>, \"two\"",
+    ]);
+
+    let run = |format: &str| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        cmd.args(["list", &format!("--format={format}")])
+            .current_dir(repo.root_path());
+        let output = cmd.output().unwrap();
+        assert!(
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let csv = run("csv");
+    assert_eq!(csv.lines().next(), Some(",Branch,Ticket"));
+    assert!(
+        csv.lines()
+            .any(|line| line == r#"+,feature-a,"<JIRA-1>, ""two""""#),
+        "csv: {csv}"
+    );
+
+    let html = run("html");
+    assert!(
+        html.contains("<td>feature-a</td><td>&lt;JIRA-1&gt;, &quot;two&quot;</td>"),
+        "html: {html}"
+    );
+}
+
 /// A narrowed `[list] columns` selection prunes the git work that fed only the
 /// hidden columns — not just the rendered cells (#3133). With `["branch", "age"]`
 /// no column consumes a background task, so `git status` (run by the
@@ -0,0 +1,227 @@
+//! Integration tests for `wt mcp`.
+//!
+//! [`Client`] is a minimal MCP client: it spawns `wt mcp` as an MCP host
+//! would, performs the `initialize` handshake, and calls tools over the
+//! stdio transport.
+
+use crate::common::json_rpc::JsonRpcChild;
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use serde_json::{Value, json};
+
+struct Client {
+    rpc: JsonRpcChild,
+}
+
+impl Client {
+    /// Start the server and complete the handshake at `protocol_version`,
+    /// returning the `initialize` result alongside the client.
+    fn connect_with(repo: &TestRepo, protocol_version: &str) -> (Self, Value) {
+        let mut cmd = repo.wt_command();
+        cmd.arg("mcp");
+        let mut client = Self {
+            rpc: JsonRpcChild::spawn(cmd),
+        };
+        let initialized = client.request(
+            "initialize",
+            json!({
+                "protocolVersion": protocol_version,
+                "capabilities": {},
+                "clientInfo": {"name": "stub", "version": "0"},
+            }),
+        );
+        client.send(&json!({"jsonrpc": "2.0", "method": "notifications/initialized"}));
+        (client, initialized["result"].clone())
+    }
+
+    fn connect(repo: &TestRepo) -> Self {
+        Self::connect_with(repo, "2025-06-18").0
+    }
+
+    fn send(&mut self, message: &Value) {
+        self.rpc.send(message);
+    }
+
+    /// Send a request and wait for its response. The server sends nothing
+    /// unprompted, so the next line is the answer.
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        let id = self.rpc.send_request(method, params);
+        let response = self.rpc.recv("response");
+        assert_eq!(response["id"], id, "{response}");
+        response
+    }
+
+    /// Call a tool and return its result, failed or not.
+    fn call_tool(&mut self, name: &str, arguments: Value) -> Value {
+        let response = self.request("tools/call", json!({"name": name, "arguments": arguments}));
+        assert!(response.get("error").is_none(), "{response}");
+        response["result"].clone()
+    }
+
+    /// Call a tool that must succeed and return its structured content.
+    fn call_ok(&mut self, name: &str, arguments: Value) -> Value {
+        let result = self.call_tool(name, arguments);
+        assert_eq!(result["isError"], false, "{result}");
+        result["structuredContent"].clone()
+    }
+
+    /// The text content of a tool result.
+    fn text(result: &Value) -> &str {
+        result["content"][0]["text"].as_str().unwrap()
+    }
+}
+
+/// The handshake negotiates the protocol version and advertises tools, each
+/// with an object input schema.
+#[rstest]
+fn test_mcp_handshake_and_tools(repo: TestRepo) {
+    let (_, older) = Client::connect_with(&repo, "2024-11-05");
+    assert_eq!(older["protocolVersion"], "2024-11-05");
+    assert_eq!(older["serverInfo"]["name"], "worktrunk");
+
+    let (mut client, unknown) = Client::connect_with(&repo, "1999-01-01");
+    assert_eq!(unknown["protocolVersion"], "2025-06-18");
+    assert!(unknown["capabilities"]["tools"].is_object());
+
+    assert_eq!(client.request("ping", json!({}))["result"], json!({}));
+
+    let tools = client.request("tools/list", json!({}))["result"]["tools"].clone();
+    let names: Vec<&str> = tools
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "list_worktrees",
+            "create_worktree",
+            "worktree_diff",
+            "set_marker",
+            "set_var",
+            "merge"
+        ]
+    );
+    for tool in tools.as_array().unwrap() {
+        assert_eq!(tool["inputSchema"]["type"], "object", "{tool}");
+    }
+
+    let unknown_tool = client.request("tools/call", json!({"name": "rebase_everything"}));
+    assert_eq!(unknown_tool["error"]["code"], -32602);
+    let bad_arguments = client.request(
+        "tools/call",
+        json!({"name": "worktree_diff", "arguments": {}}),
+    );
+    assert_eq!(bad_arguments["error"]["code"], -32602);
+}
+
+/// `list_worktrees` returns the envelope `wt list --format=json` prints.
+#[rstest]
+fn test_mcp_list_worktrees_matches_wt_list(mut repo: TestRepo) {
+    repo.write_test_config("[list]\njson-schema = 2\n");
+    repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected: Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let mut client = Client::connect(&repo);
+    assert_eq!(client.call_ok("list_worktrees", json!({})), expected);
+}
+
+/// A subtask's life: create a sibling worktree, mark it, read its diff from
+/// the main worktree, then squash-merge it.
+#[rstest]
+fn test_mcp_subtask_round_trip(repo: TestRepo) {
+    let mut client = Client::connect(&repo);
+
+    let created = client.call_ok("create_worktree", json!({"branch": "subtask"}));
+    let path = std::path::PathBuf::from(created["path"].as_str().unwrap());
+    assert!(path.is_dir());
+    assert_eq!(
+        repo.git_output(&["rev-parse", "subtask"]),
+        repo.git_output(&["rev-parse", "main"])
+    );
+
+    client.call_ok("set_marker", json!({"branch": "subtask", "marker": "🤖"}));
+    client.call_ok(
+        "set_var",
+        json!({"branch": "subtask", "key": "ticket", "value": "ENG-1"}),
+    );
+    let listed = client.call_ok("list_worktrees", json!({}));
+    let row = listed["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "subtask")
+        .unwrap();
+    assert_eq!(row["vars"], json!({"ticket": "ENG-1"}));
+    assert!(row.to_string().contains("🤖"), "{row}");
+
+    for name in ["started.txt", "done.txt"] {
+        std::fs::write(path.join(name), format!("{name}\n")).unwrap();
+        repo.run_git_in(&path, &["add", name]);
+        repo.run_git_in(&path, &["commit", "-m", name]);
+    }
+    std::fs::write(path.join("scratch.txt"), "untracked\n").unwrap();
+
+    let diff = client.call_tool("worktree_diff", json!({"branch": "subtask"}));
+    let patch = Client::text(&diff);
+    assert!(patch.contains("+done.txt"), "{patch}");
+    assert!(patch.contains("+untracked"), "{patch}");
+    let stat = client.call_tool("worktree_diff", json!({"branch": "subtask", "stat": true}));
+    assert!(Client::text(&stat).contains("3 files changed"), "{stat}");
+
+    // Uncommitted work blocks the merge, reported to the model as a failed
+    // tool rather than a protocol error.
+    let blocked = client.call_tool("merge", json!({"branch": "subtask"}));
+    assert_eq!(blocked["isError"], true, "{blocked}");
+    std::fs::remove_file(path.join("scratch.txt")).unwrap();
+
+    let merged = client.call_ok(
+        "merge",
+        json!({"branch": "subtask", "squash_message": "Land the subtask"}),
+    );
+    assert_eq!(merged["target"], "main");
+    assert_eq!(merged["removed"], true);
+    assert!(!path.exists());
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s", "main"]),
+        "Land the subtask"
+    );
+}
+
+/// Unapproved project hooks stop a tool before it changes anything, and the
+/// agent can't approve them; once the user does, the same call succeeds.
+#[rstest]
+fn test_mcp_requires_approved_hooks(repo: TestRepo) {
+    repo.write_project_config("pre-start = \"echo started\"\n");
+    let mut client = Client::connect(&repo);
+
+    let refused = client.call_tool("create_worktree", json!({"branch": "subtask"}));
+    assert_eq!(refused["isError"], true);
+    assert!(
+        Client::text(&refused).contains("wt config approvals add"),
+        "{refused}"
+    );
+    assert_eq!(
+        refused["structuredContent"]["unapproved"],
+        json!([{"hook": "pre-start", "name": null, "command": "echo started"}])
+    );
+    assert_eq!(repo.git_output(&["branch", "--list", "subtask"]), "");
+
+    let approved = repo
+        .wt_command()
+        .args(["config", "approvals", "add", "--yes"])
+        .output()
+        .unwrap();
+    assert!(approved.status.success(), "{approved:?}");
+
+    let created = client.call_ok("create_worktree", json!({"branch": "subtask"}));
+    assert!(std::path::Path::new(created["path"].as_str().unwrap()).is_dir());
+}
@@ -18,7 +18,9 @@ pub mod config_show;
 pub mod config_show_theme;
 pub mod config_state;
 pub mod config_update_pty;
+pub mod config_validate;
 pub mod configure_shell;
+pub mod containers;
 pub mod custom;
 pub mod default_branch;
 pub mod diagnostic;
@@ -32,10 +34,12 @@ pub mod git_error_display;
 pub mod help;
 pub mod hook_show;
 pub mod init;
+pub mod lfs;
 pub mod list;
 pub mod list_config;
 pub mod list_layout;
 pub mod list_progressive;
+pub mod mcp;
 pub mod merge;
 pub mod nushell_default_config_dir;
 pub mod output_system_guard;
@@ -43,10 +47,13 @@ pub mod packaged_assets;
 pub mod post_start_commands;
 pub mod push;
 pub mod readme_sync;
+pub mod remote_hosts;
 pub mod remove;
 pub mod repository;
+pub mod sandbox;
 pub mod security;
 pub mod select_config;
+pub mod serve;
 pub mod shell_integration_prompt;
 pub mod shell_integration_windows;
 pub mod shell_powershell;
@@ -59,7 +66,10 @@ pub mod step_diff;
 pub mod step_promote;
 pub mod step_prune;
 pub mod step_relocate;
+pub mod step_resolve;
+pub mod step_review;
 pub mod step_tether;
+pub mod submodules;
 pub mod switch;
 pub mod switch_picker;
 pub mod switch_picker_dry_run;
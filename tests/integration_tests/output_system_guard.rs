@@ -48,10 +48,16 @@ const STDOUT_ALLOWED_PATHS: &[&str] = &[
     "commands/init.rs",
     // Table and summary output for wt list
     "commands/list/collect/mod.rs",
+    // NDJSON record stream for wt list --format=ndjson
+    "commands/list/json_stream.rs",
     // State data output (branch names, previous worktree, etc.)
     "commands/config/state.rs",
+    // Command history rows and --stats table (pipeable; --follow streams)
+    "commands/config/history.rs",
     // Hint list output
     "commands/config/hints.rs",
+    // Agent session and activity history tables (pipeable)
+    "commands/config/activity.rs",
     // Alias introspection output (show / dry-run), intended to be pipeable
     "commands/config/alias.rs",
     // Template evaluation output for scripting
@@ -63,6 +69,8 @@ const STDOUT_ALLOWED_PATHS: &[&str] = &[
     "commands/step/copy_ignored.rs",
     // wt step prune dry-run plan (human preview + --format=json)
     "commands/step/prune.rs",
+    // wt step review findings (pipeable; status lines go to stderr)
+    "commands/step/review.rs",
     // wt step relocate dry-run human preview (show_dry_run_preview)
     "commands/relocate.rs",
     // wt config shell install/uninstall --dry-run preview (the interactive
@@ -1541,7 +1541,7 @@ fn test_starlight_frontmatter_helpers() {
 /// Each page preserves its frontmatter and replaces the AUTO-GENERATED marker region.
 /// Note: `select` is excluded because it's a deprecated hidden alias for `wt switch`.
 const COMMAND_PAGES: &[&str] = &[
-    "switch", "list", "merge", "remove", "config", "step", "hook",
+    "switch", "list", "merge", "remove", "config", "step", "hook", "serve", "mcp",
 ];
 
 /// Write `expected` to `path` and record `rel_path` in `updated`. Creates
@@ -2173,47 +2173,66 @@ fn sync_cli_mod_example_bodies(project_root: &Path) -> (Vec<String>, Vec<String>
     (errors, updated_files)
 }
 
-/// Generate `docs/public/schema/list-v2.json` from `wt list --print-schema`,
-/// publishing it at the `$id` the document carries
-/// (`https://worktrunk.dev/schema/list-v2.json`).
+/// Generate the published JSON Schemas under `docs/public/schema/`:
+///
+/// - `list-v2.json` from `wt list --print-schema`, at the `$id` the document
+///   carries (`https://worktrunk.dev/schema/list-v2.json`)
+/// - `user-config.json` / `project-config.json` from `wt config schema`, the
+///   URLs `wt config create` writes into its `#:schema` directive
 ///
 /// Shells out rather than calling `schema_for!` directly: the `JsonEnvelope`
 /// it derives from lives in the bin-only `crate::commands` tree, which an
 /// integration test can't import. Same reason `sync_command_pages` runs
 /// `--help-page`.
 ///
-/// A schemars upgrade rewrites this file. That shows up here as an ordinary
+/// A schemars upgrade rewrites these files. That shows up here as an ordinary
 /// out-of-sync failure, which is the intent — a consumer's schema changing
 /// under a dependency bump should be a reviewed diff.
 fn sync_json_schema(project_root: &Path) -> (Vec<String>, Vec<String>) {
     let mut errors = Vec::new();
     let mut updated_files = Vec::new();
 
-    let output = wt_command()
-        .args(["list", "--print-schema"])
-        .current_dir(project_root)
-        .output()
-        .expect("Failed to run wt list --print-schema");
+    let schemas: [(&[&str], &str); 3] = [
+        (
+            &["list", "--print-schema"],
+            "docs/public/schema/list-v2.json",
+        ),
+        (
+            &["config", "schema", "--user"],
+            "docs/public/schema/user-config.json",
+        ),
+        (
+            &["config", "schema", "--project"],
+            "docs/public/schema/project-config.json",
+        ),
+    ];
+    for (args, rel_path) in schemas {
+        let display = format!("wt {}", args.join(" "));
+        let output = wt_command()
+            .args(args)
+            .current_dir(project_root)
+            .output()
+            .unwrap_or_else(|e| panic!("Failed to run {display}: {e}"));
 
-    if !output.status.success() {
-        errors.push(format!(
-            "'wt list --print-schema' failed (exit {}): {}",
-            output.status.code().unwrap_or(-1),
-            String::from_utf8_lossy(&output.stderr)
-        ));
-        return (errors, updated_files);
-    }
+        if !output.status.success() {
+            errors.push(format!(
+                "'{display}' failed (exit {}): {}",
+                output.status.code().unwrap_or(-1),
+                String::from_utf8_lossy(&output.stderr)
+            ));
+            continue;
+        }
 
-    let generated = String::from_utf8_lossy(&output.stdout).to_string();
-    if generated.trim().is_empty() {
-        errors.push("Empty output from 'wt list --print-schema'".to_string());
-        return (errors, updated_files);
-    }
+        let generated = String::from_utf8_lossy(&output.stdout).to_string();
+        if generated.trim().is_empty() {
+            errors.push(format!("Empty output from '{display}'"));
+            continue;
+        }
 
-    let rel_path = "docs/public/schema/list-v2.json";
-    let dst = project_root.join(rel_path);
-    if fs::read_to_string(&dst).unwrap_or_default() != generated {
-        write_tracked(&dst, &generated, rel_path, &mut updated_files);
+        let dst = project_root.join(rel_path);
+        if fs::read_to_string(&dst).unwrap_or_default() != generated {
+            write_tracked(&dst, &generated, rel_path, &mut updated_files);
+        }
     }
 
     (errors, updated_files)
@@ -2612,8 +2631,12 @@ fn test_template_variables_table_matches_constants() {
     exec.insert("hook_name".into());
     exec.insert(ALIAS_ARGS_KEY.to_string());
     expected.insert("exec".into(), exec);
-    // `user` row has a single entry — the `{{ vars.<key> }}` placeholder.
-    expected.insert("user".into(), BTreeSet::from(["vars.<key>".to_string()]));
+    // `user` rows are placeholders — `{{ vars.<key> }}` and the port registry's
+    // `{{ ports.<service> }}`.
+    expected.insert(
+        "user".into(),
+        BTreeSet::from(["vars.<key>".to_string(), "ports.<service>".to_string()]),
+    );
     // `operation` = union of hook-type-specific extras. Derived through the
     // public `vars_available_in` so this test doesn't depend on the private
     // `hook_extras` helper.
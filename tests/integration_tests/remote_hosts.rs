@@ -0,0 +1,210 @@
+//! `[remotes]` hosts: `--on <name>` for switch/remove/merge and host rows in
+//! `wt list`.
+//!
+//! The "host" is a second clone on the local filesystem, reached through a
+//! fake `ssh` that runs the remote command with its own (empty) user config —
+//! as a real host would have.
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use crate::common::{TestRepo, repo, run_wt, wait_for_worktree_removed, wt_bin};
+use rstest::rstest;
+
+/// Clone the test repo as the `devbox` host's checkout, and point
+/// `[remotes.devbox]` at it. Returns the clone's path.
+fn setup_devbox(repo: &TestRepo) -> PathBuf {
+    let home = repo.home_path().join("devbox");
+    let clone = home.join("repo");
+    fs::create_dir_all(&home).unwrap();
+    repo.run_git_in(
+        &home,
+        &[
+            "clone",
+            "--quiet",
+            repo.root_path().to_str().unwrap(),
+            "repo",
+        ],
+    );
+
+    let host_config = home.join("config.toml");
+    fs::write(&host_config, "").unwrap();
+    let ssh = home.join("fake-ssh");
+    fs::write(
+        &ssh,
+        format!(
+            "#!/bin/sh\n# The last argument is the remote command.\nfor last; do :; done\nexport WORKTRUNK_CONFIG_PATH='{}'\nexec sh -c \"$last\"\n",
+            host_config.display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&ssh, fs::Permissions::from_mode(0o755)).unwrap();
+
+    repo.write_test_config(&format!(
+        r#"[remotes.devbox]
+host = "devbox"
+path = "{}"
+ssh = "{}"
+wt = "{}"
+"#,
+        clone.display(),
+        ssh.display(),
+        wt_bin().display()
+    ));
+    clone
+}
+
+/// `switch --on` creates the worktree on the host, `list` shows it as a host
+/// row, and `remove --on` removes it there — the local repo never gains it.
+#[rstest]
+fn test_switch_list_remove_on_host(repo: TestRepo) {
+    let clone = setup_devbox(&repo);
+    let remote_worktree = clone.parent().unwrap().join("repo.feat");
+
+    run_wt(
+        repo.wt_command()
+            .args(["switch", "--on", "devbox", "--create", "feat", "--yes"]),
+    );
+    assert!(remote_worktree.is_dir());
+    assert!(
+        !repo
+            .root_path()
+            .parent()
+            .unwrap()
+            .join("repo.feat")
+            .exists()
+    );
+
+    let json: serde_json::Value = serde_json::from_str(
+        &run_wt(repo.wt_command().args([
+            "--config-set",
+            "list.json-schema=1",
+            "list",
+            "--format=json",
+        ]))
+        .0,
+    )
+    .unwrap();
+    let rows = json.as_array().unwrap();
+    let host_rows: Vec<_> = rows.iter().filter(|row| row["host"] == "devbox").collect();
+    assert_eq!(
+        host_rows
+            .iter()
+            .map(|row| row["branch"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        ["main", "feat"]
+    );
+    assert_eq!(
+        Path::new(host_rows[1]["path"].as_str().unwrap())
+            .file_name()
+            .unwrap(),
+        "repo.feat"
+    );
+    assert!(
+        rows.iter()
+            .any(|row| row["branch"] == "main" && row["host"].is_null())
+    );
+
+    let table = run_wt(repo.wt_command().args(["list"])).0;
+    assert!(table.contains("devbox:"), "table:\n{table}");
+
+    run_wt(
+        repo.wt_command()
+            .args(["remove", "--on", "devbox", "feat", "--yes"]),
+    );
+    assert!(!remote_worktree.exists());
+}
+
+/// `merge --on` merges the host's worktree for the local current branch.
+#[rstest]
+fn test_merge_on_host(mut repo: TestRepo) {
+    let clone = setup_devbox(&repo);
+    let local_feat = repo.add_worktree("feat");
+
+    run_wt(
+        repo.wt_command()
+            .args(["switch", "--on", "devbox", "--create", "feat", "--yes"]),
+    );
+    let remote_worktree = clone.parent().unwrap().join("repo.feat");
+    repo.commit_in_worktree(
+        &remote_worktree,
+        "remote.txt",
+        "on devbox",
+        "Add remote file",
+    );
+
+    run_wt(
+        repo.wt_command()
+            .current_dir(&local_feat)
+            .args(["merge", "--on", "devbox", "--yes"]),
+    );
+    assert!(clone.join("remote.txt").is_file());
+    wait_for_worktree_removed(&remote_worktree);
+    assert!(local_feat.is_dir());
+}
+
+/// An undefined host fails before anything runs, naming the config section.
+#[rstest]
+fn test_switch_on_unknown_host(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--on", "nowhere", "feat"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("[remotes.nowhere]"), "stderr: {stderr}");
+}
+
+/// A host that fails to answer is skipped with a warning; local rows remain.
+#[rstest]
+fn test_list_skips_unreachable_host(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[remotes.devbox]
+host = "devbox"
+path = "/srv/repo"
+ssh = "false"
+"#,
+    );
+    let output = repo.wt_command().args(["list"]).output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Skipped remote host"), "stderr: {stderr}");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("main"));
+}
+
+/// `--format=ndjson` doesn't hold the local rows for the hosts: host rows
+/// follow as extra `item` records, indexed after the local ones, before `end`.
+#[rstest]
+fn test_list_ndjson_appends_host_rows(repo: TestRepo) {
+    setup_devbox(&repo);
+
+    let stream = run_wt(repo.wt_command().args(["list", "--format=ndjson"])).0;
+    let records: Vec<serde_json::Value> = stream
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records.last().unwrap()["type"], "end");
+
+    let items: Vec<_> = records.iter().filter(|r| r["type"] == "item").collect();
+    for (index, record) in items.iter().enumerate() {
+        assert_eq!(record["index"], index);
+    }
+    // Local rows first, then the host's checkout.
+    let local = items
+        .iter()
+        .take_while(|r| r["item"]["worktree"]["host"].is_null())
+        .count();
+    assert_eq!(local, 4, "{stream}");
+    let host_rows = &items[local..];
+    assert_eq!(host_rows.len(), 1, "{stream}");
+    assert_eq!(host_rows[0]["item"]["worktree"]["host"], "devbox");
+    assert_eq!(host_rows[0]["item"]["branch"], "main");
+
+    // The local rows didn't wait: their drain patches precede the host row.
+    let first_patch = records.iter().position(|r| r["type"] == "patch").unwrap();
+    let host_item = records.iter().position(|r| r["index"] == local).unwrap();
+    assert!(first_patch < host_item, "{stream}");
+}
@@ -0,0 +1,180 @@
+//! `[hooks] sandbox = "bubblewrap"`: project hooks run under bwrap, able to
+//! write the worktree but not what git reads to decide what to execute.
+//!
+//! Skipped where bubblewrap can't run (not installed, or no unprivileged user
+//! namespaces).
+#![cfg(target_os = "linux")]
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::common::json_rpc::JsonRpcChild;
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use serde_json::json;
+
+/// Each attempt that succeeds appends its label to `escaped.txt`; the hook
+/// then proves it ran by writing `sandboxed.txt`.
+const PROBE_HOOK: &str = r##"pre-start = '''
+attempt() { if (eval "$2") 2>/dev/null; then echo "$1" >> escaped.txt; fi; }
+common=$(git rev-parse --git-common-dir)
+gitdir=$(git rev-parse --git-dir)
+attempt hooks 'echo "#!/bin/sh" > "$common/hooks/post-checkout"'
+attempt config 'git config core.fsmonitor "touch /tmp/pwned"'
+attempt config-file 'echo "[core]" >> "$common/config"'
+attempt refs 'git update-ref refs/heads/planted HEAD'
+attempt objects 'touch "$common/objects/planted"'
+attempt packed-refs 'echo >> "$common/packed-refs"'
+if [ "$gitdir" = "$common" ]; then
+  attempt worktrees 'for f in "$common"/worktrees/*/commondir; do echo /tmp > "$f"; done'
+else
+  attempt gitlink 'echo "gitdir: /tmp" > .git'
+  attempt commondir 'echo /tmp > "$gitdir/commondir"'
+fi
+echo ok > sandboxed.txt
+'''
+"##;
+
+fn bwrap_works() -> bool {
+    Command::new("bwrap")
+        .args(["--ro-bind", "/", "/", "true"])
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Set up the probe hook as an approved project hook, for the paths that
+/// can't prompt.
+fn approve_probe_hook(repo: &TestRepo) {
+    repo.write_test_config("[hooks]\nsandbox = \"bubblewrap\"\n");
+    repo.write_project_config(PROBE_HOOK);
+    repo.commit("Add probe hook");
+    let approved = repo
+        .wt_command()
+        .args(["config", "approvals", "add", "--yes"])
+        .output()
+        .unwrap();
+    assert!(approved.status.success(), "{approved:?}");
+}
+
+fn assert_confined(repo: &TestRepo, worktree: &Path) {
+    assert!(
+        !worktree.join("escaped.txt").exists(),
+        "sandboxed hook wrote: {}",
+        fs::read_to_string(worktree.join("escaped.txt")).unwrap()
+    );
+    assert_eq!(
+        fs::read_to_string(worktree.join("sandboxed.txt")).unwrap(),
+        "ok\n"
+    );
+    assert!(!repo.root_path().join(".git/hooks/post-checkout").exists());
+}
+
+/// In a linked worktree: the `.git` file, `commondir`, and the shared
+/// `config` and `hooks/` stay read-only.
+#[rstest]
+fn test_sandboxed_hook_cannot_rewrite_git_in_linked_worktree(repo: TestRepo) {
+    if !bwrap_works() {
+        eprintln!("skipping: bubblewrap unavailable");
+        return;
+    }
+    repo.write_test_config("[hooks]\nsandbox = \"bubblewrap\"\n");
+    repo.write_project_config(PROBE_HOOK);
+    repo.commit("Add probe hook");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feat", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let worktree = repo.root_path().parent().unwrap().join("repo.feat");
+    assert_confined(&repo, &worktree);
+}
+
+/// In the main worktree, where the git directory is the shared one: its
+/// `config`, `hooks/`, refs, objects and the other worktrees' pointers stay
+/// read-only.
+#[rstest]
+fn test_sandboxed_hook_cannot_rewrite_git_in_main_worktree(mut repo: TestRepo) {
+    if !bwrap_works() {
+        eprintln!("skipping: bubblewrap unavailable");
+        return;
+    }
+    repo.add_worktree("other");
+    repo.write_test_config("[hooks]\nsandbox = \"bubblewrap\"\n");
+    repo.write_project_config(PROBE_HOOK);
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "pre-start", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_confined(&repo, repo.root_path());
+}
+
+/// `wt serve` runs project hooks through the library's hook runner, which
+/// confines them as `wt switch` does.
+#[rstest]
+fn test_sandboxed_hook_via_serve(repo: TestRepo) {
+    if !bwrap_works() {
+        eprintln!("skipping: bubblewrap unavailable");
+        return;
+    }
+    approve_probe_hook(&repo);
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["serve", "--stdio"]);
+    let mut rpc = JsonRpcChild::spawn(cmd);
+    let id = rpc.send_request("switch", json!({"branch": "feat", "create": true}));
+    let response = loop {
+        let message = rpc.recv("response");
+        if message["id"] == id {
+            break message;
+        }
+    };
+    assert!(response.get("error").is_none(), "{response}");
+    let worktree = response["result"]["path"].as_str().unwrap();
+    assert_confined(&repo, Path::new(worktree));
+}
+
+/// `wt mcp` likewise.
+#[rstest]
+fn test_sandboxed_hook_via_mcp(repo: TestRepo) {
+    if !bwrap_works() {
+        eprintln!("skipping: bubblewrap unavailable");
+        return;
+    }
+    approve_probe_hook(&repo);
+
+    let mut cmd = repo.wt_command();
+    cmd.arg("mcp");
+    let mut rpc = JsonRpcChild::spawn(cmd);
+    rpc.send_request(
+        "initialize",
+        json!({
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": {"name": "stub", "version": "0"},
+        }),
+    );
+    rpc.recv("initialize response");
+    rpc.send(&json!({"jsonrpc": "2.0", "method": "notifications/initialized"}));
+    rpc.send_request(
+        "tools/call",
+        json!({"name": "create_worktree", "arguments": {"branch": "feat"}}),
+    );
+    let result = rpc.recv("tool result")["result"].clone();
+    assert_eq!(result["isError"], false, "{result}");
+    let worktree = result["structuredContent"]["path"].as_str().unwrap();
+    assert_confined(&repo, Path::new(worktree));
+}
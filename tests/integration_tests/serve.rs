@@ -0,0 +1,221 @@
+//! Integration tests for `wt serve`.
+//!
+//! Each test drives a real `wt serve --stdio` child through
+//! [`JsonRpcChild`], waiting for a response or a notification with a timeout
+//! instead of blocking forever.
+
+use crate::common::json_rpc::JsonRpcChild;
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use serde_json::{Value, json};
+
+struct Session {
+    rpc: JsonRpcChild,
+    /// Notifications read while waiting for a response.
+    notifications: Vec<Value>,
+}
+
+impl Session {
+    fn start(repo: &TestRepo) -> Self {
+        let mut cmd = repo.wt_command();
+        cmd.args(["serve", "--stdio"]);
+        Self {
+            rpc: JsonRpcChild::spawn(cmd),
+            notifications: Vec::new(),
+        }
+    }
+
+    fn send_line(&mut self, line: &str) {
+        self.rpc.send_line(line);
+    }
+
+    /// Send a request and wait for its response.
+    fn call(&mut self, method: &str, params: Value) -> Value {
+        let id = self.rpc.send_request(method, params);
+        self.response(json!(id))
+    }
+
+    fn response(&mut self, id: Value) -> Value {
+        loop {
+            let message = self.rpc.recv("response");
+            if message.get("id") == Some(&id) {
+                return message;
+            }
+            self.notifications.push(message);
+        }
+    }
+
+    /// Send a request and unwrap a successful result.
+    fn result(&mut self, method: &str, params: Value) -> Value {
+        let response = self.call(method, params);
+        assert!(response.get("error").is_none(), "{response}");
+        response["result"].clone()
+    }
+
+    /// Wait for the next `changed` notification.
+    fn changed(&mut self) -> Value {
+        if let Some(i) = self
+            .notifications
+            .iter()
+            .position(|n| n["method"] == "changed")
+        {
+            return self.notifications.remove(i)["params"].clone();
+        }
+        loop {
+            let message = self.rpc.recv("notification");
+            if message["method"] == "changed" {
+                return message["params"].clone();
+            }
+        }
+    }
+}
+
+/// `list` answers with the same schema-2 envelope `wt list --format=json`
+/// prints.
+#[rstest]
+fn test_serve_list_matches_wt_list(mut repo: TestRepo) {
+    repo.write_test_config("[list]\njson-schema = 2\n");
+    let feature = repo.add_worktree("feature");
+    std::fs::write(feature.join("new.txt"), "new").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--branches"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected: Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let mut session = Session::start(&repo);
+    let listed = session.result("list", json!({"branches": true}));
+    assert_eq!(listed, expected);
+    // A second call is served from the same warm repository and still agrees.
+    assert_eq!(session.result("list", json!({"branches": true})), expected);
+}
+
+/// `switch` creates a missing worktree and returns an existing one; vars
+/// round-trip; `remove` takes the worktree and its integrated branch away.
+#[rstest]
+fn test_serve_switch_vars_remove(repo: TestRepo) {
+    let mut session = Session::start(&repo);
+
+    let created = session.result(
+        "switch",
+        json!({"branch": "feature", "create": true, "hooks": false}),
+    );
+    assert_eq!(created["created"], true);
+    let path = created["path"].as_str().unwrap().to_string();
+    assert!(std::path::Path::new(&path).is_dir());
+
+    let existing = session.result("switch", json!({"branch": "feature"}));
+    assert_eq!(existing["created"], false);
+    assert_eq!(existing["path"], path.as_str());
+
+    session.result(
+        "vars/set",
+        json!({"branch": "feature", "key": "env", "value": "staging"}),
+    );
+    assert_eq!(
+        session.result("vars/get", json!({"branch": "feature", "key": "env"})),
+        "staging"
+    );
+    assert_eq!(
+        session.result("vars/list", json!({"branch": "feature"})),
+        json!({"env": "staging"})
+    );
+
+    let removed = session.result("remove", json!({"branch": "feature", "hooks": false}));
+    assert_eq!(removed["branch_deleted"], true);
+    assert!(!std::path::Path::new(&path).exists());
+}
+
+/// `merge` squashes with the given message, fast-forwards the target and
+/// removes the worktree.
+#[rstest]
+fn test_serve_merge(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    for name in ["a.txt", "b.txt"] {
+        std::fs::write(feature.join(name), name).unwrap();
+        repo.run_git_in(&feature, &["add", name]);
+        repo.run_git_in(&feature, &["commit", "-m", name]);
+    }
+
+    let mut session = Session::start(&repo);
+    let merged = session.result(
+        "merge",
+        json!({"branch": "feature", "squash_message": "Add a and b", "hooks": false}),
+    );
+    assert_eq!(merged["target"], "main");
+    assert_eq!(merged["squashed"], true);
+    assert_eq!(merged["removed"]["branch_deleted"], true);
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), merged["commit"]);
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s"]),
+        "Add a and b"
+    );
+}
+
+/// Protocol errors use the JSON-RPC codes; failed operations carry the
+/// typed failure's name in `data.kind`.
+#[rstest]
+fn test_serve_errors(repo: TestRepo) {
+    let mut session = Session::start(&repo);
+
+    session.send_line("{not json");
+    let parse_error = session.response(Value::Null);
+    assert_eq!(parse_error["error"]["code"], -32700);
+
+    let unknown = session.call("rebase-everything", json!({}));
+    assert_eq!(unknown["error"]["code"], -32601);
+
+    let missing_param = session.call("vars/get", json!({"branch": "main"}));
+    assert_eq!(missing_param["error"]["code"], -32602);
+
+    let bad_key = session.call(
+        "vars/set",
+        json!({"branch": "main", "key": "a.b", "value": "x"}),
+    );
+    assert_eq!(bad_key["error"]["code"], -32602);
+
+    let failed = session.call("remove", json!({"branch": "nope"}));
+    assert_eq!(failed["error"]["code"], -32000);
+    assert_eq!(failed["error"]["data"]["kind"], "WorktreeNotFound");
+    assert_eq!(failed["error"]["message"], "Branch nope has no worktree");
+
+    // Notifications get no response, so the next answer is for the next id.
+    session.send_line(r#"{"jsonrpc":"2.0","method":"list"}"#);
+    let after = session.call("vars/list", json!({"branch": "main"}));
+    assert_eq!(after["result"], json!({}));
+}
+
+/// A ref or worktree change made outside the server is pushed as a `changed`
+/// notification.
+#[rstest]
+fn test_serve_notifies_on_changes(mut repo: TestRepo) {
+    let mut session = Session::start(&repo);
+    // Answering a request means the watcher has taken its first reading.
+    session.result("vars/list", json!({"branch": "main"}));
+
+    repo.run_git(&["branch", "topic"]);
+    assert_eq!(session.changed(), json!({"refs": true, "worktrees": false}));
+
+    repo.add_worktree("feature");
+    assert_eq!(session.changed()["worktrees"], true);
+}
+
+/// `hooks/show` returns `wt hook show --format=json`'s records.
+#[rstest]
+fn test_serve_hooks_show(repo: TestRepo) {
+    repo.write_project_config("pre-merge = \"cargo test\"\n");
+    let mut session = Session::start(&repo);
+    assert_eq!(
+        session.result("hooks/show", json!({"hook_type": "pre-merge"})),
+        json!([{
+            "type": "pre-merge",
+            "source": "project",
+            "name": null,
+            "template": "cargo test",
+            "needs_approval": true,
+        }])
+    );
+}
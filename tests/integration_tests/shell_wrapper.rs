@@ -190,13 +190,17 @@ fn wrapper_shell(shell_name: &str) -> shell::Shell {
         "nu" | "nushell" => shell::Shell::Nushell,
         "zsh" => shell::Shell::Zsh,
         "powershell" | "pwsh" => shell::Shell::PowerShell,
+        "xonsh" => shell::Shell::Xonsh,
+        "elvish" => shell::Shell::Elvish,
         other => panic!("Unsupported shell wrapper test shell: {other}"),
     }
 }
 
 fn wrapper_env_vars(shell: shell::Shell, repo: &TestRepo) -> Vec<(&'static str, String)> {
     let quote = |value: &str| match shell {
-        shell::Shell::PowerShell => powershell_quote(value),
+        // Both double a single quote inside single quotes
+        shell::Shell::PowerShell | shell::Shell::Elvish => powershell_quote(value),
+        shell::Shell::Xonsh => format!("{value:?}"),
         _ => shell_quote(value),
     };
 
@@ -213,7 +217,10 @@ fn wrapper_env_vars(shell: shell::Shell, repo: &TestRepo) -> Vec<(&'static str,
         (
             "CLICOLOR_FORCE",
             match shell {
-                shell::Shell::Nushell | shell::Shell::PowerShell => "'1'".to_string(),
+                shell::Shell::Nushell
+                | shell::Shell::PowerShell
+                | shell::Shell::Xonsh
+                | shell::Shell::Elvish => "'1'".to_string(),
                 _ => "1".to_string(),
             },
         ),
@@ -230,6 +237,8 @@ fn append_shell_env_exports(script: &mut String, shell: shell::Shell, vars: &[(&
             shell::Shell::Fish => script.push_str(&format!("set -x {key} {value}\n")),
             shell::Shell::Nushell => script.push_str(&format!("$env.{key} = {value}\n")),
             shell::Shell::PowerShell => script.push_str(&format!("$env:{key} = {value}\n")),
+            shell::Shell::Xonsh => script.push_str(&format!("${key} = {value}\n")),
+            shell::Shell::Elvish => script.push_str(&format!("set E:{key} = {value}\n")),
             shell::Shell::Bash | shell::Shell::Zsh => {
                 script.push_str(&format!("export {key}={value}\n"))
             }
@@ -1866,3 +1866,303 @@ fn test_copy_ignored_require_include_empty_worktreeinclude(mut repo: TestRepo) {
         "empty .worktreeinclude matches nothing — nothing copied"
     );
 }
+
+/// Seed `target/` in main with two files and sync it into a fresh `feature`.
+fn setup_copy_ignored_sync(repo: &mut TestRepo) -> PathBuf {
+    let feature_path = repo.add_worktree("feature");
+    let target = repo.root_path().join("target");
+    fs::create_dir_all(target.join("debug")).unwrap();
+    fs::write(target.join("debug").join("app"), "v1").unwrap();
+    fs::write(target.join("debug").join("old.o"), "object").unwrap();
+    fs::write(target.join("CACHEDIR.TAG"), "tag").unwrap();
+    fs::write(repo.root_path().join(".gitignore"), "target/\n").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "copy-ignored", "--sync"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "initial sync should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    feature_path
+}
+
+/// `--sync --delete` rewrites only the changed file, adds the new one, and
+/// removes the one deleted at the source.
+#[rstest]
+fn test_copy_ignored_sync_delete(mut repo: TestRepo) {
+    let feature_path = setup_copy_ignored_sync(&mut repo);
+    let target = repo.root_path().join("target");
+    fs::write(target.join("debug").join("app"), "v2 build").unwrap();
+    fs::write(target.join("debug").join("new.o"), "obj").unwrap();
+    fs::remove_file(target.join("debug").join("old.o")).unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["copy-ignored", "--sync", "--delete"],
+        Some(&feature_path),
+    ));
+
+    let dest = feature_path.join("target").join("debug");
+    assert_eq!(fs::read_to_string(dest.join("app")).unwrap(), "v2 build");
+    assert_eq!(fs::read_to_string(dest.join("new.o")).unwrap(), "obj");
+    assert!(!dest.join("old.o").exists());
+}
+
+/// Without `--sync`, an existing file is skipped even though the source
+/// changed; `--sync` without `--delete` updates it but keeps extra files.
+#[rstest]
+fn test_copy_ignored_sync_keeps_extra_without_delete(mut repo: TestRepo) {
+    let feature_path = setup_copy_ignored_sync(&mut repo);
+    let target = repo.root_path().join("target");
+    fs::write(target.join("debug").join("app"), "v2 build").unwrap();
+    fs::remove_file(target.join("debug").join("old.o")).unwrap();
+    let dest = feature_path.join("target").join("debug");
+
+    run_copy_ignored(&repo, &feature_path);
+    assert_eq!(fs::read_to_string(dest.join("app")).unwrap(), "v1");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "copy-ignored", "--sync"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(fs::read_to_string(dest.join("app")).unwrap(), "v2 build");
+    assert!(dest.join("old.o").exists());
+}
+
+/// `--sync --format=json` reports per-entry deltas; `--dry-run` computes the
+/// same counts without writing.
+#[rstest]
+fn test_copy_ignored_sync_json_deltas(mut repo: TestRepo) {
+    let feature_path = setup_copy_ignored_sync(&mut repo);
+    let target = repo.root_path().join("target");
+    fs::write(target.join("debug").join("app"), "v2 build").unwrap();
+    fs::remove_file(target.join("debug").join("old.o")).unwrap();
+
+    let run = |extra: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args([
+                "step",
+                "copy-ignored",
+                "--sync",
+                "--delete",
+                "--format=json",
+            ])
+            .args(extra)
+            .current_dir(&feature_path)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).expect("valid JSON")
+    };
+
+    let planned = run(&["--dry-run"]);
+    assert_eq!(planned["outcome"], "planned");
+    assert_eq!(
+        fs::read_to_string(feature_path.join("target/debug/app")).unwrap(),
+        "v1",
+        "dry run writes nothing"
+    );
+
+    let synced = run(&[]);
+    assert_eq!(synced["outcome"], "synced");
+    for parsed in [&planned, &synced] {
+        assert_eq!(parsed["files"], 1);
+        assert_eq!(parsed["bytes"], 8);
+        let entry = &parsed["entries"][0];
+        assert_eq!(entry["path"], "target");
+        assert_eq!(entry["kind"], "dir");
+        assert_eq!(entry["created"], 0);
+        assert_eq!(entry["updated"], 1);
+        assert_eq!(entry["unchanged"], 1);
+        assert_eq!(entry["deleted"], 1);
+    }
+
+    // Nothing left to do.
+    let again = run(&[]);
+    assert_eq!(again["files"], 0);
+    assert_eq!(again["unchanged"], 2);
+}
+
+/// `--checksum` treats a same-content file as unchanged even when its mtime
+/// differs, where the default quick check would rewrite it.
+#[rstest]
+fn test_copy_ignored_sync_checksum(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    fs::write(repo.root_path().join(".env"), "SECRET=value").unwrap();
+    fs::write(repo.root_path().join(".gitignore"), ".env\n").unwrap();
+    // A plain copy doesn't carry the source's mtime.
+    fs::write(feature_path.join(".env"), "SECRET=value").unwrap();
+    fs::File::options()
+        .write(true)
+        .open(feature_path.join(".env"))
+        .unwrap()
+        .set_modified(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap();
+
+    let sync_json = |extra: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args(["step", "copy-ignored", "--sync", "--format=json"])
+            .args(extra)
+            .current_dir(&feature_path)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).expect("valid JSON")
+    };
+    assert_eq!(sync_json(&["--checksum"])["unchanged"], 1);
+    assert_eq!(sync_json(&[])["updated"], 1);
+}
+
+/// `--sync` doesn't use the store, so a configured `store = true` is called
+/// out rather than silently dropped.
+#[cfg(unix)]
+#[rstest]
+fn test_copy_ignored_sync_warns_about_configured_store(mut repo: TestRepo) {
+    use std::os::unix::fs::MetadataExt;
+
+    let feature_path = repo.add_worktree("feature");
+    fs::write(repo.root_path().join(".env"), "SECRET=value").unwrap();
+    fs::write(repo.root_path().join(".gitignore"), ".env\n").unwrap();
+    repo.write_test_config("[step.copy-ignored]\nstore = true\n");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "copy-ignored", "--sync"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("doesn't apply to"), "{stderr}");
+    assert_eq!(fs::metadata(feature_path.join(".env")).unwrap().nlink(), 1);
+}
+
+/// Two worktrees with the same `target/` in `main`, for `--store` tests.
+#[cfg(unix)]
+fn setup_copy_ignored_store(repo: &mut TestRepo) -> (PathBuf, PathBuf) {
+    let alpha = repo.add_worktree("alpha");
+    let beta = repo.add_worktree("beta");
+    let target = repo.root_path().join("target");
+    fs::create_dir_all(target.join("debug")).unwrap();
+    fs::write(target.join("debug").join("app"), "binary").unwrap();
+    fs::write(target.join("debug").join("app.d"), "binary").unwrap();
+    fs::write(target.join("CACHEDIR.TAG"), "tag").unwrap();
+    fs::write(repo.root_path().join(".gitignore"), "target/\n").unwrap();
+    (alpha, beta)
+}
+
+/// Copies through the store share one object per distinct file: the second
+/// worktree adds nothing, and both link the same inode.
+#[cfg(unix)]
+#[rstest]
+fn test_copy_ignored_store_dedups_across_worktrees(mut repo: TestRepo) {
+    use std::os::unix::fs::MetadataExt;
+
+    let (alpha, beta) = setup_copy_ignored_store(&mut repo);
+    let copy = |worktree: &Path| {
+        let output = repo
+            .wt_command()
+            .args(["step", "copy-ignored", "--store", "--format=json"])
+            .current_dir(worktree)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).expect("valid JSON")
+    };
+
+    let first = copy(&alpha);
+    assert_eq!(first["files"], 3);
+    assert_eq!(first["bytes"], 15);
+    // `app` and `app.d` share contents, so only one copy of them is stored.
+    assert_eq!(first["stored_bytes"], 9);
+    let second = copy(&beta);
+    assert_eq!(second["files"], 3);
+    assert_eq!(second["stored_bytes"], 0);
+
+    let a = fs::metadata(alpha.join("target/debug/app")).unwrap();
+    let b = fs::metadata(beta.join("target/debug/app.d")).unwrap();
+    assert_eq!(a.ino(), b.ino());
+    assert!(a.permissions().readonly());
+    // The source itself is never linked into the store.
+    let src = fs::metadata(repo.root_path().join("target/debug/app")).unwrap();
+    assert_ne!(src.ino(), a.ino());
+    assert!(!src.permissions().readonly());
+
+    let output = repo
+        .wt_command()
+        .args(["config", "state", "store", "--format=json"])
+        .output()
+        .unwrap();
+    let usage: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(usage["objects"], 2);
+    assert_eq!(usage["bytes"], 9);
+    assert_eq!(usage["unreferenced"], 0);
+}
+
+/// `[step.copy-ignored] store = true` turns the store on without the flag,
+/// and `store gc` drops objects once no worktree links them.
+#[cfg(unix)]
+#[rstest]
+fn test_copy_ignored_store_config_and_gc(mut repo: TestRepo) {
+    let (alpha, beta) = setup_copy_ignored_store(&mut repo);
+    repo.write_test_config("[step.copy-ignored]\nstore = true\n");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["copy-ignored"],
+        Some(&alpha),
+    ));
+    let output = repo
+        .wt_command()
+        .args(["step", "copy-ignored"])
+        .current_dir(&beta)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // One worktree still links everything, so nothing is collectable yet.
+    fs::remove_dir_all(alpha.join("target")).unwrap();
+    let output = repo
+        .wt_command()
+        .args(["config", "state", "store", "gc"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("No unreferenced store files"),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    fs::remove_dir_all(beta.join("target")).unwrap();
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "config",
+        &["state", "store", "gc"],
+        None,
+    ));
+    assert!(
+        !repo
+            .root_path()
+            .join(".git/wt/store/objects")
+            .read_dir()
+            .unwrap()
+            .any(|fanout| fanout.unwrap().path().read_dir().unwrap().next().is_some())
+    );
+}
@@ -0,0 +1,147 @@
+//! Integration tests for `wt step resolve`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use std::path::PathBuf;
+
+/// Leave `feature` stopped on a conflicting pick of `conflict.txt` while
+/// rebasing onto main.
+fn stop_feature_mid_rebase(repo: &mut TestRepo) -> PathBuf {
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "conflict.txt", "feature\n", "Feature edit");
+    fs::write(repo.root_path().join("conflict.txt"), "main\n").unwrap();
+    repo.run_git(&["add", "conflict.txt"]);
+    repo.run_git(&["commit", "-m", "Main edit"]);
+
+    let rebase = repo
+        .git_command()
+        .current_dir(&feature_wt)
+        .args(["rebase", "main"])
+        .run()
+        .unwrap();
+    assert!(
+        !rebase.status.success(),
+        "rebase should have stopped on the conflict"
+    );
+    feature_wt
+}
+
+fn rebase_in_progress(repo: &TestRepo, worktree: &std::path::Path) -> bool {
+    let output = repo
+        .git_command()
+        .current_dir(worktree)
+        .args([
+            "rev-parse",
+            "--path-format=absolute",
+            "--git-path",
+            "rebase-merge",
+        ])
+        .run()
+        .unwrap();
+    PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()).exists()
+}
+
+#[rstest]
+fn test_step_resolve_no_rebase(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["resolve"],
+        Some(&feature_wt)
+    ));
+}
+
+#[rstest]
+fn test_step_resolve_requires_llm(mut repo: TestRepo) {
+    let feature_wt = stop_feature_mid_rebase(&mut repo);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["resolve", "--yes"],
+        Some(&feature_wt)
+    ));
+}
+
+/// Without a terminal every proposal would go unanswered, so the command
+/// refuses before spending an LLM call.
+#[rstest]
+fn test_step_resolve_not_interactive(mut repo: TestRepo) {
+    let feature_wt = stop_feature_mid_rebase(&mut repo);
+
+    assert_cmd_snapshot!({
+        let mut cmd = make_snapshot_cmd(&repo, "step", &["resolve"], Some(&feature_wt));
+        cmd.env(
+            "WORKTRUNK_COMMIT__GENERATION__COMMAND",
+            "cat >/dev/null && echo unreachable",
+        );
+        cmd
+    });
+}
+
+/// An accepted proposal is staged and the rebase runs to completion.
+#[rstest]
+fn test_step_resolve_completes_rebase(mut repo: TestRepo) {
+    let feature_wt = stop_feature_mid_rebase(&mut repo);
+
+    assert_cmd_snapshot!({
+        let mut cmd = make_snapshot_cmd(&repo, "step", &["resolve", "--yes"], Some(&feature_wt));
+        cmd.env(
+            "WORKTRUNK_COMMIT__GENERATION__COMMAND",
+            "cat >/dev/null && echo 'main and feature'",
+        );
+        cmd
+    });
+
+    assert!(!rebase_in_progress(&repo, &feature_wt));
+    assert_eq!(
+        fs::read_to_string(feature_wt.join("conflict.txt")).unwrap(),
+        "main and feature\n"
+    );
+    let branch = repo
+        .git_command()
+        .current_dir(&feature_wt)
+        .args(["branch", "--show-current"])
+        .run()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&branch.stdout).trim(), "feature");
+}
+
+/// A proposal that still carries conflict markers is discarded: the file keeps
+/// git's own markers, stays unmerged, and the rebase stays open.
+#[rstest]
+fn test_step_resolve_rejects_markers(mut repo: TestRepo) {
+    let feature_wt = stop_feature_mid_rebase(&mut repo);
+    let conflicted = fs::read_to_string(feature_wt.join("conflict.txt")).unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "resolve", "--yes", "--format=json"])
+        .current_dir(&feature_wt)
+        .env(
+            "WORKTRUNK_COMMIT__GENERATION__COMMAND",
+            "cat >/dev/null && printf '<<<<<<< HEAD\\nmain\\n=======\\nfeature\\n>>>>>>> x\\n'",
+        )
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["outcome"], "unresolved");
+    assert_eq!(json["resolved"], serde_json::json!([]));
+    assert_eq!(json["unresolved"][0]["path"], "conflict.txt");
+    assert_eq!(
+        json["unresolved"][0]["reason"],
+        "proposal still has conflict markers"
+    );
+
+    assert!(rebase_in_progress(&repo, &feature_wt));
+    assert_eq!(
+        fs::read_to_string(feature_wt.join("conflict.txt")).unwrap(),
+        conflicted
+    );
+}
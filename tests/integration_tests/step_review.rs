@@ -0,0 +1,157 @@
+//! Integration tests for `wt step review`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::path::{Path, PathBuf};
+
+fn feature_with_change(repo: &mut TestRepo) -> PathBuf {
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "feature.txt", "feature\n", "Add feature");
+    feature_wt
+}
+
+/// Run `wt step review --check --format=json` in `dir` with a mock LLM that
+/// prints `findings`, plus extra `envs`.
+fn review_json(
+    repo: &TestRepo,
+    dir: &Path,
+    findings: &str,
+    envs: &[(&str, &str)],
+) -> (Option<i32>, serde_json::Value) {
+    let output = repo
+        .wt_command()
+        .args(["step", "review", "--check", "--format=json"])
+        .current_dir(dir)
+        .env(
+            "WORKTRUNK_COMMIT__GENERATION__COMMAND",
+            format!("cat >/dev/null && printf -- '{findings}'"),
+        )
+        .envs(envs.iter().copied())
+        .output()
+        .unwrap();
+    let json = serde_json::from_slice(&output.stdout).unwrap();
+    (output.status.code(), json)
+}
+
+#[rstest]
+fn test_step_review_requires_llm(mut repo: TestRepo) {
+    let feature_wt = feature_with_change(&mut repo);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["review"],
+        Some(&feature_wt)
+    ));
+}
+
+#[rstest]
+fn test_step_review_no_changes(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+
+    assert_cmd_snapshot!({
+        let mut cmd = make_snapshot_cmd(&repo, "step", &["review"], Some(&feature_wt));
+        cmd.env(
+            "WORKTRUNK_COMMIT__GENERATION__COMMAND",
+            "cat >/dev/null && echo unreachable",
+        );
+        cmd
+    });
+}
+
+/// `--check` turns a `[blocking]` finding into exit 1, after printing it.
+#[rstest]
+fn test_step_review_check_blocks(mut repo: TestRepo) {
+    let feature_wt = feature_with_change(&mut repo);
+
+    assert_cmd_snapshot!({
+        let mut cmd = make_snapshot_cmd(&repo, "step", &["review", "--check"], Some(&feature_wt));
+        cmd.env(
+            "WORKTRUNK_COMMIT__GENERATION__COMMAND",
+            "cat >/dev/null && printf -- '- [blocking] feature.txt:1 — breaks the build\\n- [suggestion] feature.txt:1 — add a test\\n'",
+        );
+        cmd
+    });
+}
+
+/// Suggestions alone pass `--check`, and a second run with the same diff,
+/// template and command reads the cache instead of calling the LLM;
+/// `--refresh` bypasses it.
+#[rstest]
+fn test_step_review_suggestions_pass_and_cache(mut repo: TestRepo) {
+    let feature_wt = feature_with_change(&mut repo);
+    let suggestion = "- [suggestion] feature.txt:1 — name it\\n";
+
+    let (code, json) = review_json(&repo, &feature_wt, suggestion, &[]);
+    assert_eq!(code, Some(0));
+    assert_eq!(json["branch"], "feature");
+    assert_eq!(json["blocking"], serde_json::json!([]));
+    assert_eq!(json["cached"], false);
+
+    let (code, json) = review_json(&repo, &feature_wt, suggestion, &[]);
+    assert_eq!(code, Some(0));
+    assert_eq!(json["cached"], true);
+    assert_eq!(json["review"], "- [suggestion] feature.txt:1 — name it");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "review", "--check", "--refresh", "--format=json"])
+        .current_dir(&feature_wt)
+        .env(
+            "WORKTRUNK_COMMIT__GENERATION__COMMAND",
+            "cat >/dev/null && echo '- [blocking] feature.txt:1 — bug'",
+        )
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["cached"], false);
+    assert_eq!(
+        json["blocking"],
+        serde_json::json!(["[blocking] feature.txt:1 — bug"])
+    );
+}
+
+/// A different LLM command or review template misses the cache.
+#[rstest]
+fn test_step_review_cache_keyed_by_command_and_template(mut repo: TestRepo) {
+    let feature_wt = feature_with_change(&mut repo);
+    let (_, json) = review_json(&repo, &feature_wt, "- [suggestion] a\\n", &[]);
+    assert_eq!(json["cached"], false);
+
+    let (code, json) = review_json(&repo, &feature_wt, "- [blocking] b\\n", &[]);
+    assert_eq!(code, Some(1));
+    assert_eq!(json["cached"], false);
+    assert_eq!(json["review"], "- [blocking] b");
+
+    let template = [(
+        "WORKTRUNK_COMMIT__GENERATION__REVIEW_TEMPLATE",
+        "Review {{ branch }}:\n{{ git_diff }}",
+    )];
+    let (_, json) = review_json(&repo, &feature_wt, "- [blocking] b\\n", &template);
+    assert_eq!(json["cached"], false);
+    let (_, json) = review_json(&repo, &feature_wt, "- [blocking] b\\n", &template);
+    assert_eq!(json["cached"], true);
+}
+
+/// `--branch` reviews another worktree's branch from anywhere in the repo.
+#[rstest]
+fn test_step_review_branch_flag(mut repo: TestRepo) {
+    feature_with_change(&mut repo);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "review", "--branch", "feature", "--format=json"])
+        .current_dir(repo.root_path())
+        .env(
+            "WORKTRUNK_COMMIT__GENERATION__COMMAND",
+            "cat >/dev/null && echo 'No findings'",
+        )
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["branch"], "feature");
+    assert_eq!(json["review"], "No findings");
+}
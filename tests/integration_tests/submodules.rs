@@ -0,0 +1,149 @@
+//! Submodules: `[switch] submodules = "reference"` initializes a new
+//! worktree's submodules from the main worktree's copies, `wt list` shows
+//! their drift, and `copy-ignored` and `wt step diff` look inside them.
+#![cfg(unix)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::common::{TestRepo, repo, run_wt};
+use rstest::rstest;
+
+/// Create a library repository and add it to the main worktree as the `lib`
+/// submodule. Returns the library's path, which is the submodule URL.
+fn setup_submodule(repo: &TestRepo) -> PathBuf {
+    let lib = repo.home_path().join("lib-src");
+    fs::create_dir_all(&lib).unwrap();
+    git_in(repo, &lib, &["init", "-q", "-b", "main"]);
+    fs::write(lib.join("lib.txt"), "library\n").unwrap();
+    fs::write(lib.join(".gitignore"), "*.local\n").unwrap();
+    git_in(repo, &lib, &["add", "."]);
+    git_in(repo, &lib, &["commit", "-q", "-m", "Library"]);
+
+    repo.run_git(&[
+        "-c",
+        "protocol.file.allow=always",
+        "submodule",
+        "add",
+        "-q",
+        lib.to_str().unwrap(),
+        "lib",
+    ]);
+    repo.commit("Add lib submodule");
+    lib
+}
+
+fn git_in(repo: &TestRepo, dir: &Path, args: &[&str]) -> String {
+    let output = repo
+        .git_command()
+        .current_dir(dir)
+        .args(args.iter().copied())
+        .run()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// The new worktree's submodule is cloned from the main worktree's copy,
+/// borrowing its objects, with `origin` pointing back at the real URL.
+#[rstest]
+fn test_switch_create_initializes_submodules_from_reference(repo: TestRepo) {
+    let lib = setup_submodule(&repo);
+    repo.write_test_config("[switch]\nsubmodules = \"reference\"\n");
+    let worktree = repo.root_path().parent().unwrap().join("repo.feat");
+
+    let (_, stderr) = run_wt(
+        repo.wt_command()
+            .args(["switch", "--create", "feat", "--yes"]),
+    );
+    assert!(
+        stderr.contains("Initialized 1 submodule from the main worktree"),
+        "{stderr}"
+    );
+    let checkout = worktree.join("lib");
+    assert_eq!(
+        fs::read_to_string(checkout.join("lib.txt")).unwrap(),
+        "library\n"
+    );
+    assert_eq!(
+        git_in(&repo, &checkout, &["remote", "get-url", "origin"]).trim(),
+        lib.to_str().unwrap()
+    );
+    let alternates = git_in(
+        &repo,
+        &checkout,
+        &["rev-parse", "--git-path", "objects/info/alternates"],
+    );
+    assert!(
+        checkout.join(alternates.trim()).is_file(),
+        "submodule objects aren't borrowed from the main worktree"
+    );
+    assert_eq!(git_in(&repo, &worktree, &["status", "--porcelain"]), "");
+}
+
+/// Without `[switch] submodules`, new worktrees keep today's behavior.
+#[rstest]
+fn test_switch_create_leaves_submodules_by_default(repo: TestRepo) {
+    setup_submodule(&repo);
+    let worktree = repo.root_path().parent().unwrap().join("repo.feat");
+
+    run_wt(
+        repo.wt_command()
+            .args(["switch", "--create", "feat", "--yes"]),
+    );
+    assert!(!worktree.join("lib/lib.txt").exists());
+}
+
+/// A modified submodule shows as drift in `wt list` and as a content diff in
+/// `wt step diff`; `copy-ignored` copies ignored files inside it.
+#[rstest]
+fn test_submodule_drift_copy_ignored_and_diff(repo: TestRepo) {
+    setup_submodule(&repo);
+    repo.write_test_config("[switch]\nsubmodules = \"reference\"\n");
+    let worktree = repo.root_path().parent().unwrap().join("repo.feat");
+    run_wt(
+        repo.wt_command()
+            .args(["switch", "--create", "feat", "--yes"]),
+    );
+
+    fs::write(worktree.join("lib/lib.txt"), "library\nchanged\n").unwrap();
+    let (stdout, _) = run_wt(repo.wt_command().args([
+        "--config-set",
+        "list.json-schema=1",
+        "list",
+        "--format=json",
+    ]));
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let row = |branch: &str| {
+        json.as_array()
+            .unwrap()
+            .iter()
+            .find(|row| row["branch"] == branch)
+            .unwrap()
+            .clone()
+    };
+    let feat = row("feat");
+    assert_eq!(
+        feat["submodules"],
+        serde_json::json!({"out_of_date": false, "dirty": true})
+    );
+    assert!(feat["symbols"].as_str().unwrap().contains('⊙'), "{feat}");
+    assert!(row("main").get("submodules").is_none());
+
+    let (diff, _) = run_wt(repo.wt_command().args(["step", "diff", "--branch", "feat"]));
+    assert!(diff.contains("+changed"), "{diff}");
+
+    fs::write(repo.root_path().join("lib/settings.local"), "secret\n").unwrap();
+    run_wt(
+        repo.wt_command()
+            .args(["step", "copy-ignored", "--to", "feat"]),
+    );
+    assert_eq!(
+        fs::read_to_string(worktree.join("lib/settings.local")).unwrap(),
+        "secret\n"
+    );
+}
@@ -2766,12 +2766,12 @@ fn test_switch_prs_dry_run_github_log_tab_local(repo: TestRepo) {
 
     // The comments tab has no local path, so its forge fetch failed (pr view →
     // exit 1); the closure caches a terminal "couldn't load" pane rather than
-    // leaving the slot empty. Mode 8 is Comments. A non-empty entry here can only
+    // leaving the slot empty. Mode 9 is Comments. A non-empty entry here can only
     // be that failure pane — a successful comments fetch is impossible with
     // `pr view` rigged to exit 1.
     let comments_entry = entries
         .iter()
-        .find(|e| e["branch"] == "pr:42" && e["mode"] == 8)
+        .find(|e| e["branch"] == "pr:42" && e["mode"] == 9)
         .unwrap_or_else(|| {
             panic!("failed comments fetch must cache a couldn't-load pane:\n{stdout}")
         });
@@ -2786,7 +2786,7 @@ fn test_switch_prs_dry_run_github_log_tab_local(repo: TestRepo) {
 /// strand the tab on its loading spinner for the session — there's no in-session
 /// retry). Here the PR carries no `headRefOid`, so the `log` tab can't take the
 /// local fast path and must hit the forge API, and `gh pr view` is rigged to
-/// exit 1 — so both the `log` (mode 4) and `comments` (mode 8) cache entries are
+/// exit 1 — so both the `log` (mode 4) and `comments` (mode 9) cache entries are
 /// present and non-empty, each the couldn't-load pane.
 #[rstest]
 fn test_switch_prs_dry_run_github_deferred_fetch_failure(repo: TestRepo) {
@@ -2822,8 +2822,8 @@ fn test_switch_prs_dry_run_github_deferred_fetch_failure(repo: TestRepo) {
     let parsed: serde_json::Value = serde_json::from_str(&stdout).expect("stdout is valid JSON");
     let entries = parsed["entries"].as_array().expect("entries array");
     // Both deferred tabs cached a terminal couldn't-load pane (non-empty), never
-    // an empty slot. Modes: 4 = Log, 8 = Comments.
-    for (mode, tab) in [(4, "log"), (8, "comments")] {
+    // an empty slot. Modes: 4 = Log, 9 = Comments.
+    for (mode, tab) in [(4, "log"), (9, "comments")] {
         let entry = entries
             .iter()
             .find(|e| e["branch"] == "pr:42" && e["mode"] == mode)
@@ -2875,10 +2875,10 @@ fn test_switch_prs_dry_run_github_comments_tab(repo: TestRepo) {
     let stdout = String::from_utf8(output.stdout).expect("stdout is utf-8");
     let parsed: serde_json::Value = serde_json::from_str(&stdout).expect("stdout is valid JSON");
     let entries = parsed["entries"].as_array().expect("entries array");
-    // Mode 8 is Comments; the row keys its cache by `pr:42`.
+    // Mode 9 is Comments; the row keys its cache by `pr:42`.
     let comments_entry = entries
         .iter()
-        .find(|e| e["branch"] == "pr:42" && e["mode"] == 8)
+        .find(|e| e["branch"] == "pr:42" && e["mode"] == 9)
         .unwrap_or_else(|| panic!("no pr:42 Comments cache entry in dump:\n{stdout}"));
     assert!(
         comments_entry["bytes"].as_u64().unwrap_or(0) > 0,
@@ -2964,7 +2964,7 @@ fn test_switch_prs_dry_run_gitlab_deferred_tabs(repo: TestRepo) {
     let stdout = String::from_utf8(output.stdout).expect("stdout is utf-8");
     let parsed: serde_json::Value = serde_json::from_str(&stdout).expect("stdout is valid JSON");
     let entries = parsed["entries"].as_array().expect("entries array");
-    for (mode, label) in [(4, "Log"), (8, "Comments")] {
+    for (mode, label) in [(4, "Log"), (9, "Comments")] {
         let entry = entries
             .iter()
             .find(|e| e["branch"] == "mr:7" && e["mode"] == mode)
@@ -2161,6 +2161,138 @@ fn test_switch_picker_alt_x_morphs_removed_worktree_in_place(mut repo: TestRepo)
     );
 }
 
+/// A custom action from `[switch.picker.keys.actions]` runs against the selected
+/// row, and the row reflects what it did: this one removes the row's worktree
+/// but not its (unmerged) branch, so the row morphs to `/ branch` in place —
+/// the same end state as an alt-x removal that keeps the branch. Removal itself
+/// is rebound off alt-x, and the action takes the freed key.
+#[rstest]
+fn test_switch_picker_custom_action_morphs_row(mut repo: TestRepo) {
+    repo.run_git(&["remote", "remove", "origin"]);
+    repo.write_test_config(
+        r#"
+[switch.picker.keys]
+remove = "ctrl-x"
+
+[switch.picker.keys.actions]
+alt-x = "git -C {{ primary_worktree_path }} worktree remove {{ worktree_path }}"
+"#,
+    );
+
+    let wt_path = repo.add_worktree("action-target");
+    std::fs::write(wt_path.join("new.txt"), "unmerged work").unwrap();
+    repo.git_command()
+        .args(["-C", wt_path.to_str().unwrap(), "add", "new.txt"])
+        .run()
+        .unwrap();
+    repo.git_command()
+        .args([
+            "-C",
+            wt_path.to_str().unwrap(),
+            "commit",
+            "-m",
+            "unmerged work",
+        ])
+        .run()
+        .unwrap();
+
+    let env_vars = repo.test_env_vars();
+    let PickerSession {
+        child,
+        _master,
+        writer,
+        rx,
+        mut parser,
+    } = boot_picker_pty(
+        wt_bin().to_str().unwrap(),
+        &["switch"],
+        repo.root_path(),
+        &env_vars,
+    );
+    let send = |bytes: &[u8]| {
+        let mut w = writer.lock().unwrap();
+        w.write_all(bytes).unwrap();
+        w.flush().unwrap();
+    };
+
+    wait_for_stable_with_content(&rx, &mut parser, Some("action-target"));
+    send(b"action-target");
+    wait_for_cursor_on_row(&rx, &mut parser, "+ action-target");
+
+    // The action runs in the background; once the worktree is gone the row
+    // morphs under the cursor.
+    send(b"\x1bx");
+    wait_for_cursor_on_row(&rx, &mut parser, "/ action-target");
+
+    worktrunk::testing::wait_for_worktree_removed(&wt_path);
+    let exit_code = abort_and_exit_code(child, writer, rx);
+    assert_valid_abort_exit_code(exit_code);
+
+    let branches = repo.git_output(&["branch", "--list", "action-target"]);
+    assert!(
+        branches.contains("action-target"),
+        "the action removed only the worktree: {branches:?}"
+    );
+}
+
+/// Marking rows with ctrl-space makes alt-x remove every marked row at once.
+/// Both worktrees sit at main's commit, so each removal integrates and drops
+/// its row along with the branch.
+#[rstest]
+fn test_switch_picker_alt_x_removes_marked_rows(mut repo: TestRepo) {
+    repo.run_git(&["remote", "remove", "origin"]);
+    let path_a = repo.add_worktree("bulk-a");
+    let path_b = repo.add_worktree("bulk-b");
+
+    let env_vars = repo.test_env_vars();
+    let PickerSession {
+        child,
+        _master,
+        writer,
+        rx,
+        mut parser,
+    } = boot_picker_pty(
+        wt_bin().to_str().unwrap(),
+        &["switch"],
+        repo.root_path(),
+        &env_vars,
+    );
+    let send = |bytes: &[u8]| {
+        let mut w = writer.lock().unwrap();
+        w.write_all(bytes).unwrap();
+        w.flush().unwrap();
+    };
+
+    wait_for_stable_with_content(&rx, &mut parser, Some("bulk-b"));
+    send(b"bulk-");
+    wait_for_stable_with_content(&rx, &mut parser, Some("bulk-"));
+    let screen = parser.screen().contents();
+    let (first, second) = if cursor_points_at(&screen, "+ bulk-a") {
+        ("+ bulk-a", "+ bulk-b")
+    } else {
+        ("+ bulk-b", "+ bulk-a")
+    };
+    wait_for_cursor_on_row(&rx, &mut parser, first);
+
+    // Marking moves the cursor on, so two presses mark both rows.
+    send(b"\x00");
+    wait_for_cursor_on_row(&rx, &mut parser, second);
+    send(b"\x00");
+    wait_for_stable_with_content(&rx, &mut parser, Some("2 rows marked"));
+
+    send(b"\x1bx");
+    worktrunk::testing::wait_for_worktree_removed(&path_a);
+    worktrunk::testing::wait_for_worktree_removed(&path_b);
+    let exit_code = abort_and_exit_code(child, writer, rx);
+    assert_valid_abort_exit_code(exit_code);
+
+    let branches = repo.git_output(&["branch", "--list", "bulk-*"]);
+    assert!(
+        branches.trim().is_empty(),
+        "both integrated branches are deleted: {branches:?}"
+    );
+}
+
 /// alt-x under an active fuzzy query lands the cursor on the row displayed just
 /// below the removed one — the *filtered display* order, not the removed row's
 /// index in the full (unfiltered) `shared_items` list.
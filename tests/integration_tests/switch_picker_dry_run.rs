@@ -449,7 +449,7 @@ fn test_picker_dry_run_shows_cached_pr_numbers(mut repo: TestRepo) {
         .as_array()
         .expect("top-level `entries` array")
         .iter()
-        .filter(|e| e["mode"] == 8)
+        .filter(|e| e["mode"] == 9)
         .map(|e| e["branch"].as_str().expect("branch is a string"))
         .collect();
     assert!(
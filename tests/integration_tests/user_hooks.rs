@@ -1173,6 +1173,43 @@ post-switch = "exit 9"
     assert_snapshot!(fs::read_to_string(&unnamed).unwrap(), @"[31m✗[39m [31mcommand failed with exit code 9: exit 9[39m");
 }
 
+/// A failed background pipeline raises the `pipeline-failed` notification,
+/// which the `command` sink receives as JSON on stdin.
+#[rstest]
+fn test_background_hook_failure_notifies(repo: TestRepo) {
+    let notified = repo.root_path().join("notified.json");
+    repo.write_test_config(&format!(
+        r#"post-start = [{{ broken = "exit 7" }}]
+
+[notify]
+command = "cat > '{}'"
+
+[notify.pipeline-failed]
+sinks = ["command"]
+"#,
+        notified.display()
+    ));
+
+    let mut cmd = crate::common::wt_command();
+    cmd.current_dir(repo.root_path());
+    cmd.env("WORKTRUNK_CONFIG_PATH", repo.test_config_path());
+    cmd.args(["hook", "post-start", "--yes"]);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+
+    wait_for_file_content(&notified);
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&notified).unwrap()).unwrap();
+    assert_eq!(json["event"], "pipeline-failed");
+    assert_eq!(json["title"], "post-start failed on main");
+    assert_eq!(json["body"], "command failed with exit code 7: broken");
+    assert_eq!(json["branch"], "main");
+}
+
 /// A semantic template error in a foreground pipeline step surfaces when that
 /// step runs: earlier steps execute first, then the broken step's render
 /// aborts the pipeline. (Pre-change, prep expanded every template upfront and